@@ -0,0 +1,24 @@
+#version 450 core
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform texture2D uSource;
+layout (set = 0, binding = 1) uniform sampler uSampler;
+layout (set = 0, binding = 3) uniform BloomPassLayout {
+    vec4 f4Direction;
+} uPass;
+
+void main() {
+    vec2 f2Size = vec2(textureSize(sampler2D(uSource, uSampler), 0));
+    vec2 f2Texcoord = gl_FragCoord.xy / f2Size;
+    vec2 f2Step = uPass.f4Direction.xy / f2Size;
+
+    // A 9-tap gaussian kernel in five fetches, merging neighbouring taps with bilinear filtering.
+    vec3 f3Color = texture(sampler2D(uSource, uSampler), f2Texcoord).rgb * 0.2270270270;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + f2Step * 1.3846153846).rgb * 0.3162162162;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord - f2Step * 1.3846153846).rgb * 0.3162162162;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + f2Step * 3.2307692308).rgb * 0.0702702703;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord - f2Step * 3.2307692308).rgb * 0.0702702703;
+
+    outFragColor = vec4(f3Color, 1.0);
+}
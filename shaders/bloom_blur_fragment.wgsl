@@ -0,0 +1,76 @@
+// Generated from `bloom_blur_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct BloomPassLayout {
+    f4Direction: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uSource: texture_2d<f32>;
+@group(0) @binding(1) 
+var uSampler: sampler;
+@group(0) @binding(3) 
+var<uniform> uPass: BloomPassLayout;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn main_1() {
+    var f2Size: vec2<f32>;
+    var f2Texcoord: vec2<f32>;
+    var f2Step: vec2<f32>;
+    var f3Color: vec3<f32>;
+
+    let _e8 = textureDimensions(uSource, 0i);
+    f2Size = vec2<f32>(vec2<i32>(_e8));
+    let _e13 = gl_FragCoord;
+    let _e15 = f2Size;
+    f2Texcoord = (_e13.xy / _e15);
+    let _e18 = uPass;
+    let _e21 = f2Size;
+    f2Step = (_e18.f4Direction.xy / _e21);
+    let _e25 = f2Texcoord;
+    let _e26 = textureSample(uSource, uSampler, _e25);
+    f3Color = (_e26.xyz * 0.22702703f);
+    let _e31 = f3Color;
+    let _e32 = f2Texcoord;
+    let _e33 = f2Step;
+    let _e37 = f2Texcoord;
+    let _e38 = f2Step;
+    let _e42 = textureSample(uSource, uSampler, (_e37 + (_e38 * 1.3846154f)));
+    f3Color = (_e31 + (_e42.xyz * 0.31621623f));
+    let _e47 = f3Color;
+    let _e48 = f2Texcoord;
+    let _e49 = f2Step;
+    let _e53 = f2Texcoord;
+    let _e54 = f2Step;
+    let _e58 = textureSample(uSource, uSampler, (_e53 - (_e54 * 1.3846154f)));
+    f3Color = (_e47 + (_e58.xyz * 0.31621623f));
+    let _e63 = f3Color;
+    let _e64 = f2Texcoord;
+    let _e65 = f2Step;
+    let _e69 = f2Texcoord;
+    let _e70 = f2Step;
+    let _e74 = textureSample(uSource, uSampler, (_e69 + (_e70 * 3.2307692f)));
+    f3Color = (_e63 + (_e74.xyz * 0.07027027f));
+    let _e79 = f3Color;
+    let _e80 = f2Texcoord;
+    let _e81 = f2Step;
+    let _e85 = f2Texcoord;
+    let _e86 = f2Step;
+    let _e90 = textureSample(uSource, uSampler, (_e85 - (_e86 * 3.2307692f)));
+    f3Color = (_e79 + (_e90.xyz * 0.07027027f));
+    let _e95 = f3Color;
+    outFragColor = vec4<f32>(_e95.x, _e95.y, _e95.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e12 = outFragColor;
+    return FragmentOutput(_e12);
+}
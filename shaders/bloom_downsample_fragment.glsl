@@ -0,0 +1,20 @@
+#version 450 core
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform texture2D uSource;
+layout (set = 0, binding = 1) uniform sampler uSampler;
+
+void main() {
+    // The target is the next level of the mip chain, half the size of the source.
+    vec2 f2SourceSize = vec2(textureSize(sampler2D(uSource, uSampler), 0));
+    vec2 f2Texcoord = gl_FragCoord.xy / max(floor(f2SourceSize * 0.5), vec2(1.0));
+    vec2 f2Texel = 1.0 / f2SourceSize;
+
+    vec3 f3Color = texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(-1.0, -1.0) * f2Texel).rgb;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(1.0, -1.0) * f2Texel).rgb;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(-1.0, 1.0) * f2Texel).rgb;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(1.0, 1.0) * f2Texel).rgb;
+
+    outFragColor = vec4(f3Color * 0.25, 1.0);
+}
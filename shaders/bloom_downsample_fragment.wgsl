@@ -0,0 +1,69 @@
+// Generated from `bloom_downsample_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uSource: texture_2d<f32>;
+@group(0) @binding(1) 
+var uSampler: sampler;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn main_1() {
+    var f2SourceSize: vec2<f32>;
+    var f2Texcoord: vec2<f32>;
+    var f2Texel: vec2<f32>;
+    var f3Color: vec3<f32>;
+
+    let _e5 = textureDimensions(uSource, 0i);
+    f2SourceSize = vec2<f32>(vec2<i32>(_e5));
+    let _e10 = gl_FragCoord;
+    let _e12 = f2SourceSize;
+    let _e15 = f2SourceSize;
+    let _e21 = f2SourceSize;
+    let _e24 = f2SourceSize;
+    f2Texcoord = (_e10.xy / max(floor((_e24 * 0.5f)), vec2(1f)));
+    let _e34 = f2SourceSize;
+    f2Texel = (vec2(1f) / _e34);
+    let _e38 = f2Texcoord;
+    let _e44 = f2Texel;
+    let _e47 = f2Texcoord;
+    let _e53 = f2Texel;
+    let _e56 = textureSample(uSource, uSampler, (_e47 + (vec2<f32>(-1f, -1f) * _e53)));
+    f3Color = _e56.xyz;
+    let _e59 = f3Color;
+    let _e60 = f2Texcoord;
+    let _e65 = f2Texel;
+    let _e68 = f2Texcoord;
+    let _e73 = f2Texel;
+    let _e76 = textureSample(uSource, uSampler, (_e68 + (vec2<f32>(1f, -1f) * _e73)));
+    f3Color = (_e59 + _e76.xyz);
+    let _e79 = f3Color;
+    let _e80 = f2Texcoord;
+    let _e85 = f2Texel;
+    let _e88 = f2Texcoord;
+    let _e93 = f2Texel;
+    let _e96 = textureSample(uSource, uSampler, (_e88 + (vec2<f32>(-1f, 1f) * _e93)));
+    f3Color = (_e79 + _e96.xyz);
+    let _e99 = f3Color;
+    let _e100 = f2Texcoord;
+    let _e104 = f2Texel;
+    let _e107 = f2Texcoord;
+    let _e111 = f2Texel;
+    let _e114 = textureSample(uSource, uSampler, (_e107 + (vec2<f32>(1f, 1f) * _e111)));
+    f3Color = (_e99 + _e114.xyz);
+    let _e117 = f3Color;
+    let _e119 = (_e117 * 0.25f);
+    outFragColor = vec4<f32>(_e119.x, _e119.y, _e119.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e9 = outFragColor;
+    return FragmentOutput(_e9);
+}
@@ -0,0 +1,32 @@
+#version 450 core
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform texture2D uSource;
+layout (set = 0, binding = 1) uniform sampler uSampler;
+layout (set = 0, binding = 2) uniform BloomUniformLayout {
+    float fThreshold;
+    float fKnee;
+} uBloom;
+
+void main() {
+    // The target is half the size of the source, as the next level of a mip chain.
+    vec2 f2SourceSize = vec2(textureSize(sampler2D(uSource, uSampler), 0));
+    vec2 f2Texcoord = gl_FragCoord.xy / max(floor(f2SourceSize * 0.5), vec2(1.0));
+    vec2 f2Texel = 1.0 / f2SourceSize;
+
+    // Averages four bilinear taps so small bright spots do not flicker.
+    vec3 f3Color = texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(-1.0, -1.0) * f2Texel).rgb;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(1.0, -1.0) * f2Texel).rgb;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(-1.0, 1.0) * f2Texel).rgb;
+    f3Color += texture(sampler2D(uSource, uSampler), f2Texcoord + vec2(1.0, 1.0) * f2Texel).rgb;
+    f3Color = clamp(f3Color * 0.25, vec3(0.0), vec3(65504.0));
+
+    // Soft knee threshold: a quadratic curve eases the colors near the threshold in.
+    float fBrightness = max(f3Color.r, max(f3Color.g, f3Color.b));
+    float fSoft = clamp(fBrightness - uBloom.fThreshold + uBloom.fKnee, 0.0, 2.0 * uBloom.fKnee);
+    fSoft = fSoft * fSoft / (4.0 * uBloom.fKnee + 1e-5);
+    float fContribution = max(fSoft, fBrightness - uBloom.fThreshold) / max(fBrightness, 1e-5);
+
+    outFragColor = vec4(f3Color * fContribution, 1.0);
+}
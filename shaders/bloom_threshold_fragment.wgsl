@@ -0,0 +1,114 @@
+// Generated from `bloom_threshold_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct BloomUniformLayout {
+    fThreshold: f32,
+    fKnee: f32,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uSource: texture_2d<f32>;
+@group(0) @binding(1) 
+var uSampler: sampler;
+@group(0) @binding(2) 
+var<uniform> uBloom: BloomUniformLayout;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn main_1() {
+    var f2SourceSize: vec2<f32>;
+    var f2Texcoord: vec2<f32>;
+    var f2Texel: vec2<f32>;
+    var f3Color: vec3<f32>;
+    var fBrightness: f32;
+    var fSoft: f32;
+    var fContribution: f32;
+
+    let _e10 = textureDimensions(uSource, 0i);
+    f2SourceSize = vec2<f32>(vec2<i32>(_e10));
+    let _e15 = gl_FragCoord;
+    let _e17 = f2SourceSize;
+    let _e20 = f2SourceSize;
+    let _e26 = f2SourceSize;
+    let _e29 = f2SourceSize;
+    f2Texcoord = (_e15.xy / max(floor((_e29 * 0.5f)), vec2(1f)));
+    let _e39 = f2SourceSize;
+    f2Texel = (vec2(1f) / _e39);
+    let _e43 = f2Texcoord;
+    let _e49 = f2Texel;
+    let _e52 = f2Texcoord;
+    let _e58 = f2Texel;
+    let _e61 = textureSample(uSource, uSampler, (_e52 + (vec2<f32>(-1f, -1f) * _e58)));
+    f3Color = _e61.xyz;
+    let _e64 = f3Color;
+    let _e65 = f2Texcoord;
+    let _e70 = f2Texel;
+    let _e73 = f2Texcoord;
+    let _e78 = f2Texel;
+    let _e81 = textureSample(uSource, uSampler, (_e73 + (vec2<f32>(1f, -1f) * _e78)));
+    f3Color = (_e64 + _e81.xyz);
+    let _e84 = f3Color;
+    let _e85 = f2Texcoord;
+    let _e90 = f2Texel;
+    let _e93 = f2Texcoord;
+    let _e98 = f2Texel;
+    let _e101 = textureSample(uSource, uSampler, (_e93 + (vec2<f32>(-1f, 1f) * _e98)));
+    f3Color = (_e84 + _e101.xyz);
+    let _e104 = f3Color;
+    let _e105 = f2Texcoord;
+    let _e109 = f2Texel;
+    let _e112 = f2Texcoord;
+    let _e116 = f2Texel;
+    let _e119 = textureSample(uSource, uSampler, (_e112 + (vec2<f32>(1f, 1f) * _e116)));
+    f3Color = (_e104 + _e119.xyz);
+    let _e122 = f3Color;
+    let _e129 = f3Color;
+    f3Color = clamp((_e129 * 0.25f), vec3(0f), vec3(65504f));
+    let _e137 = f3Color;
+    let _e139 = f3Color;
+    let _e141 = f3Color;
+    let _e143 = f3Color;
+    let _e145 = f3Color;
+    let _e148 = f3Color;
+    let _e150 = f3Color;
+    let _e152 = f3Color;
+    let _e154 = f3Color;
+    let _e156 = f3Color;
+    fBrightness = max(_e148.x, max(_e154.y, _e156.z));
+    let _e161 = fBrightness;
+    let _e162 = uBloom;
+    let _e165 = uBloom;
+    let _e170 = uBloom;
+    let _e173 = fBrightness;
+    let _e174 = uBloom;
+    let _e177 = uBloom;
+    let _e182 = uBloom;
+    fSoft = clamp(((_e173 - _e174.fThreshold) + _e177.fKnee), 0f, (2f * _e182.fKnee));
+    let _e187 = fSoft;
+    let _e188 = fSoft;
+    let _e191 = uBloom;
+    fSoft = ((_e187 * _e188) / ((4f * _e191.fKnee) + 0.00001f));
+    let _e198 = fBrightness;
+    let _e199 = uBloom;
+    let _e202 = fSoft;
+    let _e203 = fBrightness;
+    let _e204 = uBloom;
+    let _e210 = fBrightness;
+    fContribution = (max(_e202, (_e203 - _e204.fThreshold)) / max(_e210, 0.00001f));
+    let _e215 = f3Color;
+    let _e216 = fContribution;
+    let _e217 = (_e215 * _e216);
+    outFragColor = vec4<f32>(_e217.x, _e217.y, _e217.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e14 = outFragColor;
+    return FragmentOutput(_e14);
+}
@@ -0,0 +1,22 @@
+// Generated from `debug_line_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> inColor_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+
+fn main_1() {
+    let _e2 = inColor_1;
+    outFragColor = _e2;
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    main_1();
+    let _e7 = outFragColor;
+    return FragmentOutput(_e7);
+}
@@ -0,0 +1,17 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+layout (location = 1) in vec4 inColor;
+
+layout (location = 0) out vec4 outColor;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+void main() {
+    outColor = inColor;
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * vec4(inPosition, 1.0);
+}
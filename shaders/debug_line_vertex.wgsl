@@ -0,0 +1,39 @@
+// Generated from `debug_line_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outColor: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inColor_1: vec4<f32>;
+var<private> outColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    let _e10 = inColor_1;
+    outColor = _e10;
+    let _e12 = uCamera;
+    let _e14 = uCamera;
+    let _e17 = inPosition_1;
+    gl_Position = ((_e12.mtxProjection * _e14.mtxView) * vec4<f32>(_e17.x, _e17.y, _e17.z, 1f));
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inColor: vec4<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inColor_1 = inColor;
+    main_1();
+    let _e18 = outColor;
+    let _e20 = gl_Position;
+    return VertexOutput(_e18, _e20);
+}
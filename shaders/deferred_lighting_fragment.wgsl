@@ -0,0 +1,759 @@
+// Generated from `deferred_lighting_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct DeferredUniformLayout {
+    mtxInverseViewProjection: mat4x4<f32>,
+    f4CameraPosition: vec4<f32>,
+    f4Params: vec4<f32>,
+}
+
+struct PhongLightLayout {
+    f4Position: vec4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct DeferredLightLayout {
+    aLights: array<PhongLightLayout>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+const PI: f32 = 3.1415927f;
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uAlbedo: texture_2d<f32>;
+@group(0) @binding(1) 
+var uNormal: texture_2d<f32>;
+@group(0) @binding(2) 
+var uMaterial: texture_2d<f32>;
+@group(0) @binding(3) 
+var uDepth: texture_2d<f32>;
+@group(0) @binding(4) 
+var uGBufferSampler: sampler;
+@group(0) @binding(5) 
+var<uniform> uDeferred: DeferredUniformLayout;
+@group(0) @binding(6) 
+var<storage> bLights: DeferredLightLayout;
+@group(1) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(2) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(2) @binding(1) 
+var uSampler: sampler_comparison;
+@group(2) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(3) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e46 = f2ProjCoords_1;
+    let _e47 = fCurrentDepth_1;
+    let _e51 = f2ProjCoords_1;
+    let _e52 = fCurrentDepth_1;
+    let _e55 = vec3<f32>(_e51.x, _e51.y, _e52);
+    let _e58 = textureSampleCompare(uShadowMap, uSampler, _e55.xy, _e55.z);
+    return _e58;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e44 = f4LightSpaceFragPosition_1;
+    if (_e44.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e49 = f4LightSpaceFragPosition_1;
+    let _e51 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e49.z / _e51.w);
+    let _e55 = f4LightSpaceFragPosition_1;
+    let _e57 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e55.xy / vec2(_e57.w));
+    let _e62 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e62 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e71 = uShadowFilter;
+    iKernel = i32((_e71.f4Params.x + 0.5f));
+    let _e78 = uShadowFilter;
+    let _e83 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e78.f4Params.y) / vec2<f32>(vec2<i32>(_e83)));
+    let _e89 = iKernel;
+    if (_e89 == 3i) {
+        {
+            loop {
+                let _e96 = i;
+                if !((_e96 < 16i)) {
+                    break;
+                }
+                {
+                    let _e103 = fSum;
+                    let _e104 = f2ProjCoords_2;
+                    let _e105 = i;
+                    let _e109 = local[_e105];
+                    let _e110 = f2Radius;
+                    let _e114 = f2ProjCoords_2;
+                    let _e115 = i;
+                    let _e119 = local_1[_e115];
+                    let _e120 = f2Radius;
+                    let _e123 = fCurrentDepth_2;
+                    let _e124 = sampleShadow((_e114 + (_e119 * _e120)), _e123);
+                    fSum = (_e103 + _e124);
+                }
+                continuing {
+                    let _e100 = i;
+                    i = (_e100 + 1i);
+                }
+            }
+            let _e126 = fSum;
+            return (_e126 / 16f);
+        }
+    }
+    let _e129 = iKernel;
+    let _e132 = iKernel;
+    if ((_e129 == 1i) || (_e132 == 2i)) {
+        {
+            let _e138 = iKernel;
+            y = -(_e138);
+            loop {
+                let _e141 = y;
+                let _e142 = iKernel;
+                if !((_e141 <= _e142)) {
+                    break;
+                }
+                {
+                    let _e148 = iKernel;
+                    x = -(_e148);
+                    loop {
+                        let _e151 = x;
+                        let _e152 = iKernel;
+                        if !((_e151 <= _e152)) {
+                            break;
+                        }
+                        {
+                            let _e158 = fSum_1;
+                            let _e159 = f2ProjCoords_2;
+                            let _e160 = x;
+                            let _e161 = y;
+                            let _e165 = iKernel;
+                            let _e169 = f2Radius;
+                            let _e173 = f2ProjCoords_2;
+                            let _e174 = x;
+                            let _e175 = y;
+                            let _e179 = iKernel;
+                            let _e183 = f2Radius;
+                            let _e186 = fCurrentDepth_2;
+                            let _e187 = sampleShadow((_e173 + ((vec2<f32>(f32(_e174), f32(_e175)) / vec2(f32(_e179))) * _e183)), _e186);
+                            fSum_1 = (_e158 + _e187);
+                        }
+                        continuing {
+                            let _e155 = x;
+                            x = (_e155 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e145 = y;
+                    y = (_e145 + 1i);
+                }
+            }
+            let _e190 = iKernel;
+            fWidth = f32(((2i * _e190) + 1i));
+            let _e196 = fSum_1;
+            let _e197 = fWidth;
+            let _e198 = fWidth;
+            return (_e196 / (_e197 * _e198));
+        }
+    }
+    let _e203 = f2ProjCoords_2;
+    let _e204 = fCurrentDepth_2;
+    let _e205 = sampleShadow(_e203, _e204);
+    return _e205;
+}
+
+fn calculateFog(f3PositionW: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_1: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_1 = f3PositionW;
+    let _e44 = uFog;
+    fDensity = _e44.f4Params.x;
+    let _e48 = uFog;
+    let _e52 = uFog;
+    fFalloff = max(_e52.f4Params.y, 0.0001f);
+    let _e58 = uFog;
+    fBaseHeight = _e58.f4Params.z;
+    let _e62 = uFog;
+    fMaxOpacity = _e62.f4Params.w;
+    let _e66 = f3PositionW_1;
+    let _e67 = uDeferred;
+    f3Ray = (_e66 - _e67.f4CameraPosition.xyz);
+    let _e73 = f3Ray;
+    fDistance = length(_e73);
+    let _e76 = fDensity;
+    let _e79 = fDistance;
+    if ((_e76 <= 0f) || (_e79 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e86 = uFog;
+    if (_e86.f4Range.z < 0.5f) {
+        {
+            let _e91 = fDistance;
+            let _e92 = uFog;
+            let _e96 = uFog;
+            let _e99 = uFog;
+            let _e104 = uFog;
+            let _e107 = uFog;
+            let _e116 = fDistance;
+            let _e117 = uFog;
+            let _e121 = uFog;
+            let _e124 = uFog;
+            let _e129 = uFog;
+            let _e132 = uFog;
+            fOpacity = clamp(((_e116 - _e117.f4Range.x) / max((_e129.f4Range.y - _e132.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e142 = uFog;
+        if (_e142.f4Range.z < 1.5f) {
+            {
+                let _e148 = fDensity;
+                let _e150 = fDistance;
+                let _e151 = uFog;
+                let _e156 = fDistance;
+                let _e157 = uFog;
+                let _e164 = fDensity;
+                let _e166 = fDistance;
+                let _e167 = uFog;
+                let _e172 = fDistance;
+                let _e173 = uFog;
+                fOpacity = (1f - exp((-(_e164) * max((_e172 - _e173.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e182 = fDensity;
+                let _e183 = fFalloff;
+                let _e185 = uDeferred;
+                let _e188 = fBaseHeight;
+                let _e191 = fFalloff;
+                let _e193 = uDeferred;
+                let _e196 = fBaseHeight;
+                let _e201 = fDistance;
+                fFogAmount = ((_e182 * exp((-(_e191) * (_e193.f4CameraPosition.y - _e196)))) * _e201);
+                let _e204 = fFalloff;
+                let _e205 = f3Ray;
+                fRayHeight = (_e204 * _e205.y);
+                let _e210 = fRayHeight;
+                if (abs(_e210) > 0.0001f) {
+                    {
+                        let _e214 = fFogAmount;
+                        let _e216 = fRayHeight;
+                        let _e218 = fRayHeight;
+                        let _e222 = fRayHeight;
+                        fFogAmount = (_e214 * ((1f - exp(-(_e218))) / _e222));
+                    }
+                }
+                let _e226 = fFogAmount;
+                let _e228 = fFogAmount;
+                fOpacity = (1f - exp(-(_e228)));
+            }
+        }
+    }
+    let _e234 = fOpacity;
+    let _e235 = fMaxOpacity;
+    fOpacity = min(_e234, _e235);
+    let _e237 = f3Ray;
+    let _e238 = fDistance;
+    let _e241 = uGlobalLight;
+    let _e244 = uGlobalLight;
+    let _e248 = f3Ray;
+    let _e249 = fDistance;
+    let _e252 = uGlobalLight;
+    let _e255 = uGlobalLight;
+    let _e261 = f3Ray;
+    let _e262 = fDistance;
+    let _e265 = uGlobalLight;
+    let _e268 = uGlobalLight;
+    let _e272 = f3Ray;
+    let _e273 = fDistance;
+    let _e276 = uGlobalLight;
+    let _e279 = uGlobalLight;
+    fSunAmount = max(dot((_e272 / vec3(_e273)), normalize(_e279.f4Direction.xyz)), 0f);
+    let _e287 = uFog;
+    let _e290 = uGlobalLight;
+    f3SunColor = (_e287.f4SunColor.xyz * _e290.f4LightColor.xyz);
+    let _e295 = uFog;
+    let _e300 = uFog;
+    let _e303 = fSunAmount;
+    let _e304 = uFog;
+    let _e308 = uFog;
+    let _e311 = f3SunColor;
+    let _e313 = uFog;
+    let _e316 = fSunAmount;
+    let _e317 = uFog;
+    f3FogColor = mix(_e308.f4Color.xyz, _e311, vec3(pow(_e316, _e317.f4SunColor.w)));
+    let _e324 = f3FogColor;
+    let _e325 = fOpacity;
+    return vec4<f32>(_e324.x, _e324.y, _e324.z, _e325);
+}
+
+fn calculateSpotAttenuation(f3PositionW_2: vec3<f32>) -> f32 {
+    var f3PositionW_3: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e44 = uGlobalLight;
+    if (_e44.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e50 = f3PositionW_3;
+    let _e51 = uGlobalLight;
+    f3ToFragment = (_e50 - _e51.f4Position.xyz);
+    let _e57 = f3ToFragment;
+    fDistance_1 = length(_e57);
+    let _e60 = f3ToFragment;
+    let _e63 = fDistance_1;
+    let _e68 = uGlobalLight;
+    let _e71 = uGlobalLight;
+    let _e76 = f3ToFragment;
+    let _e79 = fDistance_1;
+    let _e84 = uGlobalLight;
+    let _e87 = uGlobalLight;
+    fCosAngle = dot((_e76 / vec3(max(_e79, 0.0001f))), -(normalize(_e87.f4Direction.xyz)));
+    let _e94 = uGlobalLight;
+    let _e97 = uGlobalLight;
+    let _e101 = uGlobalLight;
+    let _e104 = uGlobalLight;
+    let _e107 = fCosAngle;
+    fCone = smoothstep(_e101.f4Cone.y, _e104.f4Cone.x, _e107);
+    let _e111 = fDistance_1;
+    let _e112 = uGlobalLight;
+    let _e116 = uGlobalLight;
+    let _e126 = fDistance_1;
+    let _e127 = uGlobalLight;
+    let _e131 = uGlobalLight;
+    fRange = clamp((1f - (_e126 / max(_e131.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e142 = fCone;
+    let _e143 = fRange;
+    let _e145 = fRange;
+    return ((_e142 * _e143) * _e145);
+}
+
+fn distributionGGX(fNdotH: f32, fRoughness: f32) -> f32 {
+    var fNdotH_1: f32;
+    var fRoughness_1: f32;
+    var fAlpha2_: f32;
+    var fDenom: f32;
+
+    fNdotH_1 = fNdotH;
+    fRoughness_1 = fRoughness;
+    let _e47 = fRoughness_1;
+    let _e48 = fRoughness_1;
+    let _e50 = fRoughness_1;
+    let _e52 = fRoughness_1;
+    fAlpha2_ = (((_e47 * _e48) * _e50) * _e52);
+    let _e55 = fNdotH_1;
+    let _e56 = fNdotH_1;
+    let _e58 = fAlpha2_;
+    fDenom = (((_e55 * _e56) * (_e58 - 1f)) + 1f);
+    let _e65 = fAlpha2_;
+    let _e66 = fDenom;
+    let _e69 = fDenom;
+    let _e72 = fDenom;
+    let _e75 = fDenom;
+    return (_e65 / max(((PI * _e72) * _e75), 0.000001f));
+}
+
+fn geometrySchlickGGX(fNdotX: f32, fRoughness_2: f32) -> f32 {
+    var fNdotX_1: f32;
+    var fRoughness_3: f32;
+    var fK: f32;
+
+    fNdotX_1 = fNdotX;
+    fRoughness_3 = fRoughness_2;
+    let _e47 = fRoughness_3;
+    let _e50 = fRoughness_3;
+    fK = (((_e47 + 1f) * (_e50 + 1f)) / 8f);
+    let _e57 = fNdotX_1;
+    let _e58 = fNdotX_1;
+    let _e60 = fK;
+    let _e63 = fK;
+    return (_e57 / ((_e58 * (1f - _e60)) + _e63));
+}
+
+fn fresnelSchlick(fCosTheta: f32, f3F0_: vec3<f32>) -> vec3<f32> {
+    var fCosTheta_1: f32;
+    var f3F0_1: vec3<f32>;
+
+    fCosTheta_1 = fCosTheta;
+    f3F0_1 = f3F0_;
+    let _e47 = f3F0_1;
+    let _e49 = f3F0_1;
+    let _e53 = fCosTheta_1;
+    let _e58 = fCosTheta_1;
+    let _e65 = fCosTheta_1;
+    let _e70 = fCosTheta_1;
+    return (_e47 + ((vec3(1f) - _e49) * pow(clamp((1f - _e70), 0f, 1f), 5f)));
+}
+
+fn calculateCookTorrance(f3NormalW: vec3<f32>, f3View: vec3<f32>, f3Light: vec3<f32>, f3LightColor: vec3<f32>, f3Albedo: vec3<f32>, f2Material: vec2<f32>) -> vec3<f32> {
+    var f3NormalW_1: vec3<f32>;
+    var f3View_1: vec3<f32>;
+    var f3Light_1: vec3<f32>;
+    var f3LightColor_1: vec3<f32>;
+    var f3Albedo_1: vec3<f32>;
+    var f2Material_1: vec2<f32>;
+    var fNdotL: f32;
+    var fMetallic: f32;
+    var fRoughness_4: f32;
+    var f3Half: vec3<f32>;
+    var fNdotV: f32;
+    var fNdotH_2: f32;
+    var f3F0_2: vec3<f32>;
+    var f3Fresnel: vec3<f32>;
+    var fGeometry: f32;
+    var f3Specular: vec3<f32>;
+    var f3Diffuse: vec3<f32>;
+
+    f3NormalW_1 = f3NormalW;
+    f3View_1 = f3View;
+    f3Light_1 = f3Light;
+    f3LightColor_1 = f3LightColor;
+    f3Albedo_1 = f3Albedo;
+    f2Material_1 = f2Material;
+    let _e57 = f3NormalW_1;
+    let _e58 = f3Light_1;
+    let _e63 = f3NormalW_1;
+    let _e64 = f3Light_1;
+    fNdotL = max(dot(_e63, _e64), 0f);
+    let _e69 = fNdotL;
+    if (_e69 <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e74 = f2Material_1;
+    fMetallic = _e74.x;
+    let _e77 = f2Material_1;
+    let _e80 = f2Material_1;
+    fRoughness_4 = max(_e80.y, 0.04f);
+    let _e85 = f3Light_1;
+    let _e86 = f3View_1;
+    let _e88 = f3Light_1;
+    let _e89 = f3View_1;
+    f3Half = normalize((_e88 + _e89));
+    let _e95 = f3NormalW_1;
+    let _e96 = f3View_1;
+    let _e101 = f3NormalW_1;
+    let _e102 = f3View_1;
+    fNdotV = max(dot(_e101, _e102), 0.0001f);
+    let _e109 = f3NormalW_1;
+    let _e110 = f3Half;
+    let _e115 = f3NormalW_1;
+    let _e116 = f3Half;
+    fNdotH_2 = max(dot(_e115, _e116), 0f);
+    let _e127 = f3Albedo_1;
+    let _e128 = fMetallic;
+    f3F0_2 = mix(vec3(0.04f), _e127, vec3(_e128));
+    let _e134 = f3Half;
+    let _e135 = f3View_1;
+    let _e140 = f3Half;
+    let _e141 = f3View_1;
+    let _e148 = f3Half;
+    let _e149 = f3View_1;
+    let _e154 = f3Half;
+    let _e155 = f3View_1;
+    let _e159 = f3F0_2;
+    let _e160 = fresnelSchlick(max(dot(_e154, _e155), 0f), _e159);
+    f3Fresnel = _e160;
+    let _e164 = fNdotV;
+    let _e165 = fRoughness_4;
+    let _e166 = geometrySchlickGGX(_e164, _e165);
+    let _e169 = fNdotL;
+    let _e170 = fRoughness_4;
+    let _e171 = geometrySchlickGGX(_e169, _e170);
+    fGeometry = (_e166 * _e171);
+    let _e176 = fNdotH_2;
+    let _e177 = fRoughness_4;
+    let _e178 = distributionGGX(_e176, _e177);
+    let _e179 = fGeometry;
+    let _e181 = f3Fresnel;
+    let _e184 = fNdotV;
+    let _e186 = fNdotL;
+    f3Specular = (((_e178 * _e179) * _e181) / vec3((((4f * _e184) * _e186) + 0.0001f)));
+    let _e194 = f3Fresnel;
+    let _e198 = fMetallic;
+    let _e201 = f3Albedo_1;
+    f3Diffuse = (((vec3(1f) - _e194) * (1f - _e198)) * _e201);
+    let _e204 = f3Diffuse;
+    let _e205 = f3Specular;
+    let _e208 = f3LightColor_1;
+    let _e210 = fNdotL;
+    return (((_e204 + (_e205 * PI)) * _e208) * _e210);
+}
+
+fn calculateLights(f3NormalW_2: vec3<f32>, f3View_2: vec3<f32>, f3PositionW_4: vec3<f32>, f3Albedo_2: vec3<f32>, f2Material_2: vec2<f32>) -> vec3<f32> {
+    var f3NormalW_3: vec3<f32>;
+    var f3View_3: vec3<f32>;
+    var f3PositionW_5: vec3<f32>;
+    var f3Albedo_3: vec3<f32>;
+    var f2Material_3: vec2<f32>;
+    var f3Color: vec3<f32> = vec3(0f);
+    var iNumLights: i32;
+    var i_1: i32 = 0i;
+    var f4Position: vec4<f32>;
+    var f4LightColor: vec4<f32>;
+    var f3ToLight: vec3<f32>;
+    var fDistance_2: f32;
+    var fRange_1: f32;
+
+    f3NormalW_3 = f3NormalW_2;
+    f3View_3 = f3View_2;
+    f3PositionW_5 = f3PositionW_4;
+    f3Albedo_3 = f3Albedo_2;
+    f2Material_3 = f2Material_2;
+    let _e56 = uDeferred;
+    iNumLights = i32(_e56.f4Params.x);
+    loop {
+        let _e63 = i_1;
+        let _e64 = iNumLights;
+        if !((_e63 < _e64)) {
+            break;
+        }
+        {
+            let _e70 = i_1;
+            let _e73 = bLights.aLights[_e70];
+            f4Position = _e73.f4Position;
+            let _e76 = i_1;
+            let _e79 = bLights.aLights[_e76];
+            f4LightColor = _e79.f4Color;
+            let _e82 = f4Position;
+            if (_e82.w <= 0f) {
+                {
+                    let _e86 = f3Color;
+                    let _e89 = f4Position;
+                    let _e91 = f4Position;
+                    let _e94 = f4LightColor;
+                    let _e98 = f3NormalW_3;
+                    let _e99 = f3View_3;
+                    let _e100 = f4Position;
+                    let _e102 = f4Position;
+                    let _e105 = f4LightColor;
+                    let _e107 = f3Albedo_3;
+                    let _e108 = f2Material_3;
+                    let _e109 = calculateCookTorrance(_e98, _e99, normalize(_e102.xyz), _e105.xyz, _e107, _e108);
+                    f3Color = (_e86 + _e109);
+                    continue;
+                }
+            }
+            let _e111 = f4Position;
+            let _e113 = f3PositionW_5;
+            f3ToLight = (_e111.xyz - _e113);
+            let _e117 = f3ToLight;
+            fDistance_2 = length(_e117);
+            let _e120 = fDistance_2;
+            let _e121 = f4LightColor;
+            if (_e120 >= _e121.w) {
+                {
+                    continue;
+                }
+            }
+            let _e125 = fDistance_2;
+            let _e126 = f4LightColor;
+            let _e129 = f4LightColor;
+            fRange_1 = (1f - (_e125 / max(_e129.w, 0.0001f)));
+            let _e136 = f3Color;
+            let _e139 = f3ToLight;
+            let _e142 = fDistance_2;
+            let _e147 = f4LightColor;
+            let _e151 = f3NormalW_3;
+            let _e152 = f3View_3;
+            let _e153 = f3ToLight;
+            let _e156 = fDistance_2;
+            let _e161 = f4LightColor;
+            let _e163 = f3Albedo_3;
+            let _e164 = f2Material_3;
+            let _e165 = calculateCookTorrance(_e151, _e152, (_e153 / vec3(max(_e156, 0.0001f))), _e161.xyz, _e163, _e164);
+            let _e166 = fRange_1;
+            let _e168 = fRange_1;
+            f3Color = (_e136 + ((_e165 * _e166) * _e168));
+        }
+        continuing {
+            let _e67 = i_1;
+            i_1 = (_e67 + 1i);
+        }
+    }
+    let _e171 = f3Color;
+    return _e171;
+}
+
+fn main_1() {
+    var i2Coord: vec2<i32>;
+    var fDepth: f32;
+    var f3Albedo_4: vec3<f32>;
+    var f3NormalW_4: vec3<f32>;
+    var f3Material: vec3<f32>;
+    var f2Size: vec2<f32>;
+    var f2Ndc: vec2<f32>;
+    var f4PositionW: vec4<f32>;
+    var f3PositionW_6: vec3<f32>;
+    var f3View_4: vec3<f32>;
+    var f4LightSpaceFragPosition_2: vec4<f32>;
+    var fShadow: f32;
+    var f3Sun: vec3<f32>;
+    var f3Color_1: vec3<f32>;
+    var f4Fog: vec4<f32>;
+
+    let _e44 = gl_FragCoord;
+    i2Coord = vec2<i32>(_e44.xy);
+    let _e50 = i2Coord;
+    let _e52 = textureLoad(uDepth, _e50, 0i);
+    fDepth = _e52.x;
+    let _e55 = fDepth;
+    if (_e55 >= 1f) {
+        {
+            discard;
+        }
+    }
+    let _e60 = i2Coord;
+    let _e62 = textureLoad(uAlbedo, _e60, 0i);
+    f3Albedo_4 = _e62.xyz;
+    let _e67 = i2Coord;
+    let _e69 = textureLoad(uNormal, _e67, 0i);
+    let _e73 = i2Coord;
+    let _e75 = textureLoad(uNormal, _e73, 0i);
+    f3NormalW_4 = normalize(_e75.xyz);
+    let _e81 = i2Coord;
+    let _e83 = textureLoad(uMaterial, _e81, 0i);
+    f3Material = _e83.xyz;
+    let _e88 = textureDimensions(uDepth, 0i);
+    f2Size = vec2<f32>(vec2<i32>(_e88));
+    let _e92 = gl_FragCoord;
+    let _e94 = f2Size;
+    f2Ndc = (((_e92.xy / _e94) * vec2<f32>(2f, -2f)) + vec2<f32>(-1f, 1f));
+    let _e107 = uDeferred;
+    let _e109 = f2Ndc;
+    let _e110 = fDepth;
+    f4PositionW = (_e107.mtxInverseViewProjection * vec4<f32>(_e109.x, _e109.y, _e110, 1f));
+    let _e117 = f4PositionW;
+    let _e119 = f4PositionW;
+    f3PositionW_6 = (_e117.xyz / vec3(_e119.w));
+    let _e124 = uDeferred;
+    let _e127 = f3PositionW_6;
+    let _e129 = uDeferred;
+    let _e132 = f3PositionW_6;
+    f3View_4 = normalize((_e129.f4CameraPosition.xyz - _e132));
+    let _e136 = uGlobalLight;
+    let _e138 = f3PositionW_6;
+    f4LightSpaceFragPosition_2 = (_e136.mtxProjView * vec4<f32>(_e138.x, _e138.y, _e138.z, 1f));
+    let _e147 = f4LightSpaceFragPosition_2;
+    let _e148 = calculateShadow(_e147);
+    let _e150 = f3PositionW_6;
+    let _e151 = calculateSpotAttenuation(_e150);
+    fShadow = (_e148 * _e151);
+    let _e156 = uGlobalLight;
+    let _e159 = uGlobalLight;
+    let _e163 = uGlobalLight;
+    let _e167 = f3Material;
+    let _e169 = f3NormalW_4;
+    let _e170 = f3View_4;
+    let _e171 = uGlobalLight;
+    let _e174 = uGlobalLight;
+    let _e178 = uGlobalLight;
+    let _e181 = f3Albedo_4;
+    let _e182 = f3Material;
+    let _e184 = calculateCookTorrance(_e169, _e170, normalize(_e174.f4Direction.xyz), _e178.f4LightColor.xyz, _e181, _e182.xy);
+    f3Sun = _e184;
+    let _e186 = f3Albedo_4;
+    let _e187 = uDeferred;
+    let _e191 = f3Material;
+    let _e194 = f3Sun;
+    let _e195 = fShadow;
+    f3Color_1 = (((_e186 * _e187.f4Params.y) * _e191.z) + (_e194 * _e195));
+    let _e199 = f3Color_1;
+    let _e204 = f3Material;
+    let _e206 = f3NormalW_4;
+    let _e207 = f3View_4;
+    let _e208 = f3PositionW_6;
+    let _e209 = f3Albedo_4;
+    let _e210 = f3Material;
+    let _e212 = calculateLights(_e206, _e207, _e208, _e209, _e210.xy);
+    f3Color_1 = (_e199 + _e212);
+    let _e215 = f3PositionW_6;
+    let _e216 = calculateFog(_e215);
+    f4Fog = _e216;
+    let _e219 = f4Fog;
+    let _e221 = f4Fog;
+    let _e223 = f3Color_1;
+    let _e224 = f4Fog;
+    let _e226 = f4Fog;
+    let _e229 = mix(_e223, _e224.xyz, vec3(_e226.w));
+    outFragColor = vec4<f32>(_e229.x, _e229.y, _e229.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e56 = outFragColor;
+    return FragmentOutput(_e56);
+}
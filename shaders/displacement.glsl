@@ -0,0 +1,71 @@
+#version 450 core
+
+layout (local_size_x = 8, local_size_y = 8, local_size_z = 1) in;
+
+layout (set = 0, binding = 0) uniform DisplacementUniformLayout {
+    vec4 f4Size;
+    vec4 f4Params;
+} uDisplacement;
+
+// Each vertex is (position.xyz, normal.xyz, texcoord.xy, tangent.xyzw), matchingthe layout of `ObjectVertexLayout`.
+layout (set = 0, binding = 1) buffer VertexLayout {
+    float aVertices[];
+} bVertices;
+
+float hash(vec2 f2Cell) {
+    return fract(sin(dot(f2Cell, vec2(127.1, 311.7))) * 43758.5453) * 2.0 - 1.0;
+}
+
+float valueNoise(vec2 f2Position) {
+    vec2 f2Cell = floor(f2Position);
+    vec2 f2Fract = f2Position - f2Cell;
+    vec2 f2Blend = f2Fract * f2Fract * (3.0 - 2.0 * f2Fract);
+    float a = hash(f2Cell);
+    float b = hash(f2Cell + vec2(1.0, 0.0));
+    float c = hash(f2Cell + vec2(0.0, 1.0));
+    float d = hash(f2Cell + vec2(1.0, 1.0));
+    return mix(mix(a, b, f2Blend.x), mix(c, d, f2Blend.x), f2Blend.y);
+}
+
+float calculateHeight(vec2 f2Position) {
+    float fAmplitude = uDisplacement.f4Params.x;
+    float fFrequency = uDisplacement.f4Params.y;
+    float fTime = uDisplacement.f4Size.w * uDisplacement.f4Params.z;
+
+    // Two octaves of value noise scrolling in different directions.
+    vec2 f2Position0 = f2Position * fFrequency + vec2(fTime, 0.7 * fTime);
+    vec2 f2Position1 = f2Position * fFrequency * 2.0 - vec2(0.6 * fTime, 1.3 * fTime);
+    return fAmplitude * (valueNoise(f2Position0) + 0.5 * valueNoise(f2Position1));
+}
+
+void main() {
+    uint uNumQuads = uint(uDisplacement.f4Size.z);
+    uint uNumVertices = uNumQuads + 1;
+    if (gl_GlobalInvocationID.x >= uNumVertices || gl_GlobalInvocationID.y >= uNumVertices) {
+        return;
+    }
+
+    vec2 f2Size = uDisplacement.f4Size.xy;
+    vec2 f2Step = f2Size / float(uNumQuads);
+    vec2 f2Position = vec2(gl_GlobalInvocationID.xy) * f2Step - 0.5 * f2Size;
+
+    float fHeight = calculateHeight(f2Position);
+    float fDx = calculateHeight(f2Position + vec2(f2Step.x, 0.0)) - calculateHeight(f2Position - vec2(f2Step.x, 0.0));
+    float fDz = calculateHeight(f2Position + vec2(0.0, f2Step.y)) - calculateHeight(f2Position - vec2(0.0, f2Step.y));
+    vec3 f3Normal = normalize(vec3(-fDx * f2Step.y, 2.0 * f2Step.x * f2Step.y, -fDz * f2Step.x));
+    vec3 f3Tangent = normalize(vec3(2.0 * f2Step.x, fDx, 0.0));
+
+    uint uOffset = 12 *(gl_GlobalInvocationID.y * uNumVertices + gl_GlobalInvocationID.x);
+    bVertices.aVertices[uOffset + 0] = f2Position.x;
+    bVertices.aVertices[uOffset + 1] = fHeight;
+    bVertices.aVertices[uOffset + 2] = f2Position.y;
+    bVertices.aVertices[uOffset + 3] = f3Normal.x;
+    bVertices.aVertices[uOffset + 4] = f3Normal.y;
+    bVertices.aVertices[uOffset + 5] = f3Normal.z;
+    bVertices.aVertices[uOffset + 6] = float(gl_GlobalInvocationID.x) / float(uNumQuads);
+    bVertices.aVertices[uOffset + 7] = float(gl_GlobalInvocationID.y) / float(uNumQuads);
+    bVertices.aVertices[uOffset + 8] = f3Tangent.x;
+    bVertices.aVertices[uOffset + 9] = f3Tangent.y;
+    bVertices.aVertices[uOffset + 10] = f3Tangent.z;
+    bVertices.aVertices[uOffset + 11] = -1.0;
+}
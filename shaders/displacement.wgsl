@@ -0,0 +1,246 @@
+// Generated from `displacement.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct DisplacementUniformLayout {
+    f4Size: vec4<f32>,
+    f4Params: vec4<f32>,
+}
+
+struct VertexLayout {
+    aVertices: array<f32>,
+}
+
+@group(0) @binding(0) 
+var<uniform> uDisplacement: DisplacementUniformLayout;
+@group(0) @binding(1) 
+var<storage, read_write> bVertices: VertexLayout;
+var<private> gl_GlobalInvocationID: vec3<u32>;
+
+fn hash(f2Cell: vec2<f32>) -> f32 {
+    var f2Cell_1: vec2<f32>;
+
+    f2Cell_1 = f2Cell;
+    let _e14 = f2Cell_1;
+    let _e23 = f2Cell_1;
+    let _e35 = f2Cell_1;
+    let _e44 = f2Cell_1;
+    return ((fract((sin(dot(_e44, vec2<f32>(127.1f, 311.7f))) * 43758.547f)) * 2f) - 1f);
+}
+
+fn valueNoise(f2Position: vec2<f32>) -> f32 {
+    var f2Position_1: vec2<f32>;
+    var f2Cell_2: vec2<f32>;
+    var f2Fract: vec2<f32>;
+    var f2Blend: vec2<f32>;
+    var a: f32;
+    var b: f32;
+    var c: f32;
+    var d: f32;
+
+    f2Position_1 = f2Position;
+    let _e11 = f2Position_1;
+    f2Cell_2 = floor(_e11);
+    let _e14 = f2Position_1;
+    let _e15 = f2Cell_2;
+    f2Fract = (_e14 - _e15);
+    let _e18 = f2Fract;
+    let _e19 = f2Fract;
+    let _e23 = f2Fract;
+    f2Blend = ((_e18 * _e19) * (vec2(3f) - (2f * _e23)));
+    let _e30 = f2Cell_2;
+    let _e31 = hash(_e30);
+    a = _e31;
+    let _e33 = f2Cell_2;
+    let _e38 = f2Cell_2;
+    let _e43 = hash((_e38 + vec2<f32>(1f, 0f)));
+    b = _e43;
+    let _e45 = f2Cell_2;
+    let _e50 = f2Cell_2;
+    let _e55 = hash((_e50 + vec2<f32>(0f, 1f)));
+    c = _e55;
+    let _e57 = f2Cell_2;
+    let _e62 = f2Cell_2;
+    let _e67 = hash((_e62 + vec2<f32>(1f, 1f)));
+    d = _e67;
+    let _e71 = f2Blend;
+    let _e73 = a;
+    let _e74 = b;
+    let _e75 = f2Blend;
+    let _e80 = f2Blend;
+    let _e82 = c;
+    let _e83 = d;
+    let _e84 = f2Blend;
+    let _e87 = f2Blend;
+    let _e91 = f2Blend;
+    let _e93 = a;
+    let _e94 = b;
+    let _e95 = f2Blend;
+    let _e100 = f2Blend;
+    let _e102 = c;
+    let _e103 = d;
+    let _e104 = f2Blend;
+    let _e107 = f2Blend;
+    return mix(mix(_e93, _e94, _e95.x), mix(_e102, _e103, _e104.x), _e107.y);
+}
+
+fn calculateHeight(f2Position_2: vec2<f32>) -> f32 {
+    var f2Position_3: vec2<f32>;
+    var fAmplitude: f32;
+    var fFrequency: f32;
+    var fTime: f32;
+    var f2Position0_: vec2<f32>;
+    var f2Position1_: vec2<f32>;
+
+    f2Position_3 = f2Position_2;
+    let _e10 = uDisplacement;
+    fAmplitude = _e10.f4Params.x;
+    let _e14 = uDisplacement;
+    fFrequency = _e14.f4Params.y;
+    let _e18 = uDisplacement;
+    let _e21 = uDisplacement;
+    fTime = (_e18.f4Size.w * _e21.f4Params.z);
+    let _e26 = f2Position_3;
+    let _e27 = fFrequency;
+    let _e29 = fTime;
+    let _e31 = fTime;
+    f2Position0_ = ((_e26 * _e27) + vec2<f32>(_e29, (0.7f * _e31)));
+    let _e36 = f2Position_3;
+    let _e37 = fFrequency;
+    let _e42 = fTime;
+    let _e45 = fTime;
+    f2Position1_ = (((_e36 * _e37) * 2f) - vec2<f32>((0.6f * _e42), (1.3f * _e45)));
+    let _e50 = fAmplitude;
+    let _e52 = f2Position0_;
+    let _e53 = valueNoise(_e52);
+    let _e56 = f2Position1_;
+    let _e57 = valueNoise(_e56);
+    return (_e50 * (_e53 + (0.5f * _e57)));
+}
+
+fn main_1() {
+    var uNumQuads: u32;
+    var uNumVertices: u32;
+    var f2Size: vec2<f32>;
+    var f2Step: vec2<f32>;
+    var f2Position_4: vec2<f32>;
+    var fHeight: f32;
+    var fDx: f32;
+    var fDz: f32;
+    var f3Normal: vec3<f32>;
+    var f3Tangent: vec3<f32>;
+    var uOffset: u32;
+
+    let _e8 = uDisplacement;
+    uNumQuads = u32(_e8.f4Size.z);
+    let _e13 = uNumQuads;
+    uNumVertices = (_e13 + 1u);
+    let _e19 = gl_GlobalInvocationID;
+    let _e21 = uNumVertices;
+    let _e23 = gl_GlobalInvocationID;
+    let _e25 = uNumVertices;
+    if ((_e19.x >= _e21) || (_e23.y >= _e25)) {
+        {
+            return;
+        }
+    }
+    let _e28 = uDisplacement;
+    f2Size = _e28.f4Size.xy;
+    let _e32 = f2Size;
+    let _e33 = uNumQuads;
+    f2Step = (_e32 / vec2(f32(_e33)));
+    let _e38 = gl_GlobalInvocationID;
+    let _e41 = f2Step;
+    let _e44 = f2Size;
+    f2Position_4 = ((vec2<f32>(_e38.xy) * _e41) - (0.5f * _e44));
+    let _e49 = f2Position_4;
+    let _e50 = calculateHeight(_e49);
+    fHeight = _e50;
+    let _e52 = f2Position_4;
+    let _e53 = f2Step;
+    let _e58 = f2Position_4;
+    let _e59 = f2Step;
+    let _e64 = calculateHeight((_e58 + vec2<f32>(_e59.x, 0f)));
+    let _e65 = f2Position_4;
+    let _e66 = f2Step;
+    let _e71 = f2Position_4;
+    let _e72 = f2Step;
+    let _e77 = calculateHeight((_e71 - vec2<f32>(_e72.x, 0f)));
+    fDx = (_e64 - _e77);
+    let _e80 = f2Position_4;
+    let _e82 = f2Step;
+    let _e86 = f2Position_4;
+    let _e88 = f2Step;
+    let _e92 = calculateHeight((_e86 + vec2<f32>(0f, _e88.y)));
+    let _e93 = f2Position_4;
+    let _e95 = f2Step;
+    let _e99 = f2Position_4;
+    let _e101 = f2Step;
+    let _e105 = calculateHeight((_e99 - vec2<f32>(0f, _e101.y)));
+    fDz = (_e92 - _e105);
+    let _e108 = fDx;
+    let _e110 = f2Step;
+    let _e114 = f2Step;
+    let _e117 = f2Step;
+    let _e120 = fDz;
+    let _e122 = f2Step;
+    let _e126 = fDx;
+    let _e128 = f2Step;
+    let _e132 = f2Step;
+    let _e135 = f2Step;
+    let _e138 = fDz;
+    let _e140 = f2Step;
+    f3Normal = normalize(vec3<f32>((-(_e126) * _e128.y), ((2f * _e132.x) * _e135.y), (-(_e138) * _e140.x)));
+    let _e147 = f2Step;
+    let _e150 = fDx;
+    let _e154 = f2Step;
+    let _e157 = fDx;
+    f3Tangent = normalize(vec3<f32>((2f * _e154.x), _e157, 0f));
+    let _e163 = gl_GlobalInvocationID;
+    let _e165 = uNumVertices;
+    let _e167 = gl_GlobalInvocationID;
+    uOffset = (12u * ((_e163.y * _e165) + _e167.x));
+    let _e173 = uOffset;
+    let _e179 = f2Position_4;
+    bVertices.aVertices[(_e173 + 0u)] = _e179.x;
+    let _e181 = uOffset;
+    let _e187 = fHeight;
+    bVertices.aVertices[(_e181 + 1u)] = _e187;
+    let _e188 = uOffset;
+    let _e194 = f2Position_4;
+    bVertices.aVertices[(_e188 + 2u)] = _e194.y;
+    let _e196 = uOffset;
+    let _e202 = f3Normal;
+    bVertices.aVertices[(_e196 + 3u)] = _e202.x;
+    let _e204 = uOffset;
+    let _e210 = f3Normal;
+    bVertices.aVertices[(_e204 + 4u)] = _e210.y;
+    let _e212 = uOffset;
+    let _e218 = f3Normal;
+    bVertices.aVertices[(_e212 + 5u)] = _e218.z;
+    let _e220 = uOffset;
+    let _e226 = gl_GlobalInvocationID;
+    let _e229 = uNumQuads;
+    bVertices.aVertices[(_e220 + 6u)] = (f32(_e226.x) / f32(_e229));
+    let _e232 = uOffset;
+    let _e238 = gl_GlobalInvocationID;
+    let _e241 = uNumQuads;
+    bVertices.aVertices[(_e232 + 7u)] = (f32(_e238.y) / f32(_e241));
+    let _e244 = uOffset;
+    let _e250 = f3Tangent;
+    bVertices.aVertices[(_e244 + 8u)] = _e250.x;
+    let _e252 = uOffset;
+    let _e258 = f3Tangent;
+    bVertices.aVertices[(_e252 + 9u)] = _e258.y;
+    let _e260 = uOffset;
+    let _e266 = f3Tangent;
+    bVertices.aVertices[(_e260 + 10u)] = _e266.z;
+    let _e268 = uOffset;
+    bVertices.aVertices[(_e268 + 11u)] = -1f;
+    return;
+}
+
+@compute @workgroup_size(8, 8, 1) 
+fn main(@builtin(global_invocation_id) param: vec3<u32>) {
+    gl_GlobalInvocationID = param;
+    main_1();
+    return;
+}
@@ -0,0 +1,57 @@
+#version 450 core
+
+layout (local_size_x = 256, local_size_y = 1, local_size_z = 1) in;
+
+layout (set = 0, binding = 0) uniform texture2D uScene;
+layout (set = 0, binding = 1) uniform sampler uSampler;
+
+layout (set = 0, binding = 2) buffer HistogramLayout {
+    uint aBins[256];
+} bHistogram;
+
+layout (set = 0, binding = 3) buffer ExposureLayout {
+    vec4 f4Exposure;
+} bExposure;
+
+layout (set = 0, binding = 4) uniform ExposureUniformLayout {
+    float fMinLogLuminance;
+    float fLogLuminanceRange;
+    float fElapsedTime;
+    float fAdaptationSpeed;
+    float fKeyValue;
+    float fMinExposure;
+    float fMaxExposure;
+    float fEnabled;
+} uExposure;
+
+shared uint sHistogram[256];
+
+void main() {
+    uint uIndex = gl_LocalInvocationIndex;
+    uint uCount = bHistogram.aBins[uIndex];
+    sHistogram[uIndex] = uCount * uIndex;
+    bHistogram.aBins[uIndex] = 0;
+    barrier();
+
+    for (uint uStride = 128; uStride > 0; uStride >>= 1) {
+        if (uIndex < uStride) {
+            sHistogram[uIndex] += sHistogram[uIndex + uStride];
+        }
+        barrier();
+    }
+
+    if (uIndex == 0) {
+        ivec2 i2Size = textureSize(sampler2D(uScene, uSampler), 0);
+        float fNumPixels = float(i2Size.x * i2Size.y);
+        float fNumLitPixels = max(fNumPixels - float(uCount), 1.0);
+        float fWeightedLogAverage = float(sHistogram[0]) / fNumLitPixels - 1.0;
+        float fAverageLuminance = exp2(fWeightedLogAverage / 254.0 * uExposure.fLogLuminanceRange + uExposure.fMinLogLuminance);
+
+        float fLastLuminance = bExposure.f4Exposure.x;
+        float fAdaptation = 1.0 - exp(-uExposure.fElapsedTime * uExposure.fAdaptationSpeed);
+        float fAdaptedLuminance = fLastLuminance + (fAverageLuminance - fLastLuminance) * fAdaptation;
+
+        float fExposure = clamp(uExposure.fKeyValue / max(fAdaptedLuminance, 0.0001), uExposure.fMinExposure, uExposure.fMaxExposure);
+        bExposure.f4Exposure = vec4(fAdaptedLuminance, mix(1.0, fExposure, uExposure.fEnabled), 0.0, 0.0);
+    }
+}
@@ -0,0 +1,150 @@
+// Generated from `exposure_average.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct HistogramLayout {
+    aBins: array<u32, 256>,
+}
+
+struct ExposureLayout {
+    f4Exposure: vec4<f32>,
+}
+
+struct ExposureUniformLayout {
+    fMinLogLuminance: f32,
+    fLogLuminanceRange: f32,
+    fElapsedTime: f32,
+    fAdaptationSpeed: f32,
+    fKeyValue: f32,
+    fMinExposure: f32,
+    fMaxExposure: f32,
+    fEnabled: f32,
+}
+
+@group(0) @binding(0) 
+var uScene: texture_2d<f32>;
+@group(0) @binding(1) 
+var uSampler: sampler;
+@group(0) @binding(2) 
+var<storage, read_write> bHistogram: HistogramLayout;
+@group(0) @binding(3) 
+var<storage, read_write> bExposure: ExposureLayout;
+@group(0) @binding(4) 
+var<uniform> uExposure: ExposureUniformLayout;
+var<workgroup> sHistogram: array<u32, 256>;
+var<private> gl_LocalInvocationIndex: u32;
+
+fn main_1() {
+    var uIndex: u32;
+    var uCount: u32;
+    var uStride: u32 = 128u;
+    var i2Size: vec2<i32>;
+    var fNumPixels: f32;
+    var fNumLitPixels: f32;
+    var fWeightedLogAverage: f32;
+    var fAverageLuminance: f32;
+    var fLastLuminance: f32;
+    var fAdaptation: f32;
+    var fAdaptedLuminance: f32;
+    var fExposure: f32;
+
+    let _e27 = gl_LocalInvocationIndex;
+    uIndex = _e27;
+    let _e29 = uIndex;
+    let _e32 = bHistogram.aBins[_e29];
+    uCount = _e32;
+    let _e34 = uIndex;
+    let _e36 = uCount;
+    let _e37 = uIndex;
+    sHistogram[_e34] = (_e36 * _e37);
+    let _e39 = uIndex;
+    bHistogram.aBins[_e39] = 0u;
+    storageBarrier();
+    workgroupBarrier();
+    loop {
+        let _e47 = uStride;
+        if !((_e47 > 0u)) {
+            break;
+        }
+        {
+            let _e56 = uIndex;
+            let _e57 = uStride;
+            if (_e56 < _e57) {
+                {
+                    let _e59 = uIndex;
+                    let _e61 = uIndex;
+                    let _e63 = sHistogram[_e61];
+                    let _e64 = uIndex;
+                    let _e65 = uStride;
+                    let _e68 = sHistogram[(_e64 + _e65)];
+                    sHistogram[_e59] = (_e63 + _e68);
+                }
+            }
+            storageBarrier();
+            workgroupBarrier();
+        }
+        continuing {
+            let _e52 = uStride;
+            uStride = (_e52 >> 1u);
+        }
+    }
+    let _e70 = uIndex;
+    if (_e70 == 0u) {
+        {
+            let _e76 = textureDimensions(uScene, 0i);
+            i2Size = vec2<i32>(_e76);
+            let _e79 = i2Size;
+            let _e81 = i2Size;
+            fNumPixels = f32((_e79.x * _e81.y));
+            let _e86 = fNumPixels;
+            let _e87 = uCount;
+            let _e91 = fNumPixels;
+            let _e92 = uCount;
+            fNumLitPixels = max((_e91 - f32(_e92)), 1f);
+            let _e100 = sHistogram[0];
+            let _e102 = fNumLitPixels;
+            fWeightedLogAverage = ((f32(_e100) / _e102) - 1f);
+            let _e107 = fWeightedLogAverage;
+            let _e110 = uExposure;
+            let _e113 = uExposure;
+            let _e116 = fWeightedLogAverage;
+            let _e119 = uExposure;
+            let _e122 = uExposure;
+            fAverageLuminance = exp2((((_e116 / 254f) * _e119.fLogLuminanceRange) + _e122.fMinLogLuminance));
+            let _e127 = bExposure;
+            fLastLuminance = _e127.f4Exposure.x;
+            let _e132 = uExposure;
+            let _e135 = uExposure;
+            let _e138 = uExposure;
+            let _e141 = uExposure;
+            fAdaptation = (1f - exp((-(_e138.fElapsedTime) * _e141.fAdaptationSpeed)));
+            let _e147 = fLastLuminance;
+            let _e148 = fAverageLuminance;
+            let _e149 = fLastLuminance;
+            let _e151 = fAdaptation;
+            fAdaptedLuminance = (_e147 + ((_e148 - _e149) * _e151));
+            let _e155 = uExposure;
+            let _e159 = fAdaptedLuminance;
+            let _e163 = uExposure;
+            let _e165 = uExposure;
+            let _e167 = uExposure;
+            let _e171 = fAdaptedLuminance;
+            let _e175 = uExposure;
+            let _e177 = uExposure;
+            fExposure = clamp((_e167.fKeyValue / max(_e171, 0.0001f)), _e175.fMinExposure, _e177.fMaxExposure);
+            let _e182 = fAdaptedLuminance;
+            let _e185 = uExposure;
+            let _e188 = fExposure;
+            let _e189 = uExposure;
+            bExposure.f4Exposure = vec4<f32>(_e182, mix(1f, _e188, _e189.fEnabled), 0f, 0f);
+            return;
+        }
+    } else {
+        return;
+    }
+}
+
+@compute @workgroup_size(256, 1, 1) 
+fn main(@builtin(local_invocation_index) param: u32) {
+    gl_LocalInvocationIndex = param;
+    main_1();
+    return;
+}
@@ -0,0 +1,49 @@
+// The GLSL front end used to build the SPIR-V shaders has no atomic functions,
+// so the luminance histogram is written in WGSL.
+
+struct ExposureUniformLayout {
+    min_log_luminance: f32,
+    log_luminance_range: f32,
+    elapsed_time: f32,
+    adaptation_speed: f32,
+    key_value: f32,
+    min_exposure: f32,
+    max_exposure: f32,
+    enabled: f32,
+}
+
+@group(0) @binding(0) var u_scene: texture_2d<f32>;
+@group(0) @binding(1) var u_sampler: sampler;
+@group(0) @binding(2) var<storage, read_write> b_histogram: array<atomic<u32>, 256>;
+@group(0) @binding(3) var<storage, read_write> b_exposure: vec4<f32>;
+@group(0) @binding(4) var<uniform> u_exposure: ExposureUniformLayout;
+
+var<workgroup> s_histogram: array<atomic<u32>, 256>;
+
+fn luminance_to_bin(color: vec3<f32>) -> u32 {
+    let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    if (luminance < 0.0001) {
+        return 0u;
+    }
+
+    let log_luminance = clamp((log2(luminance) - u_exposure.min_log_luminance) / u_exposure.log_luminance_range, 0.0, 1.0);
+    return u32(log_luminance * 254.0 + 1.0);
+}
+
+@compute @workgroup_size(16, 16, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32
+) {
+    atomicStore(&s_histogram[local_index], 0u);
+    workgroupBarrier();
+
+    let size = textureDimensions(u_scene, 0);
+    if (global_id.x < size.x && global_id.y < size.y) {
+        let color = textureLoad(u_scene, vec2<i32>(global_id.xy), 0).rgb;
+        atomicAdd(&s_histogram[luminance_to_bin(color)], 1u);
+    }
+    workgroupBarrier();
+
+    atomicAdd(&b_histogram[local_index], atomicLoad(&s_histogram[local_index]));
+}
@@ -0,0 +1,468 @@
+// Generated from `fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    view: mat4x4<f32>,
+    projection: mat4x4<f32>,
+    position: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> inLightSpaceFragPosition_1: vec4<f32>;
+var<private> inPositionW_1: vec3<f32>;
+var<private> inRim_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(3) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(3) @binding(1) 
+var uSampler: sampler_comparison;
+@group(3) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(4) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e43 = f2ProjCoords_1;
+    let _e44 = fCurrentDepth_1;
+    let _e48 = f2ProjCoords_1;
+    let _e49 = fCurrentDepth_1;
+    let _e52 = vec3<f32>(_e48.x, _e48.y, _e49);
+    let _e55 = textureSampleCompare(uShadowMap, uSampler, _e52.xy, _e52.z);
+    return _e55;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e41 = f4LightSpaceFragPosition_1;
+    if (_e41.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e46 = f4LightSpaceFragPosition_1;
+    let _e48 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e46.z / _e48.w);
+    let _e52 = f4LightSpaceFragPosition_1;
+    let _e54 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e52.xy / vec2(_e54.w));
+    let _e59 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e59 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e68 = uShadowFilter;
+    iKernel = i32((_e68.f4Params.x + 0.5f));
+    let _e75 = uShadowFilter;
+    let _e80 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e75.f4Params.y) / vec2<f32>(vec2<i32>(_e80)));
+    let _e86 = iKernel;
+    if (_e86 == 3i) {
+        {
+            loop {
+                let _e93 = i;
+                if !((_e93 < 16i)) {
+                    break;
+                }
+                {
+                    let _e100 = fSum;
+                    let _e101 = f2ProjCoords_2;
+                    let _e102 = i;
+                    let _e106 = local[_e102];
+                    let _e107 = f2Radius;
+                    let _e111 = f2ProjCoords_2;
+                    let _e112 = i;
+                    let _e116 = local_1[_e112];
+                    let _e117 = f2Radius;
+                    let _e120 = fCurrentDepth_2;
+                    let _e121 = sampleShadow((_e111 + (_e116 * _e117)), _e120);
+                    fSum = (_e100 + _e121);
+                }
+                continuing {
+                    let _e97 = i;
+                    i = (_e97 + 1i);
+                }
+            }
+            let _e123 = fSum;
+            return (_e123 / 16f);
+        }
+    }
+    let _e126 = iKernel;
+    let _e129 = iKernel;
+    if ((_e126 == 1i) || (_e129 == 2i)) {
+        {
+            let _e135 = iKernel;
+            y = -(_e135);
+            loop {
+                let _e138 = y;
+                let _e139 = iKernel;
+                if !((_e138 <= _e139)) {
+                    break;
+                }
+                {
+                    let _e145 = iKernel;
+                    x = -(_e145);
+                    loop {
+                        let _e148 = x;
+                        let _e149 = iKernel;
+                        if !((_e148 <= _e149)) {
+                            break;
+                        }
+                        {
+                            let _e155 = fSum_1;
+                            let _e156 = f2ProjCoords_2;
+                            let _e157 = x;
+                            let _e158 = y;
+                            let _e162 = iKernel;
+                            let _e166 = f2Radius;
+                            let _e170 = f2ProjCoords_2;
+                            let _e171 = x;
+                            let _e172 = y;
+                            let _e176 = iKernel;
+                            let _e180 = f2Radius;
+                            let _e183 = fCurrentDepth_2;
+                            let _e184 = sampleShadow((_e170 + ((vec2<f32>(f32(_e171), f32(_e172)) / vec2(f32(_e176))) * _e180)), _e183);
+                            fSum_1 = (_e155 + _e184);
+                        }
+                        continuing {
+                            let _e152 = x;
+                            x = (_e152 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e142 = y;
+                    y = (_e142 + 1i);
+                }
+            }
+            let _e187 = iKernel;
+            fWidth = f32(((2i * _e187) + 1i));
+            let _e193 = fSum_1;
+            let _e194 = fWidth;
+            let _e195 = fWidth;
+            return (_e193 / (_e194 * _e195));
+        }
+    }
+    let _e200 = f2ProjCoords_2;
+    let _e201 = fCurrentDepth_2;
+    let _e202 = sampleShadow(_e200, _e201);
+    return _e202;
+}
+
+fn calculateRim(f3NormalW: vec3<f32>, f3PositionW: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_1: vec3<f32>;
+    var f3PositionW_1: vec3<f32>;
+    var f3View: vec3<f32>;
+    var fFresnel: f32;
+
+    f3NormalW_1 = f3NormalW;
+    f3PositionW_1 = f3PositionW;
+    let _e43 = inRim_1;
+    let _e45 = inRim_1;
+    let _e47 = inRim_1;
+    let _e49 = inRim_1;
+    if (dot(_e47.xyz, _e49.xyz) <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e56 = uCamera;
+    let _e59 = f3PositionW_1;
+    let _e61 = uCamera;
+    let _e64 = f3PositionW_1;
+    f3View = normalize((_e61.position.xyz - _e64));
+    let _e70 = f3NormalW_1;
+    let _e74 = f3NormalW_1;
+    let _e76 = f3View;
+    let _e80 = f3NormalW_1;
+    let _e84 = f3NormalW_1;
+    let _e86 = f3View;
+    fFresnel = (1f - max(dot(normalize(_e84), _e86), 0f));
+    let _e92 = inRim_1;
+    let _e95 = inRim_1;
+    let _e98 = inRim_1;
+    let _e102 = fFresnel;
+    let _e103 = inRim_1;
+    let _e106 = inRim_1;
+    return (_e92.xyz * pow(_e102, max(_e106.w, 0.0001f)));
+}
+
+fn calculateFog(f3PositionW_2: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_3: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e41 = uFog;
+    fDensity = _e41.f4Params.x;
+    let _e45 = uFog;
+    let _e49 = uFog;
+    fFalloff = max(_e49.f4Params.y, 0.0001f);
+    let _e55 = uFog;
+    fBaseHeight = _e55.f4Params.z;
+    let _e59 = uFog;
+    fMaxOpacity = _e59.f4Params.w;
+    let _e63 = f3PositionW_3;
+    let _e64 = uCamera;
+    f3Ray = (_e63 - _e64.position.xyz);
+    let _e70 = f3Ray;
+    fDistance = length(_e70);
+    let _e73 = fDensity;
+    let _e76 = fDistance;
+    if ((_e73 <= 0f) || (_e76 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e83 = uFog;
+    if (_e83.f4Range.z < 0.5f) {
+        {
+            let _e88 = fDistance;
+            let _e89 = uFog;
+            let _e93 = uFog;
+            let _e96 = uFog;
+            let _e101 = uFog;
+            let _e104 = uFog;
+            let _e113 = fDistance;
+            let _e114 = uFog;
+            let _e118 = uFog;
+            let _e121 = uFog;
+            let _e126 = uFog;
+            let _e129 = uFog;
+            fOpacity = clamp(((_e113 - _e114.f4Range.x) / max((_e126.f4Range.y - _e129.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e139 = uFog;
+        if (_e139.f4Range.z < 1.5f) {
+            {
+                let _e145 = fDensity;
+                let _e147 = fDistance;
+                let _e148 = uFog;
+                let _e153 = fDistance;
+                let _e154 = uFog;
+                let _e161 = fDensity;
+                let _e163 = fDistance;
+                let _e164 = uFog;
+                let _e169 = fDistance;
+                let _e170 = uFog;
+                fOpacity = (1f - exp((-(_e161) * max((_e169 - _e170.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e179 = fDensity;
+                let _e180 = fFalloff;
+                let _e182 = uCamera;
+                let _e185 = fBaseHeight;
+                let _e188 = fFalloff;
+                let _e190 = uCamera;
+                let _e193 = fBaseHeight;
+                let _e198 = fDistance;
+                fFogAmount = ((_e179 * exp((-(_e188) * (_e190.position.y - _e193)))) * _e198);
+                let _e201 = fFalloff;
+                let _e202 = f3Ray;
+                fRayHeight = (_e201 * _e202.y);
+                let _e207 = fRayHeight;
+                if (abs(_e207) > 0.0001f) {
+                    {
+                        let _e211 = fFogAmount;
+                        let _e213 = fRayHeight;
+                        let _e215 = fRayHeight;
+                        let _e219 = fRayHeight;
+                        fFogAmount = (_e211 * ((1f - exp(-(_e215))) / _e219));
+                    }
+                }
+                let _e223 = fFogAmount;
+                let _e225 = fFogAmount;
+                fOpacity = (1f - exp(-(_e225)));
+            }
+        }
+    }
+    let _e231 = fOpacity;
+    let _e232 = fMaxOpacity;
+    fOpacity = min(_e231, _e232);
+    let _e234 = f3Ray;
+    let _e235 = fDistance;
+    let _e238 = uGlobalLight;
+    let _e241 = uGlobalLight;
+    let _e245 = f3Ray;
+    let _e246 = fDistance;
+    let _e249 = uGlobalLight;
+    let _e252 = uGlobalLight;
+    let _e258 = f3Ray;
+    let _e259 = fDistance;
+    let _e262 = uGlobalLight;
+    let _e265 = uGlobalLight;
+    let _e269 = f3Ray;
+    let _e270 = fDistance;
+    let _e273 = uGlobalLight;
+    let _e276 = uGlobalLight;
+    fSunAmount = max(dot((_e269 / vec3(_e270)), normalize(_e276.f4Direction.xyz)), 0f);
+    let _e284 = uFog;
+    let _e287 = uGlobalLight;
+    f3SunColor = (_e284.f4SunColor.xyz * _e287.f4LightColor.xyz);
+    let _e292 = uFog;
+    let _e297 = uFog;
+    let _e300 = fSunAmount;
+    let _e301 = uFog;
+    let _e305 = uFog;
+    let _e308 = f3SunColor;
+    let _e310 = uFog;
+    let _e313 = fSunAmount;
+    let _e314 = uFog;
+    f3FogColor = mix(_e305.f4Color.xyz, _e308, vec3(pow(_e313, _e314.f4SunColor.w)));
+    let _e321 = f3FogColor;
+    let _e322 = fOpacity;
+    return vec4<f32>(_e321.x, _e321.y, _e321.z, _e322);
+}
+
+fn calculateSpotAttenuation(f3PositionW_4: vec3<f32>) -> f32 {
+    var f3PositionW_5: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_5 = f3PositionW_4;
+    let _e41 = uGlobalLight;
+    if (_e41.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e47 = f3PositionW_5;
+    let _e48 = uGlobalLight;
+    f3ToFragment = (_e47 - _e48.f4Position.xyz);
+    let _e54 = f3ToFragment;
+    fDistance_1 = length(_e54);
+    let _e57 = f3ToFragment;
+    let _e60 = fDistance_1;
+    let _e65 = uGlobalLight;
+    let _e68 = uGlobalLight;
+    let _e73 = f3ToFragment;
+    let _e76 = fDistance_1;
+    let _e81 = uGlobalLight;
+    let _e84 = uGlobalLight;
+    fCosAngle = dot((_e73 / vec3(max(_e76, 0.0001f))), -(normalize(_e84.f4Direction.xyz)));
+    let _e91 = uGlobalLight;
+    let _e94 = uGlobalLight;
+    let _e98 = uGlobalLight;
+    let _e101 = uGlobalLight;
+    let _e104 = fCosAngle;
+    fCone = smoothstep(_e98.f4Cone.y, _e101.f4Cone.x, _e104);
+    let _e108 = fDistance_1;
+    let _e109 = uGlobalLight;
+    let _e113 = uGlobalLight;
+    let _e123 = fDistance_1;
+    let _e124 = uGlobalLight;
+    let _e128 = uGlobalLight;
+    fRange = clamp((1f - (_e123 / max(_e128.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e139 = fCone;
+    let _e140 = fRange;
+    let _e142 = fRange;
+    return ((_e139 * _e140) * _e142);
+}
+
+fn main_1() {
+    var fShadow: f32;
+    var f4Color: vec4<f32>;
+    var f4Fog: vec4<f32>;
+
+    let _e40 = inLightSpaceFragPosition_1;
+    let _e41 = calculateShadow(_e40);
+    let _e43 = inPositionW_1;
+    let _e44 = calculateSpotAttenuation(_e43);
+    fShadow = (_e41 * _e44);
+    let _e47 = inColor_1;
+    let _e50 = inColor_1;
+    let _e51 = fShadow;
+    f4Color = ((_e47 * 0.2f) + (_e50 * _e51));
+    let _e55 = f4Color;
+    let _e57 = f4Color;
+    let _e61 = inNormalW_1;
+    let _e62 = inPositionW_1;
+    let _e63 = calculateRim(_e61, _e62);
+    let _e64 = (_e57.xyz + _e63);
+    f4Color.x = _e64.x;
+    f4Color.y = _e64.y;
+    f4Color.z = _e64.z;
+    let _e72 = inPositionW_1;
+    let _e73 = calculateFog(_e72);
+    f4Fog = _e73;
+    let _e75 = f4Color;
+    let _e77 = f4Fog;
+    let _e79 = f4Fog;
+    let _e81 = f4Color;
+    let _e83 = f4Fog;
+    let _e85 = f4Fog;
+    let _e88 = mix(_e81.xyz, _e83.xyz, vec3(_e85.w));
+    let _e89 = inColor_1;
+    outFragColor = vec4<f32>(_e88.x, _e88.y, _e88.z, _e89.w);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>, @location(2) inLightSpaceFragPosition: vec4<f32>, @location(3) inPositionW: vec3<f32>, @location(4) inRim: vec4<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    inLightSpaceFragPosition_1 = inLightSpaceFragPosition;
+    inPositionW_1 = inPositionW;
+    inRim_1 = inRim;
+    main_1();
+    let _e59 = outFragColor;
+    return FragmentOutput(_e59);
+}
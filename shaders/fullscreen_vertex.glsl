@@ -0,0 +1,6 @@
+#version 450 core
+
+void main() {
+    vec2 f2Position = vec2(float((gl_VertexIndex << 1) & 2), float(gl_VertexIndex & 2));
+    gl_Position = vec4(f2Position * 2.0 - 1.0, 0.0, 1.0);
+}
@@ -0,0 +1,28 @@
+// Generated from `fullscreen_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct VertexOutput {
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> gl_VertexIndex: u32;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var f2Position: vec2<f32>;
+
+    let _e1 = gl_VertexIndex;
+    let _e9 = gl_VertexIndex;
+    f2Position = vec2<f32>(f32(((_e1 << 1u) & 2u)), f32((_e9 & 2u)));
+    let _e17 = f2Position;
+    let _e22 = ((_e17 * 2f) - vec2(1f));
+    gl_Position = vec4<f32>(_e22.x, _e22.y, 0f, 1f);
+    return;
+}
+
+@vertex 
+fn main(@builtin(vertex_index) param: u32) -> VertexOutput {
+    gl_VertexIndex = param;
+    main_1();
+    let _e3 = gl_Position;
+    return VertexOutput(_e3);
+}
@@ -0,0 +1,18 @@
+#version 450 core
+
+layout (location = 0) in vec4 inColor;
+layout (location = 1) in vec3 inNormalW;
+
+layout (location = 0) out vec4 outAlbedo;
+layout (location = 1) out vec4 outNormal;
+layout (location = 2) out vec4 outMaterial;
+
+layout (set = 3, binding = 0) uniform PbrUniformLayout {
+    vec4 f4Params;
+} uPbr;
+
+void main() {
+    outAlbedo = vec4(inColor.rgb, 1.0);
+    outNormal = vec4(normalize(inNormalW), 0.0);
+    outMaterial = vec4(clamp(uPbr.f4Params.xyz, 0.0, 1.0), 1.0);
+}
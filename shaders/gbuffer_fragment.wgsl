@@ -0,0 +1,44 @@
+// Generated from `gbuffer_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct PbrUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outAlbedo: vec4<f32>,
+    @location(1) outNormal: vec4<f32>,
+    @location(2) outMaterial: vec4<f32>,
+}
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> outAlbedo: vec4<f32>;
+var<private> outNormal: vec4<f32>;
+var<private> outMaterial: vec4<f32>;
+@group(3) @binding(0) 
+var<uniform> uPbr: PbrUniformLayout;
+
+fn main_1() {
+    let _e8 = inColor_1;
+    let _e9 = _e8.xyz;
+    outAlbedo = vec4<f32>(_e9.x, _e9.y, _e9.z, 1f);
+    let _e16 = inNormalW_1;
+    let _e17 = normalize(_e16);
+    outNormal = vec4<f32>(_e17.x, _e17.y, _e17.z, 0f);
+    let _e23 = uPbr;
+    let _e28 = uPbr;
+    let _e35 = clamp(_e28.f4Params.xyz, vec3(0f), vec3(1f));
+    outMaterial = vec4<f32>(_e35.x, _e35.y, _e35.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    main_1();
+    let _e18 = outAlbedo;
+    let _e20 = outNormal;
+    let _e22 = outMaterial;
+    return FragmentOutput(_e18, _e20, _e22);
+}
@@ -0,0 +1,53 @@
+// The GLSL front end used to build the SPIR-V shaders has no atomic functions,
+// so the frustum culling of the instances is written in WGSL.
+
+struct GpuCullUniformLayout {
+    world: mat4x4<f32>,
+    planes: array<vec4<f32>, 6>,
+    sphere: vec4<f32>,
+    params: vec4<f32>,
+}
+
+struct InstanceLayout {
+    world: mat4x4<f32>,
+    color: vec4<f32>,
+}
+
+// Matches the layout of `wgpu::util::DrawIndexedIndirectArgs`.
+struct DrawIndexedIndirectArgs {
+    index_count: u32,
+    instance_count: atomic<u32>,
+    first_index: u32,
+    base_vertex: i32,
+    first_instance: u32,
+}
+
+@group(0) @binding(0) var<uniform> u_cull: GpuCullUniformLayout;
+@group(0) @binding(1) var<storage, read> b_instances: array<InstanceLayout>;
+@group(0) @binding(2) var<storage, read_write> b_visible: array<InstanceLayout>;
+@group(0) @binding(3) var<storage, read_write> b_indirect: DrawIndexedIndirectArgs;
+
+@compute @workgroup_size(64, 1, 1)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if (index >= u32(u_cull.params.x)) {
+        return;
+    }
+
+    // The bounding sphere of the mesh is moved into world space, and its radius grows with the largest axis scale.
+    let instance = b_instances[index];
+    let world = u_cull.world * instance.world;
+    let center = (world * vec4<f32>(u_cull.sphere.xyz, 1.0)).xyz;
+    let scale = max(length(world[0].xyz), max(length(world[1].xyz), length(world[2].xyz)));
+    let radius = u_cull.sphere.w * scale;
+    for (var i = 0u; i < 6u; i++) {
+        let plane = u_cull.planes[i];
+        if (dot(plane.xyz, center) + plane.w < -radius) {
+            return;
+        }
+    }
+
+    // The visible instances are compacted to the front of the buffer in the order they pass.
+    let slot = atomicAdd(&b_indirect.instance_count, 1u);
+    b_visible[slot] = instance;
+}
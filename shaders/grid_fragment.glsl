@@ -0,0 +1,44 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPositionW;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform GridUniformLayout {
+    vec4 f4MinorColor;
+    vec4 f4MajorColor;
+    float fCellSize;
+    float fMajorDivisions;
+    float fFadeDistance;
+    float fLineWidth;
+} uGrid;
+
+float calculateLine(vec2 f2Coord, float fCellSize) {
+    vec2 f2Cell = f2Coord / fCellSize;
+    vec2 f2Derivative = fwidth(f2Cell);
+    vec2 f2Distance = abs(fract(f2Cell - 0.5) - 0.5) / f2Derivative;
+    float fLine = min(f2Distance.x, f2Distance.y);
+    return 1.0 - min(fLine / uGrid.fLineWidth, 1.0);
+}
+
+void main() {
+    float fMinor = calculateLine(inPositionW.xz, uGrid.fCellSize);
+    float fMajor = calculateLine(inPositionW.xz, uGrid.fCellSize * uGrid.fMajorDivisions);
+
+    float fDistance = length(inPositionW.xz - uCamera.f4Position.xz);
+    float fFade = 1.0 - smoothstep(0.25 * uGrid.fFadeDistance, uGrid.fFadeDistance, fDistance);
+
+    vec4 f4Color = mix(uGrid.f4MinorColor, uGrid.f4MajorColor, fMajor);
+    float fAlpha = max(fMinor * uGrid.f4MinorColor.a, fMajor * uGrid.f4MajorColor.a) * fFade;
+    if (fAlpha <= 0.0) {
+        discard;
+    }
+
+    outFragColor = vec4(f4Color.rgb, fAlpha);
+}
@@ -0,0 +1,131 @@
+// Generated from `grid_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct GridUniformLayout {
+    f4MinorColor: vec4<f32>,
+    f4MajorColor: vec4<f32>,
+    fCellSize: f32,
+    fMajorDivisions: f32,
+    fFadeDistance: f32,
+    fLineWidth: f32,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> inPositionW_1: vec3<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uGrid: GridUniformLayout;
+
+fn calculateLine(f2Coord: vec2<f32>, fCellSize: f32) -> f32 {
+    var f2Coord_1: vec2<f32>;
+    var fCellSize_1: f32;
+    var f2Cell: vec2<f32>;
+    var f2Derivative: vec2<f32>;
+    var f2Distance: vec2<f32>;
+    var fLine: f32;
+
+    f2Coord_1 = f2Coord;
+    fCellSize_1 = fCellSize;
+    let _e26 = f2Coord_1;
+    let _e27 = fCellSize_1;
+    f2Cell = (_e26 / vec2(_e27));
+    let _e32 = f2Cell;
+    let _e33 = fwidth(_e32);
+    f2Derivative = _e33;
+    let _e35 = f2Cell;
+    let _e39 = f2Cell;
+    let _e47 = f2Cell;
+    let _e51 = f2Cell;
+    let _e60 = f2Derivative;
+    f2Distance = (abs((fract((_e51 - vec2(0.5f))) - vec2(0.5f))) / _e60);
+    let _e63 = f2Distance;
+    let _e65 = f2Distance;
+    let _e67 = f2Distance;
+    let _e69 = f2Distance;
+    fLine = min(_e67.x, _e69.y);
+    let _e74 = fLine;
+    let _e75 = uGrid;
+    let _e79 = fLine;
+    let _e80 = uGrid;
+    return (1f - min((_e79 / _e80.fLineWidth), 1f));
+}
+
+fn main_1() {
+    var fMinor: f32;
+    var fMajor: f32;
+    var fDistance: f32;
+    var fFade: f32;
+    var f4Color: vec4<f32>;
+    var fAlpha: f32;
+
+    let _e22 = inPositionW_1;
+    let _e24 = uGrid;
+    let _e26 = inPositionW_1;
+    let _e28 = uGrid;
+    let _e30 = calculateLine(_e26.xz, _e28.fCellSize);
+    fMinor = _e30;
+    let _e32 = inPositionW_1;
+    let _e34 = uGrid;
+    let _e36 = uGrid;
+    let _e39 = inPositionW_1;
+    let _e41 = uGrid;
+    let _e43 = uGrid;
+    let _e46 = calculateLine(_e39.xz, (_e41.fCellSize * _e43.fMajorDivisions));
+    fMajor = _e46;
+    let _e48 = inPositionW_1;
+    let _e50 = uCamera;
+    let _e54 = inPositionW_1;
+    let _e56 = uCamera;
+    fDistance = length((_e54.xz - _e56.f4Position.xz));
+    let _e64 = uGrid;
+    let _e67 = uGrid;
+    let _e71 = uGrid;
+    let _e74 = uGrid;
+    let _e76 = fDistance;
+    fFade = (1f - smoothstep((0.25f * _e71.fFadeDistance), _e74.fFadeDistance, _e76));
+    let _e80 = uGrid;
+    let _e82 = uGrid;
+    let _e85 = uGrid;
+    let _e87 = uGrid;
+    let _e89 = fMajor;
+    f4Color = mix(_e85.f4MinorColor, _e87.f4MajorColor, vec4(_e89));
+    let _e93 = fMinor;
+    let _e94 = uGrid;
+    let _e98 = fMajor;
+    let _e99 = uGrid;
+    let _e103 = fMinor;
+    let _e104 = uGrid;
+    let _e108 = fMajor;
+    let _e109 = uGrid;
+    let _e114 = fFade;
+    fAlpha = (max((_e103 * _e104.f4MinorColor.w), (_e108 * _e109.f4MajorColor.w)) * _e114);
+    let _e117 = fAlpha;
+    if (_e117 <= 0f) {
+        {
+            discard;
+        }
+    }
+    let _e120 = f4Color;
+    let _e121 = _e120.xyz;
+    let _e122 = fAlpha;
+    outFragColor = vec4<f32>(_e121.x, _e121.y, _e121.z, _e122);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inPositionW: vec3<f32>) -> FragmentOutput {
+    inPositionW_1 = inPositionW;
+    main_1();
+    let _e27 = outFragColor;
+    return FragmentOutput(_e27);
+}
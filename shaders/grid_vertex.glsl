@@ -0,0 +1,30 @@
+#version 450 core
+
+layout (location = 0) out vec3 outPositionW;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform GridUniformLayout {
+    vec4 f4MinorColor;
+    vec4 f4MajorColor;
+    float fCellSize;
+    float fMajorDivisions;
+    float fFadeDistance;
+    float fLineWidth;
+} uGrid;
+
+const vec2 QUAD[6] = vec2[6](
+    vec2(-1.0, -1.0), vec2(-1.0,  1.0), vec2( 1.0,  1.0), 
+    vec2( 1.0,  1.0), vec2( 1.0, -1.0), vec2(-1.0, -1.0)
+);
+
+void main() {
+    // The quad follows the camera, so the grid never ends before it fades out.
+    vec2 f2Offset = QUAD[gl_VertexIndex] * uGrid.fFadeDistance;
+    outPositionW = vec3(uCamera.f4Position.x + f2Offset.x, 0.0, uCamera.f4Position.z + f2Offset.y);
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * vec4(outPositionW, 1.0);
+}
@@ -0,0 +1,60 @@
+// Generated from `grid_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct GridUniformLayout {
+    f4MinorColor: vec4<f32>,
+    f4MajorColor: vec4<f32>,
+    fCellSize: f32,
+    fMajorDivisions: f32,
+    fFadeDistance: f32,
+    fLineWidth: f32,
+}
+
+struct VertexOutput {
+    @location(0) outPositionW: vec3<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+const QUAD: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(-1f, -1f), vec2<f32>(-1f, 1f), vec2<f32>(1f, 1f), vec2<f32>(1f, 1f), vec2<f32>(1f, -1f), vec2<f32>(-1f, -1f));
+
+var<private> outPositionW: vec3<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uGrid: GridUniformLayout;
+var<private> gl_VertexIndex: u32;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var local: array<vec2<f32>, 6> = QUAD;
+    var f2Offset: vec2<f32>;
+
+    let _e23 = gl_VertexIndex;
+    let _e27 = local[_e23];
+    let _e28 = uGrid;
+    f2Offset = (_e27 * _e28.fFadeDistance);
+    let _e32 = uCamera;
+    let _e35 = f2Offset;
+    let _e39 = uCamera;
+    let _e42 = f2Offset;
+    outPositionW = vec3<f32>((_e32.f4Position.x + _e35.x), 0f, (_e39.f4Position.z + _e42.y));
+    let _e47 = uCamera;
+    let _e49 = uCamera;
+    let _e52 = outPositionW;
+    gl_Position = ((_e47.mtxProjection * _e49.mtxView) * vec4<f32>(_e52.x, _e52.y, _e52.z, 1f));
+    return;
+}
+
+@vertex 
+fn main(@builtin(vertex_index) param: u32) -> VertexOutput {
+    gl_VertexIndex = param;
+    main_1();
+    let _e27 = outPositionW;
+    let _e29 = gl_Position;
+    return VertexOutput(_e27, _e29);
+}
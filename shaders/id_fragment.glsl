@@ -0,0 +1,9 @@
+#version 450 core
+
+layout (location = 0) flat in uint inObjectId;
+
+layout (location = 0) out uint outObjectId;
+
+void main() {
+    outObjectId = inObjectId;
+}
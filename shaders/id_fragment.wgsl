@@ -0,0 +1,22 @@
+// Generated from `id_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) @interpolate(flat) outObjectId: u32,
+}
+
+var<private> inObjectId_1: u32;
+var<private> outObjectId: u32;
+
+fn main_1() {
+    let _e2 = inObjectId_1;
+    outObjectId = _e2;
+    return;
+}
+
+@fragment 
+fn main(@location(0) @interpolate(flat) inObjectId: u32) -> FragmentOutput {
+    inObjectId_1 = inObjectId;
+    main_1();
+    let _e7 = outObjectId;
+    return FragmentOutput(_e7);
+}
@@ -0,0 +1,23 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+layout (location = 1) in vec3 inNormal;
+
+layout (location = 0) flat out uint outObjectId;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform ObjectUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+} uEntity;
+
+void main() {
+    // The object id is passed as the first instance of the draw call.
+    outObjectId = uint(gl_InstanceIndex);
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * uEntity.mtxWorld * vec4(inPosition, 1.0);
+}
@@ -0,0 +1,49 @@
+// Generated from `id_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) @interpolate(flat) outObjectId: u32,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> outObjectId: u32;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+var<private> gl_InstanceIndex: u32;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    let _e16 = gl_InstanceIndex;
+    outObjectId = u32(_e16);
+    let _e19 = uCamera;
+    let _e21 = uCamera;
+    let _e24 = uEntity;
+    let _e27 = inPosition_1;
+    gl_Position = (((_e19.mtxProjection * _e21.mtxView) * _e24.mtxWorld) * vec4<f32>(_e27.x, _e27.y, _e27.z, 1f));
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>, @builtin(instance_index) param: u32) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    gl_InstanceIndex = param;
+    main_1();
+    let _e25 = outObjectId;
+    let _e27 = gl_Position;
+    return VertexOutput(_e25, _e27);
+}
@@ -0,0 +1,45 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+layout (location = 1) in vec3 inNormal;
+layout (location = 2) in vec4 inInstanceWorld0;
+layout (location = 3) in vec4 inInstanceWorld1;
+layout (location = 4) in vec4 inInstanceWorld2;
+layout (location = 5) in vec4 inInstanceWorld3;
+layout (location = 6) in vec4 inInstanceColor;
+
+layout (location = 0) out vec4 outColor;
+layout (location = 1) out vec3 outNormalW;
+layout (location = 2) out vec4 outLightSpaceFragPosition;
+layout (location = 3) out vec3 outPositionW;
+layout (location = 4) out vec4 outRim;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform ObjectUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+    vec4 f4Rim;
+} uEntity;
+
+layout (set = 2, binding = 0) uniform GlobalLightUniformLayout {
+    mat4 mtxProjView;
+    vec4 f4Direction;
+    vec4 f4LightColor;
+} uGlobalLight;
+
+void main() {
+    mat4 mtxInstanceWorld = mat4(inInstanceWorld0, inInstanceWorld1, inInstanceWorld2, inInstanceWorld3);
+    mat4 mtxWorld = uEntity.mtxWorld * mtxInstanceWorld;
+    vec4 f4PositionW = mtxWorld * vec4(inPosition, 1.0);
+    outColor = uEntity.f4Color * inInstanceColor;
+    outNormalW = mat3(mtxWorld) * inNormal;
+    outLightSpaceFragPosition = uGlobalLight.mtxProjView * f4PositionW;
+    outPositionW = f4PositionW.xyz;
+    outRim = uEntity.f4Rim;
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
+}
@@ -0,0 +1,103 @@
+// Generated from `instanced_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+    f4Rim: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outColor: vec4<f32>,
+    @location(1) outNormalW: vec3<f32>,
+    @location(2) outLightSpaceFragPosition: vec4<f32>,
+    @location(3) outPositionW: vec3<f32>,
+    @location(4) outRim: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> inInstanceWorld0_1: vec4<f32>;
+var<private> inInstanceWorld1_1: vec4<f32>;
+var<private> inInstanceWorld2_1: vec4<f32>;
+var<private> inInstanceWorld3_1: vec4<f32>;
+var<private> inInstanceColor_1: vec4<f32>;
+var<private> outColor: vec4<f32>;
+var<private> outNormalW: vec3<f32>;
+var<private> outLightSpaceFragPosition: vec4<f32>;
+var<private> outPositionW: vec3<f32>;
+var<private> outRim: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var mtxInstanceWorld: mat4x4<f32>;
+    var mtxWorld: mat4x4<f32>;
+    var f4PositionW: vec4<f32>;
+
+    let _e33 = inInstanceWorld0_1;
+    let _e34 = inInstanceWorld1_1;
+    let _e35 = inInstanceWorld2_1;
+    let _e36 = inInstanceWorld3_1;
+    mtxInstanceWorld = mat4x4<f32>(vec4<f32>(_e33.x, _e33.y, _e33.z, _e33.w), vec4<f32>(_e34.x, _e34.y, _e34.z, _e34.w), vec4<f32>(_e35.x, _e35.y, _e35.z, _e35.w), vec4<f32>(_e36.x, _e36.y, _e36.z, _e36.w));
+    let _e59 = uEntity;
+    let _e61 = mtxInstanceWorld;
+    mtxWorld = (_e59.mtxWorld * _e61);
+    let _e64 = mtxWorld;
+    let _e65 = inPosition_1;
+    f4PositionW = (_e64 * vec4<f32>(_e65.x, _e65.y, _e65.z, 1f));
+    let _e73 = uEntity;
+    let _e75 = inInstanceColor_1;
+    outColor = (_e73.f4Color * _e75);
+    let _e77 = mtxWorld;
+    let _e87 = inNormal_1;
+    outNormalW = (mat3x3<f32>(_e77[0].xyz, _e77[1].xyz, _e77[2].xyz) * _e87);
+    let _e89 = uGlobalLight;
+    let _e91 = f4PositionW;
+    outLightSpaceFragPosition = (_e89.mtxProjView * _e91);
+    let _e93 = f4PositionW;
+    outPositionW = _e93.xyz;
+    let _e95 = uEntity;
+    outRim = _e95.f4Rim;
+    let _e98 = uCamera;
+    let _e100 = uCamera;
+    let _e103 = f4PositionW;
+    gl_Position = ((_e98.mtxProjection * _e100.mtxView) * _e103);
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>, @location(2) inInstanceWorld0_: vec4<f32>, @location(3) inInstanceWorld1_: vec4<f32>, @location(4) inInstanceWorld2_: vec4<f32>, @location(5) inInstanceWorld3_: vec4<f32>, @location(6) inInstanceColor: vec4<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    inInstanceWorld0_1 = inInstanceWorld0_;
+    inInstanceWorld1_1 = inInstanceWorld1_;
+    inInstanceWorld2_1 = inInstanceWorld2_;
+    inInstanceWorld3_1 = inInstanceWorld3_;
+    inInstanceColor_1 = inInstanceColor;
+    main_1();
+    let _e60 = outColor;
+    let _e62 = outNormalW;
+    let _e64 = outLightSpaceFragPosition;
+    let _e66 = outPositionW;
+    let _e68 = outRim;
+    let _e70 = gl_Position;
+    return VertexOutput(_e60, _e62, _e64, _e66, _e68, _e70);
+}
@@ -0,0 +1,17 @@
+#version 450 core
+
+layout (location = 0) in vec4 inColor;
+layout (location = 1) in vec3 inNormalV;
+
+layout (location = 0) out vec4 outColor;
+
+layout (set = 2, binding = 0) uniform texture2D tMatcap;
+layout (set = 2, binding = 1) uniform sampler sMatcap;
+
+void main() {
+    // The x and y of the view-space normal map the hemisphere facing the camera onto the matcap disc.
+    vec3 f3Normal = normalize(inNormalV);
+    vec2 f2TexCoord = vec2(0.5 + 0.49 * f3Normal.x, 0.5 - 0.49 * f3Normal.y);
+    vec3 f3Matcap = texture(sampler2D(tMatcap, sMatcap), f2TexCoord).rgb;
+    outColor = vec4(f3Matcap * inColor.rgb, 1.0);
+}
@@ -0,0 +1,42 @@
+// Generated from `matcap_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outColor: vec4<f32>,
+}
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalV_1: vec3<f32>;
+var<private> outColor: vec4<f32>;
+@group(2) @binding(0) 
+var tMatcap: texture_2d<f32>;
+@group(2) @binding(1) 
+var sMatcap: sampler;
+
+fn main_1() {
+    var f3Normal: vec3<f32>;
+    var f2TexCoord: vec2<f32>;
+    var f3Matcap: vec3<f32>;
+
+    let _e6 = inNormalV_1;
+    f3Normal = normalize(_e6);
+    let _e11 = f3Normal;
+    let _e17 = f3Normal;
+    f2TexCoord = vec2<f32>((0.5f + (0.49f * _e11.x)), (0.5f - (0.49f * _e17.y)));
+    let _e24 = f2TexCoord;
+    let _e25 = textureSample(tMatcap, sMatcap, _e24);
+    f3Matcap = _e25.xyz;
+    let _e28 = f3Matcap;
+    let _e29 = inColor_1;
+    let _e31 = (_e28 * _e29.xyz);
+    outColor = vec4<f32>(_e31.x, _e31.y, _e31.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalV: vec3<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalV_1 = inNormalV;
+    main_1();
+    let _e15 = outColor;
+    return FragmentOutput(_e15);
+}
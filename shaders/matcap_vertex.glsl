@@ -0,0 +1,25 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+layout (location = 1) in vec3 inNormal;
+
+layout (location = 0) out vec4 outColor;
+layout (location = 1) out vec3 outNormalV;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform ObjectUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+} uEntity;
+
+void main() {
+    vec4 f4PositionW = uEntity.mtxWorld * vec4(inPosition, 1.0);
+    outColor = uEntity.f4Color;
+    outNormalV = mat3(uCamera.mtxView) * mat3(uEntity.mtxWorld) * inNormal;
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
+}
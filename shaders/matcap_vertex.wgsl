@@ -0,0 +1,58 @@
+// Generated from `matcap_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outColor: vec4<f32>,
+    @location(1) outNormalV: vec3<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> outColor: vec4<f32>;
+var<private> outNormalV: vec3<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var f4PositionW: vec4<f32>;
+
+    let _e16 = uEntity;
+    let _e18 = inPosition_1;
+    f4PositionW = (_e16.mtxWorld * vec4<f32>(_e18.x, _e18.y, _e18.z, 1f));
+    let _e26 = uEntity;
+    outColor = _e26.f4Color;
+    let _e28 = uCamera;
+    let _e39 = uEntity;
+    let _e51 = inNormal_1;
+    outNormalV = ((mat3x3<f32>(_e28.mtxView[0].xyz, _e28.mtxView[1].xyz, _e28.mtxView[2].xyz) * mat3x3<f32>(_e39.mtxWorld[0].xyz, _e39.mtxWorld[1].xyz, _e39.mtxWorld[2].xyz)) * _e51);
+    let _e54 = uCamera;
+    let _e56 = uCamera;
+    let _e59 = f4PositionW;
+    gl_Position = ((_e54.mtxProjection * _e56.mtxView) * _e59);
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    main_1();
+    let _e25 = outColor;
+    let _e27 = outNormalV;
+    let _e29 = gl_Position;
+    return VertexOutput(_e25, _e27, _e29);
+}
@@ -0,0 +1,16 @@
+#version 450 core
+
+layout (location = 0) out vec4 outColor;
+
+layout (set = 1, binding = 0) uniform MirrorUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Tint;
+    vec4 f4BackdropColor;
+    vec4 f4HalfSize;
+} uMirror;
+
+void main() {
+    // The depth is pushed to the far plane so the reflected scene can be drawn behind the mirror surface.
+    gl_FragDepth = 1.0;
+    outColor = uMirror.f4BackdropColor;
+}
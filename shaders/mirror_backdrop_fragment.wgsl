@@ -0,0 +1,33 @@
+// Generated from `mirror_backdrop_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct MirrorUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Tint: vec4<f32>,
+    f4BackdropColor: vec4<f32>,
+    f4HalfSize: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outColor: vec4<f32>,
+    @builtin(frag_depth) member: f32,
+}
+
+var<private> outColor: vec4<f32>;
+@group(1) @binding(0) 
+var<uniform> uMirror: MirrorUniformLayout;
+var<private> gl_FragDepth: f32;
+
+fn main_1() {
+    gl_FragDepth = 1f;
+    let _e12 = uMirror;
+    outColor = _e12.f4BackdropColor;
+    return;
+}
+
+@fragment 
+fn main() -> FragmentOutput {
+    main_1();
+    let _e12 = outColor;
+    let _e14 = gl_FragDepth;
+    return FragmentOutput(_e12, _e14);
+}
@@ -0,0 +1,14 @@
+#version 450 core
+
+layout (location = 0) out vec4 outColor;
+
+layout (set = 1, binding = 0) uniform MirrorUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Tint;
+    vec4 f4BackdropColor;
+    vec4 f4HalfSize;
+} uMirror;
+
+void main() {
+    outColor = uMirror.f4Tint;
+}
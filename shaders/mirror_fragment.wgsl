@@ -0,0 +1,29 @@
+// Generated from `mirror_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct MirrorUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Tint: vec4<f32>,
+    f4BackdropColor: vec4<f32>,
+    f4HalfSize: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outColor: vec4<f32>,
+}
+
+var<private> outColor: vec4<f32>;
+@group(1) @binding(0) 
+var<uniform> uMirror: MirrorUniformLayout;
+
+fn main_1() {
+    let _e10 = uMirror;
+    outColor = _e10.f4Tint;
+    return;
+}
+
+@fragment 
+fn main() -> FragmentOutput {
+    main_1();
+    let _e12 = outColor;
+    return FragmentOutput(_e12);
+}
@@ -0,0 +1,24 @@
+#version 450 core
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform MirrorUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Tint;
+    vec4 f4BackdropColor;
+    vec4 f4HalfSize;
+} uMirror;
+
+const vec2 QUAD[6] = vec2[6](
+    vec2(-1.0, -1.0), vec2( 1.0, -1.0), vec2( 1.0,  1.0), 
+    vec2( 1.0,  1.0), vec2(-1.0,  1.0), vec2(-1.0, -1.0)
+);
+
+void main() {
+    vec4 f4PositionW = uMirror.mtxWorld * vec4(QUAD[gl_VertexIndex] * uMirror.f4HalfSize.xy, 0.0, 1.0);
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
+}
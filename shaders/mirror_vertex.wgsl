@@ -0,0 +1,52 @@
+// Generated from `mirror_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct MirrorUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Tint: vec4<f32>,
+    f4BackdropColor: vec4<f32>,
+    f4HalfSize: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) member: vec4<f32>,
+}
+
+const QUAD: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(-1f, -1f), vec2<f32>(1f, -1f), vec2<f32>(1f, 1f), vec2<f32>(1f, 1f), vec2<f32>(-1f, 1f), vec2<f32>(-1f, -1f));
+
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uMirror: MirrorUniformLayout;
+var<private> gl_VertexIndex: u32;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var local: array<vec2<f32>, 6> = QUAD;
+    var f4PositionW: vec4<f32>;
+
+    let _e18 = uMirror;
+    let _e20 = gl_VertexIndex;
+    let _e24 = local[_e20];
+    let _e25 = uMirror;
+    let _e28 = (_e24 * _e25.f4HalfSize.xy);
+    f4PositionW = (_e18.mtxWorld * vec4<f32>(_e28.x, _e28.y, 0f, 1f));
+    let _e37 = uCamera;
+    let _e39 = uCamera;
+    let _e42 = f4PositionW;
+    gl_Position = ((_e37.mtxProjection * _e39.mtxView) * _e42);
+    return;
+}
+
+@vertex 
+fn main(@builtin(vertex_index) param: u32) -> VertexOutput {
+    gl_VertexIndex = param;
+    main_1();
+    let _e21 = gl_Position;
+    return VertexOutput(_e21);
+}
@@ -0,0 +1,21 @@
+#version 450 core
+
+layout (location = 0) in vec2 inTexCoord;
+
+layout (location = 0) out vec4 outColor;
+
+layout (set = 1, binding = 0) uniform MonitorUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Params;
+} uMonitor;
+layout (set = 1, binding = 1) uniform texture2D tTarget;
+layout (set = 1, binding = 2) uniform sampler sTarget;
+
+const float SCANLINES = 240.0;
+
+void main() {
+    vec3 f3Color = texture(sampler2D(tTarget, sTarget), inTexCoord).rgb;
+    // Dark horizontal scanlines give the picture a security camera look.
+    float fScanline = 1.0 - uMonitor.f4Params.w * (0.5 + 0.5 * cos(6.28318530718 * SCANLINES * inTexCoord.y));
+    outColor = vec4(f3Color * uMonitor.f4Params.z * fScanline, 1.0);
+}
@@ -0,0 +1,48 @@
+// Generated from `monitor_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct MonitorUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outColor: vec4<f32>,
+}
+
+const SCANLINES: f32 = 240f;
+
+var<private> inTexCoord_1: vec2<f32>;
+var<private> outColor: vec4<f32>;
+@group(1) @binding(0) 
+var<uniform> uMonitor: MonitorUniformLayout;
+@group(1) @binding(1) 
+var tTarget: texture_2d<f32>;
+@group(1) @binding(2) 
+var sTarget: sampler;
+
+fn main_1() {
+    var f3Color: vec3<f32>;
+    var fScanline: f32;
+
+    let _e11 = inTexCoord_1;
+    let _e12 = textureSample(tTarget, sTarget, _e11);
+    f3Color = _e12.xyz;
+    let _e16 = uMonitor;
+    let _e24 = inTexCoord_1;
+    let _e30 = inTexCoord_1;
+    fScanline = (1f - (_e16.f4Params.w * (0.5f + (0.5f * cos((1507.9645f * _e30.y))))));
+    let _e39 = f3Color;
+    let _e40 = uMonitor;
+    let _e44 = fScanline;
+    let _e45 = ((_e39 * _e40.f4Params.z) * _e44);
+    outColor = vec4<f32>(_e45.x, _e45.y, _e45.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inTexCoord: vec2<f32>) -> FragmentOutput {
+    inTexCoord_1 = inTexCoord;
+    main_1();
+    let _e18 = outColor;
+    return FragmentOutput(_e18);
+}
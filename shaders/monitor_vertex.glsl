@@ -0,0 +1,27 @@
+#version 450 core
+
+layout (location = 0) out vec2 outTexCoord;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform MonitorUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Params;
+} uMonitor;
+
+const vec2 QUAD[6] = vec2[6](
+    vec2(-1.0, -1.0), vec2( 1.0, -1.0), vec2( 1.0,  1.0), 
+    vec2( 1.0,  1.0), vec2(-1.0,  1.0), vec2(-1.0, -1.0)
+);
+
+void main() {
+    vec2 f2Corner = QUAD[gl_VertexIndex];
+    // Texture rows go from top to bottom, so the v coordinate is flipped.
+    outTexCoord = vec2(0.5 * f2Corner.x + 0.5, 0.5 - 0.5 * f2Corner.y);
+    vec4 f4PositionW = uMonitor.mtxWorld * vec4(f2Corner * uMonitor.f4Params.xy, 0.0, 1.0);
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
+}
@@ -0,0 +1,59 @@
+// Generated from `monitor_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct MonitorUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Params: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outTexCoord: vec2<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+const QUAD: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(-1f, -1f), vec2<f32>(1f, -1f), vec2<f32>(1f, 1f), vec2<f32>(1f, 1f), vec2<f32>(-1f, 1f), vec2<f32>(-1f, -1f));
+
+var<private> outTexCoord: vec2<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uMonitor: MonitorUniformLayout;
+var<private> gl_VertexIndex: u32;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var local: array<vec2<f32>, 6> = QUAD;
+    var f2Corner: vec2<f32>;
+    var f4PositionW: vec4<f32>;
+
+    let _e15 = gl_VertexIndex;
+    let _e19 = local[_e15];
+    f2Corner = _e19;
+    let _e22 = f2Corner;
+    let _e29 = f2Corner;
+    outTexCoord = vec2<f32>(((0.5f * _e22.x) + 0.5f), (0.5f - (0.5f * _e29.y)));
+    let _e34 = uMonitor;
+    let _e36 = f2Corner;
+    let _e37 = uMonitor;
+    let _e40 = (_e36 * _e37.f4Params.xy);
+    f4PositionW = (_e34.mtxWorld * vec4<f32>(_e40.x, _e40.y, 0f, 1f));
+    let _e49 = uCamera;
+    let _e51 = uCamera;
+    let _e54 = f4PositionW;
+    gl_Position = ((_e49.mtxProjection * _e51.mtxView) * _e54);
+    return;
+}
+
+@vertex 
+fn main(@builtin(vertex_index) param: u32) -> VertexOutput {
+    gl_VertexIndex = param;
+    main_1();
+    let _e19 = outTexCoord;
+    let _e21 = gl_Position;
+    return VertexOutput(_e19, _e21);
+}
@@ -0,0 +1,19 @@
+#version 450 core
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform texture2D uAccumulation;
+layout (set = 0, binding = 1) uniform texture2D uRevealage;
+layout (set = 0, binding = 2) uniform sampler uSampler;
+
+void main() {
+    ivec2 i2Coord = ivec2(gl_FragCoord.xy);
+    vec4 f4Accumulation = texelFetch(sampler2D(uAccumulation, uSampler), i2Coord, 0);
+    float fRevealage = texelFetch(sampler2D(uRevealage, uSampler), i2Coord, 0).r;
+    if (fRevealage >= 1.0) {
+        discard;
+    }
+
+    vec3 f3AverageColor = f4Accumulation.rgb / max(f4Accumulation.a, 1e-5);
+    outFragColor = vec4(f3AverageColor, 1.0 - fRevealage);
+}
@@ -0,0 +1,52 @@
+// Generated from `oit_composite_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uAccumulation: texture_2d<f32>;
+@group(0) @binding(1) 
+var uRevealage: texture_2d<f32>;
+@group(0) @binding(2) 
+var uSampler: sampler;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn main_1() {
+    var i2Coord: vec2<i32>;
+    var f4Accumulation: vec4<f32>;
+    var fRevealage: f32;
+    var f3AverageColor: vec3<f32>;
+
+    let _e5 = gl_FragCoord;
+    i2Coord = vec2<i32>(_e5.xy);
+    let _e11 = i2Coord;
+    let _e13 = textureLoad(uAccumulation, _e11, 0i);
+    f4Accumulation = _e13;
+    let _e17 = i2Coord;
+    let _e19 = textureLoad(uRevealage, _e17, 0i);
+    fRevealage = _e19.x;
+    let _e22 = fRevealage;
+    if (_e22 >= 1f) {
+        {
+            discard;
+        }
+    }
+    let _e25 = f4Accumulation;
+    let _e27 = f4Accumulation;
+    let _e30 = f4Accumulation;
+    f3AverageColor = (_e25.xyz / vec3(max(_e30.w, 0.00001f)));
+    let _e37 = f3AverageColor;
+    let _e39 = fRevealage;
+    outFragColor = vec4<f32>(_e37.x, _e37.y, _e37.z, (1f - _e39));
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e11 = outFragColor;
+    return FragmentOutput(_e11);
+}
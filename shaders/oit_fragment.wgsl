@@ -0,0 +1,497 @@
+// Generated from `oit_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    view: mat4x4<f32>,
+    projection: mat4x4<f32>,
+    position: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outAccumulation: vec4<f32>,
+    @location(1) outRevealage: f32,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> inLightSpaceFragPosition_1: vec4<f32>;
+var<private> inPositionW_1: vec3<f32>;
+var<private> inRim_1: vec4<f32>;
+var<private> outAccumulation: vec4<f32>;
+var<private> outRevealage: f32;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(3) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(3) @binding(1) 
+var uSampler: sampler_comparison;
+@group(3) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(4) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e44 = f2ProjCoords_1;
+    let _e45 = fCurrentDepth_1;
+    let _e49 = f2ProjCoords_1;
+    let _e50 = fCurrentDepth_1;
+    let _e53 = vec3<f32>(_e49.x, _e49.y, _e50);
+    let _e56 = textureSampleCompare(uShadowMap, uSampler, _e53.xy, _e53.z);
+    return _e56;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e42 = f4LightSpaceFragPosition_1;
+    if (_e42.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e47 = f4LightSpaceFragPosition_1;
+    let _e49 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e47.z / _e49.w);
+    let _e53 = f4LightSpaceFragPosition_1;
+    let _e55 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e53.xy / vec2(_e55.w));
+    let _e60 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e60 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e69 = uShadowFilter;
+    iKernel = i32((_e69.f4Params.x + 0.5f));
+    let _e76 = uShadowFilter;
+    let _e81 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e76.f4Params.y) / vec2<f32>(vec2<i32>(_e81)));
+    let _e87 = iKernel;
+    if (_e87 == 3i) {
+        {
+            loop {
+                let _e94 = i;
+                if !((_e94 < 16i)) {
+                    break;
+                }
+                {
+                    let _e101 = fSum;
+                    let _e102 = f2ProjCoords_2;
+                    let _e103 = i;
+                    let _e107 = local[_e103];
+                    let _e108 = f2Radius;
+                    let _e112 = f2ProjCoords_2;
+                    let _e113 = i;
+                    let _e117 = local_1[_e113];
+                    let _e118 = f2Radius;
+                    let _e121 = fCurrentDepth_2;
+                    let _e122 = sampleShadow((_e112 + (_e117 * _e118)), _e121);
+                    fSum = (_e101 + _e122);
+                }
+                continuing {
+                    let _e98 = i;
+                    i = (_e98 + 1i);
+                }
+            }
+            let _e124 = fSum;
+            return (_e124 / 16f);
+        }
+    }
+    let _e127 = iKernel;
+    let _e130 = iKernel;
+    if ((_e127 == 1i) || (_e130 == 2i)) {
+        {
+            let _e136 = iKernel;
+            y = -(_e136);
+            loop {
+                let _e139 = y;
+                let _e140 = iKernel;
+                if !((_e139 <= _e140)) {
+                    break;
+                }
+                {
+                    let _e146 = iKernel;
+                    x = -(_e146);
+                    loop {
+                        let _e149 = x;
+                        let _e150 = iKernel;
+                        if !((_e149 <= _e150)) {
+                            break;
+                        }
+                        {
+                            let _e156 = fSum_1;
+                            let _e157 = f2ProjCoords_2;
+                            let _e158 = x;
+                            let _e159 = y;
+                            let _e163 = iKernel;
+                            let _e167 = f2Radius;
+                            let _e171 = f2ProjCoords_2;
+                            let _e172 = x;
+                            let _e173 = y;
+                            let _e177 = iKernel;
+                            let _e181 = f2Radius;
+                            let _e184 = fCurrentDepth_2;
+                            let _e185 = sampleShadow((_e171 + ((vec2<f32>(f32(_e172), f32(_e173)) / vec2(f32(_e177))) * _e181)), _e184);
+                            fSum_1 = (_e156 + _e185);
+                        }
+                        continuing {
+                            let _e153 = x;
+                            x = (_e153 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e143 = y;
+                    y = (_e143 + 1i);
+                }
+            }
+            let _e188 = iKernel;
+            fWidth = f32(((2i * _e188) + 1i));
+            let _e194 = fSum_1;
+            let _e195 = fWidth;
+            let _e196 = fWidth;
+            return (_e194 / (_e195 * _e196));
+        }
+    }
+    let _e201 = f2ProjCoords_2;
+    let _e202 = fCurrentDepth_2;
+    let _e203 = sampleShadow(_e201, _e202);
+    return _e203;
+}
+
+fn calculateRim(f3NormalW: vec3<f32>, f3PositionW: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_1: vec3<f32>;
+    var f3PositionW_1: vec3<f32>;
+    var f3View: vec3<f32>;
+    var fFresnel: f32;
+
+    f3NormalW_1 = f3NormalW;
+    f3PositionW_1 = f3PositionW;
+    let _e44 = inRim_1;
+    let _e46 = inRim_1;
+    let _e48 = inRim_1;
+    let _e50 = inRim_1;
+    if (dot(_e48.xyz, _e50.xyz) <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e57 = uCamera;
+    let _e60 = f3PositionW_1;
+    let _e62 = uCamera;
+    let _e65 = f3PositionW_1;
+    f3View = normalize((_e62.position.xyz - _e65));
+    let _e71 = f3NormalW_1;
+    let _e75 = f3NormalW_1;
+    let _e77 = f3View;
+    let _e81 = f3NormalW_1;
+    let _e85 = f3NormalW_1;
+    let _e87 = f3View;
+    fFresnel = (1f - max(dot(normalize(_e85), _e87), 0f));
+    let _e93 = inRim_1;
+    let _e96 = inRim_1;
+    let _e99 = inRim_1;
+    let _e103 = fFresnel;
+    let _e104 = inRim_1;
+    let _e107 = inRim_1;
+    return (_e93.xyz * pow(_e103, max(_e107.w, 0.0001f)));
+}
+
+fn calculateFog(f3PositionW_2: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_3: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e42 = uFog;
+    fDensity = _e42.f4Params.x;
+    let _e46 = uFog;
+    let _e50 = uFog;
+    fFalloff = max(_e50.f4Params.y, 0.0001f);
+    let _e56 = uFog;
+    fBaseHeight = _e56.f4Params.z;
+    let _e60 = uFog;
+    fMaxOpacity = _e60.f4Params.w;
+    let _e64 = f3PositionW_3;
+    let _e65 = uCamera;
+    f3Ray = (_e64 - _e65.position.xyz);
+    let _e71 = f3Ray;
+    fDistance = length(_e71);
+    let _e74 = fDensity;
+    let _e77 = fDistance;
+    if ((_e74 <= 0f) || (_e77 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e84 = uFog;
+    if (_e84.f4Range.z < 0.5f) {
+        {
+            let _e89 = fDistance;
+            let _e90 = uFog;
+            let _e94 = uFog;
+            let _e97 = uFog;
+            let _e102 = uFog;
+            let _e105 = uFog;
+            let _e114 = fDistance;
+            let _e115 = uFog;
+            let _e119 = uFog;
+            let _e122 = uFog;
+            let _e127 = uFog;
+            let _e130 = uFog;
+            fOpacity = clamp(((_e114 - _e115.f4Range.x) / max((_e127.f4Range.y - _e130.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e140 = uFog;
+        if (_e140.f4Range.z < 1.5f) {
+            {
+                let _e146 = fDensity;
+                let _e148 = fDistance;
+                let _e149 = uFog;
+                let _e154 = fDistance;
+                let _e155 = uFog;
+                let _e162 = fDensity;
+                let _e164 = fDistance;
+                let _e165 = uFog;
+                let _e170 = fDistance;
+                let _e171 = uFog;
+                fOpacity = (1f - exp((-(_e162) * max((_e170 - _e171.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e180 = fDensity;
+                let _e181 = fFalloff;
+                let _e183 = uCamera;
+                let _e186 = fBaseHeight;
+                let _e189 = fFalloff;
+                let _e191 = uCamera;
+                let _e194 = fBaseHeight;
+                let _e199 = fDistance;
+                fFogAmount = ((_e180 * exp((-(_e189) * (_e191.position.y - _e194)))) * _e199);
+                let _e202 = fFalloff;
+                let _e203 = f3Ray;
+                fRayHeight = (_e202 * _e203.y);
+                let _e208 = fRayHeight;
+                if (abs(_e208) > 0.0001f) {
+                    {
+                        let _e212 = fFogAmount;
+                        let _e214 = fRayHeight;
+                        let _e216 = fRayHeight;
+                        let _e220 = fRayHeight;
+                        fFogAmount = (_e212 * ((1f - exp(-(_e216))) / _e220));
+                    }
+                }
+                let _e224 = fFogAmount;
+                let _e226 = fFogAmount;
+                fOpacity = (1f - exp(-(_e226)));
+            }
+        }
+    }
+    let _e232 = fOpacity;
+    let _e233 = fMaxOpacity;
+    fOpacity = min(_e232, _e233);
+    let _e235 = f3Ray;
+    let _e236 = fDistance;
+    let _e239 = uGlobalLight;
+    let _e242 = uGlobalLight;
+    let _e246 = f3Ray;
+    let _e247 = fDistance;
+    let _e250 = uGlobalLight;
+    let _e253 = uGlobalLight;
+    let _e259 = f3Ray;
+    let _e260 = fDistance;
+    let _e263 = uGlobalLight;
+    let _e266 = uGlobalLight;
+    let _e270 = f3Ray;
+    let _e271 = fDistance;
+    let _e274 = uGlobalLight;
+    let _e277 = uGlobalLight;
+    fSunAmount = max(dot((_e270 / vec3(_e271)), normalize(_e277.f4Direction.xyz)), 0f);
+    let _e285 = uFog;
+    let _e288 = uGlobalLight;
+    f3SunColor = (_e285.f4SunColor.xyz * _e288.f4LightColor.xyz);
+    let _e293 = uFog;
+    let _e298 = uFog;
+    let _e301 = fSunAmount;
+    let _e302 = uFog;
+    let _e306 = uFog;
+    let _e309 = f3SunColor;
+    let _e311 = uFog;
+    let _e314 = fSunAmount;
+    let _e315 = uFog;
+    f3FogColor = mix(_e306.f4Color.xyz, _e309, vec3(pow(_e314, _e315.f4SunColor.w)));
+    let _e322 = f3FogColor;
+    let _e323 = fOpacity;
+    return vec4<f32>(_e322.x, _e322.y, _e322.z, _e323);
+}
+
+fn calculateSpotAttenuation(f3PositionW_4: vec3<f32>) -> f32 {
+    var f3PositionW_5: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_5 = f3PositionW_4;
+    let _e42 = uGlobalLight;
+    if (_e42.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e48 = f3PositionW_5;
+    let _e49 = uGlobalLight;
+    f3ToFragment = (_e48 - _e49.f4Position.xyz);
+    let _e55 = f3ToFragment;
+    fDistance_1 = length(_e55);
+    let _e58 = f3ToFragment;
+    let _e61 = fDistance_1;
+    let _e66 = uGlobalLight;
+    let _e69 = uGlobalLight;
+    let _e74 = f3ToFragment;
+    let _e77 = fDistance_1;
+    let _e82 = uGlobalLight;
+    let _e85 = uGlobalLight;
+    fCosAngle = dot((_e74 / vec3(max(_e77, 0.0001f))), -(normalize(_e85.f4Direction.xyz)));
+    let _e92 = uGlobalLight;
+    let _e95 = uGlobalLight;
+    let _e99 = uGlobalLight;
+    let _e102 = uGlobalLight;
+    let _e105 = fCosAngle;
+    fCone = smoothstep(_e99.f4Cone.y, _e102.f4Cone.x, _e105);
+    let _e109 = fDistance_1;
+    let _e110 = uGlobalLight;
+    let _e114 = uGlobalLight;
+    let _e124 = fDistance_1;
+    let _e125 = uGlobalLight;
+    let _e129 = uGlobalLight;
+    fRange = clamp((1f - (_e124 / max(_e129.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e140 = fCone;
+    let _e141 = fRange;
+    let _e143 = fRange;
+    return ((_e140 * _e141) * _e143);
+}
+
+fn main_1() {
+    var fShadow: f32;
+    var f4Color: vec4<f32>;
+    var f4Fog: vec4<f32>;
+    var f3Color: vec3<f32>;
+    var fAlpha: f32;
+    var fWeight: f32;
+
+    let _e41 = inLightSpaceFragPosition_1;
+    let _e42 = calculateShadow(_e41);
+    let _e44 = inPositionW_1;
+    let _e45 = calculateSpotAttenuation(_e44);
+    fShadow = (_e42 * _e45);
+    let _e48 = inColor_1;
+    let _e51 = inColor_1;
+    let _e52 = fShadow;
+    f4Color = ((_e48 * 0.2f) + (_e51 * _e52));
+    let _e56 = f4Color;
+    let _e58 = f4Color;
+    let _e62 = inNormalW_1;
+    let _e63 = inPositionW_1;
+    let _e64 = calculateRim(_e62, _e63);
+    let _e65 = (_e58.xyz + _e64);
+    f4Color.x = _e65.x;
+    f4Color.y = _e65.y;
+    f4Color.z = _e65.z;
+    let _e73 = inPositionW_1;
+    let _e74 = calculateFog(_e73);
+    f4Fog = _e74;
+    let _e76 = f4Color;
+    let _e78 = f4Fog;
+    let _e80 = f4Fog;
+    let _e82 = f4Color;
+    let _e84 = f4Fog;
+    let _e86 = f4Fog;
+    f3Color = mix(_e82.xyz, _e84.xyz, vec3(_e86.w));
+    let _e91 = inColor_1;
+    fAlpha = _e91.w;
+    let _e96 = fAlpha;
+    let _e100 = fAlpha;
+    let _e108 = fAlpha;
+    let _e112 = fAlpha;
+    let _e123 = gl_FragCoord;
+    let _e130 = gl_FragCoord;
+    let _e141 = fAlpha;
+    let _e145 = fAlpha;
+    let _e153 = fAlpha;
+    let _e157 = fAlpha;
+    let _e168 = gl_FragCoord;
+    let _e175 = gl_FragCoord;
+    fWeight = clamp(((pow((min(1f, (_e157 * 10f)) + 0.01f), 3f) * 100000000f) * pow((1f - (_e175.z * 0.9f)), 3f)), 0.01f, 3000f);
+    let _e187 = f3Color;
+    let _e188 = fAlpha;
+    let _e189 = (_e187 * _e188);
+    let _e190 = fAlpha;
+    let _e195 = fWeight;
+    outAccumulation = (vec4<f32>(_e189.x, _e189.y, _e189.z, _e190) * _e195);
+    let _e197 = fAlpha;
+    outRevealage = _e197;
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>, @location(2) inLightSpaceFragPosition: vec4<f32>, @location(3) inPositionW: vec3<f32>, @location(4) inRim: vec4<f32>, @builtin(position) param: vec4<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    inLightSpaceFragPosition_1 = inLightSpaceFragPosition;
+    inPositionW_1 = inPositionW;
+    inRim_1 = inRim;
+    gl_FragCoord = param;
+    main_1();
+    let _e63 = outAccumulation;
+    let _e65 = outRevealage;
+    return FragmentOutput(_e63, _e65);
+}
@@ -0,0 +1,201 @@
+#version 450 core
+
+layout (location = 0) in vec4 inColor;
+layout (location = 1) in vec3 inNormalW;
+layout (location = 2) in vec4 inLightSpaceFragPosition;
+layout (location = 3) in vec3 inPositionW;
+layout (location = 4) in vec3 inTangentW;
+layout (location = 5) in vec3 inBitangentW;
+layout (location = 6) in vec2 inTexCoord;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 view;
+    mat4 projection;
+    vec4 position;
+} uCamera;
+
+layout (set = 2, binding = 0) uniform GlobalLightUniformLayout {
+    mat4 mtxProjView;
+    vec4 f4Direction;
+    vec4 f4LightColor;
+    vec4 f4Position;
+    vec4 f4Cone;
+} uGlobalLight;
+
+layout (set = 3, binding = 0) uniform texture2D uShadowMap;
+layout (set = 3, binding = 1) uniform samplerShadow uSampler;
+layout (set = 3, binding = 2) uniform ShadowFilterUniformLayout {
+    vec4 f4Params;
+} uShadowFilter;
+
+layout (set = 4, binding = 0) uniform FogUniformLayout {
+    vec4 f4Color;
+    vec4 f4SunColor;
+    vec4 f4Params;
+    vec4 f4Range;
+} uFog;
+
+layout (set = 5, binding = 0) uniform ParallaxUniformLayout {
+    vec4 f4Params;
+} uParallax;
+layout (set = 5, binding = 1) uniform texture2D uSurfaceMap;
+layout (set = 5, binding = 2) uniform sampler uSurfaceSampler;
+
+const vec2 POISSON_DISK[16] = vec2[](
+    vec2(-0.94201624, -0.39906216), vec2(0.94558609, -0.76890725),
+    vec2(-0.09418410, -0.92938870), vec2(0.34495938, 0.29387760),
+    vec2(-0.91588581, 0.45771432), vec2(-0.81544232, -0.87912464),
+    vec2(-0.38277543, 0.27676845), vec2(0.97484398, 0.75648379),
+    vec2(0.44323325, -0.97511554), vec2(0.53742981, -0.47373420),
+    vec2(-0.26496911, -0.41893023), vec2(0.79197514, 0.19090188),
+    vec2(-0.24188840, 0.99706507), vec2(-0.81409955, 0.91437590),
+    vec2(0.19984126, 0.78641367), vec2(0.14383161, -0.14100790)
+);
+
+float sampleShadow(vec2 f2ProjCoords, float fCurrentDepth) {
+    return texture(sampler2DShadow(uShadowMap, uSampler), vec3(f2ProjCoords, fCurrentDepth));
+}
+
+float calculateShadow(vec4 f4LightSpaceFragPosition) {
+    if (f4LightSpaceFragPosition.w <= 0.0) {
+        return 1.0;
+    }
+
+    float fCurrentDepth = f4LightSpaceFragPosition.z / f4LightSpaceFragPosition.w;
+    vec2 f2ProjCoords = f4LightSpaceFragPosition.xy / f4LightSpaceFragPosition.w;
+    f2ProjCoords = f2ProjCoords * vec2(0.5, -0.5) + 0.5;
+
+    // (한국어) 커널의 탭들을 평균하여 그림자의 경계를 부드럽게 만듭니다. 반지름은 텍셀 단위 입니다.
+    // (English Translation) Softens the edges of the shadow by averaging the taps of the kernel. The radius is in texels.
+    int iKernel = int(uShadowFilter.f4Params.x + 0.5);
+    vec2 f2Radius = uShadowFilter.f4Params.y / vec2(textureSize(sampler2DShadow(uShadowMap, uSampler), 0));
+    if (iKernel == 3) {
+        float fSum = 0.0;
+        for (int i = 0; i < 16; ++i) {
+            fSum += sampleShadow(f2ProjCoords + POISSON_DISK[i] * f2Radius, fCurrentDepth);
+        }
+        return fSum / 16.0;
+    }
+    if (iKernel == 1 || iKernel == 2) {
+        float fSum = 0.0;
+        for (int y = -iKernel; y <= iKernel; ++y) {
+            for (int x = -iKernel; x <= iKernel; ++x) {
+                fSum += sampleShadow(f2ProjCoords + vec2(x, y) / float(iKernel) * f2Radius, fCurrentDepth);
+            }
+        }
+        float fWidth = float(2 * iKernel + 1);
+        return fSum / (fWidth * fWidth);
+    }
+    return sampleShadow(f2ProjCoords, fCurrentDepth);
+}
+
+// The surface map stores the tangent space normal in rgb and the depth below the surface in a.
+vec4 sampleSurface(vec2 f2TexCoord) {
+    return textureLod(sampler2D(uSurfaceMap, uSurfaceSampler), f2TexCoord, 0.0);
+}
+
+// Parallax occlusion mapping: ray-marches the depth map along the view ray in tangent space
+// and interpolates between the last two steps around the intersection.
+vec2 parallaxOcclusion(vec2 f2TexCoord, vec3 f3ViewT) {
+    float fHeightScale = uParallax.f4Params.x;
+    float fMinSteps = max(uParallax.f4Params.y, 1.0);
+    float fMaxSteps = max(uParallax.f4Params.z, fMinSteps);
+    if (fHeightScale <= 0.0) {
+        return f2TexCoord;
+    }
+
+    // Grazing angles need more steps than views from straight above.
+    float fNumSteps = floor(mix(fMaxSteps, fMinSteps, clamp(f3ViewT.z, 0.0, 1.0)));
+    float fStepDepth = 1.0 / fNumSteps;
+    vec2 f2Delta = (f3ViewT.xy / max(f3ViewT.z, 0.05)) * fHeightScale / fNumSteps;
+
+    vec2 f2Current = f2TexCoord;
+    float fCurrentDepth = 0.0;
+    float fSurfaceDepth = sampleSurface(f2Current).a;
+    for (int i = 0; i < int(fNumSteps); ++i) {
+        if (fCurrentDepth >= fSurfaceDepth) {
+            break;
+        }
+        f2Current -= f2Delta;
+        fCurrentDepth += fStepDepth;
+        fSurfaceDepth = sampleSurface(f2Current).a;
+    }
+
+    vec2 f2Previous = f2Current + f2Delta;
+    float fAfter = fSurfaceDepth - fCurrentDepth;
+    float fBefore = sampleSurface(f2Previous).a - fCurrentDepth + fStepDepth;
+    float fWeight = fAfter / min(fAfter - fBefore, -1e-5);
+    return mix(f2Current, f2Previous, clamp(fWeight, 0.0, 1.0));
+}
+
+vec4 calculateFog(vec3 f3PositionW) {
+    float fDensity = uFog.f4Params.x;
+    float fFalloff = max(uFog.f4Params.y, 1e-4);
+    float fBaseHeight = uFog.f4Params.z;
+    float fMaxOpacity = uFog.f4Params.w;
+
+    vec3 f3Ray = f3PositionW - uCamera.position.xyz;
+    float fDistance = length(f3Ray);
+    if (fDensity <= 0.0 || fDistance <= 0.0) {
+        return vec4(0.0);
+    }
+
+    float fOpacity;
+    if (uFog.f4Range.z < 0.5) {
+        // Linear fog between the start and end distances, the density is not used.
+        fOpacity = clamp((fDistance - uFog.f4Range.x) / max(uFog.f4Range.y - uFog.f4Range.x, 1e-3), 0.0, 1.0);
+    } else if (uFog.f4Range.z < 1.5) {
+        // Exponential fog of uniform density past the start distance.
+        fOpacity = 1.0 - exp(-fDensity * max(fDistance - uFog.f4Range.x, 0.0));
+    } else {
+        // Integral of density * exp(-falloff * (height - base)) along the view ray.
+        float fFogAmount = fDensity * exp(-fFalloff * (uCamera.position.y - fBaseHeight)) * fDistance;
+        float fRayHeight = fFalloff * f3Ray.y;
+        if (abs(fRayHeight) > 1e-4) {
+            fFogAmount *= (1.0 - exp(-fRayHeight)) / fRayHeight;
+        }
+        fOpacity = 1.0 - exp(-fFogAmount);
+    }
+    fOpacity = min(fOpacity, fMaxOpacity);
+
+    float fSunAmount = max(dot(f3Ray / fDistance, normalize(uGlobalLight.f4Direction.xyz)), 0.0);
+    vec3 f3SunColor = uFog.f4SunColor.rgb * uGlobalLight.f4LightColor.rgb;
+    vec3 f3FogColor = mix(uFog.f4Color.rgb, f3SunColor, pow(fSunAmount, uFog.f4SunColor.w));
+    return vec4(f3FogColor, fOpacity);
+}
+
+float calculateSpotAttenuation(vec3 f3PositionW) {
+    if (uGlobalLight.f4Position.w <= 0.0) {
+        return 1.0;
+    }
+
+    vec3 f3ToFragment = f3PositionW - uGlobalLight.f4Position.xyz;
+    float fDistance = length(f3ToFragment);
+    float fCosAngle = dot(f3ToFragment / max(fDistance, 1e-4), -normalize(uGlobalLight.f4Direction.xyz));
+    float fCone = smoothstep(uGlobalLight.f4Cone.y, uGlobalLight.f4Cone.x, fCosAngle);
+    float fRange = clamp(1.0 - fDistance / max(uGlobalLight.f4Cone.z, 1e-4), 0.0, 1.0);
+    return fCone * fRange * fRange;
+}
+
+void main() {
+    vec3 f3NormalW = normalize(inNormalW);
+    vec3 f3TangentW = normalize(inTangentW - f3NormalW * dot(f3NormalW, inTangentW));
+    vec3 f3BitangentW = normalize(inBitangentW - f3NormalW * dot(f3NormalW, inBitangentW) - f3TangentW * dot(f3TangentW, inBitangentW));
+    mat3 mtxTBN = mat3(f3TangentW, f3BitangentW, f3NormalW);
+
+    vec3 f3ViewW = normalize(uCamera.position.xyz - inPositionW);
+    vec3 f3ViewT = normalize(transpose(mtxTBN) * f3ViewW);
+    vec2 f2TexCoord = parallaxOcclusion(inTexCoord, f3ViewT);
+    vec4 f4Surface = sampleSurface(f2TexCoord);
+
+    vec3 f3SurfaceNormalW = normalize(mtxTBN * (f4Surface.rgb * 2.0 - 1.0));
+    float fDiffuse = max(dot(f3SurfaceNormalW, normalize(uGlobalLight.f4Direction.xyz)), 0.0);
+    vec4 f4Albedo = vec4(inColor.rgb * mix(1.0, 0.55, f4Surface.a), inColor.a);
+
+    float fShadow = calculateShadow(inLightSpaceFragPosition) * calculateSpotAttenuation(inPositionW);
+    vec4 f4Color = f4Albedo * 0.2 + (f4Albedo * fShadow * fDiffuse);
+    vec4 f4Fog = calculateFog(inPositionW);
+    outFragColor = vec4(mix(f4Color.rgb, f4Fog.rgb, f4Fog.a), inColor.a);
+}
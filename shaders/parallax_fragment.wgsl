@@ -0,0 +1,640 @@
+// Generated from `parallax_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    view: mat4x4<f32>,
+    projection: mat4x4<f32>,
+    position: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct ParallaxUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> inLightSpaceFragPosition_1: vec4<f32>;
+var<private> inPositionW_1: vec3<f32>;
+var<private> inTangentW_1: vec3<f32>;
+var<private> inBitangentW_1: vec3<f32>;
+var<private> inTexCoord_1: vec2<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(3) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(3) @binding(1) 
+var uSampler: sampler_comparison;
+@group(3) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(4) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+@group(5) @binding(0) 
+var<uniform> uParallax: ParallaxUniformLayout;
+@group(5) @binding(1) 
+var uSurfaceMap: texture_2d<f32>;
+@group(5) @binding(2) 
+var uSurfaceSampler: sampler;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e50 = f2ProjCoords_1;
+    let _e51 = fCurrentDepth_1;
+    let _e55 = f2ProjCoords_1;
+    let _e56 = fCurrentDepth_1;
+    let _e59 = vec3<f32>(_e55.x, _e55.y, _e56);
+    let _e62 = textureSampleCompare(uShadowMap, uSampler, _e59.xy, _e59.z);
+    return _e62;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e48 = f4LightSpaceFragPosition_1;
+    if (_e48.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e53 = f4LightSpaceFragPosition_1;
+    let _e55 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e53.z / _e55.w);
+    let _e59 = f4LightSpaceFragPosition_1;
+    let _e61 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e59.xy / vec2(_e61.w));
+    let _e66 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e66 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e75 = uShadowFilter;
+    iKernel = i32((_e75.f4Params.x + 0.5f));
+    let _e82 = uShadowFilter;
+    let _e87 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e82.f4Params.y) / vec2<f32>(vec2<i32>(_e87)));
+    let _e93 = iKernel;
+    if (_e93 == 3i) {
+        {
+            loop {
+                let _e100 = i;
+                if !((_e100 < 16i)) {
+                    break;
+                }
+                {
+                    let _e107 = fSum;
+                    let _e108 = f2ProjCoords_2;
+                    let _e109 = i;
+                    let _e113 = local[_e109];
+                    let _e114 = f2Radius;
+                    let _e118 = f2ProjCoords_2;
+                    let _e119 = i;
+                    let _e123 = local_1[_e119];
+                    let _e124 = f2Radius;
+                    let _e127 = fCurrentDepth_2;
+                    let _e128 = sampleShadow((_e118 + (_e123 * _e124)), _e127);
+                    fSum = (_e107 + _e128);
+                }
+                continuing {
+                    let _e104 = i;
+                    i = (_e104 + 1i);
+                }
+            }
+            let _e130 = fSum;
+            return (_e130 / 16f);
+        }
+    }
+    let _e133 = iKernel;
+    let _e136 = iKernel;
+    if ((_e133 == 1i) || (_e136 == 2i)) {
+        {
+            let _e142 = iKernel;
+            y = -(_e142);
+            loop {
+                let _e145 = y;
+                let _e146 = iKernel;
+                if !((_e145 <= _e146)) {
+                    break;
+                }
+                {
+                    let _e152 = iKernel;
+                    x = -(_e152);
+                    loop {
+                        let _e155 = x;
+                        let _e156 = iKernel;
+                        if !((_e155 <= _e156)) {
+                            break;
+                        }
+                        {
+                            let _e162 = fSum_1;
+                            let _e163 = f2ProjCoords_2;
+                            let _e164 = x;
+                            let _e165 = y;
+                            let _e169 = iKernel;
+                            let _e173 = f2Radius;
+                            let _e177 = f2ProjCoords_2;
+                            let _e178 = x;
+                            let _e179 = y;
+                            let _e183 = iKernel;
+                            let _e187 = f2Radius;
+                            let _e190 = fCurrentDepth_2;
+                            let _e191 = sampleShadow((_e177 + ((vec2<f32>(f32(_e178), f32(_e179)) / vec2(f32(_e183))) * _e187)), _e190);
+                            fSum_1 = (_e162 + _e191);
+                        }
+                        continuing {
+                            let _e159 = x;
+                            x = (_e159 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e149 = y;
+                    y = (_e149 + 1i);
+                }
+            }
+            let _e194 = iKernel;
+            fWidth = f32(((2i * _e194) + 1i));
+            let _e200 = fSum_1;
+            let _e201 = fWidth;
+            let _e202 = fWidth;
+            return (_e200 / (_e201 * _e202));
+        }
+    }
+    let _e207 = f2ProjCoords_2;
+    let _e208 = fCurrentDepth_2;
+    let _e209 = sampleShadow(_e207, _e208);
+    return _e209;
+}
+
+fn sampleSurface(f2TexCoord: vec2<f32>) -> vec4<f32> {
+    var f2TexCoord_1: vec2<f32>;
+
+    f2TexCoord_1 = f2TexCoord;
+    let _e50 = f2TexCoord_1;
+    let _e52 = textureSampleLevel(uSurfaceMap, uSurfaceSampler, _e50, 0f);
+    return _e52;
+}
+
+fn parallaxOcclusion(f2TexCoord_2: vec2<f32>, f3ViewT: vec3<f32>) -> vec2<f32> {
+    var f2TexCoord_3: vec2<f32>;
+    var f3ViewT_1: vec3<f32>;
+    var fHeightScale: f32;
+    var fMinSteps: f32;
+    var fMaxSteps: f32;
+    var fNumSteps: f32;
+    var fStepDepth: f32;
+    var f2Delta: vec2<f32>;
+    var f2Current: vec2<f32>;
+    var fCurrentDepth_3: f32 = 0f;
+    var fSurfaceDepth: f32;
+    var i_1: i32 = 0i;
+    var f2Previous: vec2<f32>;
+    var fAfter: f32;
+    var fBefore: f32;
+    var fWeight: f32;
+
+    f2TexCoord_3 = f2TexCoord_2;
+    f3ViewT_1 = f3ViewT;
+    let _e50 = uParallax;
+    fHeightScale = _e50.f4Params.x;
+    let _e54 = uParallax;
+    let _e58 = uParallax;
+    fMinSteps = max(_e58.f4Params.y, 1f);
+    let _e64 = uParallax;
+    let _e68 = uParallax;
+    let _e71 = fMinSteps;
+    fMaxSteps = max(_e68.f4Params.z, _e71);
+    let _e74 = fHeightScale;
+    if (_e74 <= 0f) {
+        {
+            let _e77 = f2TexCoord_3;
+            return _e77;
+        }
+    }
+    let _e80 = f3ViewT_1;
+    let _e84 = f3ViewT_1;
+    let _e89 = fMaxSteps;
+    let _e90 = fMinSteps;
+    let _e91 = f3ViewT_1;
+    let _e95 = f3ViewT_1;
+    let _e103 = f3ViewT_1;
+    let _e107 = f3ViewT_1;
+    let _e112 = fMaxSteps;
+    let _e113 = fMinSteps;
+    let _e114 = f3ViewT_1;
+    let _e118 = f3ViewT_1;
+    fNumSteps = floor(mix(_e112, _e113, clamp(_e118.z, 0f, 1f)));
+    let _e127 = fNumSteps;
+    fStepDepth = (1f / _e127);
+    let _e130 = f3ViewT_1;
+    let _e132 = f3ViewT_1;
+    let _e135 = f3ViewT_1;
+    let _e141 = fHeightScale;
+    let _e143 = fNumSteps;
+    f2Delta = (((_e130.xy / vec2(max(_e135.z, 0.05f))) * _e141) / vec2(_e143));
+    let _e147 = f2TexCoord_3;
+    f2Current = _e147;
+    let _e152 = f2Current;
+    let _e153 = sampleSurface(_e152);
+    fSurfaceDepth = _e153.w;
+    loop {
+        let _e158 = i_1;
+        let _e159 = fNumSteps;
+        if !((_e158 < i32(_e159))) {
+            break;
+        }
+        {
+            let _e166 = fCurrentDepth_3;
+            let _e167 = fSurfaceDepth;
+            if (_e166 >= _e167) {
+                {
+                    break;
+                }
+            }
+            let _e169 = f2Current;
+            let _e170 = f2Delta;
+            f2Current = (_e169 - _e170);
+            let _e172 = fCurrentDepth_3;
+            let _e173 = fStepDepth;
+            fCurrentDepth_3 = (_e172 + _e173);
+            let _e176 = f2Current;
+            let _e177 = sampleSurface(_e176);
+            fSurfaceDepth = _e177.w;
+        }
+        continuing {
+            let _e163 = i_1;
+            i_1 = (_e163 + 1i);
+        }
+    }
+    let _e179 = f2Current;
+    let _e180 = f2Delta;
+    f2Previous = (_e179 + _e180);
+    let _e183 = fSurfaceDepth;
+    let _e184 = fCurrentDepth_3;
+    fAfter = (_e183 - _e184);
+    let _e188 = f2Previous;
+    let _e189 = sampleSurface(_e188);
+    let _e191 = fCurrentDepth_3;
+    let _e193 = fStepDepth;
+    fBefore = ((_e189.w - _e191) + _e193);
+    let _e196 = fAfter;
+    let _e197 = fAfter;
+    let _e198 = fBefore;
+    let _e202 = fAfter;
+    let _e203 = fBefore;
+    fWeight = (_e196 / min((_e202 - _e203), -0.00001f));
+    let _e215 = fWeight;
+    let _e219 = f2Current;
+    let _e220 = f2Previous;
+    let _e224 = fWeight;
+    return mix(_e219, _e220, vec2(clamp(_e224, 0f, 1f)));
+}
+
+fn calculateFog(f3PositionW: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_1: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_1 = f3PositionW;
+    let _e48 = uFog;
+    fDensity = _e48.f4Params.x;
+    let _e52 = uFog;
+    let _e56 = uFog;
+    fFalloff = max(_e56.f4Params.y, 0.0001f);
+    let _e62 = uFog;
+    fBaseHeight = _e62.f4Params.z;
+    let _e66 = uFog;
+    fMaxOpacity = _e66.f4Params.w;
+    let _e70 = f3PositionW_1;
+    let _e71 = uCamera;
+    f3Ray = (_e70 - _e71.position.xyz);
+    let _e77 = f3Ray;
+    fDistance = length(_e77);
+    let _e80 = fDensity;
+    let _e83 = fDistance;
+    if ((_e80 <= 0f) || (_e83 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e90 = uFog;
+    if (_e90.f4Range.z < 0.5f) {
+        {
+            let _e95 = fDistance;
+            let _e96 = uFog;
+            let _e100 = uFog;
+            let _e103 = uFog;
+            let _e108 = uFog;
+            let _e111 = uFog;
+            let _e120 = fDistance;
+            let _e121 = uFog;
+            let _e125 = uFog;
+            let _e128 = uFog;
+            let _e133 = uFog;
+            let _e136 = uFog;
+            fOpacity = clamp(((_e120 - _e121.f4Range.x) / max((_e133.f4Range.y - _e136.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e146 = uFog;
+        if (_e146.f4Range.z < 1.5f) {
+            {
+                let _e152 = fDensity;
+                let _e154 = fDistance;
+                let _e155 = uFog;
+                let _e160 = fDistance;
+                let _e161 = uFog;
+                let _e168 = fDensity;
+                let _e170 = fDistance;
+                let _e171 = uFog;
+                let _e176 = fDistance;
+                let _e177 = uFog;
+                fOpacity = (1f - exp((-(_e168) * max((_e176 - _e177.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e186 = fDensity;
+                let _e187 = fFalloff;
+                let _e189 = uCamera;
+                let _e192 = fBaseHeight;
+                let _e195 = fFalloff;
+                let _e197 = uCamera;
+                let _e200 = fBaseHeight;
+                let _e205 = fDistance;
+                fFogAmount = ((_e186 * exp((-(_e195) * (_e197.position.y - _e200)))) * _e205);
+                let _e208 = fFalloff;
+                let _e209 = f3Ray;
+                fRayHeight = (_e208 * _e209.y);
+                let _e214 = fRayHeight;
+                if (abs(_e214) > 0.0001f) {
+                    {
+                        let _e218 = fFogAmount;
+                        let _e220 = fRayHeight;
+                        let _e222 = fRayHeight;
+                        let _e226 = fRayHeight;
+                        fFogAmount = (_e218 * ((1f - exp(-(_e222))) / _e226));
+                    }
+                }
+                let _e230 = fFogAmount;
+                let _e232 = fFogAmount;
+                fOpacity = (1f - exp(-(_e232)));
+            }
+        }
+    }
+    let _e238 = fOpacity;
+    let _e239 = fMaxOpacity;
+    fOpacity = min(_e238, _e239);
+    let _e241 = f3Ray;
+    let _e242 = fDistance;
+    let _e245 = uGlobalLight;
+    let _e248 = uGlobalLight;
+    let _e252 = f3Ray;
+    let _e253 = fDistance;
+    let _e256 = uGlobalLight;
+    let _e259 = uGlobalLight;
+    let _e265 = f3Ray;
+    let _e266 = fDistance;
+    let _e269 = uGlobalLight;
+    let _e272 = uGlobalLight;
+    let _e276 = f3Ray;
+    let _e277 = fDistance;
+    let _e280 = uGlobalLight;
+    let _e283 = uGlobalLight;
+    fSunAmount = max(dot((_e276 / vec3(_e277)), normalize(_e283.f4Direction.xyz)), 0f);
+    let _e291 = uFog;
+    let _e294 = uGlobalLight;
+    f3SunColor = (_e291.f4SunColor.xyz * _e294.f4LightColor.xyz);
+    let _e299 = uFog;
+    let _e304 = uFog;
+    let _e307 = fSunAmount;
+    let _e308 = uFog;
+    let _e312 = uFog;
+    let _e315 = f3SunColor;
+    let _e317 = uFog;
+    let _e320 = fSunAmount;
+    let _e321 = uFog;
+    f3FogColor = mix(_e312.f4Color.xyz, _e315, vec3(pow(_e320, _e321.f4SunColor.w)));
+    let _e328 = f3FogColor;
+    let _e329 = fOpacity;
+    return vec4<f32>(_e328.x, _e328.y, _e328.z, _e329);
+}
+
+fn calculateSpotAttenuation(f3PositionW_2: vec3<f32>) -> f32 {
+    var f3PositionW_3: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e48 = uGlobalLight;
+    if (_e48.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e54 = f3PositionW_3;
+    let _e55 = uGlobalLight;
+    f3ToFragment = (_e54 - _e55.f4Position.xyz);
+    let _e61 = f3ToFragment;
+    fDistance_1 = length(_e61);
+    let _e64 = f3ToFragment;
+    let _e67 = fDistance_1;
+    let _e72 = uGlobalLight;
+    let _e75 = uGlobalLight;
+    let _e80 = f3ToFragment;
+    let _e83 = fDistance_1;
+    let _e88 = uGlobalLight;
+    let _e91 = uGlobalLight;
+    fCosAngle = dot((_e80 / vec3(max(_e83, 0.0001f))), -(normalize(_e91.f4Direction.xyz)));
+    let _e98 = uGlobalLight;
+    let _e101 = uGlobalLight;
+    let _e105 = uGlobalLight;
+    let _e108 = uGlobalLight;
+    let _e111 = fCosAngle;
+    fCone = smoothstep(_e105.f4Cone.y, _e108.f4Cone.x, _e111);
+    let _e115 = fDistance_1;
+    let _e116 = uGlobalLight;
+    let _e120 = uGlobalLight;
+    let _e130 = fDistance_1;
+    let _e131 = uGlobalLight;
+    let _e135 = uGlobalLight;
+    fRange = clamp((1f - (_e130 / max(_e135.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e146 = fCone;
+    let _e147 = fRange;
+    let _e149 = fRange;
+    return ((_e146 * _e147) * _e149);
+}
+
+fn main_1() {
+    var f3NormalW: vec3<f32>;
+    var f3TangentW: vec3<f32>;
+    var f3BitangentW: vec3<f32>;
+    var mtxTBN: mat3x3<f32>;
+    var f3ViewW: vec3<f32>;
+    var f3ViewT_2: vec3<f32>;
+    var f2TexCoord_4: vec2<f32>;
+    var f4Surface: vec4<f32>;
+    var f3SurfaceNormalW: vec3<f32>;
+    var fDiffuse: f32;
+    var f4Albedo: vec4<f32>;
+    var fShadow: f32;
+    var f4Color: vec4<f32>;
+    var f4Fog: vec4<f32>;
+
+    let _e47 = inNormalW_1;
+    f3NormalW = normalize(_e47);
+    let _e50 = inTangentW_1;
+    let _e51 = f3NormalW;
+    let _e54 = f3NormalW;
+    let _e55 = inTangentW_1;
+    let _e59 = inTangentW_1;
+    let _e60 = f3NormalW;
+    let _e63 = f3NormalW;
+    let _e64 = inTangentW_1;
+    f3TangentW = normalize((_e59 - (_e60 * dot(_e63, _e64))));
+    let _e70 = inBitangentW_1;
+    let _e71 = f3NormalW;
+    let _e74 = f3NormalW;
+    let _e75 = inBitangentW_1;
+    let _e79 = f3TangentW;
+    let _e82 = f3TangentW;
+    let _e83 = inBitangentW_1;
+    let _e87 = inBitangentW_1;
+    let _e88 = f3NormalW;
+    let _e91 = f3NormalW;
+    let _e92 = inBitangentW_1;
+    let _e96 = f3TangentW;
+    let _e99 = f3TangentW;
+    let _e100 = inBitangentW_1;
+    f3BitangentW = normalize(((_e87 - (_e88 * dot(_e91, _e92))) - (_e96 * dot(_e99, _e100))));
+    let _e106 = f3TangentW;
+    let _e107 = f3BitangentW;
+    let _e108 = f3NormalW;
+    mtxTBN = mat3x3<f32>(vec3<f32>(_e106.x, _e106.y, _e106.z), vec3<f32>(_e107.x, _e107.y, _e107.z), vec3<f32>(_e108.x, _e108.y, _e108.z));
+    let _e123 = uCamera;
+    let _e126 = inPositionW_1;
+    let _e128 = uCamera;
+    let _e131 = inPositionW_1;
+    f3ViewW = normalize((_e128.position.xyz - _e131));
+    let _e136 = mtxTBN;
+    let _e138 = f3ViewW;
+    let _e141 = mtxTBN;
+    let _e143 = f3ViewW;
+    f3ViewT_2 = normalize((transpose(_e141) * _e143));
+    let _e149 = inTexCoord_1;
+    let _e150 = f3ViewT_2;
+    let _e151 = parallaxOcclusion(_e149, _e150);
+    f2TexCoord_4 = _e151;
+    let _e154 = f2TexCoord_4;
+    let _e155 = sampleSurface(_e154);
+    f4Surface = _e155;
+    let _e157 = mtxTBN;
+    let _e158 = f4Surface;
+    let _e166 = mtxTBN;
+    let _e167 = f4Surface;
+    f3SurfaceNormalW = normalize((_e166 * ((_e167.xyz * 2f) - vec3(1f))));
+    let _e178 = uGlobalLight;
+    let _e181 = uGlobalLight;
+    let _e185 = f3SurfaceNormalW;
+    let _e186 = uGlobalLight;
+    let _e189 = uGlobalLight;
+    let _e196 = uGlobalLight;
+    let _e199 = uGlobalLight;
+    let _e203 = f3SurfaceNormalW;
+    let _e204 = uGlobalLight;
+    let _e207 = uGlobalLight;
+    fDiffuse = max(dot(_e203, normalize(_e207.f4Direction.xyz)), 0f);
+    let _e215 = inColor_1;
+    let _e219 = f4Surface;
+    let _e223 = f4Surface;
+    let _e226 = (_e215.xyz * mix(1f, 0.55f, _e223.w));
+    let _e227 = inColor_1;
+    f4Albedo = vec4<f32>(_e226.x, _e226.y, _e226.z, _e227.w);
+    let _e235 = inLightSpaceFragPosition_1;
+    let _e236 = calculateShadow(_e235);
+    let _e238 = inPositionW_1;
+    let _e239 = calculateSpotAttenuation(_e238);
+    fShadow = (_e236 * _e239);
+    let _e242 = f4Albedo;
+    let _e245 = f4Albedo;
+    let _e246 = fShadow;
+    let _e248 = fDiffuse;
+    f4Color = ((_e242 * 0.2f) + ((_e245 * _e246) * _e248));
+    let _e253 = inPositionW_1;
+    let _e254 = calculateFog(_e253);
+    f4Fog = _e254;
+    let _e256 = f4Color;
+    let _e258 = f4Fog;
+    let _e260 = f4Fog;
+    let _e262 = f4Color;
+    let _e264 = f4Fog;
+    let _e266 = f4Fog;
+    let _e269 = mix(_e262.xyz, _e264.xyz, vec3(_e266.w));
+    let _e270 = inColor_1;
+    outFragColor = vec4<f32>(_e269.x, _e269.y, _e269.z, _e270.w);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>, @location(2) inLightSpaceFragPosition: vec4<f32>, @location(3) inPositionW: vec3<f32>, @location(4) inTangentW: vec3<f32>, @location(5) inBitangentW: vec3<f32>, @location(6) inTexCoord: vec2<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    inLightSpaceFragPosition_1 = inLightSpaceFragPosition;
+    inPositionW_1 = inPositionW;
+    inTangentW_1 = inTangentW;
+    inBitangentW_1 = inBitangentW;
+    inTexCoord_1 = inTexCoord;
+    main_1();
+    let _e74 = outFragColor;
+    return FragmentOutput(_e74);
+}
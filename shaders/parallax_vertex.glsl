@@ -0,0 +1,48 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+layout (location = 1) in vec3 inNormal;
+
+layout (location = 0) out vec4 outColor;
+layout (location = 1) out vec3 outNormalW;
+layout (location = 2) out vec4 outLightSpaceFragPosition;
+layout (location = 3) out vec3 outPositionW;
+layout (location = 4) out vec3 outTangentW;
+layout (location = 5) out vec3 outBitangentW;
+layout (location = 6) out vec2 outTexCoord;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform ObjectUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+    vec4 f4Rim;
+} uEntity;
+
+layout (set = 2, binding = 0) uniform GlobalLightUniformLayout {
+    mat4 mtxProjView;
+    vec4 f4Direction;
+    vec4 f4LightColor;
+} uGlobalLight;
+
+layout (set = 5, binding = 0) uniform ParallaxUniformLayout {
+    vec4 f4Params;
+} uParallax;
+
+void main() {
+    vec4 f4PositionW = uEntity.mtxWorld * vec4(inPosition, 1.0);
+    outColor = uEntity.f4Color;
+    outNormalW = mat3(uEntity.mtxWorld) * inNormal;
+    outLightSpaceFragPosition = uGlobalLight.mtxProjView * f4PositionW;
+    outPositionW = f4PositionW.xyz;
+
+    // The surface lies on the local XZ plane, so the local X and Z axes are the texture axes.
+    outTangentW = mat3(uEntity.mtxWorld) * vec3(1.0, 0.0, 0.0);
+    outBitangentW = mat3(uEntity.mtxWorld) * vec3(0.0, 0.0, 1.0);
+    outTexCoord = inPosition.xz * uParallax.f4Params.w;
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
+}
@@ -0,0 +1,99 @@
+// Generated from `parallax_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+    f4Rim: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+}
+
+struct ParallaxUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outColor: vec4<f32>,
+    @location(1) outNormalW: vec3<f32>,
+    @location(2) outLightSpaceFragPosition: vec4<f32>,
+    @location(3) outPositionW: vec3<f32>,
+    @location(4) outTangentW: vec3<f32>,
+    @location(5) outBitangentW: vec3<f32>,
+    @location(6) outTexCoord: vec2<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> outColor: vec4<f32>;
+var<private> outNormalW: vec3<f32>;
+var<private> outLightSpaceFragPosition: vec4<f32>;
+var<private> outPositionW: vec3<f32>;
+var<private> outTangentW: vec3<f32>;
+var<private> outBitangentW: vec3<f32>;
+var<private> outTexCoord: vec2<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(5) @binding(0) 
+var<uniform> uParallax: ParallaxUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var f4PositionW: vec4<f32>;
+
+    let _e33 = uEntity;
+    let _e35 = inPosition_1;
+    f4PositionW = (_e33.mtxWorld * vec4<f32>(_e35.x, _e35.y, _e35.z, 1f));
+    let _e43 = uEntity;
+    outColor = _e43.f4Color;
+    let _e45 = uEntity;
+    let _e56 = inNormal_1;
+    outNormalW = (mat3x3<f32>(_e45.mtxWorld[0].xyz, _e45.mtxWorld[1].xyz, _e45.mtxWorld[2].xyz) * _e56);
+    let _e58 = uGlobalLight;
+    let _e60 = f4PositionW;
+    outLightSpaceFragPosition = (_e58.mtxProjView * _e60);
+    let _e62 = f4PositionW;
+    outPositionW = _e62.xyz;
+    let _e64 = uEntity;
+    outTangentW = (mat3x3<f32>(_e64.mtxWorld[0].xyz, _e64.mtxWorld[1].xyz, _e64.mtxWorld[2].xyz) * vec3<f32>(1f, 0f, 0f));
+    let _e80 = uEntity;
+    outBitangentW = (mat3x3<f32>(_e80.mtxWorld[0].xyz, _e80.mtxWorld[1].xyz, _e80.mtxWorld[2].xyz) * vec3<f32>(0f, 0f, 1f));
+    let _e96 = inPosition_1;
+    let _e98 = uParallax;
+    outTexCoord = (_e96.xz * _e98.f4Params.w);
+    let _e103 = uCamera;
+    let _e105 = uCamera;
+    let _e108 = f4PositionW;
+    gl_Position = ((_e103.mtxProjection * _e105.mtxView) * _e108);
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    main_1();
+    let _e47 = outColor;
+    let _e49 = outNormalW;
+    let _e51 = outLightSpaceFragPosition;
+    let _e53 = outPositionW;
+    let _e55 = outTangentW;
+    let _e57 = outBitangentW;
+    let _e59 = outTexCoord;
+    let _e61 = gl_Position;
+    return VertexOutput(_e47, _e49, _e51, _e53, _e55, _e57, _e59, _e61);
+}
@@ -0,0 +1,232 @@
+#version 450 core
+
+layout (location = 0) in vec4 inColor;
+layout (location = 1) in vec3 inNormalW;
+layout (location = 2) in vec4 inLightSpaceFragPosition;
+layout (location = 3) in vec3 inPositionW;
+layout (location = 4) in vec4 inRim;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 view;
+    mat4 projection;
+    vec4 position;
+} uCamera;
+
+layout (set = 2, binding = 0) uniform GlobalLightUniformLayout {
+    mat4 mtxProjView;
+    vec4 f4Direction;
+    vec4 f4LightColor;
+    vec4 f4Position;
+    vec4 f4Cone;
+} uGlobalLight;
+
+layout (set = 3, binding = 0) uniform texture2D uShadowMap;
+layout (set = 3, binding = 1) uniform samplerShadow uSampler;
+layout (set = 3, binding = 2) uniform ShadowFilterUniformLayout {
+    vec4 f4Params;
+} uShadowFilter;
+
+layout (set = 4, binding = 0) uniform FogUniformLayout {
+    vec4 f4Color;
+    vec4 f4SunColor;
+    vec4 f4Params;
+    vec4 f4Range;
+} uFog;
+
+#define MAX_LIGHTS 8
+
+struct PhongLightLayout {
+    vec4 f4Position;
+    vec4 f4Color;
+};
+
+layout (set = 5, binding = 0) uniform PbrUniformLayout {
+    vec4 f4Params;
+} uPbr;
+
+layout (set = 6, binding = 0) uniform LightingUniformLayout{
+    PhongLightLayout aLights[MAX_LIGHTS];
+    vec4 f4Params;
+} uLighting;
+
+const vec2 POISSON_DISK[16] = vec2[](
+    vec2(-0.94201624, -0.39906216), vec2(0.94558609, -0.76890725),
+    vec2(-0.09418410, -0.92938870), vec2(0.34495938, 0.29387760),
+    vec2(-0.91588581, 0.45771432), vec2(-0.81544232, -0.87912464),
+    vec2(-0.38277543, 0.27676845), vec2(0.97484398, 0.75648379),
+    vec2(0.44323325, -0.97511554), vec2(0.53742981, -0.47373420),
+    vec2(-0.26496911, -0.41893023), vec2(0.79197514, 0.19090188),
+    vec2(-0.24188840, 0.99706507), vec2(-0.81409955, 0.91437590),
+    vec2(0.19984126, 0.78641367), vec2(0.14383161, -0.14100790)
+);
+
+float sampleShadow(vec2 f2ProjCoords, float fCurrentDepth) {
+    return texture(sampler2DShadow(uShadowMap, uSampler), vec3(f2ProjCoords, fCurrentDepth));
+}
+
+float calculateShadow(vec4 f4LightSpaceFragPosition) {
+    if (f4LightSpaceFragPosition.w <= 0.0) {
+        return 1.0;
+    }
+
+    float fCurrentDepth = f4LightSpaceFragPosition.z / f4LightSpaceFragPosition.w;
+    vec2 f2ProjCoords = f4LightSpaceFragPosition.xy / f4LightSpaceFragPosition.w;
+    f2ProjCoords = f2ProjCoords * vec2(0.5, -0.5) + 0.5;
+
+    // (한국어) 커널의 탭들을 평균하여 그림자의 경계를 부드럽게 만듭니다. 반지름은 텍셀 단위 입니다.
+    // (English Translation) Softens the edges of the shadow by averaging the taps of the kernel. The radius is in texels.
+    int iKernel = int(uShadowFilter.f4Params.x + 0.5);
+    vec2 f2Radius = uShadowFilter.f4Params.y / vec2(textureSize(sampler2DShadow(uShadowMap, uSampler), 0));
+    if (iKernel == 3) {
+        float fSum = 0.0;
+        for (int i = 0; i < 16; ++i) {
+            fSum += sampleShadow(f2ProjCoords + POISSON_DISK[i] * f2Radius, fCurrentDepth);
+        }
+        return fSum / 16.0;
+    }
+    if (iKernel == 1 || iKernel == 2) {
+        float fSum = 0.0;
+        for (int y = -iKernel; y <= iKernel; ++y) {
+            for (int x = -iKernel; x <= iKernel; ++x) {
+                fSum += sampleShadow(f2ProjCoords + vec2(x, y) / float(iKernel) * f2Radius, fCurrentDepth);
+            }
+        }
+        float fWidth = float(2 * iKernel + 1);
+        return fSum / (fWidth * fWidth);
+    }
+    return sampleShadow(f2ProjCoords, fCurrentDepth);
+}
+
+vec3 calculateRim(vec3 f3NormalW, vec3 f3PositionW) {
+    if (dot(inRim.rgb, inRim.rgb) <= 0.0) {
+        return vec3(0.0);
+    }
+
+    vec3 f3View = normalize(uCamera.position.xyz - f3PositionW);
+    float fFresnel = 1.0 - max(dot(normalize(f3NormalW), f3View), 0.0);
+    return inRim.rgb * pow(fFresnel, max(inRim.w, 1e-4));
+}
+
+vec4 calculateFog(vec3 f3PositionW) {
+    float fDensity = uFog.f4Params.x;
+    float fFalloff = max(uFog.f4Params.y, 1e-4);
+    float fBaseHeight = uFog.f4Params.z;
+    float fMaxOpacity = uFog.f4Params.w;
+
+    vec3 f3Ray = f3PositionW - uCamera.position.xyz;
+    float fDistance = length(f3Ray);
+    if (fDensity <= 0.0 || fDistance <= 0.0) {
+        return vec4(0.0);
+    }
+
+    float fOpacity;
+    if (uFog.f4Range.z < 0.5) {
+        // Linear fog between the start and end distances, the density is not used.
+        fOpacity = clamp((fDistance - uFog.f4Range.x) / max(uFog.f4Range.y - uFog.f4Range.x, 1e-3), 0.0, 1.0);
+    } else if (uFog.f4Range.z < 1.5) {
+        // Exponential fog of uniform density past the start distance.
+        fOpacity = 1.0 - exp(-fDensity * max(fDistance - uFog.f4Range.x, 0.0));
+    } else {
+        // Integral of density * exp(-falloff * (height - base)) along the view ray.
+        float fFogAmount = fDensity * exp(-fFalloff * (uCamera.position.y - fBaseHeight)) * fDistance;
+        float fRayHeight = fFalloff * f3Ray.y;
+        if (abs(fRayHeight) > 1e-4) {
+            fFogAmount *= (1.0 - exp(-fRayHeight)) / fRayHeight;
+        }
+        fOpacity = 1.0 - exp(-fFogAmount);
+    }
+    fOpacity = min(fOpacity, fMaxOpacity);
+
+    float fSunAmount = max(dot(f3Ray / fDistance, normalize(uGlobalLight.f4Direction.xyz)), 0.0);
+    vec3 f3SunColor = uFog.f4SunColor.rgb * uGlobalLight.f4LightColor.rgb;
+    vec3 f3FogColor = mix(uFog.f4Color.rgb, f3SunColor, pow(fSunAmount, uFog.f4SunColor.w));
+    return vec4(f3FogColor, fOpacity);
+}
+
+float calculateSpotAttenuation(vec3 f3PositionW) {
+    if (uGlobalLight.f4Position.w <= 0.0) {
+        return 1.0;
+    }
+
+    vec3 f3ToFragment = f3PositionW - uGlobalLight.f4Position.xyz;
+    float fDistance = length(f3ToFragment);
+    float fCosAngle = dot(f3ToFragment / max(fDistance, 1e-4), -normalize(uGlobalLight.f4Direction.xyz));
+    float fCone = smoothstep(uGlobalLight.f4Cone.y, uGlobalLight.f4Cone.x, fCosAngle);
+    float fRange = clamp(1.0 - fDistance / max(uGlobalLight.f4Cone.z, 1e-4), 0.0, 1.0);
+    return fCone * fRange * fRange;
+}
+
+const float PI = 3.14159265359;
+
+float distributionGGX(float fNdotH, float fRoughness) {
+    float fAlpha2 = fRoughness * fRoughness * fRoughness * fRoughness;
+    float fDenom = fNdotH * fNdotH * (fAlpha2 - 1.0) + 1.0;
+    return fAlpha2 / max(PI * fDenom * fDenom, 1e-6);
+}
+
+float geometrySchlickGGX(float fNdotX, float fRoughness) {
+    float fK = (fRoughness + 1.0) * (fRoughness + 1.0) / 8.0;
+    return fNdotX / (fNdotX * (1.0 - fK) + fK);
+}
+
+vec3 fresnelSchlick(float fCosTheta, vec3 f3F0) {
+    return f3F0 + (1.0 - f3F0) * pow(clamp(1.0 - fCosTheta, 0.0, 1.0), 5.0);
+}
+
+// Cook-Torrance reflection of a light coming from the direction f3Light.
+// The light color is treated as the irradiance of a surface facing the light, so the Lambertian term is not divided by PI.
+vec3 calculateCookTorrance(vec3 f3NormalW, vec3 f3View, vec3 f3Light, vec3 f3LightColor, vec3 f3Albedo) {
+    float fNdotL = max(dot(f3NormalW, f3Light), 0.0);
+    if (fNdotL <= 0.0) {
+        return vec3(0.0);
+    }
+
+    float fMetallic = uPbr.f4Params.x;
+    float fRoughness = uPbr.f4Params.y;
+    vec3 f3Half = normalize(f3Light + f3View);
+    float fNdotV = max(dot(f3NormalW, f3View), 1e-4);
+    float fNdotH = max(dot(f3NormalW, f3Half), 0.0);
+
+    vec3 f3F0 = mix(vec3(0.04), f3Albedo, fMetallic);
+    vec3 f3Fresnel = fresnelSchlick(max(dot(f3Half, f3View), 0.0), f3F0);
+    float fGeometry = geometrySchlickGGX(fNdotV, fRoughness) * geometrySchlickGGX(fNdotL, fRoughness);
+    vec3 f3Specular = distributionGGX(fNdotH, fRoughness) * fGeometry * f3Fresnel / (4.0 * fNdotV * fNdotL + 1e-4);
+
+    vec3 f3Diffuse = (1.0 - f3Fresnel) * (1.0 - fMetallic) * f3Albedo;
+    return (f3Diffuse + f3Specular * PI) * f3LightColor * fNdotL;
+}
+
+vec3 calculateLights(vec3 f3NormalW, vec3 f3View, vec3 f3PositionW, vec3 f3Albedo) {
+    vec3 f3Color = vec3(0.0);
+    int iNumLights = min(int(uLighting.f4Params.x), MAX_LIGHTS);
+    for (int i = 0; i < iNumLights; ++i) {
+        vec4 f4Position = uLighting.aLights[i].f4Position;
+        vec4 f4LightColor = uLighting.aLights[i].f4Color;
+        if (f4Position.w <= 0.0) {
+            f3Color += calculateCookTorrance(f3NormalW, f3View, normalize(f4Position.xyz), f4LightColor.rgb, f3Albedo);
+            continue;
+        }
+
+        vec3 f3ToLight = f4Position.xyz - f3PositionW;
+        float fDistance = length(f3ToLight);
+        float fRange = clamp(1.0 - fDistance / max(f4LightColor.w, 1e-4), 0.0, 1.0);
+        f3Color += calculateCookTorrance(f3NormalW, f3View, f3ToLight / max(fDistance, 1e-4), f4LightColor.rgb, f3Albedo) * fRange * fRange;
+    }
+    return f3Color;
+}
+
+void main() {
+    vec3 f3NormalW = normalize(inNormalW);
+    vec3 f3View = normalize(uCamera.position.xyz - inPositionW);
+
+    // The global light casts the shadows, and the lights of the lighting block are added on top of it without shadows.
+    float fShadow = calculateShadow(inLightSpaceFragPosition) * calculateSpotAttenuation(inPositionW);
+    vec3 f3Sun = calculateCookTorrance(f3NormalW, f3View, normalize(uGlobalLight.f4Direction.xyz), uGlobalLight.f4LightColor.rgb, inColor.rgb);
+    vec4 f4Color = vec4(inColor.rgb * uLighting.f4Params.y * uPbr.f4Params.z + f3Sun * fShadow, inColor.a);
+    f4Color.rgb += calculateLights(f3NormalW, f3View, inPositionW, inColor.rgb);
+    f4Color.rgb += calculateRim(inNormalW, inPositionW);
+    vec4 f4Fog = calculateFog(inPositionW);
+    outFragColor = vec4(mix(f4Color.rgb, f4Fog.rgb, f4Fog.a), inColor.a);
+}
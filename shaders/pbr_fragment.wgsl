@@ -0,0 +1,763 @@
+// Generated from `pbr_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    view: mat4x4<f32>,
+    projection: mat4x4<f32>,
+    position: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct PhongLightLayout {
+    f4Position: vec4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct PbrUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct LightingUniformLayout {
+    aLights: array<PhongLightLayout, 8>,
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+const PI: f32 = 3.1415927f;
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> inLightSpaceFragPosition_1: vec4<f32>;
+var<private> inPositionW_1: vec3<f32>;
+var<private> inRim_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(3) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(3) @binding(1) 
+var uSampler: sampler_comparison;
+@group(3) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(4) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+@group(5) @binding(0) 
+var<uniform> uPbr: PbrUniformLayout;
+@group(6) @binding(0) 
+var<uniform> uLighting: LightingUniformLayout;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e51 = f2ProjCoords_1;
+    let _e52 = fCurrentDepth_1;
+    let _e56 = f2ProjCoords_1;
+    let _e57 = fCurrentDepth_1;
+    let _e60 = vec3<f32>(_e56.x, _e56.y, _e57);
+    let _e63 = textureSampleCompare(uShadowMap, uSampler, _e60.xy, _e60.z);
+    return _e63;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e49 = f4LightSpaceFragPosition_1;
+    if (_e49.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e54 = f4LightSpaceFragPosition_1;
+    let _e56 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e54.z / _e56.w);
+    let _e60 = f4LightSpaceFragPosition_1;
+    let _e62 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e60.xy / vec2(_e62.w));
+    let _e67 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e67 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e76 = uShadowFilter;
+    iKernel = i32((_e76.f4Params.x + 0.5f));
+    let _e83 = uShadowFilter;
+    let _e88 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e83.f4Params.y) / vec2<f32>(vec2<i32>(_e88)));
+    let _e94 = iKernel;
+    if (_e94 == 3i) {
+        {
+            loop {
+                let _e101 = i;
+                if !((_e101 < 16i)) {
+                    break;
+                }
+                {
+                    let _e108 = fSum;
+                    let _e109 = f2ProjCoords_2;
+                    let _e110 = i;
+                    let _e114 = local[_e110];
+                    let _e115 = f2Radius;
+                    let _e119 = f2ProjCoords_2;
+                    let _e120 = i;
+                    let _e124 = local_1[_e120];
+                    let _e125 = f2Radius;
+                    let _e128 = fCurrentDepth_2;
+                    let _e129 = sampleShadow((_e119 + (_e124 * _e125)), _e128);
+                    fSum = (_e108 + _e129);
+                }
+                continuing {
+                    let _e105 = i;
+                    i = (_e105 + 1i);
+                }
+            }
+            let _e131 = fSum;
+            return (_e131 / 16f);
+        }
+    }
+    let _e134 = iKernel;
+    let _e137 = iKernel;
+    if ((_e134 == 1i) || (_e137 == 2i)) {
+        {
+            let _e143 = iKernel;
+            y = -(_e143);
+            loop {
+                let _e146 = y;
+                let _e147 = iKernel;
+                if !((_e146 <= _e147)) {
+                    break;
+                }
+                {
+                    let _e153 = iKernel;
+                    x = -(_e153);
+                    loop {
+                        let _e156 = x;
+                        let _e157 = iKernel;
+                        if !((_e156 <= _e157)) {
+                            break;
+                        }
+                        {
+                            let _e163 = fSum_1;
+                            let _e164 = f2ProjCoords_2;
+                            let _e165 = x;
+                            let _e166 = y;
+                            let _e170 = iKernel;
+                            let _e174 = f2Radius;
+                            let _e178 = f2ProjCoords_2;
+                            let _e179 = x;
+                            let _e180 = y;
+                            let _e184 = iKernel;
+                            let _e188 = f2Radius;
+                            let _e191 = fCurrentDepth_2;
+                            let _e192 = sampleShadow((_e178 + ((vec2<f32>(f32(_e179), f32(_e180)) / vec2(f32(_e184))) * _e188)), _e191);
+                            fSum_1 = (_e163 + _e192);
+                        }
+                        continuing {
+                            let _e160 = x;
+                            x = (_e160 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e150 = y;
+                    y = (_e150 + 1i);
+                }
+            }
+            let _e195 = iKernel;
+            fWidth = f32(((2i * _e195) + 1i));
+            let _e201 = fSum_1;
+            let _e202 = fWidth;
+            let _e203 = fWidth;
+            return (_e201 / (_e202 * _e203));
+        }
+    }
+    let _e208 = f2ProjCoords_2;
+    let _e209 = fCurrentDepth_2;
+    let _e210 = sampleShadow(_e208, _e209);
+    return _e210;
+}
+
+fn calculateRim(f3NormalW: vec3<f32>, f3PositionW: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_1: vec3<f32>;
+    var f3PositionW_1: vec3<f32>;
+    var f3View: vec3<f32>;
+    var fFresnel: f32;
+
+    f3NormalW_1 = f3NormalW;
+    f3PositionW_1 = f3PositionW;
+    let _e51 = inRim_1;
+    let _e53 = inRim_1;
+    let _e55 = inRim_1;
+    let _e57 = inRim_1;
+    if (dot(_e55.xyz, _e57.xyz) <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e64 = uCamera;
+    let _e67 = f3PositionW_1;
+    let _e69 = uCamera;
+    let _e72 = f3PositionW_1;
+    f3View = normalize((_e69.position.xyz - _e72));
+    let _e78 = f3NormalW_1;
+    let _e82 = f3NormalW_1;
+    let _e84 = f3View;
+    let _e88 = f3NormalW_1;
+    let _e92 = f3NormalW_1;
+    let _e94 = f3View;
+    fFresnel = (1f - max(dot(normalize(_e92), _e94), 0f));
+    let _e100 = inRim_1;
+    let _e103 = inRim_1;
+    let _e106 = inRim_1;
+    let _e110 = fFresnel;
+    let _e111 = inRim_1;
+    let _e114 = inRim_1;
+    return (_e100.xyz * pow(_e110, max(_e114.w, 0.0001f)));
+}
+
+fn calculateFog(f3PositionW_2: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_3: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e49 = uFog;
+    fDensity = _e49.f4Params.x;
+    let _e53 = uFog;
+    let _e57 = uFog;
+    fFalloff = max(_e57.f4Params.y, 0.0001f);
+    let _e63 = uFog;
+    fBaseHeight = _e63.f4Params.z;
+    let _e67 = uFog;
+    fMaxOpacity = _e67.f4Params.w;
+    let _e71 = f3PositionW_3;
+    let _e72 = uCamera;
+    f3Ray = (_e71 - _e72.position.xyz);
+    let _e78 = f3Ray;
+    fDistance = length(_e78);
+    let _e81 = fDensity;
+    let _e84 = fDistance;
+    if ((_e81 <= 0f) || (_e84 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e91 = uFog;
+    if (_e91.f4Range.z < 0.5f) {
+        {
+            let _e96 = fDistance;
+            let _e97 = uFog;
+            let _e101 = uFog;
+            let _e104 = uFog;
+            let _e109 = uFog;
+            let _e112 = uFog;
+            let _e121 = fDistance;
+            let _e122 = uFog;
+            let _e126 = uFog;
+            let _e129 = uFog;
+            let _e134 = uFog;
+            let _e137 = uFog;
+            fOpacity = clamp(((_e121 - _e122.f4Range.x) / max((_e134.f4Range.y - _e137.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e147 = uFog;
+        if (_e147.f4Range.z < 1.5f) {
+            {
+                let _e153 = fDensity;
+                let _e155 = fDistance;
+                let _e156 = uFog;
+                let _e161 = fDistance;
+                let _e162 = uFog;
+                let _e169 = fDensity;
+                let _e171 = fDistance;
+                let _e172 = uFog;
+                let _e177 = fDistance;
+                let _e178 = uFog;
+                fOpacity = (1f - exp((-(_e169) * max((_e177 - _e178.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e187 = fDensity;
+                let _e188 = fFalloff;
+                let _e190 = uCamera;
+                let _e193 = fBaseHeight;
+                let _e196 = fFalloff;
+                let _e198 = uCamera;
+                let _e201 = fBaseHeight;
+                let _e206 = fDistance;
+                fFogAmount = ((_e187 * exp((-(_e196) * (_e198.position.y - _e201)))) * _e206);
+                let _e209 = fFalloff;
+                let _e210 = f3Ray;
+                fRayHeight = (_e209 * _e210.y);
+                let _e215 = fRayHeight;
+                if (abs(_e215) > 0.0001f) {
+                    {
+                        let _e219 = fFogAmount;
+                        let _e221 = fRayHeight;
+                        let _e223 = fRayHeight;
+                        let _e227 = fRayHeight;
+                        fFogAmount = (_e219 * ((1f - exp(-(_e223))) / _e227));
+                    }
+                }
+                let _e231 = fFogAmount;
+                let _e233 = fFogAmount;
+                fOpacity = (1f - exp(-(_e233)));
+            }
+        }
+    }
+    let _e239 = fOpacity;
+    let _e240 = fMaxOpacity;
+    fOpacity = min(_e239, _e240);
+    let _e242 = f3Ray;
+    let _e243 = fDistance;
+    let _e246 = uGlobalLight;
+    let _e249 = uGlobalLight;
+    let _e253 = f3Ray;
+    let _e254 = fDistance;
+    let _e257 = uGlobalLight;
+    let _e260 = uGlobalLight;
+    let _e266 = f3Ray;
+    let _e267 = fDistance;
+    let _e270 = uGlobalLight;
+    let _e273 = uGlobalLight;
+    let _e277 = f3Ray;
+    let _e278 = fDistance;
+    let _e281 = uGlobalLight;
+    let _e284 = uGlobalLight;
+    fSunAmount = max(dot((_e277 / vec3(_e278)), normalize(_e284.f4Direction.xyz)), 0f);
+    let _e292 = uFog;
+    let _e295 = uGlobalLight;
+    f3SunColor = (_e292.f4SunColor.xyz * _e295.f4LightColor.xyz);
+    let _e300 = uFog;
+    let _e305 = uFog;
+    let _e308 = fSunAmount;
+    let _e309 = uFog;
+    let _e313 = uFog;
+    let _e316 = f3SunColor;
+    let _e318 = uFog;
+    let _e321 = fSunAmount;
+    let _e322 = uFog;
+    f3FogColor = mix(_e313.f4Color.xyz, _e316, vec3(pow(_e321, _e322.f4SunColor.w)));
+    let _e329 = f3FogColor;
+    let _e330 = fOpacity;
+    return vec4<f32>(_e329.x, _e329.y, _e329.z, _e330);
+}
+
+fn calculateSpotAttenuation(f3PositionW_4: vec3<f32>) -> f32 {
+    var f3PositionW_5: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_5 = f3PositionW_4;
+    let _e49 = uGlobalLight;
+    if (_e49.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e55 = f3PositionW_5;
+    let _e56 = uGlobalLight;
+    f3ToFragment = (_e55 - _e56.f4Position.xyz);
+    let _e62 = f3ToFragment;
+    fDistance_1 = length(_e62);
+    let _e65 = f3ToFragment;
+    let _e68 = fDistance_1;
+    let _e73 = uGlobalLight;
+    let _e76 = uGlobalLight;
+    let _e81 = f3ToFragment;
+    let _e84 = fDistance_1;
+    let _e89 = uGlobalLight;
+    let _e92 = uGlobalLight;
+    fCosAngle = dot((_e81 / vec3(max(_e84, 0.0001f))), -(normalize(_e92.f4Direction.xyz)));
+    let _e99 = uGlobalLight;
+    let _e102 = uGlobalLight;
+    let _e106 = uGlobalLight;
+    let _e109 = uGlobalLight;
+    let _e112 = fCosAngle;
+    fCone = smoothstep(_e106.f4Cone.y, _e109.f4Cone.x, _e112);
+    let _e116 = fDistance_1;
+    let _e117 = uGlobalLight;
+    let _e121 = uGlobalLight;
+    let _e131 = fDistance_1;
+    let _e132 = uGlobalLight;
+    let _e136 = uGlobalLight;
+    fRange = clamp((1f - (_e131 / max(_e136.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e147 = fCone;
+    let _e148 = fRange;
+    let _e150 = fRange;
+    return ((_e147 * _e148) * _e150);
+}
+
+fn distributionGGX(fNdotH: f32, fRoughness: f32) -> f32 {
+    var fNdotH_1: f32;
+    var fRoughness_1: f32;
+    var fAlpha2_: f32;
+    var fDenom: f32;
+
+    fNdotH_1 = fNdotH;
+    fRoughness_1 = fRoughness;
+    let _e52 = fRoughness_1;
+    let _e53 = fRoughness_1;
+    let _e55 = fRoughness_1;
+    let _e57 = fRoughness_1;
+    fAlpha2_ = (((_e52 * _e53) * _e55) * _e57);
+    let _e60 = fNdotH_1;
+    let _e61 = fNdotH_1;
+    let _e63 = fAlpha2_;
+    fDenom = (((_e60 * _e61) * (_e63 - 1f)) + 1f);
+    let _e70 = fAlpha2_;
+    let _e71 = fDenom;
+    let _e74 = fDenom;
+    let _e77 = fDenom;
+    let _e80 = fDenom;
+    return (_e70 / max(((PI * _e77) * _e80), 0.000001f));
+}
+
+fn geometrySchlickGGX(fNdotX: f32, fRoughness_2: f32) -> f32 {
+    var fNdotX_1: f32;
+    var fRoughness_3: f32;
+    var fK: f32;
+
+    fNdotX_1 = fNdotX;
+    fRoughness_3 = fRoughness_2;
+    let _e52 = fRoughness_3;
+    let _e55 = fRoughness_3;
+    fK = (((_e52 + 1f) * (_e55 + 1f)) / 8f);
+    let _e62 = fNdotX_1;
+    let _e63 = fNdotX_1;
+    let _e65 = fK;
+    let _e68 = fK;
+    return (_e62 / ((_e63 * (1f - _e65)) + _e68));
+}
+
+fn fresnelSchlick(fCosTheta: f32, f3F0_: vec3<f32>) -> vec3<f32> {
+    var fCosTheta_1: f32;
+    var f3F0_1: vec3<f32>;
+
+    fCosTheta_1 = fCosTheta;
+    f3F0_1 = f3F0_;
+    let _e52 = f3F0_1;
+    let _e54 = f3F0_1;
+    let _e58 = fCosTheta_1;
+    let _e63 = fCosTheta_1;
+    let _e70 = fCosTheta_1;
+    let _e75 = fCosTheta_1;
+    return (_e52 + ((vec3(1f) - _e54) * pow(clamp((1f - _e75), 0f, 1f), 5f)));
+}
+
+fn calculateCookTorrance(f3NormalW_2: vec3<f32>, f3View_1: vec3<f32>, f3Light: vec3<f32>, f3LightColor: vec3<f32>, f3Albedo: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_3: vec3<f32>;
+    var f3View_2: vec3<f32>;
+    var f3Light_1: vec3<f32>;
+    var f3LightColor_1: vec3<f32>;
+    var f3Albedo_1: vec3<f32>;
+    var fNdotL: f32;
+    var fMetallic: f32;
+    var fRoughness_4: f32;
+    var f3Half: vec3<f32>;
+    var fNdotV: f32;
+    var fNdotH_2: f32;
+    var f3F0_2: vec3<f32>;
+    var f3Fresnel: vec3<f32>;
+    var fGeometry: f32;
+    var f3Specular: vec3<f32>;
+    var f3Diffuse: vec3<f32>;
+
+    f3NormalW_3 = f3NormalW_2;
+    f3View_2 = f3View_1;
+    f3Light_1 = f3Light;
+    f3LightColor_1 = f3LightColor;
+    f3Albedo_1 = f3Albedo;
+    let _e60 = f3NormalW_3;
+    let _e61 = f3Light_1;
+    let _e66 = f3NormalW_3;
+    let _e67 = f3Light_1;
+    fNdotL = max(dot(_e66, _e67), 0f);
+    let _e72 = fNdotL;
+    if (_e72 <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e77 = uPbr;
+    fMetallic = _e77.f4Params.x;
+    let _e81 = uPbr;
+    fRoughness_4 = _e81.f4Params.y;
+    let _e85 = f3Light_1;
+    let _e86 = f3View_2;
+    let _e88 = f3Light_1;
+    let _e89 = f3View_2;
+    f3Half = normalize((_e88 + _e89));
+    let _e95 = f3NormalW_3;
+    let _e96 = f3View_2;
+    let _e101 = f3NormalW_3;
+    let _e102 = f3View_2;
+    fNdotV = max(dot(_e101, _e102), 0.0001f);
+    let _e109 = f3NormalW_3;
+    let _e110 = f3Half;
+    let _e115 = f3NormalW_3;
+    let _e116 = f3Half;
+    fNdotH_2 = max(dot(_e115, _e116), 0f);
+    let _e127 = f3Albedo_1;
+    let _e128 = fMetallic;
+    f3F0_2 = mix(vec3(0.04f), _e127, vec3(_e128));
+    let _e134 = f3Half;
+    let _e135 = f3View_2;
+    let _e140 = f3Half;
+    let _e141 = f3View_2;
+    let _e148 = f3Half;
+    let _e149 = f3View_2;
+    let _e154 = f3Half;
+    let _e155 = f3View_2;
+    let _e159 = f3F0_2;
+    let _e160 = fresnelSchlick(max(dot(_e154, _e155), 0f), _e159);
+    f3Fresnel = _e160;
+    let _e164 = fNdotV;
+    let _e165 = fRoughness_4;
+    let _e166 = geometrySchlickGGX(_e164, _e165);
+    let _e169 = fNdotL;
+    let _e170 = fRoughness_4;
+    let _e171 = geometrySchlickGGX(_e169, _e170);
+    fGeometry = (_e166 * _e171);
+    let _e176 = fNdotH_2;
+    let _e177 = fRoughness_4;
+    let _e178 = distributionGGX(_e176, _e177);
+    let _e179 = fGeometry;
+    let _e181 = f3Fresnel;
+    let _e184 = fNdotV;
+    let _e186 = fNdotL;
+    f3Specular = (((_e178 * _e179) * _e181) / vec3((((4f * _e184) * _e186) + 0.0001f)));
+    let _e194 = f3Fresnel;
+    let _e198 = fMetallic;
+    let _e201 = f3Albedo_1;
+    f3Diffuse = (((vec3(1f) - _e194) * (1f - _e198)) * _e201);
+    let _e204 = f3Diffuse;
+    let _e205 = f3Specular;
+    let _e208 = f3LightColor_1;
+    let _e210 = fNdotL;
+    return (((_e204 + (_e205 * PI)) * _e208) * _e210);
+}
+
+fn calculateLights(f3NormalW_4: vec3<f32>, f3View_3: vec3<f32>, f3PositionW_6: vec3<f32>, f3Albedo_2: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_5: vec3<f32>;
+    var f3View_4: vec3<f32>;
+    var f3PositionW_7: vec3<f32>;
+    var f3Albedo_3: vec3<f32>;
+    var f3Color: vec3<f32> = vec3(0f);
+    var iNumLights: i32;
+    var i_1: i32 = 0i;
+    var f4Position: vec4<f32>;
+    var f4LightColor: vec4<f32>;
+    var f3ToLight: vec3<f32>;
+    var fDistance_2: f32;
+    var fRange_1: f32;
+
+    f3NormalW_5 = f3NormalW_4;
+    f3View_4 = f3View_3;
+    f3PositionW_7 = f3PositionW_6;
+    f3Albedo_3 = f3Albedo_2;
+    let _e59 = uLighting;
+    let _e64 = uLighting;
+    iNumLights = min(i32(_e64.f4Params.x), 8i);
+    loop {
+        let _e73 = i_1;
+        let _e74 = iNumLights;
+        if !((_e73 < _e74)) {
+            break;
+        }
+        {
+            let _e80 = i_1;
+            let _e83 = uLighting.aLights[_e80];
+            f4Position = _e83.f4Position;
+            let _e86 = i_1;
+            let _e89 = uLighting.aLights[_e86];
+            f4LightColor = _e89.f4Color;
+            let _e92 = f4Position;
+            if (_e92.w <= 0f) {
+                {
+                    let _e96 = f3Color;
+                    let _e99 = f4Position;
+                    let _e101 = f4Position;
+                    let _e104 = f4LightColor;
+                    let _e107 = f3NormalW_5;
+                    let _e108 = f3View_4;
+                    let _e109 = f4Position;
+                    let _e111 = f4Position;
+                    let _e114 = f4LightColor;
+                    let _e116 = f3Albedo_3;
+                    let _e117 = calculateCookTorrance(_e107, _e108, normalize(_e111.xyz), _e114.xyz, _e116);
+                    f3Color = (_e96 + _e117);
+                    continue;
+                }
+            }
+            let _e119 = f4Position;
+            let _e121 = f3PositionW_7;
+            f3ToLight = (_e119.xyz - _e121);
+            let _e125 = f3ToLight;
+            fDistance_2 = length(_e125);
+            let _e129 = fDistance_2;
+            let _e130 = f4LightColor;
+            let _e133 = f4LightColor;
+            let _e142 = fDistance_2;
+            let _e143 = f4LightColor;
+            let _e146 = f4LightColor;
+            fRange_1 = clamp((1f - (_e142 / max(_e146.w, 0.0001f))), 0f, 1f);
+            let _e156 = f3Color;
+            let _e159 = f3ToLight;
+            let _e162 = fDistance_2;
+            let _e167 = f4LightColor;
+            let _e170 = f3NormalW_5;
+            let _e171 = f3View_4;
+            let _e172 = f3ToLight;
+            let _e175 = fDistance_2;
+            let _e180 = f4LightColor;
+            let _e182 = f3Albedo_3;
+            let _e183 = calculateCookTorrance(_e170, _e171, (_e172 / vec3(max(_e175, 0.0001f))), _e180.xyz, _e182);
+            let _e184 = fRange_1;
+            let _e186 = fRange_1;
+            f3Color = (_e156 + ((_e183 * _e184) * _e186));
+        }
+        continuing {
+            let _e77 = i_1;
+            i_1 = (_e77 + 1i);
+        }
+    }
+    let _e189 = f3Color;
+    return _e189;
+}
+
+fn main_1() {
+    var f3NormalW_6: vec3<f32>;
+    var f3View_5: vec3<f32>;
+    var fShadow: f32;
+    var f3Sun: vec3<f32>;
+    var f4Color: vec4<f32>;
+    var f4Fog: vec4<f32>;
+
+    let _e49 = inNormalW_1;
+    f3NormalW_6 = normalize(_e49);
+    let _e52 = uCamera;
+    let _e55 = inPositionW_1;
+    let _e57 = uCamera;
+    let _e60 = inPositionW_1;
+    f3View_5 = normalize((_e57.position.xyz - _e60));
+    let _e65 = inLightSpaceFragPosition_1;
+    let _e66 = calculateShadow(_e65);
+    let _e68 = inPositionW_1;
+    let _e69 = calculateSpotAttenuation(_e68);
+    fShadow = (_e66 * _e69);
+    let _e74 = uGlobalLight;
+    let _e77 = uGlobalLight;
+    let _e81 = uGlobalLight;
+    let _e84 = inColor_1;
+    let _e86 = f3NormalW_6;
+    let _e87 = f3View_5;
+    let _e88 = uGlobalLight;
+    let _e91 = uGlobalLight;
+    let _e95 = uGlobalLight;
+    let _e98 = inColor_1;
+    let _e100 = calculateCookTorrance(_e86, _e87, normalize(_e91.f4Direction.xyz), _e95.f4LightColor.xyz, _e98.xyz);
+    f3Sun = _e100;
+    let _e102 = inColor_1;
+    let _e104 = uLighting;
+    let _e108 = uPbr;
+    let _e112 = f3Sun;
+    let _e113 = fShadow;
+    let _e115 = (((_e102.xyz * _e104.f4Params.y) * _e108.f4Params.z) + (_e112 * _e113));
+    let _e116 = inColor_1;
+    f4Color = vec4<f32>(_e115.x, _e115.y, _e115.z, _e116.w);
+    let _e123 = f4Color;
+    let _e125 = f4Color;
+    let _e130 = inColor_1;
+    let _e132 = f3NormalW_6;
+    let _e133 = f3View_5;
+    let _e134 = inPositionW_1;
+    let _e135 = inColor_1;
+    let _e137 = calculateLights(_e132, _e133, _e134, _e135.xyz);
+    let _e138 = (_e125.xyz + _e137);
+    f4Color.x = _e138.x;
+    f4Color.y = _e138.y;
+    f4Color.z = _e138.z;
+    let _e145 = f4Color;
+    let _e147 = f4Color;
+    let _e151 = inNormalW_1;
+    let _e152 = inPositionW_1;
+    let _e153 = calculateRim(_e151, _e152);
+    let _e154 = (_e147.xyz + _e153);
+    f4Color.x = _e154.x;
+    f4Color.y = _e154.y;
+    f4Color.z = _e154.z;
+    let _e162 = inPositionW_1;
+    let _e163 = calculateFog(_e162);
+    f4Fog = _e163;
+    let _e165 = f4Color;
+    let _e167 = f4Fog;
+    let _e169 = f4Fog;
+    let _e171 = f4Color;
+    let _e173 = f4Fog;
+    let _e175 = f4Fog;
+    let _e178 = mix(_e171.xyz, _e173.xyz, vec3(_e175.w));
+    let _e179 = inColor_1;
+    outFragColor = vec4<f32>(_e178.x, _e178.y, _e178.z, _e179.w);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>, @location(2) inLightSpaceFragPosition: vec4<f32>, @location(3) inPositionW: vec3<f32>, @location(4) inRim: vec4<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    inLightSpaceFragPosition_1 = inLightSpaceFragPosition;
+    inPositionW_1 = inPositionW;
+    inRim_1 = inRim;
+    main_1();
+    let _e69 = outFragColor;
+    return FragmentOutput(_e69);
+}
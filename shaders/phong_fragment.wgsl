@@ -0,0 +1,657 @@
+// Generated from `phong_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    view: mat4x4<f32>,
+    projection: mat4x4<f32>,
+    position: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct PhongLightLayout {
+    f4Position: vec4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct LightingUniformLayout {
+    aLights: array<PhongLightLayout, 8>,
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> inLightSpaceFragPosition_1: vec4<f32>;
+var<private> inPositionW_1: vec3<f32>;
+var<private> inRim_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(3) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(3) @binding(1) 
+var uSampler: sampler_comparison;
+@group(3) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(4) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+@group(5) @binding(0) 
+var<uniform> uLighting: LightingUniformLayout;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e48 = f2ProjCoords_1;
+    let _e49 = fCurrentDepth_1;
+    let _e53 = f2ProjCoords_1;
+    let _e54 = fCurrentDepth_1;
+    let _e57 = vec3<f32>(_e53.x, _e53.y, _e54);
+    let _e60 = textureSampleCompare(uShadowMap, uSampler, _e57.xy, _e57.z);
+    return _e60;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e46 = f4LightSpaceFragPosition_1;
+    if (_e46.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e51 = f4LightSpaceFragPosition_1;
+    let _e53 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e51.z / _e53.w);
+    let _e57 = f4LightSpaceFragPosition_1;
+    let _e59 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e57.xy / vec2(_e59.w));
+    let _e64 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e64 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e73 = uShadowFilter;
+    iKernel = i32((_e73.f4Params.x + 0.5f));
+    let _e80 = uShadowFilter;
+    let _e85 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e80.f4Params.y) / vec2<f32>(vec2<i32>(_e85)));
+    let _e91 = iKernel;
+    if (_e91 == 3i) {
+        {
+            loop {
+                let _e98 = i;
+                if !((_e98 < 16i)) {
+                    break;
+                }
+                {
+                    let _e105 = fSum;
+                    let _e106 = f2ProjCoords_2;
+                    let _e107 = i;
+                    let _e111 = local[_e107];
+                    let _e112 = f2Radius;
+                    let _e116 = f2ProjCoords_2;
+                    let _e117 = i;
+                    let _e121 = local_1[_e117];
+                    let _e122 = f2Radius;
+                    let _e125 = fCurrentDepth_2;
+                    let _e126 = sampleShadow((_e116 + (_e121 * _e122)), _e125);
+                    fSum = (_e105 + _e126);
+                }
+                continuing {
+                    let _e102 = i;
+                    i = (_e102 + 1i);
+                }
+            }
+            let _e128 = fSum;
+            return (_e128 / 16f);
+        }
+    }
+    let _e131 = iKernel;
+    let _e134 = iKernel;
+    if ((_e131 == 1i) || (_e134 == 2i)) {
+        {
+            let _e140 = iKernel;
+            y = -(_e140);
+            loop {
+                let _e143 = y;
+                let _e144 = iKernel;
+                if !((_e143 <= _e144)) {
+                    break;
+                }
+                {
+                    let _e150 = iKernel;
+                    x = -(_e150);
+                    loop {
+                        let _e153 = x;
+                        let _e154 = iKernel;
+                        if !((_e153 <= _e154)) {
+                            break;
+                        }
+                        {
+                            let _e160 = fSum_1;
+                            let _e161 = f2ProjCoords_2;
+                            let _e162 = x;
+                            let _e163 = y;
+                            let _e167 = iKernel;
+                            let _e171 = f2Radius;
+                            let _e175 = f2ProjCoords_2;
+                            let _e176 = x;
+                            let _e177 = y;
+                            let _e181 = iKernel;
+                            let _e185 = f2Radius;
+                            let _e188 = fCurrentDepth_2;
+                            let _e189 = sampleShadow((_e175 + ((vec2<f32>(f32(_e176), f32(_e177)) / vec2(f32(_e181))) * _e185)), _e188);
+                            fSum_1 = (_e160 + _e189);
+                        }
+                        continuing {
+                            let _e157 = x;
+                            x = (_e157 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e147 = y;
+                    y = (_e147 + 1i);
+                }
+            }
+            let _e192 = iKernel;
+            fWidth = f32(((2i * _e192) + 1i));
+            let _e198 = fSum_1;
+            let _e199 = fWidth;
+            let _e200 = fWidth;
+            return (_e198 / (_e199 * _e200));
+        }
+    }
+    let _e205 = f2ProjCoords_2;
+    let _e206 = fCurrentDepth_2;
+    let _e207 = sampleShadow(_e205, _e206);
+    return _e207;
+}
+
+fn calculateRim(f3NormalW: vec3<f32>, f3PositionW: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_1: vec3<f32>;
+    var f3PositionW_1: vec3<f32>;
+    var f3View: vec3<f32>;
+    var fFresnel: f32;
+
+    f3NormalW_1 = f3NormalW;
+    f3PositionW_1 = f3PositionW;
+    let _e48 = inRim_1;
+    let _e50 = inRim_1;
+    let _e52 = inRim_1;
+    let _e54 = inRim_1;
+    if (dot(_e52.xyz, _e54.xyz) <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e61 = uCamera;
+    let _e64 = f3PositionW_1;
+    let _e66 = uCamera;
+    let _e69 = f3PositionW_1;
+    f3View = normalize((_e66.position.xyz - _e69));
+    let _e75 = f3NormalW_1;
+    let _e79 = f3NormalW_1;
+    let _e81 = f3View;
+    let _e85 = f3NormalW_1;
+    let _e89 = f3NormalW_1;
+    let _e91 = f3View;
+    fFresnel = (1f - max(dot(normalize(_e89), _e91), 0f));
+    let _e97 = inRim_1;
+    let _e100 = inRim_1;
+    let _e103 = inRim_1;
+    let _e107 = fFresnel;
+    let _e108 = inRim_1;
+    let _e111 = inRim_1;
+    return (_e97.xyz * pow(_e107, max(_e111.w, 0.0001f)));
+}
+
+fn calculateFog(f3PositionW_2: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_3: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e46 = uFog;
+    fDensity = _e46.f4Params.x;
+    let _e50 = uFog;
+    let _e54 = uFog;
+    fFalloff = max(_e54.f4Params.y, 0.0001f);
+    let _e60 = uFog;
+    fBaseHeight = _e60.f4Params.z;
+    let _e64 = uFog;
+    fMaxOpacity = _e64.f4Params.w;
+    let _e68 = f3PositionW_3;
+    let _e69 = uCamera;
+    f3Ray = (_e68 - _e69.position.xyz);
+    let _e75 = f3Ray;
+    fDistance = length(_e75);
+    let _e78 = fDensity;
+    let _e81 = fDistance;
+    if ((_e78 <= 0f) || (_e81 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e88 = uFog;
+    if (_e88.f4Range.z < 0.5f) {
+        {
+            let _e93 = fDistance;
+            let _e94 = uFog;
+            let _e98 = uFog;
+            let _e101 = uFog;
+            let _e106 = uFog;
+            let _e109 = uFog;
+            let _e118 = fDistance;
+            let _e119 = uFog;
+            let _e123 = uFog;
+            let _e126 = uFog;
+            let _e131 = uFog;
+            let _e134 = uFog;
+            fOpacity = clamp(((_e118 - _e119.f4Range.x) / max((_e131.f4Range.y - _e134.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e144 = uFog;
+        if (_e144.f4Range.z < 1.5f) {
+            {
+                let _e150 = fDensity;
+                let _e152 = fDistance;
+                let _e153 = uFog;
+                let _e158 = fDistance;
+                let _e159 = uFog;
+                let _e166 = fDensity;
+                let _e168 = fDistance;
+                let _e169 = uFog;
+                let _e174 = fDistance;
+                let _e175 = uFog;
+                fOpacity = (1f - exp((-(_e166) * max((_e174 - _e175.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e184 = fDensity;
+                let _e185 = fFalloff;
+                let _e187 = uCamera;
+                let _e190 = fBaseHeight;
+                let _e193 = fFalloff;
+                let _e195 = uCamera;
+                let _e198 = fBaseHeight;
+                let _e203 = fDistance;
+                fFogAmount = ((_e184 * exp((-(_e193) * (_e195.position.y - _e198)))) * _e203);
+                let _e206 = fFalloff;
+                let _e207 = f3Ray;
+                fRayHeight = (_e206 * _e207.y);
+                let _e212 = fRayHeight;
+                if (abs(_e212) > 0.0001f) {
+                    {
+                        let _e216 = fFogAmount;
+                        let _e218 = fRayHeight;
+                        let _e220 = fRayHeight;
+                        let _e224 = fRayHeight;
+                        fFogAmount = (_e216 * ((1f - exp(-(_e220))) / _e224));
+                    }
+                }
+                let _e228 = fFogAmount;
+                let _e230 = fFogAmount;
+                fOpacity = (1f - exp(-(_e230)));
+            }
+        }
+    }
+    let _e236 = fOpacity;
+    let _e237 = fMaxOpacity;
+    fOpacity = min(_e236, _e237);
+    let _e239 = f3Ray;
+    let _e240 = fDistance;
+    let _e243 = uGlobalLight;
+    let _e246 = uGlobalLight;
+    let _e250 = f3Ray;
+    let _e251 = fDistance;
+    let _e254 = uGlobalLight;
+    let _e257 = uGlobalLight;
+    let _e263 = f3Ray;
+    let _e264 = fDistance;
+    let _e267 = uGlobalLight;
+    let _e270 = uGlobalLight;
+    let _e274 = f3Ray;
+    let _e275 = fDistance;
+    let _e278 = uGlobalLight;
+    let _e281 = uGlobalLight;
+    fSunAmount = max(dot((_e274 / vec3(_e275)), normalize(_e281.f4Direction.xyz)), 0f);
+    let _e289 = uFog;
+    let _e292 = uGlobalLight;
+    f3SunColor = (_e289.f4SunColor.xyz * _e292.f4LightColor.xyz);
+    let _e297 = uFog;
+    let _e302 = uFog;
+    let _e305 = fSunAmount;
+    let _e306 = uFog;
+    let _e310 = uFog;
+    let _e313 = f3SunColor;
+    let _e315 = uFog;
+    let _e318 = fSunAmount;
+    let _e319 = uFog;
+    f3FogColor = mix(_e310.f4Color.xyz, _e313, vec3(pow(_e318, _e319.f4SunColor.w)));
+    let _e326 = f3FogColor;
+    let _e327 = fOpacity;
+    return vec4<f32>(_e326.x, _e326.y, _e326.z, _e327);
+}
+
+fn calculateSpotAttenuation(f3PositionW_4: vec3<f32>) -> f32 {
+    var f3PositionW_5: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_5 = f3PositionW_4;
+    let _e46 = uGlobalLight;
+    if (_e46.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e52 = f3PositionW_5;
+    let _e53 = uGlobalLight;
+    f3ToFragment = (_e52 - _e53.f4Position.xyz);
+    let _e59 = f3ToFragment;
+    fDistance_1 = length(_e59);
+    let _e62 = f3ToFragment;
+    let _e65 = fDistance_1;
+    let _e70 = uGlobalLight;
+    let _e73 = uGlobalLight;
+    let _e78 = f3ToFragment;
+    let _e81 = fDistance_1;
+    let _e86 = uGlobalLight;
+    let _e89 = uGlobalLight;
+    fCosAngle = dot((_e78 / vec3(max(_e81, 0.0001f))), -(normalize(_e89.f4Direction.xyz)));
+    let _e96 = uGlobalLight;
+    let _e99 = uGlobalLight;
+    let _e103 = uGlobalLight;
+    let _e106 = uGlobalLight;
+    let _e109 = fCosAngle;
+    fCone = smoothstep(_e103.f4Cone.y, _e106.f4Cone.x, _e109);
+    let _e113 = fDistance_1;
+    let _e114 = uGlobalLight;
+    let _e118 = uGlobalLight;
+    let _e128 = fDistance_1;
+    let _e129 = uGlobalLight;
+    let _e133 = uGlobalLight;
+    fRange = clamp((1f - (_e128 / max(_e133.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e144 = fCone;
+    let _e145 = fRange;
+    let _e147 = fRange;
+    return ((_e144 * _e145) * _e147);
+}
+
+fn calculateBlinnPhong(f3NormalW_2: vec3<f32>, f3View_1: vec3<f32>, f3Light: vec3<f32>, f3LightColor: vec3<f32>, f3Albedo: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_3: vec3<f32>;
+    var f3View_2: vec3<f32>;
+    var f3Light_1: vec3<f32>;
+    var f3LightColor_1: vec3<f32>;
+    var f3Albedo_1: vec3<f32>;
+    var fDiffuse: f32;
+    var f3Half: vec3<f32>;
+    var fSpecular: f32;
+
+    f3NormalW_3 = f3NormalW_2;
+    f3View_2 = f3View_1;
+    f3Light_1 = f3Light;
+    f3LightColor_1 = f3LightColor;
+    f3Albedo_1 = f3Albedo;
+    let _e56 = f3NormalW_3;
+    let _e57 = f3Light_1;
+    let _e62 = f3NormalW_3;
+    let _e63 = f3Light_1;
+    fDiffuse = max(dot(_e62, _e63), 0f);
+    let _e68 = fDiffuse;
+    if (_e68 <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e73 = f3Light_1;
+    let _e74 = f3View_2;
+    let _e76 = f3Light_1;
+    let _e77 = f3View_2;
+    f3Half = normalize((_e76 + _e77));
+    let _e83 = f3NormalW_3;
+    let _e84 = f3Half;
+    let _e89 = f3NormalW_3;
+    let _e90 = f3Half;
+    let _e94 = uLighting;
+    let _e98 = uLighting;
+    let _e105 = f3NormalW_3;
+    let _e106 = f3Half;
+    let _e111 = f3NormalW_3;
+    let _e112 = f3Half;
+    let _e116 = uLighting;
+    let _e120 = uLighting;
+    let _e126 = uLighting;
+    fSpecular = (pow(max(dot(_e111, _e112), 0f), max(_e120.f4Params.z, 1f)) * _e126.f4Params.w);
+    let _e131 = f3LightColor_1;
+    let _e132 = f3Albedo_1;
+    let _e133 = fDiffuse;
+    let _e135 = fSpecular;
+    return (_e131 * ((_e132 * _e133) + vec3(_e135)));
+}
+
+fn calculateLights(f3NormalW_4: vec3<f32>, f3View_3: vec3<f32>, f3PositionW_6: vec3<f32>, f3Albedo_2: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_5: vec3<f32>;
+    var f3View_4: vec3<f32>;
+    var f3PositionW_7: vec3<f32>;
+    var f3Albedo_3: vec3<f32>;
+    var f3Color: vec3<f32> = vec3(0f);
+    var iNumLights: i32;
+    var i_1: i32 = 0i;
+    var f4Position: vec4<f32>;
+    var f4LightColor: vec4<f32>;
+    var f3ToLight: vec3<f32>;
+    var fDistance_2: f32;
+    var fRange_1: f32;
+
+    f3NormalW_5 = f3NormalW_4;
+    f3View_4 = f3View_3;
+    f3PositionW_7 = f3PositionW_6;
+    f3Albedo_3 = f3Albedo_2;
+    let _e55 = uLighting;
+    let _e60 = uLighting;
+    iNumLights = min(i32(_e60.f4Params.x), 8i);
+    loop {
+        let _e69 = i_1;
+        let _e70 = iNumLights;
+        if !((_e69 < _e70)) {
+            break;
+        }
+        {
+            let _e76 = i_1;
+            let _e79 = uLighting.aLights[_e76];
+            f4Position = _e79.f4Position;
+            let _e82 = i_1;
+            let _e85 = uLighting.aLights[_e82];
+            f4LightColor = _e85.f4Color;
+            let _e88 = f4Position;
+            if (_e88.w <= 0f) {
+                {
+                    let _e92 = f3Color;
+                    let _e95 = f4Position;
+                    let _e97 = f4Position;
+                    let _e100 = f4LightColor;
+                    let _e103 = f3NormalW_5;
+                    let _e104 = f3View_4;
+                    let _e105 = f4Position;
+                    let _e107 = f4Position;
+                    let _e110 = f4LightColor;
+                    let _e112 = f3Albedo_3;
+                    let _e113 = calculateBlinnPhong(_e103, _e104, normalize(_e107.xyz), _e110.xyz, _e112);
+                    f3Color = (_e92 + _e113);
+                    continue;
+                }
+            }
+            let _e115 = f4Position;
+            let _e117 = f3PositionW_7;
+            f3ToLight = (_e115.xyz - _e117);
+            let _e121 = f3ToLight;
+            fDistance_2 = length(_e121);
+            let _e125 = fDistance_2;
+            let _e126 = f4LightColor;
+            let _e129 = f4LightColor;
+            let _e138 = fDistance_2;
+            let _e139 = f4LightColor;
+            let _e142 = f4LightColor;
+            fRange_1 = clamp((1f - (_e138 / max(_e142.w, 0.0001f))), 0f, 1f);
+            let _e152 = f3Color;
+            let _e155 = f3ToLight;
+            let _e158 = fDistance_2;
+            let _e163 = f4LightColor;
+            let _e166 = f3NormalW_5;
+            let _e167 = f3View_4;
+            let _e168 = f3ToLight;
+            let _e171 = fDistance_2;
+            let _e176 = f4LightColor;
+            let _e178 = f3Albedo_3;
+            let _e179 = calculateBlinnPhong(_e166, _e167, (_e168 / vec3(max(_e171, 0.0001f))), _e176.xyz, _e178);
+            let _e180 = fRange_1;
+            let _e182 = fRange_1;
+            f3Color = (_e152 + ((_e179 * _e180) * _e182));
+        }
+        continuing {
+            let _e73 = i_1;
+            i_1 = (_e73 + 1i);
+        }
+    }
+    let _e185 = f3Color;
+    return _e185;
+}
+
+fn main_1() {
+    var f3NormalW_6: vec3<f32>;
+    var f3View_5: vec3<f32>;
+    var fShadow: f32;
+    var f3Sun: vec3<f32>;
+    var f4Color: vec4<f32>;
+    var f4Fog: vec4<f32>;
+
+    let _e45 = inNormalW_1;
+    f3NormalW_6 = normalize(_e45);
+    let _e48 = uCamera;
+    let _e51 = inPositionW_1;
+    let _e53 = uCamera;
+    let _e56 = inPositionW_1;
+    f3View_5 = normalize((_e53.position.xyz - _e56));
+    let _e61 = inLightSpaceFragPosition_1;
+    let _e62 = calculateShadow(_e61);
+    let _e64 = inPositionW_1;
+    let _e65 = calculateSpotAttenuation(_e64);
+    fShadow = (_e62 * _e65);
+    let _e70 = uGlobalLight;
+    let _e73 = uGlobalLight;
+    let _e77 = uGlobalLight;
+    let _e80 = inColor_1;
+    let _e82 = f3NormalW_6;
+    let _e83 = f3View_5;
+    let _e84 = uGlobalLight;
+    let _e87 = uGlobalLight;
+    let _e91 = uGlobalLight;
+    let _e94 = inColor_1;
+    let _e96 = calculateBlinnPhong(_e82, _e83, normalize(_e87.f4Direction.xyz), _e91.f4LightColor.xyz, _e94.xyz);
+    f3Sun = _e96;
+    let _e98 = inColor_1;
+    let _e100 = uLighting;
+    let _e104 = f3Sun;
+    let _e105 = fShadow;
+    let _e107 = ((_e98.xyz * _e100.f4Params.y) + (_e104 * _e105));
+    let _e108 = inColor_1;
+    f4Color = vec4<f32>(_e107.x, _e107.y, _e107.z, _e108.w);
+    let _e115 = f4Color;
+    let _e117 = f4Color;
+    let _e122 = inColor_1;
+    let _e124 = f3NormalW_6;
+    let _e125 = f3View_5;
+    let _e126 = inPositionW_1;
+    let _e127 = inColor_1;
+    let _e129 = calculateLights(_e124, _e125, _e126, _e127.xyz);
+    let _e130 = (_e117.xyz + _e129);
+    f4Color.x = _e130.x;
+    f4Color.y = _e130.y;
+    f4Color.z = _e130.z;
+    let _e137 = f4Color;
+    let _e139 = f4Color;
+    let _e143 = inNormalW_1;
+    let _e144 = inPositionW_1;
+    let _e145 = calculateRim(_e143, _e144);
+    let _e146 = (_e139.xyz + _e145);
+    f4Color.x = _e146.x;
+    f4Color.y = _e146.y;
+    f4Color.z = _e146.z;
+    let _e154 = inPositionW_1;
+    let _e155 = calculateFog(_e154);
+    f4Fog = _e155;
+    let _e157 = f4Color;
+    let _e159 = f4Fog;
+    let _e161 = f4Fog;
+    let _e163 = f4Color;
+    let _e165 = f4Fog;
+    let _e167 = f4Fog;
+    let _e170 = mix(_e163.xyz, _e165.xyz, vec3(_e167.w));
+    let _e171 = inColor_1;
+    outFragColor = vec4<f32>(_e170.x, _e170.y, _e170.z, _e171.w);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>, @location(2) inLightSpaceFragPosition: vec4<f32>, @location(3) inPositionW: vec3<f32>, @location(4) inRim: vec4<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    inLightSpaceFragPosition_1 = inLightSpaceFragPosition;
+    inPositionW_1 = inPositionW;
+    inRim_1 = inRim;
+    main_1();
+    let _e64 = outFragColor;
+    return FragmentOutput(_e64);
+}
@@ -0,0 +1,69 @@
+#version 450 core
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform texture2D uScene;
+layout (set = 0, binding = 1) uniform sampler uSampler;
+layout (set = 0, binding = 2) uniform PostProcessUniformLayout {
+    float fVignetteIntensity;
+    float fVignetteRadius;
+    float fVignetteSoftness;
+    float fGrainIntensity;
+    float fTime;
+    float fPreserveAlpha;
+    float fEncodeSrgb;
+    float fBloomIntensity;
+    float fBloomLevels;
+} uPost;
+layout (set = 0, binding = 3) readonly buffer ExposureLayout {
+    vec4 f4Exposure;
+} bExposure;
+layout (set = 0, binding = 4) uniform texture2D uBloom;
+
+vec3 tonemapACES(vec3 f3Color) {
+    // Narkowicz's fit of the ACES filmic curve.
+    return clamp((f3Color * (2.51 * f3Color + 0.03)) / (f3Color * (2.43 * f3Color + 0.59) + 0.14), 0.0, 1.0);
+}
+
+vec3 linearToSrgb(vec3 f3Color) {
+    vec3 f3Low = f3Color * 12.92;
+    vec3 f3High = 1.055 * pow(f3Color, vec3(1.0 / 2.4)) - 0.055;
+    return mix(f3High, f3Low, lessThanEqual(f3Color, vec3(0.0031308)));
+}
+
+float hash(vec2 f2Point) {
+    vec3 f3Point = fract(vec3(f2Point.xyx) * 0.1031);
+    f3Point += dot(f3Point, f3Point.yzx + 33.33);
+    return fract((f3Point.x + f3Point.y) * f3Point.z);
+}
+
+void main() {
+    vec2 f2Size = vec2(textureSize(sampler2D(uScene, uSampler), 0));
+    vec2 f2Texcoord = gl_FragCoord.xy / f2Size;
+    vec4 f4Scene = texture(sampler2D(uScene, uSampler), f2Texcoord);
+    vec3 f3Color = f4Scene.rgb;
+
+    // Adds every blurred level of the bloom mip chain before the exposure and tonemapping.
+    vec3 f3Bloom = vec3(0.0);
+    for (int i = 0; i < int(uPost.fBloomLevels); ++i) {
+        f3Bloom += textureLod(sampler2D(uBloom, uSampler), f2Texcoord, float(i)).rgb;
+    }
+    f3Color += f3Bloom * (uPost.fBloomIntensity / max(uPost.fBloomLevels, 1.0));
+    f3Color = tonemapACES(f3Color * bExposure.f4Exposure.y);
+
+    vec2 f2Centered = (f2Texcoord - 0.5) * vec2(f2Size.x / f2Size.y, 1.0);
+    float fVignette = smoothstep(uPost.fVignetteRadius, uPost.fVignetteRadius - uPost.fVignetteSoftness, length(f2Centered));
+    f3Color *= mix(1.0, fVignette, uPost.fVignetteIntensity);
+
+    float fGrain = hash(gl_FragCoord.xy + fract(uPost.fTime) * 1000.0) - 0.5;
+    f3Color += fGrain * uPost.fGrainIntensity;
+
+    // Encodes to sRGB when the frame buffer does not do it in hardware.
+    if (uPost.fEncodeSrgb > 0.5) {
+        f3Color = linearToSrgb(clamp(f3Color, 0.0, 1.0));
+    }
+
+    // Premultiplies the color so the overlay window composites correctly over the desktop.
+    float fAlpha = mix(1.0, clamp(f4Scene.a, 0.0, 1.0), uPost.fPreserveAlpha);
+    outFragColor = vec4(f3Color * fAlpha, fAlpha);
+}
@@ -0,0 +1,203 @@
+// Generated from `post_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct PostProcessUniformLayout {
+    fVignetteIntensity: f32,
+    fVignetteRadius: f32,
+    fVignetteSoftness: f32,
+    fGrainIntensity: f32,
+    fTime: f32,
+    fPreserveAlpha: f32,
+    fEncodeSrgb: f32,
+    fBloomIntensity: f32,
+    fBloomLevels: f32,
+}
+
+struct ExposureLayout {
+    f4Exposure: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uScene: texture_2d<f32>;
+@group(0) @binding(1) 
+var uSampler: sampler;
+@group(0) @binding(2) 
+var<uniform> uPost: PostProcessUniformLayout;
+@group(0) @binding(3) 
+var<storage> bExposure: ExposureLayout;
+@group(0) @binding(4) 
+var uBloom: texture_2d<f32>;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn tonemapACES(f3Color: vec3<f32>) -> vec3<f32> {
+    var f3Color_1: vec3<f32>;
+
+    f3Color_1 = f3Color;
+    let _e28 = f3Color_1;
+    let _e30 = f3Color_1;
+    let _e36 = f3Color_1;
+    let _e38 = f3Color_1;
+    let _e50 = f3Color_1;
+    let _e52 = f3Color_1;
+    let _e58 = f3Color_1;
+    let _e60 = f3Color_1;
+    return clamp(((_e50 * ((2.51f * _e52) + vec3(0.03f))) / ((_e58 * ((2.43f * _e60) + vec3(0.59f))) + vec3(0.14f))), vec3(0f), vec3(1f));
+}
+
+fn linearToSrgb(f3Color_2: vec3<f32>) -> vec3<f32> {
+    var f3Color_3: vec3<f32>;
+    var f3Low: vec3<f32>;
+    var f3High: vec3<f32>;
+
+    f3Color_3 = f3Color_2;
+    let _e28 = f3Color_3;
+    f3Low = (_e28 * 12.92f);
+    let _e38 = f3Color_3;
+    f3High = ((1.055f * pow(_e38, vec3(0.41666666f))) - vec3(0.055f));
+    let _e54 = f3Color_3;
+    let _e58 = f3High;
+    let _e59 = f3Low;
+    let _e63 = f3Color_3;
+    return select(_e58, _e59, (_e63 <= vec3(0.0031308f)));
+}
+
+fn hash(f2Point: vec2<f32>) -> f32 {
+    var f2Point_1: vec2<f32>;
+    var f3Point: vec3<f32>;
+
+    f2Point_1 = f2Point;
+    let _e28 = f2Point_1;
+    let _e33 = f2Point_1;
+    f3Point = fract((vec3<f32>(_e33.xyx) * 0.1031f));
+    let _e40 = f3Point;
+    let _e42 = f3Point;
+    let _e47 = f3Point;
+    let _e48 = f3Point;
+    f3Point = (_e40 + vec3(dot(_e47, (_e48.yzx + vec3(33.33f)))));
+    let _e56 = f3Point;
+    let _e58 = f3Point;
+    let _e61 = f3Point;
+    let _e64 = f3Point;
+    let _e66 = f3Point;
+    let _e69 = f3Point;
+    return fract(((_e64.x + _e66.y) * _e69.z));
+}
+
+fn main_1() {
+    var f2Size: vec2<f32>;
+    var f2Texcoord: vec2<f32>;
+    var f4Scene: vec4<f32>;
+    var f3Color_4: vec3<f32>;
+    var f3Bloom: vec3<f32> = vec3(0f);
+    var i: i32 = 0i;
+    var f2Centered: vec2<f32>;
+    var fVignette: f32;
+    var fGrain: f32;
+    var fAlpha: f32;
+
+    let _e28 = textureDimensions(uScene, 0i);
+    f2Size = vec2<f32>(vec2<i32>(_e28));
+    let _e33 = gl_FragCoord;
+    let _e35 = f2Size;
+    f2Texcoord = (_e33.xy / _e35);
+    let _e39 = f2Texcoord;
+    let _e40 = textureSample(uScene, uSampler, _e39);
+    f4Scene = _e40;
+    let _e42 = f4Scene;
+    f3Color_4 = _e42.xyz;
+    loop {
+        let _e50 = i;
+        let _e51 = uPost;
+        if !((_e50 < i32(_e51.fBloomLevels))) {
+            break;
+        }
+        {
+            let _e59 = f3Bloom;
+            let _e61 = i;
+            let _e63 = f2Texcoord;
+            let _e64 = i;
+            let _e66 = textureSampleLevel(uBloom, uSampler, _e63, f32(_e64));
+            f3Bloom = (_e59 + _e66.xyz);
+        }
+        continuing {
+            let _e56 = i;
+            i = (_e56 + 1i);
+        }
+    }
+    let _e69 = f3Color_4;
+    let _e70 = f3Bloom;
+    let _e71 = uPost;
+    let _e73 = uPost;
+    let _e76 = uPost;
+    f3Color_4 = (_e69 + (_e70 * (_e71.fBloomIntensity / max(_e76.fBloomLevels, 1f))));
+    let _e83 = f3Color_4;
+    let _e84 = bExposure;
+    let _e88 = f3Color_4;
+    let _e89 = bExposure;
+    let _e93 = tonemapACES((_e88 * _e89.f4Exposure.y));
+    f3Color_4 = _e93;
+    let _e94 = f2Texcoord;
+    let _e98 = f2Size;
+    let _e100 = f2Size;
+    f2Centered = ((_e94 - vec2(0.5f)) * vec2<f32>((_e98.x / _e100.y), 1f));
+    let _e107 = uPost;
+    let _e109 = uPost;
+    let _e111 = uPost;
+    let _e115 = f2Centered;
+    let _e117 = uPost;
+    let _e119 = uPost;
+    let _e121 = uPost;
+    let _e125 = f2Centered;
+    fVignette = smoothstep(_e117.fVignetteRadius, (_e119.fVignetteRadius - _e121.fVignetteSoftness), length(_e125));
+    let _e129 = f3Color_4;
+    let _e132 = uPost;
+    let _e135 = fVignette;
+    let _e136 = uPost;
+    f3Color_4 = (_e129 * mix(1f, _e135, _e136.fVignetteIntensity));
+    let _e140 = gl_FragCoord;
+    let _e142 = uPost;
+    let _e144 = uPost;
+    let _e151 = gl_FragCoord;
+    let _e153 = uPost;
+    let _e155 = uPost;
+    let _e162 = hash((_e151.xy + vec2((fract(_e155.fTime) * 1000f))));
+    fGrain = (_e162 - 0.5f);
+    let _e166 = f3Color_4;
+    let _e167 = fGrain;
+    let _e168 = uPost;
+    f3Color_4 = (_e166 + vec3((_e167 * _e168.fGrainIntensity)));
+    let _e173 = uPost;
+    if (_e173.fEncodeSrgb > 0.5f) {
+        {
+            let _e180 = f3Color_4;
+            let _e189 = f3Color_4;
+            let _e195 = linearToSrgb(clamp(_e189, vec3(0f), vec3(1f)));
+            f3Color_4 = _e195;
+        }
+    }
+    let _e197 = f4Scene;
+    let _e201 = f4Scene;
+    let _e206 = uPost;
+    let _e209 = f4Scene;
+    let _e213 = f4Scene;
+    let _e218 = uPost;
+    fAlpha = mix(1f, clamp(_e213.w, 0f, 1f), _e218.fPreserveAlpha);
+    let _e222 = f3Color_4;
+    let _e223 = fAlpha;
+    let _e224 = (_e222 * _e223);
+    let _e225 = fAlpha;
+    outFragColor = vec4<f32>(_e224.x, _e224.y, _e224.z, _e225);
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e33 = outFragColor;
+    return FragmentOutput(_e33);
+}
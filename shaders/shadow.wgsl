@@ -0,0 +1,41 @@
+// Generated from `shadow.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+@group(0) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    let _e15 = uGlobalLight;
+    let _e17 = uEntity;
+    let _e20 = inPosition_1;
+    gl_Position = ((_e15.mtxProjView * _e17.mtxWorld) * vec4<f32>(_e20.x, _e20.y, _e20.z, 1f));
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    main_1();
+    let _e21 = gl_Position;
+    return VertexOutput(_e21);
+}
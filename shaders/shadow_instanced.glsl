@@ -0,0 +1,24 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+layout (location = 1) in vec3 inNormal;
+layout (location = 2) in vec4 inInstanceWorld0;
+layout (location = 3) in vec4 inInstanceWorld1;
+layout (location = 4) in vec4 inInstanceWorld2;
+layout (location = 5) in vec4 inInstanceWorld3;
+
+layout (set = 0, binding = 0) uniform GlobalLightUniformLayout {
+    mat4 mtxProjView;
+    vec4 f4Direction;
+    vec4 f4LightColor;
+} uGlobalLight;
+
+layout (set = 1, binding = 0) uniform ObjectUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+} uEntity;
+
+void main() {
+    mat4 mtxInstanceWorld = mat4(inInstanceWorld0, inInstanceWorld1, inInstanceWorld2, inInstanceWorld3);
+    gl_Position = uGlobalLight.mtxProjView * uEntity.mtxWorld * mtxInstanceWorld * vec4(inPosition, 1.0);
+}
@@ -0,0 +1,57 @@
+// Generated from `shadow_instanced.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> inInstanceWorld0_1: vec4<f32>;
+var<private> inInstanceWorld1_1: vec4<f32>;
+var<private> inInstanceWorld2_1: vec4<f32>;
+var<private> inInstanceWorld3_1: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var mtxInstanceWorld: mat4x4<f32>;
+
+    let _e18 = inInstanceWorld0_1;
+    let _e19 = inInstanceWorld1_1;
+    let _e20 = inInstanceWorld2_1;
+    let _e21 = inInstanceWorld3_1;
+    mtxInstanceWorld = mat4x4<f32>(vec4<f32>(_e18.x, _e18.y, _e18.z, _e18.w), vec4<f32>(_e19.x, _e19.y, _e19.z, _e19.w), vec4<f32>(_e20.x, _e20.y, _e20.z, _e20.w), vec4<f32>(_e21.x, _e21.y, _e21.z, _e21.w));
+    let _e45 = uGlobalLight;
+    let _e47 = uEntity;
+    let _e50 = mtxInstanceWorld;
+    let _e52 = inPosition_1;
+    gl_Position = (((_e45.mtxProjView * _e47.mtxWorld) * _e50) * vec4<f32>(_e52.x, _e52.y, _e52.z, 1f));
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>, @location(2) inInstanceWorld0_: vec4<f32>, @location(3) inInstanceWorld1_: vec4<f32>, @location(4) inInstanceWorld2_: vec4<f32>, @location(5) inInstanceWorld3_: vec4<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    inInstanceWorld0_1 = inInstanceWorld0_;
+    inInstanceWorld1_1 = inInstanceWorld1_;
+    inInstanceWorld2_1 = inInstanceWorld2_;
+    inInstanceWorld3_1 = inInstanceWorld3_;
+    main_1();
+    let _e37 = gl_Position;
+    return VertexOutput(_e37);
+}
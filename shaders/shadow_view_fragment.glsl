@@ -0,0 +1,30 @@
+#version 450 core
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 0) uniform texture2D uShadowMap;
+layout (set = 0, binding = 1) uniform sampler uShadowSampler;
+layout (set = 0, binding = 2) uniform ShadowViewUniformLayout {
+    vec4 f4Rect;
+    vec4 f4Params;
+} uShadowView;
+
+void main() {
+    vec2 f2Size = vec2(textureSize(sampler2D(uShadowMap, uShadowSampler), 0));
+    vec2 f2Texcoord = clamp((gl_FragCoord.xy - uShadowView.f4Rect.xy) / uShadowView.f4Rect.zw, 0.0, 1.0);
+    ivec2 i2Coord = min(ivec2(f2Texcoord * f2Size), ivec2(f2Size) - 1);
+    float fDepth = texelFetch(sampler2D(uShadowMap, uShadowSampler), i2Coord, 0).r;
+
+    // (한국어) 아무것도 그려지지 않은 부분은 붉게 보여줍니다.
+    // (English Translation) Shows the parts where nothing was drawn in red.
+    if (fDepth >= 1.0) {
+        outFragColor = vec4(0.3, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    // (한국어) 원근 투영 깊이를 조명으로부터의 거리로 되돌립니다.
+    // (English Translation) Converts the perspective depth back to the distance from the light.
+    float fDistance = uShadowView.f4Params.y / (fDepth + uShadowView.f4Params.x);
+    float fIntensity = clamp(fDistance / uShadowView.f4Params.z, 0.0, 1.0);
+    outFragColor = vec4(vec3(fIntensity), 1.0);
+}
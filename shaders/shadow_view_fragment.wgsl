@@ -0,0 +1,76 @@
+// Generated from `shadow_view_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct ShadowViewUniformLayout {
+    f4Rect: vec4<f32>,
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var uShadowMap: texture_2d<f32>;
+@group(0) @binding(1) 
+var uShadowSampler: sampler;
+@group(0) @binding(2) 
+var<uniform> uShadowView: ShadowViewUniformLayout;
+var<private> gl_FragCoord: vec4<f32>;
+
+fn main_1() {
+    var f2Size: vec2<f32>;
+    var f2Texcoord: vec2<f32>;
+    var i2Coord: vec2<i32>;
+    var fDepth: f32;
+    var fDistance: f32;
+    var fIntensity: f32;
+
+    let _e10 = textureDimensions(uShadowMap, 0i);
+    f2Size = vec2<f32>(vec2<i32>(_e10));
+    let _e15 = gl_FragCoord;
+    let _e17 = uShadowView;
+    let _e21 = uShadowView;
+    let _e27 = gl_FragCoord;
+    let _e29 = uShadowView;
+    let _e33 = uShadowView;
+    f2Texcoord = clamp(((_e27.xy - _e29.f4Rect.xy) / _e33.f4Rect.zw), vec2(0f), vec2(1f));
+    let _e43 = f2Texcoord;
+    let _e44 = f2Size;
+    let _e47 = f2Size;
+    let _e52 = f2Texcoord;
+    let _e53 = f2Size;
+    let _e56 = f2Size;
+    i2Coord = min(vec2<i32>((_e52 * _e53)), (vec2<i32>(_e56) - vec2(1i)));
+    let _e65 = i2Coord;
+    let _e67 = textureLoad(uShadowMap, _e65, 0i);
+    fDepth = _e67.x;
+    let _e70 = fDepth;
+    if (_e70 >= 1f) {
+        {
+            outFragColor = vec4<f32>(0.3f, 0f, 0f, 1f);
+            return;
+        }
+    }
+    let _e78 = uShadowView;
+    let _e81 = fDepth;
+    let _e82 = uShadowView;
+    fDistance = (_e78.f4Params.y / (_e81 + _e82.f4Params.x));
+    let _e88 = fDistance;
+    let _e89 = uShadowView;
+    let _e95 = fDistance;
+    let _e96 = uShadowView;
+    fIntensity = clamp((_e95 / _e96.f4Params.z), 0f, 1f);
+    let _e104 = fIntensity;
+    let _e105 = vec3(_e104);
+    outFragColor = vec4<f32>(_e105.x, _e105.y, _e105.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@builtin(position) param: vec4<f32>) -> FragmentOutput {
+    gl_FragCoord = param;
+    main_1();
+    let _e14 = outFragColor;
+    return FragmentOutput(_e14);
+}
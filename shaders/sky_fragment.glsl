@@ -0,0 +1,40 @@
+#version 450 core
+
+layout (location = 0) in vec3 inDirection;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 1, binding = 0) uniform SkyUniformLayout {
+    vec4 f4ZenithColor;
+    vec4 f4HorizonColor;
+    vec4 f4GroundColor;
+    vec4 f4SunDirection;
+    vec4 f4SunColor;
+} uSky;
+
+const vec3 SUNSET_COLOR = vec3(1.0, 0.45, 0.15);
+
+void main() {
+    vec3 f3Direction = normalize(inDirection);
+    vec3 f3SunDirection = normalize(uSky.f4SunDirection.xyz);
+    float fElevation = f3Direction.y;
+    float fSunElevation = f3SunDirection.y;
+
+    float fSunset = 1.0 - smoothstep(0.0, 0.35, abs(fSunElevation));
+    vec3 f3Horizon = mix(uSky.f4HorizonColor.rgb, SUNSET_COLOR, fSunset * 0.7);
+    vec3 f3Zenith = uSky.f4ZenithColor.rgb * mix(0.15, 1.0, smoothstep(-0.2, 0.3, fSunElevation));
+
+    vec3 f3Color;
+    if (fElevation >= 0.0) {
+        f3Color = mix(f3Horizon, f3Zenith, pow(fElevation, 0.5));
+    } else {
+        f3Color = mix(f3Horizon, uSky.f4GroundColor.rgb, pow(-fElevation, 0.35));
+    }
+
+    float fCosSun = dot(f3Direction, f3SunDirection);
+    float fGlow = pow(max(fCosSun, 0.0), 64.0) * 0.6 + pow(max(fCosSun, 0.0), 8.0) * 0.2 * fSunset;
+    float fDisc = smoothstep(uSky.f4SunDirection.w - 0.0005, uSky.f4SunDirection.w, fCosSun);
+    f3Color += uSky.f4SunColor.rgb * (fGlow + fDisc) * step(0.0, fElevation + 0.05);
+
+    outFragColor = vec4(min(f3Color, vec3(1.0)), 1.0);
+}
@@ -0,0 +1,111 @@
+// Generated from `sky_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct SkyUniformLayout {
+    f4ZenithColor: vec4<f32>,
+    f4HorizonColor: vec4<f32>,
+    f4GroundColor: vec4<f32>,
+    f4SunDirection: vec4<f32>,
+    f4SunColor: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const SUNSET_COLOR: vec3<f32> = vec3<f32>(1f, 0.45f, 0.15f);
+
+var<private> inDirection_1: vec3<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(1) @binding(0) 
+var<uniform> uSky: SkyUniformLayout;
+
+fn main_1() {
+    var f3Direction: vec3<f32>;
+    var f3SunDirection: vec3<f32>;
+    var fElevation: f32;
+    var fSunElevation: f32;
+    var fSunset: f32;
+    var f3Horizon: vec3<f32>;
+    var f3Zenith: vec3<f32>;
+    var f3Color: vec3<f32>;
+    var fCosSun: f32;
+    var fGlow: f32;
+    var fDisc: f32;
+
+    let _e15 = inDirection_1;
+    f3Direction = normalize(_e15);
+    let _e18 = uSky;
+    let _e21 = uSky;
+    f3SunDirection = normalize(_e21.f4SunDirection.xyz);
+    let _e26 = f3Direction;
+    fElevation = _e26.y;
+    let _e29 = f3SunDirection;
+    fSunElevation = _e29.y;
+    let _e36 = fSunElevation;
+    let _e41 = fSunElevation;
+    fSunset = (1f - smoothstep(0f, 0.35f, abs(_e41)));
+    let _e46 = uSky;
+    let _e49 = fSunset;
+    let _e52 = uSky;
+    let _e55 = fSunset;
+    f3Horizon = mix(_e52.f4HorizonColor.xyz, SUNSET_COLOR, vec3((_e55 * 0.7f)));
+    let _e61 = uSky;
+    let _e73 = fSunElevation;
+    let _e84 = fSunElevation;
+    f3Zenith = (_e61.f4ZenithColor.xyz * mix(0.15f, 1f, smoothstep(-0.2f, 0.3f, _e84)));
+    let _e90 = fElevation;
+    if (_e90 >= 0f) {
+        {
+            let _e97 = fElevation;
+            let _e100 = f3Horizon;
+            let _e101 = f3Zenith;
+            let _e104 = fElevation;
+            f3Color = mix(_e100, _e101, vec3(pow(_e104, 0.5f)));
+        }
+    } else {
+        {
+            let _e110 = uSky;
+            let _e113 = fElevation;
+            let _e116 = fElevation;
+            let _e120 = f3Horizon;
+            let _e121 = uSky;
+            let _e124 = fElevation;
+            let _e127 = fElevation;
+            f3Color = mix(_e120, _e121.f4GroundColor.xyz, vec3(pow(-(_e127), 0.35f)));
+        }
+    }
+    let _e135 = f3Direction;
+    let _e136 = f3SunDirection;
+    fCosSun = dot(_e135, _e136);
+    let _e141 = fCosSun;
+    let _e147 = fCosSun;
+    let _e156 = fCosSun;
+    let _e162 = fCosSun;
+    let _e169 = fSunset;
+    fGlow = ((pow(max(_e147, 0f), 64f) * 0.6f) + ((pow(max(_e162, 0f), 8f) * 0.2f) * _e169));
+    let _e173 = uSky;
+    let _e178 = uSky;
+    let _e182 = uSky;
+    let _e187 = uSky;
+    let _e190 = fCosSun;
+    fDisc = smoothstep((_e182.f4SunDirection.w - 0.0005f), _e187.f4SunDirection.w, _e190);
+    let _e193 = f3Color;
+    let _e194 = uSky;
+    let _e197 = fGlow;
+    let _e198 = fDisc;
+    let _e202 = fElevation;
+    let _e206 = fElevation;
+    f3Color = (_e193 + ((_e194.f4SunColor.xyz * (_e197 + _e198)) * step(0f, (_e206 + 0.05f))));
+    let _e215 = f3Color;
+    let _e218 = min(_e215, vec3(1f));
+    outFragColor = vec4<f32>(_e218.x, _e218.y, _e218.z, 1f);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inDirection: vec3<f32>) -> FragmentOutput {
+    inDirection_1 = inDirection;
+    main_1();
+    let _e20 = outFragColor;
+    return FragmentOutput(_e20);
+}
@@ -0,0 +1,12 @@
+#version 450 core
+
+layout (location = 0) in vec3 inDirection;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 1, binding = 0) uniform textureCube uSkybox;
+layout (set = 1, binding = 1) uniform sampler uSampler;
+
+void main() {
+    outFragColor = texture(samplerCube(uSkybox, uSampler), normalize(inDirection));
+}
@@ -0,0 +1,28 @@
+// Generated from `skybox_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> inDirection_1: vec3<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(1) @binding(0) 
+var uSkybox: texture_cube<f32>;
+@group(1) @binding(1) 
+var uSampler: sampler;
+
+fn main_1() {
+    let _e5 = inDirection_1;
+    let _e8 = inDirection_1;
+    let _e10 = textureSample(uSkybox, uSampler, normalize(_e8));
+    outFragColor = _e10;
+    return;
+}
+
+@fragment 
+fn main(@location(0) inDirection: vec3<f32>) -> FragmentOutput {
+    inDirection_1 = inDirection;
+    main_1();
+    let _e11 = outFragColor;
+    return FragmentOutput(_e11);
+}
@@ -0,0 +1,18 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPosition;
+
+layout (location = 0) out vec3 outDirection;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+void main() {
+    mat4 mtxRotation = mat4(mat3(uCamera.mtxView));
+    vec4 f4Position = uCamera.mtxProjection * mtxRotation * vec4(inPosition, 1.0);
+    outDirection = inPosition;
+    gl_Position = f4Position.xyww;
+}
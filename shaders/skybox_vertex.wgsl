@@ -0,0 +1,45 @@
+// Generated from `skybox_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outDirection: vec3<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> outDirection: vec3<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var mtxRotation: mat4x4<f32>;
+    var f4Position: vec4<f32>;
+
+    let _e9 = uCamera;
+    let _e19 = mat3x3<f32>(_e9.mtxView[0].xyz, _e9.mtxView[1].xyz, _e9.mtxView[2].xyz);
+    mtxRotation = mat4x4<f32>(vec4<f32>(_e19[0].x, _e19[0].y, _e19[0].z, 0f), vec4<f32>(_e19[1].x, _e19[1].y, _e19[1].z, 0f), vec4<f32>(_e19[2].x, _e19[2].y, _e19[2].z, 0f), vec4<f32>(0f, 0f, 0f, 1f));
+    let _e40 = uCamera;
+    let _e42 = mtxRotation;
+    let _e44 = inPosition_1;
+    f4Position = ((_e40.mtxProjection * _e42) * vec4<f32>(_e44.x, _e44.y, _e44.z, 1f));
+    let _e52 = inPosition_1;
+    outDirection = _e52;
+    let _e54 = f4Position;
+    gl_Position = _e54.xyww;
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    main_1();
+    let _e14 = outDirection;
+    let _e16 = gl_Position;
+    return VertexOutput(_e14, _e16);
+}
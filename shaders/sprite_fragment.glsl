@@ -0,0 +1,13 @@
+#version 450 core
+
+layout (location = 0) in vec2 inTexcoord;
+layout (location = 1) in vec4 inColor;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 1, binding = 0) uniform texture2D uTexture;
+layout (set = 1, binding = 1) uniform sampler uSampler;
+
+void main() {
+    outFragColor = texture(sampler2D(uTexture, uSampler), inTexcoord) * inColor;
+}
@@ -0,0 +1,30 @@
+// Generated from `sprite_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> inTexcoord_1: vec2<f32>;
+var<private> inColor_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(1) @binding(0) 
+var uTexture: texture_2d<f32>;
+@group(1) @binding(1) 
+var uSampler: sampler;
+
+fn main_1() {
+    let _e6 = inTexcoord_1;
+    let _e7 = textureSample(uTexture, uSampler, _e6);
+    let _e8 = inColor_1;
+    outFragColor = (_e7 * _e8);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inTexcoord: vec2<f32>, @location(1) inColor: vec4<f32>) -> FragmentOutput {
+    inTexcoord_1 = inTexcoord;
+    inColor_1 = inColor;
+    main_1();
+    let _e15 = outFragColor;
+    return FragmentOutput(_e15);
+}
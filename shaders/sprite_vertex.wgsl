@@ -0,0 +1,46 @@
+// Generated from `sprite_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outTexcoord: vec2<f32>,
+    @location(1) outColor: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec2<f32>;
+var<private> inTexcoord_1: vec2<f32>;
+var<private> inColor_1: vec4<f32>;
+var<private> outTexcoord: vec2<f32>;
+var<private> outColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    let _e12 = inTexcoord_1;
+    outTexcoord = _e12;
+    let _e13 = inColor_1;
+    outColor = _e13;
+    let _e15 = uCamera;
+    let _e17 = uCamera;
+    let _e20 = inPosition_1;
+    gl_Position = ((_e15.mtxProjection * _e17.mtxView) * vec4<f32>(_e20.x, _e20.y, 0f, 1f));
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec2<f32>, @location(1) inTexcoord: vec2<f32>, @location(2) inColor: vec4<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inTexcoord_1 = inTexcoord;
+    inColor_1 = inColor;
+    main_1();
+    let _e24 = outTexcoord;
+    let _e26 = outColor;
+    let _e28 = gl_Position;
+    return VertexOutput(_e24, _e26, _e28);
+}
@@ -0,0 +1,14 @@
+#version 450 core
+
+layout (location = 0) in vec2 inTexcoord;
+layout (location = 1) in vec4 inColor;
+
+layout (location = 0) out vec4 outFragColor;
+
+layout (set = 0, binding = 1) uniform texture2D uGlyphAtlas;
+layout (set = 0, binding = 2) uniform sampler uSampler;
+
+void main() {
+    float fCoverage = texture(sampler2D(uGlyphAtlas, uSampler), inTexcoord).r;
+    outFragColor = vec4(inColor.rgb, inColor.a * fCoverage);
+}
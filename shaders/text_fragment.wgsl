@@ -0,0 +1,36 @@
+// Generated from `text_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+var<private> inTexcoord_1: vec2<f32>;
+var<private> inColor_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(1) 
+var uGlyphAtlas: texture_2d<f32>;
+@group(0) @binding(2) 
+var uSampler: sampler;
+
+fn main_1() {
+    var fCoverage: f32;
+
+    let _e6 = inTexcoord_1;
+    let _e7 = textureSample(uGlyphAtlas, uSampler, _e6);
+    fCoverage = _e7.x;
+    let _e10 = inColor_1;
+    let _e11 = _e10.xyz;
+    let _e12 = inColor_1;
+    let _e14 = fCoverage;
+    outFragColor = vec4<f32>(_e11.x, _e11.y, _e11.z, (_e12.w * _e14));
+    return;
+}
+
+@fragment 
+fn main(@location(0) inTexcoord: vec2<f32>, @location(1) inColor: vec4<f32>) -> FragmentOutput {
+    inTexcoord_1 = inTexcoord;
+    inColor_1 = inColor;
+    main_1();
+    let _e15 = outFragColor;
+    return FragmentOutput(_e15);
+}
@@ -0,0 +1,19 @@
+#version 450 core
+
+layout (location = 0) in vec2 inPosition;
+layout (location = 1) in vec2 inTexcoord;
+layout (location = 2) in vec4 inColor;
+
+layout (location = 0) out vec2 outTexcoord;
+layout (location = 1) out vec4 outColor;
+
+layout (set = 0, binding = 0) uniform ScreenUniformLayout {
+    vec4 f4ScreenSize;
+} uScreen;
+
+void main() {
+    vec2 f2Ndc = inPosition * uScreen.f4ScreenSize.zw * vec2(2.0, -2.0) + vec2(-1.0, 1.0);
+    outTexcoord = inTexcoord;
+    outColor = inColor;
+    gl_Position = vec4(f2Ndc, 0.0, 1.0);
+}
@@ -0,0 +1,47 @@
+// Generated from `text_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct ScreenUniformLayout {
+    f4ScreenSize: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outTexcoord: vec2<f32>,
+    @location(1) outColor: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec2<f32>;
+var<private> inTexcoord_1: vec2<f32>;
+var<private> inColor_1: vec4<f32>;
+var<private> outTexcoord: vec2<f32>;
+var<private> outColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uScreen: ScreenUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var f2Ndc: vec2<f32>;
+
+    let _e8 = inPosition_1;
+    let _e9 = uScreen;
+    f2Ndc = (((_e8 * _e9.f4ScreenSize.zw) * vec2<f32>(2f, -2f)) + vec2<f32>(-1f, 1f));
+    let _e24 = inTexcoord_1;
+    outTexcoord = _e24;
+    let _e25 = inColor_1;
+    outColor = _e25;
+    let _e27 = f2Ndc;
+    gl_Position = vec4<f32>(_e27.x, _e27.y, 0f, 1f);
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec2<f32>, @location(1) inTexcoord: vec2<f32>, @location(2) inColor: vec4<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inTexcoord_1 = inTexcoord;
+    inColor_1 = inColor;
+    main_1();
+    let _e20 = outTexcoord;
+    let _e22 = outColor;
+    let _e24 = gl_Position;
+    return VertexOutput(_e20, _e22, _e24);
+}
@@ -0,0 +1,554 @@
+// Generated from `textured_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    view: mat4x4<f32>,
+    projection: mat4x4<f32>,
+    position: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+    f4Position: vec4<f32>,
+    f4Cone: vec4<f32>,
+}
+
+struct ShadowFilterUniformLayout {
+    f4Params: vec4<f32>,
+}
+
+struct FogUniformLayout {
+    f4Color: vec4<f32>,
+    f4SunColor: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Range: vec4<f32>,
+}
+
+struct MaterialUniformLayout {
+    f4ColorFactor: vec4<f32>,
+    f4Params: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outFragColor: vec4<f32>,
+}
+
+const POISSON_DISK: array<vec2<f32>, 16> = array<vec2<f32>, 16>(vec2<f32>(-0.94201624f, -0.39906216f), vec2<f32>(0.9455861f, -0.76890725f), vec2<f32>(-0.0941841f, -0.9293887f), vec2<f32>(0.34495938f, 0.2938776f), vec2<f32>(-0.9158858f, 0.45771432f), vec2<f32>(-0.8154423f, -0.87912464f), vec2<f32>(-0.38277543f, 0.27676845f), vec2<f32>(0.974844f, 0.7564838f), vec2<f32>(0.44323325f, -0.97511554f), vec2<f32>(0.5374298f, -0.4737342f), vec2<f32>(-0.2649691f, -0.41893023f), vec2<f32>(0.79197514f, 0.19090188f), vec2<f32>(-0.2418884f, 0.99706507f), vec2<f32>(-0.81409955f, 0.9143759f), vec2<f32>(0.19984126f, 0.78641367f), vec2<f32>(0.14383161f, -0.1410079f));
+
+var<private> inColor_1: vec4<f32>;
+var<private> inNormalW_1: vec3<f32>;
+var<private> inLightSpaceFragPosition_1: vec4<f32>;
+var<private> inPositionW_1: vec3<f32>;
+var<private> inRim_1: vec4<f32>;
+var<private> inTexCoord_1: vec2<f32>;
+var<private> inTangentW_1: vec4<f32>;
+var<private> outFragColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+@group(3) @binding(0) 
+var uShadowMap: texture_depth_2d;
+@group(3) @binding(1) 
+var uSampler: sampler_comparison;
+@group(3) @binding(2) 
+var<uniform> uShadowFilter: ShadowFilterUniformLayout;
+@group(4) @binding(0) 
+var<uniform> uFog: FogUniformLayout;
+@group(5) @binding(0) 
+var<uniform> uMaterial: MaterialUniformLayout;
+@group(5) @binding(1) 
+var uAlbedoMap: texture_2d<f32>;
+@group(5) @binding(2) 
+var uNormalMap: texture_2d<f32>;
+@group(5) @binding(3) 
+var uMaterialSampler: sampler;
+
+fn sampleShadow(f2ProjCoords: vec2<f32>, fCurrentDepth: f32) -> f32 {
+    var f2ProjCoords_1: vec2<f32>;
+    var fCurrentDepth_1: f32;
+
+    f2ProjCoords_1 = f2ProjCoords;
+    fCurrentDepth_1 = fCurrentDepth;
+    let _e53 = f2ProjCoords_1;
+    let _e54 = fCurrentDepth_1;
+    let _e58 = f2ProjCoords_1;
+    let _e59 = fCurrentDepth_1;
+    let _e62 = vec3<f32>(_e58.x, _e58.y, _e59);
+    let _e65 = textureSampleCompare(uShadowMap, uSampler, _e62.xy, _e62.z);
+    return _e65;
+}
+
+fn calculateShadow(f4LightSpaceFragPosition: vec4<f32>) -> f32 {
+    var f4LightSpaceFragPosition_1: vec4<f32>;
+    var fCurrentDepth_2: f32;
+    var f2ProjCoords_2: vec2<f32>;
+    var iKernel: i32;
+    var f2Radius: vec2<f32>;
+    var fSum: f32 = 0f;
+    var i: i32 = 0i;
+    var local: array<vec2<f32>, 16> = POISSON_DISK;
+    var local_1: array<vec2<f32>, 16> = POISSON_DISK;
+    var fSum_1: f32 = 0f;
+    var y: i32;
+    var x: i32;
+    var fWidth: f32;
+
+    f4LightSpaceFragPosition_1 = f4LightSpaceFragPosition;
+    let _e51 = f4LightSpaceFragPosition_1;
+    if (_e51.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e56 = f4LightSpaceFragPosition_1;
+    let _e58 = f4LightSpaceFragPosition_1;
+    fCurrentDepth_2 = (_e56.z / _e58.w);
+    let _e62 = f4LightSpaceFragPosition_1;
+    let _e64 = f4LightSpaceFragPosition_1;
+    f2ProjCoords_2 = (_e62.xy / vec2(_e64.w));
+    let _e69 = f2ProjCoords_2;
+    f2ProjCoords_2 = ((_e69 * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e78 = uShadowFilter;
+    iKernel = i32((_e78.f4Params.x + 0.5f));
+    let _e85 = uShadowFilter;
+    let _e90 = textureDimensions(uShadowMap, 0i);
+    f2Radius = (vec2(_e85.f4Params.y) / vec2<f32>(vec2<i32>(_e90)));
+    let _e96 = iKernel;
+    if (_e96 == 3i) {
+        {
+            loop {
+                let _e103 = i;
+                if !((_e103 < 16i)) {
+                    break;
+                }
+                {
+                    let _e110 = fSum;
+                    let _e111 = f2ProjCoords_2;
+                    let _e112 = i;
+                    let _e116 = local[_e112];
+                    let _e117 = f2Radius;
+                    let _e121 = f2ProjCoords_2;
+                    let _e122 = i;
+                    let _e126 = local_1[_e122];
+                    let _e127 = f2Radius;
+                    let _e130 = fCurrentDepth_2;
+                    let _e131 = sampleShadow((_e121 + (_e126 * _e127)), _e130);
+                    fSum = (_e110 + _e131);
+                }
+                continuing {
+                    let _e107 = i;
+                    i = (_e107 + 1i);
+                }
+            }
+            let _e133 = fSum;
+            return (_e133 / 16f);
+        }
+    }
+    let _e136 = iKernel;
+    let _e139 = iKernel;
+    if ((_e136 == 1i) || (_e139 == 2i)) {
+        {
+            let _e145 = iKernel;
+            y = -(_e145);
+            loop {
+                let _e148 = y;
+                let _e149 = iKernel;
+                if !((_e148 <= _e149)) {
+                    break;
+                }
+                {
+                    let _e155 = iKernel;
+                    x = -(_e155);
+                    loop {
+                        let _e158 = x;
+                        let _e159 = iKernel;
+                        if !((_e158 <= _e159)) {
+                            break;
+                        }
+                        {
+                            let _e165 = fSum_1;
+                            let _e166 = f2ProjCoords_2;
+                            let _e167 = x;
+                            let _e168 = y;
+                            let _e172 = iKernel;
+                            let _e176 = f2Radius;
+                            let _e180 = f2ProjCoords_2;
+                            let _e181 = x;
+                            let _e182 = y;
+                            let _e186 = iKernel;
+                            let _e190 = f2Radius;
+                            let _e193 = fCurrentDepth_2;
+                            let _e194 = sampleShadow((_e180 + ((vec2<f32>(f32(_e181), f32(_e182)) / vec2(f32(_e186))) * _e190)), _e193);
+                            fSum_1 = (_e165 + _e194);
+                        }
+                        continuing {
+                            let _e162 = x;
+                            x = (_e162 + 1i);
+                        }
+                    }
+                }
+                continuing {
+                    let _e152 = y;
+                    y = (_e152 + 1i);
+                }
+            }
+            let _e197 = iKernel;
+            fWidth = f32(((2i * _e197) + 1i));
+            let _e203 = fSum_1;
+            let _e204 = fWidth;
+            let _e205 = fWidth;
+            return (_e203 / (_e204 * _e205));
+        }
+    }
+    let _e210 = f2ProjCoords_2;
+    let _e211 = fCurrentDepth_2;
+    let _e212 = sampleShadow(_e210, _e211);
+    return _e212;
+}
+
+fn perturbNormal(f3NormalW: vec3<f32>, f2TexCoord: vec2<f32>) -> vec3<f32> {
+    var f3NormalW_1: vec3<f32>;
+    var f2TexCoord_1: vec2<f32>;
+    var f3TangentW: vec3<f32>;
+    var f3BitangentW: vec3<f32>;
+    var mtxTBN: mat3x3<f32>;
+    var f3NormalT: vec3<f32>;
+
+    f3NormalW_1 = f3NormalW;
+    f2TexCoord_1 = f2TexCoord;
+    let _e53 = uMaterial;
+    if (_e53.f4Params.x <= 0.5f) {
+        {
+            let _e58 = f3NormalW_1;
+            return _e58;
+        }
+    }
+    let _e59 = inTangentW_1;
+    let _e61 = f3NormalW_1;
+    let _e63 = inTangentW_1;
+    let _e65 = f3NormalW_1;
+    let _e66 = inTangentW_1;
+    let _e71 = inTangentW_1;
+    let _e73 = f3NormalW_1;
+    let _e75 = inTangentW_1;
+    let _e77 = f3NormalW_1;
+    let _e78 = inTangentW_1;
+    f3TangentW = normalize((_e71.xyz - (_e73 * dot(_e77, _e78.xyz))));
+    let _e87 = f3NormalW_1;
+    let _e88 = f3TangentW;
+    let _e90 = inTangentW_1;
+    f3BitangentW = (cross(_e87, _e88) * _e90.w);
+    let _e94 = f3TangentW;
+    let _e95 = f3BitangentW;
+    let _e96 = f3NormalW_1;
+    mtxTBN = mat3x3<f32>(vec3<f32>(_e94.x, _e94.y, _e94.z), vec3<f32>(_e95.x, _e95.y, _e95.z), vec3<f32>(_e96.x, _e96.y, _e96.z));
+    let _e112 = f2TexCoord_1;
+    let _e113 = textureSample(uNormalMap, uMaterialSampler, _e112);
+    f3NormalT = ((_e113.xyz * 2f) - vec3(1f));
+    let _e121 = mtxTBN;
+    let _e122 = f3NormalT;
+    let _e124 = mtxTBN;
+    let _e125 = f3NormalT;
+    return normalize((_e124 * _e125));
+}
+
+fn calculateRim(f3NormalW_2: vec3<f32>, f3PositionW: vec3<f32>) -> vec3<f32> {
+    var f3NormalW_3: vec3<f32>;
+    var f3PositionW_1: vec3<f32>;
+    var f3View: vec3<f32>;
+    var fFresnel: f32;
+
+    f3NormalW_3 = f3NormalW_2;
+    f3PositionW_1 = f3PositionW;
+    let _e53 = inRim_1;
+    let _e55 = inRim_1;
+    let _e57 = inRim_1;
+    let _e59 = inRim_1;
+    if (dot(_e57.xyz, _e59.xyz) <= 0f) {
+        {
+            return vec3(0f);
+        }
+    }
+    let _e66 = uCamera;
+    let _e69 = f3PositionW_1;
+    let _e71 = uCamera;
+    let _e74 = f3PositionW_1;
+    f3View = normalize((_e71.position.xyz - _e74));
+    let _e81 = f3NormalW_3;
+    let _e82 = f3View;
+    let _e87 = f3NormalW_3;
+    let _e88 = f3View;
+    fFresnel = (1f - max(dot(_e87, _e88), 0f));
+    let _e94 = inRim_1;
+    let _e97 = inRim_1;
+    let _e100 = inRim_1;
+    let _e104 = fFresnel;
+    let _e105 = inRim_1;
+    let _e108 = inRim_1;
+    return (_e94.xyz * pow(_e104, max(_e108.w, 0.0001f)));
+}
+
+fn calculateFog(f3PositionW_2: vec3<f32>) -> vec4<f32> {
+    var f3PositionW_3: vec3<f32>;
+    var fDensity: f32;
+    var fFalloff: f32;
+    var fBaseHeight: f32;
+    var fMaxOpacity: f32;
+    var f3Ray: vec3<f32>;
+    var fDistance: f32;
+    var fOpacity: f32;
+    var fFogAmount: f32;
+    var fRayHeight: f32;
+    var fSunAmount: f32;
+    var f3SunColor: vec3<f32>;
+    var f3FogColor: vec3<f32>;
+
+    f3PositionW_3 = f3PositionW_2;
+    let _e51 = uFog;
+    fDensity = _e51.f4Params.x;
+    let _e55 = uFog;
+    let _e59 = uFog;
+    fFalloff = max(_e59.f4Params.y, 0.0001f);
+    let _e65 = uFog;
+    fBaseHeight = _e65.f4Params.z;
+    let _e69 = uFog;
+    fMaxOpacity = _e69.f4Params.w;
+    let _e73 = f3PositionW_3;
+    let _e74 = uCamera;
+    f3Ray = (_e73 - _e74.position.xyz);
+    let _e80 = f3Ray;
+    fDistance = length(_e80);
+    let _e83 = fDensity;
+    let _e86 = fDistance;
+    if ((_e83 <= 0f) || (_e86 <= 0f)) {
+        {
+            return vec4(0f);
+        }
+    }
+    let _e93 = uFog;
+    if (_e93.f4Range.z < 0.5f) {
+        {
+            let _e98 = fDistance;
+            let _e99 = uFog;
+            let _e103 = uFog;
+            let _e106 = uFog;
+            let _e111 = uFog;
+            let _e114 = uFog;
+            let _e123 = fDistance;
+            let _e124 = uFog;
+            let _e128 = uFog;
+            let _e131 = uFog;
+            let _e136 = uFog;
+            let _e139 = uFog;
+            fOpacity = clamp(((_e123 - _e124.f4Range.x) / max((_e136.f4Range.y - _e139.f4Range.x), 0.001f)), 0f, 1f);
+        }
+    } else {
+        let _e149 = uFog;
+        if (_e149.f4Range.z < 1.5f) {
+            {
+                let _e155 = fDensity;
+                let _e157 = fDistance;
+                let _e158 = uFog;
+                let _e163 = fDistance;
+                let _e164 = uFog;
+                let _e171 = fDensity;
+                let _e173 = fDistance;
+                let _e174 = uFog;
+                let _e179 = fDistance;
+                let _e180 = uFog;
+                fOpacity = (1f - exp((-(_e171) * max((_e179 - _e180.f4Range.x), 0f))));
+            }
+        } else {
+            {
+                let _e189 = fDensity;
+                let _e190 = fFalloff;
+                let _e192 = uCamera;
+                let _e195 = fBaseHeight;
+                let _e198 = fFalloff;
+                let _e200 = uCamera;
+                let _e203 = fBaseHeight;
+                let _e208 = fDistance;
+                fFogAmount = ((_e189 * exp((-(_e198) * (_e200.position.y - _e203)))) * _e208);
+                let _e211 = fFalloff;
+                let _e212 = f3Ray;
+                fRayHeight = (_e211 * _e212.y);
+                let _e217 = fRayHeight;
+                if (abs(_e217) > 0.0001f) {
+                    {
+                        let _e221 = fFogAmount;
+                        let _e223 = fRayHeight;
+                        let _e225 = fRayHeight;
+                        let _e229 = fRayHeight;
+                        fFogAmount = (_e221 * ((1f - exp(-(_e225))) / _e229));
+                    }
+                }
+                let _e233 = fFogAmount;
+                let _e235 = fFogAmount;
+                fOpacity = (1f - exp(-(_e235)));
+            }
+        }
+    }
+    let _e241 = fOpacity;
+    let _e242 = fMaxOpacity;
+    fOpacity = min(_e241, _e242);
+    let _e244 = f3Ray;
+    let _e245 = fDistance;
+    let _e248 = uGlobalLight;
+    let _e251 = uGlobalLight;
+    let _e255 = f3Ray;
+    let _e256 = fDistance;
+    let _e259 = uGlobalLight;
+    let _e262 = uGlobalLight;
+    let _e268 = f3Ray;
+    let _e269 = fDistance;
+    let _e272 = uGlobalLight;
+    let _e275 = uGlobalLight;
+    let _e279 = f3Ray;
+    let _e280 = fDistance;
+    let _e283 = uGlobalLight;
+    let _e286 = uGlobalLight;
+    fSunAmount = max(dot((_e279 / vec3(_e280)), normalize(_e286.f4Direction.xyz)), 0f);
+    let _e294 = uFog;
+    let _e297 = uGlobalLight;
+    f3SunColor = (_e294.f4SunColor.xyz * _e297.f4LightColor.xyz);
+    let _e302 = uFog;
+    let _e307 = uFog;
+    let _e310 = fSunAmount;
+    let _e311 = uFog;
+    let _e315 = uFog;
+    let _e318 = f3SunColor;
+    let _e320 = uFog;
+    let _e323 = fSunAmount;
+    let _e324 = uFog;
+    f3FogColor = mix(_e315.f4Color.xyz, _e318, vec3(pow(_e323, _e324.f4SunColor.w)));
+    let _e331 = f3FogColor;
+    let _e332 = fOpacity;
+    return vec4<f32>(_e331.x, _e331.y, _e331.z, _e332);
+}
+
+fn calculateSpotAttenuation(f3PositionW_4: vec3<f32>) -> f32 {
+    var f3PositionW_5: vec3<f32>;
+    var f3ToFragment: vec3<f32>;
+    var fDistance_1: f32;
+    var fCosAngle: f32;
+    var fCone: f32;
+    var fRange: f32;
+
+    f3PositionW_5 = f3PositionW_4;
+    let _e51 = uGlobalLight;
+    if (_e51.f4Position.w <= 0f) {
+        {
+            return 1f;
+        }
+    }
+    let _e57 = f3PositionW_5;
+    let _e58 = uGlobalLight;
+    f3ToFragment = (_e57 - _e58.f4Position.xyz);
+    let _e64 = f3ToFragment;
+    fDistance_1 = length(_e64);
+    let _e67 = f3ToFragment;
+    let _e70 = fDistance_1;
+    let _e75 = uGlobalLight;
+    let _e78 = uGlobalLight;
+    let _e83 = f3ToFragment;
+    let _e86 = fDistance_1;
+    let _e91 = uGlobalLight;
+    let _e94 = uGlobalLight;
+    fCosAngle = dot((_e83 / vec3(max(_e86, 0.0001f))), -(normalize(_e94.f4Direction.xyz)));
+    let _e101 = uGlobalLight;
+    let _e104 = uGlobalLight;
+    let _e108 = uGlobalLight;
+    let _e111 = uGlobalLight;
+    let _e114 = fCosAngle;
+    fCone = smoothstep(_e108.f4Cone.y, _e111.f4Cone.x, _e114);
+    let _e118 = fDistance_1;
+    let _e119 = uGlobalLight;
+    let _e123 = uGlobalLight;
+    let _e133 = fDistance_1;
+    let _e134 = uGlobalLight;
+    let _e138 = uGlobalLight;
+    fRange = clamp((1f - (_e133 / max(_e138.f4Cone.z, 0.0001f))), 0f, 1f);
+    let _e149 = fCone;
+    let _e150 = fRange;
+    let _e152 = fRange;
+    return ((_e149 * _e150) * _e152);
+}
+
+fn main_1() {
+    var f3NormalW_4: vec3<f32>;
+    var f4Albedo: vec4<f32>;
+    var fDiffuse: f32;
+    var fShadow: f32;
+    var f4Color: vec4<f32>;
+    var f4Fog: vec4<f32>;
+
+    let _e50 = inNormalW_1;
+    let _e54 = inNormalW_1;
+    let _e56 = inTexCoord_1;
+    let _e57 = perturbNormal(normalize(_e54), _e56);
+    f3NormalW_4 = _e57;
+    let _e60 = inTexCoord_1;
+    let _e61 = textureSample(uAlbedoMap, uMaterialSampler, _e60);
+    let _e62 = uMaterial;
+    let _e65 = inColor_1;
+    f4Albedo = ((_e61 * _e62.f4ColorFactor) * _e65);
+    let _e69 = uGlobalLight;
+    let _e72 = uGlobalLight;
+    let _e76 = f3NormalW_4;
+    let _e77 = uGlobalLight;
+    let _e80 = uGlobalLight;
+    let _e87 = uGlobalLight;
+    let _e90 = uGlobalLight;
+    let _e94 = f3NormalW_4;
+    let _e95 = uGlobalLight;
+    let _e98 = uGlobalLight;
+    fDiffuse = max(dot(_e94, normalize(_e98.f4Direction.xyz)), 0f);
+    let _e107 = inLightSpaceFragPosition_1;
+    let _e108 = calculateShadow(_e107);
+    let _e110 = inPositionW_1;
+    let _e111 = calculateSpotAttenuation(_e110);
+    fShadow = (_e108 * _e111);
+    let _e114 = f4Albedo;
+    let _e117 = f4Albedo;
+    let _e118 = fShadow;
+    let _e120 = fDiffuse;
+    f4Color = ((_e114 * 0.2f) + ((_e117 * _e118) * _e120));
+    let _e124 = f4Color;
+    let _e126 = f4Color;
+    let _e130 = f3NormalW_4;
+    let _e131 = inPositionW_1;
+    let _e132 = calculateRim(_e130, _e131);
+    let _e133 = (_e126.xyz + _e132);
+    f4Color.x = _e133.x;
+    f4Color.y = _e133.y;
+    f4Color.z = _e133.z;
+    let _e141 = inPositionW_1;
+    let _e142 = calculateFog(_e141);
+    f4Fog = _e142;
+    let _e144 = f4Color;
+    let _e146 = f4Fog;
+    let _e148 = f4Fog;
+    let _e150 = f4Color;
+    let _e152 = f4Fog;
+    let _e154 = f4Fog;
+    let _e157 = mix(_e150.xyz, _e152.xyz, vec3(_e154.w));
+    let _e158 = f4Albedo;
+    outFragColor = vec4<f32>(_e157.x, _e157.y, _e157.z, _e158.w);
+    return;
+}
+
+@fragment 
+fn main(@location(0) inColor: vec4<f32>, @location(1) inNormalW: vec3<f32>, @location(2) inLightSpaceFragPosition: vec4<f32>, @location(3) inPositionW: vec3<f32>, @location(4) inRim: vec4<f32>, @location(5) inTexCoord: vec2<f32>, @location(6) inTangentW: vec4<f32>) -> FragmentOutput {
+    inColor_1 = inColor;
+    inNormalW_1 = inNormalW;
+    inLightSpaceFragPosition_1 = inLightSpaceFragPosition;
+    inPositionW_1 = inPositionW;
+    inRim_1 = inRim;
+    inTexCoord_1 = inTexCoord;
+    inTangentW_1 = inTangentW;
+    main_1();
+    let _e78 = outFragColor;
+    return FragmentOutput(_e78);
+}
@@ -0,0 +1,99 @@
+// Generated from `textured_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+    f4Rim: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outColor: vec4<f32>,
+    @location(1) outNormalW: vec3<f32>,
+    @location(2) outLightSpaceFragPosition: vec4<f32>,
+    @location(3) outPositionW: vec3<f32>,
+    @location(4) outRim: vec4<f32>,
+    @location(5) outTexCoord: vec2<f32>,
+    @location(6) outTangentW: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> inTexCoord_1: vec2<f32>;
+var<private> inTangent_1: vec4<f32>;
+var<private> outColor: vec4<f32>;
+var<private> outNormalW: vec3<f32>;
+var<private> outLightSpaceFragPosition: vec4<f32>;
+var<private> outPositionW: vec3<f32>;
+var<private> outRim: vec4<f32>;
+var<private> outTexCoord: vec2<f32>;
+var<private> outTangentW: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var f4PositionW: vec4<f32>;
+
+    let _e32 = uEntity;
+    let _e34 = inPosition_1;
+    f4PositionW = (_e32.mtxWorld * vec4<f32>(_e34.x, _e34.y, _e34.z, 1f));
+    let _e42 = uEntity;
+    outColor = _e42.f4Color;
+    let _e44 = uEntity;
+    let _e55 = inNormal_1;
+    outNormalW = (mat3x3<f32>(_e44.mtxWorld[0].xyz, _e44.mtxWorld[1].xyz, _e44.mtxWorld[2].xyz) * _e55);
+    let _e57 = uGlobalLight;
+    let _e59 = f4PositionW;
+    outLightSpaceFragPosition = (_e57.mtxProjView * _e59);
+    let _e61 = f4PositionW;
+    outPositionW = _e61.xyz;
+    let _e63 = uEntity;
+    outRim = _e63.f4Rim;
+    let _e65 = inTexCoord_1;
+    outTexCoord = _e65;
+    let _e66 = uEntity;
+    let _e77 = inTangent_1;
+    let _e79 = (mat3x3<f32>(_e66.mtxWorld[0].xyz, _e66.mtxWorld[1].xyz, _e66.mtxWorld[2].xyz) * _e77.xyz);
+    let _e80 = inTangent_1;
+    outTangentW = vec4<f32>(_e79.x, _e79.y, _e79.z, _e80.w);
+    let _e87 = uCamera;
+    let _e89 = uCamera;
+    let _e92 = f4PositionW;
+    gl_Position = ((_e87.mtxProjection * _e89.mtxView) * _e92);
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>, @location(2) inTexCoord: vec2<f32>, @location(3) inTangent: vec4<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    inTexCoord_1 = inTexCoord;
+    inTangent_1 = inTangent;
+    main_1();
+    let _e52 = outColor;
+    let _e54 = outNormalW;
+    let _e56 = outLightSpaceFragPosition;
+    let _e58 = outPositionW;
+    let _e60 = outRim;
+    let _e62 = outTexCoord;
+    let _e64 = outTangentW;
+    let _e66 = gl_Position;
+    return VertexOutput(_e52, _e54, _e56, _e58, _e60, _e62, _e64, _e66);
+}
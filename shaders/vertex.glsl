@@ -6,6 +6,8 @@ layout (location = 1) in vec3 inNormal;
 layout (location = 0) out vec4 outColor;
 layout (location = 1) out vec3 outNormalW;
 layout (location = 2) out vec4 outLightSpaceFragPosition;
+layout (location = 3) out vec3 outPositionW;
+layout (location = 4) out vec4 outRim;
 
 layout (set = 0, binding = 0) uniform CameraUniformLayout {
     mat4 mtxView;
@@ -16,6 +18,7 @@ layout (set = 0, binding = 0) uniform CameraUniformLayout {
 layout (set = 1, binding = 0) uniform ObjectUniformLayout {
     mat4 mtxWorld;
     vec4 f4Color;
+    vec4 f4Rim;
 } uEntity;
 
 layout (set = 2, binding = 0) uniform GlobalLightUniformLayout {
@@ -25,8 +28,11 @@ layout (set = 2, binding = 0) uniform GlobalLightUniformLayout {
 } uGlobalLight;
 
 void main() {
+    vec4 f4PositionW = uEntity.mtxWorld * vec4(inPosition, 1.0);
     outColor = uEntity.f4Color;
     outNormalW = mat3(uEntity.mtxWorld) * inNormal;
-    outLightSpaceFragPosition = uGlobalLight.mtxProjView * uEntity.mtxWorld * vec4(inPosition, 1.0);
-    gl_Position = uCamera.mtxProjection * uCamera.mtxView * uEntity.mtxWorld * vec4(inPosition, 1.0);
+    outLightSpaceFragPosition = uGlobalLight.mtxProjView * f4PositionW;
+    outPositionW = f4PositionW.xyz;
+    outRim = uEntity.f4Rim;
+    gl_Position = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
 }
@@ -0,0 +1,82 @@
+// Generated from `vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct ObjectUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+    f4Rim: vec4<f32>,
+}
+
+struct GlobalLightUniformLayout {
+    mtxProjView: mat4x4<f32>,
+    f4Direction: vec4<f32>,
+    f4LightColor: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outColor: vec4<f32>,
+    @location(1) outNormalW: vec3<f32>,
+    @location(2) outLightSpaceFragPosition: vec4<f32>,
+    @location(3) outPositionW: vec3<f32>,
+    @location(4) outRim: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+var<private> inPosition_1: vec3<f32>;
+var<private> inNormal_1: vec3<f32>;
+var<private> outColor: vec4<f32>;
+var<private> outNormalW: vec3<f32>;
+var<private> outLightSpaceFragPosition: vec4<f32>;
+var<private> outPositionW: vec3<f32>;
+var<private> outRim: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uEntity: ObjectUniformLayout;
+@group(2) @binding(0) 
+var<uniform> uGlobalLight: GlobalLightUniformLayout;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var f4PositionW: vec4<f32>;
+
+    let _e28 = uEntity;
+    let _e30 = inPosition_1;
+    f4PositionW = (_e28.mtxWorld * vec4<f32>(_e30.x, _e30.y, _e30.z, 1f));
+    let _e38 = uEntity;
+    outColor = _e38.f4Color;
+    let _e40 = uEntity;
+    let _e51 = inNormal_1;
+    outNormalW = (mat3x3<f32>(_e40.mtxWorld[0].xyz, _e40.mtxWorld[1].xyz, _e40.mtxWorld[2].xyz) * _e51);
+    let _e53 = uGlobalLight;
+    let _e55 = f4PositionW;
+    outLightSpaceFragPosition = (_e53.mtxProjView * _e55);
+    let _e57 = f4PositionW;
+    outPositionW = _e57.xyz;
+    let _e59 = uEntity;
+    outRim = _e59.f4Rim;
+    let _e62 = uCamera;
+    let _e64 = uCamera;
+    let _e67 = f4PositionW;
+    gl_Position = ((_e62.mtxProjection * _e64.mtxView) * _e67);
+    return;
+}
+
+@vertex 
+fn main(@location(0) inPosition: vec3<f32>, @location(1) inNormal: vec3<f32>) -> VertexOutput {
+    inPosition_1 = inPosition;
+    inNormal_1 = inNormal;
+    main_1();
+    let _e40 = outColor;
+    let _e42 = outNormalW;
+    let _e44 = outLightSpaceFragPosition;
+    let _e46 = outPositionW;
+    let _e48 = outRim;
+    let _e50 = gl_Position;
+    return VertexOutput(_e40, _e42, _e44, _e46, _e48, _e50);
+}
@@ -0,0 +1,61 @@
+#version 450 core
+
+layout (location = 0) in vec3 inPositionW;
+layout (location = 1) in vec4 inPositionC;
+
+layout (location = 0) out vec4 outColor;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform WaterUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+    vec4 f4Params;
+    vec4 f4Surface;
+} uWater;
+layout (set = 1, binding = 1) uniform texture2D tReflection;
+layout (set = 1, binding = 2) uniform sampler sReflection;
+
+// Reflectance of water looking straight down at the surface.
+const float F0 = 0.02;
+
+// Direction, relative frequency and relative speed of the summed waves.
+const vec4 WAVES[3] = vec4[3](
+    vec4( 0.80,  0.60, 1.00, 1.00), 
+    vec4(-0.45,  0.89, 1.70, 1.30), 
+    vec4( 0.20, -0.98, 2.90, 1.90)
+);
+
+// The steepness of the summed waves.
+const float SLOPE = 0.1;
+
+// The normal is built from the slopes of a sum of sine waves, so nothing about the waves is stored.
+vec3 waveNormal(vec2 f2Position) {
+    vec2 f2Slope = vec2(0.0);
+    for (int i = 0; i < 3; ++i) {
+        float fFrequency = 6.28318530718 * uWater.f4Params.w * WAVES[i].z;
+        float fPhase = fFrequency * dot(WAVES[i].xy, f2Position) + uWater.f4Params.z * WAVES[i].w * 2.0;
+        f2Slope += WAVES[i].xy * cos(fPhase) / WAVES[i].z;
+    }
+    return normalize(vec3(-SLOPE * f2Slope.x, 1.0, -SLOPE * f2Slope.y));
+}
+
+void main() {
+    vec3 f3Normal = normalize(mat3(uWater.mtxWorld) * waveNormal(inPositionW.xz));
+    vec3 f3View = normalize(uCamera.f4Position.xyz - inPositionW);
+
+    // The reflection was drawn with the same projection, so the screen position of the surface is its texture coordinate.
+    // Texture rows go from top to bottom, so the v coordinate is flipped.
+    vec2 f2TexCoord = inPositionC.xy / inPositionC.w * vec2(0.5, -0.5) + 0.5;
+    f2TexCoord = clamp(f2TexCoord + f3Normal.xz * uWater.f4Surface.x, vec2(0.001), vec2(0.999));
+    vec3 f3Reflection = texture(sampler2D(tReflection, sReflection), f2TexCoord).rgb;
+
+    // Schlick's approximation of the fresnel term.
+    float fFresnel = F0 + (1.0 - F0) * pow(1.0 - max(dot(f3Normal, f3View), 0.0), uWater.f4Surface.y);
+    vec3 f3Color = mix(uWater.f4Color.rgb, f3Reflection, fFresnel);
+    outColor = vec4(f3Color, mix(uWater.f4Color.a, 1.0, fFresnel));
+}
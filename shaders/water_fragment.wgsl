@@ -0,0 +1,156 @@
+// Generated from `water_fragment.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct WaterUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Surface: vec4<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) outColor: vec4<f32>,
+}
+
+const F0_: f32 = 0.02f;
+const WAVES: array<vec4<f32>, 3> = array<vec4<f32>, 3>(vec4<f32>(0.8f, 0.6f, 1f, 1f), vec4<f32>(-0.45f, 0.89f, 1.7f, 1.3f), vec4<f32>(0.2f, -0.98f, 2.9f, 1.9f));
+const SLOPE: f32 = 0.1f;
+
+var<private> inPositionW_1: vec3<f32>;
+var<private> inPositionC_1: vec4<f32>;
+var<private> outColor: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uWater: WaterUniformLayout;
+@group(1) @binding(1) 
+var tReflection: texture_2d<f32>;
+@group(1) @binding(2) 
+var sReflection: sampler;
+
+fn waveNormal(f2Position: vec2<f32>) -> vec3<f32> {
+    var f2Position_1: vec2<f32>;
+    var f2Slope: vec2<f32> = vec2(0f);
+    var i: i32 = 0i;
+    var local: array<vec4<f32>, 3> = WAVES;
+    var fFrequency: f32;
+    var local_1: array<vec4<f32>, 3> = WAVES;
+    var local_2: array<vec4<f32>, 3> = WAVES;
+    var local_3: array<vec4<f32>, 3> = WAVES;
+    var fPhase: f32;
+    var local_4: array<vec4<f32>, 3> = WAVES;
+    var local_5: array<vec4<f32>, 3> = WAVES;
+
+    f2Position_1 = f2Position;
+    loop {
+        let _e31 = i;
+        if !((_e31 < 3i)) {
+            break;
+        }
+        {
+            let _e39 = uWater;
+            let _e43 = i;
+            let _e47 = local[_e43];
+            fFrequency = ((6.2831855f * _e39.f4Params.w) * _e47.z);
+            let _e51 = fFrequency;
+            let _e52 = i;
+            let _e56 = local_1[_e52];
+            let _e59 = i;
+            let _e63 = local_2[_e59];
+            let _e65 = f2Position_1;
+            let _e68 = uWater;
+            let _e71 = i;
+            let _e75 = local_3[_e71];
+            fPhase = ((_e51 * dot(_e63.xy, _e65)) + ((_e68.f4Params.z * _e75.w) * 2f));
+            let _e82 = f2Slope;
+            let _e83 = i;
+            let _e87 = local_4[_e83];
+            let _e90 = fPhase;
+            let _e93 = i;
+            let _e97 = local_5[_e93];
+            f2Slope = (_e82 + ((_e87.xy * cos(_e90)) / vec2(_e97.z)));
+        }
+        continuing {
+            let _e35 = i;
+            i = (_e35 + 1i);
+        }
+    }
+    let _e104 = f2Slope;
+    let _e110 = f2Slope;
+    let _e116 = f2Slope;
+    let _e122 = f2Slope;
+    return normalize(vec3<f32>((-0.1f * _e116.x), 1f, (-0.1f * _e122.y)));
+}
+
+fn main_1() {
+    var f3Normal: vec3<f32>;
+    var f3View: vec3<f32>;
+    var f2TexCoord: vec2<f32>;
+    var f3Reflection: vec3<f32>;
+    var fFresnel: f32;
+    var f3Color: vec3<f32>;
+
+    let _e24 = uWater;
+    let _e35 = inPositionW_1;
+    let _e37 = inPositionW_1;
+    let _e39 = waveNormal(_e37.xz);
+    let _e41 = uWater;
+    let _e52 = inPositionW_1;
+    let _e54 = inPositionW_1;
+    let _e56 = waveNormal(_e54.xz);
+    f3Normal = normalize((mat3x3<f32>(_e41.mtxWorld[0].xyz, _e41.mtxWorld[1].xyz, _e41.mtxWorld[2].xyz) * _e56));
+    let _e60 = uCamera;
+    let _e63 = inPositionW_1;
+    let _e65 = uCamera;
+    let _e68 = inPositionW_1;
+    f3View = normalize((_e65.f4Position.xyz - _e68));
+    let _e72 = inPositionC_1;
+    let _e74 = inPositionC_1;
+    f2TexCoord = (((_e72.xy / vec2(_e74.w)) * vec2<f32>(0.5f, -0.5f)) + vec2(0.5f));
+    let _e87 = f2TexCoord;
+    let _e88 = f3Normal;
+    let _e90 = uWater;
+    let _e99 = f2TexCoord;
+    let _e100 = f3Normal;
+    let _e102 = uWater;
+    f2TexCoord = clamp((_e99 + (_e100.xz * _e102.f4Surface.x)), vec2(0.001f), vec2(0.999f));
+    let _e113 = f2TexCoord;
+    let _e114 = textureSample(tReflection, sReflection, _e113);
+    f3Reflection = _e114.xyz;
+    let _e123 = f3Normal;
+    let _e124 = f3View;
+    let _e129 = f3Normal;
+    let _e130 = f3View;
+    let _e135 = uWater;
+    let _e141 = f3Normal;
+    let _e142 = f3View;
+    let _e147 = f3Normal;
+    let _e148 = f3View;
+    let _e153 = uWater;
+    fFresnel = (F0_ + (0.98f * pow((1f - max(dot(_e147, _e148), 0f)), _e153.f4Surface.y)));
+    let _e161 = uWater;
+    let _e166 = uWater;
+    let _e169 = f3Reflection;
+    let _e170 = fFresnel;
+    f3Color = mix(_e166.f4Color.xyz, _e169, vec3(_e170));
+    let _e174 = f3Color;
+    let _e175 = uWater;
+    let _e180 = uWater;
+    let _e184 = fFresnel;
+    outColor = vec4<f32>(_e174.x, _e174.y, _e174.z, mix(_e180.f4Color.w, 1f, _e184));
+    return;
+}
+
+@fragment 
+fn main(@location(0) inPositionW: vec3<f32>, @location(1) inPositionC: vec4<f32>) -> FragmentOutput {
+    inPositionW_1 = inPositionW;
+    inPositionC_1 = inPositionC;
+    main_1();
+    let _e37 = outColor;
+    return FragmentOutput(_e37);
+}
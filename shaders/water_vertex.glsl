@@ -0,0 +1,30 @@
+#version 450 core
+
+layout (location = 0) out vec3 outPositionW;
+layout (location = 1) out vec4 outPositionC;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 mtxView;
+    mat4 mtxProjection;
+    vec4 f4Position;
+} uCamera;
+
+layout (set = 1, binding = 0) uniform WaterUniformLayout {
+    mat4 mtxWorld;
+    vec4 f4Color;
+    vec4 f4Params;
+    vec4 f4Surface;
+} uWater;
+
+const vec2 QUAD[6] = vec2[6](
+    vec2(-1.0, -1.0), vec2(-1.0,  1.0), vec2( 1.0,  1.0), 
+    vec2( 1.0,  1.0), vec2( 1.0, -1.0), vec2(-1.0, -1.0)
+);
+
+void main() {
+    vec2 f2Corner = QUAD[gl_VertexIndex] * uWater.f4Params.xy;
+    vec4 f4PositionW = uWater.mtxWorld * vec4(f2Corner.x, 0.0, f2Corner.y, 1.0);
+    outPositionW = f4PositionW.xyz;
+    outPositionC = uCamera.mtxProjection * uCamera.mtxView * f4PositionW;
+    gl_Position = outPositionC;
+}
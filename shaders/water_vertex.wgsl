@@ -0,0 +1,65 @@
+// Generated from `water_vertex.glsl` by naga. Regenerate it after editing the GLSL source.
+
+struct CameraUniformLayout {
+    mtxView: mat4x4<f32>,
+    mtxProjection: mat4x4<f32>,
+    f4Position: vec4<f32>,
+}
+
+struct WaterUniformLayout {
+    mtxWorld: mat4x4<f32>,
+    f4Color: vec4<f32>,
+    f4Params: vec4<f32>,
+    f4Surface: vec4<f32>,
+}
+
+struct VertexOutput {
+    @location(0) outPositionW: vec3<f32>,
+    @location(1) outPositionC: vec4<f32>,
+    @builtin(position) member: vec4<f32>,
+}
+
+const QUAD: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(-1f, -1f), vec2<f32>(-1f, 1f), vec2<f32>(1f, 1f), vec2<f32>(1f, 1f), vec2<f32>(1f, -1f), vec2<f32>(-1f, -1f));
+
+var<private> outPositionW: vec3<f32>;
+var<private> outPositionC: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uCamera: CameraUniformLayout;
+@group(1) @binding(0) 
+var<uniform> uWater: WaterUniformLayout;
+var<private> gl_VertexIndex: u32;
+var<private> gl_Position: vec4<f32>;
+
+fn main_1() {
+    var local: array<vec2<f32>, 6> = QUAD;
+    var f2Corner: vec2<f32>;
+    var f4PositionW: vec4<f32>;
+
+    let _e20 = gl_VertexIndex;
+    let _e24 = local[_e20];
+    let _e25 = uWater;
+    f2Corner = (_e24 * _e25.f4Params.xy);
+    let _e30 = uWater;
+    let _e32 = f2Corner;
+    let _e35 = f2Corner;
+    f4PositionW = (_e30.mtxWorld * vec4<f32>(_e32.x, 0f, _e35.y, 1f));
+    let _e41 = f4PositionW;
+    outPositionW = _e41.xyz;
+    let _e43 = uCamera;
+    let _e45 = uCamera;
+    let _e48 = f4PositionW;
+    outPositionC = ((_e43.mtxProjection * _e45.mtxView) * _e48);
+    let _e51 = outPositionC;
+    gl_Position = _e51;
+    return;
+}
+
+@vertex 
+fn main(@builtin(vertex_index) param: u32) -> VertexOutput {
+    gl_VertexIndex = param;
+    main_1();
+    let _e25 = outPositionW;
+    let _e27 = outPositionC;
+    let _e29 = gl_Position;
+    return VertexOutput(_e25, _e27, _e29);
+}
@@ -0,0 +1,53 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering as MemOrdering};
+
+
+
+static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// #### 한국어 </br>
+/// 시스템 할당자를 감싸 힙 할당 횟수를 세는 전역 할당자 입니다. </br>
+/// 모든 스레드의 할당을 세므로, 창 메시지 루프와 그래픽 드라이버의 할당도 포함됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A global allocator that wraps the system allocator and counts the number of heap allocations. </br>
+/// It counts the allocations of all threads, so the allocations of the window message loop and the graphics driver are included too. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, MemOrdering::Relaxed);
+        System.alloc(layout)
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, MemOrdering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, MemOrdering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// #### 한국어 </br>
+/// 프로그램이 시작된 후 힙 할당(재할당 포함)이 일어난 횟수를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the number of heap allocations (including reallocations) since the program started. </br>
+/// 
+#[inline]
+pub fn num_allocations() -> usize {
+    NUM_ALLOCATIONS.load(MemOrdering::Relaxed)
+}
@@ -0,0 +1,152 @@
+use std::io;
+use std::fmt::Write;
+use std::time::Instant;
+
+use crate::timer;
+
+
+
+/// #### 한국어 </br>
+/// 벤치마크 모드를 켜는 명령줄 인수 입니다. 뒤에 렌더링할 프레임의 수가 옵니다. (예: `--bench 600`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that turns on the benchmark mode. It is followed by the number of frames to render. (e.g. `--bench 600`) </br>
+/// 
+pub const BENCH_ARG: &str = "--bench";
+
+/// #### 한국어 </br>
+/// `BENCH_ARG` 명령줄 인수의 값을 렌더링할 프레임의 수로 해석합니다. 양의 정수가 아니면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `BENCH_ARG` command line argument as the number of frames to render. Returns an `InvalidInput` error if it is not a positive integer. </br>
+/// 
+pub fn parse_num_frames(value: &str) -> io::Result<u32> {
+    match value.parse::<u32>() {
+        Ok(num_frames) if num_frames > 0 => Ok(num_frames), 
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!("{} must be followed by a positive number of frames", BENCH_ARG)
+        )), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 정해진 수의 프레임을 렌더링하며 프레임 시간과 그리기 호출 수를 모으는 벤치마크 입니다. </br>
+/// 카메라는 매번 같은 경로를 따라 장면 주위를 한 바퀴 돕니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A benchmark that renders a fixed number of frames and collects the frame times and draw call counts. </br>
+/// The camera goes around the scene once along the same path every time. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct Benchmark {
+    num_frames: u32, 
+    frame_times_ms: Vec<f64>, 
+    draw_calls: Vec<usize>, 
+    frame_start: Option<Instant>, 
+}
+
+#[allow(dead_code)]
+impl Benchmark {
+    #[inline]
+    pub fn new(num_frames: u32) -> Self {
+        Self {
+            num_frames: num_frames.max(1), 
+            frame_times_ms: Vec::with_capacity(num_frames as usize), 
+            draw_calls: Vec::with_capacity(num_frames as usize), 
+            frame_start: None, 
+        }
+    }
+
+    #[inline]
+    pub fn num_frames(&self) -> u32 {
+        self.num_frames
+    }
+
+    #[inline]
+    pub fn num_recorded_frames(&self) -> u32 {
+        self.frame_times_ms.len() as u32
+    }
+
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.num_recorded_frames() >= self.num_frames
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 프레임에서 카메라의 월드 변환 행렬을 반환합니다. 첫 프레임은 기본 카메라 위치와 같습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the world transform matrix of the camera at the current frame. The first frame matches the default camera position. </br>
+    /// 
+    pub fn camera_transform(&self) -> glam::Mat4 {
+        let angle = std::f32::consts::TAU * self.num_recorded_frames() as f32 / self.num_frames as f32;
+        let rotation = glam::Quat::from_rotation_y(angle) * glam::Quat::from_rotation_x(-15.0f32.to_radians());
+        let translation = glam::vec3(8.0 * angle.sin(), 3.5, 8.0 * angle.cos());
+        glam::Mat4::from_rotation_translation(rotation, translation)
+    }
+
+    #[inline]
+    pub fn begin_frame(&mut self) {
+        self.frame_start = Some(Instant::now());
+    }
+
+    /// #### 한국어 </br>
+    /// `begin_frame` 이후 걸린 시간과 이번 프레임의 그리기 호출 수를 기록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the time taken since `begin_frame` and the number of draw calls of this frame. </br>
+    /// 
+    pub fn end_frame(&mut self, num_draw_calls: usize) {
+        if let Some(frame_start) = self.frame_start.take() {
+            self.frame_times_ms.push(frame_start.elapsed().as_secs_f64() * 1000.0);
+            self.draw_calls.push(num_draw_calls);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 프레임 시간의 평균과 백분위수, 그리기 호출 수, 주어진 자원 통계를 한 줄의 JSON으로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the mean and percentiles of the frame times, the draw call counts and the given resource stats as a single line of JSON. </br>
+    /// 
+    pub fn report(&self, resources: &[(&str, usize)]) -> String {
+        let mut sorted = self.frame_times_ms.clone();
+        sorted.sort_by(f64::total_cmp);
+        let percentile = |p: f64| timer::percentile(&sorted, p);
+        let mean = |values: &mut dyn Iterator<Item = f64>| {
+            let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| (sum + value, count + 1));
+            if count > 0 { sum / count as f64 } else { 0.0 }
+        };
+
+        let mut report = String::new();
+        write!(
+            &mut report, 
+            "{{\"frames\":{},\"mean_ms\":{:.3},\"min_ms\":{:.3},\"p50_ms\":{:.3},\"p90_ms\":{:.3},\"p95_ms\":{:.3},\"p99_ms\":{:.3},\"max_ms\":{:.3}", 
+            sorted.len(), 
+            mean(&mut sorted.iter().copied()), 
+            sorted.first().copied().unwrap_or(0.0), 
+            percentile(50.0), 
+            percentile(90.0), 
+            percentile(95.0), 
+            percentile(99.0), 
+            sorted.last().copied().unwrap_or(0.0)
+        ).unwrap();
+        write!(
+            &mut report, 
+            ",\"mean_draw_calls\":{:.1},\"max_draw_calls\":{}", 
+            mean(&mut self.draw_calls.iter().map(|&count| count as f64)), 
+            self.draw_calls.iter().copied().max().unwrap_or(0)
+        ).unwrap();
+
+        report.push_str(",\"resources\":{");
+        for (i, (name, value)) in resources.iter().enumerate() {
+            if i > 0 {
+                report.push(',');
+            }
+            write!(&mut report, "\"{}\":{}", name, value).unwrap();
+        }
+        report.push_str("}}");
+        return report;
+    }
+}
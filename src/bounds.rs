@@ -0,0 +1,246 @@
+/// #### 한국어 </br>
+/// 축 정렬 경계 상자(AABB) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An axis-aligned bounding box (AABB). </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: glam::Vec3, 
+    pub max: glam::Vec3, 
+}
+
+#[allow(dead_code)]
+impl Aabb {
+    #[inline]
+    pub fn new(min: glam::Vec3, max: glam::Vec3) -> Self {
+        Self { min: min.min(max), max: max.max(min) }
+    }
+
+    #[inline]
+    pub fn from_center_half_extents(center: glam::Vec3, half_extents: glam::Vec3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// #### 한국어 </br>
+    /// 점들을 모두 포함하는 가장 작은 축 정렬 경계 상자를 생성합니다. 점이 없으면 원점의 상자를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the smallest axis-aligned bounding box containing all the points. Returns the box at the origin if there are no points. </br>
+    /// 
+    pub fn from_points(points: &[glam::Vec3]) -> Self {
+        match points.split_first() {
+            Some((first, rest)) => rest.iter().fold(
+                Self { min: *first, max: *first }, 
+                |aabb, point| Self { min: aabb.min.min(*point), max: aabb.max.max(*point) }
+            ), 
+            None => Self { min: glam::Vec3::ZERO, max: glam::Vec3::ZERO }, 
+        }
+    }
+
+    #[inline]
+    pub fn center(&self) -> glam::Vec3 {
+        0.5 * (self.min + self.max)
+    }
+
+    #[inline]
+    pub fn half_extents(&self) -> glam::Vec3 {
+        0.5 * (self.max - self.min)
+    }
+
+    #[inline]
+    pub fn surface_area(&self) -> f32 {
+        let d = self.max - self.min;
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Self { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    #[inline]
+    pub fn expand(&self, margin: f32) -> Aabb {
+        Self { min: self.min - margin, max: self.max + margin }
+    }
+
+    #[inline]
+    pub fn contains(&self, other: &Aabb) -> bool {
+        self.min.cmple(other.min).all() && self.max.cmpge(other.max).all()
+    }
+
+    #[inline]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.cmple(other.max).all() && self.max.cmpge(other.min).all()
+    }
+
+    /// #### 한국어 </br>
+    /// 변환 행렬을 적용한 상자를 감싸는 새로운 축 정렬 경계 상자를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns a new axis-aligned bounding box enclosing the box with the transform matrix applied. </br>
+    /// 
+    pub fn transform(&self, matrix: &glam::Mat4) -> Aabb {
+        let center = matrix.transform_point3(self.center());
+        let half_extents = self.half_extents();
+        let abs = glam::Mat3::from_cols(
+            matrix.x_axis.truncate().abs(), 
+            matrix.y_axis.truncate().abs(), 
+            matrix.z_axis.truncate().abs(), 
+        );
+        Self::from_center_half_extents(center, abs * half_extents)
+    }
+
+    /// #### 한국어 </br>
+    /// 광선이 상자와 교차하면 광선의 시작점에서 교차점까지의 거리를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the ray intersects the box, returns the distance from the origin of the ray to the intersection. </br>
+    /// 
+    pub fn ray_intersection(&self, ray: &Ray) -> Option<f32> {
+        let inv_direction = ray.direction.recip();
+        let t0 = (self.min - ray.origin) * inv_direction;
+        let t1 = (self.max - ray.origin) * inv_direction;
+        let t_near = t0.min(t1).max_element().max(0.0);
+        let t_far = t0.max(t1).min_element();
+        (t_near <= t_far).then_some(t_near)
+    }
+}
+
+/// #### 한국어 </br>
+/// 중심과 반지름을 가지는 경계 구 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A bounding sphere with a center and a radius. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    pub center: glam::Vec3, 
+    pub radius: f32, 
+}
+
+#[allow(dead_code)]
+impl Sphere {
+    #[inline]
+    pub fn new(center: glam::Vec3, radius: f32) -> Self {
+        Self { center, radius: radius.abs() }
+    }
+
+    /// #### 한국어 </br>
+    /// 축 정렬 경계 상자를 감싸는 경계 구를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a bounding sphere enclosing the axis-aligned bounding box. </br>
+    /// 
+    #[inline]
+    pub fn from_aabb(aabb: &Aabb) -> Self {
+        Self::new(aabb.center(), aabb.half_extents().length())
+    }
+
+    /// #### 한국어 </br>
+    /// 점들의 경계 상자 중심을 중심으로 하여 점들을 모두 포함하는 경계 구를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a bounding sphere containing all the points, centered at the center of their bounding box. </br>
+    /// 
+    pub fn from_points(points: &[glam::Vec3]) -> Self {
+        let center = Aabb::from_points(points).center();
+        let radius = points.iter().fold(0.0f32, |radius, point| radius.max(point.distance(center)));
+        Self::new(center, radius)
+    }
+
+    /// #### 한국어 </br>
+    /// 변환 행렬을 적용한 구를 감싸는 새로운 경계 구를 반환합니다. 반지름은 가장 큰 축의 배율만큼 커집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns a new bounding sphere enclosing the sphere with the transform matrix applied. The radius grows by the scale of the largest axis. </br>
+    /// 
+    pub fn transform(&self, matrix: &glam::Mat4) -> Sphere {
+        let scale = matrix.x_axis.truncate().length()
+            .max(matrix.y_axis.truncate().length())
+            .max(matrix.z_axis.truncate().length());
+        Self::new(matrix.transform_point3(self.center), self.radius * scale)
+    }
+}
+
+/// #### 한국어 </br>
+/// 시작점과 정규화된 방향을 가지는 광선 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A ray with an origin and a normalized direction. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: glam::Vec3, 
+    pub direction: glam::Vec3, 
+}
+
+#[allow(dead_code)]
+impl Ray {
+    #[inline]
+    pub fn new(origin: glam::Vec3, direction: glam::Vec3) -> Self {
+        Self { origin, direction: direction.normalize() }
+    }
+
+    #[inline]
+    pub fn point_at(&self, distance: f32) -> glam::Vec3 {
+        self.origin + self.direction * distance
+    }
+}
+
+/// #### 한국어 </br>
+/// 투영-뷰 행렬에서 추출한 6개의 평면으로 이루어진 절두체 입니다. </br>
+/// 평면의 법선은 절두체의 안쪽을 향합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A frustum made of 6 planes extracted from a projection-view matrix. </br>
+/// The normals of the planes point to the inside of the frustum. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    pub planes: [glam::Vec4; 6], 
+}
+
+impl Frustum {
+    /// #### 한국어 </br>
+    /// `wgpu`의 깊이 범위(0 ~ 1)를 사용하는 투영-뷰 행렬에서 절두체를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a frustum from a projection-view matrix using the depth range of `wgpu` (0 to 1). </br>
+    /// 
+    pub fn from_matrix(proj_view: &glam::Mat4) -> Self {
+        let r0 = proj_view.row(0);
+        let r1 = proj_view.row(1);
+        let r2 = proj_view.row(2);
+        let r3 = proj_view.row(3);
+        let planes = [r3 + r0, r3 - r0, r3 + r1, r3 - r1, r2, r3 - r2]
+            .map(|plane| plane / plane.truncate().length());
+        Self { planes }
+    }
+
+    /// #### 한국어 </br>
+    /// 축 정렬 경계 상자가 절두체와 겹치는지 보수적으로 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Conservatively tests whether the axis-aligned bounding box overlaps the frustum. </br>
+    /// 
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+        self.planes.iter().all(|plane| {
+            let normal = plane.truncate();
+            let radius = half_extents.dot(normal.abs());
+            normal.dot(center) + plane.w >= -radius
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 경계 구가 절두체와 겹치는지 보수적으로 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Conservatively tests whether the bounding sphere overlaps the frustum. </br>
+    /// 
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes.iter().all(|plane| plane.truncate().dot(sphere.center) + plane.w >= -sphere.radius)
+    }
+}
@@ -0,0 +1,362 @@
+use crate::bounds::{Aabb, Frustum, Ray};
+
+
+
+/// #### 한국어 </br>
+/// 경계 볼륨 계층(BVH)에 삽입된 항목을 가리키는 핸들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A handle pointing to an item inserted into the bounding volume hierarchy (BVH). </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BvhHandle(usize);
+
+#[derive(Debug)]
+enum NodeKind<T> {
+    Leaf { tight: Aabb, value: T }, 
+    Branch { children: [usize; 2] }, 
+    Free, 
+}
+
+#[derive(Debug)]
+struct Node<T> {
+    aabb: Aabb, 
+    parent: Option<usize>, 
+    kind: NodeKind<T>, 
+}
+
+/// #### 한국어 </br>
+/// 물체들의 축 정렬 경계 상자 위에 만들어지는 동적 경계 볼륨 계층 입니다. </br>
+/// 잎 노드는 `margin`만큼 넓힌 상자를 저장하므로 조금씩 움직이는 물체는 다시 삽입되지 않으며, 
+/// 삽입 시에는 표면적 비용이 가장 작은 형제 노드를 선택합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A dynamic bounding volume hierarchy built over the axis-aligned bounding boxes of objects. </br>
+/// Leaf nodes store boxes enlarged by `margin`, so objects moving slightly are not reinserted, 
+/// and insertion chooses the sibling node with the smallest surface area cost. </br>
+/// 
+#[derive(Debug)]
+pub struct Bvh<T> {
+    nodes: Vec<Node<T>>, 
+    free_list: Vec<usize>, 
+    root: Option<usize>, 
+    margin: f32, 
+    len: usize, 
+}
+
+impl<T> Default for Bvh<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+#[allow(dead_code)]
+impl<T> Bvh<T> {
+    #[inline]
+    pub fn new(margin: f32) -> Self {
+        assert!(margin >= 0.0);
+        Self {
+            nodes: Vec::new(), 
+            free_list: Vec::new(), 
+            root: None, 
+            margin, 
+            len: 0
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// #### 한국어 </br>
+    /// 항목의 값을 반환합니다. 제거된 핸들이면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the value of the item. Returns `None` if the handle has been removed. </br>
+    /// 
+    #[inline]
+    pub fn get(&self, handle: BvhHandle) -> Option<&T> {
+        match self.nodes.get(handle.0).map(|node| &node.kind) {
+            Some(NodeKind::Leaf { value, .. }) => Some(value), 
+            _ => None, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 경계 상자와 값을 삽입하고 핸들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Inserts a bounding box and a value and returns a handle. </br>
+    /// 
+    pub fn insert(&mut self, aabb: Aabb, value: T) -> BvhHandle {
+        let leaf = self.allocate(Node {
+            aabb: aabb.expand(self.margin), 
+            parent: None, 
+            kind: NodeKind::Leaf { tight: aabb, value }, 
+        });
+        self.insert_leaf(leaf);
+        self.len += 1;
+        BvhHandle(leaf)
+    }
+
+    /// #### 한국어 </br>
+    /// 항목을 제거하고 값을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes the item and returns its value. </br>
+    /// 
+    pub fn remove(&mut self, handle: BvhHandle) -> Option<T> {
+        if !matches!(self.nodes.get(handle.0).map(|node| &node.kind), Some(NodeKind::Leaf { .. })) {
+            return None;
+        }
+
+        self.remove_leaf(handle.0);
+        self.len -= 1;
+        match self.free(handle.0) {
+            NodeKind::Leaf { value, .. } => Some(value), 
+            _ => unreachable!(), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 항목의 경계 상자를 갱신합니다. 새로운 상자가 넓혀진 상자를 벗어날 때만 다시 삽입하며, 
+    /// 다시 삽입되었으면 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the bounding box of the item. It is reinserted only when the new box leaves the enlarged box, 
+    /// and returns `true` if it was reinserted. </br>
+    /// 
+    pub fn update(&mut self, handle: BvhHandle, aabb: Aabb) -> bool {
+        let node = &mut self.nodes[handle.0];
+        match &mut node.kind {
+            NodeKind::Leaf { tight, .. } => *tight = aabb, 
+            _ => panic!("invalid BVH handle: {:?}", handle), 
+        }
+
+        if node.aabb.contains(&aabb) {
+            return false;
+        }
+
+        self.remove_leaf(handle.0);
+        self.nodes[handle.0].aabb = aabb.expand(self.margin);
+        self.insert_leaf(handle.0);
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// 절두체와 겹치는 모든 항목을 방문합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Visits every item overlapping the frustum. </br>
+    /// 
+    pub fn query_frustum<F>(&self, frustum: &Frustum, mut visit: F)
+    where F: FnMut(BvhHandle, &T) {
+        self.traverse(|aabb| frustum.intersects_aabb(aabb), |handle, tight, value| {
+            if frustum.intersects_aabb(tight) {
+                visit(handle, value);
+            }
+        });
+    }
+
+    /// #### 한국어 </br>
+    /// 경계 상자와 겹치는 모든 항목을 방문합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Visits every item overlapping the bounding box. </br>
+    /// 
+    pub fn query_aabb<F>(&self, aabb: &Aabb, mut visit: F)
+    where F: FnMut(BvhHandle, &T) {
+        self.traverse(|node| node.intersects(aabb), |handle, tight, value| {
+            if tight.intersects(aabb) {
+                visit(handle, value);
+            }
+        });
+    }
+
+    /// #### 한국어 </br>
+    /// 광선과 가장 가까이에서 교차하는 항목과 그 거리를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the item intersecting the ray nearest and its distance. </br>
+    /// 
+    #[inline]
+    pub fn query_ray(&self, ray: &Ray) -> Option<(BvhHandle, f32)> {
+        self.query_ray_with(ray, f32::INFINITY, |_, tight, _| tight.ray_intersection(ray))
+    }
+
+    /// #### 한국어 </br>
+    /// 경계 상자가 광선과 `max_distance` 안에서 교차하는 항목들을 `test`로 세밀하게 검사하여, 
+    /// 가장 가까운 항목과 `test`가 반환한 거리를 반환합니다. 
+    /// 지금까지 찾은 가장 가까운 거리보다 먼 노드는 방문하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Tests in detail with `test` the items whose bounding boxes intersect the ray within `max_distance`, 
+    /// and returns the nearest item and the distance returned by `test`. 
+    /// Nodes farther than the nearest distance found so far are not visited. </br>
+    /// 
+    pub fn query_ray_with<F>(&self, ray: &Ray, max_distance: f32, mut test: F) -> Option<(BvhHandle, f32)>
+    where F: FnMut(BvhHandle, &Aabb, &T) -> Option<f32> {
+        let mut nearest: Option<(BvhHandle, f32)> = None;
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let Some(distance) = node.aabb.ray_intersection(ray) else {
+                continue;
+            };
+            let limit = nearest.map_or(max_distance, |(_, nearest)| nearest);
+            if distance > limit {
+                continue;
+            }
+
+            match &node.kind {
+                NodeKind::Leaf { tight, value } => {
+                    if let Some(distance) = test(BvhHandle(index), tight, value) {
+                        if distance <= limit && nearest.map_or(true, |(_, nearest)| distance < nearest) {
+                            nearest = Some((BvhHandle(index), distance));
+                        }
+                    }
+                }, 
+                NodeKind::Branch { children } => stack.extend_from_slice(children), 
+                NodeKind::Free => unreachable!(), 
+            }
+        }
+        return nearest;
+    }
+
+    fn traverse<P, F>(&self, mut predicate: P, mut visit: F)
+    where P: FnMut(&Aabb) -> bool, F: FnMut(BvhHandle, &Aabb, &T) {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !predicate(&node.aabb) {
+                continue;
+            }
+
+            match &node.kind {
+                NodeKind::Leaf { tight, value } => visit(BvhHandle(index), tight, value), 
+                NodeKind::Branch { children } => stack.extend_from_slice(children), 
+                NodeKind::Free => unreachable!(), 
+            }
+        }
+    }
+
+    fn allocate(&mut self, node: Node<T>) -> usize {
+        match self.free_list.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }, 
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn free(&mut self, index: usize) -> NodeKind<T> {
+        self.free_list.push(index);
+        self.nodes[index].parent = None;
+        std::mem::replace(&mut self.nodes[index].kind, NodeKind::Free)
+    }
+
+    fn insert_leaf(&mut self, leaf: usize) {
+        let Some(root) = self.root else {
+            self.nodes[leaf].parent = None;
+            self.root = Some(leaf);
+            return;
+        };
+
+        // (한국어) 표면적 비용이 가장 작아지는 형제 노드를 찾습니다.
+        // (English Translation) Finds the sibling node that minimizes the surface area cost.
+        let leaf_aabb = self.nodes[leaf].aabb;
+        let mut sibling = root;
+        while let NodeKind::Branch { children } = self.nodes[sibling].kind {
+            let area = self.nodes[sibling].aabb.surface_area();
+            let combined_area = self.nodes[sibling].aabb.union(&leaf_aabb).surface_area();
+            let cost = 2.0 * combined_area;
+            let inheritance_cost = 2.0 * (combined_area - area);
+
+            let child_cost = |child: usize| {
+                let aabb = self.nodes[child].aabb;
+                let union_area = aabb.union(&leaf_aabb).surface_area();
+                match self.nodes[child].kind {
+                    NodeKind::Leaf { .. } => union_area + inheritance_cost, 
+                    _ => union_area - aabb.surface_area() + inheritance_cost, 
+                }
+            };
+            let cost_0 = child_cost(children[0]);
+            let cost_1 = child_cost(children[1]);
+
+            if cost < cost_0 && cost < cost_1 {
+                break;
+            }
+            sibling = if cost_0 < cost_1 { children[0] } else { children[1] };
+        }
+
+        // (한국어) 형제 노드와 새로운 잎 노드를 자식으로 가지는 부모 노드를 만듭니다.
+        // (English Translation) Creates a parent node that has the sibling and the new leaf as children.
+        let old_parent = self.nodes[sibling].parent;
+        let new_parent = self.allocate(Node {
+            aabb: self.nodes[sibling].aabb.union(&leaf_aabb), 
+            parent: old_parent, 
+            kind: NodeKind::Branch { children: [sibling, leaf] }, 
+        });
+        self.nodes[sibling].parent = Some(new_parent);
+        self.nodes[leaf].parent = Some(new_parent);
+
+        match old_parent {
+            Some(old_parent) => self.replace_child(old_parent, sibling, new_parent), 
+            None => self.root = Some(new_parent), 
+        }
+
+        self.refit(old_parent);
+    }
+
+    fn remove_leaf(&mut self, leaf: usize) {
+        if self.root == Some(leaf) {
+            self.root = None;
+            return;
+        }
+
+        let parent = self.nodes[leaf].parent.expect("leaf must have a parent");
+        let sibling = match self.nodes[parent].kind {
+            NodeKind::Branch { children } => if children[0] == leaf { children[1] } else { children[0] }, 
+            _ => unreachable!(), 
+        };
+
+        let grand_parent = self.nodes[parent].parent;
+        self.nodes[sibling].parent = grand_parent;
+        match grand_parent {
+            Some(grand_parent) => self.replace_child(grand_parent, parent, sibling), 
+            None => self.root = Some(sibling), 
+        }
+        self.free(parent);
+        self.nodes[leaf].parent = None;
+
+        self.refit(grand_parent);
+    }
+
+    fn replace_child(&mut self, parent: usize, old_child: usize, new_child: usize) {
+        if let NodeKind::Branch { children } = &mut self.nodes[parent].kind {
+            for child in children.iter_mut().filter(|child| **child == old_child) {
+                *child = new_child;
+            }
+        }
+    }
+
+    fn refit(&mut self, mut index: Option<usize>) {
+        while let Some(current) = index {
+            if let NodeKind::Branch { children } = self.nodes[current].kind {
+                self.nodes[current].aabb = self.nodes[children[0]].aabb.union(&self.nodes[children[1]].aabb);
+            }
+            index = self.nodes[current].parent;
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use std::mem;
 use bytemuck::{Pod, Zeroable};
 
-use crate::{object::GameObject, resource::ShaderResource};
+use winit::{keyboard::KeyCode, event::MouseButton};
+
+use crate::{input::InputState, object::GameObject, replay::InputEvent, resource::{FrameUniformBuffer, ShaderResource}};
 
 
 
@@ -14,6 +16,26 @@ use crate::{object::GameObject, resource::ShaderResource};
 pub trait GameCameraObject : GameObject {
     fn view_transform(&self) -> glam::Mat4;
     fn projection_transform(&self) -> glam::Mat4;
+
+    /// #### 한국어 </br>
+    /// 월드 공간의 점을 화면 공간(좌측 상단 기준, 픽셀 단위)으로 변환합니다. </br>
+    /// 점이 카메라 뒤에 있으면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts a point in world space to screen space (top-left origin, in pixels). </br>
+    /// Returns `None` if the point is behind the camera. </br>
+    /// 
+    fn world_to_screen(&self, point: glam::Vec3, width: f32, height: f32) -> Option<glam::Vec2> {
+        let clip = self.projection_transform()
+            .mul_mat4(&self.view_transform())
+            .mul_vec4((point, 1.0).into());
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = glam::vec2(clip.x, clip.y) / clip.w;
+        Some(glam::vec2((ndc.x * 0.5 + 0.5) * width, (0.5 - ndc.y * 0.5) * height))
+    }
 }
 
 /// #### 한국어 </br>
@@ -55,6 +77,12 @@ impl PerspectiveCameraBuilder {
         Self::default()
     }
 
+    #[inline]
+    pub fn set_fov_y_radian(mut self, fov_y_radian: f32) -> Self {
+        self.fov_y_radian = fov_y_radian;
+        self
+    }
+
     #[inline]
     pub fn set_width(mut self, width: f32) -> Self {
         self.width = width;
@@ -106,28 +134,12 @@ impl PerspectiveCameraBuilder {
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) -> PerspectiveCamera {
-        let uniform_buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some("Uniform(PerspectiveCamera)"), 
-                mapped_at_creation: false, 
-                size: mem::size_of::<CameraUniformLayout>() as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-
-        let bind_group = device.create_bind_group(
-            &wgpu::BindGroupDescriptor {
-                label: Some("BindGroup(PerspectiveCamera)"), 
-                layout: bind_group_layout, 
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0, 
-                        resource: wgpu::BindingResource::Buffer(
-                            uniform_buffer.as_entire_buffer_binding()
-                        ),
-                    },
-                ],
-            },
+        let uniform = FrameUniformBuffer::new(
+            "PerspectiveCamera", 
+            mem::size_of::<CameraUniformLayout>() as wgpu::BufferAddress, 
+            0, 
+            bind_group_layout, 
+            device
         );
 
         let camera = PerspectiveCamera {
@@ -139,8 +151,7 @@ impl PerspectiveCameraBuilder {
             aspect_ratio: self.width / self.height, 
             near_z: self.near_z, 
             far_z: self.far_z, 
-            uniform_buffer, 
-            uniform_bind_group: bind_group, 
+            uniform, 
         };
         camera.update_resource(queue);
 
@@ -161,8 +172,37 @@ pub struct PerspectiveCamera {
     aspect_ratio: f32, 
     near_z: f32, 
     far_z: f32, 
-    uniform_buffer: wgpu::Buffer, 
-    pub uniform_bind_group: wgpu::BindGroup, 
+    uniform: FrameUniformBuffer, 
+}
+
+#[allow(dead_code)]
+impl PerspectiveCamera {
+    /// #### 한국어 </br>
+    /// 마지막으로 갱신된 유니폼 데이터를 묶는 바인드 그룹을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bind group binding the most recently updated uniform data. </br>
+    /// 
+    #[inline]
+    pub fn uniform_bind_group(&self) -> &wgpu::BindGroup {
+        self.uniform.bind_group()
+    }
+
+    #[inline]
+    pub fn fov_y_radian(&self) -> f32 {
+        self.fov_y_radian
+    }
+
+    /// #### 한국어 </br>
+    /// 수직 시야각을 변경합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the vertical field of view. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn set_fov_y_radian(&mut self, fov_y_radian: f32) {
+        self.fov_y_radian = fov_y_radian.clamp(1.0f32.to_radians(), 179.0f32.to_radians());
+    }
 }
 
 impl GameObject for PerspectiveCamera {
@@ -205,7 +245,7 @@ impl ShaderResource for PerspectiveCamera {
             projection: self.projection_transform(), 
             position: (self.get_translation(), 0.0).into(), 
         };
-        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+        self.uniform.write(bytemuck::bytes_of(&data), queue);
     }
 }
 
@@ -234,3 +274,485 @@ impl Default for CameraUniformLayout {
         }
     }
 }
+
+/// #### 한국어 </br>
+/// 직교 투영 카메라를 생성하는 빌더 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a builder that creates an orthographic projection camera. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrthographicCameraBuilder {
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub width: f32, 
+    pub height: f32, 
+    pub near_z: f32, 
+    pub far_z: f32, 
+}
+
+impl Default for OrthographicCameraBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY, 
+            width: 800.0, 
+            height: 600.0, 
+            near_z: -1.0, 
+            far_z: 1.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl OrthographicCameraBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    #[inline]
+    pub fn set_height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.rotation = rotation.normalize();
+        self
+    }
+
+    #[inline]
+    pub fn set_near_z(mut self, near_z: f32) -> Self {
+        self.near_z = near_z;
+        self
+    }
+
+    #[inline]
+    pub fn set_far_z(mut self, far_z: f32) -> Self {
+        self.far_z = far_z;
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> OrthographicCamera {
+        let uniform = FrameUniformBuffer::new(
+            "OrthographicCamera", 
+            mem::size_of::<CameraUniformLayout>() as wgpu::BufferAddress, 
+            0, 
+            bind_group_layout, 
+            device
+        );
+
+        let camera = OrthographicCamera {
+            transform: glam::Mat4::from_rotation_translation(
+                self.rotation.normalize(), 
+                self.translation
+            ), 
+            width: self.width, 
+            height: self.height, 
+            near_z: self.near_z, 
+            far_z: self.far_z, 
+            uniform, 
+        };
+        camera.update_resource(queue);
+
+        return camera;
+    }
+}
+
+/// #### 한국어 </br>
+/// 화면 공간(좌측 상단 기준, 픽셀 단위)을 비추는 직교 투영 카메라입니다. </br>
+/// HUD와 같은 2D 요소를 그릴 때 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An orthographic projection camera that looks at screen space (top-left origin, in pixels). </br>
+/// It is used to draw 2D elements such as the HUD. </br>
+/// 
+#[derive(Debug)]
+pub struct OrthographicCamera {
+    transform: glam::Mat4, 
+    width: f32, 
+    height: f32, 
+    near_z: f32, 
+    far_z: f32, 
+    uniform: FrameUniformBuffer, 
+}
+
+impl OrthographicCamera {
+    /// #### 한국어 </br>
+    /// 마지막으로 갱신된 유니폼 데이터를 묶는 바인드 그룹을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bind group binding the most recently updated uniform data. </br>
+    /// 
+    #[inline]
+    pub fn uniform_bind_group(&self) -> &wgpu::BindGroup {
+        self.uniform.bind_group()
+    }
+
+    /// #### 한국어 </br>
+    /// 화면의 크기를 변경합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the size of the screen. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn set_size(&mut self, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+impl GameObject for OrthographicCamera {
+    #[inline]
+    fn world_transform_ref(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    #[inline]
+    fn world_transform_mut(&mut self) -> &mut glam::Mat4 {
+        &mut self.transform
+    }
+}
+
+impl GameCameraObject for OrthographicCamera {
+    fn view_transform(&self) -> glam::Mat4 {
+        let right = self.get_right();
+        let up = self.get_up();
+        let look = self.get_look();
+        let position = self.get_translation();
+        return glam::mat4(
+            glam::vec4(right.x, up.x, look.x, 0.0), 
+            glam::vec4(right.y, up.y, look.y, 0.0), 
+            glam::vec4(right.z, up.z, look.z, 0.0), 
+            glam::vec4(-position.dot(right), -position.dot(up), -position.dot(look), 1.0)
+        );
+    }
+
+    #[inline]
+    fn projection_transform(&self) -> glam::Mat4 {
+        glam::Mat4::orthographic_rh(0.0, self.width, self.height, 0.0, self.near_z, self.far_z)
+    }
+}
+
+impl ShaderResource for OrthographicCamera {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = CameraUniformLayout {
+            view: self.view_transform(), 
+            projection: self.projection_transform(), 
+            position: (self.get_translation(), 0.0).into(), 
+        };
+        self.uniform.write(bytemuck::bytes_of(&data), queue);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 키보드와 마우스 입력으로 카메라를 자유롭게 움직이는 컨트롤러 입니다. </br>
+/// W/S/A/D로 앞뒤와 좌우로, E/Q로 위아래로 움직이며, 마우스 오른쪽 버튼을 누른 채로 마우스를 움직이면 주위를 둘러봅니다. </br>
+/// 이벤트를 직접 처리하지 않고 매 프레임 입력 상태를 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A controller that moves the camera freely with keyboard and mouse input. </br>
+/// W/S/A/D move forward, backward and sideways, E/Q move up and down, and moving the mouse while holding the right mouse button looks around. </br>
+/// It does not handle the events itself but reads the input state every frame. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraController {
+    move_speed: f32, 
+    look_sensitivity: f32, 
+    mouse_look: bool, 
+}
+
+#[allow(dead_code)]
+impl CameraController {
+    /// #### 한국어 </br>
+    /// 초당 이동 거리와 마우스 이동 1 픽셀당 회전각(라디안)으로 컨트롤러를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a controller with the distance moved per second and the rotation angle (in radians) per pixel of mouse motion. </br>
+    /// 
+    #[inline]
+    pub fn new(move_speed: f32, look_sensitivity: f32) -> Self {
+        Self {
+            move_speed, 
+            look_sensitivity, 
+            mouse_look: false, 
+        }
+    }
+
+    #[inline]
+    pub fn move_speed(&self) -> f32 {
+        self.move_speed
+    }
+
+    #[inline]
+    pub fn set_move_speed(&mut self, move_speed: f32) {
+        self.move_speed = move_speed.max(0.0);
+    }
+
+    #[inline]
+    pub fn is_mouse_look(&self) -> bool {
+        self.mouse_look
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 오른쪽 버튼을 누르지 않아도 마우스 이동으로 주위를 둘러보게 합니다. 커서를 창에 가둔 동안 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Lets the mouse motion look around without holding the right mouse button. It is used while the cursor is grabbed by the window. </br>
+    /// 
+    #[inline]
+    pub fn set_mouse_look(&mut self, mouse_look: bool) {
+        self.mouse_look = mouse_look;
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 입력 상태로 카메라를 회전하고 이동합니다. 카메라가 바뀌었으면 `true`를 반환합니다. </br>
+    /// 위아래 회전은 카메라가 뒤집히지 않도록 수직에 가까워지면 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Rotates and moves the camera with the input state of this frame. Returns `true` if the camera changed. </br>
+    /// The up and down rotation stops near the vertical so the camera does not flip over. </br>
+    /// 
+    pub fn update<T: GameObject>(&mut self, camera: &mut T, input: &InputState, elapsed_time_sec: f32) -> bool {
+        let mut changed = false;
+
+        let look_delta = input.mouse_delta();
+        if (self.mouse_look || input.is_button_pressed(MouseButton::Right)) && look_delta != glam::Vec2::ZERO {
+            let yaw = glam::Quat::from_rotation_y(-look_delta.x * self.look_sensitivity);
+            let pitch = glam::Quat::from_rotation_x(-look_delta.y * self.look_sensitivity);
+            let rotation = camera.get_rotation();
+            let pitched = (yaw * rotation * pitch).normalize();
+            match (pitched * glam::Vec3::Z).y.abs() < 0.99 {
+                true => camera.set_rotation(pitched), 
+                false => camera.set_rotation((yaw * rotation).normalize()), 
+            }
+            changed = true;
+        }
+
+        let axis = |positive: KeyCode, negative: KeyCode| input.is_key_pressed(positive) as i32 as f32 - input.is_key_pressed(negative) as i32 as f32;
+        let direction = -camera.get_look() * axis(KeyCode::KeyW, KeyCode::KeyS)
+            + camera.get_right() * axis(KeyCode::KeyD, KeyCode::KeyA)
+            + glam::Vec3::Y * axis(KeyCode::KeyE, KeyCode::KeyQ);
+        if direction != glam::Vec3::ZERO {
+            camera.translate_world(direction.normalize() * self.move_speed * elapsed_time_sec);
+            changed = true;
+        }
+
+        return changed;
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 초점을 중심으로 카메라를 궤도 운동시키는 컨트롤러 입니다. </br>
+/// 마우스 왼쪽 버튼을 누른 채로 끌면 초점 주위를 돌고, 가운데 버튼으로 끌면 초점을 옮기며, 휠로 가까워지거나 멀어집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A controller that orbits the camera around a focus point. </br>
+/// Dragging with the left mouse button rotates around the focus, dragging with the middle button pans the focus, and the wheel zooms in and out. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCameraController {
+    focus: glam::Vec3, 
+    distance: f32, 
+    min_distance: f32, 
+    max_distance: f32, 
+    yaw: f32, 
+    pitch: f32, 
+    rotate_sensitivity: f32, 
+    pan_sensitivity: f32, 
+    zoom_factor: f32, 
+    rotating: bool, 
+    panning: bool, 
+    last_cursor: Option<glam::Vec2>, 
+    rotate_delta: glam::Vec2, 
+    pan_delta: glam::Vec2, 
+    zoom_lines: f32, 
+    dirty: bool, 
+}
+
+#[allow(dead_code)]
+impl OrbitCameraController {
+    const MAX_PITCH: f32 = 89.0 * std::f32::consts::PI / 180.0;
+
+    /// #### 한국어 </br>
+    /// 초점, 초점까지의 거리와 커서 이동 1 픽셀당 회전각(라디안)으로 컨트롤러를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a controller with the focus point, the distance to the focus and the rotation angle (in radians) per pixel of cursor motion. </br>
+    /// 
+    #[inline]
+    pub fn new(focus: glam::Vec3, distance: f32, rotate_sensitivity: f32) -> Self {
+        Self {
+            focus, 
+            distance: distance.clamp(0.5, 100.0), 
+            min_distance: 0.5, 
+            max_distance: 100.0, 
+            yaw: 0.0, 
+            pitch: 0.0, 
+            rotate_sensitivity, 
+            pan_sensitivity: 0.0015, 
+            zoom_factor: 0.9, 
+            rotating: false, 
+            panning: false, 
+            last_cursor: None, 
+            rotate_delta: glam::Vec2::ZERO, 
+            pan_delta: glam::Vec2::ZERO, 
+            zoom_lines: 0.0, 
+            dirty: true, 
+        }
+    }
+
+    #[inline]
+    pub fn focus(&self) -> glam::Vec3 {
+        self.focus
+    }
+
+    #[inline]
+    pub fn set_focus(&mut self, focus: glam::Vec3) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    #[inline]
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// #### 한국어 </br>
+    /// 휠로 바꿀 수 있는 초점까지의 거리의 범위를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the range of the distance to the focus that the wheel can change. </br>
+    /// 
+    #[inline]
+    pub fn set_distance_range(&mut self, min_distance: f32, max_distance: f32) {
+        self.min_distance = min_distance.max(0.01);
+        self.max_distance = max_distance.max(self.min_distance);
+        self.distance = self.distance.clamp(self.min_distance, self.max_distance);
+        self.dirty = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 현재 위치와 방향을 유지하도록 초점과 궤도 각도를 맞춥니다. 초점은 카메라 앞쪽 현재 거리에 놓입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Fits the focus and the orbit angles so the current position and direction of the camera are kept. The focus lies ahead of the camera at the current distance. </br>
+    /// 
+    pub fn fit_to<T: GameObject>(&mut self, camera: &T) {
+        let back = camera.get_look().normalize_or_zero();
+        self.pitch = (-back.y).clamp(-1.0, 1.0).asin().clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+        self.yaw = back.x.atan2(back.z);
+        self.focus = camera.get_translation() - back * self.distance;
+        self.dirty = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 입력 이벤트를 처리합니다. 컨트롤러가 사용한 이벤트이면 `true`를 반환합니다. </br>
+    /// 커서 이동은 다른 곳에서도 커서 위치가 필요하므로 항상 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Handles an input event. Returns `true` if the event was used by the controller. </br>
+    /// Cursor moves always return `false` since the cursor position is needed elsewhere too. </br>
+    /// 
+    pub fn handle_input(&mut self, input: &InputEvent) -> bool {
+        match *input {
+            InputEvent::CursorMoved { x, y } => {
+                let cursor = glam::vec2(x as f32, y as f32);
+                if let Some(last_cursor) = self.last_cursor {
+                    let delta = cursor - last_cursor;
+                    if self.rotating {
+                        self.rotate_delta += delta;
+                    } else if self.panning {
+                        self.pan_delta += delta;
+                    }
+                }
+                self.last_cursor = Some(cursor);
+                false
+            }, 
+            InputEvent::LeftMousePressed => {
+                self.rotating = true;
+                true
+            }, 
+            InputEvent::LeftMouseReleased => {
+                self.rotating = false;
+                true
+            }, 
+            InputEvent::MiddleMouse { pressed } => {
+                self.panning = pressed;
+                true
+            }, 
+            InputEvent::MouseWheel { lines } => {
+                self.zoom_lines += lines;
+                true
+            }, 
+            _ => false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모인 입력으로 궤도를 갱신하고 카메라를 옮깁니다. 카메라가 바뀌었으면 `true`를 반환합니다. </br>
+    /// 위아래 회전은 카메라가 뒤집히지 않도록 수직에 가까워지면 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the orbit with the collected input and moves the camera. Returns `true` if the camera changed. </br>
+    /// The up and down rotation stops near the vertical so the camera does not flip over. </br>
+    /// 
+    pub fn update<T: GameObject>(&mut self, camera: &mut T) -> bool {
+        if self.rotate_delta != glam::Vec2::ZERO {
+            self.yaw -= self.rotate_delta.x * self.rotate_sensitivity;
+            self.pitch = (self.pitch - self.rotate_delta.y * self.rotate_sensitivity).clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+            self.rotate_delta = glam::Vec2::ZERO;
+            self.dirty = true;
+        }
+
+        let rotation = glam::Quat::from_rotation_y(self.yaw) * glam::Quat::from_rotation_x(self.pitch);
+        if self.pan_delta != glam::Vec2::ZERO {
+            // (한국어) 초점이 멀수록 같은 커서 이동에 더 많이 옮겨, 화면 위의 장면이 커서를 따라오게 합니다.
+            // (English Translation) Pans more for the same cursor motion when the focus is farther, so the scene on the screen follows the cursor.
+            let scale = self.pan_sensitivity * self.distance;
+            self.focus += rotation * glam::vec3(-self.pan_delta.x, self.pan_delta.y, 0.0) * scale;
+            self.pan_delta = glam::Vec2::ZERO;
+            self.dirty = true;
+        }
+
+        if self.zoom_lines != 0.0 {
+            self.distance = (self.distance * self.zoom_factor.powf(self.zoom_lines)).clamp(self.min_distance, self.max_distance);
+            self.zoom_lines = 0.0;
+            self.dirty = true;
+        }
+
+        if !self.dirty {
+            return false;
+        }
+
+        camera.set_rotation(rotation);
+        camera.set_translation(self.focus + rotation * glam::Vec3::Z * self.distance);
+        self.dirty = false;
+        return true;
+    }
+}
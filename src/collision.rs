@@ -0,0 +1,780 @@
+use std::fmt;
+use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+
+use crate::bounds::{Aabb, Ray};
+use crate::bvh::{Bvh, BvhHandle};
+
+
+
+/// #### 한국어 </br>
+/// 물체에 붙이는 충돌 모양 입니다. 크기는 물체의 로컬 공간 기준입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A collision shape attached to an object. Its size is in the local space of the object. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CollisionShape {
+    Sphere { radius: f32 }, 
+    Aabb { half_extents: glam::Vec3 }, 
+    Obb { half_extents: glam::Vec3 }, 
+}
+
+/// #### 한국어 </br>
+/// 월드 공간의 방향성 경계 상자(OBB) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An oriented bounding box (OBB) in world space. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb {
+    pub center: glam::Vec3, 
+    pub axes: [glam::Vec3; 3], 
+    pub half_extents: glam::Vec3, 
+}
+
+impl Obb {
+    /// #### 한국어 </br>
+    /// 주어진 점에서 가장 가까운 상자 안의 점을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the point inside the box closest to the given point. </br>
+    /// 
+    pub fn closest_point(&self, point: glam::Vec3) -> glam::Vec3 {
+        let offset = point - self.center;
+        let mut closest = self.center;
+        for (axis, half_extent) in self.axes.iter().zip(self.half_extents.to_array()) {
+            closest += *axis * offset.dot(*axis).clamp(-half_extent, half_extent);
+        }
+        return closest;
+    }
+
+    /// #### 한국어 </br>
+    /// 상자를 주어진 축에 투영했을 때의 반지름을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the radius of the box projected onto the given axis. </br>
+    /// 
+    #[inline]
+    pub fn projected_radius(&self, axis: glam::Vec3) -> f32 {
+        self.axes.iter()
+            .zip(self.half_extents.to_array())
+            .map(|(box_axis, half_extent)| half_extent * box_axis.dot(axis).abs())
+            .sum()
+    }
+}
+
+/// #### 한국어 </br>
+/// 월드 공간으로 변환된 충돌 모양 입니다. 축 정렬 상자는 축이 고정된 OBB로 다룹니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A collision shape transformed into world space. Axis-aligned boxes are treated as OBBs with fixed axes. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorldShape {
+    Sphere { center: glam::Vec3, radius: f32 }, 
+    Obb(Obb), 
+}
+
+impl WorldShape {
+    /// #### 한국어 </br>
+    /// 로컬 공간의 충돌 모양을 주어진 월드 변환 행렬로 변환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Transforms a collision shape in local space by the given world transform matrix. </br>
+    /// 
+    pub fn new(shape: &CollisionShape, transform: &glam::Mat4) -> Self {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        match *shape {
+            CollisionShape::Sphere { radius } => WorldShape::Sphere {
+                center: translation, 
+                radius: radius * scale.abs().max_element(), 
+            }, 
+            CollisionShape::Aabb { half_extents } => {
+                let aabb = Aabb::from_center_half_extents(glam::Vec3::ZERO, half_extents).transform(transform);
+                WorldShape::Obb(Obb {
+                    center: aabb.center(), 
+                    axes: [glam::Vec3::X, glam::Vec3::Y, glam::Vec3::Z], 
+                    half_extents: aabb.half_extents(), 
+                })
+            }, 
+            CollisionShape::Obb { half_extents } => {
+                let mat = glam::Mat3::from_quat(rotation);
+                WorldShape::Obb(Obb {
+                    center: translation, 
+                    axes: [mat.x_axis, mat.y_axis, mat.z_axis], 
+                    half_extents: half_extents * scale.abs(), 
+                })
+            }, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모양을 감싸는 축 정렬 경계 상자를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the axis-aligned bounding box enclosing the shape. </br>
+    /// 
+    pub fn aabb(&self) -> Aabb {
+        match self {
+            WorldShape::Sphere { center, radius } => Aabb::from_center_half_extents(*center, glam::Vec3::splat(*radius)), 
+            WorldShape::Obb(obb) => Aabb::from_center_half_extents(obb.center, glam::vec3(
+                obb.projected_radius(glam::Vec3::X), 
+                obb.projected_radius(glam::Vec3::Y), 
+                obb.projected_radius(glam::Vec3::Z)
+            )), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 광선이 모양과 교차하면 광선 시작점으로부터의 거리와 교차점의 법선을 반환합니다. </br>
+    /// 광선이 모양 안에서 시작하면 교차하지 않은 것으로 처리합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the ray intersects the shape, returns the distance from the origin of the ray and the normal at the intersection. </br>
+    /// A ray starting inside the shape is treated as not intersecting. </br>
+    /// 
+    pub fn ray_intersection(&self, ray: &Ray) -> Option<(f32, glam::Vec3)> {
+        match self {
+            WorldShape::Sphere { center, radius } => {
+                let offset = ray.origin - *center;
+                let c = offset.length_squared() - radius * radius;
+                let b = offset.dot(ray.direction);
+                if c < 0.0 || b > 0.0 {
+                    return None;
+                }
+
+                let discriminant = b * b - c;
+                if discriminant < 0.0 {
+                    return None;
+                }
+                let distance = -b - discriminant.sqrt();
+                Some((distance, (ray.point_at(distance) - *center) / *radius))
+            }, 
+            WorldShape::Obb(obb) => {
+                // (한국어) 상자의 축마다 두 평면 사이의 구간을 구해 겹치는 구간을 찾습니다.
+                // (English Translation) Finds the interval between the two planes on each box axis and intersects the intervals.
+                let offset = ray.origin - obb.center;
+                let mut t_near = f32::NEG_INFINITY;
+                let mut t_far = f32::INFINITY;
+                let mut normal = glam::Vec3::ZERO;
+                for (axis, half_extent) in obb.axes.iter().zip(obb.half_extents.to_array()) {
+                    let origin = offset.dot(*axis);
+                    let direction = ray.direction.dot(*axis);
+                    if direction.abs() < f32::EPSILON {
+                        if origin.abs() > half_extent {
+                            return None;
+                        }
+                        continue;
+                    }
+
+                    let t0 = (-half_extent - origin) / direction;
+                    let t1 = (half_extent - origin) / direction;
+                    let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+                    if t0 > t_near {
+                        t_near = t0;
+                        normal = if direction > 0.0 { -*axis } else { *axis };
+                    }
+                    t_far = t_far.min(t1);
+                    if t_near > t_far {
+                        return None;
+                    }
+                }
+                (t_near >= 0.0).then_some((t_near, normal))
+            }, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 광선이 삼각형과 교차하면 광선 시작점으로부터의 거리를 반환합니다. (Möller–Trumbore) </br>
+/// 광선의 방향은 정규화되지 않아도 되며, 거리는 방향의 길이를 단위로 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// If the ray intersects the triangle, returns the distance from the origin of the ray. (Möller–Trumbore) </br>
+/// The direction of the ray does not have to be normalized, and the distance is in units of the length of the direction. </br>
+/// 
+pub fn ray_triangle(origin: glam::Vec3, direction: glam::Vec3, triangle: &[glam::Vec3; 3]) -> Option<f32> {
+    let edge0 = triangle[1] - triangle[0];
+    let edge1 = triangle[2] - triangle[0];
+    let p = direction.cross(edge1);
+    let determinant = edge0.dot(p);
+    if determinant.abs() < 1e-8 {
+        return None;
+    }
+
+    let inv_determinant = determinant.recip();
+    let offset = origin - triangle[0];
+    let u = offset.dot(p) * inv_determinant;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = offset.cross(edge0);
+    let v = direction.dot(q) * inv_determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let distance = edge1.dot(q) * inv_determinant;
+    (distance >= 0.0).then_some(distance)
+}
+
+/// #### 한국어 </br>
+/// 두 모양이 겹친 정보 입니다. `normal`은 첫 번째 모양에서 두 번째 모양을 향합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Information about two overlapping shapes. `normal` points from the first shape toward the second. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub normal: glam::Vec3, 
+    pub depth: f32, 
+}
+
+/// #### 한국어 </br>
+/// 두 월드 공간 모양의 세부 충돌 검사를 수행합니다. 겹치지 않으면 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Performs the narrow-phase test between two shapes in world space. Returns `None` if they do not overlap. </br>
+/// 
+pub fn test_shapes(a: &WorldShape, b: &WorldShape) -> Option<Contact> {
+    match (a, b) {
+        (WorldShape::Sphere { center: ca, radius: ra }, WorldShape::Sphere { center: cb, radius: rb }) => {
+            sphere_sphere(*ca, *ra, *cb, *rb)
+        }, 
+        (WorldShape::Sphere { center, radius }, WorldShape::Obb(obb)) => {
+            sphere_obb(*center, *radius, obb)
+        }, 
+        (WorldShape::Obb(obb), WorldShape::Sphere { center, radius }) => {
+            sphere_obb(*center, *radius, obb).map(|contact| Contact { normal: -contact.normal, ..contact })
+        }, 
+        (WorldShape::Obb(a), WorldShape::Obb(b)) => obb_obb(a, b), 
+    }
+}
+
+fn sphere_sphere(ca: glam::Vec3, ra: f32, cb: glam::Vec3, rb: f32) -> Option<Contact> {
+    let offset = cb - ca;
+    let distance = offset.length();
+    if distance > ra + rb {
+        return None;
+    }
+
+    let normal = if distance > f32::EPSILON { offset / distance } else { glam::Vec3::Y };
+    Some(Contact { normal, depth: ra + rb - distance })
+}
+
+fn sphere_obb(center: glam::Vec3, radius: f32, obb: &Obb) -> Option<Contact> {
+    let closest = obb.closest_point(center);
+    let offset = closest - center;
+    let distance = offset.length();
+    if distance > radius {
+        return None;
+    }
+
+    if distance > f32::EPSILON {
+        return Some(Contact { normal: offset / distance, depth: radius - distance });
+    }
+
+    // (한국어) 구의 중심이 상자 안에 있으면 가장 가까운 면을 밀어내는 방향으로 사용합니다.
+    // (English Translation) If the center of the sphere is inside the box, the nearest face is used as the push direction.
+    let local = center - obb.center;
+    let (normal, depth) = obb.axes.iter()
+        .zip(obb.half_extents.to_array())
+        .map(|(axis, half_extent)| {
+            let distance = local.dot(*axis);
+            let normal = if distance > 0.0 { -*axis } else { *axis };
+            (normal, half_extent - distance.abs())
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap();
+    Some(Contact { normal, depth: depth + radius })
+}
+
+/// #### 한국어 </br>
+/// 분리 축 정리로 두 OBB를 검사합니다. 두 상자의 면 축 6개와 모서리 외적 축 9개를 검사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Tests two OBBs with the separating axis theorem. It checks the 6 face axes and the 9 edge cross product axes of the two boxes. </br>
+/// 
+fn obb_obb(a: &Obb, b: &Obb) -> Option<Contact> {
+    let offset = b.center - a.center;
+    let mut best: Option<Contact> = None;
+    let face_axes = a.axes.iter().chain(b.axes.iter()).copied();
+    let edge_axes = a.axes.iter().flat_map(|axis_a| b.axes.iter().map(move |axis_b| axis_a.cross(*axis_b)));
+    for axis in face_axes.chain(edge_axes) {
+        // (한국어) 평행한 모서리의 외적은 0이므로 건너뜁니다.
+        // (English Translation) The cross product of parallel edges is zero, so it is skipped.
+        let length = axis.length();
+        if length < 1e-6 {
+            continue;
+        }
+        let axis = axis / length;
+
+        let distance = offset.dot(axis);
+        let depth = a.projected_radius(axis) + b.projected_radius(axis) - distance.abs();
+        if depth < 0.0 {
+            return None;
+        }
+
+        if best.map_or(true, |best| depth < best.depth) {
+            let normal = if distance < 0.0 { -axis } else { axis };
+            best = Some(Contact { normal, depth });
+        }
+    }
+    return best;
+}
+
+
+
+/// #### 한국어 </br>
+/// 충돌 세계에 등록된 충돌체를 가리키는 번호 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An id pointing to a collider registered in the collision world. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColliderId(usize);
+
+#[derive(Debug)]
+struct Collider<T> {
+    owner: T, 
+    shape: CollisionShape, 
+    transform: glam::Mat4, 
+    world: WorldShape, 
+    mesh: Option<Arc<[[glam::Vec3; 3]]>>, 
+    is_trigger: bool, 
+    handle: BvhHandle, 
+}
+
+/// #### 한국어 </br>
+/// 광선 검사에 맞은 물체와 교차점 정보 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The object hit by a raycast and the intersection information. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<T> {
+    pub object: T, 
+    pub collider: ColliderId, 
+    pub distance: f32, 
+    pub point: glam::Vec3, 
+    pub normal: glam::Vec3, 
+}
+
+/// #### 한국어 </br>
+/// 두 충돌체가 닿기 시작했는지, 계속 닿아 있는지, 떨어졌는지를 나타냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Indicates whether two colliders started touching, are still touching, or separated. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionPhase {
+    Begin, 
+    Stay, 
+    End, 
+}
+
+/// #### 한국어 </br>
+/// 고정 갱신마다 발생하는 충돌 이벤트 입니다. `End` 이벤트의 `contact`는 `None` 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A collision event emitted per fixed update. The `contact` of an `End` event is `None`. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CollisionEvent<T> {
+    pub phase: CollisionPhase, 
+    pub colliders: (ColliderId, ColliderId), 
+    pub owners: (T, T), 
+    pub contact: Option<Contact>, 
+}
+
+/// #### 한국어 </br>
+/// 충돌체가 트리거 볼륨에 들어왔는지, 나갔는지를 나타냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Indicates whether a collider entered or exited a trigger volume. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerPhase {
+    Enter, 
+    Exit, 
+}
+
+/// #### 한국어 </br>
+/// 트리거 볼륨과 겹치기 시작하거나 끝난 충돌체를 알리는 이벤트 입니다. </br>
+/// `owners`는 (트리거의 소유자, 충돌체의 소유자) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An event notifying a collider that started or stopped overlapping a trigger volume. </br>
+/// `owners` is (owner of the trigger, owner of the collider). </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriggerEvent<T> {
+    pub phase: TriggerPhase, 
+    pub trigger: ColliderId, 
+    pub collider: ColliderId, 
+    pub owners: (T, T), 
+}
+
+/// #### 한국어 </br>
+/// 트리거 이벤트가 생길 때마다 호출되는 함수들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The functions called whenever a trigger event occurs. </br>
+/// 
+struct TriggerListeners<T>(Vec<Box<dyn FnMut(&TriggerEvent<T>)>>);
+
+impl<T> fmt::Debug for TriggerListeners<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TriggerListeners")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+/// #### 한국어 </br>
+/// 물체에 붙인 충돌체들을 고정된 시간 간격마다 검사하는 충돌 검출 세계 입니다. </br>
+/// 시간 간격은 다른 시뮬레이션과 같은 `timer::FixedTimestep`이 정하며, 고정 갱신마다 `fixed_update`를 호출합니다. </br>
+/// 넓은 단계에서는 경계 볼륨 계층으로 겹칠 수 있는 쌍을 찾고, 좁은 단계에서 모양별 검사를 수행하며, 
+/// 이전 갱신의 접촉과 비교하여 `Begin`, `Stay`, `End` 이벤트를 만듭니다. </br>
+/// 트리거 볼륨은 접촉을 만들지 않고 광선에도 맞지 않으며, 대신 겹친 다른 충돌체들과 `Enter`, `Exit` 이벤트를 만듭니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A collision detection world that tests the colliders attached to objects at a fixed time step. </br>
+/// The time step is set by the same `timer::FixedTimestep` as the rest of the simulation, and `fixed_update` is called per fixed update. </br>
+/// The broad phase finds possibly overlapping pairs with the bounding volume hierarchy, the narrow phase runs the per-shape tests, 
+/// and `Begin`, `Stay` and `End` events are made by comparing with the contacts of the previous update. </br>
+/// Trigger volumes do not make contacts and are not hit by rays; instead they make `Enter` and `Exit` events with the other colliders overlapping them. </br>
+/// 
+#[derive(Debug)]
+pub struct CollisionWorld<T> {
+    colliders: Vec<Option<Collider<T>>>, 
+    free_list: Vec<usize>, 
+    bvh: Bvh<ColliderId>, 
+    contacts: HashMap<(ColliderId, ColliderId), Contact>, 
+    events: Vec<CollisionEvent<T>>, 
+    trigger_overlaps: HashSet<(ColliderId, ColliderId)>, 
+    trigger_events: Vec<TriggerEvent<T>>, 
+    trigger_listeners: TriggerListeners<T>, 
+}
+
+impl<T: fmt::Debug + Clone> Default for CollisionWorld<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(dead_code)]
+impl<T: fmt::Debug + Clone> CollisionWorld<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            colliders: Vec::new(), 
+            free_list: Vec::new(), 
+            bvh: Bvh::default(), 
+            contacts: HashMap::new(), 
+            events: Vec::new(), 
+            trigger_overlaps: HashSet::new(), 
+            trigger_events: Vec::new(), 
+            trigger_listeners: TriggerListeners(Vec::new())
+        }
+    }
+
+    #[inline]
+    pub fn num_colliders(&self) -> usize {
+        self.bvh.len()
+    }
+
+    #[inline]
+    pub fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    pub fn owner(&self, id: ColliderId) -> Option<T> {
+        self.collider(id).map(|collider| collider.owner.clone())
+    }
+
+    #[inline]
+    pub fn world_shape(&self, id: ColliderId) -> Option<&WorldShape> {
+        self.collider(id).map(|collider| &collider.world)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 월드 변환 행렬에 놓인 충돌체를 등록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers a collider placed at the given world transform matrix. </br>
+    /// 
+    #[inline]
+    pub fn insert(&mut self, owner: T, shape: CollisionShape, transform: &glam::Mat4) -> ColliderId {
+        self.insert_collider(owner, shape, transform, false)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 월드 변환 행렬에 놓인 트리거 볼륨을 등록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers a trigger volume placed at the given world transform matrix. </br>
+    /// 
+    #[inline]
+    pub fn insert_trigger(&mut self, owner: T, shape: CollisionShape, transform: &glam::Mat4) -> ColliderId {
+        self.insert_collider(owner, shape, transform, true)
+    }
+
+    #[inline]
+    pub fn is_trigger(&self, id: ColliderId) -> bool {
+        self.collider(id).is_some_and(|collider| collider.is_trigger)
+    }
+
+    fn insert_collider(&mut self, owner: T, shape: CollisionShape, transform: &glam::Mat4, is_trigger: bool) -> ColliderId {
+        let index = self.free_list.pop().unwrap_or_else(|| {
+            self.colliders.push(None);
+            self.colliders.len() - 1
+        });
+        let id = ColliderId(index);
+        let world = WorldShape::new(&shape, transform);
+        let handle = self.bvh.insert(world.aabb(), id);
+        self.colliders[index] = Some(Collider { owner, shape, transform: *transform, world, mesh: None, is_trigger, handle });
+        return id;
+    }
+
+    /// #### 한국어 </br>
+    /// 충돌체를 제거합니다. 닿아 있던 충돌체들과의 접촉은 바로 `End` 이벤트로 끝납니다. </br>
+    /// 트리거 볼륨과의 겹침도 바로 `Exit` 이벤트로 끝납니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes the collider. Contacts with the colliders it was touching end immediately with an `End` event. </br>
+    /// Overlaps with trigger volumes also end immediately with an `Exit` event. </br>
+    /// 
+    pub fn remove(&mut self, id: ColliderId) -> Option<T> {
+        self.collider(id)?;
+        let mut ended: Vec<_> = self.contacts.keys()
+            .filter(|(a, b)| *a == id || *b == id)
+            .copied()
+            .collect();
+        ended.sort();
+        for pair in ended {
+            self.contacts.remove(&pair);
+            self.push_event(CollisionPhase::End, pair, None);
+        }
+
+        let mut exited: Vec<_> = self.trigger_overlaps.iter()
+            .filter(|(trigger, collider)| *trigger == id || *collider == id)
+            .copied()
+            .collect();
+        exited.sort();
+        for pair in exited {
+            self.trigger_overlaps.remove(&pair);
+            self.push_trigger_event(TriggerPhase::Exit, pair);
+        }
+
+        let collider = self.colliders[id.0].take()?;
+        self.bvh.remove(collider.handle);
+        self.free_list.push(id.0);
+        Some(collider.owner)
+    }
+
+    /// #### 한국어 </br>
+    /// 충돌체가 붙은 물체의 월드 변환 행렬을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the world transform matrix of the object the collider is attached to. </br>
+    /// 
+    pub fn set_transform(&mut self, id: ColliderId, transform: &glam::Mat4) {
+        let collider = self.colliders[id.0].as_mut().expect("invalid collider id");
+        collider.transform = *transform;
+        collider.world = WorldShape::new(&collider.shape, transform);
+        self.bvh.update(collider.handle, collider.world.aabb());
+    }
+
+    /// #### 한국어 </br>
+    /// 광선 검사에 사용할 로컬 공간의 삼각형들을 설정합니다. </br>
+    /// 삼각형이 있으면 충돌 모양은 경계로만 사용되고, 광선은 삼각형들과 검사됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the triangles in local space used for raycasts. </br>
+    /// If there are triangles, the collision shape is only used as a bound, and rays are tested against the triangles. </br>
+    /// 
+    pub fn set_mesh(&mut self, id: ColliderId, triangles: Option<Arc<[[glam::Vec3; 3]]>>) {
+        self.colliders[id.0].as_mut().expect("invalid collider id").mesh = triangles;
+    }
+
+    /// #### 한국어 </br>
+    /// `origin`에서 `direction`으로 `max_distance`까지 광선을 쏘아 가장 가까이에서 맞은 물체를 반환합니다. </br>
+    /// 넓은 단계는 경계 볼륨 계층으로, 세부 검사는 충돌 모양이나 설정된 삼각형들로 수행합니다. </br>
+    /// 광선이 충돌 모양 안에서 시작하면 그 물체는 맞지 않은 것으로 처리합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Casts a ray from `origin` toward `direction` up to `max_distance` and returns the nearest object hit. </br>
+    /// The broad phase uses the bounding volume hierarchy, and the detailed test uses the collision shape or the triangles set. </br>
+    /// If the ray starts inside a collision shape, that object is treated as not hit. </br>
+    /// 
+    pub fn raycast(&self, origin: glam::Vec3, direction: glam::Vec3, max_distance: f32) -> Option<RayHit<T>> {
+        let ray = Ray::new(origin, direction);
+        let (handle, _) = self.bvh.query_ray_with(&ray, max_distance, |_, _, &id| {
+            self.ray_test(id, &ray).map(|(distance, _)| distance)
+        })?;
+
+        let id = *self.bvh.get(handle).unwrap();
+        let (distance, normal) = self.ray_test(id, &ray)?;
+        Some(RayHit {
+            object: self.colliders[id.0].as_ref().unwrap().owner.clone(), 
+            collider: id, 
+            distance, 
+            point: ray.point_at(distance), 
+            normal, 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 쌓인 충돌 이벤트들을 꺼냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Takes out the accumulated collision events. </br>
+    /// 
+    #[inline]
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, CollisionEvent<T>> {
+        self.events.drain(..)
+    }
+
+    /// #### 한국어 </br>
+    /// 쌓인 트리거 이벤트들을 꺼냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Takes out the accumulated trigger events. </br>
+    /// 
+    #[inline]
+    pub fn drain_trigger_events(&mut self) -> std::vec::Drain<'_, TriggerEvent<T>> {
+        self.trigger_events.drain(..)
+    }
+
+    /// #### 한국어 </br>
+    /// 트리거 이벤트가 생길 때마다 호출될 함수를 등록합니다. 이벤트는 함수 호출과 별개로 계속 쌓입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers a function to be called whenever a trigger event occurs. Events keep being accumulated apart from the calls. </br>
+    /// 
+    #[inline]
+    pub fn add_trigger_listener<F: FnMut(&TriggerEvent<T>) + 'static>(&mut self, listener: F) {
+        self.trigger_listeners.0.push(Box::new(listener));
+    }
+
+    /// #### 한국어 </br>
+    /// 넓은 단계와 좁은 단계를 한 번 실행하고 충돌 이벤트들을 만듭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs the broad phase and the narrow phase once and makes the collision events. </br>
+    /// 
+    pub fn fixed_update(&mut self) {
+        let mut contacts = HashMap::new();
+        let mut trigger_overlaps = HashSet::new();
+        for (index, collider) in self.colliders.iter().enumerate() {
+            let Some(collider) = collider else {
+                continue;
+            };
+
+            let id = ColliderId(index);
+            self.bvh.query_aabb(&collider.world.aabb(), |_, &other| {
+                // (한국어) 각 쌍은 번호가 작은 충돌체에서 한 번만 검사합니다.
+                // (English Translation) Each pair is tested only once, from the collider with the smaller id.
+                if other <= id {
+                    return;
+                }
+                let other_collider = self.colliders[other.0].as_ref().unwrap();
+                if collider.is_trigger && other_collider.is_trigger {
+                    return;
+                }
+                let Some(contact) = test_shapes(&collider.world, &other_collider.world) else {
+                    return;
+                };
+
+                match (collider.is_trigger, other_collider.is_trigger) {
+                    (true, _) => { trigger_overlaps.insert((id, other)); }, 
+                    (_, true) => { trigger_overlaps.insert((other, id)); }, 
+                    _ => { contacts.insert((id, other), contact); }, 
+                }
+            });
+        }
+
+        // (한국어) 해시 맵의 순회 순서는 실행마다 달라지므로, 이벤트는 충돌체 번호 쌍의 순서로 만듭니다.
+        // (English Translation) The iteration order of the hash map varies between runs, so the events are made in the order of the collider id pairs.
+        let mut touching: Vec<_> = contacts.iter().map(|(&pair, &contact)| (pair, contact)).collect();
+        touching.sort_by_key(|&(pair, _)| pair);
+        for (pair, contact) in touching {
+            let phase = match self.contacts.contains_key(&pair) {
+                true => CollisionPhase::Stay, 
+                false => CollisionPhase::Begin, 
+            };
+            self.push_event(phase, pair, Some(contact));
+        }
+
+        let mut ended: Vec<_> = self.contacts.keys()
+            .filter(|pair| !contacts.contains_key(pair))
+            .copied()
+            .collect();
+        ended.sort();
+        for pair in ended {
+            self.push_event(CollisionPhase::End, pair, None);
+        }
+
+        self.contacts = contacts;
+
+        let mut entered: Vec<_> = trigger_overlaps.difference(&self.trigger_overlaps).copied().collect();
+        let mut exited: Vec<_> = self.trigger_overlaps.difference(&trigger_overlaps).copied().collect();
+        entered.sort();
+        exited.sort();
+        self.trigger_overlaps = trigger_overlaps;
+        for pair in entered {
+            self.push_trigger_event(TriggerPhase::Enter, pair);
+        }
+        for pair in exited {
+            self.push_trigger_event(TriggerPhase::Exit, pair);
+        }
+    }
+
+    #[inline]
+    fn collider(&self, id: ColliderId) -> Option<&Collider<T>> {
+        self.colliders.get(id.0).and_then(|collider| collider.as_ref())
+    }
+
+    fn ray_test(&self, id: ColliderId, ray: &Ray) -> Option<(f32, glam::Vec3)> {
+        let collider = self.collider(id).filter(|collider| !collider.is_trigger)?;
+        match &collider.mesh {
+            Some(mesh) => raycast_mesh(ray, &collider.transform, mesh), 
+            None => collider.world.ray_intersection(ray), 
+        }
+    }
+
+    fn push_event(&mut self, phase: CollisionPhase, pair: (ColliderId, ColliderId), contact: Option<Contact>) {
+        let owners = (self.owner(pair.0).unwrap(), self.owner(pair.1).unwrap());
+        self.events.push(CollisionEvent { phase, colliders: pair, owners, contact });
+    }
+
+    fn push_trigger_event(&mut self, phase: TriggerPhase, (trigger, collider): (ColliderId, ColliderId)) {
+        let owners = (self.owner(trigger).unwrap(), self.owner(collider).unwrap());
+        let event = TriggerEvent { phase, trigger, collider, owners };
+        for listener in self.trigger_listeners.0.iter_mut() {
+            listener(&event);
+        }
+        self.trigger_events.push(event);
+    }
+}
+
+fn raycast_mesh(ray: &Ray, transform: &glam::Mat4, triangles: &[[glam::Vec3; 3]]) -> Option<(f32, glam::Vec3)> {
+    // (한국어) 광선을 로컬 공간으로 옮깁니다. 방향을 정규화하지 않으므로 거리는 월드 공간의 거리와 같습니다.
+    // (English Translation) Moves the ray into local space. The direction is not normalized, so the distance equals the distance in world space.
+    let inverse = transform.inverse();
+    let origin = inverse.transform_point3(ray.origin);
+    let direction = inverse.transform_vector3(ray.direction);
+    let (distance, triangle) = triangles.iter()
+        .filter_map(|triangle| ray_triangle(origin, direction, triangle).map(|distance| (distance, triangle)))
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))?;
+
+    let local_normal = (triangle[1] - triangle[0]).cross(triangle[2] - triangle[0]);
+    let normal = inverse.transpose().transform_vector3(local_normal).normalize();
+    let normal = if normal.dot(ray.direction) > 0.0 { -normal } else { normal };
+    Some((distance, normal))
+}
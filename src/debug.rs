@@ -0,0 +1,208 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::bounds::Aabb;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 월드 공간의 선분들을 모아서 한 번에 그리는 디버그 선 배치 입니다. </br>
+/// 매 프레임 선분들을 다시 추가해야 하며, 깊이 검사는 하지만 깊이 버퍼에 쓰지는 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A debug line batch that collects line segments in world space and draws them at once. </br>
+/// Line segments must be added again every frame, and they are depth tested but do not write to the depth buffer. </br>
+/// 
+#[derive(Debug)]
+pub struct DebugLines {
+    vertices: Vec<DebugLineVertexLayout>, 
+    num_prepared: u32, 
+    vertex_buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl DebugLines {
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self {
+            vertices: Vec::new(), 
+            num_prepared: 0, 
+            vertex_buffer: create_vertex_buffer(256, device), 
+        }
+    }
+
+    #[inline]
+    pub fn line(&mut self, from: glam::Vec3, to: glam::Vec3, color: glam::Vec4) {
+        self.vertices.push(DebugLineVertexLayout { position: from, color });
+        self.vertices.push(DebugLineVertexLayout { position: to, color });
+    }
+
+    /// #### 한국어 </br>
+    /// 지점들을 차례로 잇는 꺾은선을 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a polyline connecting the points in order. </br>
+    /// 
+    pub fn polyline(&mut self, points: &[glam::Vec3], color: glam::Vec4) {
+        for window in points.windows(2) {
+            self.line(window[0], window[1], color);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 위치에 세 축 방향의 십자 표시를 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a cross marker along the three axes at the given position. </br>
+    /// 
+    pub fn cross(&mut self, center: glam::Vec3, size: f32, color: glam::Vec4) {
+        for axis in [glam::Vec3::X, glam::Vec3::Y, glam::Vec3::Z] {
+            self.line(center - 0.5 * size * axis, center + 0.5 * size * axis, color);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 축 정렬 경계 상자의 12개 모서리를 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the 12 edges of the axis-aligned bounding box. </br>
+    /// 
+    pub fn aabb(&mut self, aabb: &Aabb, color: glam::Vec4) {
+        let corners = [0, 1, 2, 3, 4, 5, 6, 7].map(|i| glam::vec3(
+            if i & 1 == 0 { aabb.min.x } else { aabb.max.x }, 
+            if i & 2 == 0 { aabb.min.y } else { aabb.max.y }, 
+            if i & 4 == 0 { aabb.min.z } else { aabb.max.z }, 
+        ));
+        self.box_edges(&corners, color);
+    }
+
+    /// #### 한국어 </br>
+    /// 변환 행렬의 위치에 X, Y, Z 축을 각각 빨강, 초록, 파랑으로 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the X, Y, and Z axes in red, green, and blue at the position of the transform matrix. </br>
+    /// 
+    pub fn axes(&mut self, transform: &glam::Mat4, size: f32) {
+        let origin = transform.w_axis.truncate();
+        self.line(origin, origin + size * transform.x_axis.truncate().normalize_or_zero(), (1.0, 0.0, 0.0, 1.0).into());
+        self.line(origin, origin + size * transform.y_axis.truncate().normalize_or_zero(), (0.0, 1.0, 0.0, 1.0).into());
+        self.line(origin, origin + size * transform.z_axis.truncate().normalize_or_zero(), (0.0, 0.0, 1.0, 1.0).into());
+    }
+
+    /// #### 한국어 </br>
+    /// `center`를 중심으로 XZ 평면 위에 `cells` x `cells`칸의 격자를 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a grid of `cells` x `cells` cells on the XZ plane centered at `center`. </br>
+    /// 
+    pub fn grid(&mut self, center: glam::Vec3, cell_size: f32, cells: u32, color: glam::Vec4) {
+        let half = 0.5 * cell_size * cells as f32;
+        for i in 0..=cells {
+            let offset = -half + cell_size * i as f32;
+            self.line(center + glam::vec3(offset, 0.0, -half), center + glam::vec3(offset, 0.0, half), color);
+            self.line(center + glam::vec3(-half, 0.0, offset), center + glam::vec3(half, 0.0, offset), color);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// `wgpu`의 깊이 범위(0 ~ 1)를 사용하는 투영-뷰 행렬의 절두체를 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the frustum of a projection-view matrix using the depth range of `wgpu` (0 to 1). </br>
+    /// 
+    pub fn frustum(&mut self, proj_view: &glam::Mat4, color: glam::Vec4) {
+        let inverse = proj_view.inverse();
+        let corners = [0, 1, 2, 3, 4, 5, 6, 7].map(|i| inverse.project_point3(glam::vec3(
+            if i & 1 == 0 { -1.0 } else { 1.0 }, 
+            if i & 2 == 0 { -1.0 } else { 1.0 }, 
+            if i & 4 == 0 { 0.0 } else { 1.0 }, 
+        )));
+        self.box_edges(&corners, color);
+    }
+
+    /// #### 한국어 </br>
+    /// 비트 0, 1, 2가 각 축의 끝을 고르는 순서로 놓인 8개의 꼭짓점을 잇는 모서리들을 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the edges connecting 8 corners ordered so that bits 0, 1, and 2 select the end of each axis. </br>
+    /// 
+    fn box_edges(&mut self, corners: &[glam::Vec3; 8], color: glam::Vec4) {
+        for i in 0..8 {
+            for bit in [1, 2, 4] {
+                if i & bit == 0 {
+                    self.line(corners[i], corners[i | bit], color);
+                }
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 모은 선분들의 정점 데이터를 GPU에 올리고, 대기열을 비웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uploads the vertex data of the line segments collected this frame to the GPU and clears the queue. </br>
+    /// 
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let required = (mem::size_of::<DebugLineVertexLayout>() * self.vertices.len()) as wgpu::BufferAddress;
+        if required > self.vertex_buffer.size() {
+            self.vertex_buffer = create_vertex_buffer(self.vertices.len().next_power_of_two(), device);
+        }
+
+        if !self.vertices.is_empty() {
+            queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+        self.num_prepared = self.vertices.len() as u32;
+        self.vertices.clear();
+    }
+
+    /// #### 한국어 </br>
+    /// 준비된 선분들을 그립니다. 디버그 선 파이프라인과 카메라(0번 그룹)가 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the prepared line segments. The debug line pipeline and camera (group 0) must be set. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if self.num_prepared == 0 {
+            return;
+        }
+
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        stats::draw(rpass, 0..self.num_prepared, 0..1);
+    }
+}
+
+#[inline]
+fn create_vertex_buffer(capacity: usize, device: &wgpu::Device) -> wgpu::Buffer {
+    stats::track_buffer(device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some("Vertex(DebugLines)"), 
+            mapped_at_creation: false, 
+            size: (mem::size_of::<DebugLineVertexLayout>() * capacity) as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    ))
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 디버그 선 버텍스 입력 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the debug line vertex input data used in the shader. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugLineVertexLayout {
+    pub position: glam::Vec3, 
+    pub color: glam::Vec4, 
+}
+
+impl Default for DebugLineVertexLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            position: glam::Vec3::ZERO, 
+            color: glam::Vec4::ONE, 
+        }
+    }
+}
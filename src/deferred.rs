@@ -0,0 +1,525 @@
+use std::io;
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::camera::GameCameraObject;
+use crate::graph::{GraphColorAttachment, GraphTexture};
+use crate::lighting::{PhongLight, PhongLightLayout};
+use crate::pbr::PbrUniformLayout;
+use crate::resource::ShaderResource;
+use crate::target::{PooledTargetId, RenderTargetPool};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 렌더러를 선택하는 명령줄 인수 입니다. 뒤에 `RENDERERS`의 이름이 옵니다. (예: `--renderer deferred`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that selects the renderer. It is followed by a name of `RENDERERS`. (e.g. `--renderer deferred`) </br>
+/// 
+pub const RENDERER_ARG: &str = "--renderer";
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 렌더러와 명령줄에서 쓰는 이름 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable renderers and the names used on the command line. </br>
+/// 
+pub const RENDERERS: [(&str, RendererKind); 2] = [
+    ("forward", RendererKind::Forward), 
+    ("deferred", RendererKind::Deferred), 
+];
+
+/// #### 한국어 </br>
+/// G-버퍼의 알베도 타겟 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The format of the albedo target of the G-buffer. </br>
+/// 
+pub const ALBEDO_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+/// #### 한국어 </br>
+/// G-버퍼의 월드 공간 법선 타겟 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The format of the world space normal target of the G-buffer. </br>
+/// 
+pub const NORMAL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// #### 한국어 </br>
+/// G-버퍼의 재질 타겟 형식입니다. (금속성, 거칠기, 주변광 차폐)를 담습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The format of the material target of the G-buffer. It holds (metallic, roughness, ambient occlusion). </br>
+/// 
+pub const MATERIAL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+/// #### 한국어 </br>
+/// 조명 단계가 비출 수 있는 최대 조명 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of lights the lighting pass can shine. </br>
+/// 
+pub const MAX_DEFERRED_LIGHTS: usize = 256;
+
+
+
+/// #### 한국어 </br>
+/// 장면의 불투명한 물체들을 그리는 방식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The way the opaque objects of the scene are drawn. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RendererKind {
+    /// #### 한국어 </br>
+    /// 물체마다 모든 조명을 계산하며 그립니다. 모든 기능을 지원합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws each object while computing all lights. Supports every feature. </br>
+    /// 
+    #[default]
+    Forward, 
+    /// #### 한국어 </br>
+    /// 물체들을 G-버퍼에 그린 후, 화면 전체를 덮는 조명 단계에서 픽셀마다 조명을 한 번씩 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the objects into the G-buffer, then computes the lights once per pixel in a lighting pass covering the whole screen. </br>
+    /// 
+    Deferred, 
+}
+
+impl RendererKind {
+    #[inline]
+    pub fn name(self) -> &'static str {
+        RENDERERS.iter().find(|&&(_, kind)| kind == self).map(|&(name, _)| name).unwrap_or("forward")
+    }
+}
+
+/// #### 한국어 </br>
+/// `RENDERER_ARG` 명령줄 인수의 값을 렌더러로 해석합니다. 알 수 없는 값이면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `RENDERER_ARG` command line argument as a renderer. Returns an `InvalidInput` error if the value is unknown. </br>
+/// 
+pub fn parse_renderer(value: &str) -> io::Result<RendererKind> {
+    match RENDERERS.iter().find(|(name, _)| *name == value) {
+        Some(&(_, kind)) => Ok(kind), 
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!(
+                "{} must be followed by one of {}", 
+                RENDERER_ARG, 
+                RENDERERS.map(|(name, _)| name).join(", ")
+            )
+        )), 
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 지연 렌더러를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates the deferred renderer. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeferredRendererBuilder {
+    pub lights: Vec<PhongLight>, 
+    pub ambient: f32, 
+    pub default_metallic: f32, 
+    pub default_roughness: f32, 
+}
+
+impl Default for DeferredRendererBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            lights: Vec::new(), 
+            ambient: 0.2, 
+            default_metallic: 0.0, 
+            default_roughness: 0.6
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl DeferredRendererBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 조명을 추가합니다. `MAX_DEFERRED_LIGHTS`를 넘는 조명은 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a light. Lights beyond `MAX_DEFERRED_LIGHTS` are ignored. </br>
+    /// 
+    #[inline]
+    pub fn add_light(mut self, light: PhongLight) -> Self {
+        if self.lights.len() < MAX_DEFERRED_LIGHTS {
+            self.lights.push(light);
+        } else {
+            log::warn!("Only up to {} deferred lights are supported. The light is ignored.", MAX_DEFERRED_LIGHTS);
+        }
+        self
+    }
+
+    #[inline]
+    pub fn set_ambient(mut self, ambient: f32) -> Self {
+        self.ambient = ambient.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 자신의 재질이 없는 물체들을 G-버퍼에 그릴 때 사용하는 금속성과 거칠기를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the metallic and roughness used when drawing the objects without their own material into the G-buffer. </br>
+    /// 
+    #[inline]
+    pub fn set_default_material(mut self, metallic: f32, roughness: f32) -> Self {
+        self.default_metallic = metallic.clamp(0.0, 1.0);
+        self.default_roughness = roughness.clamp(0.04, 1.0);
+        self
+    }
+
+    pub fn build(
+        self, 
+        pool: &mut RenderTargetPool, 
+        depth_target: PooledTargetId, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        material_bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> DeferredRenderer {
+        let usage = wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING;
+        let albedo_target = pool.acquire("Texture(GBufferAlbedo)", ALBEDO_FORMAT, usage, device);
+        let normal_target = pool.acquire("Texture(GBufferNormal)", NORMAL_FORMAT, usage, device);
+        let material_target = pool.acquire("Texture(GBufferMaterial)", MATERIAL_FORMAT, usage, device);
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(GBuffer)"), 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Deferred)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<DeferredUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let light_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Storage(DeferredLights)"), 
+                mapped_at_creation: false, 
+                size: (MAX_DEFERRED_LIGHTS * mem::size_of::<PhongLightLayout>()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        // (한국어) 자신의 재질이 없는 물체들이 G-버퍼에 재질을 기록하도록 기본 재질을 생성합니다.
+        // (English Translation) Creates the default material so the objects without their own material write a material into the G-buffer.
+        let default_material_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(DeferredDefaultMaterial)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<PbrUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        let data = PbrUniformLayout {
+            params: (self.default_metallic, self.default_roughness, 1.0, 0.0).into(), 
+        };
+        queue.write_buffer(&default_material_buffer, 0, bytemuck::bytes_of(&data));
+
+        let default_material_bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(DeferredDefaultMaterial)"), 
+                layout: material_bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            default_material_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let views = create_views(pool, depth_target, albedo_target, normal_target, material_target);
+        let bind_group = create_bind_group(&views, &sampler, &uniform_buffer, &light_buffer, bind_group_layout, device);
+
+        let renderer = DeferredRenderer {
+            lights: self.lights, 
+            ambient: self.ambient, 
+            view_projection: glam::Mat4::IDENTITY, 
+            camera_position: glam::Vec3::ZERO, 
+            depth_target, 
+            albedo_target, 
+            normal_target, 
+            material_target, 
+            views, 
+            sampler, 
+            uniform_buffer, 
+            light_buffer, 
+            _default_material_buffer: default_material_buffer, 
+            default_material_bind_group, 
+            bind_group, 
+        };
+        renderer.update_resource(queue);
+
+        return renderer;
+    }
+}
+
+#[derive(Debug)]
+struct GBufferViews {
+    albedo: wgpu::TextureView, 
+    normal: wgpu::TextureView, 
+    material: wgpu::TextureView, 
+    depth: wgpu::TextureView, 
+}
+
+/// #### 한국어 </br>
+/// 순방향 렌더러 대신 선택할 수 있는 지연 렌더러 입니다. </br>
+/// 불투명한 물체들은 G-버퍼(알베도, 법선, 재질, 깊이)에 그려지고, 화면 전체를 덮는 조명 단계가 깊이로부터 월드 좌표를 복원하여
+/// 그림자를 드리우는 전역 조명과 최대 `MAX_DEFERRED_LIGHTS`개의 조명을 픽셀마다 한 번씩 계산합니다. </br>
+/// 하늘, 반투명한 물체, 거울 등 나머지는 G-버퍼의 깊이를 이어받아 순방향으로 그립니다. 멀티샘플링과 오클루전 컬링은 지원하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A deferred renderer that can be selected instead of the forward renderer. </br>
+/// The opaque objects are drawn into the G-buffer (albedo, normal, material, depth), and a lighting pass covering the whole screen reconstructs the world position from the depth
+/// and computes the global light casting shadows and up to `MAX_DEFERRED_LIGHTS` lights once per pixel. </br>
+/// The rest, such as the sky, translucent objects and the mirror, is drawn forward on top of the depth of the G-buffer. Multisampling and occlusion culling are not supported. </br>
+/// 
+#[derive(Debug)]
+pub struct DeferredRenderer {
+    lights: Vec<PhongLight>, 
+    ambient: f32, 
+    view_projection: glam::Mat4, 
+    camera_position: glam::Vec3, 
+    depth_target: PooledTargetId, 
+    albedo_target: PooledTargetId, 
+    normal_target: PooledTargetId, 
+    material_target: PooledTargetId, 
+    views: GBufferViews, 
+    sampler: wgpu::Sampler, 
+    uniform_buffer: wgpu::Buffer, 
+    light_buffer: wgpu::Buffer, 
+    _default_material_buffer: wgpu::Buffer, 
+    pub default_material_bind_group: wgpu::BindGroup, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl DeferredRenderer {
+    #[inline]
+    pub fn lights(&self) -> &[PhongLight] {
+        &self.lights
+    }
+
+    /// #### 한국어 </br>
+    /// 조명들을 변경합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the lights. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn lights_mut(&mut self) -> &mut [PhongLight] {
+        &mut self.lights
+    }
+
+    #[inline]
+    pub fn ambient(&self) -> f32 {
+        self.ambient
+    }
+
+    #[inline]
+    pub fn set_ambient(&mut self, ambient: f32) {
+        self.ambient = ambient.max(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 조명 단계가 깊이로부터 월드 좌표를 복원할 수 있도록 카메라의 변환을 갱신합니다. 매 프레임 그리기 전에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the transform of the camera so the lighting pass can reconstruct the world position from the depth. It must be called every frame before drawing. </br>
+    /// 
+    #[inline]
+    pub fn update_camera<C: GameCameraObject>(&mut self, camera: &C, queue: &wgpu::Queue) {
+        self.view_projection = camera.projection_transform() * camera.view_transform();
+        self.camera_position = camera.get_translation();
+        self.write_uniform(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// G-버퍼의 타겟들을 지우고 그리는 렌더 그래프의 색상 첨부들을 반환합니다. 깊이는 G-버퍼를 생성할 때 주어진 깊이 버퍼에 기록됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the render graph color attachments that clear and draw into the targets of the G-buffer. The depth is written into the depth buffer given when creating the G-buffer. </br>
+    /// 
+    pub fn color_attachments(&self) -> [Option<GraphColorAttachment<'static>>; 3] {
+        [self.albedo_target, self.normal_target, self.material_target].map(|target| {
+            Some(GraphColorAttachment {
+                target: GraphTexture::Pooled(target), 
+                resolve_target: None, 
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), 
+                    store: wgpu::StoreOp::Store, 
+                }, 
+            })
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟 풀의 크기가 바뀐 후 다시 생성된 G-버퍼를 묶습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the recreated G-buffer after the render target pool is resized. </br>
+    /// 
+    pub fn resize(&mut self, pool: &RenderTargetPool, bind_group_layout: &wgpu::BindGroupLayout, device: &wgpu::Device) {
+        self.views = create_views(pool, self.depth_target, self.albedo_target, self.normal_target, self.material_target);
+        self.bind_group = create_bind_group(&self.views, &self.sampler, &self.uniform_buffer, &self.light_buffer, bind_group_layout, device);
+    }
+
+    /// #### 한국어 </br>
+    /// 조명 단계를 그립니다. 조명 파이프라인과 전역 조명, 그림자 맵, 안개 바인드 그룹이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the lighting pass. The lighting pipeline and the global light, shadow map and fog bind groups must be set. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::set_bind_group(rpass, 0, &self.bind_group, &[]);
+        stats::draw(rpass, 0..3, 0..1);
+    }
+
+    fn write_uniform(&self, queue: &wgpu::Queue) {
+        let data = DeferredUniformLayout {
+            inverse_view_projection: self.view_projection.inverse(), 
+            camera_position: (self.camera_position, 1.0).into(), 
+            params: (self.lights.len() as f32, self.ambient, 0.0, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+impl ShaderResource for DeferredRenderer {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        self.write_uniform(queue);
+
+        let lights: Vec<PhongLightLayout> = self.lights.iter().map(PhongLight::layout).collect();
+        if !lights.is_empty() {
+            queue.write_buffer(&self.light_buffer, 0, bytemuck::cast_slice(&lights));
+        }
+    }
+}
+
+fn create_bind_group(
+    views: &GBufferViews, 
+    sampler: &wgpu::Sampler, 
+    uniform_buffer: &wgpu::Buffer, 
+    light_buffer: &wgpu::Buffer, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    device: &wgpu::Device
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(Deferred)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(&views.albedo), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::TextureView(&views.normal), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::TextureView(&views.material), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 3, 
+                    resource: wgpu::BindingResource::TextureView(&views.depth), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 4, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 5, 
+                    resource: wgpu::BindingResource::Buffer(
+                        uniform_buffer.as_entire_buffer_binding()
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 6, 
+                    resource: wgpu::BindingResource::Buffer(
+                        light_buffer.as_entire_buffer_binding()
+                    ), 
+                }, 
+            ], 
+        }, 
+    )
+}
+
+fn create_views(
+    pool: &RenderTargetPool, 
+    depth_target: PooledTargetId, 
+    albedo_target: PooledTargetId, 
+    normal_target: PooledTargetId, 
+    material_target: PooledTargetId
+) -> GBufferViews {
+    GBufferViews {
+        albedo: pool.create_view(albedo_target), 
+        normal: pool.create_view(normal_target), 
+        material: pool.create_view(material_target), 
+        // (한국어) 깊이-스텐실 형식의 깊이 버퍼는 깊이 측면만 쉐이더에서 읽을 수 있습니다.
+        // (English Translation) Only the depth aspect of a depth-stencil buffer can be read in shaders.
+        depth: pool.texture_ref(depth_target).create_view(&wgpu::TextureViewDescriptor {
+            aspect: wgpu::TextureAspect::DepthOnly, 
+            ..Default::default()
+        }), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 지연 렌더러 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`는 (조명 수, 주변광 세기, 사용하지 않음, 사용하지 않음) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the deferred renderer uniform data used in the shader. </br>
+/// `params` is (number of lights, ambient strength, unused, unused). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeferredUniformLayout {
+    pub inverse_view_projection: glam::Mat4, 
+    pub camera_position: glam::Vec4, 
+    pub params: glam::Vec4, 
+}
+
+impl Default for DeferredUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inverse_view_projection: glam::Mat4::IDENTITY, 
+            camera_position: glam::Vec4::W, 
+            params: (0.0, 0.2, 0.0, 0.0).into()
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::mem;
+use std::rc::Rc;
+use bytemuck::{Pod, Zeroable};
+
+use crate::object::{ObjectVertexLayout, StdObject, StdObjectBuilder};
+use crate::resource::{ShaderResource, UniformArena, buffer_bind_group_entry, storage_buffer_layout_entry, uniform_buffer_layout_entry};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 정점 변위 컴퓨트 쉐이더의 작업 그룹 한 변의 크기입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of one side of a work group of the vertex displacement compute shader. </br>
+/// 
+pub const DISPLACEMENT_WORKGROUP_SIZE: u32 = 8;
+
+
+
+/// #### 한국어 </br>
+/// 컴퓨트 쉐이더로 정점을 움직이는 세분화된 평면을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a subdivided plane whose vertices are moved by a compute shader. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplacedPlaneBuilder {
+    pub color: glam::Vec3, 
+    pub translation: glam::Vec3, 
+    pub size: glam::Vec2, 
+    pub resolution: u32, 
+    pub amplitude: f32, 
+    pub frequency: f32, 
+    pub speed: f32, 
+}
+
+impl Default for DisplacedPlaneBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: (0.2, 0.45, 0.7).into(), 
+            translation: glam::Vec3::ZERO, 
+            size: (4.0, 4.0).into(), 
+            resolution: 128, 
+            amplitude: 0.15, 
+            frequency: 1.5, 
+            speed: 0.6
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl DisplacedPlaneBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_color(mut self, color: glam::Vec3) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    #[inline]
+    pub fn set_size(mut self, size: glam::Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 한 변을 나누는 사각형의 개수를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the number of quads dividing one side. </br>
+    /// 
+    #[inline]
+    pub fn set_resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub fn set_amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    #[inline]
+    pub fn set_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    #[inline]
+    pub fn set_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn build(
+        self, 
+        object_uniforms: &Rc<UniformArena>, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> DisplacedPlane {
+        assert!(self.resolution > 0);
+        assert!(self.size.x > 0.0 && self.size.y > 0.0);
+
+        let object = StdObjectBuilder::new()
+            .set_color(self.color)
+            .set_translation(self.translation)
+            .build(object_uniforms, queue);
+
+        // (한국어) 정점 버퍼는 컴퓨트 쉐이더가 매 프레임 채우므로 저장 버퍼로도 사용됩니다.
+        // (English Translation) The compute shader fills the vertex buffer every frame, so it is also used as a storage buffer.
+        let num_vertices = (self.resolution + 1) * (self.resolution + 1);
+        let vertex_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Vertex(DisplacedPlane)"), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<ObjectVertexLayout>() * num_vertices as usize) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE, 
+            }, 
+        ));
+
+        let row = self.resolution + 1;
+        let mut indices: Vec<u32> = Vec::with_capacity((6 * self.resolution * self.resolution) as usize);
+        for z in 0..self.resolution {
+            for x in 0..self.resolution {
+                let i00 = z * row + x;
+                let i10 = i00 + 1;
+                let i01 = i00 + row;
+                let i11 = i01 + 1;
+                indices.extend_from_slice(&[i00, i01, i10, i10, i01, i11]);
+            }
+        }
+        let index_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Index(DisplacedPlane)"), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<u32>() * indices.len()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        queue.write_buffer(&index_buffer, 0, bytemuck::cast_slice(&indices));
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Displacement)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<DisplacementUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Displacement)"), 
+                entries: &[
+                    uniform_buffer_layout_entry(0, wgpu::ShaderStages::COMPUTE), 
+                    storage_buffer_layout_entry(1, wgpu::ShaderStages::COMPUTE, false), 
+                ], 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Displacement)"), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    buffer_bind_group_entry(0, &uniform_buffer), 
+                    buffer_bind_group_entry(1, &vertex_buffer), 
+                ], 
+            }, 
+        );
+
+        let plane = DisplacedPlane {
+            size: self.size, 
+            resolution: self.resolution, 
+            amplitude: self.amplitude, 
+            frequency: self.frequency, 
+            speed: self.speed, 
+            time: 0.0, 
+            object, 
+            num_indices: indices.len() as u32, 
+            vertex_buffer, 
+            index_buffer, 
+            uniform_buffer, 
+            bind_group_layout, 
+            bind_group, 
+        };
+        plane.update_resource(queue);
+
+        return plane;
+    }
+}
+
+/// #### 한국어 </br>
+/// 컴퓨트 쉐이더가 움직이는 노이즈로 정점의 높이와 법선을 계산해 정점 버퍼에 직접 쓰는 평면 입니다. </br>
+/// 렌더 파이프라인은 같은 버퍼를 일반 정점 버퍼로 읽으므로 CPU를 거치지 않고 메쉬가 움직입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A plane whose vertex heights and normals are computed from animated noise by a compute shader and written directly into the vertex buffer. </br>
+/// The render pipeline reads the same buffer as an ordinary vertex buffer, so the mesh moves without going through the CPU. </br>
+/// 
+#[derive(Debug)]
+pub struct DisplacedPlane {
+    size: glam::Vec2, 
+    resolution: u32, 
+    amplitude: f32, 
+    frequency: f32, 
+    speed: f32, 
+    time: f32, 
+    object: StdObject, 
+    num_indices: u32, 
+    vertex_buffer: wgpu::Buffer, 
+    index_buffer: wgpu::Buffer, 
+    uniform_buffer: wgpu::Buffer, 
+    bind_group_layout: wgpu::BindGroupLayout, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl DisplacedPlane {
+    #[inline]
+    pub fn bind_group_layout_ref(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    #[inline]
+    pub fn object_ref(&self) -> &StdObject {
+        &self.object
+    }
+
+    #[inline]
+    pub fn object_mut(&mut self) -> &mut StdObject {
+        &mut self.object
+    }
+
+    /// #### 한국어 </br>
+    /// 노이즈 애니메이션의 시간을 진행시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time of the noise animation. </br>
+    /// 
+    pub fn advance(&mut self, elapsed_time_sec: f32, queue: &wgpu::Queue) {
+        self.time += elapsed_time_sec;
+        self.update_resource(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 정점 버퍼를 갱신하는 컴퓨트 패스를 실행합니다. 이 평면을 그리는 렌더 패스보다 먼저 실행해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs the compute pass that updates the vertex buffer. It must run before the render passes that draw this plane. </br>
+    /// 
+    pub fn dispatch(&self, encoder: &mut wgpu::CommandEncoder, pipeline: &wgpu::ComputePipeline) {
+        let mut cpass = encoder.begin_compute_pass(
+            &wgpu::ComputePassDescriptor {
+                label: Some("ComputePass(Displacement)"), 
+                timestamp_writes: None, 
+            }, 
+        );
+
+        let num_workgroups = (self.resolution + 1).div_ceil(DISPLACEMENT_WORKGROUP_SIZE);
+        cpass.set_pipeline(pipeline);
+        cpass.set_bind_group(0, &self.bind_group, &[]);
+        cpass.dispatch_workgroups(num_workgroups, num_workgroups, 1);
+    }
+
+    /// #### 한국어 </br>
+    /// 평면을 그립니다. `ObjectVertexLayout` 정점을 사용하는 파이프라인이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the plane. A pipeline using `ObjectVertexLayout` vertices must be set. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        self.object.bind(rpass, 1);
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        rpass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        stats::draw_indexed(rpass, 0..self.num_indices, 0, 0..1);
+    }
+}
+
+impl ShaderResource for DisplacedPlane {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = DisplacementUniformLayout {
+            size: (self.size.x, self.size.y, self.resolution as f32, self.time).into(), 
+            params: (self.amplitude, self.frequency, self.speed, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 정점 변위 유니폼 데이터의 레이아웃 입니다. </br>
+/// `size`는 (가로, 세로, 분할 수, 시간), `params`는 (진폭, 주파수, 속도, 사용 안 함) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the vertex displacement uniform data used in the shader. </br>
+/// `size` is (width, depth, resolution, time), and `params` is (amplitude, frequency, speed, unused). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplacementUniformLayout {
+    pub size: glam::Vec4, 
+    pub params: glam::Vec4, 
+}
+
+impl Default for DisplacementUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            size: (1.0, 1.0, 1.0, 0.0).into(), 
+            params: (0.0, 1.0, 1.0, 0.0).into()
+        }
+    }
+}
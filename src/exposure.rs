@@ -0,0 +1,400 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::{ShaderResource, buffer_bind_group_entry, storage_buffer_layout_entry, uniform_buffer_layout_entry};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 휘도 히스토그램의 구간 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of bins of the luminance histogram. </br>
+/// 
+pub const HISTOGRAM_BINS: u32 = 256;
+
+/// #### 한국어 </br>
+/// 히스토그램 컴퓨트 쉐이더의 작업 그룹 크기 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The workgroup size of the histogram compute shader. </br>
+/// 
+const HISTOGRAM_WORKGROUP_SIZE: u32 = 16;
+
+
+
+/// #### 한국어 </br>
+/// 자동 노출(눈 적응)을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates the automatic exposure (eye adaptation). </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoExposureBuilder {
+    pub min_log_luminance: f32, 
+    pub max_log_luminance: f32, 
+    pub adaptation_speed: f32, 
+    pub key_value: f32, 
+    pub min_exposure: f32, 
+    pub max_exposure: f32, 
+}
+
+impl Default for AutoExposureBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min_log_luminance: -8.0, 
+            max_log_luminance: 4.0, 
+            adaptation_speed: 1.5, 
+            key_value: 0.8, 
+            min_exposure: 0.1, 
+            max_exposure: 8.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl AutoExposureBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_luminance_range(mut self, min_log_luminance: f32, max_log_luminance: f32) -> Self {
+        self.min_log_luminance = min_log_luminance;
+        self.max_log_luminance = max_log_luminance;
+        self
+    }
+
+    #[inline]
+    pub fn set_adaptation_speed(mut self, adaptation_speed: f32) -> Self {
+        self.adaptation_speed = adaptation_speed;
+        self
+    }
+
+    #[inline]
+    pub fn set_key_value(mut self, key_value: f32) -> Self {
+        self.key_value = key_value;
+        self
+    }
+
+    #[inline]
+    pub fn set_exposure_range(mut self, min_exposure: f32, max_exposure: f32) -> Self {
+        self.min_exposure = min_exposure;
+        self.max_exposure = max_exposure;
+        self
+    }
+
+    pub fn build(
+        self, 
+        scene_view: &wgpu::TextureView, 
+        width: u32, 
+        height: u32, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> AutoExposure {
+        assert!(self.min_log_luminance < self.max_log_luminance);
+        assert!(0.0 < self.min_exposure && self.min_exposure <= self.max_exposure);
+
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(AutoExposure)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::COMPUTE, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::COMPUTE, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::NonFiltering, 
+                        ), 
+                        count: None, 
+                    }, 
+                    storage_buffer_layout_entry(2, wgpu::ShaderStages::COMPUTE, false), 
+                    storage_buffer_layout_entry(3, wgpu::ShaderStages::COMPUTE, false), 
+                    uniform_buffer_layout_entry(4, wgpu::ShaderStages::COMPUTE), 
+                ], 
+            }, 
+        );
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(AutoExposure)"), 
+                ..Default::default()
+            }, 
+        );
+
+        let histogram_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Storage(LuminanceHistogram)"), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<u32>() * HISTOGRAM_BINS as usize) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let exposure_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Storage(Exposure)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<glam::Vec4>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        let initial: glam::Vec4 = (self.key_value, 1.0, 0.0, 0.0).into();
+        queue.write_buffer(&exposure_buffer, 0, bytemuck::bytes_of(&initial));
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(AutoExposure)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ExposureUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = create_bind_group(
+            scene_view, 
+            &sampler, 
+            &histogram_buffer, 
+            &exposure_buffer, 
+            &uniform_buffer, 
+            &bind_group_layout, 
+            device
+        );
+
+        let exposure = AutoExposure {
+            enabled: true, 
+            elapsed_time: 0.0, 
+            width, 
+            height, 
+            params: self, 
+            sampler, 
+            histogram_buffer, 
+            exposure_buffer, 
+            uniform_buffer, 
+            bind_group, 
+            bind_group_layout, 
+        };
+        exposure.update_resource(queue);
+
+        return exposure;
+    }
+}
+
+/// #### 한국어 </br>
+/// HDR 장면의 휘도 히스토그램을 계산하고 노출을 시간에 따라 적응시키는 자동 노출 입니다. </br>
+/// 첫 번째 컴퓨트 단계는 로그 휘도의 히스토그램을 만들고, 두 번째 단계는 평균 휘도를 구한 후
+/// 이전 프레임의 값에서 부드럽게 적응시켜 톤 매퍼가 읽는 노출 버퍼에 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Automatic exposure that computes a luminance histogram of the HDR scene and adapts the exposure over time. </br>
+/// The first compute stage builds a histogram of the log luminance, and the second stage finds the average luminance, 
+/// adapts smoothly from the value of the previous frame and writes it to the exposure buffer read by the tonemapper. </br>
+/// 
+#[derive(Debug)]
+pub struct AutoExposure {
+    enabled: bool, 
+    elapsed_time: f32, 
+    width: u32, 
+    height: u32, 
+    params: AutoExposureBuilder, 
+    sampler: wgpu::Sampler, 
+    histogram_buffer: wgpu::Buffer, 
+    exposure_buffer: wgpu::Buffer, 
+    uniform_buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+    bind_group_layout: wgpu::BindGroupLayout, 
+}
+
+#[allow(dead_code)]
+impl AutoExposure {
+    #[inline]
+    pub fn bind_group_layout_ref(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    /// #### 한국어 </br>
+    /// 톤 매퍼가 읽는 노출 버퍼를 반환합니다. (x: 적응된 평균 휘도, y: 노출) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the exposure buffer read by the tonemapper. (x: adapted average luminance, y: exposure) </br>
+    /// 
+    #[inline]
+    pub fn exposure_buffer_ref(&self) -> &wgpu::Buffer {
+        &self.exposure_buffer
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// #### 한국어 </br>
+    /// 자동 노출을 켜거나 끕니다. 꺼져 있으면 노출은 1로 고정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Turns the automatic exposure on or off. When off, the exposure is fixed at 1. </br>
+    /// 
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool, queue: &wgpu::Queue) {
+        self.enabled = enabled;
+        self.update_resource(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 장면 타겟이 다시 생성되었을 때 바인드 그룹을 다시 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the bind group when the scene target has been recreated. </br>
+    /// 
+    pub fn resize(&mut self, scene_view: &wgpu::TextureView, width: u32, height: u32, device: &wgpu::Device) {
+        self.width = width;
+        self.height = height;
+        self.bind_group = create_bind_group(
+            scene_view, 
+            &self.sampler, 
+            &self.histogram_buffer, 
+            &self.exposure_buffer, 
+            &self.uniform_buffer, 
+            &self.bind_group_layout, 
+            device
+        );
+    }
+
+    /// #### 한국어 </br>
+    /// 노출이 적응하는 데 사용할 프레임 시간을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the frame time used for adapting the exposure. </br>
+    /// 
+    #[inline]
+    pub fn advance(&mut self, elapsed_time_sec: f32, queue: &wgpu::Queue) {
+        self.elapsed_time = elapsed_time_sec;
+        self.update_resource(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 히스토그램 단계와 평균 단계를 차례로 실행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs the histogram stage and the average stage in order. </br>
+    /// 
+    pub fn dispatch(
+        &self, 
+        encoder: &mut wgpu::CommandEncoder, 
+        histogram_pipeline: &wgpu::ComputePipeline, 
+        average_pipeline: &wgpu::ComputePipeline
+    ) {
+        let mut cpass = encoder.begin_compute_pass(
+            &wgpu::ComputePassDescriptor {
+                label: Some("ComputePass(AutoExposure)"), 
+                timestamp_writes: None, 
+            }, 
+        );
+
+        cpass.set_bind_group(0, &self.bind_group, &[]);
+        cpass.set_pipeline(histogram_pipeline);
+        cpass.dispatch_workgroups(
+            self.width.div_ceil(HISTOGRAM_WORKGROUP_SIZE), 
+            self.height.div_ceil(HISTOGRAM_WORKGROUP_SIZE), 
+            1
+        );
+
+        cpass.set_pipeline(average_pipeline);
+        cpass.dispatch_workgroups(1, 1, 1);
+    }
+}
+
+fn create_bind_group(
+    scene_view: &wgpu::TextureView, 
+    sampler: &wgpu::Sampler, 
+    histogram_buffer: &wgpu::Buffer, 
+    exposure_buffer: &wgpu::Buffer, 
+    uniform_buffer: &wgpu::Buffer, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    device: &wgpu::Device
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(AutoExposure)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(scene_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+                buffer_bind_group_entry(2, histogram_buffer), 
+                buffer_bind_group_entry(3, exposure_buffer), 
+                buffer_bind_group_entry(4, uniform_buffer), 
+            ], 
+        }, 
+    )
+}
+
+impl ShaderResource for AutoExposure {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = ExposureUniformLayout {
+            min_log_luminance: self.params.min_log_luminance, 
+            log_luminance_range: self.params.max_log_luminance - self.params.min_log_luminance, 
+            elapsed_time: self.elapsed_time, 
+            adaptation_speed: self.params.adaptation_speed, 
+            key_value: self.params.key_value, 
+            min_exposure: self.params.min_exposure, 
+            max_exposure: self.params.max_exposure, 
+            enabled: if self.enabled { 1.0 } else { 0.0 }, 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 자동 노출 유니폼 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the automatic exposure uniform data used in the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExposureUniformLayout {
+    pub min_log_luminance: f32, 
+    pub log_luminance_range: f32, 
+    pub elapsed_time: f32, 
+    pub adaptation_speed: f32, 
+    pub key_value: f32, 
+    pub min_exposure: f32, 
+    pub max_exposure: f32, 
+    pub enabled: f32, 
+}
+
+impl Default for ExposureUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min_log_luminance: -8.0, 
+            log_luminance_range: 12.0, 
+            elapsed_time: 0.0, 
+            adaptation_speed: 1.5, 
+            key_value: 0.8, 
+            min_exposure: 0.1, 
+            max_exposure: 8.0, 
+            enabled: 1.0, 
+        }
+    }
+}
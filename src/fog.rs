@@ -0,0 +1,386 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 안개 모드와 화면에 보여주는 이름 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable fog modes and the names shown on the screen. </br>
+/// 
+pub const FOG_MODES: [(&str, FogMode); 3] = [
+    ("linear", FogMode::Linear), 
+    ("exponential", FogMode::Exponential), 
+    ("height", FogMode::Height), 
+];
+
+
+
+/// #### 한국어 </br>
+/// 카메라로부터의 거리에 따라 안개의 불투명도를 계산하는 방법 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The way the opacity of the fog is computed from the distance to the camera. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FogMode {
+    /// #### 한국어 </br>
+    /// `start`에서 `end`까지 불투명도가 선형으로 증가합니다. 농도는 사용하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The opacity increases linearly from `start` to `end`. The density is not used. </br>
+    /// 
+    Linear, 
+    /// #### 한국어 </br>
+    /// `start` 이후의 거리와 농도에 따라 불투명도가 지수적으로 증가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The opacity increases exponentially with the density and the distance past `start`. </br>
+    /// 
+    Exponential, 
+    /// #### 한국어 </br>
+    /// 농도가 높이에 따라 지수적으로 줄어드는 높이 안개 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Height fog whose density decreases exponentially with the height. </br>
+    /// 
+    #[default]
+    Height, 
+}
+
+impl FogMode {
+    #[inline]
+    pub fn name(self) -> &'static str {
+        FOG_MODES.iter().find(|&&(_, mode)| mode == self).map(|&(name, _)| name).unwrap_or("height")
+    }
+
+    /// #### 한국어 </br>
+    /// `FOG_MODES`의 순서대로 다음 모드를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the next mode in the order of `FOG_MODES`. </br>
+    /// 
+    #[inline]
+    pub fn next(self) -> Self {
+        let index = FOG_MODES.iter().position(|&(_, mode)| mode == self).unwrap_or(0);
+        FOG_MODES[(index + 1) % FOG_MODES.len()].1
+    }
+
+    #[inline]
+    fn as_index(self) -> f32 {
+        match self {
+            Self::Linear => 0.0, 
+            Self::Exponential => 1.0, 
+            Self::Height => 2.0, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 지수 높이 안개를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates exponential height fog. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeightFogBuilder {
+    pub mode: FogMode, 
+    pub color: glam::Vec3, 
+    pub sun_color: glam::Vec3, 
+    pub density: f32, 
+    pub height_falloff: f32, 
+    pub base_height: f32, 
+    pub max_opacity: f32, 
+    pub sun_exponent: f32, 
+    pub start: f32, 
+    pub end: f32, 
+}
+
+impl Default for HeightFogBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            mode: FogMode::default(), 
+            color: (0.6, 0.68, 0.78).into(), 
+            sun_color: (1.0, 0.9, 0.7).into(), 
+            density: 0.02, 
+            height_falloff: 0.35, 
+            base_height: 0.0, 
+            max_opacity: 0.9, 
+            sun_exponent: 8.0, 
+            start: 5.0, 
+            end: 40.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl HeightFogBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_mode(mut self, mode: FogMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[inline]
+    pub fn set_color(mut self, color: glam::Vec3) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[inline]
+    pub fn set_sun_color(mut self, sun_color: glam::Vec3) -> Self {
+        self.sun_color = sun_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    #[inline]
+    pub fn set_height_falloff(mut self, height_falloff: f32) -> Self {
+        self.height_falloff = height_falloff;
+        self
+    }
+
+    #[inline]
+    pub fn set_base_height(mut self, base_height: f32) -> Self {
+        self.base_height = base_height;
+        self
+    }
+
+    #[inline]
+    pub fn set_max_opacity(mut self, max_opacity: f32) -> Self {
+        self.max_opacity = max_opacity;
+        self
+    }
+
+    #[inline]
+    pub fn set_sun_exponent(mut self, sun_exponent: f32) -> Self {
+        self.sun_exponent = sun_exponent;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 선형 안개와 지수 안개가 시작되는 카메라로부터의 거리와, 선형 안개가 가장 짙어지는 거리를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distance from the camera where the linear and exponential fog begin, and the distance where the linear fog is thickest. </br>
+    /// 
+    #[inline]
+    pub fn set_range(mut self, start: f32, end: f32) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> HeightFog {
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(HeightFog)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<FogUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(HeightFog)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let fog = HeightFog {
+            enabled: true, 
+            mode: self.mode, 
+            color: self.color, 
+            sun_color: self.sun_color, 
+            density: self.density, 
+            height_falloff: self.height_falloff, 
+            base_height: self.base_height, 
+            max_opacity: self.max_opacity, 
+            sun_exponent: self.sun_exponent, 
+            start: self.start.max(0.0), 
+            end: self.end.max(self.start.max(0.0) + 1e-3), 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+        };
+        fog.update_resource(queue);
+
+        return fog;
+    }
+}
+
+/// #### 한국어 </br>
+/// 셰이딩 단계에서 계산되는 지수 높이 안개 입니다. </br>
+/// 안개의 농도는 `base_height`에서 위로 갈수록 `height_falloff`에 따라 줄어들며, 
+/// 태양을 바라보는 방향일수록 안개의 색이 `sun_color`에 가까워집니다. </br>
+/// 모드를 바꾸면 높이와 상관없이 카메라로부터의 거리만으로 짙어지는 선형 안개나 지수 안개로 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Exponential height fog evaluated during the shading stage. </br>
+/// The fog density decreases upwards from `base_height` according to `height_falloff`, 
+/// and the fog color approaches `sun_color` the more the view faces the sun. </br>
+/// Changing the mode computes linear or exponential fog that only thickens with the distance from the camera regardless of the height. </br>
+/// 
+#[derive(Debug)]
+pub struct HeightFog {
+    enabled: bool, 
+    mode: FogMode, 
+    color: glam::Vec3, 
+    sun_color: glam::Vec3, 
+    density: f32, 
+    height_falloff: f32, 
+    base_height: f32, 
+    max_opacity: f32, 
+    sun_exponent: f32, 
+    start: f32, 
+    end: f32, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl HeightFog {
+    /// #### 한국어 </br>
+    /// 안개를 켜거나 끕니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Turns the fog on or off. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[inline]
+    pub fn set_color(&mut self, color: glam::Vec3) {
+        self.color = color;
+    }
+
+    #[inline]
+    pub fn color(&self) -> glam::Vec3 {
+        self.color
+    }
+
+    #[inline]
+    pub fn set_density(&mut self, density: f32) {
+        self.density = density;
+    }
+
+    #[inline]
+    pub fn density(&self) -> f32 {
+        self.density
+    }
+
+    #[inline]
+    pub fn mode(&self) -> FogMode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn set_mode(&mut self, mode: FogMode) {
+        self.mode = mode;
+    }
+
+    #[inline]
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+
+    #[inline]
+    pub fn end(&self) -> f32 {
+        self.end
+    }
+
+    /// #### 한국어 </br>
+    /// 안개가 시작되고 끝나는 거리를 설정합니다. 끝나는 거리는 시작되는 거리보다 멀게 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distances where the fog begins and ends. The end distance is kept farther than the start distance. </br>
+    /// 
+    #[inline]
+    pub fn set_range(&mut self, start: f32, end: f32) {
+        self.start = start.max(0.0);
+        self.end = end.max(self.start + 1e-3);
+    }
+}
+
+impl ShaderResource for HeightFog {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let density = if self.enabled { self.density } else { 0.0 };
+        let data = FogUniformLayout {
+            color: (self.color, 1.0).into(), 
+            sun_color: (self.sun_color, self.sun_exponent).into(), 
+            params: (density, self.height_falloff, self.base_height, self.max_opacity).into(), 
+            range: (self.start, self.end, self.mode.as_index(), 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 안개 유니폼 데이터의 레이아웃 입니다. </br>
+/// `sun_color`의 `w`는 태양 방향 산란의 지수, `params`는 (농도, 높이 감쇠, 기준 높이, 최대 불투명도), 
+/// `range`는 (시작 거리, 끝 거리, 모드 번호, 0) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the fog uniform data used in the shader. </br>
+/// `w` of `sun_color` is the exponent of the sun scattering, `params` is (density, height falloff, base height, max opacity), 
+/// and `range` is (start distance, end distance, mode index, 0). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FogUniformLayout {
+    pub color: glam::Vec4, 
+    pub sun_color: glam::Vec4, 
+    pub params: glam::Vec4, 
+    pub range: glam::Vec4, 
+}
+
+impl Default for FogUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: glam::Vec4::ONE, 
+            sun_color: glam::Vec4::ONE, 
+            params: glam::Vec4::ZERO, 
+            range: (5.0, 40.0, 2.0, 0.0).into(), 
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DrawIndexedIndirectArgs;
+
+use crate::bounds::{Frustum, Sphere};
+use crate::indirect::IndirectDrawBuffer;
+use crate::instance::{InstanceLayout, InstancedObject};
+use crate::resource::{buffer_bind_group_entry, storage_buffer_layout_entry, uniform_buffer_layout_entry};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// GPU 컬링 컴퓨트 쉐이더의 작업 그룹 크기 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The workgroup size of the GPU culling compute shader. </br>
+/// 
+pub const GPU_CULL_WORKGROUP_SIZE: u32 = 64;
+
+
+
+/// #### 한국어 </br>
+/// 컴퓨트 쉐이더로 인스턴스들의 경계 구를 절두체 평면들과 검사하여, 보이는 인스턴스들만 간접 그리기 버퍼로 모으는 GPU 컬러 입니다. </br>
+/// 보이는 인스턴스들은 별도의 인스턴스 버퍼 앞쪽으로 압축되고, 간접 그리기 명령의 `instance_count`는 원자적으로 증가하므로
+/// CPU는 인스턴스 수와 상관없이 한 번의 디스패치와 한 번의 간접 그리기만 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A GPU culler that tests the bounding spheres of the instances against the frustum planes with a compute shader, and gathers only the visible instances into an indirect draw buffer. </br>
+/// The visible instances are compacted to the front of a separate instance buffer, and the `instance_count` of the indirect draw command is incremented atomically, 
+/// so the CPU only records one dispatch and one indirect draw regardless of the number of instances. </br>
+/// 
+#[derive(Debug)]
+pub struct GpuCuller {
+    num_instances: u32, 
+    bounding_sphere: Sphere, 
+    uniform_buffer: wgpu::Buffer, 
+    visible_buffer: wgpu::Buffer, 
+    indirect: IndirectDrawBuffer, 
+    bind_group_layout: wgpu::BindGroupLayout, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl GpuCuller {
+    /// #### 한국어 </br>
+    /// `instances`의 인스턴스들을 컬링하는 GPU 컬러를 생성합니다. </br>
+    /// `num_indices`와 `bounding_sphere`는 인스턴스마다 그려지는 메쉬의 인덱스 수와 로컬 공간의 경계 구 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a GPU culler culling the instances of `instances`. </br>
+    /// `num_indices` and `bounding_sphere` are the number of indices and the local space bounding sphere of the mesh drawn for each instance. </br>
+    /// 
+    pub fn new(
+        label: &str, 
+        instances: &InstancedObject, 
+        num_indices: u32, 
+        bounding_sphere: Sphere, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        let num_instances = instances.num_instances();
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&format!("Uniform(GpuCull({}))", label)), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<GpuCullUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        // (한국어) 보이는 인스턴스들은 컴퓨트 쉐이더가 쓰고, 렌더 파이프라인이 정점 버퍼로 읽습니다.
+        // (English Translation) The visible instances are written by the compute shader, and read as a vertex buffer by the render pipeline.
+        let visible_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&format!("Instance(GpuCull({}))", label)), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<InstanceLayout>() * num_instances as usize) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE, 
+            }, 
+        ));
+
+        let indirect = IndirectDrawBuffer::new(
+            &format!("Indirect(GpuCull({}))", label), 
+            vec![DrawIndexedIndirectArgs {
+                index_count: num_indices, 
+                instance_count: 0, 
+                first_index: 0, 
+                base_vertex: 0, 
+                first_instance: 0, 
+            }], 
+            device, 
+            queue
+        );
+
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(GpuCull)"), 
+                entries: &[
+                    uniform_buffer_layout_entry(0, wgpu::ShaderStages::COMPUTE), 
+                    storage_buffer_layout_entry(1, wgpu::ShaderStages::COMPUTE, true), 
+                    storage_buffer_layout_entry(2, wgpu::ShaderStages::COMPUTE, false), 
+                    storage_buffer_layout_entry(3, wgpu::ShaderStages::COMPUTE, false), 
+                ], 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(&format!("BindGroup(GpuCull({}))", label)), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    buffer_bind_group_entry(0, &uniform_buffer), 
+                    buffer_bind_group_entry(1, instances.buffer_ref()), 
+                    buffer_bind_group_entry(2, &visible_buffer), 
+                    buffer_bind_group_entry(3, indirect.buffer_ref()), 
+                ], 
+            }, 
+        );
+
+        Self {
+            num_instances, 
+            bounding_sphere, 
+            uniform_buffer, 
+            visible_buffer, 
+            indirect, 
+            bind_group_layout, 
+            bind_group, 
+        }
+    }
+
+    #[inline]
+    pub fn bind_group_layout_ref(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    #[inline]
+    pub fn num_instances(&self) -> u32 {
+        self.num_instances
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 절두체와 인스턴스들을 놓는 월드 변환 행렬을 쓰고, 간접 그리기 명령의 인스턴스 수를 0으로 되돌립니다. </br>
+    /// 대기열의 쓰기는 다음에 제출되는 커맨드 버퍼보다 먼저 실행되므로 `dispatch`를 기록하기 전에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the frustum of this frame and the world transform matrix placing the instances, and resets the instance count of the indirect draw command to 0. </br>
+    /// The writes of the queue run before the next submitted command buffer, so it must be called before recording `dispatch`. </br>
+    /// 
+    pub fn update(&self, frustum: &Frustum, world: &glam::Mat4, queue: &wgpu::Queue) {
+        let data = GpuCullUniformLayout {
+            world: *world, 
+            planes: frustum.planes, 
+            sphere: (self.bounding_sphere.center, self.bounding_sphere.radius).into(), 
+            params: (self.num_instances as f32, 0.0, 0.0, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+        self.indirect.write_commands(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 인스턴스들을 컬링하는 컴퓨트 패스를 실행합니다. 컬링된 인스턴스들을 그리는 렌더 패스보다 먼저 실행해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs the compute pass culling the instances. It must run before the render passes that draw the culled instances. </br>
+    /// 
+    pub fn dispatch(&self, encoder: &mut wgpu::CommandEncoder, pipeline: &wgpu::ComputePipeline) {
+        let mut cpass = encoder.begin_compute_pass(
+            &wgpu::ComputePassDescriptor {
+                label: Some("ComputePass(GpuCull)"), 
+                timestamp_writes: None, 
+            }, 
+        );
+
+        cpass.set_pipeline(pipeline);
+        cpass.set_bind_group(0, &self.bind_group, &[]);
+        cpass.dispatch_workgroups(self.num_instances.div_ceil(GPU_CULL_WORKGROUP_SIZE), 1, 1);
+    }
+
+    /// #### 한국어 </br>
+    /// 보이는 인스턴스들을 한 번의 간접 그리기로 그립니다. 메쉬가 바인딩되어 있어야 합니다. </br>
+    /// 보이는 인스턴스의 수는 GPU만 알고 있으므로 통계에는 그리기 호출만 기록됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the visible instances with a single indirect draw. The mesh must be bound. </br>
+    /// Only the GPU knows the number of visible instances, so only the draw call is recorded in the stats. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        rpass.set_vertex_buffer(1, self.visible_buffer.slice(..));
+        self.indirect.draw(rpass, 0);
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 GPU 컬링 유니폼 데이터의 레이아웃 입니다. </br>
+/// `sphere`는 메쉬의 로컬 공간 경계 구 (중심, 반지름) 이고, `params`는 (인스턴스 수, 0, 0, 0) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the GPU culling uniform data used in the shader. </br>
+/// `sphere` is the local space bounding sphere (center, radius) of the mesh, and `params` is (number of instances, 0, 0, 0). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpuCullUniformLayout {
+    pub world: glam::Mat4, 
+    pub planes: [glam::Vec4; 6], 
+    pub sphere: glam::Vec4, 
+    pub params: glam::Vec4, 
+}
+
+impl Default for GpuCullUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            world: glam::Mat4::IDENTITY, 
+            planes: [glam::Vec4::ZERO; 6], 
+            sphere: (0.0, 0.0, 0.0, 1.0).into(), 
+            params: glam::Vec4::ZERO
+        }
+    }
+}
@@ -0,0 +1,469 @@
+use std::ptr;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use crate::profiler::GpuProfiler;
+use crate::target::{PooledTargetId, RenderTargetPool};
+
+
+
+/// #### 한국어 </br>
+/// 렌더 그래프의 노드가 읽거나 쓰는 텍스처 입니다. </br>
+/// 렌더 타겟 풀의 텍스처, 그래프가 할당하는 임시 텍스처, 또는 그래프 밖에서 관리되는 텍스처 뷰 중 하나 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A texture read or written by a node of the render graph. </br>
+/// It is one of a texture of the render target pool, a transient texture allocated by the graph, or a texture view managed outside the graph. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub enum GraphTexture<'a> {
+    Pooled(PooledTargetId), 
+    Transient(usize), 
+    View(&'a wgpu::TextureView), 
+}
+
+impl PartialEq for GraphTexture<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Pooled(a), Self::Pooled(b)) => a == b, 
+            (Self::Transient(a), Self::Transient(b)) => a == b, 
+            (Self::View(a), Self::View(b)) => ptr::eq(*a, *b), 
+            _ => false, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 렌더 패스 노드의 색상 첨부 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A color attachment of a render pass node. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct GraphColorAttachment<'a> {
+    pub target: GraphTexture<'a>, 
+    pub resolve_target: Option<GraphTexture<'a>>, 
+    pub ops: wgpu::Operations<wgpu::Color>, 
+}
+
+impl<'a> From<wgpu::RenderPassColorAttachment<'a>> for GraphColorAttachment<'a> {
+    #[inline]
+    fn from(attachment: wgpu::RenderPassColorAttachment<'a>) -> Self {
+        Self {
+            target: GraphTexture::View(attachment.view), 
+            resolve_target: attachment.resolve_target.map(GraphTexture::View), 
+            ops: attachment.ops, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 렌더 패스 노드의 깊이-스텐실 첨부 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A depth-stencil attachment of a render pass node. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct GraphDepthStencilAttachment<'a> {
+    pub target: GraphTexture<'a>, 
+    pub depth_ops: Option<wgpu::Operations<f32>>, 
+    pub stencil_ops: Option<wgpu::Operations<u32>>, 
+}
+
+impl<'a> From<wgpu::RenderPassDepthStencilAttachment<'a>> for GraphDepthStencilAttachment<'a> {
+    #[inline]
+    fn from(attachment: wgpu::RenderPassDepthStencilAttachment<'a>) -> Self {
+        Self {
+            target: GraphTexture::View(attachment.view), 
+            depth_ops: attachment.depth_ops, 
+            stencil_ops: attachment.stencil_ops, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 렌더 패스 노드가 그리기 명령을 기록하는 렌더 패스 입니다. `wgpu::RenderPass`처럼 사용합니다. </br>
+/// 두 번째 수명은 기록하는 클로저가 빌린 자원들이 렌더 패스보다 오래 산다는 것을 컴파일러에게 알려줍니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The render pass a render pass node records its draw commands into. It is used like a `wgpu::RenderPass`. </br>
+/// The second lifetime tells the compiler that the resources borrowed by the recording closure outlive the render pass. </br>
+/// 
+pub struct GraphRenderPass<'p, 'a> {
+    rpass: wgpu::RenderPass<'p>, 
+    _resources: PhantomData<&'p &'a ()>, 
+}
+
+impl<'p> Deref for GraphRenderPass<'p, '_> {
+    type Target = wgpu::RenderPass<'p>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.rpass
+    }
+}
+
+impl DerefMut for GraphRenderPass<'_, '_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rpass
+    }
+}
+
+type RecordPass<'a> = dyn for<'p> FnOnce(GraphRenderPass<'p, 'a>) + 'a;
+type RecordCommands<'a> = dyn FnOnce(&mut wgpu::CommandEncoder, &RenderTargetPool) + 'a;
+
+enum NodeRecord<'a> {
+    Pass(Box<RecordPass<'a>>), 
+    Commands(Box<RecordCommands<'a>>), 
+}
+
+/// #### 한국어 </br>
+/// 렌더 패스 노드를 생성하는 빌더입니다. </br>
+/// 노드는 자신이 그리는 첨부와 읽는 텍스처, 앞서 실행되어야 하는 노드들을 선언합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a render pass node. </br>
+/// A node declares the attachments it draws into, the textures it reads and the nodes that must run before it. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct RenderPassNodeBuilder<'a> {
+    pub label: &'static str, 
+    pub color_attachments: Vec<Option<GraphColorAttachment<'a>>>, 
+    pub depth_stencil_attachment: Option<GraphDepthStencilAttachment<'a>>, 
+    pub occlusion_query_set: Option<&'a wgpu::QuerySet>, 
+    pub reads: Vec<GraphTexture<'a>>, 
+    pub writes: Vec<GraphTexture<'a>>, 
+    pub dependencies: Vec<&'static str>, 
+}
+
+#[allow(dead_code)]
+impl<'a> RenderPassNodeBuilder<'a> {
+    #[inline]
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label, 
+            color_attachments: Vec::new(), 
+            depth_stencil_attachment: None, 
+            occlusion_query_set: None, 
+            reads: Vec::new(), 
+            writes: Vec::new(), 
+            dependencies: Vec::new()
+        }
+    }
+
+    #[inline]
+    pub fn set_color_attachments<T: Into<GraphColorAttachment<'a>>>(mut self, attachments: impl IntoIterator<Item = Option<T>>) -> Self {
+        self.color_attachments = attachments.into_iter().map(|attachment| attachment.map(Into::into)).collect();
+        self
+    }
+
+    #[inline]
+    pub fn set_depth_stencil_attachment(mut self, attachment: impl Into<GraphDepthStencilAttachment<'a>>) -> Self {
+        self.depth_stencil_attachment = Some(attachment.into());
+        self
+    }
+
+    #[inline]
+    pub fn set_occlusion_query_set(mut self, query_set: Option<&'a wgpu::QuerySet>) -> Self {
+        self.occlusion_query_set = query_set;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 노드가 쉐이더에서 읽는 텍스처를 선언합니다. 이 텍스처를 쓰는 앞선 노드들이 먼저 실행됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Declares a texture the node reads in its shaders. The preceding nodes writing this texture run first. </br>
+    /// 
+    #[inline]
+    pub fn add_read(mut self, texture: GraphTexture<'a>) -> Self {
+        self.reads.push(texture);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 첨부가 아닌 방법으로 노드가 쓰는 텍스처를 선언합니다. 명령 노드가 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Declares a texture the node writes other than through its attachments. Used by the command nodes. </br>
+    /// 
+    #[inline]
+    pub fn add_write(mut self, texture: GraphTexture<'a>) -> Self {
+        self.writes.push(texture);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 이 노드보다 먼저 실행되어야 하는 노드의 레이블을 추가합니다. 그래프에 없는 노드는 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the label of a node that must run before this node. Nodes that are not in the graph are ignored. </br>
+    /// 
+    #[inline]
+    pub fn add_dependency(mut self, label: &'static str) -> Self {
+        self.dependencies.push(label);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 선언된 첨부들로 시작한 렌더 패스에 `record`가 그리기 명령을 기록하는 노드를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a node where `record` records the draw commands into the render pass begun with the declared attachments. </br>
+    /// 
+    pub fn build(self, record: impl for<'p> FnOnce(GraphRenderPass<'p, 'a>) + 'a) -> RenderPassNode<'a> {
+        let mut writes = self.writes;
+        for attachment in self.color_attachments.iter().flatten() {
+            writes.push(attachment.target);
+            writes.extend(attachment.resolve_target);
+        }
+        writes.extend(self.depth_stencil_attachment.map(|attachment| attachment.target));
+
+        return RenderPassNode {
+            label: self.label, 
+            color_attachments: self.color_attachments, 
+            depth_stencil_attachment: self.depth_stencil_attachment, 
+            occlusion_query_set: self.occlusion_query_set, 
+            reads: self.reads, 
+            writes, 
+            dependencies: self.dependencies, 
+            record: NodeRecord::Pass(Box::new(record)), 
+        };
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 패스 대신 `record`가 커맨드 인코더에 직접 명령을 기록하는 노드를 생성합니다. </br>
+    /// 컴퓨트 패스나 스스로 렌더 패스들을 시작하는 단계에 사용하며, 첨부는 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a node where `record` records the commands directly into the command encoder instead of a render pass. </br>
+    /// Used for compute passes or stages that begin their own render passes, and the attachments are ignored. </br>
+    /// 
+    pub fn build_commands(self, record: impl FnOnce(&mut wgpu::CommandEncoder, &RenderTargetPool) + 'a) -> RenderPassNode<'a> {
+        return RenderPassNode {
+            label: self.label, 
+            color_attachments: Vec::new(), 
+            depth_stencil_attachment: None, 
+            occlusion_query_set: None, 
+            reads: self.reads, 
+            writes: self.writes, 
+            dependencies: self.dependencies, 
+            record: NodeRecord::Commands(Box::new(record)), 
+        };
+    }
+}
+
+/// #### 한국어 </br>
+/// 렌더 그래프의 노드 입니다. 렌더 패스 하나, 또는 커맨드 인코더에 직접 기록되는 명령들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A node of the render graph. It is a single render pass, or commands recorded directly into the command encoder. </br>
+/// 
+pub struct RenderPassNode<'a> {
+    label: &'static str, 
+    color_attachments: Vec<Option<GraphColorAttachment<'a>>>, 
+    depth_stencil_attachment: Option<GraphDepthStencilAttachment<'a>>, 
+    occlusion_query_set: Option<&'a wgpu::QuerySet>, 
+    reads: Vec<GraphTexture<'a>>, 
+    writes: Vec<GraphTexture<'a>>, 
+    dependencies: Vec<&'static str>, 
+    record: NodeRecord<'a>, 
+}
+
+#[allow(dead_code)]
+impl RenderPassNode<'_> {
+    #[inline]
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// #### 한국어 </br>
+    /// `other`가 이 노드보다 먼저 추가되었을 때, 이 노드가 `other`의 뒤에 실행되어야 하는지 여부를 반환합니다. </br>
+    /// 앞선 노드가 쓴 텍스처를 읽거나 쓰는 경우와, 앞선 노드가 읽는 텍스처를 덮어쓰는 경우 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether this node must run after `other`, when `other` was added before this node. </br>
+    /// It is when reading or writing a texture written by the preceding node, or overwriting a texture read by the preceding node. </br>
+    /// 
+    fn depends_on(&self, other: &Self) -> bool {
+        if self.dependencies.contains(&other.label) {
+            return true;
+        }
+        self.reads.iter().chain(self.writes.iter()).any(|texture| other.writes.contains(texture))
+            || self.writes.iter().any(|texture| other.reads.contains(texture))
+    }
+}
+
+/// #### 한국어 </br>
+/// 그래프가 할당하는 임시 텍스처의 서술자 입니다. 렌더 타겟 풀에서 같은 레이블의 텍스처를 프레임마다 재사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The descriptor of a transient texture allocated by the graph. The texture with the same label is reused from the render target pool every frame. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TransientTexture {
+    label: &'static str, 
+    format: wgpu::TextureFormat, 
+    usage: wgpu::TextureUsages, 
+    sample_count: u32, 
+}
+
+/// #### 한국어 </br>
+/// 한 프레임의 렌더 패스들을 모아 순서대로 실행하는 렌더 그래프 입니다. </br>
+/// 노드들은 추가된 순서대로 실행되지만, 선언된 의존성이 있으면 그 노드들이 먼저 실행되도록 순서가 바뀝니다. </br>
+/// 텍스처를 쓴 노드는 그 텍스처를 읽는 뒤의 노드보다 항상 먼저 실행됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A render graph that collects the render passes of a frame and executes them in order. </br>
+/// The nodes run in the order they were added, but the order changes so the declared dependencies run first. </br>
+/// A node writing a texture always runs before the later nodes reading that texture. </br>
+/// 
+#[derive(Default)]
+pub struct RenderGraph<'a> {
+    nodes: Vec<RenderPassNode<'a>>, 
+    transients: Vec<TransientTexture>, 
+    profiler: Option<&'a mut GpuProfiler>, 
+}
+
+#[allow(dead_code)]
+impl<'a> RenderGraph<'a> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 크기의 임시 텍스처를 선언합니다. 실제 텍스처는 그래프를 실행할 때 렌더 타겟 풀에서 할당됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Declares a transient texture of the screen size. The actual texture is allocated from the render target pool when the graph is executed. </br>
+    /// 
+    pub fn create_texture(
+        &mut self, 
+        label: &'static str, 
+        format: wgpu::TextureFormat, 
+        usage: wgpu::TextureUsages, 
+        sample_count: u32
+    ) -> GraphTexture<'a> {
+        self.transients.push(TransientTexture { label, format, usage, sample_count });
+        return GraphTexture::Transient(self.transients.len() - 1);
+    }
+
+    #[inline]
+    pub fn add_node(&mut self, node: RenderPassNode<'a>) {
+        self.nodes.push(node);
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 패스 노드마다 GPU 시간을 측정할 프로파일러를 설정합니다. 명령 노드는 측정되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the profiler measuring the GPU time of each render pass node. The command nodes are not measured. </br>
+    /// 
+    #[inline]
+    pub fn set_profiler(&mut self, profiler: Option<&'a mut GpuProfiler>) {
+        self.profiler = profiler;
+    }
+
+    /// #### 한국어 </br>
+    /// 임시 텍스처들을 할당하고, 노드들을 의존성 순서대로 커맨드 인코더에 기록합니다. </br>
+    /// 의존성에 순환이 있으면 패닉합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Allocates the transient textures, and records the nodes into the command encoder in dependency order. </br>
+    /// Panics if the dependencies have a cycle. </br>
+    /// 
+    pub fn execute(self, pool: &mut RenderTargetPool, encoder: &mut wgpu::CommandEncoder, device: &wgpu::Device) {
+        let transients: Vec<_> = self.transients.iter()
+            .map(|texture| pool.acquire_multisampled(texture.label, texture.format, texture.usage, texture.sample_count, device))
+            .collect();
+        let pool: &RenderTargetPool = pool;
+        let view = |texture: GraphTexture<'a>| match texture {
+            GraphTexture::Pooled(id) => pool.view_ref(id), 
+            GraphTexture::Transient(index) => pool.view_ref(transients[index]), 
+            GraphTexture::View(view) => view, 
+        };
+
+        let mut profiler = self.profiler;
+        let order = sort_nodes(&self.nodes);
+        let mut nodes: Vec<_> = self.nodes.into_iter().map(Some).collect();
+        for index in order {
+            let node = nodes[index].take().unwrap();
+            match node.record {
+                NodeRecord::Pass(record) => {
+                    let color_attachments: Vec<_> = node.color_attachments.iter()
+                        .map(|attachment| attachment.map(|attachment| wgpu::RenderPassColorAttachment {
+                            view: view(attachment.target), 
+                            resolve_target: attachment.resolve_target.map(view), 
+                            ops: attachment.ops, 
+                        }))
+                        .collect();
+                    let rpass = encoder.begin_render_pass(
+                        &wgpu::RenderPassDescriptor {
+                            label: Some(node.label), 
+                            color_attachments: &color_attachments, 
+                            depth_stencil_attachment: node.depth_stencil_attachment.map(|attachment| wgpu::RenderPassDepthStencilAttachment {
+                                view: view(attachment.target), 
+                                depth_ops: attachment.depth_ops, 
+                                stencil_ops: attachment.stencil_ops, 
+                            }), 
+                            timestamp_writes: profiler.as_mut().and_then(|profiler| profiler.begin_pass(node.label)), 
+                            occlusion_query_set: node.occlusion_query_set, 
+                        }, 
+                    );
+                    record(GraphRenderPass { rpass, _resources: PhantomData });
+                }, 
+                NodeRecord::Commands(record) => {
+                    record(encoder, pool);
+                }, 
+            }
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 노드들의 실행 순서를 구합니다. 실행할 수 있는 노드 중 가장 먼저 추가된 노드를 고르므로, 
+/// 의존성이 없으면 추가된 순서를 유지합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Finds the execution order of the nodes. Since the earliest added node among the runnable ones is picked, 
+/// the order they were added is kept without dependencies. </br>
+/// 
+fn sort_nodes(nodes: &[RenderPassNode]) -> Vec<usize> {
+    // (한국어) 명시된 의존성은 추가된 순서와 관계없이 적용되고, 텍스처 의존성은 앞서 추가된 노드에만 적용됩니다.
+    // (English Translation) The explicit dependencies apply regardless of the added order, and the texture dependencies only apply to the nodes added before.
+    let predecessors: Vec<Vec<usize>> = nodes.iter()
+        .enumerate()
+        .map(|(index, node)| {
+            nodes.iter()
+                .enumerate()
+                .filter(|&(other_index, other)| match other_index < index {
+                    true => node.depends_on(other), 
+                    false => other_index != index && node.dependencies.contains(&other.label), 
+                })
+                .map(|(other_index, _)| other_index)
+                .collect()
+        })
+        .collect();
+
+    let mut done = vec![false; nodes.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+    while order.len() < nodes.len() {
+        let next = (0..nodes.len())
+            .find(|&index| !done[index] && predecessors[index].iter().all(|&other| done[other]));
+        let Some(next) = next else {
+            let remaining: Vec<_> = (0..nodes.len()).filter(|&index| !done[index]).map(|index| nodes[index].label).collect();
+            panic!("The render graph has a dependency cycle between {:?}", remaining);
+        };
+        done[next] = true;
+        order.push(next);
+    }
+    return order;
+}
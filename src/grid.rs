@@ -0,0 +1,211 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 무한 그리드를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a builder that creates an infinite grid. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InfiniteGridBuilder {
+    pub minor_color: glam::Vec4, 
+    pub major_color: glam::Vec4, 
+    pub cell_size: f32, 
+    pub major_divisions: u32, 
+    pub fade_distance: f32, 
+    pub line_width: f32, 
+}
+
+impl Default for InfiniteGridBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            minor_color: (0.45, 0.45, 0.45, 0.6).into(), 
+            major_color: (0.2, 0.2, 0.2, 0.9).into(), 
+            cell_size: 0.5, 
+            major_divisions: 10, 
+            fade_distance: 60.0, 
+            line_width: 1.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl InfiniteGridBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_minor_color(mut self, minor_color: glam::Vec4) -> Self {
+        self.minor_color = minor_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_major_color(mut self, major_color: glam::Vec4) -> Self {
+        self.major_color = major_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    #[inline]
+    pub fn set_major_divisions(mut self, major_divisions: u32) -> Self {
+        self.major_divisions = major_divisions;
+        self
+    }
+
+    #[inline]
+    pub fn set_fade_distance(mut self, fade_distance: f32) -> Self {
+        self.fade_distance = fade_distance;
+        self
+    }
+
+    #[inline]
+    pub fn set_line_width(mut self, line_width: f32) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> InfiniteGrid {
+        assert!(self.cell_size > 0.0 && self.major_divisions > 0);
+        assert!(self.fade_distance > 0.0 && self.line_width > 0.0);
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(InfiniteGrid)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<GridUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(InfiniteGrid)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let grid = InfiniteGrid {
+            minor_color: self.minor_color, 
+            major_color: self.major_color, 
+            cell_size: self.cell_size, 
+            major_divisions: self.major_divisions, 
+            fade_distance: self.fade_distance, 
+            line_width: self.line_width, 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+        };
+        grid.update_resource(queue);
+
+        return grid;
+    }
+}
+
+/// #### 한국어 </br>
+/// 월드 공간의 XZ 평면에 그려지는 무한 그리드 입니다. </br>
+/// 정점 버퍼 없이 카메라 주변의 큰 사각형을 그리며, 
+/// 선과 거리에 따른 감쇠는 프래그먼트 쉐이더에서 계산됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An infinite grid drawn on the XZ plane of world space. </br>
+/// It draws a large quad around the camera without a vertex buffer, 
+/// and the lines and distance fade are computed in the fragment shader. </br>
+/// 
+#[derive(Debug)]
+pub struct InfiniteGrid {
+    minor_color: glam::Vec4, 
+    major_color: glam::Vec4, 
+    cell_size: f32, 
+    major_divisions: u32, 
+    fade_distance: f32, 
+    line_width: f32, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+}
+
+impl InfiniteGrid {
+    /// #### 한국어 </br>
+    /// 그리드를 그립니다. 그리드 파이프라인이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the grid. The grid pipeline must be set. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::draw(rpass, 0..6, 0..1);
+    }
+}
+
+impl ShaderResource for InfiniteGrid {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = GridUniformLayout {
+            minor_color: self.minor_color, 
+            major_color: self.major_color, 
+            cell_size: self.cell_size, 
+            major_divisions: self.major_divisions as f32, 
+            fade_distance: self.fade_distance, 
+            line_width: self.line_width, 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 그리드 유니폼 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the grid uniform data used in the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridUniformLayout {
+    pub minor_color: glam::Vec4, 
+    pub major_color: glam::Vec4, 
+    pub cell_size: f32, 
+    pub major_divisions: f32, 
+    pub fade_distance: f32, 
+    pub line_width: f32, 
+}
+
+impl Default for GridUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            minor_color: glam::Vec4::ONE, 
+            major_color: glam::Vec4::ONE, 
+            cell_size: 1.0, 
+            major_divisions: 10.0, 
+            fade_distance: 60.0, 
+            line_width: 1.0
+        }
+    }
+}
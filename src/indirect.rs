@@ -0,0 +1,134 @@
+use std::mem;
+use wgpu::util::DrawIndexedIndirectArgs;
+
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 간접 그리기 경로가 사용할 수 있으면 요청하는 장치 기능들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The device features requested for the indirect draw path when they are available. </br>
+/// 
+pub const INDIRECT_DRAW_FEATURES: wgpu::Features = wgpu::Features::MULTI_DRAW_INDIRECT
+    .union(wgpu::Features::INDIRECT_FIRST_INSTANCE);
+
+
+
+/// #### 한국어 </br>
+/// 그리기 인자들이 GPU 버퍼에 저장되는 간접 그리기 버퍼 입니다. </br>
+/// 버퍼는 `STORAGE` 용도로도 생성되므로 GPU에서 컬링을 수행하는 컴퓨트 쉐이더가
+/// 각 명령의 `instance_count`를 직접 기록하여 그려질 대상을 결정할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An indirect draw buffer where the draw arguments are stored in a GPU buffer. </br>
+/// The buffer is also created with the `STORAGE` usage, so a compute shader performing culling on the GPU
+/// can write the `instance_count` of each command itself to decide what gets drawn. </br>
+/// 
+#[derive(Debug)]
+pub struct IndirectDrawBuffer {
+    commands: Vec<DrawIndexedIndirectArgs>, 
+    buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl IndirectDrawBuffer {
+    pub fn new(
+        label: &str, 
+        commands: Vec<DrawIndexedIndirectArgs>, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(!commands.is_empty());
+
+        let buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(label), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<DrawIndexedIndirectArgs>() * commands.len()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::INDIRECT | wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let indirect = Self { commands, buffer };
+        indirect.write_commands(queue);
+
+        return indirect;
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    #[inline]
+    pub fn commands_ref(&self) -> &[DrawIndexedIndirectArgs] {
+        &self.commands
+    }
+
+    #[inline]
+    pub fn commands_mut(&mut self) -> &mut [DrawIndexedIndirectArgs] {
+        &mut self.commands
+    }
+
+    #[inline]
+    pub fn buffer_ref(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+
+    /// #### 한국어 </br>
+    /// CPU 쪽의 그리기 명령들을 GPU 버퍼에 기록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the draw commands on the CPU side to the GPU buffer. </br>
+    /// 
+    pub fn write_commands(&self, queue: &wgpu::Queue) {
+        let data: Vec<u8> = self.commands.iter()
+            .flat_map(|command| command.as_bytes().iter().copied())
+            .collect();
+        queue.write_buffer(&self.buffer, 0, &data);
+    }
+
+    /// #### 한국어 </br>
+    /// `index`번째 명령의 버퍼 내 위치를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the offset in the buffer of the `index`-th command. </br>
+    /// 
+    #[inline]
+    pub fn offset_of(&self, index: usize) -> wgpu::BufferAddress {
+        (mem::size_of::<DrawIndexedIndirectArgs>() * index) as wgpu::BufferAddress
+    }
+
+    /// #### 한국어 </br>
+    /// 버퍼의 모든 명령을 한 번의 다중 간접 그리기 호출로 그립니다. </br>
+    /// `MULTI_DRAW_INDIRECT` 기능이 활성화되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws all commands of the buffer with a single multi-draw indirect call. </br>
+    /// The `MULTI_DRAW_INDIRECT` feature must be enabled. </br>
+    /// 
+    #[inline]
+    pub fn multi_draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::record_draws(1, self.commands.iter().map(|command| command.instance_count as usize).sum());
+        rpass.multi_draw_indexed_indirect(&self.buffer, 0, self.commands.len() as u32);
+    }
+
+    /// #### 한국어 </br>
+    /// `index`번째 명령 하나를 간접 그리기로 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the single `index`-th command as an indirect draw. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, index: usize) {
+        stats::record_draws(1, self.commands[index].instance_count as usize);
+        rpass.draw_indexed_indirect(&self.buffer, self.offset_of(index));
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+use winit::{
+    keyboard::KeyCode, 
+    event::MouseButton, 
+    window::{CursorGrabMode, Window}, 
+};
+
+use crate::replay::InputEvent;
+
+
+
+/// #### 한국어 </br>
+/// 커서를 창에 가두고 숨기거나, 다시 풀어 보이게 합니다. 창이 커서를 가둘 수 있으면 `true`를 반환합니다. </br>
+/// 커서를 제자리에 고정하는 `Locked` 모드를 먼저 시도하고, 지원되지 않으면 창 안에 가두는 `Confined` 모드를 사용합니다. </br>
+/// 가둔 동안에는 커서 위치가 의미가 없으므로 `DeviceEvent::MouseMotion`의 상대 이동을 사용해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Confines and hides the cursor in the window, or releases it and shows it again. Returns `true` if the window could grab the cursor. </br>
+/// It tries the `Locked` mode keeping the cursor in place first, and uses the `Confined` mode keeping it inside the window if that is not supported. </br>
+/// The cursor position is meaningless while grabbed, so the relative motion of `DeviceEvent::MouseMotion` must be used. </br>
+/// 
+pub fn set_cursor_grab(window: &Window, grab: bool) -> bool {
+    if !grab {
+        if let Err(e) = window.set_cursor_grab(CursorGrabMode::None) {
+            log::warn!("Failed to release the cursor. ({})", e);
+        }
+        window.set_cursor_visible(true);
+        return false;
+    }
+
+    let result = window.set_cursor_grab(CursorGrabMode::Locked)
+        .or_else(|_| window.set_cursor_grab(CursorGrabMode::Confined));
+    match result {
+        Ok(()) => {
+            window.set_cursor_visible(false);
+            return true;
+        }, 
+        Err(e) => {
+            log::warn!("Failed to grab the cursor. ({})", e);
+            return false;
+        }, 
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 프레임의 입력 이벤트들을 모은 입력 상태 입니다. </br>
+/// 눌린 키와 마우스 버튼, 커서 위치는 프레임이 지나도 유지되고, 마우스 상대 이동과 휠 스크롤, 이번 프레임에 눌린 키는 프레임마다 새로 모입니다. </br>
+/// 기록된 입력을 재생할 때도 같은 상태가 만들어지도록 `EVENT_QUEUE`의 이벤트가 아니라 그로부터 만든 `InputEvent`로 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The input state gathering the input events of one frame. </br>
+/// The pressed keys and mouse buttons and the cursor position are kept across frames, while the relative mouse motion, the wheel scroll and the keys pressed this frame are gathered anew every frame. </br>
+/// It is updated with the `InputEvent`s made from the events of `EVENT_QUEUE` rather than the events themselves, so the same state is built when replaying a recorded input. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputState {
+    pressed_keys: HashSet<KeyCode>, 
+    just_pressed_keys: HashSet<KeyCode>, 
+    pressed_buttons: HashSet<MouseButton>, 
+    cursor_position: (f64, f64), 
+    mouse_delta: glam::Vec2, 
+    scroll_delta: f32, 
+}
+
+impl Default for InputState {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            pressed_keys: HashSet::new(), 
+            just_pressed_keys: HashSet::new(), 
+            pressed_buttons: HashSet::new(), 
+            cursor_position: (0.0, 0.0), 
+            mouse_delta: glam::Vec2::ZERO, 
+            scroll_delta: 0.0, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl InputState {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 새 프레임을 시작하고 이번 프레임의 입력 이벤트들을 모읍니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Begins a new frame and gathers the input events of this frame. </br>
+    /// 
+    pub fn update(&mut self, inputs: &[InputEvent]) {
+        self.just_pressed_keys.clear();
+        self.mouse_delta = glam::Vec2::ZERO;
+        self.scroll_delta = 0.0;
+        for input in inputs.iter() {
+            self.handle_input(input);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 입력 이벤트 하나를 상태에 반영합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies one input event to the state. </br>
+    /// 
+    pub fn handle_input(&mut self, input: &InputEvent) {
+        match *input {
+            InputEvent::Key { code, pressed: true, repeat } => {
+                if self.pressed_keys.insert(code) || !repeat {
+                    self.just_pressed_keys.insert(code);
+                }
+            }, 
+            InputEvent::Key { code, pressed: false, .. } => {
+                self.pressed_keys.remove(&code);
+            }, 
+            InputEvent::CursorMoved { x, y } => self.cursor_position = (x, y), 
+            InputEvent::LeftMousePressed => self.set_button(MouseButton::Left, true), 
+            InputEvent::LeftMouseReleased => self.set_button(MouseButton::Left, false), 
+            InputEvent::MiddleMouse { pressed } => self.set_button(MouseButton::Middle, pressed), 
+            InputEvent::RightMouse { pressed } => self.set_button(MouseButton::Right, pressed), 
+            InputEvent::MouseMotion { dx, dy } => self.mouse_delta += glam::vec2(dx as f32, dy as f32), 
+            InputEvent::MouseWheel { lines } => self.scroll_delta += lines, 
+        }
+    }
+
+    #[inline]
+    fn set_button(&mut self, button: MouseButton, pressed: bool) {
+        match pressed {
+            true => self.pressed_buttons.insert(button), 
+            false => self.pressed_buttons.remove(&button), 
+        };
+    }
+
+    /// #### 한국어 </br>
+    /// 키가 눌려 있으면 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the key is held down. </br>
+    /// 
+    #[inline]
+    pub fn is_key_pressed(&self, code: KeyCode) -> bool {
+        self.pressed_keys.contains(&code)
+    }
+
+    /// #### 한국어 </br>
+    /// 키가 이번 프레임에 눌렸으면 `true`를 반환합니다. 키 반복은 포함하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the key was pressed this frame. Key repeats are not included. </br>
+    /// 
+    #[inline]
+    pub fn is_key_just_pressed(&self, code: KeyCode) -> bool {
+        self.just_pressed_keys.contains(&code)
+    }
+
+    #[inline]
+    pub fn is_button_pressed(&self, button: MouseButton) -> bool {
+        self.pressed_buttons.contains(&button)
+    }
+
+    #[inline]
+    pub fn cursor_position(&self) -> (f64, f64) {
+        self.cursor_position
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임 동안 마우스가 움직인 상대 거리(픽셀) 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The relative distance (in pixels) the mouse moved during this frame. </br>
+    /// 
+    #[inline]
+    pub fn mouse_delta(&self) -> glam::Vec2 {
+        self.mouse_delta
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임 동안 휠이 굴러간 줄 수 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of lines the wheel scrolled during this frame. </br>
+    /// 
+    #[inline]
+    pub fn scroll_delta(&self) -> f32 {
+        self.scroll_delta
+    }
+}
@@ -0,0 +1,401 @@
+use std::mem;
+use std::ops::Range;
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DrawIndexedIndirectArgs;
+
+use crate::indirect::{IndirectDrawBuffer, INDIRECT_DRAW_FEATURES};
+use crate::mesh::ModelMesh;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 스트레스 테스트 장면에 그려지는 기본 인스턴스 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default number of instances drawn in the stress test scene. </br>
+/// 
+pub const STRESS_TEST_INSTANCE_COUNT: usize = 100_000;
+
+
+
+/// #### 한국어 </br>
+/// 하나의 인스턴스 버퍼로 그려지는 큐브 필드를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a cube field drawn from one instance buffer. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubeFieldBuilder {
+    pub count: usize, 
+    pub chunk_size: u32, 
+    pub spacing: f32, 
+    pub inner_radius: f32, 
+    pub max_height: f32, 
+}
+
+impl Default for CubeFieldBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: STRESS_TEST_INSTANCE_COUNT, 
+            chunk_size: 4096, 
+            spacing: 1.5, 
+            inner_radius: 4.0, 
+            max_height: 3.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl CubeFieldBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    #[inline]
+    pub fn set_chunk_size(mut self, chunk_size: u32) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    #[inline]
+    pub fn set_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    #[inline]
+    pub fn set_inner_radius(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    #[inline]
+    pub fn set_max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    pub fn build(self, device: &wgpu::Device, queue: &wgpu::Queue) -> CubeField {
+        assert!(self.count > 0 && self.chunk_size > 0);
+        assert!(self.spacing > 0.0 && self.max_height > 0.0);
+
+        // (한국어) 장면의 중앙을 비워두고 안쪽 고리부터 격자 위에 인스턴스들을 배치합니다.
+        // (English Translation) Places the instances on a grid from the inner rings outward, leaving the center of the scene empty.
+        let mut instances = Vec::with_capacity(self.count);
+        let mut ring = 0i32;
+        while instances.len() < self.count {
+            for z in -ring..=ring {
+                for x in -ring..=ring {
+                    if (x.abs() != ring && z.abs() != ring) || instances.len() >= self.count {
+                        continue;
+                    }
+
+                    let position = glam::vec2(x as f32, z as f32) * self.spacing;
+                    if position.length() < self.inner_radius {
+                        continue;
+                    }
+
+                    let hash = hash(x, z);
+                    let height = 0.25 + (hash & 0xFF) as f32 / 255.0 * self.max_height;
+                    let color = glam::vec3(
+                        ((hash >> 8) & 0xFF) as f32 / 255.0, 
+                        ((hash >> 16) & 0xFF) as f32 / 255.0, 
+                        ((hash >> 24) & 0xFF) as f32 / 255.0, 
+                    );
+                    instances.push(InstanceLayout {
+                        world: glam::Mat4::from_scale_rotation_translation(
+                            (0.5, height, 0.5).into(), 
+                            glam::Quat::IDENTITY, 
+                            (position.x, 0.5 * height, position.y).into()
+                        ), 
+                        color: (0.2 + 0.8 * color, 1.0).into(), 
+                    });
+                }
+            }
+            ring += 1;
+        }
+
+        return CubeField {
+            instances: InstancedObject::new("CubeField", instances, device, queue), 
+            chunk_size: self.chunk_size, 
+        };
+    }
+}
+
+/// #### 한국어 </br>
+/// 인스턴스마다 변환 행렬과 색상을 가지는 큐브 필드 입니다. </br>
+/// 오브젝트마다 바인드 그룹을 바꾸는 대신 한 번의 그리기 호출로 모든 큐브를 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A cube field where each instance has its own transform matrix and color. </br>
+/// Instead of switching bind groups per object, it draws all cubes with a single draw call. </br>
+/// 
+#[derive(Debug)]
+pub struct CubeField {
+    instances: InstancedObject, 
+    chunk_size: u32, 
+}
+
+impl CubeField {
+    #[inline]
+    pub fn num_instances(&self) -> u32 {
+        self.instances.num_instances()
+    }
+
+    #[inline]
+    pub fn instances_ref(&self) -> &InstancedObject {
+        &self.instances
+    }
+
+    /// #### 한국어 </br>
+    /// 인스턴스 버퍼를 1번 정점 버퍼 슬롯에 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the instance buffer to vertex buffer slot 1. </br>
+    /// 
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        self.instances.bind(rpass);
+    }
+
+    /// #### 한국어 </br>
+    /// 인스턴스들을 `chunk_size` 단위로 나눈 간접 그리기 버퍼를 생성합니다. </br>
+    /// `INDIRECT_FIRST_INSTANCE` 기능이 없으면 모든 명령의 `first_instance`는 0이 되며, 
+    /// 그리기 시 청크마다 인스턴스 버퍼의 범위를 다시 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an indirect draw buffer that splits the instances into units of `chunk_size`. </br>
+    /// Without the `INDIRECT_FIRST_INSTANCE` feature, the `first_instance` of every command is 0, 
+    /// and the range of the instance buffer is rebound for each chunk when drawing. </br>
+    /// 
+    pub fn create_indirect_buffer(
+        &self, 
+        num_indices: u32, 
+        features: wgpu::Features, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> IndirectDrawBuffer {
+        let first_instance = features.contains(wgpu::Features::INDIRECT_FIRST_INSTANCE);
+        let num_instances = self.num_instances();
+        let commands = (0..num_instances)
+            .step_by(self.chunk_size as usize)
+            .map(|start| DrawIndexedIndirectArgs {
+                index_count: num_indices, 
+                instance_count: self.chunk_size.min(num_instances - start), 
+                first_index: 0, 
+                base_vertex: 0, 
+                first_instance: if first_instance { start } else { 0 }, 
+            })
+            .collect();
+
+        IndirectDrawBuffer::new("Indirect(CubeField)", commands, device, queue)
+    }
+
+    /// #### 한국어 </br>
+    /// 간접 그리기 버퍼의 명령들로 큐브 필드를 그립니다. 메쉬가 바인딩되어 있어야 합니다. </br>
+    /// 가능하면 한 번의 다중 간접 그리기 호출을 사용하고, 그렇지 않으면 청크마다 간접 그리기를 호출합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the cube field with the commands of the indirect draw buffer. The mesh must be bound. </br>
+    /// It uses a single multi-draw indirect call when possible, otherwise it issues an indirect draw per chunk. </br>
+    /// 
+    pub fn draw_indirect<'a>(
+        &'a self, 
+        rpass: &mut wgpu::RenderPass<'a>, 
+        indirect: &'a IndirectDrawBuffer, 
+        features: wgpu::Features
+    ) {
+        if features.contains(wgpu::Features::INDIRECT_FIRST_INSTANCE) {
+            self.bind(rpass);
+            if features.contains(wgpu::Features::MULTI_DRAW_INDIRECT) {
+                indirect.multi_draw(rpass);
+            } else {
+                for index in 0..indirect.len() {
+                    indirect.draw(rpass, index);
+                }
+            }
+        } else {
+            for index in 0..indirect.len() {
+                let start = index as u32 * self.chunk_size;
+                let end = (start + self.chunk_size).min(self.num_instances());
+                self.instances.bind_range(rpass, start..end);
+                indirect.draw(rpass, index);
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 간접 그리기 경로가 사용하는 그리기 호출의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of draw calls used by the indirect draw path. </br>
+    /// 
+    #[inline]
+    pub fn num_indirect_draw_calls(&self, indirect: &IndirectDrawBuffer, features: wgpu::Features) -> usize {
+        match features.contains(INDIRECT_DRAW_FEATURES) {
+            true => 1, 
+            false => indirect.len(), 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 인스턴스마다 월드 변환 행렬과 색상을 담는 인스턴스 버퍼를 가진 오브젝트 입니다. </br>
+/// 같은 메쉬를 반복해서 그릴 때 `VertexStepMode::Instance` 파이프라인으로 한 번에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An object with an instance buffer holding the world transform matrix and color of each instance. </br>
+/// When the same mesh is drawn repeatedly, it is drawn at once with a `VertexStepMode::Instance` pipeline. </br>
+/// 
+#[derive(Debug)]
+pub struct InstancedObject {
+    instances: Vec<InstanceLayout>, 
+    instance_buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl InstancedObject {
+    pub fn new(
+        label: &str, 
+        instances: Vec<InstanceLayout>, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(!instances.is_empty());
+
+        // (한국어) GPU 컬링의 컴퓨트 쉐이더가 인스턴스들을 읽을 수 있도록 저장 버퍼로도 사용됩니다.
+        // (English Translation) It is also used as a storage buffer so the compute shader of the GPU culling can read the instances.
+        let instance_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&format!("Instance({})", label)), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<InstanceLayout>() * instances.len()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        queue.write_buffer(&instance_buffer, 0, bytemuck::cast_slice(&instances));
+
+        Self { 
+            instances, 
+            instance_buffer 
+        }
+    }
+
+    #[inline]
+    pub fn num_instances(&self) -> u32 {
+        self.instances.len() as u32
+    }
+
+    #[inline]
+    pub fn instances_ref(&self) -> &[InstanceLayout] {
+        &self.instances
+    }
+
+    #[inline]
+    pub fn buffer_ref(&self) -> &wgpu::Buffer {
+        &self.instance_buffer
+    }
+
+    /// #### 한국어 </br>
+    /// 인스턴스의 데이터를 바꿉니다. 변경 사항은 `update_range`를 호출해야 버퍼에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the data of an instance. The change is reflected in the buffer after calling `update_range`. </br>
+    /// 
+    #[inline]
+    pub fn set_instance(&mut self, index: usize, instance: InstanceLayout) {
+        self.instances[index] = instance;
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 범위의 인스턴스 데이터를 버퍼에 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the instance data of the given range into the buffer. </br>
+    /// 
+    pub fn update_range(&self, range: Range<u32>, queue: &wgpu::Queue) {
+        let offset = range.start as wgpu::BufferAddress * mem::size_of::<InstanceLayout>() as wgpu::BufferAddress;
+        let data = &self.instances[range.start as usize..range.end as usize];
+        queue.write_buffer(&self.instance_buffer, offset, bytemuck::cast_slice(data));
+    }
+
+    /// #### 한국어 </br>
+    /// 인스턴스 버퍼를 1번 정점 버퍼 슬롯에 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the instance buffer to vertex buffer slot 1. </br>
+    /// 
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        rpass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 범위의 인스턴스만 1번 정점 버퍼 슬롯에 바인딩합니다. 첫 인스턴스는 인스턴스 번호 0이 됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds only the instances of the given range to vertex buffer slot 1. The first of them becomes instance index 0. </br>
+    /// 
+    #[inline]
+    pub fn bind_range<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, range: Range<u32>) {
+        let stride = mem::size_of::<InstanceLayout>() as wgpu::BufferAddress;
+        let start = range.start as wgpu::BufferAddress * stride;
+        let end = range.end as wgpu::BufferAddress * stride;
+        rpass.set_vertex_buffer(1, self.instance_buffer.slice(start..end));
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 메쉬와 인스턴스 버퍼를 바인딩하고 모든 인스턴스를 한 번의 그리기 호출로 그립니다.</br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the given mesh and the instance buffer, and draws all instances in a single draw call.</br>
+    /// 
+    #[inline]
+    pub fn draw<'a, T: ModelMesh>(&'a self, rpass: &mut wgpu::RenderPass<'a>, mesh: &'a T) {
+        mesh.bind(rpass);
+        self.bind(rpass);
+        mesh.draw_instanced(rpass, 0..self.num_instances());
+    }
+}
+
+#[inline]
+fn hash(x: i32, z: i32) -> u32 {
+    let mut h = (x as u32).wrapping_mul(0x8DA6_B343) ^ (z as u32).wrapping_mul(0xD816_3841);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0x5BD1_E995);
+    h ^= h >> 15;
+    h
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 인스턴스 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the instance data used in the shader. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InstanceLayout {
+    pub world: glam::Mat4, 
+    pub color: glam::Vec4, 
+}
+
+impl Default for InstanceLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            world: glam::Mat4::IDENTITY, 
+            color: glam::Vec4::ONE, 
+        }
+    }
+}
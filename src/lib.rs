@@ -0,0 +1,118 @@
+//! #### 한국어 </br>
+//! `wgpu`로 물체와 그림자를 그리는 실험에서 사용하는 기본 요소들 입니다. </br>
+//! `camera`, `light`, `mesh`, `object`, `pipeline`, `renderer`, `shadow`, `timer` 모듈이 공개된 API이며, 
+//! 나머지 모듈들은 `renderer::Renderer`와 예제 바이너리(`main.rs`)가 사용하는 장면의 단계들로 문서에 드러나지 않고 자주 바뀔 수 있습니다. </br>
+//! 렌더러와 예제 바이너리가 직접 사용하지 않는 내부 모듈은 크레이트 밖으로 공개되지 않습니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! The basic building blocks used by the experiments drawing objects and their shadows with `wgpu`. </br>
+//! The `camera`, `light`, `mesh`, `object`, `pipeline`, `renderer`, `shadow` and `timer` modules are the public API, 
+//! and the remaining modules are the stages of the scene used by `renderer::Renderer` and the example binary (`main.rs`), which are hidden from the documentation and may change often. </br>
+//! The internal modules used directly by neither the renderer nor the example binary are not exposed outside the crate. </br>
+//! 
+
+#![allow(clippy::needless_return, clippy::vec_init_then_push, clippy::type_complexity, clippy::collapsible_match)]
+
+pub mod camera;
+pub mod light;
+pub mod mesh;
+pub mod object;
+pub mod pipeline;
+pub mod renderer;
+pub mod shadow;
+pub mod timer;
+
+#[doc(hidden)]
+pub mod allocation;
+#[doc(hidden)]
+pub mod bench;
+#[doc(hidden)]
+pub mod bounds;
+#[doc(hidden)]
+pub mod bvh;
+#[doc(hidden)]
+pub mod collision;
+#[doc(hidden)]
+pub mod debug;
+#[doc(hidden)]
+pub mod deferred;
+#[doc(hidden)]
+pub mod displacement;
+pub(crate) mod exposure;
+#[doc(hidden)]
+pub mod fog;
+#[doc(hidden)]
+pub mod gpucull;
+#[doc(hidden)]
+pub mod graph;
+#[doc(hidden)]
+pub mod grid;
+pub(crate) mod indirect;
+#[doc(hidden)]
+pub mod input;
+#[doc(hidden)]
+pub mod instance;
+#[doc(hidden)]
+pub mod lighting;
+#[doc(hidden)]
+pub mod matcap;
+#[doc(hidden)]
+pub mod material;
+#[doc(hidden)]
+pub mod mirror;
+#[doc(hidden)]
+pub mod monitor;
+#[doc(hidden)]
+pub mod navigation;
+#[doc(hidden)]
+pub mod network;
+#[doc(hidden)]
+pub mod occlusion;
+#[doc(hidden)]
+pub mod oit;
+#[doc(hidden)]
+pub mod parallax;
+#[doc(hidden)]
+pub mod pbr;
+#[cfg(feature = "physics")]
+#[doc(hidden)]
+pub mod physics;
+#[doc(hidden)]
+pub mod picking;
+#[doc(hidden)]
+pub mod profiler;
+#[doc(hidden)]
+pub mod postprocess;
+#[doc(hidden)]
+pub mod replay;
+#[doc(hidden)]
+pub mod resource;
+#[doc(hidden)]
+pub mod scene;
+#[doc(hidden)]
+pub mod settings;
+#[doc(hidden)]
+pub mod skybox;
+#[doc(hidden)]
+pub mod shader;
+#[doc(hidden)]
+pub mod shadowview;
+#[doc(hidden)]
+pub mod sprite;
+#[doc(hidden)]
+pub mod stats;
+#[doc(hidden)]
+pub mod steering;
+#[doc(hidden)]
+pub mod target;
+#[doc(hidden)]
+pub mod terrain;
+#[doc(hidden)]
+pub mod text;
+#[cfg(feature = "egui")]
+#[doc(hidden)]
+pub mod tweak;
+#[doc(hidden)]
+pub mod utils;
+#[doc(hidden)]
+pub mod water;
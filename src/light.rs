@@ -2,7 +2,9 @@ use std::mem;
 use bytemuck::{Pod, Zeroable};
 
 use crate::object::GameObject;
-use crate::resource::ShaderResource;
+use crate::resource::{FrameUniformBuffer, ShaderResource};
+use crate::shadow::ShadowFilter;
+use crate::stats;
 
 
 
@@ -95,86 +97,25 @@ impl GlobalLightBuilder {
         self, 
         uniform_bind_group_layout: &wgpu::BindGroupLayout, 
         texture_bind_group_layout: &wgpu::BindGroupLayout, 
+        shadow_filter: &ShadowFilter, 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) -> GlobalLight {
-        let uniform_buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some("Uniform(GlobalLight)"), 
-                mapped_at_creation: false,  
-                size: mem::size_of::<GlobalLightUniformLayout>() as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-
-        let uniform_bind_group = device.create_bind_group(
-            &wgpu::BindGroupDescriptor {
-                label: Some("BindGroup(Uniform(GlobalLight))"), 
-                layout: uniform_bind_group_layout, 
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0, 
-                        resource: wgpu::BindingResource::Buffer(
-                            uniform_buffer.as_entire_buffer_binding()
-                        ), 
-                    }, 
-                ], 
-            }, 
+        let uniform = FrameUniformBuffer::new(
+            "GlobalLight", 
+            mem::size_of::<GlobalLightUniformLayout>() as wgpu::BufferAddress, 
+            0, 
+            uniform_bind_group_layout, 
+            device
         );
 
-        let shadow_texture_view = device.create_texture(
-            &wgpu::TextureDescriptor {
-                label: Some("Texture(GlobalLight)"), 
-                size: wgpu::Extent3d {
-                    width: self.shadow_map_width, 
-                    height: self.shadow_map_height, 
-                    depth_or_array_layers: 1, 
-                }, 
-                dimension: wgpu::TextureDimension::D2, 
-                format: wgpu::TextureFormat::Depth32Float, 
-                mip_level_count: 1, 
-                sample_count: 1, 
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-                view_formats: &[]
-            }
-        )
-        .create_view(&wgpu::TextureViewDescriptor {
-            ..Default::default()
-        });
-
-        let shadow_texture_sampler = device.create_sampler(
-            &wgpu::SamplerDescriptor {
-                label: Some("Sampler(GlobalLight)"), 
-                address_mode_u: wgpu::AddressMode::ClampToEdge, 
-                address_mode_v: wgpu::AddressMode::ClampToEdge, 
-                address_mode_w: wgpu::AddressMode::ClampToEdge, 
-                mag_filter: wgpu::FilterMode::Linear, 
-                min_filter: wgpu::FilterMode::Linear, 
-                mipmap_filter: wgpu::FilterMode::Nearest, 
-                compare: Some(wgpu::CompareFunction::LessEqual), 
-                ..Default::default()
-            }, 
-        );
-
-        let texture_bind_group = device.create_bind_group(
-            &wgpu::BindGroupDescriptor {
-                label: Some("BindGroup(TextureView(Shadow))"), 
-                layout: texture_bind_group_layout, 
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0, 
-                        resource: wgpu::BindingResource::TextureView(
-                            &shadow_texture_view
-                        ), 
-                    }, 
-                    wgpu::BindGroupEntry {
-                        binding: 1, 
-                        resource: wgpu::BindingResource::Sampler(
-                            &shadow_texture_sampler
-                        ), 
-                    }, 
-                ], 
-            }, 
+        let (shadow_texture_view, texture_bind_group) = create_shadow_map(
+            "GlobalLight", 
+            self.shadow_map_width, 
+            self.shadow_map_height, 
+            texture_bind_group_layout, 
+            shadow_filter, 
+            device
         );
 
         let global_light = GlobalLight {
@@ -186,8 +127,7 @@ impl GlobalLightBuilder {
                 self.rotation.normalize(), 
                 self.translation
             ), 
-            uniform_buffer, 
-            uniform_bind_group, 
+            uniform, 
             texture_bind_group, 
         };
         global_light.update_resource(queue);
@@ -222,11 +162,40 @@ pub struct GlobalLight {
     shadow_map_width: u32, 
     shadow_map_height: u32, 
     shadow_texture_view: wgpu::TextureView, 
-    uniform_buffer: wgpu::Buffer, 
-    pub uniform_bind_group: wgpu::BindGroup, 
+    uniform: FrameUniformBuffer, 
     pub texture_bind_group: wgpu::BindGroup, 
 }
 
+#[allow(dead_code)]
+impl GlobalLight {
+    /// #### 한국어 </br>
+    /// 마지막으로 갱신된 유니폼 데이터를 묶는 바인드 그룹을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bind group binding the most recently updated uniform data. </br>
+    /// 
+    #[inline]
+    pub fn uniform_bind_group(&self) -> &wgpu::BindGroup {
+        self.uniform.bind_group()
+    }
+
+    #[inline]
+    pub fn light_color(&self) -> glam::Vec3 {
+        self.light_color
+    }
+
+    /// #### 한국어 </br>
+    /// 조명의 색상을 변경합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the color of the light. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn set_light_color(&mut self, light_color: glam::Vec3) {
+        self.light_color = light_color;
+    }
+}
+
 impl GameObject for GlobalLight {
     #[inline]
     fn world_transform_ref(&self) -> &glam::Mat4 {
@@ -254,17 +223,9 @@ impl LightObject for GlobalLight {
         )
     }
 
+    #[inline]
     fn get_view_matrix(&self) -> glam::Mat4 {
-        let right = self.get_right();
-        let up = self.get_up();
-        let look = self.get_look();
-        let position = self.get_translation();
-        return glam::mat4(
-            glam::vec4(right.x, up.x, look.x, 0.0), 
-            glam::vec4(right.y, up.y, look.y, 0.0), 
-            glam::vec4(right.z, up.z, look.z, 0.0), 
-            glam::vec4(-position.dot(right), -position.dot(up), -position.dot(look), 1.0)
-        );
+        light_view_matrix(self)
     }
 }
 
@@ -275,16 +236,20 @@ impl ShaderResource for GlobalLight {
             proj_view: self.get_projection_matrix().mul_mat4(&self.get_view_matrix()), 
             direction: (self.get_look(), 0.0).into(), 
             light_color: (self.light_color, 1.0).into(), 
+            position: (self.get_translation(), 0.0).into(), 
+            cone: glam::Vec4::ZERO, 
         };
-        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+        self.uniform.write(bytemuck::bytes_of(&data), queue);
     }
 }
 
 /// #### 한국어 </br>
 /// 쉐이더에서 사용하는 전역 조명 유니폼 데이터의 레이아웃 입니다. </br>
+/// `SpotLightUniformLayout`과 같은 레이아웃을 가지며, 스포트라이트가 아니므로 `position`의 `w`는 0 입니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This is the layout of the global lighting uniform data used in the shader. </br>
+/// It has the same layout as `SpotLightUniformLayout`, and the `w` of `position` is 0 since it is not a spot light. </br>
 /// 
 #[repr(C, align(16))]
 #[derive(Pod, Zeroable)]
@@ -293,6 +258,8 @@ pub struct GlobalLightUniformLayout {
     pub proj_view: glam::Mat4, 
     pub direction: glam::Vec4, 
     pub light_color: glam::Vec4, 
+    pub position: glam::Vec4, 
+    pub cone: glam::Vec4, 
 }
 
 impl Default for GlobalLightUniformLayout {
@@ -301,7 +268,423 @@ impl Default for GlobalLightUniformLayout {
         Self { 
             proj_view: glam::Mat4::IDENTITY, 
             direction: glam::Vec4::ZERO, 
-            light_color: glam::Vec4::ONE 
+            light_color: glam::Vec4::ONE, 
+            position: glam::Vec4::ZERO, 
+            cone: glam::Vec4::ZERO 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 스포트라이트를 생성하는 빌더입니다. </br>
+/// 각도는 원뿔의 축에서 잰 반각이며, 안쪽 각도 안에서는 완전히 밝고 바깥쪽 각도에서 0이 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a spot light. </br>
+/// The angles are half angles measured from the axis of the cone, fully lit inside the inner angle and falling to 0 at the outer angle. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpotLightBuilder {
+    pub shadow_map_width: u32, 
+    pub shadow_map_height: u32, 
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub light_color: glam::Vec3, 
+    pub inner_angle: f32, 
+    pub outer_angle: f32, 
+    pub range: f32, 
+}
+
+#[allow(dead_code)]
+impl SpotLightBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.rotation = rotation.normalize();
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 조명이 `position`에서 `target`을 비추도록 위치와 회전을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the translation and rotation so that the light shines on `target` from `position`. </br>
+    /// 
+    #[inline]
+    pub fn look_at(mut self, position: glam::Vec3, target: glam::Vec3) -> Self {
+        let view = glam::Mat4::look_at_rh(position, target, glam::Vec3::Y);
+        self.translation = position;
+        self.rotation = glam::Quat::from_mat4(&view.inverse()).normalize();
+        self
+    }
+
+    #[inline]
+    pub fn set_shadow_map_width(mut self, shadow_map_width: u32) -> Self {
+        self.shadow_map_width = shadow_map_width;
+        self
+    }
+
+    #[inline]
+    pub fn set_shadow_map_height(mut self, shadow_map_height: u32) -> Self {
+        self.shadow_map_height = shadow_map_height;
+        self
+    }
+
+    #[inline]
+    pub fn set_light_color(mut self, light_color: glam::Vec3) -> Self {
+        self.light_color = light_color;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 원뿔의 안쪽과 바깥쪽 반각을 라디안 단위로 설정합니다. 안쪽 각도는 바깥쪽 각도를 넘지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the inner and outer half angles of the cone in radians. The inner angle does not exceed the outer angle. </br>
+    /// 
+    #[inline]
+    pub fn set_cone_angles(mut self, inner_angle: f32, outer_angle: f32) -> Self {
+        self.outer_angle = outer_angle.clamp(1e-3, 89.0f32.to_radians());
+        self.inner_angle = inner_angle.clamp(0.0, self.outer_angle);
+        self
+    }
+
+    #[inline]
+    pub fn set_range(mut self, range: f32) -> Self {
+        self.range = range.max(SPOT_LIGHT_NEAR * 2.0);
+        self
+    }
+
+    pub fn build(
+        self, 
+        uniform_bind_group_layout: &wgpu::BindGroupLayout, 
+        texture_bind_group_layout: &wgpu::BindGroupLayout, 
+        shadow_filter: &ShadowFilter, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> SpotLight {
+        let uniform = FrameUniformBuffer::new(
+            "SpotLight", 
+            mem::size_of::<SpotLightUniformLayout>() as wgpu::BufferAddress, 
+            0, 
+            uniform_bind_group_layout, 
+            device
+        );
+
+        let (shadow_texture_view, texture_bind_group) = create_shadow_map(
+            "SpotLight", 
+            self.shadow_map_width, 
+            self.shadow_map_height, 
+            texture_bind_group_layout, 
+            shadow_filter, 
+            device
+        );
+
+        let spot_light = SpotLight {
+            light_color: self.light_color, 
+            inner_angle: self.inner_angle, 
+            outer_angle: self.outer_angle, 
+            range: self.range, 
+            shadow_map_width: self.shadow_map_width, 
+            shadow_map_height: self.shadow_map_height, 
+            shadow_texture_view, 
+            transform: glam::Mat4::from_rotation_translation(
+                self.rotation.normalize(), 
+                self.translation
+            ), 
+            uniform, 
+            texture_bind_group, 
+        };
+        spot_light.update_resource(queue);
+
+        return spot_light;
+    }
+}
+
+impl Default for SpotLightBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            shadow_map_width: 1024, 
+            shadow_map_height: 1024, 
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY, 
+            light_color: glam::Vec3::ONE, 
+            inner_angle: 20.0f32.to_radians(), 
+            outer_angle: 30.0f32.to_radians(), 
+            range: 20.0 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 스포트라이트 그림자 맵의 투영 행렬이 사용하는 가까운 평면의 거리 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The distance of the near plane used by the projection matrix of the spot light shadow map. </br>
+/// 
+const SPOT_LIGHT_NEAR: f32 = 0.05;
+
+/// #### 한국어 </br>
+/// 게임 월드에 존재하는 스포트라이트 입니다. 조명은 `-look` 방향을 비추며, 원뿔과 거리에 따라 감쇠합니다. </br>
+/// 전역 조명과 같은 바인드 그룹 레이아웃을 사용하므로 같은 그림자 맵 경로에서 대신 사용할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a spot light that exists in the game world. It shines in the `-look` direction, attenuated by the cone and the distance. </br>
+/// It uses the same bind group layouts as the global light, so it can be used instead in the same shadow map path. </br>
+/// 
+#[derive(Debug)]
+pub struct SpotLight {
+    light_color: glam::Vec3, 
+    inner_angle: f32, 
+    outer_angle: f32, 
+    range: f32, 
+    transform: glam::Mat4, 
+    shadow_map_width: u32, 
+    shadow_map_height: u32, 
+    shadow_texture_view: wgpu::TextureView, 
+    uniform: FrameUniformBuffer, 
+    pub texture_bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl SpotLight {
+    /// #### 한국어 </br>
+    /// 마지막으로 갱신된 유니폼 데이터를 묶는 바인드 그룹을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bind group binding the most recently updated uniform data. </br>
+    /// 
+    #[inline]
+    pub fn uniform_bind_group(&self) -> &wgpu::BindGroup {
+        self.uniform.bind_group()
+    }
+
+    #[inline]
+    pub fn inner_angle(&self) -> f32 {
+        self.inner_angle
+    }
+
+    #[inline]
+    pub fn outer_angle(&self) -> f32 {
+        self.outer_angle
+    }
+
+    #[inline]
+    pub fn range(&self) -> f32 {
+        self.range
+    }
+
+    /// #### 한국어 </br>
+    /// 원뿔의 반각을 라디안 단위로 설정합니다. 변경 사항은 `update_resource`를 호출해야 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the half angles of the cone in radians. The change is reflected in the shader after calling `update_resource`. </br>
+    /// 
+    #[inline]
+    pub fn set_cone_angles(&mut self, inner_angle: f32, outer_angle: f32) {
+        self.outer_angle = outer_angle.clamp(1e-3, 89.0f32.to_radians());
+        self.inner_angle = inner_angle.clamp(0.0, self.outer_angle);
+    }
+
+    #[inline]
+    pub fn set_range(&mut self, range: f32) {
+        self.range = range.max(SPOT_LIGHT_NEAR * 2.0);
+    }
+}
+
+impl GameObject for SpotLight {
+    #[inline]
+    fn world_transform_ref(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    #[inline]
+    fn world_transform_mut(&mut self) -> &mut glam::Mat4 {
+        &mut self.transform
+    }
+}
+
+impl LightObject for SpotLight {
+    #[inline]
+    fn texture_view_ref(&self) -> &wgpu::TextureView {
+        &self.shadow_texture_view
+    }
+
+    /// #### 한국어 </br>
+    /// 바깥쪽 원뿔을 덮는 시야각과 조명의 범위까지의 원근 투영 행렬을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the perspective projection matrix with the field of view covering the outer cone, up to the range of the light. </br>
+    /// 
+    fn get_projection_matrix(&self) -> glam::Mat4 {
+        glam::Mat4::perspective_rh(
+            2.0 * self.outer_angle, 
+            self.shadow_map_width as f32 / self.shadow_map_height as f32, 
+            SPOT_LIGHT_NEAR, 
+            self.range
+        )
+    }
+
+    #[inline]
+    fn get_view_matrix(&self) -> glam::Mat4 {
+        light_view_matrix(self)
+    }
+}
+
+impl ShaderResource for SpotLight {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = SpotLightUniformLayout {
+            proj_view: self.get_projection_matrix().mul_mat4(&self.get_view_matrix()), 
+            direction: (self.get_look(), 0.0).into(), 
+            light_color: (self.light_color, 1.0).into(), 
+            position: (self.get_translation(), 1.0).into(), 
+            cone: glam::vec4(self.inner_angle.cos(), self.outer_angle.cos(), self.range, 0.0), 
+        };
+        self.uniform.write(bytemuck::bytes_of(&data), queue);
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 스포트라이트 유니폼 데이터의 레이아웃 입니다. </br>
+/// `direction`은 조명을 향하는 방향이고, `cone`은 (안쪽 각도의 코사인, 바깥쪽 각도의 코사인, 범위, 0) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the spot light uniform data used in the shader. </br>
+/// `direction` points toward the light, and `cone` is (cosine of the inner angle, cosine of the outer angle, range, 0). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpotLightUniformLayout {
+    pub proj_view: glam::Mat4, 
+    pub direction: glam::Vec4, 
+    pub light_color: glam::Vec4, 
+    pub position: glam::Vec4, 
+    pub cone: glam::Vec4, 
+}
+
+impl Default for SpotLightUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            proj_view: glam::Mat4::IDENTITY, 
+            direction: glam::Vec4::ZERO, 
+            light_color: glam::Vec4::ONE, 
+            position: glam::Vec4::W, 
+            cone: glam::vec4(1.0, 0.0, 1.0, 0.0) 
         }
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 조명의 위치에서 `look`의 반대 방향을 바라보는 뷰 행렬을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the view matrix looking in the opposite direction of `look` from the position of the light. </br>
+/// 
+fn light_view_matrix<T: GameObject + ?Sized>(light: &T) -> glam::Mat4 {
+    let right = light.get_right();
+    let up = light.get_up();
+    let look = light.get_look();
+    let position = light.get_translation();
+    return glam::mat4(
+        glam::vec4(right.x, up.x, look.x, 0.0), 
+        glam::vec4(right.y, up.y, look.y, 0.0), 
+        glam::vec4(right.z, up.z, look.z, 0.0), 
+        glam::vec4(-position.dot(right), -position.dot(up), -position.dot(look), 1.0)
+    );
+}
+
+/// #### 한국어 </br>
+/// 조명의 그림자 맵 텍스처와, 텍스처와 비교 샘플러, 그림자 필터를 묶는 바인드 그룹을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shadow map texture of a light, and the bind group binding the texture, the comparison sampler and the shadow filter. </br>
+/// 
+fn create_shadow_map(
+    label: &str, 
+    width: u32, 
+    height: u32, 
+    texture_bind_group_layout: &wgpu::BindGroupLayout, 
+    shadow_filter: &ShadowFilter, 
+    device: &wgpu::Device
+) -> (wgpu::TextureView, wgpu::BindGroup) {
+    let shadow_texture_view = stats::track_texture(device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some(&format!("Texture({})", label)), 
+            size: wgpu::Extent3d {
+                width, 
+                height, 
+                depth_or_array_layers: 1, 
+            }, 
+            dimension: wgpu::TextureDimension::D2, 
+            format: wgpu::TextureFormat::Depth32Float, 
+            mip_level_count: 1, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
+            view_formats: &[]
+        }
+    ))
+    .create_view(&wgpu::TextureViewDescriptor {
+        ..Default::default()
+    });
+
+    let shadow_texture_sampler = device.create_sampler(
+        &wgpu::SamplerDescriptor {
+            label: Some(&format!("Sampler({})", label)), 
+            address_mode_u: wgpu::AddressMode::ClampToEdge, 
+            address_mode_v: wgpu::AddressMode::ClampToEdge, 
+            address_mode_w: wgpu::AddressMode::ClampToEdge, 
+            mag_filter: wgpu::FilterMode::Linear, 
+            min_filter: wgpu::FilterMode::Linear, 
+            mipmap_filter: wgpu::FilterMode::Nearest, 
+            compare: Some(wgpu::CompareFunction::LessEqual), 
+            ..Default::default()
+        }, 
+    );
+
+    let texture_bind_group = device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some(&format!("BindGroup(TextureView(Shadow({})))", label)), 
+            layout: texture_bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(
+                        &shadow_texture_view
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::Sampler(
+                        &shadow_texture_sampler
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Buffer(
+                        shadow_filter.buffer_ref().as_entire_buffer_binding()
+                    ), 
+                }, 
+            ], 
+        }, 
+    );
+
+    return (shadow_texture_view, texture_bind_group);
+}
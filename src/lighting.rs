@@ -0,0 +1,285 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 조명 유니폼 블록에 담을 수 있는 최대 조명 수 입니다. 쉐이더의 `MAX_LIGHTS`와 같아야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of lights the lighting uniform block can hold. It must match `MAX_LIGHTS` of the shader. </br>
+/// 
+pub const MAX_LIGHTS: usize = 8;
+
+/// #### 한국어 </br>
+/// 블린-퐁(Blinn-Phong) 음영에 사용되는 그림자가 없는 조명 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A light without shadows used by the Blinn-Phong shading. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhongLight {
+    /// #### 한국어 </br>
+    /// 무한히 먼 곳에서 비추는 조명 입니다. `direction`은 조명을 향하는 방향 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A light shining from infinitely far away. `direction` points toward the light. </br>
+    /// 
+    Directional { direction: glam::Vec3, color: glam::Vec3 }, 
+    /// #### 한국어 </br>
+    /// 한 점에서 비추며 `range`까지 부드럽게 줄어드는 조명 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A light shining from a point that fades out smoothly up to `range`. </br>
+    /// 
+    Point { position: glam::Vec3, color: glam::Vec3, range: f32 }, 
+}
+
+impl PhongLight {
+    #[inline]
+    pub fn layout(&self) -> PhongLightLayout {
+        match *self {
+            Self::Directional { direction, color } => PhongLightLayout {
+                position: (direction.normalize_or_zero(), 0.0).into(), 
+                color: (color, 0.0).into(), 
+            }, 
+            Self::Point { position, color, range } => PhongLightLayout {
+                position: (position, 1.0).into(), 
+                color: (color, range.max(1e-4)).into(), 
+            }, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 블린-퐁 음영의 조명들을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates the lights of the Blinn-Phong shading. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct LightingBuilder {
+    pub lights: Vec<PhongLight>, 
+    pub ambient: f32, 
+    pub shininess: f32, 
+    pub specular_strength: f32, 
+}
+
+impl Default for LightingBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            lights: Vec::new(), 
+            ambient: 0.2, 
+            shininess: 32.0, 
+            specular_strength: 0.5
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl LightingBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 조명을 추가합니다. `MAX_LIGHTS`를 넘는 조명은 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a light. Lights beyond `MAX_LIGHTS` are ignored. </br>
+    /// 
+    #[inline]
+    pub fn add_light(mut self, light: PhongLight) -> Self {
+        if self.lights.len() < MAX_LIGHTS {
+            self.lights.push(light);
+        } else {
+            log::warn!("Only up to {} lights are supported. The light is ignored.", MAX_LIGHTS);
+        }
+        self
+    }
+
+    #[inline]
+    pub fn set_ambient(mut self, ambient: f32) -> Self {
+        self.ambient = ambient.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 정반사 하이라이트의 지수를 설정합니다. 클수록 하이라이트가 작고 날카로워집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the exponent of the specular highlight. The larger it is, the smaller and sharper the highlight. </br>
+    /// 
+    #[inline]
+    pub fn set_shininess(mut self, shininess: f32) -> Self {
+        self.shininess = shininess.max(1.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_specular_strength(mut self, specular_strength: f32) -> Self {
+        self.specular_strength = specular_strength.max(0.0);
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Lighting {
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Lighting)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<LightingUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Lighting)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let lighting = Lighting {
+            lights: self.lights, 
+            ambient: self.ambient, 
+            shininess: self.shininess, 
+            specular_strength: self.specular_strength, 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+        };
+        lighting.update_resource(queue);
+
+        return lighting;
+    }
+}
+
+/// #### 한국어 </br>
+/// 블린-퐁 음영에 사용되는 조명들 입니다. </br>
+/// 그림자를 드리우는 전역 조명에 더해, 그림자가 없는 조명들을 최대 `MAX_LIGHTS`개까지 비춥니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The lights used by the Blinn-Phong shading. </br>
+/// In addition to the global light casting shadows, it shines up to `MAX_LIGHTS` lights without shadows. </br>
+/// 
+#[derive(Debug)]
+pub struct Lighting {
+    lights: Vec<PhongLight>, 
+    ambient: f32, 
+    shininess: f32, 
+    specular_strength: f32, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl Lighting {
+    #[inline]
+    pub fn lights(&self) -> &[PhongLight] {
+        &self.lights
+    }
+
+    /// #### 한국어 </br>
+    /// 조명들을 변경합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the lights. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn lights_mut(&mut self) -> &mut [PhongLight] {
+        &mut self.lights
+    }
+
+    #[inline]
+    pub fn ambient(&self) -> f32 {
+        self.ambient
+    }
+
+    #[inline]
+    pub fn set_ambient(&mut self, ambient: f32) {
+        self.ambient = ambient.max(0.0);
+    }
+
+    #[inline]
+    pub fn shininess(&self) -> f32 {
+        self.shininess
+    }
+
+    #[inline]
+    pub fn set_shininess(&mut self, shininess: f32) {
+        self.shininess = shininess.max(1.0);
+    }
+}
+
+impl ShaderResource for Lighting {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let mut data = LightingUniformLayout {
+            params: (self.lights.len() as f32, self.ambient, self.shininess, self.specular_strength).into(), 
+            ..Default::default()
+        };
+        for (layout, light) in data.lights.iter_mut().zip(self.lights.iter()) {
+            *layout = light.layout();
+        }
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 조명 하나의 레이아웃 입니다. </br>
+/// `position`의 w가 0이면 xyz는 조명을 향하는 방향이고, 1이면 조명의 위치 입니다. `color`의 w는 점 조명의 범위 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of a single light used in the shader. </br>
+/// If w of `position` is 0, xyz is the direction toward the light, and if it is 1, the position of the light. w of `color` is the range of a point light. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PhongLightLayout {
+    pub position: glam::Vec4, 
+    pub color: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 조명 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`는 (조명 수, 주변광 세기, 정반사 지수, 정반사 세기) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the lighting uniform data used in the shader. </br>
+/// `params` is (number of lights, ambient strength, shininess, specular strength). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightingUniformLayout {
+    pub lights: [PhongLightLayout; MAX_LIGHTS], 
+    pub params: glam::Vec4, 
+}
+
+impl Default for LightingUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            lights: [PhongLightLayout::default(); MAX_LIGHTS], 
+            params: (0.0, 0.2, 32.0, 0.5).into()
+        }
+    }
+}
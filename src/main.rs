@@ -1,31 +1,39 @@
-mod camera;
-mod light;
-mod mesh;
-mod object;
-mod pipeline;
-mod resource;
-mod timer;
-mod utils;
+#![allow(clippy::needless_return, clippy::collapsible_match)]
+
+use lab_project_01::{
+    allocation, 
+    bench, 
+    deferred, 
+    renderer, 
+    scene, 
+    settings, 
+    shader, 
+    shadow, 
+    target, 
+    terrain, 
+    timer, 
+    utils, 
+};
 
+use std::io;
+use std::env;
+use std::iter;
 use std::thread;
+use std::process;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
 use crossbeam_queue::SegQueue;
 use winit::{
-    keyboard::{KeyCode, PhysicalKey},
     event::{Event, WindowEvent}, 
-    window::{Window, WindowBuilder}, 
+    dpi::PhysicalSize, 
+    window::{Fullscreen, Window, WindowBuilder, WindowLevel}, 
     event_loop::{EventLoop, ControlFlow},
 };
 
-use camera::PerspectiveCameraBuilder;
-use light::GlobalLightBuilder;
-use mesh::{ModelMesh, CubeMesh, PlaneMesh};
-use object::StdObjectBuilder;
-use resource::ShaderResource;
-
-use crate::light::LightObject;
-use crate::object::GameObject;
+use allocation::CountingAllocator;
+use renderer::{Renderer, RendererOptions};
+use settings::Settings;
 
 
 /// #### 한국어 </br>
@@ -37,411 +45,337 @@ use crate::object::GameObject;
 static IS_RUNNING: AtomicBool = AtomicBool::new(true);
 
 /// #### 한국어 </br>
-/// 렌더링 루프로 보내는 창 이벤트 대기열 입니다. </br>
+/// 드라이버가 렌더링 장치를 잃었을 때 `true`값을 가집니다. 렌더링 루프는 장치와 렌더링 자원들을 다시 생성한 후 값을 되돌립니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// This is the window event queue that is sent to the rendering loop. </br>
+/// Has the value `true` when the driver lost the rendering device. The rendering loop recreates the device and the rendering resources and then resets the value. </br>
 /// 
-static EVENT_QUEUE: SegQueue<Event<()>> = SegQueue::new();
+static DEVICE_LOST: AtomicBool = AtomicBool::new(false);
 
+/// #### 한국어 </br>
+/// 통계 오버레이에 프레임당 할당 횟수를 보여주기 위해 힙 할당을 세는 전역 할당자 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The global allocator counting heap allocations to show the allocations per frame in the stats overlay. </br>
+/// 
+#[global_allocator]
+static GLOBAL_ALLOCATOR: CountingAllocator = CountingAllocator;
 
+/// #### 한국어 </br>
+/// 렌더링 스레드 대신 주 스레드의 창 메시지 루프 안에서 렌더링하는 명령줄 인수 입니다. </br>
+/// 보조 스레드에서 Metal 표면을 그리는 것이 불안정하므로 macOS에서는 항상 이 모드를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument to render inside the window message loop of the main thread instead of the rendering thread. </br>
+/// Drawing a Metal surface from a secondary thread is fragile, so this mode is always used on macOS. </br>
+/// 
+const MAIN_THREAD_ARG: &str = "--main-thread";
 
-fn render_loop(
-    window: Arc<Window>, 
-    instance: Arc<wgpu::Instance>, 
-    surface: Arc<wgpu::Surface>, 
-    _adapter: Arc<wgpu::Adapter>, 
-    device: Arc<wgpu::Device>, 
-    queue: Arc<wgpu::Queue>
-) {
-    // (한국어) 카메라 바인드 그룹 레이아웃을 생성합니다.
-    // (English Translation) Create a camera bind group layout.
-    let camera_bind_group_layout = device.create_bind_group_layout(
-        &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(Camera)"), 
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0, 
-                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
-                    ty: wgpu::BindingType::Buffer { 
-                        ty: wgpu::BufferBindingType::Uniform, 
-                        has_dynamic_offset: false, 
-                        min_binding_size: None 
-                    }, 
-                    count: None, 
-                }, 
-            ], 
-        }, 
-    );
-
-    // (한국어) 게임 카메라를 생성합니다. 
-    // (English Translation) Create a game camera.
-    let mut camera = PerspectiveCameraBuilder::new()
-        .set_width(window.inner_size().width as f32)
-        .set_height(window.inner_size().height as f32)
-        .set_translation((0.0, 3.5, 8.0).into())
-        .set_rotation(glam::Quat::from_rotation_x(-15.0f32.to_radians()))
-        .build(&camera_bind_group_layout, &device, &queue);
-
-    // (한국어) 오브젝트 바인드 그룹 레이아웃을 생성합니다.
-    // (English Translation) Create a object bind group layout. 
-    let object_bind_group_layout = device.create_bind_group_layout(
-        &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(Object)"), 
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0, 
-                    visibility: wgpu::ShaderStages::VERTEX, 
-                    ty: wgpu::BindingType::Buffer { 
-                        ty: wgpu::BufferBindingType::Uniform, 
-                        has_dynamic_offset: false, 
-                        min_binding_size: None 
-                    }, 
-                    count: None, 
-                }, 
-            ],
-        }, 
-    );
-
-    // (한국어) 평면 메쉬를 생성합니다.
-    // (English Translation) Create a plane mesh.
-    let plane_mesh = PlaneMesh::new(10.0, 10.0, &device, &queue);
-
-    // (한국어) 큐브 메쉬들을 생성합니다.
-    // (English Translation) Creates cube meshes. 
-    let cube_mesh_0 = CubeMesh::new(1.0, 1.0, 1.0, &device, &queue);
-
-    // (한국어) 오브젝트들을 생성합니다.
-    // (English Translation) Creates objects. 
-    let plane = StdObjectBuilder::new()
-        .set_color((0.5, 0.5, 0.5).into())
-        .set_translation((0.0, 0.0, 0.0).into())
-        .build(&object_bind_group_layout, &device, &queue);
-
-    let mut cubes = Vec::new();
-    let red_cube = StdObjectBuilder::new()
-        .set_color((1.0, 0.2, 0.2).into())
-        .set_translation((0.0, 0.5, 0.0).into())
-        .build(&object_bind_group_layout, &device, &queue);
-    cubes.push(red_cube);
-
-    let green_cube = StdObjectBuilder::new()
-        .set_color((0.2, 1.0, 0.2).into())
-        .set_translation((1.0, 1.25, 1.0).into())
-        .set_rotation(glam::Quat::from_axis_angle(
-            glam::Vec3::new(1.0, 1.0, 1.0).normalize(), 
-            60.0f32.to_radians()
-        ))
-        .build(&object_bind_group_layout, &device, &queue);
-    cubes.push(green_cube);
-
-    let blue_cube = StdObjectBuilder::new()
-        .set_color((0.2, 0.2, 1.0).into())
-        .set_translation((-1.0, 0.75, -0.8).into())
-        .set_rotation(glam::Quat::from_axis_angle(
-            glam::Vec3::new(-1.0, 1.0, 0.0).normalize(), 
-            38.0f32.to_radians()
-        ))
-        .build(&object_bind_group_layout, &device, &queue);
-    cubes.push(blue_cube);
-
-    // (한국어) 전역 조명 바인드 그룹을 생성합니다. 
-    // (English Translation) Create a global light bind group layout.
-    let global_light_bind_group_layout = device.create_bind_group_layout(
-        &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(GlobalLight)"), 
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0, 
-                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
-                    ty: wgpu::BindingType::Buffer { 
-                        ty: wgpu::BufferBindingType::Uniform, 
-                        has_dynamic_offset: false, 
-                        min_binding_size: None 
-                    }, 
-                    count: None, 
-                }, 
-            ], 
-        }, 
-    );
-
-    let shadow_map_bind_group_layout = device.create_bind_group_layout(
-        &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(ShadowMap)"), 
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0, 
-                    visibility: wgpu::ShaderStages::FRAGMENT, 
-                    ty: wgpu::BindingType::Texture { 
-                        sample_type: wgpu::TextureSampleType::Depth, 
-                        view_dimension: wgpu::TextureViewDimension::D2, 
-                        multisampled: false 
-                    }, 
-                    count: None, 
-                }, 
-                wgpu::BindGroupLayoutEntry {
-                    binding: 1, 
-                    visibility: wgpu::ShaderStages::FRAGMENT, 
-                    ty: wgpu::BindingType::Sampler(
-                        wgpu::SamplerBindingType::Comparison, 
-                    ), 
-                    count: None, 
-                }, 
-            ], 
-        }, 
-    );
-
-    // (한국어) 전역 조명을 생성합니다.
-    // (English Translation) Creates global light.
-    let global_light = GlobalLightBuilder::new()
-        .set_translation((0.0, 5.0, 0.0).into())
-        .set_rotation(glam::Quat::from_rotation_x(-90.0f32.to_radians()))
-        .set_light_color((1.0, 1.0, 1.0).into())
-        .build(&global_light_bind_group_layout, &shadow_map_bind_group_layout, &device, &queue);
-
-    // (한국어) 색상 그래픽스 파이프라인을 생성합니다.
-    // (English Translation) Create a color graphics pipeline.
-    let bind_group_layouts = &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout];
-    let color_pipeline = pipeline::create_colored_pipeline(&device, bind_group_layouts);
-
-    // (한국어) 그림자 맵 생성 파이프라인을 생성합니다.
-    // (English Translation) Create a shadow map generation pipeline.
-    let bind_group_layouts = &[&global_light_bind_group_layout, &object_bind_group_layout];
-    let shadow_pipeline = pipeline::create_shadow_pipeline(&device, bind_group_layouts);
-
-    // (한국어) 스왑체인 및 프레임 버퍼를 설정합니다.
-    // (English Translation) Sets the swapchain and frame buffer. 
-    let mut config = wgpu::SurfaceConfiguration {
-        usage: wgpu::TextureUsages::RENDER_ATTACHMENT, 
-        format: wgpu::TextureFormat::Bgra8Unorm, 
-        width: window.inner_size().width, 
-        height: window.inner_size().height, 
-        present_mode: wgpu::PresentMode::AutoVsync, 
-        desired_maximum_frame_latency: 2, 
-        alpha_mode: wgpu::CompositeAlphaMode::Auto, 
-        view_formats: vec![], 
-    };
-    surface.configure(&device, &config);
-    
-    // (한국어) 깊이-스텐실 텍스처 뷰를 생성합니다.
-    // (English Translation) Create the depth-stencil texture view.
-    let mut depth_stencil_view = device.create_texture(
-        &wgpu::TextureDescriptor {
-            label: Some("DepthStencilBuffer"), 
-            size: wgpu::Extent3d {
-                width: window.inner_size().width, 
-                height: window.inner_size().height, 
-                depth_or_array_layers: 1, 
-            },
-            format: wgpu::TextureFormat::Depth32Float, 
-            dimension: wgpu::TextureDimension::D2, 
-            mip_level_count: 1, 
-            sample_count: 1, 
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-            view_formats: &[],
-        },
-    )
-    .create_view(&wgpu::TextureViewDescriptor { 
-        ..Default::default()
-    });
+/// #### 한국어 </br>
+/// 투명한 창을 항상 위에 띄우고 하늘 없이 장면을 그려, 물체들이 바탕 화면 위에 떠 있게 하는 명령줄 인수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that keeps a transparent window always on top and draws the scene without the sky, so the objects float over the desktop. </br>
+/// 
+const OVERLAY_ARG: &str = "--overlay";
 
+/// #### 한국어 </br>
+/// 창의 처음 크기를 정하는 명령줄 인수 입니다. 뒤에 `너비x높이` 형식의 크기가 옵니다. (예: `--window-size 1280x720`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that sets the initial size of the window. It is followed by a size in the `WIDTHxHEIGHT` format. (e.g. `--window-size 1280x720`) </br>
+/// 
+const WINDOW_SIZE_ARG: &str = "--window-size";
 
-    // (한국어) 렌더링 루프를 실행합니다.
-    // (English Translation) Run the rendering loop.
-    log::info!("Run Rendering loop.");
-    let mut timer = timer::GameTimer::<50>::new();
-    while IS_RUNNING.load(MemOrdering::Acquire) {
-        // (한국어) 타이머를 갱신합니다.
-        // (English Translation) Updates the timer. 
-        timer.tick();
+/// #### 한국어 </br>
+/// 창을 현재 모니터의 테두리 없는 전체 화면으로 여는 명령줄 인수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that opens the window as a borderless fullscreen window on the current monitor. </br>
+/// 
+const FULLSCREEN_ARG: &str = "--fullscreen";
 
-        // (한국어) 창 이벤트를 처리합니다.
-        // (English Translation) Handles window events. 
-        while let Some(event) = EVENT_QUEUE.pop() {
-            match event {
-                Event::WindowEvent { event, .. } => match event {
-                    WindowEvent::Resized(size) => {
-                        if size.width > 0 && size.height > 0 {
-                            // (한국어) 모든 작업이 끝날 때 까지 기다립니다.
-                            // (English Translation) Wait until all operations are completed.
-                            instance.poll_all(true);
-
-                            // (한국어) 스왑체인 및 프레임 버퍼를 재설정합니다.
-                            // (English Translation) Reset swapchain and frame buffer. 
-                            config.width = size.width;
-                            config.height = size.height;
-                            surface.configure(&device, &config);
-
-                            // (한국어) 깊이-스텐실 텍스처 뷰를 재생성합니다.
-                            // (English Translation) Recreate the depth-stencil texture view. 
-                            depth_stencil_view = device.create_texture(
-                                &wgpu::TextureDescriptor {
-                                    label: Some("DepthStencilBuffer"), 
-                                    size: wgpu::Extent3d {
-                                        width: size.width, 
-                                        height: size.height, 
-                                        depth_or_array_layers: 1, 
-                                    },
-                                    format: wgpu::TextureFormat::Depth32Float, 
-                                    dimension: wgpu::TextureDimension::D2, 
-                                    mip_level_count: 1, 
-                                    sample_count: 1, 
-                                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-                                    view_formats: &[],
-                                },
-                            )
-                            .create_view(&wgpu::TextureViewDescriptor { 
-                                ..Default::default()
-                            });
-                        }
-                    },
-                    WindowEvent::KeyboardInput { event, .. } => {
-                        if let PhysicalKey::Code(code) = event.physical_key {
-                            if KeyCode::ArrowLeft == code && event.state.is_pressed() {
-                                let rot = glam::Mat4::from_quat(glam::Quat::from_rotation_y(-180.0f32.to_radians() * timer.elapsed_time_sec()));
-                                *camera.world_transform_mut() = rot.mul_mat4(camera.world_transform_ref());
-                                camera.update_resource(&queue);
-                            }
-
-                            if KeyCode::ArrowRight == code && event.state.is_pressed() {
-                                let rot = glam::Mat4::from_quat(glam::Quat::from_rotation_y(180.0f32.to_radians() * timer.elapsed_time_sec()));
-                                *camera.world_transform_mut() = rot.mul_mat4(camera.world_transform_ref());
-                                camera.update_resource(&queue);
-                            }
-                        }
-                    }
-                    _ => { /*--- empty ---*/ }
-                },
-                _ => { /*--- empty ---*/ }
-            }
-        }
+/// #### 한국어 </br>
+/// 렌더링 루프로 보내는 창 이벤트 대기열 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the window event queue that is sent to the rendering loop. </br>
+/// 
+static EVENT_QUEUE: SegQueue<Event<()>> = SegQueue::new();
 
-        
-        // (한국어) 오브젝트들을 그립니다.
-        // (English Translation) Draws the objects.
-        window.pre_present_notify();
-        
-        // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-        // (English Translation) Wait until the previous operation is finished.
-        device.poll(wgpu::Maintain::Wait);
-
-        // (한국어) 다음 프레임을 가져옵니다.
-        // (English Translation) Get the next frame.
-        let frame = surface.get_current_texture().unwrap();
-
-        // (한국어) 렌더 타겟의 텍스처 뷰를 생성합니다.
-        // (English Translation) Creates a texture view of render target.
-        let render_target_view = frame.texture.create_view(&wgpu::TextureViewDescriptor { 
-            ..Default::default()
-        });
 
-        // (한국어) 커맨드 버퍼를 생성합니다.
-        // (English Translation) Creates a command buffer. 
-        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-        {
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(Shadow)"), 
-                    color_attachments: &[],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: &global_light.texture_view_ref(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }), 
-                    timestamp_writes: None, 
-                    occlusion_query_set: None, 
-                },
-            );
 
-            rpass.set_pipeline(&shadow_pipeline);
-            rpass.set_bind_group(0, &global_light.uniform_bind_group, &[]);
+/// #### 한국어 </br>
+/// `--help`일 때 출력하고, 명령줄 인수를 알 수 없을 때 표준 오류에 출력하는 사용법 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The usage printed for `--help`, and to the standard error when a command line argument is unknown. </br>
+/// 
+const USAGE: &str = "\
+Usage: lab_project_01 [OPTIONS]
+
+Options:
+  --main-thread                 Render inside the window message loop of the main thread
+  --overlay                     Draw the scene in a transparent window kept on top
+  --fullscreen                  Open the window as a borderless fullscreen window
+  --window-size <WIDTHxHEIGHT>  Initial size of the window
+  --present-mode <MODE>         Present mode of the swapchain
+  --backend <BACKEND>           Graphics backend (takes precedence over WGPU_BACKEND)
+  --adapter <INDEX|prompt>      Adapter picked from the list of adapters
+  --renderer <RENDERER>         Renderer drawing the opaque objects
+  --depth-format <FORMAT>       Format of the depth buffer
+  --msaa <COUNT>                Sample count of the multisampling
+  --shadow-filter <KERNEL>      Filtering kernel of the shadows
+  --tick-rate <HZ>              Number of fixed updates per second (1 to 1000)
+  --scene <PATH>                Scene file to load
+  --heightmap <PATH>            Heightmap image drawn instead of the floor plane
+  --hot-reload                  Watch the shader directory and reload the changed shaders
+  --bench <FRAMES>              Render the given number of frames and print a report
+  -h, --help                    Print this usage";
+
+/// #### 한국어 </br>
+/// `WINDOW_SIZE_ARG` 명령줄 인수의 값을 창의 크기로 해석합니다. `너비x높이` 형식이 아니면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `WINDOW_SIZE_ARG` command line argument as the size of the window. Returns an `InvalidInput` error if it is not in the `WIDTHxHEIGHT` format. </br>
+/// 
+fn parse_window_size(value: &str) -> io::Result<PhysicalSize<u32>> {
+    let size = value.split_once('x').and_then(|(width, height)| {
+        Some(PhysicalSize::new(width.parse::<u32>().ok()?, height.parse::<u32>().ok()?))
+    });
+    size.filter(|size| size.width > 0 && size.height > 0).ok_or_else(|| io::Error::new(
+        io::ErrorKind::InvalidInput, 
+        format!("{} must be followed by a size in the WIDTHxHEIGHT format", WINDOW_SIZE_ARG)
+    ))
+}
 
-            plane_mesh.bind(&mut rpass);
-            rpass.set_bind_group(1, &plane.uniform_bind_group, &[]);
-            plane_mesh.draw(&mut rpass);
+/// #### 한국어 </br>
+/// 명령줄 인수들을 한 번 해석한 옵션들 입니다. 주어지지 않은 값은 `None`이며, 설정 파일이나 기본값을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The options parsed once from the command line arguments. A value that is not given is `None`, and the settings file or the default value is used. </br>
+/// 
+#[derive(Debug, Clone, Default)]
+struct Options {
+    help: bool, 
+    main_thread: bool, 
+    fullscreen: bool, 
+    window_size: Option<PhysicalSize<u32>>, 
+    backends: Option<wgpu::Backends>, 
+    adapter_selection: Option<utils::AdapterSelection>, 
+    renderer: RendererOptions, 
+}
 
-            cube_mesh_0.bind(&mut rpass);
-            for object in cubes.iter() {
-                rpass.set_bind_group(1, &object.uniform_bind_group, &[]);
-                cube_mesh_0.draw(&mut rpass);
+impl Options {
+    /// #### 한국어 </br>
+    /// 프로그램 이름을 뺀 명령줄 인수들을 해석합니다. </br>
+    /// 알 수 없는 인수이거나 값이 빠졌거나 잘못되면 `InvalidInput` 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Parses the command line arguments without the program name. </br>
+    /// Returns an `InvalidInput` error if an argument is unknown, or its value is missing or wrong. </br>
+    /// 
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> io::Result<Self> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            // (한국어) 값을 받는 인수는 바로 뒤의 인수를 값으로 사용합니다.
+            // (English Translation) An argument taking a value uses the argument right after it as the value.
+            let mut value = || args.next().ok_or_else(|| io::Error::new(
+                io::ErrorKind::InvalidInput, 
+                format!("{} must be followed by a value", arg)
+            ));
+            match arg.as_str() {
+                "-h" | "--help" => options.help = true, 
+                MAIN_THREAD_ARG => options.main_thread = true, 
+                OVERLAY_ARG => options.renderer.overlay = true, 
+                FULLSCREEN_ARG => options.fullscreen = true, 
+                shader::HOT_RELOAD_ARG => options.renderer.hot_reload = true, 
+                WINDOW_SIZE_ARG => options.window_size = Some(parse_window_size(&value()?)?), 
+                utils::PRESENT_MODE_ARG => options.renderer.present_mode = Some(utils::parse_present_mode(&value()?)?), 
+                utils::BACKEND_ARG => options.backends = Some(utils::parse_backend(&value()?)?), 
+                utils::ADAPTER_ARG => options.adapter_selection = Some(utils::parse_adapter_selection(&value()?)?), 
+                deferred::RENDERER_ARG => options.renderer.renderer_kind = Some(deferred::parse_renderer(&value()?)?), 
+                target::DEPTH_FORMAT_ARG => options.renderer.depth_format = Some(target::parse_depth_format(&value()?)?), 
+                target::SAMPLE_COUNT_ARG => options.renderer.sample_count = Some(target::parse_sample_count(&value()?)?), 
+                shadow::SHADOW_FILTER_ARG => options.renderer.pcf_kernel = Some(shadow::parse_pcf_kernel(&value()?)?), 
+                timer::TICK_RATE_ARG => options.renderer.tick_rate = Some(timer::parse_tick_rate(&value()?)?), 
+                scene::SCENE_ARG => options.renderer.scene_path = Some(PathBuf::from(value()?)), 
+                terrain::HEIGHTMAP_ARG => options.renderer.heightmap_path = Some(PathBuf::from(value()?)), 
+                bench::BENCH_ARG => options.renderer.bench_frames = Some(bench::parse_num_frames(&value()?)?), 
+                _ => return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput, 
+                    format!("unknown argument {}", arg)
+                )), 
             }
         }
+        return Ok(options);
+    }
+}
 
-        {
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(Draw)"), 
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &render_target_view, 
-                            resolve_target: None, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::WHITE), 
-                                store: wgpu::StoreOp::Store, 
-                            },
-                        }), 
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: &depth_stencil_view, 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }), 
-                    timestamp_writes: None, 
-                    occlusion_query_set: None, 
-                },
-            );
 
-            rpass.set_pipeline(&color_pipeline);
-            rpass.set_bind_group(0, &camera.uniform_bind_group, &[]);
-            rpass.set_bind_group(2, &global_light.uniform_bind_group, &[]);
-            rpass.set_bind_group(3, &global_light.texture_bind_group, &[]);
 
-            plane_mesh.bind(&mut rpass);
-            rpass.set_bind_group(1, &plane.uniform_bind_group, &[]);
-            plane_mesh.draw(&mut rpass);
+/// #### 한국어 </br>
+/// 렌더링 스레드에서 렌더링 루프를 실행합니다. 애플리케이션이 종료될 때 까지 반환하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Runs the rendering loop on the rendering thread. Does not return until the application quits. </br>
+/// 
+#[allow(clippy::too_many_arguments)]
+fn render_loop(
+    window: Arc<Window>, 
+    instance: Arc<wgpu::Instance>, 
+    surface: Arc<wgpu::Surface<'static>>, 
+    adapter: Arc<wgpu::Adapter>, 
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    settings: Settings, 
+    options: Options
+) {
+    let (mut device, mut queue) = (device, queue);
 
-            cube_mesh_0.bind(&mut rpass);
-            for object in cubes.iter() {
-                rpass.set_bind_group(1, &object.uniform_bind_group, &[]);
-                cube_mesh_0.draw(&mut rpass);
+    // (한국어) 렌더링 루프를 실행합니다. 장치를 잃으면 이전 자원들을 해제한 후 장치와 자원들을 다시 생성합니다.
+    // (English Translation) Run the rendering loop. When the device is lost, releases the previous resources and then recreates the device and the resources.
+    log::info!("Run Rendering loop.");
+    while IS_RUNNING.load(MemOrdering::Acquire) {
+        let mut renderer = create_renderer(window.clone(), instance.clone(), surface.clone(), adapter.clone(), device.clone(), queue.clone(), &settings, &options);
+        while IS_RUNNING.load(MemOrdering::Acquire) && !DEVICE_LOST.load(MemOrdering::Acquire) {
+            // (한국어) 대기열의 창 이벤트들로 한 프레임을 갱신하고 그립니다. 벤치마크나 재생이 끝나면 렌더링 루프를 멈춥니다.
+            // (English Translation) Updates one frame with the window events of the queue and draws it. Stops the rendering loop when the benchmark or the playback has ended.
+            renderer.update(iter::from_fn(|| EVENT_QUEUE.pop()));
+            match renderer.is_running() {
+                true => renderer.render(), 
+                false => IS_RUNNING.store(false, MemOrdering::Release), 
+            }
+        }
+        drop(renderer);
+
+        if DEVICE_LOST.swap(false, MemOrdering::AcqRel) {
+            log::warn!("Recreating the device and the rendering resources.");
+            match utils::create_render_device_and_queue(&adapter) {
+                Ok(recreated) => (device, queue) = recreated, 
+                Err(e) => {
+                    log::error!("Failed to recreate the rendering device: {}", e);
+                    IS_RUNNING.store(false, MemOrdering::Release);
+                    window.request_redraw();
+                }, 
             }
         }
-
-        // (한국어) 명령 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-        // (English Translation) Submit command buffer to the queue and output to the framebuffer. 
-        queue.submit(Some(encoder.finish()));
-        frame.present();
     }
 
     log::info!("Finish Rendering loop.");
 }
 
+/// #### 한국어 </br>
+/// 장치를 잃었을 때 렌더링 루프에 알리도록 한 후, 장면과 렌더링 자원들을 가진 렌더러를 생성합니다. </br>
+/// 렌더링 스레드의 루프와 주 스레드의 `RedrawRequested` 이벤트 양쪽에서 사용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Makes the device notify the rendering loop when it is lost, and then creates the renderer owning the scene and the rendering resources. </br>
+/// Used by both the loop of the rendering thread and the `RedrawRequested` event of the main thread. </br>
+/// 
+#[allow(clippy::too_many_arguments)]
+fn create_renderer(
+    window: Arc<Window>, 
+    instance: Arc<wgpu::Instance>, 
+    surface: Arc<wgpu::Surface<'static>>, 
+    adapter: Arc<wgpu::Adapter>, 
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    settings: &Settings, 
+    options: &Options
+) -> Renderer {
+    // (한국어) 드라이버가 장치를 잃으면 렌더링 루프가 자원들을 다시 생성하도록 알립니다. 장치를 해제할 때의 호출은 무시합니다.
+    // (English Translation) Notifies the rendering loop to recreate the resources when the driver loses the device. The call made when the device is released is ignored.
+    device.set_device_lost_callback(|reason, message| {
+        if matches!(reason, wgpu::DeviceLostReason::Unknown) {
+            log::error!("The device was lost. ({})", message);
+            DEVICE_LOST.store(true, MemOrdering::Release);
+        }
+    });
+
+    return Renderer::new(window, instance, surface, adapter, device, queue, settings, &options.renderer);
+}
+
 fn main() {
     env_logger::init();
+
+    // (한국어) 명령줄 인수들을 한 번 해석합니다. 알 수 없거나 잘못된 인수가 있으면 사용법을 출력하고 종료합니다.
+    // (English Translation) Parses the command line arguments once. If an argument is unknown or wrong, prints the usage and quits.
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options, 
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }, 
+    };
+    if options.help {
+        println!("{}", USAGE);
+        return;
+    }
     log::info!("❖ Application Launching ❖");
     
+    // (한국어) 설정 파일을 읽습니다. 파일이 없으면 기본 설정으로 만들고, 읽지 못하면 기본 설정을 사용합니다.
+    // (English Translation) Reads the settings file. If the file does not exist it is created with the default settings, and if it cannot be read the default settings are used.
+    let settings = match settings::load_or_create(settings::SETTINGS_PATH) {
+        Ok(settings) => settings, 
+        Err(e) => {
+            log::error!("Failed to load the settings {}: {}", settings::SETTINGS_PATH, e);
+            Settings::default()
+        }, 
+    };
+
     // (한국어) 창 시스템을 초기화 합니다.
     // (English Translation) Initializes the window system.
     let event_loop = EventLoop::new().unwrap();
-    let window = Arc::new(
-        WindowBuilder::new()
-            .with_visible(true)
-            .with_resizable(true)
-            .with_title("Lab Project 00")
-            .build(&event_loop)
-            .unwrap()
-    );
+    let overlay = options.renderer.overlay;
+    let fullscreen = settings.fullscreen || options.fullscreen;
+    let mut window_builder = WindowBuilder::new()
+        .with_visible(true)
+        .with_resizable(true)
+        .with_inner_size(PhysicalSize::new(settings.window_width, settings.window_height))
+        .with_title("Lab Project 00")
+        .with_transparent(overlay)
+        .with_fullscreen(fullscreen.then_some(Fullscreen::Borderless(None)))
+        .with_window_level(match overlay {
+            true => WindowLevel::AlwaysOnTop, 
+            false => WindowLevel::Normal, 
+        });
+    if let Some(size) = options.window_size {
+        window_builder = window_builder.with_inner_size(size);
+    }
+    let window = Arc::new(window_builder.build(&event_loop).unwrap());
+
+    // (한국어) `--backend` 인수나 `WGPU_BACKEND` 환경 변수가 주어지면 플랫폼의 기본 백엔드 대신 그 백엔드를 사용합니다. 명령줄 인수가 우선합니다.
+    // (English Translation) If the `--backend` argument or the `WGPU_BACKEND` environment variable is given, that backend is used instead of the default one of the platform. The command line argument takes precedence.
+    let backends = options.backends.or_else(wgpu::util::backend_bits_from_env);
+
+    // (한국어) `--adapter` 인수가 주어지면 기본 어뎁터 대신 목록에서 고른 어뎁터를 사용합니다.
+    // (English Translation) If the `--adapter` argument is given, the adapter picked from the list is used instead of the default one.
+    let adapter_selection = options.adapter_selection;
 
     // (한국어) 렌더링 시스템을 초기화 합니다.
     // (English Translation) Initialize the rendering system.
     let window_cloned = window.clone();
-    let (instance, surface, adapter, device, queue) = utils::setup_rendering_system(window_cloned);
+    let (instance, surface, adapter, device, queue) = match utils::setup_rendering_system(window_cloned, backends, adapter_selection) {
+        Ok(system) => system, 
+        Err(e) => {
+            // (한국어) 로그가 꺼져 있어도 실패 이유가 보이도록 표준 오류에 출력하고, 0이 아닌 종료 코드로 끝냅니다.
+            // (English Translation) Prints the reason of the failure to the standard error so it is visible even when the logging is off, and exits with a non-zero exit code.
+            log::error!("Failed to initialize the rendering system: {}", e);
+            eprintln!("Failed to initialize the rendering system: {}", e);
+            log::info!("❖ Application Terminate ❖");
+            process::exit(1);
+        }, 
+    };
+
+    // (한국어) 주 스레드 렌더링 모드이면 창 메시지 루프 안에서 렌더링합니다.
+    // (English Translation) In the main-thread rendering mode, renders inside the window message loop.
+    if cfg!(target_os = "macos") || options.main_thread {
+        run_on_main_thread(event_loop, window, instance.clone(), surface, adapter, device, queue, settings, options);
+        instance.poll_all(true);
+        log::info!("❖ Application Terminate ❖");
+        return;
+    }
 
     // (한국어) 새로운 스레드에서 렌더링 루프를 실행합니다.
     // (English Translation) Runs the rendering loop in a new thread.
@@ -453,7 +387,9 @@ fn main() {
         surface, 
         adapter, 
         device, 
-        queue
+        queue, 
+        settings, 
+        options
     )));
 
     // (한국어) 윈도우 메시지 루프를 실행합니다.
@@ -476,13 +412,12 @@ fn main() {
 
         // (한국어) 윈도우 이벤트를 처리합니다.
         // (English Translation) Handles window events. 
-        let event_cloned = event.clone();
-        match event_cloned {
+        match &event {
             Event::NewEvents(_) | Event::AboutToWait => {
                 return;
             },
             Event::WindowEvent { window_id, event } 
-            if window_id == window.id() => match event {
+            if *window_id == window.id() => match event {
                 WindowEvent::CloseRequested | WindowEvent::Destroyed => {
                     IS_RUNNING.store(false, MemOrdering::Release);
                     elwt.exit();
@@ -501,3 +436,87 @@ fn main() {
     instance.poll_all(true);
     log::info!("❖ Application Terminate ❖");
 }
+
+/// #### 한국어 </br>
+/// 주 스레드에서 창 메시지 루프를 실행하며, `AboutToWait`마다 다시 그리기를 요청하고 `RedrawRequested`에서 한 프레임을 그립니다. </br>
+/// 창 이벤트는 렌더링 스레드 모드와 같이 이벤트 대기열을 통해 다음 프레임에 처리됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Runs the window message loop on the main thread, requesting a redraw on every `AboutToWait` and drawing one frame on `RedrawRequested`. </br>
+/// Window events are handled in the next frame through the event queue, same as the rendering thread mode. </br>
+/// 
+#[allow(clippy::too_many_arguments)]
+fn run_on_main_thread(
+    event_loop: EventLoop<()>, 
+    window: Arc<Window>, 
+    instance: Arc<wgpu::Instance>, 
+    surface: Arc<wgpu::Surface<'static>>, 
+    adapter: Arc<wgpu::Adapter>, 
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    settings: Settings, 
+    options: Options
+) {
+    let mut renderer = Some(create_renderer(window.clone(), instance.clone(), surface.clone(), adapter.clone(), device, queue, &settings, &options));
+
+    log::info!("Run Window message loop on the main thread.");
+    event_loop.set_control_flow(ControlFlow::Wait);
+    event_loop.run(move |event, elwt| {
+        match &event {
+            Event::NewEvents(_) => {
+                return;
+            }, 
+            Event::AboutToWait => {
+                window.request_redraw();
+                return;
+            }, 
+            Event::WindowEvent { window_id, event } 
+            if *window_id == window.id() => match event {
+                WindowEvent::CloseRequested | WindowEvent::Destroyed => {
+                    IS_RUNNING.store(false, MemOrdering::Release);
+                    elwt.exit();
+                    return;
+                }, 
+                WindowEvent::RedrawRequested => {
+                    // (한국어) 한 프레임을 그립니다. 벤치마크나 재생이 끝나 렌더링이 멈추면 애플리케이션을 종료합니다.
+                    // (English Translation) Draws one frame. Quits the application when rendering stops because the benchmark or the playback has ended.
+                    if IS_RUNNING.load(MemOrdering::Acquire) {
+                        let renderer = renderer.as_mut().unwrap();
+                        renderer.update(iter::from_fn(|| EVENT_QUEUE.pop()));
+                        match renderer.is_running() {
+                            true => renderer.render(), 
+                            false => IS_RUNNING.store(false, MemOrdering::Release), 
+                        }
+                    }
+
+                    // (한국어) 장치를 잃으면 이전 자원들을 해제한 후 장치와 자원들을 다시 생성합니다.
+                    // (English Translation) When the device is lost, releases the previous resources and then recreates the device and the resources.
+                    if IS_RUNNING.load(MemOrdering::Acquire) && DEVICE_LOST.swap(false, MemOrdering::AcqRel) {
+                        log::warn!("Recreating the device and the rendering resources.");
+                        renderer = None;
+                        match utils::create_render_device_and_queue(&adapter) {
+                            Ok((device, queue)) => {
+                                renderer = Some(create_renderer(window.clone(), instance.clone(), surface.clone(), adapter.clone(), device, queue, &settings, &options));
+                            }, 
+                            Err(e) => {
+                                log::error!("Failed to recreate the rendering device: {}", e);
+                                IS_RUNNING.store(false, MemOrdering::Release);
+                            }, 
+                        }
+                    }
+                    if !IS_RUNNING.load(MemOrdering::Acquire) {
+                        log::info!("Finish Rendering loop.");
+                        elwt.exit();
+                    }
+                    return;
+                }, 
+                _ => { /* empty */ }
+            }, 
+            _ => { /* empty */ }
+        }
+
+        // (한국어) 창 이벤트를 이벤트 대기열에 추가합니다.
+        // (English Translation) Add a window event to the event queue. 
+        EVENT_QUEUE.push(event);
+    }).unwrap();
+}
@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 뷰 공간 법선으로 샘플링하여 조명 없이 물체를 음영 처리하는 매트캡(matcap) 텍스처 입니다. </br>
+/// 매트캡은 구를 정면에서 본 이미지이므로, 법선의 x, y 성분이 곧 텍스처 좌표가 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A matcap texture that shades objects without lighting by sampling it with the view-space normal. </br>
+/// A matcap is an image of a sphere seen from the front, so the x and y components of the normal are the texture coordinates. </br>
+/// 
+#[derive(Debug)]
+pub struct Matcap {
+    pub bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl Matcap {
+    /// #### 한국어 </br>
+    /// sRGB 색 공간의 RGBA8 픽셀들로 매트캡을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a matcap from RGBA8 pixels in the sRGB color space. </br>
+    /// 
+    pub fn from_rgba8(
+        width: u32, 
+        height: u32, 
+        pixels: &[u8], 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(width > 0 && height > 0);
+        assert_eq!(pixels.len(), (4 * width * height) as usize);
+
+        let texture = stats::track_texture(device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(Matcap)"), 
+                size: wgpu::Extent3d {
+                    width, 
+                    height, 
+                    depth_or_array_layers: 1, 
+                }, 
+                dimension: wgpu::TextureDimension::D2, 
+                format: wgpu::TextureFormat::Rgba8UnormSrgb, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        ));
+        queue.write_texture(
+            texture.as_image_copy(), 
+            pixels, 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(4 * width), 
+                rows_per_image: Some(height), 
+            }, 
+            texture.size(), 
+        );
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        });
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Matcap)"), 
+                address_mode_u: wgpu::AddressMode::ClampToEdge, 
+                address_mode_v: wgpu::AddressMode::ClampToEdge, 
+                address_mode_w: wgpu::AddressMode::ClampToEdge, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                mipmap_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Matcap)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::TextureView(&texture_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            bind_group
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이미지 파일에서 매트캡을 불러옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads a matcap from an image file. </br>
+    /// 
+    pub fn from_file<P: AsRef<Path>>(
+        path: P, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> image::ImageResult<Self> {
+        let image = image::open(path)?.to_rgba8();
+        Ok(Self::from_rgba8(image.width(), image.height(), &image, bind_group_layout, device, queue))
+    }
+
+    /// #### 한국어 </br>
+    /// 왼쪽 위에서 빛을 받는 점토 재질의 구를 그려 매트캡을 생성합니다. </br>
+    /// 불러올 매트캡 이미지가 없을 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a matcap by painting a clay sphere lit from the upper left. </br>
+    /// Used when there is no matcap image to load. </br>
+    /// 
+    pub fn procedural(
+        size: u32, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(size > 0);
+
+        let light = glam::vec3(-0.45, 0.55, 0.7).normalize();
+        let base = glam::vec3(0.78, 0.74, 0.7);
+        let shadow = glam::vec3(0.2, 0.19, 0.22);
+        let mut pixels = Vec::with_capacity((4 * size * size) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                // (한국어) 텍스처 좌표를 단위 원 안의 법선으로 바꿉니다. 원 밖은 가장자리의 법선을 사용합니다.
+                // (English Translation) Converts the texture coordinates to a normal inside the unit circle. Outside the circle, the normal of the edge is used.
+                let uv = glam::vec2(
+                    2.0 * (x as f32 + 0.5) / size as f32 - 1.0, 
+                    1.0 - 2.0 * (y as f32 + 0.5) / size as f32
+                );
+                let uv = if uv.length_squared() > 1.0 { uv.normalize() } else { uv };
+                let normal = glam::vec3(uv.x, uv.y, (1.0 - uv.length_squared()).max(0.0).sqrt());
+
+                let diffuse = 0.5 + 0.5 * normal.dot(light);
+                let half = (light + glam::Vec3::Z).normalize();
+                let specular = normal.dot(half).max(0.0).powf(48.0);
+                let rim = (1.0 - normal.z).powf(3.0);
+                let color = shadow.lerp(base, diffuse) + glam::Vec3::splat(0.35 * specular + 0.15 * rim);
+
+                let srgb = color.clamp(glam::Vec3::ZERO, glam::Vec3::ONE).powf(1.0 / 2.2) * 255.0;
+                pixels.extend_from_slice(&[srgb.x as u8, srgb.y as u8, srgb.z as u8, 255]);
+            }
+        }
+
+        Self::from_rgba8(size, size, &pixels, bind_group_layout, device, queue)
+    }
+}
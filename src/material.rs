@@ -0,0 +1,316 @@
+use std::mem;
+use std::path::Path;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 재질 텍스처로 업로드할 RGBA8 픽셀 이미지 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An RGBA8 pixel image to upload as a material texture. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextureImage {
+    pub width: u32, 
+    pub height: u32, 
+    pub pixels: Vec<u8>, 
+}
+
+#[allow(dead_code)]
+impl TextureImage {
+    #[inline]
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert!(width > 0 && height > 0);
+        assert_eq!(pixels.len(), (4 * width * height) as usize);
+        Self { width, height, pixels }
+    }
+
+    /// #### 한국어 </br>
+    /// 이미지 파일을 RGBA8 픽셀로 불러옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads an image file as RGBA8 pixels. </br>
+    /// 
+    pub fn from_file<P: AsRef<Path>>(path: P) -> image::ImageResult<Self> {
+        let image = image::open(path)?.to_rgba8();
+        Ok(Self::new(image.width(), image.height(), image.into_raw()))
+    }
+
+    /// #### 한국어 </br>
+    /// 두 색이 번갈아 놓인 바둑판 무늬 이미지를 생성합니다. 불러올 텍스처가 없을 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a checkerboard image of two alternating colors. Used when there is no texture to load. </br>
+    /// 
+    pub fn checkerboard(size: u32, num_cells: u32, color_a: [u8; 4], color_b: [u8; 4]) -> Self {
+        assert!(size > 0 && num_cells > 0);
+
+        let mut pixels = Vec::with_capacity((4 * size * size) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                let cell = (x * num_cells / size) + (y * num_cells / size);
+                pixels.extend_from_slice(if cell % 2 == 0 { &color_a } else { &color_b });
+            }
+        }
+        Self::new(size, size, pixels)
+    }
+}
+
+/// #### 한국어 </br>
+/// 텍스처로 물체를 그리는 재질을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a material that draws objects with textures. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialBuilder {
+    pub color_factor: glam::Vec4, 
+    pub albedo: Option<TextureImage>, 
+    pub normal_map: Option<TextureImage>, 
+}
+
+impl Default for MaterialBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color_factor: glam::Vec4::ONE, 
+            albedo: None, 
+            normal_map: None
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MaterialBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 알베도 텍스처에 곱해지는 색상을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the color multiplied by the albedo texture. </br>
+    /// 
+    #[inline]
+    pub fn set_color_factor(mut self, color_factor: glam::Vec4) -> Self {
+        self.color_factor = color_factor;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// sRGB 색 공간의 알베도 텍스처를 설정합니다. 설정하지 않으면 흰색 텍스처를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the albedo texture in the sRGB color space. If it is not set, a white texture is used. </br>
+    /// 
+    #[inline]
+    pub fn set_albedo(mut self, albedo: TextureImage) -> Self {
+        self.albedo = Some(albedo);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 접선 공간의 법선이 저장된 법선 맵을 설정합니다. 설정하지 않으면 정점 법선을 그대로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the normal map storing the tangent space normals. If it is not set, the vertex normals are used as is. </br>
+    /// 
+    #[inline]
+    pub fn set_normal_map(mut self, normal_map: TextureImage) -> Self {
+        self.normal_map = Some(normal_map);
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Material {
+        let has_normal_map = self.normal_map.is_some();
+        let albedo = self.albedo.unwrap_or_else(|| TextureImage::new(1, 1, vec![255, 255, 255, 255]));
+        let normal_map = self.normal_map.unwrap_or_else(|| TextureImage::new(1, 1, vec![128, 128, 255, 255]));
+
+        let albedo_view = create_texture_view("Texture(MaterialAlbedo)", &albedo, wgpu::TextureFormat::Rgba8UnormSrgb, device, queue);
+        let normal_map_view = create_texture_view("Texture(MaterialNormal)", &normal_map, wgpu::TextureFormat::Rgba8Unorm, device, queue);
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Material)"), 
+                address_mode_u: wgpu::AddressMode::Repeat, 
+                address_mode_v: wgpu::AddressMode::Repeat, 
+                address_mode_w: wgpu::AddressMode::Repeat, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Material)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<MaterialUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Material)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::TextureView(&albedo_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::TextureView(&normal_map_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 3, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let material = Material {
+            color_factor: self.color_factor, 
+            has_normal_map, 
+            uniform_buffer, 
+            bind_group, 
+        };
+        material.update_resource(queue);
+
+        return material;
+    }
+}
+
+/// #### 한국어 </br>
+/// 알베도 텍스처와 선택적인 법선 맵, 색상 계수로 이루어진 재질 입니다. </br>
+/// 텍스처 좌표를 가진 정점과 텍스처 파이프라인으로 그려야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A material made of an albedo texture, an optional normal map and a color factor. </br>
+/// It must be drawn with the textured pipeline and vertices carrying texture coordinates. </br>
+/// 
+#[derive(Debug)]
+pub struct Material {
+    color_factor: glam::Vec4, 
+    has_normal_map: bool, 
+    uniform_buffer: wgpu::Buffer, 
+    pub bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl Material {
+    #[inline]
+    pub fn color_factor(&self) -> glam::Vec4 {
+        self.color_factor
+    }
+
+    /// #### 한국어 </br>
+    /// 색상 계수를 설정합니다. `update_resource`를 호출해야 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the color factor. It takes effect after calling `update_resource`. </br>
+    /// 
+    #[inline]
+    pub fn set_color_factor(&mut self, color_factor: glam::Vec4) {
+        self.color_factor = color_factor;
+    }
+
+    #[inline]
+    pub fn has_normal_map(&self) -> bool {
+        self.has_normal_map
+    }
+}
+
+impl ShaderResource for Material {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = MaterialUniformLayout {
+            color_factor: self.color_factor, 
+            params: (if self.has_normal_map { 1.0 } else { 0.0 }, 0.0, 0.0, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 재질 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`의 x는 법선 맵의 사용 여부 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the material uniform data used in the shader. </br>
+/// The x of `params` is whether the normal map is used. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaterialUniformLayout {
+    pub color_factor: glam::Vec4, 
+    pub params: glam::Vec4, 
+}
+
+impl Default for MaterialUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color_factor: glam::Vec4::ONE, 
+            params: glam::Vec4::ZERO
+        }
+    }
+}
+
+fn create_texture_view(
+    label: &str, 
+    image: &TextureImage, 
+    format: wgpu::TextureFormat, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue
+) -> wgpu::TextureView {
+    let texture = stats::track_texture(device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some(label), 
+            size: wgpu::Extent3d {
+                width: image.width, 
+                height: image.height, 
+                depth_or_array_layers: 1, 
+            }, 
+            dimension: wgpu::TextureDimension::D2, 
+            format, 
+            mip_level_count: 1, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+            view_formats: &[], 
+        }, 
+    ));
+    queue.write_texture(
+        texture.as_image_copy(), 
+        &image.pixels, 
+        wgpu::ImageDataLayout {
+            offset: 0, 
+            bytes_per_row: Some(4 * image.width), 
+            rows_per_image: Some(image.height), 
+        }, 
+        texture.size(), 
+    );
+    texture.create_view(&wgpu::TextureViewDescriptor {
+        ..Default::default()
+    })
+}
@@ -1,7 +1,11 @@
 use std::fmt;
 use std::mem;
+use std::ops::Range;
 
+use crate::bounds::{Aabb, Sphere};
+use crate::material::Material;
 use crate::object::ObjectVertexLayout;
+use crate::stats;
 
 
 /// #### 한국어 </br>
@@ -12,7 +16,211 @@ use crate::object::ObjectVertexLayout;
 ///  
 pub trait ModelMesh : fmt::Debug {
     fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>);
-    fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>);
+
+    #[inline]
+    fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        self.draw_instanced(rpass, 0..1)
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 서브 메쉬를 같은 재질로 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws all the sub-meshes with the same material. </br>
+    /// 
+    #[inline]
+    fn draw_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, instances: Range<u32>) {
+        for submesh in self.submeshes() {
+            self.draw_submesh_instanced(rpass, submesh, instances.clone());
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 각 서브 메쉬의 재질을 `group`번 바인드 그룹에 바인딩한 후 그립니다. </br>
+    /// 
+    /// #### Panics </br>
+    /// 서브 메쉬의 재질 번호가 `materials`의 범위를 벗어나면 패닉합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws each sub-mesh after binding its material to bind group `group`. </br>
+    /// 
+    /// #### Panics </br>
+    /// Panics if the material index of a sub-mesh is out of the range of `materials`. </br>
+    /// 
+    fn draw_with_materials<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, group: u32, materials: &'a [Material], instances: Range<u32>) {
+        for submesh in self.submeshes() {
+            stats::set_bind_group(rpass, group, &materials[submesh.material_index].bind_group, &[]);
+            self.draw_submesh_instanced(rpass, submesh, instances.clone());
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬를 이루는 서브 메쉬들을 반환합니다. 단일 재질 메쉬는 메쉬 전체를 덮는 서브 메쉬 하나를 가집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the sub-meshes making up the mesh. A single material mesh has one sub-mesh covering the whole mesh. </br>
+    /// 
+    fn submeshes(&self) -> &[SubMesh];
+
+    fn draw_submesh_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, submesh: &SubMesh, instances: Range<u32>);
+
+    /// #### 한국어 </br>
+    /// 모델 공간에서 메쉬를 감싸는 축 정렬 경계 상자를 반환합니다. 메쉬를 생성할 때 정점들로부터 계산됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the axis-aligned bounding box enclosing the mesh in model space. It is computed from the vertices when the mesh is created. </br>
+    /// 
+    fn aabb(&self) -> Aabb;
+
+    /// #### 한국어 </br>
+    /// 모델 공간에서 메쉬를 감싸는 경계 구를 반환합니다. 메쉬를 생성할 때 정점들로부터 계산됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bounding sphere enclosing the mesh in model space. It is computed from the vertices when the mesh is created. </br>
+    /// 
+    fn bounding_sphere(&self) -> Sphere;
+}
+
+/// #### 한국어 </br>
+/// 메쉬에서 하나의 재질로 그려지는 프리미티브 범위 입니다. </br>
+/// `range`는 인덱스 버퍼를 가진 메쉬에서는 인덱스 범위, 그렇지 않은 메쉬에서는 정점 범위 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A primitive range of a mesh drawn with one material. </br>
+/// `range` is an index range for meshes with an index buffer, and a vertex range otherwise. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubMesh {
+    pub range: Range<u32>, 
+    pub base_vertex: i32, 
+    pub material_index: usize, 
+}
+
+impl SubMesh {
+    /// #### 한국어 </br>
+    /// 0번 재질로 `range`를 그리는 서브 메쉬를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a sub-mesh drawing `range` with material 0. </br>
+    /// 
+    #[inline]
+    pub fn new(range: Range<u32>) -> Self {
+        Self { range, base_vertex: 0, material_index: 0 }
+    }
+}
+
+/// #### 한국어 </br>
+/// 메쉬의 인덱스 데이터 입니다. 정점이 65536개 이하인 메쉬는 `U16`으로 인덱스 버퍼의 크기를 절반으로 줄입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The index data of a mesh. Meshes with at most 65536 vertices use `U16` to halve the size of the index buffer. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexData {
+    U16(Vec<u16>), 
+    U32(Vec<u32>), 
+}
+
+#[allow(dead_code)]
+impl IndexData {
+    /// #### 한국어 </br>
+    /// 모든 인덱스가 `u16`에 들어가면 `U16`으로, 그렇지 않으면 `U32`로 담습니다. 인덱스는 잘리지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Holds the indices as `U16` if all of them fit in `u16`, otherwise as `U32`. The indices are never truncated. </br>
+    /// 
+    pub fn from_u32(indices: Vec<u32>) -> Self {
+        if indices.iter().all(|&index| index <= u16::MAX as u32) {
+            Self::U16(indices.into_iter().map(|index| index as u16).collect())
+        } else {
+            Self::U32(indices)
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::U16(indices) => indices.len(), 
+            Self::U32(indices) => indices.len(), 
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn format(&self) -> wgpu::IndexFormat {
+        match self {
+            Self::U16(_) => wgpu::IndexFormat::Uint16, 
+            Self::U32(_) => wgpu::IndexFormat::Uint32, 
+        }
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::U16(indices) => bytemuck::cast_slice(indices), 
+            Self::U32(indices) => bytemuck::cast_slice(indices), 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 인덱스 데이터와 그 형식을 함께 가지는 인덱스 버퍼 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An index buffer holding the index data together with its format. </br>
+/// 
+#[derive(Debug)]
+pub struct IndexBuffer {
+    format: wgpu::IndexFormat, 
+    num_indices: u32, 
+    buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl IndexBuffer {
+    pub fn new(label: &str, data: &IndexData, device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        assert!(!data.is_empty());
+
+        // (한국어) 버퍼 쓰기는 4바이트 단위여야 하므로, `u16` 인덱스가 홀수개이면 뒤를 0으로 채웁니다.
+        // (English Translation) Buffer writes must be in units of 4 bytes, so an odd number of `u16` indices is padded with zeros at the end.
+        let mut bytes = data.as_bytes().to_vec();
+        bytes.resize(bytes.len().next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT as usize), 0);
+
+        let buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&format!("Index({})", label)), 
+                mapped_at_creation: false, 
+                size: bytes.len() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        queue.write_buffer(&buffer, 0, &bytes);
+
+        Self { 
+            format: data.format(), 
+            num_indices: data.len() as u32, 
+            buffer 
+        }
+    }
+
+    #[inline]
+    pub fn format(&self) -> wgpu::IndexFormat {
+        self.format
+    }
+
+    #[inline]
+    pub fn num_indices(&self) -> u32 {
+        self.num_indices
+    }
+
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        rpass.set_index_buffer(self.buffer.slice(..), self.format);
+    }
 }
 
 /// #### 한국어 </br>
@@ -23,8 +231,10 @@ pub trait ModelMesh : fmt::Debug {
 /// 
 #[derive(Debug)]
 pub struct CubeMesh {
-    num_indices: u32, 
-    index_buffer: wgpu::Buffer, 
+    aabb: Aabb, 
+    bounding_sphere: Sphere, 
+    submeshes: [SubMesh; 1], 
+    index_buffer: IndexBuffer, 
     vertex_buffer: wgpu::Buffer, 
 }
 
@@ -40,83 +250,97 @@ impl CubeMesh {
         let hy = 0.5 * y;
         let hz = 0.5 * z;
         let mut vertices = Vec::new();
-        vertices.push(ObjectVertexLayout { position: (-hx, -hy, hz).into(), normal: ( 0.0,  0.0,  1.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx, -hy,  hz).into(), normal: ( 0.0,  0.0,  1.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx,  hy,  hz).into(), normal: ( 0.0,  0.0,  1.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx,  hy,  hz).into(), normal: ( 0.0,  0.0,  1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx, -hy, hz).into(), normal: ( 0.0,  0.0,  1.0).into(), texcoord: (0.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx, -hy,  hz).into(), normal: ( 0.0,  0.0,  1.0).into(), texcoord: (1.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx,  hy,  hz).into(), normal: ( 0.0,  0.0,  1.0).into(), texcoord: (1.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx,  hy,  hz).into(), normal: ( 0.0,  0.0,  1.0).into(), texcoord: (0.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
         
-        vertices.push(ObjectVertexLayout { position: (-hx,  hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx,  hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx, -hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx, -hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into() });
-
-        vertices.push(ObjectVertexLayout { position: ( hx, -hy, -hz).into(), normal: ( 1.0,  0.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx,  hy, -hz).into(), normal: ( 1.0,  0.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx,  hy,  hz).into(), normal: ( 1.0,  0.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx, -hy,  hz).into(), normal: ( 1.0,  0.0,  0.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx,  hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into(), texcoord: (0.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx,  hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into(), texcoord: (1.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx, -hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into(), texcoord: (1.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx, -hy, -hz).into(), normal: ( 0.0,  0.0, -1.0).into(), texcoord: (0.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+
+        vertices.push(ObjectVertexLayout { position: ( hx, -hy, -hz).into(), normal: ( 1.0,  0.0,  0.0).into(), texcoord: (0.0, 1.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx,  hy, -hz).into(), normal: ( 1.0,  0.0,  0.0).into(), texcoord: (1.0, 1.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx,  hy,  hz).into(), normal: ( 1.0,  0.0,  0.0).into(), texcoord: (1.0, 0.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx, -hy,  hz).into(), normal: ( 1.0,  0.0,  0.0).into(), texcoord: (0.0, 0.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
         
-        vertices.push(ObjectVertexLayout { position: (-hx, -hy,  hz).into(), normal: (-1.0,  0.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx,  hy,  hz).into(), normal: (-1.0,  0.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx,  hy, -hz).into(), normal: (-1.0,  0.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx, -hy, -hz).into(), normal: (-1.0,  0.0,  0.0).into() });
-
-        vertices.push(ObjectVertexLayout { position: ( hx,  hy, -hz).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx,  hy, -hz).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx,  hy,  hz).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx,  hy,  hz).into(), normal: ( 0.0,  1.0,  0.0).into() });
-
-        vertices.push(ObjectVertexLayout { position: ( hx, -hy,  hz).into(), normal: ( 0.0, -1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx, -hy,  hz).into(), normal: ( 0.0, -1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hx, -hy, -hz).into(), normal: ( 0.0, -1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hx, -hy, -hz).into(), normal: ( 0.0, -1.0,  0.0).into() });
-
-        let vertex_buffer = device.create_buffer(
+        vertices.push(ObjectVertexLayout { position: (-hx, -hy,  hz).into(), normal: (-1.0,  0.0,  0.0).into(), texcoord: (0.0, 1.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx,  hy,  hz).into(), normal: (-1.0,  0.0,  0.0).into(), texcoord: (1.0, 1.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx,  hy, -hz).into(), normal: (-1.0,  0.0,  0.0).into(), texcoord: (1.0, 0.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx, -hy, -hz).into(), normal: (-1.0,  0.0,  0.0).into(), texcoord: (0.0, 0.0).into(), tangent: ( 0.0,  1.0,  0.0, -1.0).into() });
+
+        vertices.push(ObjectVertexLayout { position: ( hx,  hy, -hz).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (0.0, 1.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx,  hy, -hz).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (1.0, 1.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx,  hy,  hz).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (1.0, 0.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx,  hy,  hz).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (0.0, 0.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+
+        vertices.push(ObjectVertexLayout { position: ( hx, -hy,  hz).into(), normal: ( 0.0, -1.0,  0.0).into(), texcoord: (0.0, 1.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx, -hy,  hz).into(), normal: ( 0.0, -1.0,  0.0).into(), texcoord: (1.0, 1.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hx, -hy, -hz).into(), normal: ( 0.0, -1.0,  0.0).into(), texcoord: (1.0, 0.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hx, -hy, -hz).into(), normal: ( 0.0, -1.0,  0.0).into(), texcoord: (0.0, 0.0).into(), tangent: (-1.0,  0.0,  0.0, -1.0).into() });
+
+        let positions: Vec<_> = vertices.iter().map(|vertex| vertex.position).collect();
+        let vertex_buffer = stats::track_buffer(device.create_buffer(
             &wgpu::BufferDescriptor {
                 label: Some("Vertex(Cube)"), 
                 mapped_at_creation: false, 
                 size: (mem::size_of::<ObjectVertexLayout>() * vertices.len()) as wgpu::BufferAddress, 
                 usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
             }, 
-        );
+        ));
         queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&vertices));
 
-        let indices: [u16; 36] = [
+        let indices = IndexData::U16(vec![
             0, 1, 2, 2, 3, 0,
             4, 5, 6, 6, 7, 4, 
             8, 9, 10, 10, 11, 8, 
             12, 13, 14, 14, 15, 12, 
             16, 17, 18, 18, 19, 16, 
             20, 21, 22, 22, 23, 20, 
-        ];
-
-        let index_buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some("Index(Cube)"), 
-                mapped_at_creation: false, 
-                size: mem::size_of_val(&indices) as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-        queue.write_buffer(&index_buffer, 0, bytemuck::cast_slice(&indices));
-    
+        ]);
+        let index_buffer = IndexBuffer::new("Cube", &indices, device, queue);
+        
         Self { 
-            num_indices: indices.len() as u32, 
+            aabb: Aabb::from_points(&positions), 
+            bounding_sphere: Sphere::from_points(&positions), 
+            submeshes: [SubMesh::new(0..indices.len() as u32)], 
             index_buffer, 
             vertex_buffer 
         }
     }
+
+    #[inline]
+    pub fn num_indices(&self) -> u32 {
+        self.submeshes[0].range.end
+    }
 }
 
 impl ModelMesh for CubeMesh {
     #[inline]
     fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
         rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        rpass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+        self.index_buffer.bind(rpass);
     }
 
     #[inline]
-    fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
-        rpass.draw_indexed(0..self.num_indices, 0, 0..1);
+    fn submeshes(&self) -> &[SubMesh] {
+        &self.submeshes
+    }
+
+    #[inline]
+    fn draw_submesh_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, submesh: &SubMesh, instances: Range<u32>) {
+        stats::draw_indexed(rpass, submesh.range.clone(), submesh.base_vertex, instances);
+    }
+
+    #[inline]
+    fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    #[inline]
+    fn bounding_sphere(&self) -> Sphere {
+        self.bounding_sphere
     }
 }
 
@@ -128,7 +352,9 @@ impl ModelMesh for CubeMesh {
 /// 
 #[derive(Debug)]
 pub struct PlaneMesh {
-    num_vertices: u32, 
+    aabb: Aabb, 
+    bounding_sphere: Sphere, 
+    submeshes: [SubMesh; 1], 
     vertex_buffer: wgpu::Buffer, 
 }
 
@@ -143,26 +369,29 @@ impl PlaneMesh {
         let hw = 0.5 * w;
         let hh = 0.5 * h;
         let mut vertices = Vec::new();
-        vertices.push(ObjectVertexLayout { position: (-hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (0.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (0.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (1.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
 
-        vertices.push(ObjectVertexLayout { position: ( hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: (-hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into() });
-        vertices.push(ObjectVertexLayout { position: ( hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (1.0, 0.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: (-hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (0.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
+        vertices.push(ObjectVertexLayout { position: ( hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into(), texcoord: (1.0, 1.0).into(), tangent: ( 1.0,  0.0,  0.0, -1.0).into() });
 
-        let vertex_buffer = device.create_buffer(
+        let positions: Vec<_> = vertices.iter().map(|vertex| vertex.position).collect();
+        let vertex_buffer = stats::track_buffer(device.create_buffer(
             &wgpu::BufferDescriptor {
                 label: Some("Vertex(Plane)"), 
                 mapped_at_creation: false, 
                 size: (mem::size_of::<ObjectVertexLayout>() * vertices.len()) as wgpu::BufferAddress, 
                 usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
             }, 
-        );
+        ));
         queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&vertices));
 
         Self { 
-            num_vertices: vertices.len() as u32, 
+            aabb: Aabb::from_points(&positions), 
+            bounding_sphere: Sphere::from_points(&positions), 
+            submeshes: [SubMesh::new(0..vertices.len() as u32)], 
             vertex_buffer 
         }
     }
@@ -173,7 +402,128 @@ impl ModelMesh for PlaneMesh {
         rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
     }
 
-    fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
-        rpass.draw(0..self.num_vertices, 0..1);
+    fn submeshes(&self) -> &[SubMesh] {
+        &self.submeshes
+    }
+
+    fn draw_submesh_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, submesh: &SubMesh, instances: Range<u32>) {
+        stats::draw(rpass, submesh.range.clone(), instances);
+    }
+
+    fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    fn bounding_sphere(&self) -> Sphere {
+        self.bounding_sphere
+    }
+}
+
+/// #### 한국어 </br>
+/// 3D 구 모델의 메쉬 입니다. </br>
+/// 경선(`sectors`)과 위선(`stacks`)으로 나눈 UV 구이며, 각 정점의 법선은 중심에서 정점을 향하는 방향입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A mesh of a 3D sphere model. </br>
+/// It is a UV sphere divided into meridians (`sectors`) and parallels (`stacks`), and the normal of each vertex points from the center to the vertex. </br>
+/// 
+#[derive(Debug)]
+pub struct SphereMesh {
+    aabb: Aabb, 
+    bounding_sphere: Sphere, 
+    submeshes: [SubMesh; 1], 
+    index_buffer: IndexBuffer, 
+    vertex_buffer: wgpu::Buffer, 
+}
+
+impl SphereMesh {
+    pub fn new(
+        radius: f32, 
+        sectors: u32, 
+        stacks: u32, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(radius > 0.0 && sectors >= 3 && stacks >= 2);
+
+        // (한국어) 이음매에서 텍스처 좌표가 이어지도록 각 위선의 첫 정점과 마지막 정점은 같은 위치에 중복하여 놓습니다.
+        // (English Translation) The first and last vertices of each parallel are duplicated at the same position so that texture coordinates can continue across the seam.
+        let mut vertices = Vec::with_capacity(((sectors + 1) * (stacks + 1)) as usize);
+        for stack in 0..=stacks {
+            let phi = std::f32::consts::PI * stack as f32 / stacks as f32;
+            for sector in 0..=sectors {
+                let theta = std::f32::consts::TAU * sector as f32 / sectors as f32;
+                let normal = glam::vec3(phi.sin() * theta.sin(), phi.cos(), phi.sin() * theta.cos());
+                let texcoord = glam::vec2(sector as f32 / sectors as f32, stack as f32 / stacks as f32);
+                let tangent = glam::vec4(theta.cos(), 0.0, -theta.sin(), -1.0);
+                vertices.push(ObjectVertexLayout { position: normal * radius, normal, texcoord, tangent });
+            }
+        }
+
+        let positions: Vec<_> = vertices.iter().map(|vertex| vertex.position).collect();
+        let vertex_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Vertex(Sphere)"), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<ObjectVertexLayout>() * vertices.len()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+
+        // (한국어) 양 극에 닿는 띠는 삼각형이 하나로 줄어들므로, 넓이가 0인 삼각형은 만들지 않습니다.
+        // (English Translation) The bands touching the poles collapse into single triangles, so the triangles with zero area are not created.
+        let mut indices = Vec::with_capacity((6 * sectors * (stacks - 1)) as usize);
+        for stack in 0..stacks {
+            for sector in 0..sectors {
+                let top = stack * (sectors + 1) + sector;
+                let bottom = top + sectors + 1;
+                if stack != 0 {
+                    indices.extend_from_slice(&[top, bottom, top + 1]);
+                }
+                if stack != stacks - 1 {
+                    indices.extend_from_slice(&[top + 1, bottom, bottom + 1]);
+                }
+            }
+        }
+
+        let indices = IndexData::from_u32(indices);
+        let index_buffer = IndexBuffer::new("Sphere", &indices, device, queue);
+
+        Self { 
+            aabb: Aabb::from_points(&positions), 
+            bounding_sphere: Sphere::from_points(&positions), 
+            submeshes: [SubMesh::new(0..indices.len() as u32)], 
+            index_buffer, 
+            vertex_buffer 
+        }
+    }
+}
+
+impl ModelMesh for SphereMesh {
+    #[inline]
+    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        self.index_buffer.bind(rpass);
+    }
+
+    #[inline]
+    fn submeshes(&self) -> &[SubMesh] {
+        &self.submeshes
+    }
+
+    #[inline]
+    fn draw_submesh_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, submesh: &SubMesh, instances: Range<u32>) {
+        stats::draw_indexed(rpass, submesh.range.clone(), submesh.base_vertex, instances);
+    }
+
+    #[inline]
+    fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    #[inline]
+    fn bounding_sphere(&self) -> Sphere {
+        self.bounding_sphere
     }
 }
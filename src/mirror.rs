@@ -0,0 +1,317 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::camera::{CameraUniformLayout, GameCameraObject};
+use crate::object::GameObject;
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 거울이 스텐실 버퍼에 표시하는 참조 값 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The reference value the mirror marks in the stencil buffer. </br>
+/// 
+pub const STENCIL_REFERENCE: u32 = 1;
+
+
+
+/// #### 한국어 </br>
+/// 스텐실 마스크 거울을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a stencil-masked mirror. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MirrorBuilder {
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub size: glam::Vec2, 
+    pub tint: glam::Vec4, 
+    pub backdrop_color: glam::Vec3, 
+}
+
+impl Default for MirrorBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY, 
+            size: (2.0, 2.0).into(), 
+            tint: (0.75, 0.85, 0.9, 0.2).into(), 
+            backdrop_color: (0.55, 0.6, 0.65).into()
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MirrorBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    #[inline]
+    pub fn set_size(mut self, size: glam::Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    #[inline]
+    pub fn set_tint(mut self, tint: glam::Vec4) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    #[inline]
+    pub fn set_backdrop_color(mut self, backdrop_color: glam::Vec3) -> Self {
+        self.backdrop_color = backdrop_color;
+        self
+    }
+
+    pub fn build(
+        self, 
+        camera_bind_group_layout: &wgpu::BindGroupLayout, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Mirror {
+        assert!(self.size.x > 0.0 && self.size.y > 0.0);
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Mirror)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<MirrorUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Mirror)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let camera_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(ReflectedCamera)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<CameraUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let camera_bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(ReflectedCamera)"), 
+                layout: camera_bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            camera_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let mirror = Mirror {
+            transform: glam::Mat4::from_rotation_translation(
+                self.rotation.normalize(), 
+                self.translation
+            ), 
+            size: self.size, 
+            tint: self.tint, 
+            backdrop_color: self.backdrop_color, 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+            camera_buffer, 
+            camera_bind_group, 
+        };
+        mirror.update_resource(queue);
+
+        return mirror;
+    }
+}
+
+/// #### 한국어 </br>
+/// 스텐실 버퍼로 표시한 영역 안에만 반사된 장면을 그리는 평면 거울 입니다. </br>
+/// 거울 사각형은 로컬 공간의 XY 평면에 놓이며 +Z 방향을 바라봅니다. </br>
+/// 거울을 그리는 순서는 다음과 같습니다: </br>
+/// 1. 보이는 거울 픽셀의 스텐실을 표시합니다. </br>
+/// 2. 표시된 영역의 깊이를 가장 먼 값으로 되돌리고 배경색을 칠합니다. </br>
+/// 3. 반사된 카메라(`camera_bind_group`)로 장면을 표시된 영역 안에만 다시 그립니다. </br>
+/// 4. 반투명한 거울 표면을 혼합합니다. </br>
+/// 
+/// 반사된 장면을 자르는 평면은 사용하지 않으므로 물체들은 거울 앞쪽에 있어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A planar mirror that draws the reflected scene only inside the area marked in the stencil buffer. </br>
+/// The mirror quad lies on the XY plane of local space and faces the +Z direction. </br>
+/// The mirror is drawn in the following order: </br>
+/// 1. Marks the stencil of the visible mirror pixels. </br>
+/// 2. Resets the depth of the marked area to the farthest value and paints the backdrop color. </br>
+/// 3. Redraws the scene only inside the marked area with the reflected camera (`camera_bind_group`). </br>
+/// 4. Blends the translucent mirror surface. </br>
+/// 
+/// No clip plane is used for the reflected scene, so objects must be in front of the mirror. </br>
+/// 
+#[derive(Debug)]
+pub struct Mirror {
+    transform: glam::Mat4, 
+    size: glam::Vec2, 
+    tint: glam::Vec4, 
+    backdrop_color: glam::Vec3, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+    camera_buffer: wgpu::Buffer, 
+    pub camera_bind_group: wgpu::BindGroup, 
+}
+
+impl Mirror {
+    /// #### 한국어 </br>
+    /// 월드 공간에서 거울 평면의 법선과 원점으로부터의 거리를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the normal of the mirror plane and its distance from the origin in world space. </br>
+    /// 
+    #[inline]
+    pub fn plane(&self) -> (glam::Vec3, f32) {
+        let normal = self.get_look();
+        (normal, normal.dot(self.get_translation()))
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 위치가 거울의 앞쪽에 있는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the given position is in front of the mirror. </br>
+    /// 
+    #[inline]
+    pub fn is_facing(&self, position: glam::Vec3) -> bool {
+        let (normal, distance) = self.plane();
+        normal.dot(position) > distance
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 공간의 점을 거울 평면에 대해 반사하는 변환 행렬을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the transform matrix that reflects world space points about the mirror plane. </br>
+    /// 
+    pub fn reflection_transform(&self) -> glam::Mat4 {
+        let (n, d) = self.plane();
+        return glam::mat4(
+            glam::vec4(1.0 - 2.0 * n.x * n.x, -2.0 * n.x * n.y, -2.0 * n.x * n.z, 0.0), 
+            glam::vec4(-2.0 * n.y * n.x, 1.0 - 2.0 * n.y * n.y, -2.0 * n.y * n.z, 0.0), 
+            glam::vec4(-2.0 * n.z * n.x, -2.0 * n.z * n.y, 1.0 - 2.0 * n.z * n.z, 0.0), 
+            glam::vec4(2.0 * d * n.x, 2.0 * d * n.y, 2.0 * d * n.z, 1.0)
+        );
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 카메라를 거울에 반사한 카메라의 유니폼 데이터를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the uniform data of the camera reflected from the given camera by the mirror. </br>
+    /// 
+    pub fn update_reflected_camera<C: GameCameraObject>(&self, camera: &C, queue: &wgpu::Queue) {
+        let reflection = self.reflection_transform();
+        let data = CameraUniformLayout {
+            view: camera.view_transform() * reflection, 
+            projection: camera.projection_transform(), 
+            position: (reflection.transform_point3(camera.get_translation()), 0.0).into(), 
+        };
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    /// #### 한국어 </br>
+    /// 거울 사각형을 그립니다. 거울 파이프라인 중 하나가 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the mirror quad. One of the mirror pipelines must be set. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::set_bind_group(rpass, 1, &self.uniform_bind_group, &[]);
+        stats::draw(rpass, 0..6, 0..1);
+    }
+}
+
+impl GameObject for Mirror {
+    #[inline]
+    fn world_transform_ref(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    #[inline]
+    fn world_transform_mut(&mut self) -> &mut glam::Mat4 {
+        &mut self.transform
+    }
+}
+
+impl ShaderResource for Mirror {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = MirrorUniformLayout {
+            world: self.transform, 
+            tint: self.tint, 
+            backdrop_color: (self.backdrop_color, 1.0).into(), 
+            half_size: (0.5 * self.size.x, 0.5 * self.size.y, 0.0, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 거울 유니폼 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the mirror uniform data used in the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MirrorUniformLayout {
+    pub world: glam::Mat4, 
+    pub tint: glam::Vec4, 
+    pub backdrop_color: glam::Vec4, 
+    pub half_size: glam::Vec4, 
+}
+
+impl Default for MirrorUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            world: glam::Mat4::IDENTITY, 
+            tint: glam::Vec4::ZERO, 
+            backdrop_color: glam::Vec4::ONE, 
+            half_size: (1.0, 1.0, 0.0, 0.0).into()
+        }
+    }
+}
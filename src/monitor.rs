@@ -0,0 +1,250 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::object::GameObject;
+use crate::resource::ShaderResource;
+use crate::target::RenderTarget;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 렌더 타겟을 화면처럼 보여주는 모니터를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a monitor showing a render target like a screen. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorBuilder {
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub size: glam::Vec2, 
+    pub brightness: f32, 
+    pub scanline_intensity: f32, 
+}
+
+impl Default for MonitorBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY, 
+            size: (1.6, 0.9).into(), 
+            brightness: 1.0, 
+            scanline_intensity: 0.15
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MonitorBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    #[inline]
+    pub fn set_size(mut self, size: glam::Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    #[inline]
+    pub fn set_brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    #[inline]
+    pub fn set_scanline_intensity(mut self, scanline_intensity: f32) -> Self {
+        self.scanline_intensity = scanline_intensity;
+        self
+    }
+
+    pub fn build(
+        self, 
+        target: &RenderTarget, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Monitor {
+        assert!(self.size.x > 0.0 && self.size.y > 0.0);
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Monitor)"), 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Monitor)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<MonitorUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = create_bind_group(target, &sampler, &uniform_buffer, bind_group_layout, device);
+
+        let monitor = Monitor {
+            transform: glam::Mat4::from_rotation_translation(
+                self.rotation.normalize(), 
+                self.translation
+            ), 
+            size: self.size, 
+            brightness: self.brightness, 
+            scanline_intensity: self.scanline_intensity, 
+            sampler, 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+        };
+        monitor.update_resource(queue);
+
+        return monitor;
+    }
+}
+
+/// #### 한국어 </br>
+/// 월드 공간에 놓인 사각형에 렌더 타겟의 색상 텍스처를 입혀 보여주는 감시 카메라 모니터 입니다. </br>
+/// 사각형은 정점 버퍼 없이 로컬 공간의 XY 평면에 그려지며 +Z 방향을 바라봅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A security camera monitor that shows the color texture of a render target on a quad placed in world space. </br>
+/// The quad is drawn on the XY plane of local space without a vertex buffer and faces the +Z direction. </br>
+/// 
+#[derive(Debug)]
+pub struct Monitor {
+    transform: glam::Mat4, 
+    size: glam::Vec2, 
+    brightness: f32, 
+    scanline_intensity: f32, 
+    sampler: wgpu::Sampler, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+}
+
+impl Monitor {
+    /// #### 한국어 </br>
+    /// 렌더 타겟이 다시 생성되었을 때 모니터가 보여주는 텍스처를 바꿉니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the texture shown by the monitor when the render target is recreated. </br>
+    /// 
+    #[allow(dead_code)]
+    pub fn set_target(
+        &mut self, 
+        target: &RenderTarget, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device
+    ) {
+        self.uniform_bind_group = create_bind_group(target, &self.sampler, &self.uniform_buffer, bind_group_layout, device);
+    }
+
+    /// #### 한국어 </br>
+    /// 모니터를 그립니다. 모니터 파이프라인이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the monitor. The monitor pipeline must be set. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::set_bind_group(rpass, 1, &self.uniform_bind_group, &[]);
+        stats::draw(rpass, 0..6, 0..1);
+    }
+}
+
+impl GameObject for Monitor {
+    #[inline]
+    fn world_transform_ref(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    #[inline]
+    fn world_transform_mut(&mut self) -> &mut glam::Mat4 {
+        &mut self.transform
+    }
+}
+
+impl ShaderResource for Monitor {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = MonitorUniformLayout {
+            world: self.transform, 
+            params: (0.5 * self.size.x, 0.5 * self.size.y, self.brightness, self.scanline_intensity).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 모니터 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`는 (가로 절반 크기, 세로 절반 크기, 밝기, 주사선 세기) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the monitor uniform data used in the shader. </br>
+/// `params` is (half width, half height, brightness, scanline intensity). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorUniformLayout {
+    pub world: glam::Mat4, 
+    pub params: glam::Vec4, 
+}
+
+impl Default for MonitorUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            world: glam::Mat4::IDENTITY, 
+            params: (0.5, 0.5, 1.0, 0.0).into()
+        }
+    }
+}
+
+fn create_bind_group(
+    target: &RenderTarget, 
+    sampler: &wgpu::Sampler, 
+    uniform_buffer: &wgpu::Buffer, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    device: &wgpu::Device
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(Monitor)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::Buffer(
+                        uniform_buffer.as_entire_buffer_binding()
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::TextureView(target.color_view_ref()), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+            ], 
+        }, 
+    )
+}
@@ -0,0 +1,533 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use glam::Vec3Swizzles;
+
+use crate::bounds::Aabb;
+
+
+
+/// #### 한국어 </br>
+/// 걸을 수 있는 평면과 정적 장애물들로 내비게이션 격자를 굽는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that bakes a navigation grid from a walkable plane and static obstacles. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavGridBuilder {
+    pub min: glam::Vec2, 
+    pub max: glam::Vec2, 
+    pub height: f32, 
+    pub cell_size: f32, 
+    pub agent_radius: f32, 
+    pub agent_height: f32, 
+    pub obstacles: Vec<Aabb>, 
+}
+
+impl Default for NavGridBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min: (-5.0, -5.0).into(), 
+            max: (5.0, 5.0).into(), 
+            height: 0.0, 
+            cell_size: 0.25, 
+            agent_radius: 0.3, 
+            agent_height: 1.0, 
+            obstacles: Vec::new()
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl NavGridBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 걸을 수 있는 평면의 XZ 범위와 높이를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the XZ range and the height of the walkable plane. </br>
+    /// 
+    #[inline]
+    pub fn set_walkable_plane(mut self, min: glam::Vec2, max: glam::Vec2, height: f32) -> Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self.height = height;
+        self
+    }
+
+    #[inline]
+    pub fn set_cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    #[inline]
+    pub fn set_agent_radius(mut self, agent_radius: f32) -> Self {
+        self.agent_radius = agent_radius.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_agent_height(mut self, agent_height: f32) -> Self {
+        self.agent_height = agent_height.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 장애물의 월드 공간 경계 상자를 추가합니다. 에이전트의 높이 범위와 겹치는 장애물만 길을 막습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the world space bounding box of an obstacle. Only obstacles overlapping the height range of the agent block the way. </br>
+    /// 
+    #[inline]
+    pub fn add_obstacle(mut self, obstacle: Aabb) -> Self {
+        self.obstacles.push(obstacle);
+        self
+    }
+
+    pub fn build(self) -> NavGrid {
+        assert!(self.cell_size > 0.0);
+
+        let extent = self.max - self.min;
+        let width = ((extent.x / self.cell_size).floor() as u32).max(1);
+        let depth = ((extent.y / self.cell_size).floor() as u32).max(1);
+
+        // (한국어) 장애물을 에이전트의 반지름만큼 넓혀서 셀 중심이 그 안에 있으면 막힌 셀로 표시합니다.
+        // (English Translation) Widens the obstacles by the radius of the agent, and marks a cell as blocked if its center is inside.
+        let agent_bounds = (self.height, self.height + self.agent_height);
+        let obstacles: Vec<_> = self.obstacles.iter()
+            .filter(|aabb| aabb.min.y < agent_bounds.1 && aabb.max.y > agent_bounds.0)
+            .map(|aabb| (aabb.min.xz() - self.agent_radius, aabb.max.xz() + self.agent_radius))
+            .collect();
+
+        let mut walkable = Vec::with_capacity((width * depth) as usize);
+        for z in 0..depth {
+            for x in 0..width {
+                let center = self.min + (glam::vec2(x as f32, z as f32) + 0.5) * self.cell_size;
+                let blocked = obstacles.iter()
+                    .any(|(min, max)| center.cmpge(*min).all() && center.cmple(*max).all());
+                walkable.push(!blocked);
+            }
+        }
+
+        return NavGrid {
+            origin: self.min, 
+            height: self.height, 
+            cell_size: self.cell_size, 
+            width, 
+            depth, 
+            walkable, 
+        };
+    }
+}
+
+/// #### 한국어 </br>
+/// 격자의 셀 좌표 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The cell coordinates of the grid. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NavCell {
+    pub x: u32, 
+    pub z: u32, 
+}
+
+/// #### 한국어 </br>
+/// 걸을 수 있는 셀들을 표시한 내비게이션 격자 입니다. </br>
+/// 경로는 8방향으로 이웃한 셀들 사이에서 A* 탐색으로 찾으며, 장애물의 모서리를 대각선으로 가로지르지 않습니다. </br>
+/// 찾은 경로는 직선으로 갈 수 있는 지점들을 건너뛰어 다듬어집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A navigation grid marking the walkable cells. </br>
+/// Paths are found with A* search between the cells neighboring in 8 directions, and never cut diagonally across the corners of obstacles. </br>
+/// The path found is smoothed by skipping the points that can be reached in a straight line. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavGrid {
+    origin: glam::Vec2, 
+    height: f32, 
+    cell_size: f32, 
+    width: u32, 
+    depth: u32, 
+    walkable: Vec<bool>, 
+}
+
+#[allow(dead_code)]
+impl NavGrid {
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    #[inline]
+    pub fn num_walkable_cells(&self) -> usize {
+        self.walkable.iter().filter(|&&walkable| walkable).count()
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 공간의 위치가 놓인 셀을 반환합니다. 격자 밖이면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the cell where the world space position lies. Returns `None` if it is outside the grid. </br>
+    /// 
+    pub fn cell_at(&self, position: glam::Vec3) -> Option<NavCell> {
+        let local = (position.xz() - self.origin) / self.cell_size;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+
+        let (x, z) = (local.x as u32, local.y as u32);
+        (x < self.width && z < self.depth).then_some(NavCell { x, z })
+    }
+
+    /// #### 한국어 </br>
+    /// 셀 중심의 월드 공간 위치를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the world space position of the center of the cell. </br>
+    /// 
+    #[inline]
+    pub fn cell_center(&self, cell: NavCell) -> glam::Vec3 {
+        let center = self.origin + (glam::vec2(cell.x as f32, cell.z as f32) + 0.5) * self.cell_size;
+        glam::vec3(center.x, self.height, center.y)
+    }
+
+    #[inline]
+    pub fn is_walkable(&self, cell: NavCell) -> bool {
+        cell.x < self.width && cell.z < self.depth && self.walkable[self.index(cell)]
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 위치에서 가장 가까운 걸을 수 있는 셀을 찾습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Finds the walkable cell nearest to the given position. </br>
+    /// 
+    pub fn nearest_walkable(&self, position: glam::Vec3) -> Option<NavCell> {
+        let local = ((position.xz() - self.origin) / self.cell_size).floor();
+        let x = (local.x.max(0.0) as u32).min(self.width - 1);
+        let z = (local.y.max(0.0) as u32).min(self.depth - 1);
+        let cell = NavCell { x, z };
+        if self.is_walkable(cell) {
+            return Some(cell);
+        }
+
+        // (한국어) 셀을 중심으로 한 정사각형 고리를 넓혀가며 찾습니다.
+        // (English Translation) Searches by widening square rings around the cell.
+        for ring in 1..self.width.max(self.depth) as i64 {
+            let nearest = (-ring..=ring)
+                .flat_map(|dz| (-ring..=ring).map(move |dx| (dx, dz)))
+                .filter(|(dx, dz)| dx.abs() == ring || dz.abs() == ring)
+                .filter_map(|(dx, dz)| self.offset(cell, dx, dz))
+                .filter(|&other| self.is_walkable(other))
+                .min_by(|a, b| {
+                    let da = self.cell_center(*a).distance_squared(position);
+                    let db = self.cell_center(*b).distance_squared(position);
+                    da.total_cmp(&db)
+                });
+            if nearest.is_some() {
+                return nearest;
+            }
+        }
+        return None;
+    }
+
+    /// #### 한국어 </br>
+    /// 두 셀 사이를 직선으로 지날 때 막힌 셀이 없는지 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Tests whether no blocked cell lies on the straight line between two cells. </br>
+    /// 
+    pub fn line_of_sight(&self, from: NavCell, to: NavCell) -> bool {
+        // (한국어) 선이 지나는 모든 셀을 방문하는 격자 순회(Amanatides-Woo)를 사용합니다.
+        // (English Translation) Uses a grid traversal (Amanatides-Woo) that visits every cell the line passes through.
+        let start = glam::vec2(from.x as f32, from.z as f32) + 0.5;
+        let end = glam::vec2(to.x as f32, to.z as f32) + 0.5;
+        let direction = end - start;
+        let step = (direction.x.signum() as i64, direction.y.signum() as i64);
+        let delta = direction.abs().recip();
+        let mut t_max = delta * 0.5;
+        let (mut x, mut z) = (from.x as i64, from.z as i64);
+        while (x, z) != (to.x as i64, to.z as i64) {
+            if (t_max.x - t_max.y).abs() < 1e-6 {
+                // (한국어) 선이 셀의 모서리를 지나면 양 옆의 셀도 모두 걸을 수 있어야 합니다.
+                // (English Translation) If the line passes through a corner of a cell, both neighboring cells must be walkable too.
+                if !self.is_walkable_at(x + step.0, z) || !self.is_walkable_at(x, z + step.1) {
+                    return false;
+                }
+                x += step.0;
+                z += step.1;
+                t_max += delta;
+            } else if t_max.x < t_max.y {
+                x += step.0;
+                t_max.x += delta.x;
+            } else {
+                z += step.1;
+                t_max.y += delta.y;
+            }
+
+            if !self.is_walkable_at(x, z) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// `start`에서 `goal`까지의 경로를 A* 탐색으로 찾아 월드 공간의 지점들로 반환합니다. </br>
+    /// 두 위치는 가장 가까운 걸을 수 있는 셀로 옮겨지며, 경로가 없으면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Finds a path from `start` to `goal` with A* search and returns it as world space points. </br>
+    /// Both positions are moved to the nearest walkable cell, and `None` is returned if there is no path. </br>
+    /// 
+    pub fn find_path(&self, start: glam::Vec3, goal: glam::Vec3) -> Option<Vec<glam::Vec3>> {
+        let start_cell = self.nearest_walkable(start)?;
+        let goal_cell = self.nearest_walkable(goal)?;
+        let cells = self.find_cell_path(start_cell, goal_cell)?;
+
+        // (한국어) 직선으로 갈 수 있는 중간 셀들을 건너뛰어 경로를 다듬습니다.
+        // (English Translation) Smooths the path by skipping the intermediate cells that can be reached in a straight line.
+        let mut points = vec![glam::vec3(start.x, self.height, start.z)];
+        let mut anchor = start_cell;
+        for window in cells.windows(2) {
+            if !self.line_of_sight(anchor, window[1]) {
+                points.push(self.cell_center(window[0]));
+                anchor = window[0];
+            }
+        }
+        points.push(if self.cell_at(goal) == Some(goal_cell) {
+            glam::vec3(goal.x, self.height, goal.z)
+        } else {
+            self.cell_center(goal_cell)
+        });
+        return Some(points);
+    }
+
+    /// #### 한국어 </br>
+    /// 두 셀 사이의 A* 탐색을 실행하여 지나는 셀들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs A* search between two cells and returns the cells passed through. </br>
+    /// 
+    pub fn find_cell_path(&self, start: NavCell, goal: NavCell) -> Option<Vec<NavCell>> {
+        if !self.is_walkable(start) || !self.is_walkable(goal) {
+            return None;
+        }
+
+        let heuristic = |cell: NavCell| -> f32 {
+            // (한국어) 8방향 이동의 거리(octile distance)를 사용합니다.
+            // (English Translation) Uses the distance of 8-directional movement (octile distance).
+            let dx = cell.x.abs_diff(goal.x) as f32;
+            let dz = cell.z.abs_diff(goal.z) as f32;
+            dx.max(dz) + (std::f32::consts::SQRT_2 - 1.0) * dx.min(dz)
+        };
+
+        let mut cost = vec![f32::INFINITY; self.walkable.len()];
+        let mut came_from = vec![usize::MAX; self.walkable.len()];
+        let mut open = BinaryHeap::new();
+        cost[self.index(start)] = 0.0;
+        open.push(OpenNode { estimate: heuristic(start), cell: start });
+
+        while let Some(OpenNode { estimate, cell }) = open.pop() {
+            if cell == goal {
+                let mut path = vec![goal];
+                let mut index = self.index(goal);
+                while came_from[index] != usize::MAX {
+                    index = came_from[index];
+                    path.push(self.cell(index));
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let current = cost[self.index(cell)];
+            if estimate > current + heuristic(cell) + 1e-4 {
+                continue;
+            }
+
+            for (dx, dz) in NEIGHBORS {
+                let Some(next) = self.offset(cell, dx, dz) else {
+                    continue;
+                };
+                if !self.is_walkable(next) {
+                    continue;
+                }
+                // (한국어) 대각선 이동은 양 옆의 셀이 모두 걸을 수 있을 때만 허용합니다.
+                // (English Translation) Diagonal moves are only allowed when both side cells are walkable.
+                if dx != 0 && dz != 0 && (!self.is_walkable_at(cell.x as i64 + dx, cell.z as i64) || !self.is_walkable_at(cell.x as i64, cell.z as i64 + dz)) {
+                    continue;
+                }
+
+                let step = if dx != 0 && dz != 0 { std::f32::consts::SQRT_2 } else { 1.0 };
+                let next_index = self.index(next);
+                if current + step < cost[next_index] {
+                    cost[next_index] = current + step;
+                    came_from[next_index] = self.index(cell);
+                    open.push(OpenNode { estimate: current + step + heuristic(next), cell: next });
+                }
+            }
+        }
+        return None;
+    }
+
+    #[inline]
+    fn index(&self, cell: NavCell) -> usize {
+        (cell.z * self.width + cell.x) as usize
+    }
+
+    #[inline]
+    fn cell(&self, index: usize) -> NavCell {
+        NavCell { x: index as u32 % self.width, z: index as u32 / self.width }
+    }
+
+    #[inline]
+    fn offset(&self, cell: NavCell, dx: i64, dz: i64) -> Option<NavCell> {
+        let x = cell.x as i64 + dx;
+        let z = cell.z as i64 + dz;
+        (x >= 0 && z >= 0 && x < self.width as i64 && z < self.depth as i64)
+            .then_some(NavCell { x: x as u32, z: z as u32 })
+    }
+
+    #[inline]
+    fn is_walkable_at(&self, x: i64, z: i64) -> bool {
+        x >= 0 && z >= 0 && self.is_walkable(NavCell { x: x as u32, z: z as u32 })
+    }
+}
+
+const NEIGHBORS: [(i64, i64); 8] = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// #### 한국어 </br>
+/// A* 탐색의 열린 목록에 들어가는 노드 입니다. 추정 비용이 작을수록 먼저 꺼내집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A node in the open list of A* search. The smaller the estimated cost, the earlier it is taken out. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OpenNode {
+    estimate: f32, 
+    cell: NavCell, 
+}
+
+impl Eq for OpenNode { }
+
+impl PartialOrd for OpenNode {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+/// #### 한국어 </br>
+/// 내비게이션 격자에서 찾은 경로를 따라 움직이는 에이전트 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An agent that moves along a path found on the navigation grid. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavAgent {
+    position: glam::Vec3, 
+    speed: f32, 
+    path: Vec<glam::Vec3>, 
+    next_point: usize, 
+}
+
+#[allow(dead_code)]
+impl NavAgent {
+    #[inline]
+    pub fn new(position: glam::Vec3, speed: f32) -> Self {
+        Self { position, speed, path: Vec::new(), next_point: 0 }
+    }
+
+    #[inline]
+    pub fn position(&self) -> glam::Vec3 {
+        self.position
+    }
+
+    #[inline]
+    pub fn path(&self) -> &[glam::Vec3] {
+        &self.path
+    }
+
+    /// #### 한국어 </br>
+    /// 아직 지나지 않은 경로의 지점들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the points of the path that have not been passed yet. </br>
+    /// 
+    #[inline]
+    pub fn remaining_path(&self) -> &[glam::Vec3] {
+        &self.path[self.next_point.min(self.path.len())..]
+    }
+
+    #[inline]
+    pub fn has_arrived(&self) -> bool {
+        self.next_point >= self.path.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 목적지까지의 경로를 찾습니다. 경로가 없으면 제자리에 멈추고 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Finds a path to the destination. If there is no path, it stops in place and returns `false`. </br>
+    /// 
+    pub fn set_destination(&mut self, grid: &NavGrid, destination: glam::Vec3) -> bool {
+        match grid.find_path(self.position, destination) {
+            Some(path) => {
+                self.path = path;
+                self.next_point = 1;
+                true
+            }, 
+            None => {
+                self.path.clear();
+                self.next_point = 0;
+                false
+            }, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 지난 프레임의 시간만큼 경로를 따라 움직입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Moves along the path for the time of the last frame. </br>
+    /// 
+    pub fn update(&mut self, elapsed_time_sec: f32) {
+        let mut distance = self.speed * elapsed_time_sec;
+        while distance > 0.0 && !self.has_arrived() {
+            let target = self.path[self.next_point];
+            let to_target = target - self.position;
+            let length = to_target.length();
+            if length <= distance {
+                self.position = target;
+                self.next_point += 1;
+                distance -= length;
+            } else {
+                self.position += to_target * (distance / length);
+                distance = 0.0;
+            }
+        }
+    }
+}
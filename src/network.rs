@@ -0,0 +1,456 @@
+use std::io;
+use std::env;
+use std::time::{Duration, Instant};
+use std::net::{SocketAddr, UdpSocket};
+
+
+
+/// #### 한국어 </br>
+/// 네트워크 모드를 설정하는 환경 변수의 이름 입니다. </br>
+/// 값은 `server:<바인드 주소>` 또는 `client:<서버 주소>` 입니다. (예: `server:0.0.0.0:40416`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The name of the environment variable that sets the network mode. </br>
+/// The value is `server:<bind address>` or `client:<server address>`. (e.g. `server:0.0.0.0:40416`) </br>
+/// 
+pub const NET_MODE_VAR: &str = "LAB_PROJECT_NET";
+
+/// #### 한국어 </br>
+/// 서버가 초당 보내는 스냅샷의 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of snapshots the server sends per second. </br>
+/// 
+pub const TICK_RATE: u32 = 20;
+
+/// #### 한국어 </br>
+/// 클라이언트가 스냅샷 사이를 보간하기 위해 서버 시간보다 늦게 그리는 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time the client draws behind the server time to interpolate between snapshots. </br>
+/// 
+pub const INTERPOLATION_DELAY_SEC: f64 = 2.0 / TICK_RATE as f64;
+
+const PACKET_MAGIC: u32 = u32::from_le_bytes(*b"LP01");
+const PACKET_HELLO: u8 = 0;
+const PACKET_SNAPSHOT: u8 = 1;
+const HEADER_SIZE: usize = 4 + 1 + 4 + 2;
+const ENTITY_SIZE: usize = 2 + 4 * 10;
+const MAX_PACKET_SIZE: usize = 1200;
+const HELLO_INTERVAL: Duration = Duration::from_secs(1);
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_BUFFERED_SNAPSHOTS: usize = 32;
+
+/// #### 한국어 </br>
+/// 한 스냅샷에 담을 수 있는 물체의 최대 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of objects a snapshot can hold. </br>
+/// 
+pub const MAX_ENTITIES_PER_SNAPSHOT: usize = (MAX_PACKET_SIZE - HEADER_SIZE) / ENTITY_SIZE;
+
+
+
+/// #### 한국어 </br>
+/// 복제되는 물체 하나의 변환 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The transform of one replicated object. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetEntity {
+    pub id: u16, 
+    pub scale: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub translation: glam::Vec3, 
+}
+
+#[allow(dead_code)]
+impl NetEntity {
+    #[inline]
+    pub fn from_transform(id: u16, transform: &glam::Mat4) -> Self {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        Self { id, scale, rotation, translation }
+    }
+
+    #[inline]
+    pub fn world_transform(&self) -> glam::Mat4 {
+        glam::Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// #### 한국어 </br>
+    /// 두 변환 사이를 보간합니다. 회전은 구면 선형 보간을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Interpolates between two transforms. Rotations use spherical linear interpolation. </br>
+    /// 
+    #[inline]
+    pub fn lerp(&self, other: &NetEntity, t: f32) -> Self {
+        Self {
+            id: self.id, 
+            scale: self.scale.lerp(other.scale, t), 
+            rotation: self.rotation.slerp(other.rotation, t), 
+            translation: self.translation.lerp(other.translation, t), 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 서버의 틱 번호에 담긴 물체들의 변환 입니다. 물체들은 번호 순서로 정렬되어 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The transforms of objects at a tick number of the server. The objects are sorted by id. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetSnapshot {
+    pub tick: u32, 
+    pub entities: Vec<NetEntity>, 
+}
+
+impl NetSnapshot {
+    fn encode(&self) -> Vec<u8> {
+        let mut packet = Vec::with_capacity(HEADER_SIZE + ENTITY_SIZE * self.entities.len());
+        packet.extend_from_slice(&PACKET_MAGIC.to_le_bytes());
+        packet.push(PACKET_SNAPSHOT);
+        packet.extend_from_slice(&self.tick.to_le_bytes());
+        packet.extend_from_slice(&(self.entities.len() as u16).to_le_bytes());
+        for entity in self.entities.iter() {
+            packet.extend_from_slice(&entity.id.to_le_bytes());
+            let values = entity.scale.to_array().into_iter()
+                .chain(entity.rotation.to_array())
+                .chain(entity.translation.to_array());
+            for value in values {
+                packet.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        return packet;
+    }
+
+    fn decode(packet: &[u8]) -> Option<Self> {
+        if packet.len() < HEADER_SIZE || read_u32(packet, 0) != PACKET_MAGIC || packet[4] != PACKET_SNAPSHOT {
+            return None;
+        }
+
+        let tick = read_u32(packet, 5);
+        let count = u16::from_le_bytes([packet[9], packet[10]]) as usize;
+        if packet.len() != HEADER_SIZE + ENTITY_SIZE * count {
+            return None;
+        }
+
+        let entities = packet[HEADER_SIZE..].chunks_exact(ENTITY_SIZE)
+            .map(|chunk| {
+                let value = |index: usize| f32::from_le_bytes(chunk[2 + 4 * index..6 + 4 * index].try_into().unwrap());
+                NetEntity {
+                    id: u16::from_le_bytes([chunk[0], chunk[1]]), 
+                    scale: glam::vec3(value(0), value(1), value(2)), 
+                    rotation: glam::quat(value(3), value(4), value(5), value(6)).normalize(), 
+                    translation: glam::vec3(value(7), value(8), value(9)), 
+                }
+            })
+            .collect();
+        Some(Self { tick, entities })
+    }
+}
+
+#[inline]
+fn read_u32(packet: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(packet[offset..offset + 4].try_into().unwrap())
+}
+
+#[inline]
+fn hello_packet() -> [u8; 5] {
+    let magic = PACKET_MAGIC.to_le_bytes();
+    [magic[0], magic[1], magic[2], magic[3], PACKET_HELLO]
+}
+
+
+
+/// #### 한국어 </br>
+/// 고정된 틱마다 물체들의 변환을 클라이언트들에게 보내는 UDP 서버 입니다. </br>
+/// 클라이언트는 주기적으로 인사 패킷을 보내야 하며, 일정 시간 동안 소식이 없으면 목록에서 제거됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A UDP server that sends the transforms of objects to the clients at a fixed tick. </br>
+/// Clients must send a hello packet periodically, and are removed from the list when nothing is heard from them for a while. </br>
+/// 
+#[derive(Debug)]
+pub struct NetServer {
+    socket: UdpSocket, 
+    clients: Vec<(SocketAddr, Instant)>, 
+    tick: u32, 
+    accumulated_time: f64, 
+}
+
+#[allow(dead_code)]
+impl NetServer {
+    pub fn bind(address: SocketAddr) -> io::Result<Self> {
+        let socket = UdpSocket::bind(address)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket, 
+            clients: Vec::new(), 
+            tick: 0, 
+            accumulated_time: 0.0
+        })
+    }
+
+    #[inline]
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    #[inline]
+    pub fn num_clients(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 받은 인사 패킷들을 처리하고 시간을 진행시킵니다. 이번 프레임에 스냅샷을 보내야 하면 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Handles the received hello packets and advances the time. Returns `true` if a snapshot must be sent this frame. </br>
+    /// 
+    pub fn update(&mut self, elapsed_time_sec: f32) -> bool {
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        loop {
+            match self.socket.recv_from(&mut buffer) {
+                Ok((size, address)) => {
+                    if buffer[..size] != hello_packet() {
+                        continue;
+                    }
+                    match self.clients.iter_mut().find(|(client, _)| *client == address) {
+                        Some((_, last_seen)) => *last_seen = Instant::now(), 
+                        None => {
+                            log::info!("Client connected. ({})", address);
+                            self.clients.push((address, Instant::now()));
+                        }, 
+                    }
+                }, 
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break, 
+                // (한국어) 윈도우에서는 닫힌 클라이언트로 보낸 패킷 때문에 연결 초기화 오류가 올 수 있습니다.
+                // (English Translation) On Windows, a connection reset error may arrive due to a packet sent to a closed client.
+                Err(e) if e.kind() == io::ErrorKind::ConnectionReset => continue, 
+                Err(e) => {
+                    log::warn!("Failed to receive a packet. ({})", e);
+                    break;
+                }, 
+            }
+        }
+
+        self.clients.retain(|(address, last_seen)| {
+            let alive = last_seen.elapsed() < CLIENT_TIMEOUT;
+            if !alive {
+                log::info!("Client timed out. ({})", address);
+            }
+            alive
+        });
+
+        let tick_time = 1.0 / TICK_RATE as f64;
+        self.accumulated_time += elapsed_time_sec as f64;
+        if self.accumulated_time < tick_time {
+            return false;
+        }
+        self.accumulated_time = (self.accumulated_time - tick_time).min(tick_time);
+        self.tick = self.tick.wrapping_add(1);
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 틱의 스냅샷을 모든 클라이언트에게 보냅니다. 한 패킷에 담기지 않는 물체들은 버려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sends the snapshot of the current tick to all clients. Objects that do not fit in a packet are dropped. </br>
+    /// 
+    pub fn broadcast<I: IntoIterator<Item = NetEntity>>(&mut self, entities: I) {
+        let mut entities: Vec<_> = entities.into_iter().take(MAX_ENTITIES_PER_SNAPSHOT).collect();
+        entities.sort_by_key(|entity| entity.id);
+        let packet = NetSnapshot { tick: self.tick, entities }.encode();
+        for (address, _) in self.clients.iter() {
+            if let Err(e) = self.socket.send_to(&packet, address) {
+                log::warn!("Failed to send a snapshot. ({}, {})", address, e);
+            }
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 서버의 스냅샷들을 받아 보간된 변환을 제공하는 UDP 클라이언트 입니다. </br>
+/// 클라이언트는 받은 틱으로 서버 시간을 추정하고, `INTERPOLATION_DELAY_SEC`만큼 늦은 시점을 두 스냅샷 사이에서 보간합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A UDP client that receives the snapshots of the server and provides interpolated transforms. </br>
+/// The client estimates the server time from the received ticks, and interpolates the time `INTERPOLATION_DELAY_SEC` behind between two snapshots. </br>
+/// 
+#[derive(Debug)]
+pub struct NetClient {
+    socket: UdpSocket, 
+    snapshots: Vec<NetSnapshot>, 
+    server_time: Option<f64>, 
+    last_hello: Option<Instant>, 
+}
+
+#[allow(dead_code)]
+impl NetClient {
+    pub fn connect(server: SocketAddr) -> io::Result<Self> {
+        let any: SocketAddr = match server {
+            SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(), 
+            SocketAddr::V6(_) => ([0u16; 8], 0).into(), 
+        };
+        let socket = UdpSocket::bind(any)?;
+        socket.connect(server)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket, 
+            snapshots: Vec::new(), 
+            server_time: None, 
+            last_hello: None
+        })
+    }
+
+    #[inline]
+    pub fn is_synchronized(&self) -> bool {
+        self.server_time.is_some()
+    }
+
+    /// #### 한국어 </br>
+    /// 인사 패킷을 보내고, 받은 스냅샷들을 모으며, 추정한 서버 시간을 진행시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sends the hello packet, collects the received snapshots, and advances the estimated server time. </br>
+    /// 
+    pub fn update(&mut self, elapsed_time_sec: f32) {
+        if self.last_hello.map_or(true, |last_hello| last_hello.elapsed() >= HELLO_INTERVAL) {
+            if let Err(e) = self.socket.send(&hello_packet()) {
+                log::warn!("Failed to send a hello packet. ({})", e);
+            }
+            self.last_hello = Some(Instant::now());
+        }
+
+        if let Some(server_time) = self.server_time.as_mut() {
+            *server_time += elapsed_time_sec as f64;
+        }
+
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        loop {
+            match self.socket.recv(&mut buffer) {
+                Ok(size) => {
+                    if let Some(snapshot) = NetSnapshot::decode(&buffer[..size]) {
+                        self.receive(snapshot);
+                    }
+                }, 
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break, 
+                Err(e) if e.kind() == io::ErrorKind::ConnectionReset || e.kind() == io::ErrorKind::ConnectionRefused => break, 
+                Err(e) => {
+                    log::warn!("Failed to receive a packet. ({})", e);
+                    break;
+                }, 
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 추정한 서버 시간보다 `INTERPOLATION_DELAY_SEC`만큼 늦은 시점의 물체 변환들을 보간하여 반환합니다. </br>
+    /// 한쪽 스냅샷에만 있는 물체는 그 스냅샷의 변환을 그대로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the object transforms interpolated at the time `INTERPOLATION_DELAY_SEC` behind the estimated server time. </br>
+    /// An object only in one of the snapshots uses the transform of that snapshot as is. </br>
+    /// 
+    pub fn sample(&self) -> Vec<NetEntity> {
+        let Some(server_time) = self.server_time else {
+            return Vec::new();
+        };
+
+        let render_tick = (server_time - INTERPOLATION_DELAY_SEC) * TICK_RATE as f64;
+        let next = self.snapshots.iter().position(|snapshot| snapshot.tick as f64 > render_tick);
+        let (from, to) = match next {
+            Some(0) => (&self.snapshots[0], &self.snapshots[0]), 
+            Some(index) => (&self.snapshots[index - 1], &self.snapshots[index]), 
+            None => match self.snapshots.last() {
+                Some(last) => (last, last), 
+                None => return Vec::new(), 
+            }, 
+        };
+
+        let span = to.tick.wrapping_sub(from.tick) as f64;
+        let t = if span > 0.0 { ((render_tick - from.tick as f64) / span).clamp(0.0, 1.0) as f32 } else { 0.0 };
+        to.entities.iter()
+            .map(|entity| match from.entities.binary_search_by_key(&entity.id, |other| other.id) {
+                Ok(index) => from.entities[index].lerp(entity, t), 
+                Err(_) => *entity, 
+            })
+            .collect()
+    }
+
+    fn receive(&mut self, snapshot: NetSnapshot) {
+        // (한국어) 서버가 다시 시작되어 틱이 크게 뒤로 가면 버퍼를 비웁니다.
+        // (English Translation) Clears the buffer if the tick went far back because the server restarted.
+        if let Some(last) = self.snapshots.last() {
+            if snapshot.tick + TICK_RATE < last.tick {
+                self.snapshots.clear();
+                self.server_time = None;
+            }
+        }
+
+        let snapshot_time = snapshot.tick as f64 / TICK_RATE as f64;
+        match self.server_time.as_mut() {
+            // (한국어) 추정한 서버 시간을 받은 틱 쪽으로 조금씩 당기고, 너무 많이 벗어나면 맞춥니다.
+            // (English Translation) Pulls the estimated server time slightly toward the received tick, and snaps it if it is too far off.
+            Some(server_time) if (snapshot_time - *server_time).abs() < 1.0 => {
+                if snapshot_time > *server_time {
+                    *server_time += 0.1 * (snapshot_time - *server_time);
+                }
+            }, 
+            _ => self.server_time = Some(snapshot_time), 
+        }
+
+        match self.snapshots.binary_search_by_key(&snapshot.tick, |other| other.tick) {
+            Ok(_) => { /*--- duplicated ---*/ }, 
+            Err(index) => self.snapshots.insert(index, snapshot), 
+        }
+        if self.snapshots.len() > MAX_BUFFERED_SNAPSHOTS {
+            let excess = self.snapshots.len() - MAX_BUFFERED_SNAPSHOTS;
+            self.snapshots.drain(..excess);
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 서버 또는 클라이언트로 동작하는 네트워크 세션 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A network session acting as either a server or a client. </br>
+/// 
+#[derive(Debug)]
+pub enum NetSession {
+    Server(NetServer), 
+    Client(NetClient), 
+}
+
+#[allow(dead_code)]
+impl NetSession {
+    /// #### 한국어 </br>
+    /// `NET_MODE_VAR` 환경 변수로 세션을 생성합니다. 변수가 없으면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a session from the `NET_MODE_VAR` environment variable. Returns `None` if the variable is not set. </br>
+    /// 
+    pub fn from_env() -> Option<io::Result<Self>> {
+        let value = env::var(NET_MODE_VAR).ok()?;
+        let parse = |address: &str| address.parse::<SocketAddr>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e));
+        Some(match value.split_once(':') {
+            Some(("server", address)) => parse(address).and_then(NetServer::bind).map(NetSession::Server), 
+            Some(("client", address)) => parse(address).and_then(NetClient::connect).map(NetSession::Client), 
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput, 
+                format!("{} must be `server:<address>` or `client:<address>`", NET_MODE_VAR)
+            )), 
+        })
+    }
+
+    #[inline]
+    pub fn is_client(&self) -> bool {
+        matches!(self, NetSession::Client(_))
+    }
+}
@@ -1,9 +1,11 @@
 use std::fmt;
-use std::mem;
+use std::rc::Rc;
 use bytemuck::{Pod, Zeroable};
 use glam::Vec4Swizzles;
 
-use crate::resource::ShaderResource;
+use crate::bounds::Frustum;
+use crate::mesh::ModelMesh;
+use crate::resource::{ShaderResource, UniformArena, UniformSlot};
 
 
 
@@ -13,6 +15,7 @@ use crate::resource::ShaderResource;
 /// #### English (Translation) </br>
 /// This is a trait of an object that exists in the game world. </br>
 /// 
+#[allow(dead_code)]
 pub trait GameObject : fmt::Debug {
     #[inline]
     fn get_translation(&self) -> glam::Vec3 {
@@ -99,6 +102,9 @@ pub trait GameObject : fmt::Debug {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct StdObjectBuilder {
     pub color: glam::Vec3, 
+    pub opacity: f32, 
+    pub rim_color: glam::Vec3, 
+    pub rim_power: f32, 
     pub rotation: glam::Quat, 
     pub translation: glam::Vec3, 
 }
@@ -108,6 +114,9 @@ impl Default for StdObjectBuilder {
     fn default() -> Self {
         Self { 
             color: glam::Vec3::ONE, 
+            opacity: 1.0, 
+            rim_color: glam::Vec3::ZERO, 
+            rim_power: 3.0, 
             rotation: glam::Quat::IDENTITY, 
             translation: glam::Vec3::ZERO 
         }
@@ -127,6 +136,38 @@ impl StdObjectBuilder {
         self
     }
 
+    #[inline]
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 시선과 표면이 비스듬할수록 밝아지는 림(rim) 하이라이트의 색상을 설정합니다. </br>
+    /// 검은색이면 림 하이라이트가 그려지지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the color of the rim highlight that gets brighter as the surface turns away from the view. </br>
+    /// If it is black, no rim highlight is drawn. </br>
+    /// 
+    #[inline]
+    pub fn set_rim_color(mut self, rim_color: glam::Vec3) -> Self {
+        self.rim_color = rim_color;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 림 하이라이트의 지수를 설정합니다. 값이 클수록 하이라이트가 가장자리에 좁게 모입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the exponent of the rim highlight. The larger the value, the narrower the highlight gathers at the edges. </br>
+    /// 
+    #[inline]
+    pub fn set_rim_power(mut self, rim_power: f32) -> Self {
+        self.rim_power = rim_power.max(0.0);
+        self
+    }
+
     #[inline]
     pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
         self.translation = translation;
@@ -171,42 +212,21 @@ impl StdObjectBuilder {
 
     pub fn build(
         self, 
-        bind_group_layout: &wgpu::BindGroupLayout, 
-        device: &wgpu::Device, 
+        uniforms: &Rc<UniformArena>, 
         queue: &wgpu::Queue
     ) -> StdObject {
-        let uniform_buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some("Uniform(Object)"), 
-                mapped_at_creation: false, 
-                size: mem::size_of::<ObjectUniformLayout>() as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-
-        let bind_group = device.create_bind_group(
-            &wgpu::BindGroupDescriptor {
-                label: Some("BindGroup(Object)"), 
-                layout: bind_group_layout, 
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0, 
-                        resource: wgpu::BindingResource::Buffer(
-                            uniform_buffer.as_entire_buffer_binding()
-                        ),
-                    }, 
-                ], 
-            }, 
-        );
+        let uniform = uniforms.allocate();
 
         let object = StdObject { 
             color: self.color, 
+            opacity: self.opacity, 
+            rim_color: self.rim_color, 
+            rim_power: self.rim_power, 
             transform: glam::Mat4::from_rotation_translation(
                 self.rotation, 
                 self.translation
             ), 
-            uniform_buffer, 
-            uniform_bind_group: bind_group 
+            uniform, 
         };
         object.update_resource(queue);
 
@@ -223,9 +243,81 @@ impl StdObjectBuilder {
 #[derive(Debug)]
 pub struct StdObject {
     color: glam::Vec3, 
+    opacity: f32, 
+    rim_color: glam::Vec3, 
+    rim_power: f32, 
     transform: glam::Mat4, 
-    uniform_buffer: wgpu::Buffer, 
-    pub uniform_bind_group: wgpu::BindGroup, 
+    uniform: UniformSlot, 
+}
+
+#[allow(dead_code)]
+impl StdObject {
+    /// #### 한국어 </br>
+    /// 마지막으로 갱신된 유니폼 데이터를 동적 오프셋으로 `index`번 바인드 그룹에 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the most recently updated uniform data to bind group `index` with a dynamic offset. </br>
+    /// 
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, index: u32) {
+        self.uniform.bind(rpass, index);
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명도가 1보다 작아 반투명 단계에서 그려야 하는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the opacity is less than 1 and it must be drawn in the transparent phase. </br>
+    /// 
+    #[inline]
+    pub fn is_transparent(&self) -> bool {
+        self.opacity < 1.0
+    }
+
+    #[inline]
+    pub fn color(&self) -> glam::Vec3 {
+        self.color
+    }
+
+    #[inline]
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    #[inline]
+    pub fn rim_color(&self) -> glam::Vec3 {
+        self.rim_color
+    }
+
+    #[inline]
+    pub fn rim_power(&self) -> f32 {
+        self.rim_power
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 색상을 변경합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the color of the object. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn set_color(&mut self, color: glam::Vec3) {
+        self.color = color;
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬의 경계 볼륨에 오브젝트의 변환을 적용하여 절두체와 겹치는지 검사합니다. </br>
+    /// 경계 구로 먼저 빠르게 걸러낸 후 경계 상자로 다시 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Tests whether the object overlaps the frustum by applying its transform to the bounding volumes of the mesh. </br>
+    /// It is quickly rejected with the bounding sphere first, and then tested again with the bounding box. </br>
+    /// 
+    #[inline]
+    pub fn is_in_frustum<T: ModelMesh>(&self, frustum: &Frustum, mesh: &T) -> bool {
+        frustum.intersects_sphere(&mesh.bounding_sphere().transform(&self.transform)) 
+            && frustum.intersects_aabb(&mesh.aabb().transform(&self.transform))
+    }
 }
 
 impl GameObject for StdObject {
@@ -242,20 +334,154 @@ impl GameObject for StdObject {
 
 impl ShaderResource for StdObject {
     #[inline]
-    fn update_resource(&self, queue: &wgpu::Queue) {
+    fn update_resource(&self, _queue: &wgpu::Queue) {
+        // (한국어) 유니폼 데이터는 공유 유니폼 버퍼의 사본에 쓰이고, 아레나의 `flush`에서 한 번에 올라갑니다.
+        // (English Translation) The uniform data is written to the copy of the shared uniform buffer, and uploaded at once in `flush` of the arena.
         let data = ObjectUniformLayout {
-            world: self.world_transform_ref().clone(), 
-            color: (self.color, 1.0).into(), 
+            world: *self.world_transform_ref(), 
+            color: (self.color, self.opacity).into(), 
+            rim: (self.rim_color, self.rim_power).into(), 
         };
-        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+        self.uniform.write(bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 매 프레임 경과 시간만큼 상태를 진행하는 물체의 trait 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a trait of objects that advance their state by the elapsed time every frame. </br>
+/// 
+pub trait Updatable {
+    fn update(&mut self, elapsed_time_sec: f32, queue: &wgpu::Queue);
+}
+
+/// #### 한국어 </br>
+/// 애니메이션 콜백으로 움직이는 표준 오브젝트 입니다. </br>
+/// 콜백은 매 프레임 (오브젝트, 생성 후 흐른 시간, 경과 시간)을 받으며, 호출 후 유니폼 데이터가 갱신됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A standard object moved by an animation callback. </br>
+/// The callback receives (object, time since creation, elapsed time) every frame, and the uniform data is updated after the call. </br>
+/// 
+pub struct AnimatedObject {
+    object: StdObject, 
+    time_sec: f32, 
+    previous_transform: glam::Mat4, 
+    current_transform: glam::Mat4, 
+    animation: Box<dyn FnMut(&mut StdObject, f32, f32)>, 
+}
+
+#[allow(dead_code)]
+impl AnimatedObject {
+    #[inline]
+    pub fn new<F>(object: StdObject, animation: F) -> Self 
+    where F: FnMut(&mut StdObject, f32, f32) + 'static {
+        let transform = *object.world_transform_ref();
+        Self { 
+            object, 
+            time_sec: 0.0, 
+            previous_transform: transform, 
+            current_transform: transform, 
+            animation: Box::new(animation) 
+        }
+    }
+
+    #[inline]
+    pub fn object_ref(&self) -> &StdObject {
+        &self.object
+    }
+
+    #[inline]
+    pub fn object_mut(&mut self) -> &mut StdObject {
+        &mut self.object
+    }
+
+    #[inline]
+    pub fn time_sec(&self) -> f32 {
+        self.time_sec
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 고정 갱신이 끝난 시점의 월드 변환 행렬 입니다. 보간되지 않은 값이므로 충돌 검사에 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The world transform at the end of the last fixed update. It is not interpolated, so it is used for the collision tests. </br>
+    /// 
+    #[inline]
+    pub fn current_transform_ref(&self) -> &glam::Mat4 {
+        &self.current_transform
+    }
+
+    /// #### 한국어 </br>
+    /// 애니메이션을 고정된 시간 간격 만큼 진행합니다. 유니폼 버퍼는 `interpolate`에서 갱신됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the animation by a fixed time step. The uniform buffer is updated in `interpolate`. </br>
+    /// 
+    pub fn fixed_update(&mut self, step_sec: f32) {
+        self.previous_transform = self.current_transform;
+        *self.object.world_transform_mut() = self.current_transform;
+        self.time_sec += step_sec;
+        (self.animation)(&mut self.object, self.time_sec, step_sec);
+        self.current_transform = *self.object.world_transform_ref();
+    }
+
+    /// #### 한국어 </br>
+    /// 직전 두 고정 갱신의 월드 변환 행렬을 `alpha`로 보간하여 오브젝트에 적용하고, 유니폼 버퍼를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies the world transforms of the last two fixed updates interpolated by `alpha` to the object, and updates the uniform buffer. </br>
+    /// 
+    pub fn interpolate(&mut self, alpha: f32, queue: &wgpu::Queue) {
+        *self.object.world_transform_mut() = interpolate_transform(&self.previous_transform, &self.current_transform, alpha);
+        self.object.update_resource(queue);
+    }
+}
+
+impl fmt::Debug for AnimatedObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimatedObject")
+            .field("object", &self.object)
+            .field("time_sec", &self.time_sec)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Updatable for AnimatedObject {
+    #[inline]
+    fn update(&mut self, elapsed_time_sec: f32, queue: &wgpu::Queue) {
+        self.time_sec += elapsed_time_sec;
+        (self.animation)(&mut self.object, self.time_sec, elapsed_time_sec);
+        self.previous_transform = *self.object.world_transform_ref();
+        self.current_transform = self.previous_transform;
+        self.object.update_resource(queue);
     }
 }
 
+/// #### 한국어 </br>
+/// 두 월드 변환 행렬을 크기, 회전, 이동으로 나누어 `alpha`로 보간합니다. 회전은 구면 선형 보간을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Interpolates two world transforms by `alpha`, splitting them into scale, rotation and translation. The rotation uses spherical linear interpolation. </br>
+/// 
+pub fn interpolate_transform(from: &glam::Mat4, to: &glam::Mat4, alpha: f32) -> glam::Mat4 {
+    let (from_scale, from_rotation, from_translation) = from.to_scale_rotation_translation();
+    let (to_scale, to_rotation, to_translation) = to.to_scale_rotation_translation();
+    glam::Mat4::from_scale_rotation_translation(
+        from_scale.lerp(to_scale, alpha), 
+        from_rotation.slerp(to_rotation, alpha), 
+        from_translation.lerp(to_translation, alpha)
+    )
+}
+
 /// #### 한국어 </br>
 /// 쉐이더에서 사용하는 큐브 오브젝트 유니폼 데이터의 레아아웃 입니다. </br>
+/// `rim`은 (림 색상, 림 지수) 입니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This is the layout of the cube object uniform data used in the shader. </br>
+/// `rim` is (rim color, rim power). </br>
 /// 
 #[repr(C, align(16))]
 #[derive(Pod, Zeroable)]
@@ -263,6 +489,7 @@ impl ShaderResource for StdObject {
 pub struct ObjectUniformLayout {
     pub world: glam::Mat4, 
     pub color: glam::Vec4, 
+    pub rim: glam::Vec4, 
 }
 
 impl Default for ObjectUniformLayout {
@@ -271,6 +498,7 @@ impl Default for ObjectUniformLayout {
         Self { 
             world: glam::Mat4::IDENTITY, 
             color: glam::Vec4::ONE, 
+            rim: (0.0, 0.0, 0.0, 3.0).into(), 
         }
     }
 }
@@ -281,12 +509,20 @@ impl Default for ObjectUniformLayout {
 /// #### English (Translation) </br>
 /// This is the layout of the cube object vertex input data used in the shader. </br>
 /// 
+/// #### 한국어 </br>
+/// `tangent`의 xyz는 텍스처 좌표 u가 증가하는 방향이며, w는 종접선 `cross(normal, tangent.xyz) * w`가 v가 증가하는 방향을 향하도록 하는 부호 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The xyz of `tangent` is the direction in which the texture coordinate u increases, and w is the sign that makes the bitangent `cross(normal, tangent.xyz) * w` point in the direction in which v increases. </br>
+/// 
 #[repr(C)]
 #[derive(Pod, Zeroable)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ObjectVertexLayout {
     pub position: glam::Vec3, 
     pub normal: glam::Vec3, 
+    pub texcoord: glam::Vec2, 
+    pub tangent: glam::Vec4, 
 }
 
 impl Default for ObjectVertexLayout {
@@ -295,6 +531,8 @@ impl Default for ObjectVertexLayout {
         Self {
             position: glam::Vec3::ZERO, 
             normal: glam::Vec3::ZERO, 
+            texcoord: glam::Vec2::ZERO, 
+            tangent: glam::Vec4::ZERO, 
         }
     }
 }
@@ -0,0 +1,178 @@
+use std::mem;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering as MemOrdering};
+
+use crate::stats;
+
+
+
+const MAP_PENDING: u8 = 0;
+const MAP_SUCCEEDED: u8 = 1;
+const MAP_FAILED: u8 = 2;
+
+
+
+/// #### 한국어 </br>
+/// 오클루전 쿼리를 사용하여 이전 프레임에서 완전히 가려진 물체를 건너뛰는 컬링 입니다. </br>
+/// 보이는 물체는 쿼리 안에서 그대로 그려지고, 가려진 물체는 색상과 깊이를 기록하지 않는
+/// 대리(proxy) 상자로 쿼리 안에서 그려져 다시 보이게 되었는지 확인합니다. </br>
+/// 쿼리 결과는 한 프레임 늦게 읽히므로 GPU를 기다리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Culling that uses occlusion queries to skip objects that were fully occluded in the previous frame. </br>
+/// Visible objects are drawn as usual inside their query, and occluded objects are drawn inside their query as
+/// proxy boxes that write neither color nor depth, to check whether they have become visible again. </br>
+/// The query results are read one frame late, so it never waits for the GPU. </br>
+/// 
+#[derive(Debug)]
+pub struct OcclusionCulling {
+    visible: Vec<bool>, 
+    query_set: wgpu::QuerySet, 
+    resolve_buffer: wgpu::Buffer, 
+    readback_buffer: wgpu::Buffer, 
+    in_flight: bool, 
+    map_state: Arc<AtomicU8>, 
+}
+
+impl OcclusionCulling {
+    pub fn new(capacity: u32, device: &wgpu::Device) -> Self {
+        assert!(capacity > 0);
+
+        let query_set = device.create_query_set(
+            &wgpu::QuerySetDescriptor {
+                label: Some("QuerySet(Occlusion)"), 
+                ty: wgpu::QueryType::Occlusion, 
+                count: capacity, 
+            }, 
+        );
+
+        let size = (mem::size_of::<u64>() * capacity as usize) as wgpu::BufferAddress;
+        let resolve_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("QueryResolve(Occlusion)"), 
+                mapped_at_creation: false, 
+                size, 
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC, 
+            }, 
+        ));
+        let readback_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("QueryReadback(Occlusion)"), 
+                mapped_at_creation: false, 
+                size, 
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        Self {
+            visible: vec![true; capacity as usize], 
+            query_set, 
+            resolve_buffer, 
+            readback_buffer, 
+            in_flight: false, 
+            map_state: Arc::new(AtomicU8::new(MAP_PENDING)), 
+        }
+    }
+
+    #[inline]
+    pub fn query_set_ref(&self) -> &wgpu::QuerySet {
+        &self.query_set
+    }
+
+    /// #### 한국어 </br>
+    /// `index`번째 물체가 이전 결과에서 보였는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the `index`-th object was visible in the previous result. </br>
+    /// 
+    #[inline]
+    pub fn is_visible(&self, index: usize) -> bool {
+        self.visible[index]
+    }
+
+    #[inline]
+    pub fn num_occluded(&self) -> usize {
+        self.visible.iter().filter(|visible| !**visible).count()
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 물체를 보이는 상태로 되돌립니다. 컬링을 끌 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resets all objects to the visible state. Used when culling is turned off. </br>
+    /// 
+    #[inline]
+    pub fn reset(&mut self) {
+        self.visible.iter_mut().for_each(|visible| *visible = true);
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 프레임의 쿼리 결과가 준비되었으면 읽어서 가시성을 갱신합니다. </br>
+    /// 장치가 폴링된 후에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the query results of the previous frame are ready, reads them and updates the visibility. </br>
+    /// Must be called after the device has been polled. </br>
+    /// 
+    pub fn fetch_results(&mut self) {
+        if !self.in_flight {
+            return;
+        }
+
+        match self.map_state.swap(MAP_PENDING, MemOrdering::AcqRel) {
+            MAP_SUCCEEDED => { /*--- read the results ---*/ }, 
+            MAP_FAILED => {
+                self.in_flight = false;
+                return;
+            }, 
+            _ => return, 
+        }
+
+        {
+            let data = self.readback_buffer.slice(..).get_mapped_range();
+            let samples: &[u64] = bytemuck::cast_slice(&data);
+            for (visible, &count) in self.visible.iter_mut().zip(samples.iter()) {
+                *visible = count > 0;
+            }
+        }
+        self.readback_buffer.unmap();
+        self.in_flight = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 쿼리 결과를 resolve 하고 읽기 버퍼로 복사합니다. </br>
+    /// 이전 결과를 아직 읽고 있다면 이번 프레임의 결과는 버립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resolves the query results of this frame and copies them into the readback buffer. </br>
+    /// If the previous results are still being read, the results of this frame are discarded. </br>
+    /// 
+    pub fn resolve(&self, encoder: &mut wgpu::CommandEncoder, count: u32) {
+        if self.in_flight || count == 0 {
+            return;
+        }
+
+        let size = (mem::size_of::<u64>() * count as usize) as wgpu::BufferAddress;
+        encoder.resolve_query_set(&self.query_set, 0..count, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &self.readback_buffer, 0, size);
+    }
+
+    /// #### 한국어 </br>
+    /// 커맨드 버퍼가 제출된 후 읽기 버퍼의 매핑을 요청합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests mapping of the readback buffer after the command buffer has been submitted. </br>
+    /// 
+    pub fn request_results(&mut self) {
+        if self.in_flight {
+            return;
+        }
+
+        let map_state = self.map_state.clone();
+        self.readback_buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let state = if result.is_ok() { MAP_SUCCEEDED } else { MAP_FAILED };
+            map_state.store(state, MemOrdering::Release);
+        });
+        self.in_flight = true;
+    }
+}
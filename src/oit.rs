@@ -0,0 +1,175 @@
+use crate::target::{self, PooledTargetId, RenderTargetPool};
+
+
+
+/// #### 한국어 </br>
+/// 누적 렌더 타겟의 텍스처 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The texture format of the accumulation render target. </br>
+/// 
+pub const ACCUMULATION_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// #### 한국어 </br>
+/// 드러남(revealage) 렌더 타겟의 텍스처 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The texture format of the revealage render target. </br>
+/// 
+pub const REVEALAGE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
+
+
+
+/// #### 한국어 </br>
+/// 가중 혼합 순서 독립 투명도(WBOIT)에 사용되는 렌더 타겟들 입니다. </br>
+/// 반투명한 물체들은 정렬 없이 누적 타겟과 드러남 타겟에 그려지고, 
+/// 합성 단계에서 `bind_group`을 통해 읽혀 프레임 버퍼에 혼합됩니다. </br>
+/// 멀티샘플링을 사용하면 멀티샘플 타겟에 그린 후 렌더 타겟으로 리졸브합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The render targets used for weighted blended order-independent transparency (WBOIT). </br>
+/// Translucent objects are drawn into the accumulation and revealage targets without sorting, 
+/// and are read through `bind_group` in the composite stage and blended into the frame buffer. </br>
+/// With multisampling, they are drawn into the multisampled targets and resolved into the render targets. </br>
+/// 
+#[derive(Debug)]
+pub struct OitTargets {
+    accumulation_target: PooledTargetId, 
+    revealage_target: PooledTargetId, 
+    accumulation_view: wgpu::TextureView, 
+    revealage_view: wgpu::TextureView, 
+    multisampled_targets: Option<(PooledTargetId, PooledTargetId)>, 
+    multisampled_views: Option<(wgpu::TextureView, wgpu::TextureView)>, 
+    sampler: wgpu::Sampler, 
+    pub bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl OitTargets {
+    pub fn new(
+        pool: &mut RenderTargetPool, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device
+    ) -> Self {
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(OitComposite)"), 
+                ..Default::default()
+            }, 
+        );
+
+        let usage = wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING;
+        let accumulation_target = pool.acquire("Texture(OitAccumulation)", ACCUMULATION_FORMAT, usage, device);
+        let revealage_target = pool.acquire("Texture(OitRevealage)", REVEALAGE_FORMAT, usage, device);
+        let accumulation_view = pool.create_view(accumulation_target);
+        let revealage_view = pool.create_view(revealage_target);
+        let bind_group = create_bind_group(&accumulation_view, &revealage_view, &sampler, bind_group_layout, device);
+
+        let multisampled_targets = (target::sample_count() > 1).then(|| {
+            let usage = wgpu::TextureUsages::RENDER_ATTACHMENT;
+            (
+                pool.acquire_multisampled("Texture(OitAccumulationMultisampled)", ACCUMULATION_FORMAT, usage, target::sample_count(), device), 
+                pool.acquire_multisampled("Texture(OitRevealageMultisampled)", REVEALAGE_FORMAT, usage, target::sample_count(), device)
+            )
+        });
+        let multisampled_views = multisampled_targets.map(|(accumulation, revealage)| (pool.create_view(accumulation), pool.create_view(revealage)));
+
+        Self {
+            accumulation_target, 
+            revealage_target, 
+            accumulation_view, 
+            revealage_view, 
+            multisampled_targets, 
+            multisampled_views, 
+            sampler, 
+            bind_group
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟 풀의 크기가 바뀐 후 다시 생성된 렌더 타겟들을 묶습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the recreated render targets after the render target pool is resized. </br>
+    /// 
+    pub fn resize(
+        &mut self, 
+        pool: &RenderTargetPool, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device
+    ) {
+        self.accumulation_view = pool.create_view(self.accumulation_target);
+        self.revealage_view = pool.create_view(self.revealage_target);
+        self.multisampled_views = self.multisampled_targets.map(|(accumulation, revealage)| (pool.create_view(accumulation), pool.create_view(revealage)));
+        self.bind_group = create_bind_group(&self.accumulation_view, &self.revealage_view, &self.sampler, bind_group_layout, device);
+    }
+
+    #[inline]
+    pub fn accumulation_view_ref(&self) -> &wgpu::TextureView {
+        &self.accumulation_view
+    }
+
+    #[inline]
+    pub fn revealage_view_ref(&self) -> &wgpu::TextureView {
+        &self.revealage_view
+    }
+
+    /// #### 한국어 </br>
+    /// 누적 타겟과 드러남 타겟을 지우고 그리는 색상 첨부들을 반환합니다. </br>
+    /// 멀티샘플링을 사용하면 멀티샘플 타겟에 그린 후 렌더 타겟으로 리졸브합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the color attachments that clear and draw into the accumulation and revealage targets. </br>
+    /// With multisampling, they draw into the multisampled targets and resolve them into the render targets. </br>
+    /// 
+    pub fn color_attachments(&self) -> [Option<wgpu::RenderPassColorAttachment<'_>>; 2] {
+        [
+            color_attachment(&self.accumulation_view, self.multisampled_views.as_ref().map(|(view, _)| view), wgpu::Color::TRANSPARENT), 
+            color_attachment(&self.revealage_view, self.multisampled_views.as_ref().map(|(_, view)| view), wgpu::Color::WHITE), 
+        ]
+    }
+}
+
+fn color_attachment<'a>(
+    view: &'a wgpu::TextureView, 
+    multisampled_view: Option<&'a wgpu::TextureView>, 
+    clear_color: wgpu::Color
+) -> Option<wgpu::RenderPassColorAttachment<'a>> {
+    Some(wgpu::RenderPassColorAttachment {
+        view: multisampled_view.unwrap_or(view), 
+        resolve_target: multisampled_view.map(|_| view), 
+        ops: wgpu::Operations {
+            load: wgpu::LoadOp::Clear(clear_color), 
+            store: wgpu::StoreOp::Store, 
+        }, 
+    })
+}
+
+fn create_bind_group(
+    accumulation_view: &wgpu::TextureView, 
+    revealage_view: &wgpu::TextureView, 
+    sampler: &wgpu::Sampler, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    device: &wgpu::Device
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(OitComposite)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(accumulation_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::TextureView(revealage_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+            ], 
+        }, 
+    )
+}
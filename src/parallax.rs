@@ -0,0 +1,305 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 시차 차폐 매핑(parallax occlusion mapping) 재질을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a parallax occlusion mapping material. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParallaxMaterialBuilder {
+    pub height_scale: f32, 
+    pub min_steps: u32, 
+    pub max_steps: u32, 
+    pub tiling: f32, 
+    pub tiles_per_texture: u32, 
+    pub texture_size: u32, 
+}
+
+impl Default for ParallaxMaterialBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            height_scale: 0.06, 
+            min_steps: 8, 
+            max_steps: 32, 
+            tiling: 0.5, 
+            tiles_per_texture: 4, 
+            texture_size: 256
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ParallaxMaterialBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_height_scale(mut self, height_scale: f32) -> Self {
+        self.height_scale = height_scale.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 광선 진행 단계 수의 범위를 설정합니다. 표면을 비스듬히 볼수록 최대 단계 수에 가까워집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the range of the ray-marching step count. The more obliquely the surface is viewed, the closer it gets to the maximum step count. </br>
+    /// 
+    #[inline]
+    pub fn set_steps(mut self, min_steps: u32, max_steps: u32) -> Self {
+        self.min_steps = min_steps.max(1);
+        self.max_steps = max_steps.max(self.min_steps);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 로컬 공간의 단위 길이당 텍스처가 반복되는 횟수를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets how many times the texture repeats per unit length of local space. </br>
+    /// 
+    #[inline]
+    pub fn set_tiling(mut self, tiling: f32) -> Self {
+        self.tiling = tiling;
+        self
+    }
+
+    #[inline]
+    pub fn set_tiles_per_texture(mut self, tiles_per_texture: u32) -> Self {
+        self.tiles_per_texture = tiles_per_texture;
+        self
+    }
+
+    #[inline]
+    pub fn set_texture_size(mut self, texture_size: u32) -> Self {
+        self.texture_size = texture_size;
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> ParallaxMaterial {
+        assert!(self.texture_size > 0 && self.tiles_per_texture > 0);
+        assert!(self.min_steps > 0 && self.min_steps <= self.max_steps);
+
+        let size = self.texture_size;
+        let texture = stats::track_texture(device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(ParallaxSurface)"), 
+                size: wgpu::Extent3d {
+                    width: size, 
+                    height: size, 
+                    depth_or_array_layers: 1, 
+                }, 
+                dimension: wgpu::TextureDimension::D2, 
+                format: wgpu::TextureFormat::Rgba8Unorm, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        ));
+        queue.write_texture(
+            texture.as_image_copy(), 
+            &create_tile_surface(size, self.tiles_per_texture), 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(4 * size), 
+                rows_per_image: Some(size), 
+            }, 
+            texture.size(), 
+        );
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        });
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(ParallaxSurface)"), 
+                address_mode_u: wgpu::AddressMode::Repeat, 
+                address_mode_v: wgpu::AddressMode::Repeat, 
+                address_mode_w: wgpu::AddressMode::Repeat, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Parallax)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ParallaxUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Parallax)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::TextureView(&texture_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let material = ParallaxMaterial {
+            height_scale: self.height_scale, 
+            min_steps: self.min_steps, 
+            max_steps: self.max_steps, 
+            tiling: self.tiling, 
+            uniform_buffer, 
+            bind_group, 
+        };
+        material.update_resource(queue);
+
+        return material;
+    }
+}
+
+/// #### 한국어 </br>
+/// 깊이 맵을 따라 광선을 진행시켜 텍스처 좌표를 옮기는 시차 차폐 매핑 재질 입니다. </br>
+/// 표면 텍스처의 rgb에는 접선 공간의 법선이, a에는 표면 아래로의 깊이가 저장됩니다. </br>
+/// 텍스처 좌표와 접선은 로컬 공간의 XZ 평면에서 얻으므로 평면 메쉬에 사용해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A parallax occlusion mapping material that offsets texture coordinates by ray-marching along the depth map. </br>
+/// The rgb of the surface texture stores the tangent space normal, and a stores the depth below the surface. </br>
+/// The texture coordinates and tangents come from the XZ plane of local space, so it must be used with a plane mesh. </br>
+/// 
+#[derive(Debug)]
+pub struct ParallaxMaterial {
+    height_scale: f32, 
+    min_steps: u32, 
+    max_steps: u32, 
+    tiling: f32, 
+    uniform_buffer: wgpu::Buffer, 
+    pub bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl ParallaxMaterial {
+    #[inline]
+    pub fn height_scale(&self) -> f32 {
+        self.height_scale
+    }
+
+    /// #### 한국어 </br>
+    /// 높이 배율을 설정합니다. 0이면 시차 효과 없이 법선 매핑만 적용됩니다. </br>
+    /// `update_resource`를 호출해야 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the height scale. If it is 0, only normal mapping is applied without the parallax effect. </br>
+    /// It takes effect after calling `update_resource`. </br>
+    /// 
+    #[inline]
+    pub fn set_height_scale(&mut self, height_scale: f32) {
+        self.height_scale = height_scale.max(0.0);
+    }
+
+    #[inline]
+    pub fn steps(&self) -> (u32, u32) {
+        (self.min_steps, self.max_steps)
+    }
+
+    #[inline]
+    pub fn set_steps(&mut self, min_steps: u32, max_steps: u32) {
+        self.min_steps = min_steps.max(1);
+        self.max_steps = max_steps.max(self.min_steps);
+    }
+}
+
+impl ShaderResource for ParallaxMaterial {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = ParallaxUniformLayout {
+            params: (self.height_scale, self.min_steps as f32, self.max_steps as f32, self.tiling).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 시차 차폐 매핑 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`는 (높이 배율, 최소 단계 수, 최대 단계 수, 반복 횟수) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the parallax occlusion mapping uniform data used in the shader. </br>
+/// `params` is (height scale, min steps, max steps, tiling). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParallaxUniformLayout {
+    pub params: glam::Vec4, 
+}
+
+impl Default for ParallaxUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            params: (0.0, 8.0, 32.0, 1.0).into()
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 줄눈으로 나뉜 둥근 타일 무늬의 표면 텍스처를 생성합니다. </br>
+/// 법선은 반복되는 높이 맵의 중앙 차분으로 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a surface texture of rounded tiles separated by grout. </br>
+/// The normals are computed from central differences of the repeating height map. </br>
+/// 
+fn create_tile_surface(size: u32, tiles: u32) -> Vec<u8> {
+    let tile_height = |x: i64, y: i64| -> f32 {
+        let u = x.rem_euclid(size as i64) as f32 / size as f32 * tiles as f32;
+        let v = y.rem_euclid(size as i64) as f32 / size as f32 * tiles as f32;
+        // (한국어) 타일 가장자리까지의 거리로 경사진 모서리를 만듭니다.
+        // (English Translation) Makes beveled edges from the distance to the edge of the tile.
+        let edge = (u.fract().min(1.0 - u.fract())).min(v.fract().min(1.0 - v.fract()));
+        let bevel = ((edge - 0.03) / 0.12).clamp(0.0, 1.0);
+        bevel * bevel * (3.0 - 2.0 * bevel)
+    };
+
+    let strength = size as f32 / (8.0 * tiles as f32);
+    let mut pixels = Vec::with_capacity((4 * size * size) as usize);
+    for y in 0..size as i64 {
+        for x in 0..size as i64 {
+            let dx = tile_height(x + 1, y) - tile_height(x - 1, y);
+            let dy = tile_height(x, y + 1) - tile_height(x, y - 1);
+            let normal = glam::vec3(-dx * strength, -dy * strength, 1.0).normalize();
+            let encoded = (normal * 0.5 + 0.5) * 255.0;
+            let depth = (1.0 - tile_height(x, y)) * 255.0;
+            pixels.extend_from_slice(&[encoded.x as u8, encoded.y as u8, encoded.z as u8, depth as u8]);
+        }
+    }
+    return pixels;
+}
@@ -0,0 +1,263 @@
+use std::mem;
+use std::rc::Rc;
+use bytemuck::{Pod, Zeroable};
+
+use crate::object::{GameObject, StdObject, StdObjectBuilder};
+use crate::resource::{ShaderResource, UniformArena};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 금속성(metallic)과 거칠기(roughness)로 음영 처리되는 물리 기반 오브젝트를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a physically-based object shaded by its metallic and roughness. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PbrObjectBuilder {
+    pub object: StdObjectBuilder, 
+    pub metallic: f32, 
+    pub roughness: f32, 
+    pub ambient_occlusion: f32, 
+}
+
+impl Default for PbrObjectBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            object: StdObjectBuilder::default(), 
+            metallic: 0.0, 
+            roughness: 0.5, 
+            ambient_occlusion: 1.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl PbrObjectBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 표면의 기본 색상(albedo)을 설정합니다. 금속에서는 정반사의 색이 됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the base color (albedo) of the surface. For metals, it becomes the color of the specular reflection. </br>
+    /// 
+    #[inline]
+    pub fn set_albedo(mut self, albedo: glam::Vec3) -> Self {
+        self.object = self.object.set_color(albedo);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 금속성을 설정합니다. 0은 유전체, 1은 금속 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the metallic. 0 is a dielectric and 1 is a metal. </br>
+    /// 
+    #[inline]
+    pub fn set_metallic(mut self, metallic: f32) -> Self {
+        self.metallic = metallic.clamp(0.0, 1.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 거칠기를 설정합니다. 클수록 하이라이트가 넓고 흐려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the roughness. The larger it is, the wider and blurrier the highlight. </br>
+    /// 
+    #[inline]
+    pub fn set_roughness(mut self, roughness: f32) -> Self {
+        self.roughness = roughness.clamp(0.04, 1.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 주변광에 곱해지는 차폐 정도를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the occlusion multiplied by the ambient light. </br>
+    /// 
+    #[inline]
+    pub fn set_ambient_occlusion(mut self, ambient_occlusion: f32) -> Self {
+        self.ambient_occlusion = ambient_occlusion.clamp(0.0, 1.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.object = self.object.set_translation(translation);
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.object = self.object.set_rotation(rotation);
+        self
+    }
+
+    pub fn build(
+        self, 
+        uniforms: &Rc<UniformArena>, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> PbrObject {
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Pbr)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<PbrUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Pbr)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let object = PbrObject {
+            object: self.object.build(uniforms, queue), 
+            metallic: self.metallic, 
+            roughness: self.roughness, 
+            ambient_occlusion: self.ambient_occlusion, 
+            uniform_buffer, 
+            bind_group, 
+        };
+        object.update_resource(queue);
+
+        return object;
+    }
+}
+
+/// #### 한국어 </br>
+/// 쿡-토런스(Cook-Torrance) 반사 모델로 그려지는 물리 기반 오브젝트 입니다. </br>
+/// 변환과 색상은 표준 오브젝트로 1번 집합에, 재질은 `bind_group`으로 5번 집합에 바인딩됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A physically-based object drawn with the Cook-Torrance reflection model. </br>
+/// The transform and color are bound to set 1 as a standard object, and the material to set 5 as `bind_group`. </br>
+/// 
+#[derive(Debug)]
+pub struct PbrObject {
+    object: StdObject, 
+    metallic: f32, 
+    roughness: f32, 
+    ambient_occlusion: f32, 
+    uniform_buffer: wgpu::Buffer, 
+    pub bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl PbrObject {
+    #[inline]
+    pub fn object_ref(&self) -> &StdObject {
+        &self.object
+    }
+
+    #[inline]
+    pub fn metallic(&self) -> f32 {
+        self.metallic
+    }
+
+    /// #### 한국어 </br>
+    /// 금속성을 설정합니다. `update_resource`를 호출해야 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the metallic. It takes effect after calling `update_resource`. </br>
+    /// 
+    #[inline]
+    pub fn set_metallic(&mut self, metallic: f32) {
+        self.metallic = metallic.clamp(0.0, 1.0);
+    }
+
+    #[inline]
+    pub fn roughness(&self) -> f32 {
+        self.roughness
+    }
+
+    /// #### 한국어 </br>
+    /// 거칠기를 설정합니다. `update_resource`를 호출해야 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the roughness. It takes effect after calling `update_resource`. </br>
+    /// 
+    #[inline]
+    pub fn set_roughness(&mut self, roughness: f32) {
+        self.roughness = roughness.clamp(0.04, 1.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트 유니폼 데이터를 `index`번 바인드 그룹에 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the object uniform data to bind group `index`. </br>
+    /// 
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, index: u32) {
+        self.object.bind(rpass, index);
+    }
+}
+
+impl GameObject for PbrObject {
+    #[inline]
+    fn world_transform_ref(&self) -> &glam::Mat4 {
+        self.object.world_transform_ref()
+    }
+
+    #[inline]
+    fn world_transform_mut(&mut self) -> &mut glam::Mat4 {
+        self.object.world_transform_mut()
+    }
+}
+
+impl ShaderResource for PbrObject {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        self.object.update_resource(queue);
+        let data = PbrUniformLayout {
+            params: (self.metallic, self.roughness, self.ambient_occlusion, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 물리 기반 재질 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`는 (금속성, 거칠기, 주변광 차폐, 사용하지 않음) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the physically-based material uniform data used in the shader. </br>
+/// `params` is (metallic, roughness, ambient occlusion, unused). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PbrUniformLayout {
+    pub params: glam::Vec4, 
+}
+
+impl Default for PbrUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            params: (0.0, 0.5, 1.0, 0.0).into()
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+use std::collections::HashMap;
+use rapier3d::na;
+use rapier3d::prelude::*;
+
+use crate::collision::CollisionShape;
+
+
+
+/// #### 한국어 </br>
+/// 강체가 시뮬레이션에서 움직이는 방식 입니다. </br>
+/// - `Dynamic`: 중력과 접촉에 의해 움직이며, 시뮬레이션 결과를 물체에 반영합니다. </br>
+/// - `Static`: 움직이지 않습니다. </br>
+/// - `Kinematic`: 물체의 변환 행렬을 따라 움직이며, 닿은 동적 강체들을 밀어냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// How a rigid body moves in the simulation. </br>
+/// - `Dynamic`: Moved by gravity and contacts, and the simulation result is mirrored to the object. </br>
+/// - `Static`: Does not move. </br>
+/// - `Kinematic`: Follows the transform matrix of the object, and pushes away the dynamic bodies it touches. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyKind {
+    Dynamic, 
+    Static, 
+    Kinematic, 
+}
+
+/// #### 한국어 </br>
+/// 물리 세계에 등록된 강체를 가리키는 식별자 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An identifier pointing to a rigid body registered in the physics world. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BodyId(RigidBodyHandle);
+
+/// #### 한국어 </br>
+/// `rapier` 물리 파이프라인을 고정된 시간 간격마다 실행하는 물리 세계 입니다. </br>
+/// 강체의 변환 행렬은 양방향으로 반영됩니다: `push_transform`은 물체의 변환 행렬을 강체에 옮기고, 
+/// `pull_transform`은 시뮬레이션된 강체의 위치와 회전을 물체에 옮깁니다. </br>
+/// 강체는 크기 변환을 가질 수 없으므로 충돌 모양의 크기는 등록할 때의 크기 변환으로 정해집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A physics world that runs the `rapier` physics pipeline at a fixed time step. </br>
+/// Transform matrices of the rigid bodies are mirrored both ways: `push_transform` moves the transform matrix of the object to the body, 
+/// and `pull_transform` moves the position and rotation of the simulated body to the object. </br>
+/// Rigid bodies cannot have a scale, so the size of the collision shape is fixed by the scale at registration. </br>
+/// 
+pub struct PhysicsWorld<T> {
+    fixed_time_step: f32, 
+    accumulated_time: f32, 
+    max_steps_per_frame: u32, 
+    gravity: Vector<Real>, 
+    integration_parameters: IntegrationParameters, 
+    physics_pipeline: PhysicsPipeline, 
+    island_manager: IslandManager, 
+    broad_phase: BroadPhase, 
+    narrow_phase: NarrowPhase, 
+    bodies: RigidBodySet, 
+    colliders: ColliderSet, 
+    impulse_joints: ImpulseJointSet, 
+    multibody_joints: MultibodyJointSet, 
+    ccd_solver: CCDSolver, 
+    query_pipeline: QueryPipeline, 
+    owners: HashMap<RigidBodyHandle, T>, 
+}
+
+impl<T: fmt::Debug> fmt::Debug for PhysicsWorld<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PhysicsWorld")
+            .field("fixed_time_step", &self.fixed_time_step)
+            .field("accumulated_time", &self.accumulated_time)
+            .field("gravity", &self.gravity)
+            .field("num_bodies", &self.bodies.len())
+            .field("num_colliders", &self.colliders.len())
+            .field("owners", &self.owners)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: fmt::Debug + Clone> Default for PhysicsWorld<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(1.0 / 60.0)
+    }
+}
+
+#[allow(dead_code)]
+impl<T: fmt::Debug + Clone> PhysicsWorld<T> {
+    pub fn new(fixed_time_step: f32) -> Self {
+        assert!(fixed_time_step > 0.0);
+        Self {
+            fixed_time_step, 
+            accumulated_time: 0.0, 
+            max_steps_per_frame: 5, 
+            gravity: vector![0.0, -9.81, 0.0], 
+            integration_parameters: IntegrationParameters {
+                dt: fixed_time_step, 
+                ..Default::default()
+            }, 
+            physics_pipeline: PhysicsPipeline::new(), 
+            island_manager: IslandManager::new(), 
+            broad_phase: BroadPhase::new(), 
+            narrow_phase: NarrowPhase::new(), 
+            bodies: RigidBodySet::new(), 
+            colliders: ColliderSet::new(), 
+            impulse_joints: ImpulseJointSet::new(), 
+            multibody_joints: MultibodyJointSet::new(), 
+            ccd_solver: CCDSolver::new(), 
+            query_pipeline: QueryPipeline::new(), 
+            owners: HashMap::new()
+        }
+    }
+
+    #[inline]
+    pub fn fixed_time_step(&self) -> f32 {
+        self.fixed_time_step
+    }
+
+    #[inline]
+    pub fn gravity(&self) -> glam::Vec3 {
+        glam::vec3(self.gravity.x, self.gravity.y, self.gravity.z)
+    }
+
+    #[inline]
+    pub fn set_gravity(&mut self, gravity: glam::Vec3) {
+        self.gravity = vector![gravity.x, gravity.y, gravity.z];
+    }
+
+    #[inline]
+    pub fn num_bodies(&self) -> usize {
+        self.bodies.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 잠들지 않은 동적 강체의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of dynamic bodies that are not asleep. </br>
+    /// 
+    #[inline]
+    pub fn num_awake_bodies(&self) -> usize {
+        self.island_manager.active_dynamic_bodies().len()
+    }
+
+    #[inline]
+    pub fn owner(&self, id: BodyId) -> Option<T> {
+        self.owners.get(&id.0).cloned()
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 월드 변환 행렬에 놓인 강체를 등록합니다. 동적 강체의 질량은 밀도 1로 계산됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers a rigid body placed at the given world transform matrix. The mass of a dynamic body is computed with a density of 1. </br>
+    /// 
+    pub fn add_body(&mut self, owner: T, kind: BodyKind, shape: CollisionShape, transform: &glam::Mat4) -> BodyId {
+        let (scale, isometry) = to_isometry(transform);
+        let builder = match kind {
+            BodyKind::Dynamic => RigidBodyBuilder::dynamic(), 
+            BodyKind::Static => RigidBodyBuilder::fixed(), 
+            BodyKind::Kinematic => RigidBodyBuilder::kinematic_position_based(), 
+        };
+        let handle = self.bodies.insert(builder.position(isometry).build());
+
+        let collider = match shape {
+            CollisionShape::Sphere { radius } => ColliderBuilder::ball(radius * scale.max_element()), 
+            CollisionShape::Aabb { half_extents } | CollisionShape::Obb { half_extents } => {
+                let half_extents = half_extents * scale;
+                ColliderBuilder::cuboid(half_extents.x, half_extents.y, half_extents.z)
+            }, 
+        };
+        self.colliders.insert_with_parent(
+            collider.density(1.0).friction(0.7).build(), 
+            handle, 
+            &mut self.bodies
+        );
+
+        self.owners.insert(handle, owner);
+        return BodyId(handle);
+    }
+
+    #[inline]
+    pub fn add_dynamic_body(&mut self, owner: T, shape: CollisionShape, transform: &glam::Mat4) -> BodyId {
+        self.add_body(owner, BodyKind::Dynamic, shape, transform)
+    }
+
+    #[inline]
+    pub fn add_static_body(&mut self, owner: T, shape: CollisionShape, transform: &glam::Mat4) -> BodyId {
+        self.add_body(owner, BodyKind::Static, shape, transform)
+    }
+
+    /// #### 한국어 </br>
+    /// 강체와 강체에 붙은 충돌체들을 제거합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes the rigid body and the colliders attached to it. </br>
+    /// 
+    pub fn remove(&mut self, id: BodyId) -> Option<T> {
+        self.bodies.remove(
+            id.0, 
+            &mut self.island_manager, 
+            &mut self.colliders, 
+            &mut self.impulse_joints, 
+            &mut self.multibody_joints, 
+            true
+        )?;
+        self.owners.remove(&id.0)
+    }
+
+    /// #### 한국어 </br>
+    /// 물체의 월드 변환 행렬을 강체에 반영합니다. </br>
+    /// 운동학적 강체는 다음 고정 갱신 동안 그 위치로 움직이고, 다른 강체는 그 위치로 순간 이동합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Mirrors the world transform matrix of the object to the rigid body. </br>
+    /// A kinematic body moves to that position during the next fixed update, and other bodies teleport to it. </br>
+    /// 
+    pub fn push_transform(&mut self, id: BodyId, transform: &glam::Mat4) {
+        let (_, isometry) = to_isometry(transform);
+        let body = self.bodies.get_mut(id.0).expect("invalid body id");
+        if body.is_kinematic() {
+            body.set_next_kinematic_position(isometry);
+        } else {
+            body.set_position(isometry, true);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 강체의 위치와 회전을 물체의 월드 변환 행렬에 반영합니다. 물체의 크기 변환은 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Mirrors the position and rotation of the rigid body to the world transform matrix of the object. The scale of the object is kept. </br>
+    /// 
+    pub fn pull_transform(&self, id: BodyId, transform: &mut glam::Mat4) {
+        let body = self.bodies.get(id.0).expect("invalid body id");
+        let (scale, _, _) = transform.to_scale_rotation_translation();
+        let (rotation, translation) = from_isometry(body.position());
+        *transform = glam::Mat4::from_scale_rotation_translation(scale, rotation, translation);
+    }
+
+    /// #### 한국어 </br>
+    /// 강체의 선속도와 각속도를 설정하고 강체를 깨웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the linear and angular velocities of the rigid body and wakes it up. </br>
+    /// 
+    pub fn set_velocity(&mut self, id: BodyId, linear: glam::Vec3, angular: glam::Vec3) {
+        let body = self.bodies.get_mut(id.0).expect("invalid body id");
+        body.set_linvel(vector![linear.x, linear.y, linear.z], true);
+        body.set_angvel(vector![angular.x, angular.y, angular.z], true);
+    }
+
+    /// #### 한국어 </br>
+    /// 지난 프레임의 시간을 누적하고 누적된 만큼 고정 갱신을 실행합니다. 실행한 갱신의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Accumulates the time of the last frame and runs as many fixed updates as accumulated. Returns the number of updates run. </br>
+    /// 
+    pub fn advance(&mut self, elapsed_time_sec: f32) -> u32 {
+        self.accumulated_time += elapsed_time_sec;
+        let mut steps = 0;
+        while self.accumulated_time >= self.fixed_time_step && steps < self.max_steps_per_frame {
+            self.accumulated_time -= self.fixed_time_step;
+            self.fixed_update();
+            steps += 1;
+        }
+        self.accumulated_time = self.accumulated_time.min(self.fixed_time_step);
+        return steps;
+    }
+
+    /// #### 한국어 </br>
+    /// 물리 파이프라인을 한 번 실행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs the physics pipeline once. </br>
+    /// 
+    pub fn fixed_update(&mut self) {
+        self.physics_pipeline.step(
+            &self.gravity, 
+            &self.integration_parameters, 
+            &mut self.island_manager, 
+            &mut self.broad_phase, 
+            &mut self.narrow_phase, 
+            &mut self.bodies, 
+            &mut self.colliders, 
+            &mut self.impulse_joints, 
+            &mut self.multibody_joints, 
+            &mut self.ccd_solver, 
+            Some(&mut self.query_pipeline), 
+            &(), 
+            &()
+        );
+    }
+}
+
+/// #### 한국어 </br>
+/// 월드 변환 행렬을 크기 변환과 강체 변환으로 나눕니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Splits a world transform matrix into a scale and a rigid transform. </br>
+/// 
+fn to_isometry(transform: &glam::Mat4) -> (glam::Vec3, Isometry<Real>) {
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    let rotation = na::UnitQuaternion::from_quaternion(
+        na::Quaternion::new(rotation.w, rotation.x, rotation.y, rotation.z)
+    );
+    let translation = na::Translation3::new(translation.x, translation.y, translation.z);
+    (scale, Isometry::from_parts(translation, rotation))
+}
+
+fn from_isometry(isometry: &Isometry<Real>) -> (glam::Quat, glam::Vec3) {
+    let rotation = isometry.rotation.quaternion();
+    let translation = isometry.translation.vector;
+    (
+        glam::quat(rotation.i, rotation.j, rotation.k, rotation.w), 
+        glam::vec3(translation.x, translation.y, translation.z)
+    )
+}
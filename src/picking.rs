@@ -0,0 +1,251 @@
+use std::mem;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering as MemOrdering};
+
+use crate::target::{self, PooledTargetId, RenderTargetPool};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 물체 번호가 그려지는 ID 타겟의 텍스처 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The texture format of the ID target the object indices are drawn into. </br>
+/// 
+pub const ID_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R32Uint;
+
+/// #### 한국어 </br>
+/// 아무 물체도 그려지지 않은 픽셀의 번호 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The id of a pixel where no object is drawn. </br>
+/// 
+pub const NO_OBJECT: u32 = 0;
+
+const MAP_PENDING: u8 = 0;
+const MAP_SUCCEEDED: u8 = 1;
+const MAP_FAILED: u8 = 2;
+
+
+
+/// #### 한국어 </br>
+/// `index`번째 물체를 ID 패스에서 그릴 때 사용하는 번호를 반환합니다. </br>
+/// 번호는 그리기 호출의 첫 번째 인스턴스로 쉐이더에 전달됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the id used when drawing the `index`-th object in the ID pass. </br>
+/// The id is passed to the shader as the first instance of the draw call. </br>
+/// 
+#[inline]
+pub fn object_id(index: usize) -> u32 {
+    index as u32 + 1
+}
+
+
+
+/// #### 한국어 </br>
+/// 물체 번호를 R32Uint 타겟에 그린 후 커서 아래 픽셀을 읽어 물체를 고르는 ID 버퍼 피킹 입니다. </br>
+/// 메쉬의 실제 모양대로 그려지므로 경계 상자 대신 픽셀 단위로 정확하게 고를 수 있습니다. </br>
+/// 결과는 오클루전 쿼리와 같이 한 프레임 늦게 읽히므로 GPU를 기다리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// ID buffer picking that draws object ids into an R32Uint target and reads the pixel under the cursor to pick an object. </br>
+/// Since the meshes are drawn with their actual shapes, picking is pixel-perfect instead of using bounding boxes. </br>
+/// Like the occlusion queries, the result is read one frame late, so it never waits for the GPU. </br>
+/// 
+#[derive(Debug)]
+pub struct IdPicker {
+    width: u32, 
+    height: u32, 
+    id_target: PooledTargetId, 
+    id_view: wgpu::TextureView, 
+    depth_target: PooledTargetId, 
+    depth_view: wgpu::TextureView, 
+    readback_buffer: wgpu::Buffer, 
+    requested: Option<(u32, u32)>, 
+    copied: bool, 
+    in_flight: bool, 
+    map_state: Arc<AtomicU8>, 
+    picked: Option<usize>, 
+}
+
+impl IdPicker {
+    pub fn new(pool: &mut RenderTargetPool, device: &wgpu::Device) -> Self {
+        let usage = wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC;
+        let id_target = pool.acquire("Texture(Id)", ID_FORMAT, usage, device);
+        let depth_target = pool.acquire("Texture(IdDepth)", target::depth_format(), usage, device);
+
+        // (한국어) 텍스처 복사는 행 단위로 정렬되어야 하므로 한 픽셀을 읽을 때에도 한 행 크기의 버퍼를 사용합니다.
+        // (English Translation) Texture copies must be row aligned, so a buffer of one row is used even when reading a single pixel.
+        let readback_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Readback(Id)"), 
+                mapped_at_creation: false, 
+                size: wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        Self {
+            width: pool.width(), 
+            height: pool.height(), 
+            id_target, 
+            id_view: pool.create_view(id_target), 
+            depth_target, 
+            depth_view: pool.create_view(depth_target), 
+            readback_buffer, 
+            requested: None, 
+            copied: false, 
+            in_flight: false, 
+            map_state: Arc::new(AtomicU8::new(MAP_PENDING)), 
+            picked: None, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟 풀의 크기가 바뀐 후 다시 생성된 ID 타겟을 사용합니다. 대기 중인 요청은 취소됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uses the recreated ID target after the render target pool is resized. The pending request is canceled. </br>
+    /// 
+    pub fn resize(&mut self, pool: &RenderTargetPool) {
+        self.id_view = pool.create_view(self.id_target);
+        self.depth_view = pool.create_view(self.depth_target);
+        self.width = pool.width();
+        self.height = pool.height();
+        self.requested = None;
+    }
+
+    #[inline]
+    pub fn id_view_ref(&self) -> &wgpu::TextureView {
+        &self.id_view
+    }
+
+    #[inline]
+    pub fn depth_view_ref(&self) -> &wgpu::TextureView {
+        &self.depth_view
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 고른 물체의 번호를 반환합니다. 빈 곳을 고른 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the index of the last picked object. Returns `None` if an empty spot was picked. </br>
+    /// 
+    #[inline]
+    pub fn picked(&self) -> Option<usize> {
+        self.picked
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 화면 좌표의 물체를 고르도록 요청합니다. 화면 밖의 좌표는 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests picking the object at the given screen coordinates. Coordinates outside the screen are ignored. </br>
+    /// 
+    pub fn request_pick(&mut self, x: f64, y: f64) {
+        if x < 0.0 || y < 0.0 || x >= self.width as f64 || y >= self.height as f64 {
+            return;
+        }
+        self.requested = Some((x as u32, y as u32));
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 ID 패스를 그려야 하는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the ID pass must be drawn in this frame. </br>
+    /// 
+    #[inline]
+    pub fn needs_pass(&self) -> bool {
+        self.requested.is_some() && !self.in_flight
+    }
+
+    /// #### 한국어 </br>
+    /// 이전에 요청한 픽셀을 읽었으면 고른 물체를 갱신합니다. </br>
+    /// 장치가 폴링된 후에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the previously requested pixel has been read, updates the picked object. </br>
+    /// Must be called after the device has been polled. </br>
+    /// 
+    pub fn fetch_result(&mut self) {
+        if !self.in_flight {
+            return;
+        }
+
+        match self.map_state.swap(MAP_PENDING, MemOrdering::AcqRel) {
+            MAP_SUCCEEDED => { /*--- read the result ---*/ }, 
+            MAP_FAILED => {
+                self.in_flight = false;
+                return;
+            }, 
+            _ => return, 
+        }
+
+        {
+            let data = self.readback_buffer.slice(..mem::size_of::<u32>() as wgpu::BufferAddress).get_mapped_range();
+            let id: u32 = bytemuck::cast_slice(&data)[0];
+            self.picked = (id != NO_OBJECT).then(|| (id - 1) as usize);
+        }
+        self.readback_buffer.unmap();
+        self.in_flight = false;
+    }
+
+    /// #### 한국어 </br>
+    /// ID 패스가 끝난 후 요청한 픽셀을 읽기 버퍼로 복사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Copies the requested pixel into the readback buffer after the ID pass. </br>
+    /// 
+    pub fn copy_pixel(&mut self, pool: &RenderTargetPool, encoder: &mut wgpu::CommandEncoder) {
+        if !self.needs_pass() {
+            return;
+        }
+
+        let (x, y) = self.requested.take().unwrap();
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: pool.texture_ref(self.id_target), 
+                mip_level: 0, 
+                origin: wgpu::Origin3d { x, y, z: 0 }, 
+                aspect: wgpu::TextureAspect::All, 
+            }, 
+            wgpu::ImageCopyBuffer {
+                buffer: &self.readback_buffer, 
+                layout: wgpu::ImageDataLayout {
+                    offset: 0, 
+                    bytes_per_row: Some(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT), 
+                    rows_per_image: Some(1), 
+                }, 
+            }, 
+            wgpu::Extent3d {
+                width: 1, 
+                height: 1, 
+                depth_or_array_layers: 1, 
+            }, 
+        );
+        self.copied = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 커맨드 버퍼가 제출된 후 읽기 버퍼의 매핑을 요청합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests mapping of the readback buffer after the command buffer has been submitted. </br>
+    /// 
+    pub fn request_result(&mut self) {
+        if !self.copied {
+            return;
+        }
+
+        let map_state = self.map_state.clone();
+        self.readback_buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let state = if result.is_ok() { MAP_SUCCEEDED } else { MAP_FAILED };
+            map_state.store(state, MemOrdering::Release);
+        });
+        self.copied = false;
+        self.in_flight = true;
+    }
+}
@@ -1,42 +1,1795 @@
+use std::fmt;
 use std::mem;
+use std::rc::Rc;
+use std::collections::{HashMap, HashSet};
 
+use crate::debug::DebugLineVertexLayout;
+use crate::deferred;
+use crate::instance::InstanceLayout;
 use crate::object::ObjectVertexLayout;
+use crate::oit;
+use crate::picking;
+use crate::postprocess;
+use crate::shader::{include_shader, include_wgsl_shader};
+use crate::skybox::SkyboxVertexLayout;
+use crate::sprite::SpriteVertexLayout;
+use crate::target;
+use crate::text::TextVertexLayout;
 
 
 
+/// #### 한국어 </br>
+/// 캐시된 파이프라인이 사용하는 쉐이더 입니다. 이름으로 비교되며, 필요할 때 `load`로 쉐이더 모듈 서술자를 가져옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A shader used by the cached pipelines. It is compared by its name, and `load` gets the shader module descriptor when needed. </br>
+/// 
+#[derive(Clone, Copy)]
+pub struct CachedShader {
+    pub name: &'static str, 
+    pub load: fn() -> wgpu::ShaderModuleDescriptor<'static>, 
+}
+
+impl fmt::Debug for CachedShader {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CachedShader").field(&self.name).finish()
+    }
+}
+
+/// #### 한국어 </br>
+/// `shaders/` 폴더의 쉐이더 이름으로 `CachedShader`를 만듭니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Makes a `CachedShader` from the name of a shader in the `shaders/` directory. </br>
+/// 
+macro_rules! cached_shader {
+    ($name:literal) => {
+        CachedShader { name: $name, load: || include_shader!($name) }
+    };
+}
+
+/// #### 한국어 </br>
+/// 캐시된 파이프라인이 사용하는 정점 버퍼 레이아웃의 종류 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The kinds of vertex buffer layouts used by the cached pipelines. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexLayoutKind {
+    /// #### 한국어 </br>
+    /// 정점 버퍼 없이 정점 번호로 정점을 만듭니다. (예: 전체 화면 삼각형) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Makes the vertices from the vertex index without a vertex buffer. (e.g. a fullscreen triangle) </br>
+    /// 
+    None, 
+    Object, 
+    ObjectInstanced, 
+    /// #### 한국어 </br>
+    /// 위치와 법선에 더해 텍스처 좌표를 2번 위치로, 접선을 3번 위치로 넘깁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Passes the texture coordinates at location 2 and the tangent at location 3 in addition to the position and normal. </br>
+    /// 
+    Textured, 
+}
+
+const OBJECT_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 2] = [
+    wgpu::VertexAttribute {
+        shader_location: 0, 
+        format: wgpu::VertexFormat::Float32x3, 
+        offset: mem::offset_of!(ObjectVertexLayout, position) as wgpu::BufferAddress, 
+    }, 
+    wgpu::VertexAttribute {
+        shader_location: 1, 
+        format: wgpu::VertexFormat::Float32x3, 
+        offset: mem::offset_of!(ObjectVertexLayout, normal) as wgpu::BufferAddress, 
+    }, 
+];
+
+const TEXTURED_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 4] = [
+    OBJECT_VERTEX_ATTRIBUTES[0], 
+    OBJECT_VERTEX_ATTRIBUTES[1], 
+    wgpu::VertexAttribute {
+        shader_location: 2, 
+        format: wgpu::VertexFormat::Float32x2, 
+        offset: mem::offset_of!(ObjectVertexLayout, texcoord) as wgpu::BufferAddress, 
+    }, 
+    wgpu::VertexAttribute {
+        shader_location: 3, 
+        format: wgpu::VertexFormat::Float32x4, 
+        offset: mem::offset_of!(ObjectVertexLayout, tangent) as wgpu::BufferAddress, 
+    }, 
+];
+
+const INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 5] = [
+    wgpu::VertexAttribute {
+        shader_location: 2, 
+        format: wgpu::VertexFormat::Float32x4, 
+        offset: mem::offset_of!(InstanceLayout, world) as wgpu::BufferAddress, 
+    }, 
+    wgpu::VertexAttribute {
+        shader_location: 3, 
+        format: wgpu::VertexFormat::Float32x4, 
+        offset: (mem::offset_of!(InstanceLayout, world) + mem::size_of::<glam::Vec4>()) as wgpu::BufferAddress, 
+    }, 
+    wgpu::VertexAttribute {
+        shader_location: 4, 
+        format: wgpu::VertexFormat::Float32x4, 
+        offset: (mem::offset_of!(InstanceLayout, world) + 2 * mem::size_of::<glam::Vec4>()) as wgpu::BufferAddress, 
+    }, 
+    wgpu::VertexAttribute {
+        shader_location: 5, 
+        format: wgpu::VertexFormat::Float32x4, 
+        offset: (mem::offset_of!(InstanceLayout, world) + 3 * mem::size_of::<glam::Vec4>()) as wgpu::BufferAddress, 
+    }, 
+    wgpu::VertexAttribute {
+        shader_location: 6, 
+        format: wgpu::VertexFormat::Float32x4, 
+        offset: mem::offset_of!(InstanceLayout, color) as wgpu::BufferAddress, 
+    }, 
+];
+
+const OBJECT_VERTEX_BUFFER: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
+    step_mode: wgpu::VertexStepMode::Vertex, 
+    array_stride: mem::size_of::<ObjectVertexLayout>() as wgpu::BufferAddress, 
+    attributes: &OBJECT_VERTEX_ATTRIBUTES, 
+};
+
+const TEXTURED_VERTEX_BUFFER: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
+    step_mode: wgpu::VertexStepMode::Vertex, 
+    array_stride: mem::size_of::<ObjectVertexLayout>() as wgpu::BufferAddress, 
+    attributes: &TEXTURED_VERTEX_ATTRIBUTES, 
+};
+
+const INSTANCE_BUFFER: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
+    step_mode: wgpu::VertexStepMode::Instance, 
+    array_stride: mem::size_of::<InstanceLayout>() as wgpu::BufferAddress, 
+    attributes: &INSTANCE_ATTRIBUTES, 
+};
+
+impl VertexLayoutKind {
+    #[inline]
+    pub fn buffers(self) -> &'static [wgpu::VertexBufferLayout<'static>] {
+        match self {
+            Self::None => &[], 
+            Self::Object => &[OBJECT_VERTEX_BUFFER], 
+            Self::ObjectInstanced => &[OBJECT_VERTEX_BUFFER, INSTANCE_BUFFER], 
+            Self::Textured => &[TEXTURED_VERTEX_BUFFER], 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 캐시된 렌더 파이프라인의 서술 입니다. 삼각형 목록을 반시계 방향 앞면으로 그리며, 색상 타겟은 하나 이하 입니다. </br>
+/// 이름표를 뺀 나머지가 모두 같은 서술은 같은 파이프라인을 공유합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The description of a cached render pipeline. It draws triangle lists with counter-clockwise front faces, and has at most one color target. </br>
+/// Descriptions that are equal except for the label share the same pipeline. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct PipelineDesc {
+    pub label: &'static str, 
+    pub vertex_shader: CachedShader, 
+    pub fragment_shader: Option<CachedShader>, 
+    pub vertex_layout: VertexLayoutKind, 
+    pub cull_mode: Option<wgpu::Face>, 
+    pub color_target: Option<wgpu::ColorTargetState>, 
+    pub depth_stencil: Option<wgpu::DepthStencilState>, 
+    pub multisample: wgpu::MultisampleState, 
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PipelineKey {
+    vertex_shader: &'static str, 
+    fragment_shader: Option<&'static str>, 
+    vertex_layout: VertexLayoutKind, 
+    cull_mode: Option<wgpu::Face>, 
+    color_target: Option<wgpu::ColorTargetState>, 
+    depth_stencil: Option<wgpu::DepthStencilState>, 
+    multisample: wgpu::MultisampleState, 
+    bind_group_layouts: Vec<wgpu::Id<wgpu::BindGroupLayout>>, 
+}
+
+impl PipelineKey {
+    #[inline]
+    fn uses_any(&self, shader_names: &HashSet<String>) -> bool {
+        shader_names.contains(self.vertex_shader) 
+        || self.fragment_shader.is_some_and(|name| shader_names.contains(name))
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더, 정점 레이아웃, 타겟 형식, 깊이 상태와 바인드 그룹 레이아웃으로 렌더 파이프라인을 기억하는 캐시 입니다. </br>
+/// 같은 서술을 다시 요청하면 새로 만들지 않고 기억된 파이프라인을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A cache that memoizes render pipelines by the shaders, vertex layout, target format, depth state and bind group layouts. </br>
+/// Requesting the same description again returns the memoized pipeline instead of creating a new one. </br>
+/// 
+#[derive(Debug, Default)]
+pub struct PipelineCache {
+    shaders: HashMap<&'static str, Rc<wgpu::ShaderModule>>, 
+    pipelines: HashMap<PipelineKey, Rc<wgpu::RenderPipeline>>, 
+}
+
+#[allow(dead_code)]
+impl PipelineCache {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn num_pipelines(&self) -> usize {
+        self.pipelines.len()
+    }
+
+    #[inline]
+    pub fn num_shaders(&self) -> usize {
+        self.shaders.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 서술과 바인드 그룹 레이아웃에 맞는 파이프라인을 반환합니다. 없으면 새로 만들어 기억합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the pipeline matching the description and bind group layouts. If there is none, creates and memoizes a new one. </br>
+    /// 
+    pub fn get_or_create(
+        &mut self, 
+        desc: &PipelineDesc, 
+        bind_group_layouts: &[&wgpu::BindGroupLayout], 
+        device: &wgpu::Device
+    ) -> Rc<wgpu::RenderPipeline> {
+        let key = PipelineKey {
+            vertex_shader: desc.vertex_shader.name, 
+            fragment_shader: desc.fragment_shader.map(|shader| shader.name), 
+            vertex_layout: desc.vertex_layout, 
+            cull_mode: desc.cull_mode, 
+            color_target: desc.color_target.clone(), 
+            depth_stencil: desc.depth_stencil.clone(), 
+            multisample: desc.multisample, 
+            bind_group_layouts: bind_group_layouts.iter().map(|layout| layout.global_id()).collect(), 
+        };
+        if let Some(pipeline) = self.pipelines.get(&key) {
+            return pipeline.clone();
+        }
+
+        let vertex_shader = self.shader(desc.vertex_shader, device);
+        let fragment_shader = desc.fragment_shader.map(|shader| self.shader(shader, device));
+
+        let pipeline_layout = device.create_pipeline_layout(
+            &wgpu::PipelineLayoutDescriptor {
+                label: Some(&format!("PipelineLayout({})", desc.label)), 
+                bind_group_layouts, 
+                push_constant_ranges: &[], 
+            }, 
+        );
+
+        let targets = [desc.color_target.clone()];
+        let pipeline = Rc::new(device.create_render_pipeline(
+            &wgpu::RenderPipelineDescriptor {
+                label: Some(&format!("RenderPipeline({})", desc.label)), 
+                layout: Some(&pipeline_layout), 
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList, 
+                    front_face: wgpu::FrontFace::Ccw, 
+                    cull_mode: desc.cull_mode, 
+                    polygon_mode: wgpu::PolygonMode::Fill, 
+                    ..Default::default()
+                }, 
+                vertex: wgpu::VertexState {
+                    module: &vertex_shader, 
+                    entry_point: "main", 
+                    buffers: desc.vertex_layout.buffers(), 
+                }, 
+                depth_stencil: desc.depth_stencil.clone(), 
+                multisample: desc.multisample, 
+                fragment: fragment_shader.as_ref().map(|module| wgpu::FragmentState {
+                    module, 
+                    entry_point: "main", 
+                    targets: match desc.color_target {
+                        Some(_) => &targets, 
+                        None => &[], 
+                    }, 
+                }), 
+                multiview: None, 
+            }, 
+        ));
+        self.pipelines.insert(key, pipeline.clone());
+        return pipeline;
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 쉐이더들을 사용하는 쉐이더 모듈과 파이프라인을 캐시에서 지웁니다. 쉐이더를 다시 불러온 뒤에 호출합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes the shader modules and pipelines using the given shaders from the cache. Called after the shaders are reloaded. </br>
+    /// 
+    pub fn invalidate(&mut self, shader_names: &HashSet<String>) {
+        self.shaders.retain(|name, _| !shader_names.contains(*name));
+        self.pipelines.retain(|key, _| !key.uses_any(shader_names));
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.shaders.clear();
+        self.pipelines.clear();
+    }
+
+    fn shader(&mut self, shader: CachedShader, device: &wgpu::Device) -> Rc<wgpu::ShaderModule> {
+        self.shaders.entry(shader.name)
+            .or_insert_with(|| Rc::new(device.create_shader_module((shader.load)())))
+            .clone()
+    }
+}
+
 /// #### 한국어 </br>
 /// 색상 그래픽스 파이프라인을 생성합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Create a color graphics pipeline. </br>
+/// Create a color graphics pipeline. </br>
+/// 
+pub fn create_colored_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Colored", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 카메라 위치와 정점 법선으로 여러 조명의 블린-퐁 음영을 계산하는 그래픽스 파이프라인을 생성합니다. </br>
+/// 조명 바인드 그룹은 5번 집합에 바인딩되어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that computes the Blinn-Phong shading of multiple lights from the camera position and the vertex normals. </br>
+/// The lighting bind group must be bound to set 5. </br>
+/// 
+pub fn create_phong_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Phong", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("phong_fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 금속성과 거칠기로 쿡-토런스 반사를 계산하는 물리 기반 그래픽스 파이프라인을 생성합니다. </br>
+/// 물리 기반 재질은 5번 집합에, 조명은 6번 집합에 바인딩되어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a physically-based graphics pipeline that computes the Cook-Torrance reflection from the metallic and roughness. </br>
+/// The physically-based material must be bound to set 5, and the lights to set 6. </br>
+/// 
+pub fn create_pbr_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Pbr", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("pbr_fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 거울로 표시된 영역 안에만 반사된 장면을 그리는 색상 그래픽스 파이프라인을 생성합니다. </br>
+/// 반사는 삼각형의 감기 순서를 뒤집으므로 앞면을 컬링합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a color graphics pipeline that draws the reflected scene only inside the area marked by the mirror. </br>
+/// The reflection flips the winding order of the triangles, so the front faces are culled. </br>
+/// 
+pub fn create_reflected_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    let stencil_face = wgpu::StencilFaceState {
+        compare: wgpu::CompareFunction::Equal, 
+        fail_op: wgpu::StencilOperation::Keep, 
+        depth_fail_op: wgpu::StencilOperation::Keep, 
+        pass_op: wgpu::StencilOperation::Keep, 
+    };
+
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Reflected", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Front), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: target::stencil_state(wgpu::StencilState {
+                    front: stencil_face, 
+                    back: stencil_face, 
+                    read_mask: 0xff, 
+                    write_mask: 0x00, 
+                }), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 수면에 반사된 장면을 반사 렌더 타겟에 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 반사 변환은 삼각형의 감기 순서를 뒤집으므로 앞면을 컬링하며, 스텐실은 사용하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws the scene reflected by the water surface into the reflection render target. </br>
+/// The reflection transform flips the winding order of the triangles, so it culls the front faces, and no stencil is used. </br>
+/// 
+pub fn create_water_reflection_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "WaterReflection", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Front), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 뷰 공간 법선으로 매트캡 텍스처를 샘플링하는 조명 없는 미리보기 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a lighting-free preview pipeline that samples a matcap texture with the view-space normal. </br>
+/// 
+pub fn create_matcap_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Matcap", 
+            vertex_shader: cached_shader!("matcap_vertex"), 
+            fragment_shader: Some(cached_shader!("matcap_fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 깊이 맵을 광선 진행하여 표면에 입체감을 주는 시차 차폐 매핑 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a parallax occlusion mapping pipeline that ray-marches a depth map to give the surface depth. </br>
+/// 
+pub fn create_parallax_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Parallax", 
+            vertex_shader: cached_shader!("parallax_vertex"), 
+            fragment_shader: Some(cached_shader!("parallax_fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 재질의 알베도 텍스처와 법선 맵으로 물체를 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 재질의 바인드 그룹은 5번 집합에 바인딩되어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws objects with the albedo texture and normal map of a material. </br>
+/// The bind group of the material must be bound to set 5. </br>
+/// 
+pub fn create_textured_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Textured", 
+            vertex_shader: cached_shader!("textured_vertex"), 
+            fragment_shader: Some(cached_shader!("textured_fragment")), 
+            vertex_layout: VertexLayoutKind::Textured, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 인스턴스 버퍼의 변환 행렬과 색상을 사용하는 인스턴스 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create an instanced graphics pipeline that uses the transform matrices and colors of an instance buffer. </br>
+/// 
+pub fn create_instanced_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Instanced", 
+            vertex_shader: cached_shader!("instanced_vertex"), 
+            fragment_shader: Some(cached_shader!("fragment")), 
+            vertex_layout: VertexLayoutKind::ObjectInstanced, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 반투명한 물체를 그리는 파이프라인 입니다. 색상 파이프라인과 같은 쉐이더를 사용하며, </br>
+/// 알파 블렌딩을 사용하고 깊이 값을 기록하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A pipeline that draws translucent objects. It uses the same shaders as the colored pipeline, </br>
+/// with alpha blending and without writing depth values. </br>
+/// 
+pub fn create_transparent_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Transparent", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: None, 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 순서 독립 투명도의 누적 단계 파이프라인을 생성합니다. </br>
+/// 반투명한 물체들을 정렬 없이 누적 타겟과 드러남 타겟에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the accumulation stage pipeline of the order-independent transparency. </br>
+/// Draws the translucent objects into the accumulation and revealage targets without sorting. </br>
+/// 
+pub fn create_oit_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(RenderPipeline(Oit))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("oit_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Oit)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[
+                    wgpu::VertexBufferLayout {
+                        step_mode: wgpu::VertexStepMode::Vertex, 
+                        array_stride: mem::size_of::<ObjectVertexLayout>() as wgpu::BufferAddress, 
+                        attributes: &[
+                            wgpu::VertexAttribute {
+                                shader_location: 0, 
+                                format: wgpu::VertexFormat::Float32x3, 
+                                offset: bytemuck::offset_of!(ObjectVertexLayout, position) as wgpu::BufferAddress, 
+                            }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 1, 
+                                format: wgpu::VertexFormat::Float32x3, 
+                                offset: bytemuck::offset_of!(ObjectVertexLayout, normal) as wgpu::BufferAddress, 
+                            }, 
+                        ], 
+                    }, 
+                ], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One, 
+                                dst_factor: wgpu::BlendFactor::One, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }, 
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One, 
+                                dst_factor: wgpu::BlendFactor::One, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }, 
+                        }), 
+                        format: oit::ACCUMULATION_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::Zero, 
+                                dst_factor: wgpu::BlendFactor::OneMinusSrc, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }, 
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::Zero, 
+                                dst_factor: wgpu::BlendFactor::OneMinusSrc, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }, 
+                        }), 
+                        format: oit::REVEALAGE_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 순서 독립 투명도의 합성 단계 파이프라인을 생성합니다. </br>
+/// 화면 전체를 덮는 삼각형으로 누적 결과를 프레임 버퍼에 혼합합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the composite stage pipeline of the order-independent transparency. </br>
+/// Blends the accumulated result into the frame buffer with a triangle covering the whole screen. </br>
+/// 
+pub fn create_oit_composite_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(OitComposite)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("fullscreen_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("oit_composite_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(OitComposite)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 지연 렌더링의 G-버퍼 파이프라인을 생성합니다. </br>
+/// 불투명한 물체들의 알베도, 법선, 재질을 세 개의 타겟에, 깊이를 깊이 버퍼에 기록합니다. 물리 기반 재질은 3번 집합에 바인딩되어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the G-buffer pipeline of the deferred rendering. </br>
+/// Writes the albedo, normal and material of the opaque objects into three targets, and the depth into the depth buffer. The physically-based material must be bound to set 3. </br>
+/// 
+pub fn create_gbuffer_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(GBuffer)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("gbuffer_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(GBuffer)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: Some(wgpu::Face::Back), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[
+                    wgpu::VertexBufferLayout {
+                        step_mode: wgpu::VertexStepMode::Vertex, 
+                        array_stride: mem::size_of::<ObjectVertexLayout>() as wgpu::BufferAddress, 
+                        attributes: &[
+                            wgpu::VertexAttribute {
+                                shader_location: 0, 
+                                format: wgpu::VertexFormat::Float32x3, 
+                                offset: bytemuck::offset_of!(ObjectVertexLayout, position) as wgpu::BufferAddress, 
+                            }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 1, 
+                                format: wgpu::VertexFormat::Float32x3, 
+                                offset: bytemuck::offset_of!(ObjectVertexLayout, normal) as wgpu::BufferAddress, 
+                            }, 
+                        ], 
+                    }, 
+                ], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: deferred::ALBEDO_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: deferred::NORMAL_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: deferred::MATERIAL_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 지연 렌더링의 조명 파이프라인을 생성합니다. </br>
+/// 화면 전체를 덮는 삼각형으로 G-버퍼를 읽어 모든 조명의 음영을 계산하고 오프스크린 장면에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the lighting pipeline of the deferred rendering. </br>
+/// Reads the G-buffer with a triangle covering the whole screen, computes the shading of all lights and draws it into the off-screen scene. </br>
+/// 
+pub fn create_deferred_lighting_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "DeferredLighting", 
+            vertex_shader: cached_shader!("fullscreen_vertex"), 
+            fragment_shader: Some(cached_shader!("deferred_lighting_fragment")), 
+            vertex_layout: VertexLayoutKind::None, 
+            cull_mode: None, 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 오클루전 쿼리의 대리 상자를 그리는 파이프라인을 생성합니다. </br>
+/// 깊이 테스트만 수행하며 색상과 깊이 값을 기록하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a pipeline that draws the proxy boxes of the occlusion queries. </br>
+/// It only performs the depth test and writes neither color nor depth values. </br>
+/// 
+pub fn create_occlusion_proxy_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "OcclusionProxy", 
+            vertex_shader: cached_shader!("vertex"), 
+            fragment_shader: Some(cached_shader!("fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::SCENE_COLOR_FORMAT, 
+                write_mask: wgpu::ColorWrites::empty(), 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 물체 번호를 ID 타겟에 그리는 피킹 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a picking pipeline that draws object ids into the ID target. </br>
+/// 
+pub fn create_id_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Id", 
+            vertex_shader: cached_shader!("id_vertex"), 
+            fragment_shader: Some(cached_shader!("id_fragment")), 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Back), 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: picking::ID_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 물체들을 그림자 맵에 그리는 파이프라인을 생성합니다. 깊이 편향은 `ShadowFilter`가 정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a pipeline that draws objects into the shadow map. The depth bias is decided by the `ShadowFilter`. </br>
+/// 
+pub fn create_shadow_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    depth_bias: wgpu::DepthBiasState, 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "Shadow", 
+            vertex_shader: cached_shader!("shadow"), 
+            fragment_shader: None, 
+            vertex_layout: VertexLayoutKind::Object, 
+            cull_mode: Some(wgpu::Face::Front), 
+            color_target: None, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float, 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::LessEqual, 
+                stencil: wgpu::StencilState::default(), 
+                bias: depth_bias, 
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 인스턴스마다 월드 변환 행렬을 가진 물체들을 그림자 맵에 그리는 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a pipeline that draws objects with a world transform matrix per instance into the shadow map. </br>
+/// 
+pub fn create_instanced_shadow_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    depth_bias: wgpu::DepthBiasState, 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "InstancedShadow", 
+            vertex_shader: cached_shader!("shadow_instanced"), 
+            fragment_shader: None, 
+            vertex_layout: VertexLayoutKind::ObjectInstanced, 
+            cull_mode: Some(wgpu::Face::Front), 
+            color_target: None, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float, 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::LessEqual, 
+                stencil: wgpu::StencilState::default(), 
+                bias: depth_bias, 
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 무한 그리드 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create an infinite grid graphics pipeline. </br>
+/// 
+pub fn create_grid_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Grid)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("grid_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("grid_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Grid)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 월드 공간의 선분들을 그리는 디버그 선 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a debug line graphics pipeline that draws line segments in world space. </br>
+/// 
+pub fn create_debug_line_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(DebugLine)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("debug_line_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("debug_line_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(DebugLine)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[
+                    wgpu::VertexBufferLayout {
+                        step_mode: wgpu::VertexStepMode::Vertex, 
+                        array_stride: mem::size_of::<DebugLineVertexLayout>() as wgpu::BufferAddress, 
+                        attributes: &[
+                            wgpu::VertexAttribute {
+                                shader_location: 0, 
+                                format: wgpu::VertexFormat::Float32x3, 
+                                offset: bytemuck::offset_of!(DebugLineVertexLayout, position) as wgpu::BufferAddress, 
+                            }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 1, 
+                                format: wgpu::VertexFormat::Float32x4, 
+                                offset: bytemuck::offset_of!(DebugLineVertexLayout, color) as wgpu::BufferAddress, 
+                            }, 
+                        ], 
+                    }, 
+                ], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 렌더 타겟을 입힌 모니터 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a monitor graphics pipeline that shows a render target. </br>
+/// 
+pub fn create_monitor_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Monitor)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("monitor_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("monitor_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Monitor)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 반사 렌더 타겟을 프레넬 항으로 혼합하는 수면 그래픽스 파이프라인을 생성합니다. </br>
+/// 수면 아래의 물체가 비쳐 보이도록 깊이를 쓰지 않고 혼합합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a water graphics pipeline that blends the reflection render target with the fresnel term. </br>
+/// It blends without writing the depth so the objects under the surface show through. </br>
+/// 
+pub fn create_water_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Water)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("water_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("water_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Water)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 거울이 보이는 픽셀을 스텐실 버퍼에 표시하는 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a pipeline that marks the visible pixels of the mirror in the stencil buffer. </br>
+/// 
+pub fn create_mirror_mask_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let stencil_face = wgpu::StencilFaceState {
+        compare: wgpu::CompareFunction::Always, 
+        fail_op: wgpu::StencilOperation::Keep, 
+        depth_fail_op: wgpu::StencilOperation::Keep, 
+        pass_op: wgpu::StencilOperation::Replace, 
+    };
+
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(MirrorMask)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("mirror_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("mirror_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(MirrorMask)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: target::stencil_state(wgpu::StencilState {
+                    front: stencil_face, 
+                    back: stencil_face, 
+                    read_mask: 0xff, 
+                    write_mask: 0xff, 
+                }), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::empty(), 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 거울로 표시된 영역의 깊이를 되돌리고 배경색을 칠하는 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a pipeline that resets the depth of the area marked by the mirror and paints the backdrop color. </br>
+/// 
+pub fn create_mirror_backdrop_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let stencil_face = wgpu::StencilFaceState {
+        compare: wgpu::CompareFunction::Equal, 
+        fail_op: wgpu::StencilOperation::Keep, 
+        depth_fail_op: wgpu::StencilOperation::Keep, 
+        pass_op: wgpu::StencilOperation::Keep, 
+    };
+
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(MirrorBackdrop)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("mirror_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("mirror_backdrop_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(MirrorBackdrop)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Always, 
+                stencil: target::stencil_state(wgpu::StencilState {
+                    front: stencil_face, 
+                    back: stencil_face, 
+                    read_mask: 0xff, 
+                    write_mask: 0x00, 
+                }), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 반사된 장면 위에 반투명한 거울 표면을 혼합하는 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a pipeline that blends the translucent mirror surface over the reflected scene. </br>
+/// 
+pub fn create_mirror_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let stencil_face = wgpu::StencilFaceState {
+        compare: wgpu::CompareFunction::Equal, 
+        fail_op: wgpu::StencilOperation::Keep, 
+        depth_fail_op: wgpu::StencilOperation::Keep, 
+        pass_op: wgpu::StencilOperation::Keep, 
+    };
+
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Mirror)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("mirror_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("mirror_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Mirror)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[], 
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: target::depth_format(), 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::LessEqual, 
+                stencil: target::stencil_state(wgpu::StencilState {
+                    front: stencil_face, 
+                    back: stencil_face, 
+                    read_mask: 0xff, 
+                    write_mask: 0x00, 
+                }), 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 화면 공간 텍스트 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a screen space text graphics pipeline. </br>
+/// 
+pub fn create_text_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    target_format: wgpu::TextureFormat, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Text)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("text_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("text_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Text)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[
+                    wgpu::VertexBufferLayout {
+                        step_mode: wgpu::VertexStepMode::Vertex, 
+                        array_stride: mem::size_of::<TextVertexLayout>() as wgpu::BufferAddress, 
+                        attributes: &[
+                            wgpu::VertexAttribute {
+                                shader_location: 0, 
+                                format: wgpu::VertexFormat::Float32x2, 
+                                offset: bytemuck::offset_of!(TextVertexLayout, position) as wgpu::BufferAddress, 
+                            }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 1, 
+                                format: wgpu::VertexFormat::Float32x2, 
+                                offset: bytemuck::offset_of!(TextVertexLayout, texcoord) as wgpu::BufferAddress, 
+                            }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 2, 
+                                format: wgpu::VertexFormat::Float32x4, 
+                                offset: bytemuck::offset_of!(TextVertexLayout, color) as wgpu::BufferAddress, 
+                            }, 
+                        ], 
+                    }, 
+                ], 
+            }, 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: target_format, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 화면 공간 스프라이트 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a screen space sprite graphics pipeline. </br>
 /// 
-pub fn create_colored_pipeline(
+pub fn create_sprite_pipeline(
     device: &wgpu::Device, 
     bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    target_format: wgpu::TextureFormat, 
 ) -> wgpu::RenderPipeline {
     let pipeline_layout = device.create_pipeline_layout(
         &wgpu::PipelineLayoutDescriptor {
-            label: Some("PipelineLayout(RenderPipeline(Colored))"), 
+            label: Some("PipelineLayout(Sprite)"), 
             bind_group_layouts, 
             push_constant_ranges: &[], 
-        },
+        }, 
     );
 
     let vertex_shader = device.create_shader_module(
-        wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/vertex.spv"))
+        include_shader!("sprite_vertex")
     );
     let fragment_shader = device.create_shader_module(
-        wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/fragment.spv"))
+        include_shader!("sprite_fragment")
     );
 
     device.create_render_pipeline(
         &wgpu::RenderPipelineDescriptor {
-            label: Some("RenderPipeline(Colored)"), 
+            label: Some("RenderPipeline(Sprite)"), 
             layout: Some(&pipeline_layout), 
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleList, 
                 front_face: wgpu::FrontFace::Ccw, 
-                cull_mode: Some(wgpu::Face::Back), 
+                cull_mode: None, 
                 polygon_mode: wgpu::PolygonMode::Fill, 
                 ..Default::default()
             }, 
@@ -46,37 +1799,115 @@ pub fn create_colored_pipeline(
                 buffers: &[
                     wgpu::VertexBufferLayout {
                         step_mode: wgpu::VertexStepMode::Vertex, 
-                        array_stride: mem::size_of::<ObjectVertexLayout>() as wgpu::BufferAddress, 
+                        array_stride: mem::size_of::<SpriteVertexLayout>() as wgpu::BufferAddress, 
                         attributes: &[
                             wgpu::VertexAttribute {
                                 shader_location: 0, 
-                                format: wgpu::VertexFormat::Float32x3, 
-                                offset: bytemuck::offset_of!(ObjectVertexLayout, position) as wgpu::BufferAddress, 
+                                format: wgpu::VertexFormat::Float32x2, 
+                                offset: bytemuck::offset_of!(SpriteVertexLayout, position) as wgpu::BufferAddress, 
                             }, 
                             wgpu::VertexAttribute {
                                 shader_location: 1, 
+                                format: wgpu::VertexFormat::Float32x2, 
+                                offset: bytemuck::offset_of!(SpriteVertexLayout, texcoord) as wgpu::BufferAddress, 
+                            }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 2, 
+                                format: wgpu::VertexFormat::Float32x4, 
+                                offset: bytemuck::offset_of!(SpriteVertexLayout, color) as wgpu::BufferAddress, 
+                            }, 
+                        ], 
+                    }, 
+                ], 
+            }, 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: target_format, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
+            }), 
+            multiview: None, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 스카이박스 그래픽스 파이프라인을 생성합니다. </br>
+/// 스카이박스는 먼 평면에 그려지므로 깊이 비교 함수로 `LessEqual`을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a skybox graphics pipeline. </br>
+/// Since the skybox is drawn on the far plane, `LessEqual` is used as the depth comparison function. </br>
+/// 
+pub fn create_skybox_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Skybox)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let vertex_shader = device.create_shader_module(
+        include_shader!("skybox_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("skybox_fragment")
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Skybox)"), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                front_face: wgpu::FrontFace::Ccw, 
+                cull_mode: None, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &vertex_shader, 
+                entry_point: "main", 
+                buffers: &[
+                    wgpu::VertexBufferLayout {
+                        step_mode: wgpu::VertexStepMode::Vertex, 
+                        array_stride: mem::size_of::<SkyboxVertexLayout>() as wgpu::BufferAddress, 
+                        attributes: &[
+                            wgpu::VertexAttribute {
+                                shader_location: 0, 
                                 format: wgpu::VertexFormat::Float32x3, 
-                                offset: bytemuck::offset_of!(ObjectVertexLayout, normal) as wgpu::BufferAddress, 
+                                offset: bytemuck::offset_of!(SkyboxVertexLayout, position) as wgpu::BufferAddress, 
                             }, 
                         ], 
                     }, 
                 ], 
             }, 
             depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float, 
-                depth_write_enabled: true, 
-                depth_compare: wgpu::CompareFunction::Less, 
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::LessEqual, 
                 stencil: wgpu::StencilState::default(), 
                 bias: wgpu::DepthBiasState::default()
             }), 
-            multisample: wgpu::MultisampleState::default(), 
+            multisample: target::multisample_state(), 
             fragment: Some(wgpu::FragmentState {
                 module: &fragment_shader, 
                 entry_point: "main", 
                 targets: &[
                     Some(wgpu::ColorTargetState {
                         blend: None, 
-                        format: wgpu::TextureFormat::Bgra8Unorm, 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
                         write_mask: wgpu::ColorWrites::ALL, 
                     }), 
                 ], 
@@ -86,30 +1917,41 @@ pub fn create_colored_pipeline(
     )
 }
 
-pub fn create_shadow_pipeline(
+/// #### 한국어 </br>
+/// 절차적 하늘 그래픽스 파이프라인을 생성합니다. </br>
+/// 스카이박스와 같은 정점 쉐이더를 사용하며, 먼 평면에 그려집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a procedural sky graphics pipeline. </br>
+/// It uses the same vertex shader as the skybox and is drawn on the far plane. </br>
+/// 
+pub fn create_procedural_sky_pipeline(
     device: &wgpu::Device, 
-    bind_group_layouts: &[&wgpu::BindGroupLayout]
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
 ) -> wgpu::RenderPipeline {
     let pipeline_layout = device.create_pipeline_layout(
         &wgpu::PipelineLayoutDescriptor {
-            label: Some("PipelineLayout(Shadow)"), 
+            label: Some("PipelineLayout(ProceduralSky)"), 
             bind_group_layouts, 
             push_constant_ranges: &[], 
         }, 
     );
 
     let vertex_shader = device.create_shader_module(
-        wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/shadow.spv"))
+        include_shader!("skybox_vertex")
+    );
+    let fragment_shader = device.create_shader_module(
+        include_shader!("sky_fragment")
     );
 
     device.create_render_pipeline(
         &wgpu::RenderPipelineDescriptor {
-            label: Some("RenderPipeline(Shadow)"), 
+            label: Some("RenderPipeline(ProceduralSky)"), 
             layout: Some(&pipeline_layout), 
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleList, 
                 front_face: wgpu::FrontFace::Ccw, 
-                cull_mode: Some(wgpu::Face::Front), 
+                cull_mode: None, 
                 polygon_mode: wgpu::PolygonMode::Fill, 
                 ..Default::default()
             }, 
@@ -119,36 +1961,284 @@ pub fn create_shadow_pipeline(
                 buffers: &[
                     wgpu::VertexBufferLayout {
                         step_mode: wgpu::VertexStepMode::Vertex, 
-                        array_stride: mem::size_of::<ObjectVertexLayout>() as wgpu::BufferAddress, 
+                        array_stride: mem::size_of::<SkyboxVertexLayout>() as wgpu::BufferAddress, 
                         attributes: &[
                             wgpu::VertexAttribute {
                                 shader_location: 0, 
                                 format: wgpu::VertexFormat::Float32x3, 
-                                offset: bytemuck::offset_of!(ObjectVertexLayout, position) as wgpu::BufferAddress, 
-                            }, 
-                            wgpu::VertexAttribute {
-                                shader_location: 1, 
-                                format: wgpu::VertexFormat::Float32x3, 
-                                offset: bytemuck::offset_of!(ObjectVertexLayout, normal) as wgpu::BufferAddress, 
+                                offset: bytemuck::offset_of!(SkyboxVertexLayout, position) as wgpu::BufferAddress, 
                             }, 
                         ], 
                     }, 
                 ], 
             }, 
             depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float, 
-                depth_write_enabled: true, 
+                format: target::depth_format(), 
+                depth_write_enabled: false, 
                 depth_compare: wgpu::CompareFunction::LessEqual, 
                 stencil: wgpu::StencilState::default(), 
-                bias: wgpu::DepthBiasState {
-                    constant: -2, 
-                    slope_scale: -2.0, 
-                    clamp: 0.0, 
-                }, 
+                bias: wgpu::DepthBiasState::default()
+            }), 
+            multisample: target::multisample_state(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader, 
+                entry_point: "main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: postprocess::SCENE_COLOR_FORMAT, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }), 
+                ], 
             }), 
-            multisample: wgpu::MultisampleState::default(), 
-            fragment: None, 
             multiview: None, 
-        }
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 후처리 파이프라인을 생성합니다. </br>
+/// 화면 전체를 덮는 삼각형으로 오프스크린 장면에 효과를 적용하여 프레임 버퍼에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the post-processing pipeline. </br>
+/// Applies the effects to the off-screen scene with a triangle covering the whole screen and draws it into the frame buffer. </br>
+/// 
+pub fn create_post_process_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    target_format: wgpu::TextureFormat, 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "PostProcess", 
+            vertex_shader: cached_shader!("fullscreen_vertex"), 
+            fragment_shader: Some(cached_shader!("post_fragment")), 
+            vertex_layout: VertexLayoutKind::None, 
+            cull_mode: None, 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: target_format, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 그림자 맵 디버그 화면의 파이프라인을 생성합니다. </br>
+/// 뷰포트로 화면 구석에 놓인 삼각형으로 조명의 깊이 텍스처를 선형화하여 프레임 버퍼에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the pipeline of the shadow map debug view. </br>
+/// Linearizes the depth texture of the light and draws it into the frame buffer with a triangle placed in a corner of the screen by the viewport. </br>
+/// 
+pub fn create_shadow_view_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    target_format: wgpu::TextureFormat, 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label: "ShadowView", 
+            vertex_shader: cached_shader!("fullscreen_vertex"), 
+            fragment_shader: Some(cached_shader!("shadow_view_fragment")), 
+            vertex_layout: VertexLayoutKind::None, 
+            cull_mode: None, 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: target_format, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 블룸의 임계값 추출 파이프라인을 생성합니다. </br>
+/// 장면에서 임계값을 넘는 색을 골라 절반 해상도의 밉 체인 첫 레벨에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the threshold extraction pipeline of the bloom. </br>
+/// Picks the colors above the threshold from the scene and draws them into the first level of the half resolution mip chain. </br>
+/// 
+pub fn create_bloom_threshold_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    create_bloom_pipeline("BloomThreshold", cached_shader!("bloom_threshold_fragment"), cache, device, bind_group_layouts)
+}
+
+/// #### 한국어 </br>
+/// 블룸 밉 체인의 이전 레벨을 다음 레벨로 축소하는 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the pipeline that downsamples the previous level of the bloom mip chain into the next level. </br>
+/// 
+pub fn create_bloom_downsample_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    create_bloom_pipeline("BloomDownsample", cached_shader!("bloom_downsample_fragment"), cache, device, bind_group_layouts)
+}
+
+/// #### 한국어 </br>
+/// 블룸 밉 체인의 한 레벨을 한 방향으로 흐리게 하는 분리 가능한 가우시안 블러 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the separable gaussian blur pipeline that blurs a level of the bloom mip chain in one direction. </br>
+/// 
+pub fn create_bloom_blur_pipeline(
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    create_bloom_pipeline("BloomBlur", cached_shader!("bloom_blur_fragment"), cache, device, bind_group_layouts)
+}
+
+fn create_bloom_pipeline(
+    label: &'static str, 
+    fragment_shader: CachedShader, 
+    cache: &mut PipelineCache, 
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> Rc<wgpu::RenderPipeline> {
+    cache.get_or_create(
+        &PipelineDesc {
+            label, 
+            vertex_shader: cached_shader!("fullscreen_vertex"), 
+            fragment_shader: Some(fragment_shader), 
+            vertex_layout: VertexLayoutKind::None, 
+            cull_mode: None, 
+            color_target: Some(wgpu::ColorTargetState {
+                blend: None, 
+                format: postprocess::bloom::BLOOM_FORMAT, 
+                write_mask: wgpu::ColorWrites::ALL, 
+            }), 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+        }, 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// `shader`의 `main` 진입점으로 컴퓨트 파이프라인을 생성합니다. 컴퓨트 파이프라인들은 모두 이 함수로 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a compute pipeline with the `main` entry point of `shader`. All the compute pipelines are created with this function. </br>
+/// 
+pub fn create_compute_pipeline(
+    label: &str, 
+    shader: wgpu::ShaderModuleDescriptor, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    device: &wgpu::Device
+) -> wgpu::ComputePipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some(&format!("PipelineLayout({})", label)), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let compute_shader = device.create_shader_module(shader);
+
+    device.create_compute_pipeline(
+        &wgpu::ComputePipelineDescriptor {
+            label: Some(&format!("ComputePipeline({})", label)), 
+            layout: Some(&pipeline_layout), 
+            module: &compute_shader, 
+            entry_point: "main", 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 자동 노출의 휘도 히스토그램 컴퓨트 파이프라인을 생성합니다. </br>
+/// 쉐이더가 원자적 연산을 사용하므로 항상 WGSL 소스로 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the luminance histogram compute pipeline of the automatic exposure. </br>
+/// The shader uses atomic operations, so it is always loaded from the WGSL source. </br>
+///  
+pub fn create_exposure_histogram_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::ComputePipeline {
+    create_compute_pipeline(
+        "ExposureHistogram", 
+        include_wgsl_shader!("exposure_histogram"), 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 인스턴스들의 경계 구를 절두체와 검사하여 보이는 인스턴스들을 간접 그리기 버퍼로 모으는 컴퓨트 파이프라인을 생성합니다. </br>
+/// 쉐이더가 원자적 연산을 사용하므로 항상 WGSL 소스로 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the compute pipeline that tests the bounding spheres of the instances against the frustum and gathers the visible instances into the indirect draw buffer. </br>
+/// The shader uses atomic operations, so it is always loaded from the WGSL source. </br>
+///  
+pub fn create_gpu_cull_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::ComputePipeline {
+    create_compute_pipeline(
+        "GpuCull", 
+        include_wgsl_shader!("gpu_cull"), 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 히스토그램으로부터 평균 휘도를 구하고 노출을 적응시키는 컴퓨트 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the compute pipeline that averages the luminance from the histogram and adapts the exposure. </br>
+/// 
+pub fn create_exposure_average_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::ComputePipeline {
+    create_compute_pipeline(
+        "ExposureAverage", 
+        include_shader!("exposure_average"), 
+        bind_group_layouts, 
+        device
+    )
+}
+
+/// #### 한국어 </br>
+/// 세분화된 평면의 정점들을 움직이는 컴퓨트 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a compute pipeline that displaces the vertices of a subdivided plane. </br>
+/// 
+pub fn create_displacement_pipeline(
+    device: &wgpu::Device, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::ComputePipeline {
+    create_compute_pipeline(
+        "Displacement", 
+        include_shader!("displacement"), 
+        bind_group_layouts, 
+        device
     )
-}
\ No newline at end of file
+}
@@ -0,0 +1,477 @@
+pub mod bloom;
+
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use self::bloom::{Bloom, BloomBuilder};
+use crate::exposure::{AutoExposure, AutoExposureBuilder};
+use crate::resource::ShaderResource;
+use crate::target::{PooledTargetId, RenderTargetPool};
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 장면이 그려지는 오프스크린 HDR 색상 타겟의 텍스처 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The texture format of the off-screen HDR color target the scene is drawn into. </br>
+/// 
+pub const SCENE_COLOR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+
+
+/// #### 한국어 </br>
+/// 후처리 단계를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates the post-processing stage. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PostProcessBuilder {
+    pub vignette_intensity: f32, 
+    pub vignette_radius: f32, 
+    pub vignette_softness: f32, 
+    pub grain_intensity: f32, 
+    pub preserve_alpha: bool, 
+    pub encode_srgb: bool, 
+    pub auto_exposure: AutoExposureBuilder, 
+    pub bloom: BloomBuilder, 
+}
+
+impl Default for PostProcessBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            vignette_intensity: 0.35, 
+            vignette_radius: 0.75, 
+            vignette_softness: 0.45, 
+            grain_intensity: 0.04, 
+            preserve_alpha: false, 
+            encode_srgb: false, 
+            auto_exposure: AutoExposureBuilder::default(), 
+            bloom: BloomBuilder::default()
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl PostProcessBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_vignette_intensity(mut self, vignette_intensity: f32) -> Self {
+        self.vignette_intensity = vignette_intensity;
+        self
+    }
+
+    #[inline]
+    pub fn set_vignette_radius(mut self, vignette_radius: f32) -> Self {
+        self.vignette_radius = vignette_radius;
+        self
+    }
+
+    #[inline]
+    pub fn set_vignette_softness(mut self, vignette_softness: f32) -> Self {
+        self.vignette_softness = vignette_softness;
+        self
+    }
+
+    #[inline]
+    pub fn set_grain_intensity(mut self, grain_intensity: f32) -> Self {
+        self.grain_intensity = grain_intensity;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 장면의 알파 값을 프레임 버퍼에 미리 곱해진(premultiplied) 알파로 그대로 내보낼지 설정합니다. </br>
+    /// 꺼져 있으면 프레임 버퍼의 알파는 항상 1 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to output the alpha of the scene to the frame buffer as premultiplied alpha. </br>
+    /// When off, the alpha of the frame buffer is always 1. </br>
+    /// 
+    #[inline]
+    pub fn set_preserve_alpha(mut self, preserve_alpha: bool) -> Self {
+        self.preserve_alpha = preserve_alpha;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 톤 매핑된 색상을 쉐이더에서 직접 sRGB로 인코딩할지 설정합니다. </br>
+    /// 프레임 버퍼가 sRGB 형식이 아니어서 하드웨어가 인코딩하지 않을 때 켭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to encode the tonemapped color to sRGB in the shader. </br>
+    /// Turned on when the frame buffer is not in an sRGB format, so the hardware does not encode it. </br>
+    /// 
+    #[inline]
+    pub fn set_encode_srgb(mut self, encode_srgb: bool) -> Self {
+        self.encode_srgb = encode_srgb;
+        self
+    }
+
+    #[inline]
+    pub fn set_auto_exposure(mut self, auto_exposure: AutoExposureBuilder) -> Self {
+        self.auto_exposure = auto_exposure;
+        self
+    }
+
+    #[inline]
+    pub fn set_bloom(mut self, bloom: BloomBuilder) -> Self {
+        self.bloom = bloom;
+        self
+    }
+
+    pub fn build(
+        self, 
+        pool: &mut RenderTargetPool, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> PostProcess {
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(PostProcess)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true }, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(PostProcess)"), 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(PostProcess)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<PostProcessUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let scene_target = pool.acquire(
+            "Texture(SceneColor)", 
+            SCENE_COLOR_FORMAT, 
+            wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
+            device
+        );
+        let scene_view = pool.create_view(scene_target);
+        let auto_exposure = self.auto_exposure.build(&scene_view, pool.width(), pool.height(), device, queue);
+        let bloom = self.bloom.build(&scene_view, pool.width(), pool.height(), device, queue);
+        let bind_group = create_bind_group(&scene_view, &sampler, &uniform_buffer, &auto_exposure, &bloom, &bind_group_layout, device);
+
+        let post = PostProcess {
+            enabled: true, 
+            time: 0.0, 
+            params: self, 
+            scene_target, 
+            scene_view, 
+            auto_exposure, 
+            bloom, 
+            sampler, 
+            uniform_buffer, 
+            bind_group_layout, 
+            bind_group, 
+        };
+        post.update_resource(queue);
+
+        return post;
+    }
+}
+
+/// #### 한국어 </br>
+/// 오프스크린 HDR 타겟에 그려진 장면에 블룸을 더해 자동 노출로 톤 매핑하고, 비네트와 움직이는 필름 그레인을 적용하여
+/// 프레임 버퍼에 그리는 후처리 단계 입니다. </br>
+/// 효과의 세기를 0으로 설정하면 해당 효과를 끌 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A post-processing stage that adds bloom to the scene drawn in the off-screen HDR target, tonemaps it with automatic exposure, 
+/// applies a vignette and animated film grain, and draws it into the frame buffer. </br>
+/// Setting the intensity of an effect to 0 turns that effect off. </br>
+/// 
+#[derive(Debug)]
+pub struct PostProcess {
+    enabled: bool, 
+    time: f32, 
+    params: PostProcessBuilder, 
+    scene_target: PooledTargetId, 
+    scene_view: wgpu::TextureView, 
+    auto_exposure: AutoExposure, 
+    bloom: Bloom, 
+    sampler: wgpu::Sampler, 
+    uniform_buffer: wgpu::Buffer, 
+    bind_group_layout: wgpu::BindGroupLayout, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl PostProcess {
+    #[inline]
+    pub fn bind_group_layout_ref(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    /// #### 한국어 </br>
+    /// 장면을 그려야 하는 오프스크린 색상 타겟의 텍스처 뷰를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the texture view of the off-screen color target the scene must be drawn into. </br>
+    /// 
+    #[inline]
+    pub fn scene_view_ref(&self) -> &wgpu::TextureView {
+        &self.scene_view
+    }
+
+    #[inline]
+    pub fn auto_exposure_ref(&self) -> &AutoExposure {
+        &self.auto_exposure
+    }
+
+    #[inline]
+    pub fn auto_exposure_mut(&mut self) -> &mut AutoExposure {
+        &mut self.auto_exposure
+    }
+
+    #[inline]
+    pub fn bloom_ref(&self) -> &Bloom {
+        &self.bloom
+    }
+
+    #[inline]
+    pub fn bloom_mut(&mut self) -> &mut Bloom {
+        &mut self.bloom
+    }
+
+    #[inline]
+    pub fn set_bloom_intensity(&mut self, intensity: f32, queue: &wgpu::Queue) {
+        self.bloom.set_intensity(intensity);
+        self.update_resource(queue);
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// #### 한국어 </br>
+    /// 효과들을 켜거나 끕니다. 꺼져 있으면 장면이 그대로 복사됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Turns the effects on or off. When off, the scene is copied as is. </br>
+    /// 
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool, queue: &wgpu::Queue) {
+        self.enabled = enabled;
+        self.update_resource(queue);
+    }
+
+    #[inline]
+    pub fn set_vignette_intensity(&mut self, vignette_intensity: f32, queue: &wgpu::Queue) {
+        self.params.vignette_intensity = vignette_intensity;
+        self.update_resource(queue);
+    }
+
+    #[inline]
+    pub fn set_grain_intensity(&mut self, grain_intensity: f32, queue: &wgpu::Queue) {
+        self.params.grain_intensity = grain_intensity;
+        self.update_resource(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟 풀의 크기가 바뀐 후 다시 생성된 오프스크린 타겟을 묶습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the recreated off-screen target after the render target pool is resized. </br>
+    /// 
+    pub fn resize(&mut self, pool: &RenderTargetPool, device: &wgpu::Device) {
+        self.scene_view = pool.create_view(self.scene_target);
+        self.auto_exposure.resize(&self.scene_view, pool.width(), pool.height(), device);
+        self.bloom.resize(&self.scene_view, pool.width(), pool.height(), device);
+        self.bind_group = create_bind_group(&self.scene_view, &self.sampler, &self.uniform_buffer, &self.auto_exposure, &self.bloom, &self.bind_group_layout, device);
+    }
+
+    /// #### 한국어 </br>
+    /// 필름 그레인이 움직이고 노출이 적응하도록 시간을 진행시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time so the film grain animates and the exposure adapts. </br>
+    /// 
+    #[inline]
+    pub fn advance(&mut self, elapsed_time_sec: f32, queue: &wgpu::Queue) {
+        self.time = (self.time + elapsed_time_sec) % 1000.0;
+        self.auto_exposure.advance(elapsed_time_sec, queue);
+        self.update_resource(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 후처리 단계를 그립니다. 후처리 파이프라인이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the post-processing stage. The post-processing pipeline must be set. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::set_bind_group(rpass, 0, &self.bind_group, &[]);
+        stats::draw(rpass, 0..3, 0..1);
+    }
+}
+
+impl ShaderResource for PostProcess {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let scale = if self.enabled { 1.0 } else { 0.0 };
+        let data = PostProcessUniformLayout {
+            vignette_intensity: scale * self.params.vignette_intensity, 
+            vignette_radius: self.params.vignette_radius, 
+            vignette_softness: self.params.vignette_softness, 
+            grain_intensity: scale * self.params.grain_intensity, 
+            time: self.time, 
+            preserve_alpha: if self.params.preserve_alpha { 1.0 } else { 0.0 }, 
+            encode_srgb: if self.params.encode_srgb { 1.0 } else { 0.0 }, 
+            bloom_intensity: scale * self.bloom.intensity(), 
+            bloom_levels: self.bloom.num_levels() as f32, 
+            ..Default::default()
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+fn create_bind_group(
+    scene_view: &wgpu::TextureView, 
+    sampler: &wgpu::Sampler, 
+    uniform_buffer: &wgpu::Buffer, 
+    auto_exposure: &AutoExposure, 
+    bloom: &Bloom, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    device: &wgpu::Device
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(PostProcess)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(scene_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Buffer(
+                        uniform_buffer.as_entire_buffer_binding()
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 3, 
+                    resource: wgpu::BindingResource::Buffer(
+                        auto_exposure.exposure_buffer_ref().as_entire_buffer_binding()
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 4, 
+                    resource: wgpu::BindingResource::TextureView(bloom.chain_view_ref()), 
+                }, 
+            ], 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 후처리 유니폼 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the post-processing uniform data used in the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PostProcessUniformLayout {
+    pub vignette_intensity: f32, 
+    pub vignette_radius: f32, 
+    pub vignette_softness: f32, 
+    pub grain_intensity: f32, 
+    pub time: f32, 
+    pub preserve_alpha: f32, 
+    pub encode_srgb: f32, 
+    pub bloom_intensity: f32, 
+    pub bloom_levels: f32, 
+    pub _padding: [f32; 3], 
+}
+
+impl Default for PostProcessUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            vignette_intensity: 0.0, 
+            vignette_radius: 0.75, 
+            vignette_softness: 0.45, 
+            grain_intensity: 0.0, 
+            time: 0.0, 
+            preserve_alpha: 0.0, 
+            encode_srgb: 0.0, 
+            bloom_intensity: 0.0, 
+            bloom_levels: 0.0, 
+            _padding: [0.0; 3], 
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 블룸 밉 체인 텍스처의 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The texture format of the bloom mip chain. </br>
+/// 
+pub const BLOOM_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// #### 한국어 </br>
+/// 블룸 밉 체인의 최대 레벨 수 입니다. 레벨이 많을수록 빛이 더 넓게 퍼집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of levels of the bloom mip chain. The more levels, the wider the light spreads. </br>
+/// 
+pub const MAX_BLOOM_LEVELS: u32 = 6;
+
+
+
+/// #### 한국어 </br>
+/// 블룸 효과를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates the bloom effect. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomBuilder {
+    pub threshold: f32, 
+    pub knee: f32, 
+    pub intensity: f32, 
+    pub num_levels: u32, 
+}
+
+impl Default for BloomBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            threshold: 1.0, 
+            knee: 0.5, 
+            intensity: 0.1, 
+            num_levels: 5
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl BloomBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 빛이 번지기 시작하는 HDR 밝기를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the HDR brightness at which the light starts to bleed. </br>
+    /// 
+    #[inline]
+    pub fn set_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 임계값 주변에서 블룸이 부드럽게 시작되는 구간의 폭을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the width of the range around the threshold where the bloom starts smoothly. </br>
+    /// 
+    #[inline]
+    pub fn set_knee(mut self, knee: f32) -> Self {
+        self.knee = knee.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_num_levels(mut self, num_levels: u32) -> Self {
+        self.num_levels = num_levels.clamp(1, MAX_BLOOM_LEVELS);
+        self
+    }
+
+    pub fn build(
+        self, 
+        scene_view: &wgpu::TextureView, 
+        width: u32, 
+        height: u32, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Bloom {
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Bloom)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Bloom)"), 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Bloom)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<BloomUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        // (한국어) 분리 가능한 가우시안 블러의 가로 방향과 세로 방향을 정렬된 위치에 하나씩 담습니다.
+        // (English Translation) Stores the horizontal and vertical directions of the separable gaussian blur, one at each aligned offset.
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+        let pass_stride = (mem::size_of::<BloomPassLayout>() as wgpu::BufferAddress).next_multiple_of(alignment);
+        let pass_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(BloomPass)"), 
+                mapped_at_creation: false, 
+                size: 2 * pass_stride, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        for (index, direction) in [glam::Vec2::X, glam::Vec2::Y].into_iter().enumerate() {
+            let data = BloomPassLayout { direction: (direction, 0.0, 0.0).into() };
+            queue.write_buffer(&pass_buffer, index as wgpu::BufferAddress * pass_stride, bytemuck::bytes_of(&data));
+        }
+
+        let (chain_width, chain_height) = chain_size(width, height);
+        let num_levels = chain_levels(self.num_levels, chain_width, chain_height);
+        let chain_texture = create_chain_texture("Texture(BloomChain)", chain_width, chain_height, num_levels, device);
+        let blur_texture = create_chain_texture("Texture(BloomBlur)", chain_width, chain_height, num_levels, device);
+
+        let mut bloom = Bloom {
+            params: self, 
+            num_levels, 
+            chain_view: chain_texture.create_view(&wgpu::TextureViewDescriptor {
+                ..Default::default()
+            }), 
+            chain_texture, 
+            blur_texture, 
+            passes: Vec::new(), 
+            sampler, 
+            uniform_buffer, 
+            pass_buffer, 
+            pass_stride, 
+            bind_group_layout, 
+        };
+        bloom.passes = bloom.create_passes(scene_view, device);
+        bloom.update_resource(queue);
+
+        return bloom;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BloomPassKind {
+    Threshold, 
+    Downsample, 
+    Blur, 
+}
+
+#[derive(Debug)]
+struct BloomPass {
+    kind: BloomPassKind, 
+    target_view: wgpu::TextureView, 
+    bind_group: wgpu::BindGroup, 
+}
+
+/// #### 한국어 </br>
+/// 밝은 부분이 주변으로 번지는 블룸 효과 입니다. </br>
+/// 임계값을 넘는 색을 절반 해상도로 추출한 뒤, 밉 체인을 따라 축소하며 각 레벨에 분리 가능한 가우시안 블러를 적용합니다. </br>
+/// 흐려진 모든 레벨은 후처리 단계에서 톤 매핑 전에 장면에 더해집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A bloom effect where bright areas bleed into their surroundings. </br>
+/// The colors above the threshold are extracted at half resolution, then downsampled along a mip chain with a separable gaussian blur applied to each level. </br>
+/// All blurred levels are added to the scene in the post-processing stage before tonemapping. </br>
+/// 
+#[derive(Debug)]
+pub struct Bloom {
+    params: BloomBuilder, 
+    num_levels: u32, 
+    chain_texture: wgpu::Texture, 
+    blur_texture: wgpu::Texture, 
+    chain_view: wgpu::TextureView, 
+    passes: Vec<BloomPass>, 
+    sampler: wgpu::Sampler, 
+    uniform_buffer: wgpu::Buffer, 
+    pass_buffer: wgpu::Buffer, 
+    pass_stride: wgpu::BufferAddress, 
+    bind_group_layout: wgpu::BindGroupLayout, 
+}
+
+#[allow(dead_code)]
+impl Bloom {
+    #[inline]
+    pub fn bind_group_layout_ref(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    /// #### 한국어 </br>
+    /// 흐려진 밉 체인 전체의 텍스처 뷰를 반환합니다. 후처리 단계가 이 뷰의 모든 레벨을 장면에 더합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the texture view of the whole blurred mip chain. The post-processing stage adds every level of this view to the scene. </br>
+    /// 
+    #[inline]
+    pub fn chain_view_ref(&self) -> &wgpu::TextureView {
+        &self.chain_view
+    }
+
+    /// #### 한국어 </br>
+    /// 실제로 사용되는 밉 체인의 레벨 수 입니다. 화면이 작으면 요청된 수보다 적을 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of levels of the mip chain actually used. It may be less than requested when the screen is small. </br>
+    /// 
+    #[inline]
+    pub fn num_levels(&self) -> u32 {
+        self.num_levels
+    }
+
+    #[inline]
+    pub fn threshold(&self) -> f32 {
+        self.params.threshold
+    }
+
+    #[inline]
+    pub fn set_threshold(&mut self, threshold: f32, queue: &wgpu::Queue) {
+        self.params.threshold = threshold.max(0.0);
+        self.update_resource(queue);
+    }
+
+    #[inline]
+    pub fn knee(&self) -> f32 {
+        self.params.knee
+    }
+
+    #[inline]
+    pub fn set_knee(&mut self, knee: f32, queue: &wgpu::Queue) {
+        self.params.knee = knee.max(0.0);
+        self.update_resource(queue);
+    }
+
+    #[inline]
+    pub fn intensity(&self) -> f32 {
+        self.params.intensity
+    }
+
+    /// #### 한국어 </br>
+    /// 블룸의 세기를 설정합니다. 세기는 후처리 단계의 유니폼에 담기므로 후처리 단계를 통해 설정해야 즉시 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the intensity of the bloom. The intensity is stored in the uniform of the post-processing stage, so it takes effect immediately only when set through the post-processing stage. </br>
+    /// 
+    #[inline]
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.params.intensity = intensity.max(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 장면 타겟이 다시 생성되었을 때 밉 체인과 바인드 그룹들을 다시 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the mip chain and the bind groups when the scene target has been recreated. </br>
+    /// 
+    pub fn resize(&mut self, scene_view: &wgpu::TextureView, width: u32, height: u32, device: &wgpu::Device) {
+        stats::untrack_texture(&self.chain_texture);
+        stats::untrack_texture(&self.blur_texture);
+
+        let (chain_width, chain_height) = chain_size(width, height);
+        self.num_levels = chain_levels(self.params.num_levels, chain_width, chain_height);
+        self.chain_texture = create_chain_texture("Texture(BloomChain)", chain_width, chain_height, self.num_levels, device);
+        self.blur_texture = create_chain_texture("Texture(BloomBlur)", chain_width, chain_height, self.num_levels, device);
+        self.chain_view = self.chain_texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        });
+        self.passes = self.create_passes(scene_view, device);
+    }
+
+    /// #### 한국어 </br>
+    /// 임계값 추출, 축소, 블러 단계를 차례로 그립니다. 세기가 0이면 아무것도 그리지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the threshold extraction, downsample and blur stages in order. Draws nothing if the intensity is 0. </br>
+    /// 
+    pub fn draw(
+        &self, 
+        encoder: &mut wgpu::CommandEncoder, 
+        threshold_pipeline: &wgpu::RenderPipeline, 
+        downsample_pipeline: &wgpu::RenderPipeline, 
+        blur_pipeline: &wgpu::RenderPipeline
+    ) {
+        if self.params.intensity <= 0.0 {
+            return;
+        }
+
+        for pass in self.passes.iter() {
+            let mut rpass = encoder.begin_render_pass(
+                &wgpu::RenderPassDescriptor {
+                    label: Some("RenderPass(Bloom)"), 
+                    color_attachments: &[
+                        Some(wgpu::RenderPassColorAttachment {
+                            view: &pass.target_view, 
+                            resolve_target: None, 
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), 
+                                store: wgpu::StoreOp::Store, 
+                            }, 
+                        }), 
+                    ], 
+                    depth_stencil_attachment: None, 
+                    timestamp_writes: None, 
+                    occlusion_query_set: None, 
+                }, 
+            );
+
+            rpass.set_pipeline(match pass.kind {
+                BloomPassKind::Threshold => threshold_pipeline, 
+                BloomPassKind::Downsample => downsample_pipeline, 
+                BloomPassKind::Blur => blur_pipeline, 
+            });
+            stats::set_bind_group(&mut rpass, 0, &pass.bind_group, &[]);
+            stats::draw(&mut rpass, 0..3, 0..1);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 장면에서 임계값을 추출한 뒤, 각 레벨마다 이전 레벨을 축소하고 가로와 세로로 흐리게 하는 단계들을 생성합니다. </br>
+    /// 흐려진 레벨을 축소하므로 아래 레벨일수록 빛이 더 넓게 퍼집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the passes that extract the threshold from the scene, then for each level downsample the previous level and blur it horizontally and vertically. </br>
+    /// Since the blurred level is downsampled, the light spreads wider at the lower levels. </br>
+    /// 
+    fn create_passes(&self, scene_view: &wgpu::TextureView, device: &wgpu::Device) -> Vec<BloomPass> {
+        let chain_views: Vec<_> = (0..self.num_levels).map(|level| create_level_view(&self.chain_texture, level)).collect();
+        let mut passes = Vec::with_capacity(3 * self.num_levels as usize);
+        for level in 0..self.num_levels {
+            let (kind, source) = match level {
+                0 => (BloomPassKind::Threshold, scene_view), 
+                _ => (BloomPassKind::Downsample, &chain_views[level as usize - 1]), 
+            };
+            passes.push(BloomPass {
+                kind, 
+                target_view: create_level_view(&self.chain_texture, level), 
+                bind_group: self.create_bind_group(source, 0, device), 
+            });
+
+            let blur_view = create_level_view(&self.blur_texture, level);
+            passes.push(BloomPass {
+                kind: BloomPassKind::Blur, 
+                target_view: create_level_view(&self.blur_texture, level), 
+                bind_group: self.create_bind_group(&chain_views[level as usize], 0, device), 
+            });
+            passes.push(BloomPass {
+                kind: BloomPassKind::Blur, 
+                target_view: create_level_view(&self.chain_texture, level), 
+                bind_group: self.create_bind_group(&blur_view, 1, device), 
+            });
+        }
+        return passes;
+    }
+
+    fn create_bind_group(&self, source: &wgpu::TextureView, direction: u64, device: &wgpu::Device) -> wgpu::BindGroup {
+        device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Bloom)"), 
+                layout: &self.bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::TextureView(source), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::Sampler(&self.sampler), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::Buffer(
+                            self.uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 3, 
+                        resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                            buffer: &self.pass_buffer, 
+                            offset: direction * self.pass_stride, 
+                            size: wgpu::BufferSize::new(mem::size_of::<BloomPassLayout>() as u64), 
+                        }), 
+                    }, 
+                ], 
+            }, 
+        )
+    }
+}
+
+impl ShaderResource for Bloom {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = BloomUniformLayout {
+            threshold: self.params.threshold, 
+            knee: self.params.knee, 
+            ..Default::default()
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 밉 체인의 첫 레벨은 장면의 절반 해상도 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The first level of the mip chain is half the resolution of the scene. </br>
+/// 
+#[inline]
+fn chain_size(width: u32, height: u32) -> (u32, u32) {
+    ((width / 2).max(1), (height / 2).max(1))
+}
+
+#[inline]
+fn chain_levels(num_levels: u32, width: u32, height: u32) -> u32 {
+    num_levels.min(u32::BITS - width.min(height).leading_zeros())
+}
+
+fn create_chain_texture(label: &str, width: u32, height: u32, num_levels: u32, device: &wgpu::Device) -> wgpu::Texture {
+    stats::track_texture(device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some(label), 
+            size: wgpu::Extent3d {
+                width, 
+                height, 
+                depth_or_array_layers: 1, 
+            }, 
+            dimension: wgpu::TextureDimension::D2, 
+            format: BLOOM_FORMAT, 
+            mip_level_count: num_levels, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
+            view_formats: &[], 
+        }, 
+    ))
+}
+
+#[inline]
+fn create_level_view(texture: &wgpu::Texture, level: u32) -> wgpu::TextureView {
+    texture.create_view(&wgpu::TextureViewDescriptor {
+        base_mip_level: level, 
+        mip_level_count: Some(1), 
+        ..Default::default()
+    })
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 블룸 유니폼 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the bloom uniform data used in the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomUniformLayout {
+    pub threshold: f32, 
+    pub knee: f32, 
+    pub _padding: [f32; 2], 
+}
+
+impl Default for BloomUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            threshold: 1.0, 
+            knee: 0.5, 
+            _padding: [0.0; 2], 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 가우시안 블러 단계의 유니폼 데이터 레이아웃 입니다. `direction`의 xy는 흐리게 하는 방향 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the uniform data of a gaussian blur pass. The xy of `direction` is the direction to blur. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomPassLayout {
+    pub direction: glam::Vec4, 
+}
@@ -0,0 +1,228 @@
+use std::mem;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering as MemOrdering};
+
+use crate::stats;
+
+
+
+const MAP_PENDING: u8 = 0;
+const MAP_SUCCEEDED: u8 = 1;
+const MAP_FAILED: u8 = 2;
+
+/// #### 한국어 </br>
+/// GPU 프로파일러가 사용할 수 있으면 요청하는 장치 기능들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The device features requested for the GPU profiler when they are available. </br>
+/// 
+pub const GPU_PROFILER_FEATURES: wgpu::Features = wgpu::Features::TIMESTAMP_QUERY;
+
+/// #### 한국어 </br>
+/// 한 프레임에 측정할 수 있는 최대 렌더 패스 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of render passes that can be measured in a frame. </br>
+/// 
+pub const MAX_PROFILED_PASSES: u32 = 64;
+
+
+
+/// #### 한국어 </br>
+/// 타임스탬프 쿼리로 렌더 패스마다 걸린 GPU 시간을 측정하는 프로파일러 입니다. </br>
+/// 각 렌더 패스의 시작과 끝에 타임스탬프를 쓰고, 결과는 오클루전 쿼리처럼 한 프레임 늦게 읽으므로 GPU를 기다리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A profiler measuring the GPU time taken by each render pass with timestamp queries. </br>
+/// It writes timestamps at the beginning and the end of each render pass, and the results are read one frame late like the occlusion queries, so it never waits for the GPU. </br>
+/// 
+#[derive(Debug)]
+pub struct GpuProfiler {
+    query_set: wgpu::QuerySet, 
+    resolve_buffer: wgpu::Buffer, 
+    readback_buffer: wgpu::Buffer, 
+    timestamp_period: f32, 
+    labels: Vec<&'static str>, 
+    pending_labels: Vec<&'static str>, 
+    results: Vec<(&'static str, f32)>, 
+    in_flight: bool, 
+    map_state: Arc<AtomicU8>, 
+}
+
+#[allow(dead_code)]
+impl GpuProfiler {
+    /// #### 한국어 </br>
+    /// 장치에 `GPU_PROFILER_FEATURES`가 있으면 프로파일러를 생성합니다. 없으면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a profiler if the device has `GPU_PROFILER_FEATURES`. Returns `None` otherwise. </br>
+    /// 
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(GPU_PROFILER_FEATURES) {
+            return None;
+        }
+
+        let count = 2 * MAX_PROFILED_PASSES;
+        let query_set = device.create_query_set(
+            &wgpu::QuerySetDescriptor {
+                label: Some("QuerySet(GpuProfiler)"), 
+                ty: wgpu::QueryType::Timestamp, 
+                count, 
+            }, 
+        );
+
+        let size = (mem::size_of::<u64>() * count as usize) as wgpu::BufferAddress;
+        let resolve_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("QueryResolve(GpuProfiler)"), 
+                mapped_at_creation: false, 
+                size, 
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC, 
+            }, 
+        ));
+        let readback_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("QueryReadback(GpuProfiler)"), 
+                mapped_at_creation: false, 
+                size, 
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        Some(Self {
+            query_set, 
+            resolve_buffer, 
+            readback_buffer, 
+            timestamp_period: queue.get_timestamp_period(), 
+            labels: Vec::with_capacity(MAX_PROFILED_PASSES as usize), 
+            pending_labels: Vec::with_capacity(MAX_PROFILED_PASSES as usize), 
+            results: Vec::with_capacity(MAX_PROFILED_PASSES as usize), 
+            in_flight: false, 
+            map_state: Arc::new(AtomicU8::new(MAP_PENDING)), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 가장 최근에 읽은 프레임의 렌더 패스 레이블과 GPU 시간(밀리초)들 입니다. 실행된 순서대로 놓입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The render pass labels and GPU times (in milliseconds) of the most recently read frame. They are in the order they ran. </br>
+    /// 
+    #[inline]
+    pub fn results(&self) -> &[(&'static str, f32)] {
+        &self.results
+    }
+
+    #[inline]
+    pub fn total_ms(&self) -> f32 {
+        self.results.iter().map(|(_, time_ms)| time_ms).sum()
+    }
+
+    /// #### 한국어 </br>
+    /// 새 프레임을 시작합니다. 이전 프레임에서 측정한 렌더 패스들을 지웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Begins a new frame. Clears the render passes measured in the previous frame. </br>
+    /// 
+    #[inline]
+    pub fn begin_frame(&mut self) {
+        self.labels.clear();
+    }
+
+    /// #### 한국어 </br>
+    /// `label` 렌더 패스의 시작과 끝에 쓸 타임스탬프를 반환합니다. </br>
+    /// 이전 결과를 아직 읽고 있거나 한 프레임의 최대 패스 수를 넘으면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the timestamps to write at the beginning and the end of the `label` render pass. </br>
+    /// Returns `None` if the previous results are still being read or the maximum number of passes of a frame is exceeded. </br>
+    /// 
+    pub fn begin_pass(&mut self, label: &'static str) -> Option<wgpu::RenderPassTimestampWrites<'_>> {
+        if self.in_flight || self.labels.len() >= MAX_PROFILED_PASSES as usize {
+            return None;
+        }
+
+        let index = 2 * self.labels.len() as u32;
+        self.labels.push(label);
+        Some(wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set, 
+            beginning_of_pass_write_index: Some(index), 
+            end_of_pass_write_index: Some(index + 1), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 타임스탬프들을 resolve 하고 읽기 버퍼로 복사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resolves the timestamps of this frame and copies them into the readback buffer. </br>
+    /// 
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if self.in_flight || self.labels.is_empty() {
+            return;
+        }
+
+        let count = 2 * self.labels.len() as u32;
+        let size = (mem::size_of::<u64>() * count as usize) as wgpu::BufferAddress;
+        encoder.resolve_query_set(&self.query_set, 0..count, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &self.readback_buffer, 0, size);
+        mem::swap(&mut self.labels, &mut self.pending_labels);
+        self.labels.clear();
+    }
+
+    /// #### 한국어 </br>
+    /// 커맨드 버퍼가 제출된 후 읽기 버퍼의 매핑을 요청합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests mapping of the readback buffer after the command buffer has been submitted. </br>
+    /// 
+    pub fn request_results(&mut self) {
+        if self.in_flight || self.pending_labels.is_empty() {
+            return;
+        }
+
+        let map_state = self.map_state.clone();
+        self.readback_buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let state = if result.is_ok() { MAP_SUCCEEDED } else { MAP_FAILED };
+            map_state.store(state, MemOrdering::Release);
+        });
+        self.in_flight = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 프레임의 타임스탬프들이 준비되었으면 읽어서 렌더 패스마다 걸린 시간을 갱신합니다. </br>
+    /// 장치가 폴링된 후에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the timestamps of the previous frame are ready, reads them and updates the time taken by each render pass. </br>
+    /// Must be called after the device has been polled. </br>
+    /// 
+    pub fn fetch_results(&mut self) {
+        if !self.in_flight {
+            return;
+        }
+
+        match self.map_state.swap(MAP_PENDING, MemOrdering::AcqRel) {
+            MAP_SUCCEEDED => { /*--- read the results ---*/ }, 
+            MAP_FAILED => {
+                self.pending_labels.clear();
+                self.in_flight = false;
+                return;
+            }, 
+            _ => return, 
+        }
+
+        {
+            let data = self.readback_buffer.slice(..).get_mapped_range();
+            let timestamps: &[u64] = bytemuck::cast_slice(&data);
+            let nanoseconds_to_ms = self.timestamp_period / 1_000_000.0;
+            self.results.clear();
+            self.results.extend(self.pending_labels.iter()
+                .zip(timestamps.chunks_exact(2))
+                .map(|(&label, pair)| (label, pair[1].saturating_sub(pair[0]) as f32 * nanoseconds_to_ms)));
+        }
+        self.readback_buffer.unmap();
+        self.pending_labels.clear();
+        self.in_flight = false;
+    }
+}
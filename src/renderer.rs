@@ -0,0 +1,4479 @@
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::fmt::Write;
+use std::rc::Rc;
+use std::sync::Arc;
+use winit::{
+    keyboard::KeyCode, 
+    event::{Event, WindowEvent}, 
+    window::{Fullscreen, Window}, 
+};
+
+use crate::{
+    allocation, 
+    input, 
+    mirror, 
+    oit, 
+    picking, 
+    pipeline, 
+    postprocess, 
+    resource, 
+    scene, 
+    shader, 
+    stats, 
+    target, 
+    text, 
+    timer, 
+    utils, 
+};
+use crate::bench::Benchmark;
+use crate::bounds::{Aabb, Frustum};
+use crate::bvh::Bvh;
+use crate::camera::{CameraController, GameCameraObject, OrbitCameraController, OrthographicCamera, OrthographicCameraBuilder, PerspectiveCamera, PerspectiveCameraBuilder};
+use crate::collision::{ColliderId, CollisionPhase, CollisionShape, CollisionWorld, TriggerPhase};
+use crate::debug::DebugLines;
+use crate::deferred::{DeferredRenderer, DeferredRendererBuilder, RendererKind};
+use crate::displacement::{DisplacedPlane, DisplacedPlaneBuilder};
+use crate::fog::{HeightFog, HeightFogBuilder};
+use crate::gpucull::GpuCuller;
+use crate::graph::{GraphColorAttachment, GraphDepthStencilAttachment, GraphTexture, RenderGraph, RenderPassNodeBuilder};
+use crate::grid::{InfiniteGrid, InfiniteGridBuilder};
+use crate::indirect::IndirectDrawBuffer;
+use crate::input::InputState;
+use crate::instance::{CubeField, CubeFieldBuilder};
+use crate::light::{GlobalLight, GlobalLightBuilder, SpotLight, SpotLightBuilder};
+use crate::lighting::{Lighting, LightingBuilder, PhongLight};
+use crate::matcap::Matcap;
+use crate::material::{Material, MaterialBuilder, TextureImage};
+use crate::mesh::{ModelMesh, CubeMesh, PlaneMesh, SphereMesh};
+use crate::mirror::{Mirror, MirrorBuilder};
+use crate::monitor::{Monitor, MonitorBuilder};
+use crate::object::{AnimatedObject, ObjectUniformLayout, StdObject, StdObjectBuilder};
+use crate::occlusion::OcclusionCulling;
+use crate::oit::OitTargets;
+use crate::navigation::{NavAgent, NavGrid, NavGridBuilder};
+use crate::network::{NetEntity, NetSession};
+use crate::parallax::{ParallaxMaterial, ParallaxMaterialBuilder};
+use crate::pbr::{PbrObject, PbrObjectBuilder};
+#[cfg(feature = "physics")]
+use crate::physics::{BodyId, BodyKind, PhysicsWorld};
+use crate::picking::IdPicker;
+use crate::profiler::GpuProfiler;
+use crate::pipeline::PipelineCache;
+use crate::postprocess::{PostProcess, PostProcessBuilder};
+use crate::replay::{InputEvent, InputReplay, ReplayFrame};
+use crate::resource::{ShaderResource, UniformArena, UploadRing};
+use crate::scene::{Scene, SceneCamera, SceneFog, SceneLight, SceneMesh, SceneObject, SceneWatcher};
+use crate::settings::Settings;
+use crate::skybox::{ProceduralSky, ProceduralSkyBuilder, Skybox};
+use crate::shader::ShaderWatcher;
+use crate::shadow::{PcfKernel, ShadowFilter, ShadowFilterBuilder};
+use crate::shadowview::{ShadowView, ShadowViewBuilder};
+use crate::sprite::{SpriteBatch, SpriteTexture};
+use crate::steering::{SteeringAgent, SteeringAgentBuilder};
+use crate::target::{PooledTargetId, RenderTarget, RenderTargetPool};
+use crate::terrain::{Terrain, TerrainBuilder, TerrainMesh};
+use crate::text::TextRenderer;
+#[cfg(feature = "egui")]
+use crate::tweak::TweakPanel;
+use crate::water::{Water, WaterBuilder};
+
+use crate::light::LightObject;
+use crate::object::GameObject;
+
+
+
+/// #### 한국어 </br>
+/// 공유 유니폼 버퍼에 담을 수 있는, 장면 파일의 오브젝트들을 뺀 오브젝트의 최대 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of objects, not counting the objects of the scene file, the shared uniform buffer can hold. </br>
+/// 
+const MAX_OBJECTS: u32 = 256;
+
+/// #### 한국어 </br>
+/// 네트워크로 복제할 때 큐브 더미의 첫 번째 큐브가 사용하는 번호 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The id used by the first cube of the pile when replicated over the network. </br>
+/// 
+#[cfg(feature = "physics")]
+const PILE_NET_ID: u16 = 100;
+
+
+/// #### 한국어 </br>
+/// 명령줄에서 주어진 렌더러의 옵션들 입니다. 주어지지 않은 값은 `None`이며, 설정 파일이나 기본값을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The options of the renderer given on the command line. A value that is not given is `None`, and the settings file or the default value is used. </br>
+/// 
+#[derive(Debug, Clone, Default)]
+pub struct RendererOptions {
+    pub overlay: bool, 
+    pub hot_reload: bool, 
+    pub present_mode: Option<wgpu::PresentMode>, 
+    pub renderer_kind: Option<RendererKind>, 
+    pub depth_format: Option<wgpu::TextureFormat>, 
+    pub sample_count: Option<u32>, 
+    pub pcf_kernel: Option<PcfKernel>, 
+    pub tick_rate: Option<u32>, 
+    pub scene_path: Option<PathBuf>, 
+    pub heightmap_path: Option<PathBuf>, 
+    pub bench_frames: Option<u32>, 
+}
+
+/// #### 한국어 </br>
+/// 장면의 파이프라인, 메쉬, 오브젝트와 각 단계들, 그리고 이를 그리는 스왑체인을 소유하는 렌더러 입니다. </br>
+/// 렌더링 스레드의 루프와 주 스레드의 `RedrawRequested` 이벤트는 매 프레임 `update`와 `render`를 차례로 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The renderer owning the pipelines, meshes, objects and stages of the scene, and the swapchain drawing them. </br>
+/// The loop of the rendering thread and the `RedrawRequested` event of the main thread call `update` and then `render` every frame. </br>
+/// 
+pub struct Renderer {
+    window: Arc<Window>, 
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    overlay: bool, 
+    depth_format: wgpu::TextureFormat, 
+    use_deferred: bool, 
+    sample_count: u32, 
+    surface_format: wgpu::TextureFormat, 
+    camera_bind_group_layout: wgpu::BindGroupLayout, 
+    scene_path: Option<PathBuf>, 
+    scene_watcher: Option<SceneWatcher>, 
+    camera: PerspectiveCamera, 
+    camera_controller: CameraController, 
+    cursor_grabbed: bool, 
+    orbit_controller: OrbitCameraController, 
+    use_orbit_camera: bool, 
+    object_bind_group_layout: wgpu::BindGroupLayout, 
+    object_uniforms: Rc<UniformArena>, 
+    upload_ring: UploadRing, 
+    plane_mesh: Box<dyn ModelMesh>, 
+    cube_mesh_0: CubeMesh, 
+    sphere_mesh: SphereMesh, 
+    plane: StdObject, 
+    cubes: Vec<StdObject>, 
+    cube_labels: Vec<Rc<str>>, 
+    textured_cube: StdObject, 
+    terrain: Terrain, 
+    show_terrain: bool, 
+    wave_plane: DisplacedPlane, 
+    displacement_pipeline: wgpu::ComputePipeline, 
+    show_wave_plane: bool, 
+    cube_bounds: Aabb, 
+    scene_bvh: Bvh<usize>, 
+    nav_grid: NavGrid, 
+    nav_waypoints: [glam::Vec3; 4], 
+    nav_agents: Vec<(NavAgent, usize)>, 
+    debug_lines: DebugLines, 
+    show_navigation: bool, 
+    show_gizmos: bool, 
+    collision_world: CollisionWorld<Rc<str>>, 
+    cube_colliders: Vec<ColliderId>, 
+    animated_objects: Vec<(AnimatedObject, ColliderId)>, 
+    camera_collider: ColliderId, 
+    steering_scale: glam::Vec3, 
+    steering_agents: Vec<(SteeringAgent, StdObject, Option<ColliderId>)>, 
+    show_steering_agents: bool, 
+    net_session: Option<NetSession>, 
+    is_net_client: bool, 
+    input_replay: Option<InputReplay>, 
+    shader_watcher: Option<ShaderWatcher>, 
+    benchmark: Option<Benchmark>, 
+    #[cfg(feature = "physics")]
+    physics_world: PhysicsWorld<Rc<str>>, 
+    #[cfg(feature = "physics")]
+    pile_cubes: Vec<(StdObject, BodyId, glam::Mat4)>, 
+    #[cfg(feature = "physics")]
+    camera_body: BodyId, 
+    cube_field: CubeField, 
+    cube_field_object: StdObject, 
+    show_cube_field: bool, 
+    cube_field_indirect: IndirectDrawBuffer, 
+    use_indirect_draw: bool, 
+    cube_field_culler: GpuCuller, 
+    gpu_cull_pipeline: Option<wgpu::ComputePipeline>, 
+    use_gpu_culling: bool, 
+    global_light_bind_group_layout: wgpu::BindGroupLayout, 
+    shadow_map_bind_group_layout: wgpu::BindGroupLayout, 
+    shadow_filter: ShadowFilter, 
+    global_light: GlobalLight, 
+    spot_light: SpotLight, 
+    use_spot_light: bool, 
+    fog_bind_group_layout: wgpu::BindGroupLayout, 
+    fog: HeightFog, 
+    grid_bind_group_layout: wgpu::BindGroupLayout, 
+    grid: InfiniteGrid, 
+    show_grid: bool, 
+    security_target: RenderTarget, 
+    security_camera: PerspectiveCamera, 
+    monitor_bind_group_layout: wgpu::BindGroupLayout, 
+    monitor: Monitor, 
+    show_monitor: bool, 
+    mirror_bind_group_layout: wgpu::BindGroupLayout, 
+    mirror: Mirror, 
+    show_mirror: bool, 
+    water_bind_group_layout: wgpu::BindGroupLayout, 
+    water: Water, 
+    show_water: bool, 
+    pipeline_cache: PipelineCache, 
+    color_pipeline: Rc<wgpu::RenderPipeline>, 
+    matcap_bind_group_layout: wgpu::BindGroupLayout, 
+    matcap: Matcap, 
+    use_matcap: bool, 
+    matcap_pipeline: Rc<wgpu::RenderPipeline>, 
+    parallax_bind_group_layout: wgpu::BindGroupLayout, 
+    parallax_material: ParallaxMaterial, 
+    use_parallax_floor: bool, 
+    parallax_pipeline: Rc<wgpu::RenderPipeline>, 
+    material_bind_group_layout: wgpu::BindGroupLayout, 
+    cube_materials: [Material; 1], 
+    textured_pipeline: Rc<wgpu::RenderPipeline>, 
+    lighting_bind_group_layout: wgpu::BindGroupLayout, 
+    lighting: Lighting, 
+    use_phong: bool, 
+    phong_pipeline: Rc<wgpu::RenderPipeline>, 
+    pbr_bind_group_layout: wgpu::BindGroupLayout, 
+    pbr_spheres: Vec<PbrObject>, 
+    show_pbr_spheres: bool, 
+    pbr_pipeline: Rc<wgpu::RenderPipeline>, 
+    instanced_pipeline: Rc<wgpu::RenderPipeline>, 
+    occlusion_proxy_pipeline: Rc<wgpu::RenderPipeline>, 
+    num_opaque_cubes: u32, 
+    occlusion: OcclusionCulling, 
+    use_occlusion_culling: bool, 
+    gpu_profiler: Option<GpuProfiler>, 
+    transparent_pipeline: Rc<wgpu::RenderPipeline>, 
+    oit_pipeline: wgpu::RenderPipeline, 
+    oit_bind_group_layout: wgpu::BindGroupLayout, 
+    target_pool: RenderTargetPool, 
+    depth_stencil_target: PooledTargetId, 
+    oit_targets: OitTargets, 
+    use_oit: bool, 
+    oit_composite_pipeline: wgpu::RenderPipeline, 
+    deferred_bind_group_layout: wgpu::BindGroupLayout, 
+    deferred_renderer: Option<DeferredRenderer>, 
+    gbuffer_pipeline: wgpu::RenderPipeline, 
+    deferred_lighting_pipeline: Rc<wgpu::RenderPipeline>, 
+    id_picker: IdPicker, 
+    id_pipeline: Rc<wgpu::RenderPipeline>, 
+    post_process: PostProcess, 
+    post_process_pipeline: Rc<wgpu::RenderPipeline>, 
+    exposure_histogram_pipeline: wgpu::ComputePipeline, 
+    exposure_average_pipeline: wgpu::ComputePipeline, 
+    bloom_threshold_pipeline: Rc<wgpu::RenderPipeline>, 
+    bloom_downsample_pipeline: Rc<wgpu::RenderPipeline>, 
+    bloom_blur_pipeline: Rc<wgpu::RenderPipeline>, 
+    shadow_pipeline: Rc<wgpu::RenderPipeline>, 
+    instanced_shadow_pipeline: Rc<wgpu::RenderPipeline>, 
+    grid_pipeline: wgpu::RenderPipeline, 
+    debug_line_pipeline: wgpu::RenderPipeline, 
+    monitor_pipeline: wgpu::RenderPipeline, 
+    mirror_mask_pipeline: wgpu::RenderPipeline, 
+    mirror_backdrop_pipeline: wgpu::RenderPipeline, 
+    mirror_pipeline: wgpu::RenderPipeline, 
+    reflected_pipeline: Rc<wgpu::RenderPipeline>, 
+    water_reflection_pipeline: Rc<wgpu::RenderPipeline>, 
+    water_pipeline: wgpu::RenderPipeline, 
+    text_bind_group_layout: wgpu::BindGroupLayout, 
+    text_renderer: TextRenderer, 
+    show_labels: bool, 
+    #[cfg(feature = "egui")]
+    tweak_panel: TweakPanel, 
+    #[cfg(feature = "egui")]
+    light_angles: glam::Vec2, 
+    text_pipeline: wgpu::RenderPipeline, 
+    sprite_bind_group_layout: wgpu::BindGroupLayout, 
+    screen_camera: OrthographicCamera, 
+    sprite_batch: SpriteBatch, 
+    dot_texture: usize, 
+    sprite_pipeline: wgpu::RenderPipeline, 
+    shadow_view_bind_group_layout: wgpu::BindGroupLayout, 
+    shadow_view: ShadowView, 
+    show_shadow_view: bool, 
+    shadow_view_pipeline: Rc<wgpu::RenderPipeline>, 
+    skybox: Skybox, 
+    skybox_pipeline: wgpu::RenderPipeline, 
+    procedural_sky_bind_group_layout: wgpu::BindGroupLayout, 
+    procedural_sky: ProceduralSky, 
+    use_procedural_sky: bool, 
+    procedural_sky_pipeline: wgpu::RenderPipeline, 
+    swapchain: Swapchain, 
+    timer: timer::GameTimer<50>, 
+    frame_time_stats: timer::FrameTimeStats, 
+    fixed_timestep: timer::FixedTimestep, 
+    input_frame: ReplayFrame, 
+    input_state: InputState, 
+    in_frustum: Vec<bool>, 
+    transparent_objects: Vec<(f32, usize)>, 
+    security_transparent_objects: Vec<(f32, usize)>, 
+    nav_path: Vec<glam::Vec3>, 
+    stats: String, 
+    render_target_view_descriptor: wgpu::TextureViewDescriptor<'static>, 
+    num_allocations: usize, 
+    frame_stats: stats::FrameStats, 
+    frustum: Frustum, 
+    draw_mirror: bool, 
+    draw_water: bool, 
+    num_cubes: usize, 
+    running: bool, 
+}
+
+#[allow(dead_code)]
+impl Renderer {
+    /// #### 한국어 </br>
+    /// 장면과 렌더링 자원들을 생성하고 스왑체인을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the scene and the rendering resources and configures the swapchain. </br>
+    /// 
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        window: Arc<Window>, 
+        instance: Arc<wgpu::Instance>, 
+        surface: Arc<wgpu::Surface<'static>>, 
+        adapter: Arc<wgpu::Adapter>, 
+        device: Arc<wgpu::Device>, 
+        queue: Arc<wgpu::Queue>, 
+        settings: &Settings, 
+        options: &RendererOptions
+    ) -> Self {
+        let overlay = options.overlay;
+
+        // (한국어) 파이프라인과 렌더 타겟을 생성하기 전에 장치가 지원하는 깊이 버퍼 형식을 정합니다.
+        // (English Translation) Decides the depth buffer format supported by the device before creating the pipelines and render targets.
+        let requested_depth_format = options.depth_format.unwrap_or(target::DEFAULT_DEPTH_FORMAT);
+        let depth_format = target::set_depth_format(requested_depth_format, device.features());
+        log::info!("Depth format: {:?}", depth_format);
+
+        // (한국어) 불투명한 물체들을 그리는 렌더러를 고릅니다. 지연 렌더러는 G-버퍼를 멀티샘플링하지 않습니다.
+        // (English Translation) Picks the renderer drawing the opaque objects. The deferred renderer does not multisample the G-buffer.
+        let renderer_kind = options.renderer_kind.unwrap_or_default();
+        log::info!("Renderer: {}", renderer_kind.name());
+        let use_deferred = renderer_kind == RendererKind::Deferred;
+
+        // (한국어) 장면의 색상과 깊이 형식, 순서 독립 투명도의 타겟 형식이 모두 지원하는 샘플 수 중에서 멀티샘플링의 샘플 수를 정합니다.
+        // (English Translation) Decides the sample count of the multisampling among the ones supported by all of the color and depth formats of the scene and the target formats of the order-independent transparency.
+        let requested_sample_count = options.sample_count.unwrap_or(settings.sample_count);
+        let requested_sample_count = match use_deferred && requested_sample_count > 1 {
+            true => {
+                log::warn!("The deferred swapchain does not support multisampling. Multisampling is disabled.");
+                1
+            }, 
+            false => requested_sample_count, 
+        };
+        let supported_sample_counts = target::supported_sample_counts(
+            &[postprocess::SCENE_COLOR_FORMAT, depth_format, oit::ACCUMULATION_FORMAT, oit::REVEALAGE_FORMAT], 
+            &adapter, 
+            device.features()
+        );
+        let sample_count = target::set_sample_count(requested_sample_count, &supported_sample_counts);
+        log::info!("Multisampling: {}x (supported: {:?})", sample_count, supported_sample_counts);
+        log::info!("Shader source: {}", shader::SHADER_SOURCE_FORMAT);
+
+        // (한국어) 표면이 지원하는 형식 중에서 sRGB 형식을 우선하여 스왑체인의 텍스처 형식을 고릅니다.
+        // (English Translation) Picks the texture format of the swapchain among the ones supported by the surface, preferring an sRGB format.
+        let surface_format = utils::select_surface_format(&surface, &adapter);
+        log::info!("Surface format: {:?}", surface_format);
+
+        // (한국어) 카메라 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a camera bind group layout.
+        let camera_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Camera)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 장면 파일이 주어지면 읽습니다. 없거나 읽지 못하면 코드로 만든 데모 장면을 사용합니다.
+        // (English Translation) Reads the scene file if it is given. If it is not given or cannot be read, the demo scene built in code is used.
+        let (scene, scene_path) = match options.scene_path.clone() {
+            Some(path) => match scene::load_from_file(&path) {
+                Ok(scene) => {
+                    log::info!("Scene: {} ({} objects)", path.display(), scene.objects.len());
+                    (Some(scene), Some(path))
+                }, 
+                Err(e) => {
+                    log::error!("Failed to load the scene {}: {}", path.display(), e);
+                    (None, None)
+                }, 
+            }, 
+            None => (None, None), 
+        };
+        // (한국어) 불러온 장면 파일을 감시하여, 바뀌면 실행 중에 다시 불러옵니다.
+        // (English Translation) Watches the loaded scene file, and reloads it at runtime when it changes.
+        let scene_watcher = scene_path.as_ref().map(SceneWatcher::new);
+        let scene_camera = scene.as_ref().map(|scene| scene.camera).unwrap_or_default();
+        let scene_light = scene.as_ref().map(|scene| scene.light).unwrap_or_default();
+        let scene_fog = scene.as_ref().map(|scene| scene.fog).unwrap_or_default();
+
+        // (한국어) 게임 카메라를 생성합니다. 
+        // (English Translation) Create a game camera.
+        let camera = PerspectiveCameraBuilder::new()
+            .set_fov_y_radian(settings.fov_y_radian)
+            .set_width(window.inner_size().width as f32)
+            .set_height(window.inner_size().height as f32)
+            .set_translation(scene_camera.translation)
+            .set_rotation(scene_camera.rotation)
+            .build(&camera_bind_group_layout, &device, &queue);
+
+        // (한국어) 키보드와 마우스로 카메라를 움직이는 컨트롤러를 생성합니다.
+        // (English Translation) Creates the controller moving the camera with the keyboard and mouse.
+        let camera_controller = CameraController::new(4.0, 0.003);
+        let cursor_grabbed = false;
+
+        // (한국어) 마우스로 초점 주위를 도는 궤도 카메라 컨트롤러를 생성합니다. Tab 키로 자유 카메라와 전환합니다.
+        // (English Translation) Creates the orbit camera controller going around a focus with the mouse. Tab switches between it and the free camera.
+        let orbit_controller = OrbitCameraController::new(glam::Vec3::ZERO, 8.0, 0.005);
+        let use_orbit_camera = false;
+
+        // (한국어) 오브젝트 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a object bind group layout. 
+        let object_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Object)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: true, 
+                            min_binding_size: wgpu::BufferSize::new(mem::size_of::<ObjectUniformLayout>() as wgpu::BufferAddress) 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 모든 오브젝트의 유니폼 데이터를 담는 공유 유니폼 버퍼를 생성합니다.
+        // (English Translation) Creates the shared uniform buffer holding the uniform data of all objects.
+        // (한국어) 장면 파일은 실행 중에 다시 불러올 수 있으므로, 처음 불러온 장면의 크기와 상관없이 가장 큰 장면 파일을 담을 수 있는 슬롯을 더 둡니다.
+        // (English Translation) The scene file can be reloaded at runtime, so extra slots that can hold the largest scene file are kept regardless of the size of the scene loaded first.
+        let object_uniforms = UniformArena::new(
+            "Object", 
+            mem::size_of::<ObjectUniformLayout>() as wgpu::BufferAddress, 
+            MAX_OBJECTS + scene::MAX_SCENE_OBJECTS as u32,  
+            0, 
+            &object_bind_group_layout, 
+            &device
+        );
+
+        // (한국어) 매 프레임 바뀌는 오브젝트들의 유니폼 데이터를 한 번에 올리는 업로드 링을 생성합니다.
+        // (English Translation) Creates the upload ring uploading the uniform data of the objects changing every frame at once.
+        let upload_ring = UploadRing::new(resource::UPLOAD_RING_CHUNK_SIZE);
+
+        // (한국어) 평면 메쉬를 생성합니다. 높이맵 이미지가 지정되면 같은 넓이의 지형 메쉬를 대신 사용합니다.
+        // (English Translation) Create a plane mesh. If a heightmap image is given, a terrain mesh of the same extent is used instead.
+        let plane_mesh: Box<dyn ModelMesh> = match options.heightmap_path.as_ref() {
+            Some(path) => match TerrainMesh::from_heightmap(path, (10.0, 10.0).into(), 1.0, &device, &queue) {
+                Ok(mesh) => {
+                    log::info!("Heightmap: {} ({}x{})", path.display(), mesh.columns(), mesh.rows());
+                    Box::new(mesh)
+                }, 
+                Err(e) => {
+                    log::error!("Failed to load the heightmap {}: {}", path.display(), e);
+                    Box::new(PlaneMesh::new(10.0, 10.0, &device, &queue))
+                }, 
+            }, 
+            None => Box::new(PlaneMesh::new(10.0, 10.0, &device, &queue)), 
+        };
+
+        // (한국어) 큐브 메쉬들을 생성합니다.
+        // (English Translation) Creates cube meshes. 
+        let cube_mesh_0 = CubeMesh::new(1.0, 1.0, 1.0, &device, &queue);
+
+        // (한국어) 구 메쉬를 생성합니다.
+        // (English Translation) Creates a sphere mesh.
+        let sphere_mesh = SphereMesh::new(0.5, 32, 16, &device, &queue);
+
+        // (한국어) 오브젝트들을 생성합니다.
+        // (English Translation) Creates objects. 
+        let plane = StdObjectBuilder::new()
+            .set_color((0.5, 0.5, 0.5).into())
+            .set_translation((0.0, 0.0, 0.0).into())
+            .build(&object_uniforms, &queue);
+
+        // (한국어) 장면 파일의 큐브들은 큐브 목록에, 구들은 움직이지 않는 애니메이션 오브젝트로 추가합니다.
+        // (English Translation) The cubes of the scene file are added to the cube list, and the spheres as animated objects that do not move.
+        let (cubes, cube_labels, animated_objects) = match scene.as_ref() {
+            Some(scene) => {
+                // (한국어) 이름들은 충돌체와 물리 물체의 소유자로도 쓰이므로 공유할 수 있는 `Rc<str>`로 만듭니다.
+                // (English Translation) The labels are also used as the owners of the colliders and the physics bodies, so they are made into shareable `Rc<str>`s.
+                let (cubes, cube_labels, spheres) = scene.build_objects(&object_uniforms, &queue);
+                (cubes, cube_labels.into_iter().map(Rc::from).collect::<Vec<Rc<str>>>(), spheres)
+            }, 
+            None => {
+                let mut cubes = Vec::new();
+                let cube_labels: Vec<Rc<str>> = ["Red Cube", "Green Cube", "Blue Cube", "Glass Cube", "Amber Glass Cube"].into_iter().map(Rc::from).collect();
+                let red_cube = StdObjectBuilder::new()
+                    .set_color((1.0, 0.2, 0.2).into())
+                    .set_rim_color((1.0, 0.6, 0.5).into())
+                    .set_rim_power(2.5)
+                    .set_translation((0.0, 0.5, 0.0).into())
+                    .build(&object_uniforms, &queue);
+                cubes.push(red_cube);
+
+                let green_cube = StdObjectBuilder::new()
+                    .set_color((0.2, 1.0, 0.2).into())
+                    .set_rim_color((0.6, 1.0, 0.6).into())
+                    .set_rim_power(2.5)
+                    .set_translation((1.0, 1.25, 1.0).into())
+                    .set_rotation(glam::Quat::from_axis_angle(
+                        glam::Vec3::new(1.0, 1.0, 1.0).normalize(), 
+                        60.0f32.to_radians()
+                    ))
+                    .build(&object_uniforms, &queue);
+                cubes.push(green_cube);
+
+                let blue_cube = StdObjectBuilder::new()
+                    .set_color((0.2, 0.2, 1.0).into())
+                    .set_rim_color((0.5, 0.7, 1.0).into())
+                    .set_rim_power(2.5)
+                    .set_translation((-1.0, 0.75, -0.8).into())
+                    .set_rotation(glam::Quat::from_axis_angle(
+                        glam::Vec3::new(-1.0, 1.0, 0.0).normalize(), 
+                        38.0f32.to_radians()
+                    ))
+                    .build(&object_uniforms, &queue);
+                cubes.push(blue_cube);
+
+                let glass_cube = StdObjectBuilder::new()
+                    .set_color((0.6, 0.85, 1.0).into())
+                    .set_opacity(0.35)
+                    .set_translation((1.6, 0.5, -1.2).into())
+                    .build(&object_uniforms, &queue);
+                cubes.push(glass_cube);
+
+                let amber_glass_cube = StdObjectBuilder::new()
+                    .set_color((1.0, 0.7, 0.2).into())
+                    .set_opacity(0.5)
+                    .set_translation((-1.4, 0.5, 1.3).into())
+                    .set_rotation(glam::Quat::from_rotation_y(30.0f32.to_radians()))
+                    .build(&object_uniforms, &queue);
+                cubes.push(amber_glass_cube);
+
+                // (한국어) 매 프레임 애니메이션 콜백으로 움직이는 오브젝트들을 생성합니다.
+                // (English Translation) Creates the objects moved by animation callbacks every frame.
+                let mut animated_objects = Vec::new();
+                let sphere = StdObjectBuilder::new()
+                    .set_color((0.9, 0.85, 0.7).into())
+                    .set_rim_color((1.0, 0.95, 0.8).into())
+                    .set_rim_power(3.0)
+                    .set_translation((2.2, 0.5, -1.6).into())
+                    .build(&object_uniforms, &queue);
+                animated_objects.push(AnimatedObject::new(sphere, |object, time_sec, _| {
+                    let height = 0.5 + 0.8 * (2.5 * time_sec).sin().abs();
+                    object.set_translation((2.2, height, -1.6).into());
+                }));
+                (cubes, cube_labels, animated_objects)
+            }, 
+        };
+
+        // (한국어) 재질의 텍스처로 그려지는 큐브를 생성합니다.
+        // (English Translation) Creates a cube drawn with the textures of a material.
+        let textured_cube = StdObjectBuilder::new()
+            .set_color((1.0, 1.0, 1.0).into())
+            .set_translation((-2.4, 0.5, -0.2).into())
+            .build(&object_uniforms, &queue);
+
+        // (한국어) 평면 바닥 대신 사용할 수 있는 청크 단위의 지형을 생성합니다.
+        // (English Translation) Creates a chunked terrain that can be used instead of the plane floor.
+        let terrain = TerrainBuilder::new()
+            .build(&object_uniforms, &queue);
+        let show_terrain = false;
+
+        // (한국어) 컴퓨트 쉐이더가 정점을 움직이는 물결 평면을 생성합니다.
+        // (English Translation) Creates a wave plane whose vertices are moved by a compute shader.
+        let wave_plane = DisplacedPlaneBuilder::new()
+            .set_translation((2.5, 0.35, 3.5).into())
+            .set_size((3.0, 2.0).into())
+            .build(&object_uniforms, &device, &queue);
+        let displacement_pipeline = pipeline::create_displacement_pipeline(&device, &[wave_plane.bind_group_layout_ref()]);
+        let show_wave_plane = false;
+
+        // (한국어) 큐브들의 경계 상자로 공간 색인을 만듭니다.
+        // (English Translation) Builds a spatial index from the bounding boxes of the cubes.
+        let cube_bounds = Aabb::from_center_half_extents(glam::Vec3::ZERO, glam::Vec3::splat(0.5));
+        let mut scene_bvh = Bvh::default();
+        for (index, object) in cubes.iter().enumerate() {
+            scene_bvh.insert(cube_bounds.transform(object.world_transform_ref()), index);
+        }
+
+        // (한국어) 바닥 평면과 큐브들의 경계 상자로 내비게이션 격자를 굽고, 격자 위의 지점들을 돌아다니는 에이전트들을 만듭니다.
+        // (English Translation) Bakes a navigation grid from the floor plane and the bounding boxes of the cubes, and creates agents wandering between points on the grid.
+        let nav_grid = cubes.iter()
+            .fold(NavGridBuilder::new(), |builder, object| builder.add_obstacle(cube_bounds.transform(object.world_transform_ref())))
+            .build();
+        let nav_waypoints: [glam::Vec3; 4] = [(-3.5, 0.0, -3.5).into(), (3.5, 0.0, -2.5).into(), (2.5, 0.0, 3.5).into(), (-3.0, 0.0, 2.5).into()];
+        let nav_agents: Vec<_> = (0..nav_waypoints.len())
+            .map(|index| {
+                let mut agent = NavAgent::new(nav_waypoints[index], 1.2);
+                let next = (index + 2) % nav_waypoints.len();
+                agent.set_destination(&nav_grid, nav_waypoints[next]);
+                (agent, next)
+            })
+            .collect();
+        let debug_lines = DebugLines::new(&device);
+        let show_navigation = false;
+        let show_gizmos = false;
+
+        // (한국어) 바닥과 큐브들에 충돌체를 붙입니다.
+        // (English Translation) Attaches colliders to the floor and the cubes.
+        let mut collision_world = CollisionWorld::default();
+        collision_world.insert("Floor".into(), CollisionShape::Aabb { half_extents: (5.0, 0.01, 5.0).into() }, plane.world_transform_ref());
+        let cube_colliders: Vec<_> = cubes.iter().zip(cube_labels.iter())
+            .map(|(object, label)| collision_world.insert(label.clone(), CollisionShape::Obb { half_extents: glam::Vec3::splat(0.5) }, object.world_transform_ref()))
+            .collect();
+        let animated_objects: Vec<_> = animated_objects.into_iter()
+            .map(|object| {
+                let collider = collision_world.insert("Sphere".into(), CollisionShape::Sphere { radius: 0.5 }, object.object_ref().world_transform_ref());
+                (object, collider)
+            })
+            .collect();
+        let camera_collider = collision_world.insert("Camera".into(), CollisionShape::Sphere { radius: 0.3 }, camera.world_transform_ref());
+
+        // (한국어) 물체가 들어오고 나가는 것을 알리는 트리거 볼륨들을 배치합니다.
+        // (English Translation) Places trigger volumes that notify when objects enter and exit them.
+        collision_world.insert_trigger("Entrance Zone".into(), CollisionShape::Aabb { half_extents: (1.5, 1.5, 1.0).into() }, &glam::Mat4::from_translation((0.0, 1.6, 4.0).into()));
+        collision_world.insert_trigger("Pillar Zone".into(), CollisionShape::Sphere { radius: 1.2 }, &glam::Mat4::from_translation((-3.5, 1.4, -3.5).into()));
+
+        // (한국어) 바닥 위를 배회하며 장애물을 피하고, 가까이 온 카메라를 쫓아가는 큐브 에이전트들을 만듭니다.
+        // (English Translation) Creates cube agents that wander on the floor, avoid obstacles, and chase the camera when it comes close.
+        let steering_scale = glam::Vec3::splat(0.4);
+        let steering_agents: Vec<_> = (0..6)
+            .map(|index| {
+                let angle = index as f32 / 6.0 * std::f32::consts::TAU;
+                let agent = SteeringAgentBuilder::new()
+                    .set_position((3.2 * angle.cos(), 0.21, 3.2 * angle.sin()).into())
+                    .set_seed(7919 * index + 1)
+                    .build();
+                let object = StdObjectBuilder::new()
+                    .set_color((0.95, 0.55 + 0.07 * index as f32, 0.15).into())
+                    .build(&object_uniforms, &queue);
+                (agent, object, None)
+            })
+            .collect();
+        let show_steering_agents = false;
+
+        // (한국어) `LAB_PROJECT_NET` 환경 변수가 설정되어 있으면 서버나 클라이언트로 움직이는 물체들의 변환을 복제합니다.
+        // (English Translation) If the `LAB_PROJECT_NET` environment variable is set, replicates the transforms of the moving objects as a server or a client.
+        let net_session = match NetSession::from_env() {
+            Some(Ok(session)) => Some(session), 
+            Some(Err(e)) => {
+                log::error!("Failed to start the network session. ({})", e);
+                None
+            }, 
+            None => None, 
+        };
+        let is_net_client = net_session.as_ref().is_some_and(NetSession::is_client);
+
+        // (한국어) `LAB_PROJECT_REPLAY` 환경 변수가 설정되어 있으면 매 프레임의 입력과 경과 시간을 기록하거나 재생합니다.
+        // (English Translation) If the `LAB_PROJECT_REPLAY` environment variable is set, records or replays the input and the elapsed time of every frame.
+        let input_replay = match InputReplay::from_env() {
+            Some(Ok(replay)) => Some(replay), 
+            Some(Err(e)) => {
+                log::error!("Failed to start the input replay. ({})", e);
+                None
+            }, 
+            None => None, 
+        };
+
+        // (한국어) `--hot-reload` 인수가 주어지면 쉐이더 폴더를 감시하여 바뀐 쉐이더를 다시 불러옵니다.
+        // (English Translation) If the `--hot-reload` argument is given, watches the shader directory and reloads the changed shaders.
+        let shader_watcher = match options.hot_reload.then(ShaderWatcher::for_crate_shaders) {
+            Some(Ok(watcher)) => {
+                log::info!("Watching the shaders. ({})", watcher.directory().display());
+                Some(watcher)
+            }, 
+            Some(Err(e)) => {
+                log::error!("Failed to watch the shaders. ({})", e);
+                None
+            }, 
+            None => None, 
+        };
+
+        // (한국어) `--bench N` 인수가 주어지면 수직 동기화 없이 N 프레임을 렌더링한 뒤 보고서를 출력하고 종료합니다.
+        // (English Translation) If the `--bench N` argument is given, renders N frames without vsync, then prints a report and quits.
+        let benchmark = options.bench_frames.map(Benchmark::new);
+
+        // (한국어) 물리 기능이 켜져 있으면 바닥과 큐브들 위로 떨어지는 큐브 더미를 만듭니다.
+        // (English Translation) If the physics feature is enabled, creates a pile of cubes falling onto the floor and the cubes.
+        #[cfg(feature = "physics")]
+        let mut physics_world = PhysicsWorld::default();
+        #[cfg(feature = "physics")]
+        let mut pile_cubes = Vec::new();
+        #[cfg(feature = "physics")]
+        let camera_body = {
+            physics_world.add_static_body("Floor".into(), CollisionShape::Aabb { half_extents: (5.0, 0.01, 5.0).into() }, plane.world_transform_ref());
+            for (object, label) in cubes.iter().zip(cube_labels.iter()) {
+                physics_world.add_static_body(label.clone(),  CollisionShape::Obb { half_extents: glam::Vec3::splat(0.5) }, object.world_transform_ref());
+            }
+
+            for index in 0..16 {
+                let (layer, corner) = (index / 4, index % 4);
+                let translation = glam::vec3(
+                    if corner % 2 == 0 { -0.6 } else { 0.6 }, 
+                    3.0 + 1.3 * layer as f32, 
+                    if corner / 2 == 0 { -0.6 } else { 0.6 }
+                );
+                let hue = index as f32 / 16.0;
+                let object = StdObjectBuilder::new()
+                    .set_color((0.9 - 0.5 * hue, 0.5 + 0.3 * hue, 0.3 + 0.6 * hue).into())
+                    .set_translation(translation)
+                    .set_rotation(glam::Quat::from_euler(glam::EulerRot::XYZ, 0.3 * index as f32, 0.7 * index as f32, 0.0))
+                    .build(&object_uniforms, &queue);
+                let body = physics_world.add_dynamic_body("Pile Cube".into(), CollisionShape::Obb { half_extents: glam::Vec3::splat(0.5) }, object.world_transform_ref());
+                let start = *object.world_transform_ref();
+                pile_cubes.push((object, body, start));
+            }
+
+            // (한국어) 카메라는 운동학적 강체로 등록되어 부딪힌 큐브들을 밀어냅니다.
+            // (English Translation) The camera is registered as a kinematic body and pushes away the cubes it bumps into.
+            physics_world.add_body("Camera".into(), BodyKind::Kinematic, CollisionShape::Sphere { radius: 0.3 }, camera.world_transform_ref())
+        };
+
+        // (한국어) 인스턴스 렌더링 스트레스 테스트에 사용할 큐브 필드를 생성합니다.
+        // (English Translation) Creates a cube field used for the instanced rendering stress test.
+        let cube_field = CubeFieldBuilder::new()
+            .build(&device, &queue);
+        let cube_field_object = StdObjectBuilder::new()
+            .build(&object_uniforms, &queue);
+        let show_cube_field = false;
+
+        // (한국어) 큐브 필드를 간접 그리기로 그릴 때 사용할 그리기 인자 버퍼를 생성합니다.
+        // (English Translation) Creates the draw argument buffer used when the cube field is drawn indirectly.
+        let cube_field_indirect = cube_field.create_indirect_buffer(
+            cube_mesh_0.num_indices(), 
+            device.features(), 
+            &device, 
+            &queue
+        );
+        let use_indirect_draw = false;
+
+        // (한국어) 큐브 필드의 인스턴스들을 GPU에서 절두체 컬링하는 컬러를 생성합니다. 컴퓨트 파이프라인은 GPU 컬링을 처음 켤 때 생성합니다.
+        // (English Translation) Creates the culler frustum culling the instances of the cube field on the GPU. Its compute pipeline is created when the GPU culling is first turned on.
+        let cube_field_culler = GpuCuller::new(
+            "CubeField", 
+            cube_field.instances_ref(), 
+            cube_mesh_0.num_indices(), 
+            cube_mesh_0.bounding_sphere(), 
+            &device, 
+            &queue
+        );
+        let gpu_cull_pipeline: Option<wgpu::ComputePipeline> = None;
+        let use_gpu_culling = false;
+
+        // (한국어) 전역 조명 바인드 그룹을 생성합니다. 
+        // (English Translation) Create a global light bind group layout.
+        let global_light_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(GlobalLight)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let shadow_map_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(ShadowMap)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Depth, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Comparison, 
+                        ), 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 조명들이 공유하는 그림자 필터를 생성합니다.
+        // (English Translation) Creates the shadow filter shared by the lights.
+        let pcf_kernel = options.pcf_kernel.unwrap_or_default();
+        log::info!("Shadow filter: {}", pcf_kernel.name());
+        let shadow_filter = ShadowFilterBuilder::new()
+            .set_kernel(pcf_kernel)
+            .build(&device, &queue);
+
+        // (한국어) 설정의 그림자 맵 크기가 장치가 지원하는 최대 텍스처 크기보다 크면 최대 크기로 줄입니다.
+        // (English Translation) If the shadow map size of the settings is larger than the maximum texture size supported by the device, it is reduced to the maximum size.
+        let max_texture_dimension = device.limits().max_texture_dimension_2d;
+        let shadow_map_size = match settings.shadow_map_size > max_texture_dimension {
+            true => {
+                log::warn!("The shadow map size {} exceeds the device limit, so {} is used instead.", settings.shadow_map_size, max_texture_dimension);
+                max_texture_dimension
+            }, 
+            false => settings.shadow_map_size, 
+        };
+
+        // (한국어) 전역 조명을 생성합니다.
+        // (English Translation) Creates global light.
+        let global_light = GlobalLightBuilder::new()
+            .set_translation(scene_light.translation)
+            .set_rotation(scene_light.rotation)
+            .set_light_color(scene_light.color)
+            .set_shadow_map_width(shadow_map_size)
+            .set_shadow_map_height(shadow_map_size)
+            .build(&global_light_bind_group_layout, &shadow_map_bind_group_layout, &shadow_filter, &device, &queue);
+
+        // (한국어) 전역 조명 대신 사용할 수 있는 스포트라이트를 생성합니다.
+        // (English Translation) Creates a spot light that can be used instead of the global light.
+        let spot_light = SpotLightBuilder::new()
+            .look_at((3.0, 5.0, 3.0).into(), glam::Vec3::ZERO)
+            .set_cone_angles(18.0f32.to_radians(), 28.0f32.to_radians())
+            .set_range(15.0)
+            .set_light_color((1.0, 0.9, 0.75).into())
+            .build(&global_light_bind_group_layout, &shadow_map_bind_group_layout, &shadow_filter, &device, &queue);
+        let use_spot_light = false;
+
+        // (한국어) 안개 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a fog bind group layout.
+        let fog_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(HeightFog)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 지수 높이 안개를 생성합니다.
+        // (English Translation) Creates exponential height fog.
+        let mut fog = HeightFogBuilder::new()
+            .build(&fog_bind_group_layout, &device, &queue);
+        scene_fog.apply(&mut fog);
+        fog.update_resource(&queue);
+
+        // (한국어) 그리드 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a grid bind group layout.
+        let grid_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Grid)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 평면 메쉬 대신 사용할 수 있는 무한 그리드를 생성합니다.
+        // (English Translation) Creates an infinite grid that can be used instead of the plane mesh.
+        let grid = InfiniteGridBuilder::new()
+            .build(&grid_bind_group_layout, &device, &queue);
+        let show_grid = false;
+
+        // (한국어) 감시 카메라의 장면이 그려지는 렌더 타겟을 생성합니다.
+        // (English Translation) Creates the render target the scene of the security camera is drawn into.
+        let security_target = RenderTarget::new("SecurityCamera", 512, 288, postprocess::SCENE_COLOR_FORMAT, &device);
+
+        // (한국어) 장면의 모서리에서 큐브들을 내려다보는 감시 카메라를 생성합니다.
+        // (English Translation) Creates a security camera looking down at the cubes from a corner of the scene.
+        let security_view = glam::Mat4::look_at_rh((4.5, 3.0, 4.5).into(), (0.0, 0.5, 0.0).into(), glam::Vec3::Y);
+        let security_camera = PerspectiveCameraBuilder::new()
+            .set_width(security_target.viewport().width)
+            .set_height(security_target.viewport().height)
+            .set_translation((4.5, 3.0, 4.5).into())
+            .set_rotation(glam::Quat::from_mat4(&security_view.inverse()))
+            .build(&camera_bind_group_layout, &device, &queue);
+
+        // (한국어) 모니터 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a monitor bind group layout.
+        let monitor_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Monitor)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 감시 카메라의 장면을 보여주는 모니터를 생성합니다.
+        // (English Translation) Creates a monitor showing the scene of the security camera.
+        let monitor = MonitorBuilder::new()
+            .set_translation((-3.2, 1.4, -2.6).into())
+            .set_rotation(glam::Quat::from_rotation_y(17.0f32.to_radians()))
+            .set_size((2.4, 1.35).into())
+            .build(&security_target, &monitor_bind_group_layout, &device, &queue);
+        let show_monitor = true;
+
+        // (한국어) 거울 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a mirror bind group layout.
+        let mirror_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Mirror)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 바닥의 뒤쪽 가장자리에 세워진 거울을 생성합니다.
+        // (English Translation) Creates a mirror standing at the back edge of the floor.
+        let mirror = MirrorBuilder::new()
+            .set_translation((0.0, 1.5, -5.0).into())
+            .set_size((4.0, 3.0).into())
+            .build(&camera_bind_group_layout, &mirror_bind_group_layout, &device, &queue);
+        let show_mirror = target::has_stencil();
+
+        // (한국어) 수면 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a water bind group layout.
+        let water_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Water)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 바닥의 앞쪽 구석에 놓인 수면을 생성합니다.
+        // (English Translation) Creates a water surface lying in a front corner of the floor.
+        let water = WaterBuilder::new()
+            .set_translation((-2.5, 0.05, 2.5).into())
+            .set_size((4.0, 4.0).into())
+            .build(
+                window.inner_size().width, 
+                window.inner_size().height, 
+                &camera_bind_group_layout, 
+                &water_bind_group_layout, 
+                &device, 
+                &queue
+            );
+        let show_water = false;
+
+        // (한국어) 색상 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a color graphics pipeline.
+        // (한국어) 같은 서술의 파이프라인을 다시 만들지 않도록 파이프라인 캐시를 생성합니다.
+        // (English Translation) Create a pipeline cache so pipelines with the same description are not created again.
+        let mut pipeline_cache = PipelineCache::new();
+        let bind_group_layouts = &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout];
+        let color_pipeline = pipeline::create_colored_pipeline(&mut pipeline_cache, &device, bind_group_layouts);
+
+        // (한국어) 매트캡 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a matcap bind group layout.
+        let matcap_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Matcap)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 매트캡 이미지를 불러옵니다. 이미지가 없으면 절차적으로 생성합니다.
+        // (English Translation) Loads the matcap image. If there is no image, one is generated procedurally.
+        let matcap = Matcap::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/matcap.png"), &matcap_bind_group_layout, &device, &queue)
+            .unwrap_or_else(|_| Matcap::procedural(256, &matcap_bind_group_layout, &device, &queue));
+        let use_matcap = false;
+
+        // (한국어) 조명 없이 매트캡으로 음영 처리하는 미리보기 파이프라인을 생성합니다.
+        // (English Translation) Create a preview pipeline that shades with the matcap without lighting.
+        let matcap_pipeline = pipeline::create_matcap_pipeline(&mut pipeline_cache, &device, &[&camera_bind_group_layout, &object_bind_group_layout, &matcap_bind_group_layout]);
+
+        // (한국어) 시차 차폐 매핑 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a parallax occlusion mapping bind group layout.
+        let parallax_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Parallax)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 평면 바닥에 입힐 타일 무늬의 시차 차폐 매핑 재질을 생성합니다.
+        // (English Translation) Creates a tiled parallax occlusion mapping material for the plane floor.
+        let parallax_material = ParallaxMaterialBuilder::new()
+            .set_height_scale(0.06)
+            .set_steps(8, 32)
+            .build(&parallax_bind_group_layout, &device, &queue);
+        let use_parallax_floor = false;
+
+        // (한국어) 시차 차폐 매핑 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a parallax occlusion mapping graphics pipeline.
+        let parallax_pipeline = pipeline::create_parallax_pipeline(&mut pipeline_cache, &device, &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout, &parallax_bind_group_layout]);
+
+        // (한국어) 텍스처 재질 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a texture material bind group layout.
+        let material_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Material)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 텍스처 큐브의 재질을 생성합니다. 불러올 이미지가 없으면 바둑판 무늬를 사용합니다.
+        // (English Translation) Creates the material of the textured cube. If there is no image to load, a checkerboard is used.
+        // (한국어) 재질들은 서브 메쉬의 재질 번호 순서대로 놓입니다.
+        // (English Translation) The materials are placed in the order of the material indices of the sub-meshes.
+        let cube_albedo = TextureImage::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/albedo.png"))
+            .unwrap_or_else(|_| TextureImage::checkerboard(256, 8, [235, 225, 205, 255], [60, 90, 140, 255]));
+        let mut cube_material_builder = MaterialBuilder::new().set_albedo(cube_albedo);
+        if let Ok(normal_map) = TextureImage::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/normal.png")) {
+            cube_material_builder = cube_material_builder.set_normal_map(normal_map);
+        }
+        let cube_materials = [cube_material_builder.build(&material_bind_group_layout, &device, &queue)];
+
+        // (한국어) 텍스처 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a textured graphics pipeline.
+        let textured_pipeline = pipeline::create_textured_pipeline(&mut pipeline_cache, &device, &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout, &material_bind_group_layout]);
+
+        // (한국어) 블린-퐁 조명 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a Blinn-Phong lighting bind group layout.
+        let lighting_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Lighting)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 전역 조명에 더해 장면을 비추는 색이 있는 점 조명들을 생성합니다.
+        // (English Translation) Creates colored point lights that light the scene in addition to the global light.
+        let lighting = LightingBuilder::new()
+            .add_light(PhongLight::Point { position: (-2.0, 1.5, 1.5).into(), color: (1.0, 0.55, 0.25).into(), range: 4.0 })
+            .add_light(PhongLight::Point { position: (2.0, 1.2, -0.5).into(), color: (0.3, 0.5, 1.0).into(), range: 4.0 })
+            .add_light(PhongLight::Directional { direction: (0.3, 0.4, -1.0).into(), color: (0.1, 0.1, 0.15).into() })
+            .build(&lighting_bind_group_layout, &device, &queue);
+        let use_phong = false;
+
+        // (한국어) 블린-퐁 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a Blinn-Phong graphics pipeline.
+        let phong_pipeline = pipeline::create_phong_pipeline(&mut pipeline_cache, &device, &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout, &lighting_bind_group_layout]);
+
+        // (한국어) 물리 기반 재질 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a physically-based material bind group layout.
+        let pbr_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Pbr)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 왼쪽에서 오른쪽으로 거칠기가 커지고, 금속과 유전체가 번갈아 놓인 구들을 생성합니다.
+        // (English Translation) Creates spheres whose roughness increases from left to right, alternating between metals and dielectrics.
+        let pbr_spheres: Vec<_> = (0..5)
+            .map(|index| {
+                let metallic = (index % 2) as f32;
+                PbrObjectBuilder::new()
+                    .set_albedo(if metallic > 0.0 { (1.0, 0.78, 0.34).into() } else { (0.8, 0.1, 0.1).into() })
+                    .set_metallic(metallic)
+                    .set_roughness(0.1 + 0.2 * index as f32)
+                    .set_translation((-2.4 + 1.2 * index as f32, 0.5, 4.2).into())
+                    .build(&object_uniforms, &pbr_bind_group_layout, &device, &queue)
+            })
+            .collect();
+        let show_pbr_spheres = false;
+
+        // (한국어) 물리 기반 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a physically-based graphics pipeline.
+        let pbr_pipeline = pipeline::create_pbr_pipeline(&mut pipeline_cache, &device, &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout, &pbr_bind_group_layout, &lighting_bind_group_layout]);
+
+        // (한국어) 인스턴스 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create an instanced graphics pipeline.
+        let instanced_pipeline = pipeline::create_instanced_pipeline(&mut pipeline_cache, &device, bind_group_layouts);
+
+        // (한국어) 오클루전 쿼리의 대리 상자 파이프라인을 생성합니다.
+        // (English Translation) Create the proxy box pipeline of the occlusion queries.
+        let occlusion_proxy_pipeline = pipeline::create_occlusion_proxy_pipeline(&mut pipeline_cache, &device, bind_group_layouts);
+
+        // (한국어) 불투명한 큐브들의 오클루전 컬링을 생성합니다.
+        // (English Translation) Creates the occlusion culling of the opaque cubes.
+        // (한국어) 장면 파일에 불투명한 큐브가 없을 수도 있으므로 쿼리는 적어도 하나를 만듭니다.
+        // (English Translation) The scene file may have no opaque cubes, so at least one query is created.
+        let num_opaque_cubes = cubes.iter().filter(|object| !object.is_transparent()).count() as u32;
+        let occlusion = OcclusionCulling::new(num_opaque_cubes.max(1), &device);
+        let use_occlusion_culling = !use_deferred;
+
+        // (한국어) 장치가 타임스탬프 쿼리를 지원하면 렌더 패스마다 GPU 시간을 측정하는 프로파일러를 생성합니다.
+        // (English Translation) Creates the profiler measuring the GPU time of each render pass if the device supports timestamp queries.
+        let gpu_profiler = GpuProfiler::new(&device, &queue);
+        if gpu_profiler.is_none() {
+            log::warn!("Timestamp queries are not supported. The GPU time of the render passes is not measured.");
+        }
+
+        // (한국어) 반투명 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a transparent graphics pipeline.
+        let transparent_pipeline = pipeline::create_transparent_pipeline(&mut pipeline_cache, &device, bind_group_layouts);
+
+        // (한국어) 순서 독립 투명도의 누적 파이프라인을 생성합니다.
+        // (English Translation) Create the accumulation pipeline of the order-independent transparency.
+        let oit_pipeline = pipeline::create_oit_pipeline(&device, bind_group_layouts);
+
+        // (한국어) 순서 독립 투명도의 합성 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create the composite bind group layout of the order-independent transparency.
+        let oit_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(OitComposite)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::NonFiltering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 화면 크기를 따르는 텍스처들을 관리하는 렌더 타겟 풀과 깊이-스텐실 버퍼를 생성합니다.
+        // (English Translation) Creates the render target pool managing the textures that follow the screen size, and the depth-stencil buffer.
+        let mut target_pool = RenderTargetPool::new(window.inner_size().width.max(1), window.inner_size().height.max(1));
+        let depth_stencil_target = target_pool.acquire_multisampled(
+            "DepthStencilBuffer", 
+            target::depth_format(), 
+            wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
+            sample_count, 
+            &device
+        );
+
+        // (한국어) 순서 독립 투명도의 렌더 타겟들을 생성합니다.
+        // (English Translation) Creates the render targets of the order-independent transparency.
+        let oit_targets = OitTargets::new(
+            &mut target_pool, 
+            &oit_bind_group_layout, 
+            &device
+        );
+        let use_oit = false;
+
+        // (한국어) 순서 독립 투명도의 합성 파이프라인을 생성합니다.
+        // (English Translation) Create the composite pipeline of the order-independent transparency.
+        let oit_composite_pipeline = pipeline::create_oit_composite_pipeline(&device, &[&oit_bind_group_layout]);
+
+        // (한국어) 지연 렌더러의 G-버퍼 바인드 그룹 레이아웃을 생성합니다. 깊이 버퍼는 필터링하지 않는 실수 텍스처로 읽습니다.
+        // (English Translation) Create the G-buffer bind group layout of the deferred renderer. The depth buffer is read as a non-filterable float texture.
+        let deferred_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Deferred)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::NonFiltering, 
+                        ), 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 6, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Storage { read_only: true }, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 지연 렌더러를 선택하면 블린-퐁 조명들에 더해, 순방향 렌더러로는 비추기 힘든 많은 작은 점 조명들로 장면을 비춥니다.
+        // (English Translation) With the deferred renderer, the scene is lit by many small point lights that are hard to shine with the forward renderer, in addition to the Blinn-Phong lights.
+        let deferred_renderer = use_deferred.then(|| {
+            let mut builder = DeferredRendererBuilder::new().set_ambient(lighting.ambient());
+            for &light in lighting.lights() {
+                builder = builder.add_light(light);
+            }
+            for index in 0..24 {
+                let angle = index as f32 / 24.0 * std::f32::consts::TAU;
+                let color = glam::Vec3::new(
+                    0.5 + 0.5 * angle.cos(), 
+                    0.5 + 0.5 * (angle + 2.0944).cos(), 
+                    0.5 + 0.5 * (angle + 4.1888).cos()
+                );
+                builder = builder.add_light(PhongLight::Point { 
+                    position: (4.0 * angle.cos(), 0.6, 4.0 * angle.sin()).into(), 
+                    color, 
+                    range: 2.5 
+                });
+            }
+            builder.build(&mut target_pool, depth_stencil_target, &deferred_bind_group_layout, &pbr_bind_group_layout, &device, &queue)
+        });
+
+        // (한국어) 지연 렌더러의 G-버퍼 파이프라인과 조명 파이프라인을 생성합니다.
+        // (English Translation) Create the G-buffer pipeline and the lighting pipeline of the deferred renderer.
+        let gbuffer_pipeline = pipeline::create_gbuffer_pipeline(&device, &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &pbr_bind_group_layout]);
+        let deferred_lighting_pipeline = pipeline::create_deferred_lighting_pipeline(&mut pipeline_cache, &device, &[&deferred_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout]);
+
+        // (한국어) 커서 아래의 물체를 고르는 ID 버퍼 피킹을 생성합니다.
+        // (English Translation) Creates the ID buffer picking that picks the object under the cursor.
+        let id_picker = IdPicker::new(&mut target_pool, &device);
+        let id_pipeline = pipeline::create_id_pipeline(&mut pipeline_cache, &device, &[&camera_bind_group_layout, &object_bind_group_layout]);
+
+        // (한국어) 블룸, 자동 노출, 톤 매핑, 비네트와 필름 그레인을 적용하는 후처리 단계를 생성합니다.
+        // (English Translation) Creates the post-processing stage applying the bloom, automatic exposure, tonemapping, vignette and film grain.
+        // (한국어) 스왑체인이 sRGB 형식이 아니면 후처리 단계가 직접 sRGB로 인코딩합니다.
+        // (English Translation) If the swapchain is not in an sRGB format, the post-processing stage encodes to sRGB by itself.
+        let post_process = PostProcessBuilder::new()
+            .set_preserve_alpha(overlay)
+            .set_encode_srgb(!surface_format.is_srgb())
+            .build(&mut target_pool, &device, &queue);
+        let post_process_pipeline = pipeline::create_post_process_pipeline(&mut pipeline_cache, &device, &[post_process.bind_group_layout_ref()], surface_format);
+        let exposure_histogram_pipeline = pipeline::create_exposure_histogram_pipeline(&device, &[post_process.auto_exposure_ref().bind_group_layout_ref()]);
+        let exposure_average_pipeline = pipeline::create_exposure_average_pipeline(&device, &[post_process.auto_exposure_ref().bind_group_layout_ref()]);
+        let bloom_threshold_pipeline = pipeline::create_bloom_threshold_pipeline(&mut pipeline_cache, &device, &[post_process.bloom_ref().bind_group_layout_ref()]);
+        let bloom_downsample_pipeline = pipeline::create_bloom_downsample_pipeline(&mut pipeline_cache, &device, &[post_process.bloom_ref().bind_group_layout_ref()]);
+        let bloom_blur_pipeline = pipeline::create_bloom_blur_pipeline(&mut pipeline_cache, &device, &[post_process.bloom_ref().bind_group_layout_ref()]);
+
+        // (한국어) 그림자 맵 생성 파이프라인을 생성합니다.
+        // (English Translation) Create a shadow map generation pipeline.
+        let bind_group_layouts = &[&global_light_bind_group_layout, &object_bind_group_layout];
+        let shadow_pipeline = pipeline::create_shadow_pipeline(&mut pipeline_cache, &device, bind_group_layouts, shadow_filter.depth_bias());
+        let instanced_shadow_pipeline = pipeline::create_instanced_shadow_pipeline(&mut pipeline_cache, &device, bind_group_layouts, shadow_filter.depth_bias());
+
+        // (한국어) 무한 그리드 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create an infinite grid graphics pipeline.
+        let bind_group_layouts = &[&camera_bind_group_layout, &grid_bind_group_layout];
+        let grid_pipeline = pipeline::create_grid_pipeline(&device, bind_group_layouts);
+
+        // (한국어) 디버그 선 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a debug line graphics pipeline.
+        let debug_line_pipeline = pipeline::create_debug_line_pipeline(&device, &[&camera_bind_group_layout]);
+
+        // (한국어) 모니터 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a monitor graphics pipeline.
+        let bind_group_layouts = &[&camera_bind_group_layout, &monitor_bind_group_layout];
+        let monitor_pipeline = pipeline::create_monitor_pipeline(&device, bind_group_layouts);
+
+        // (한국어) 거울의 스텐실 마스크, 배경, 표면 파이프라인과 반사된 장면의 파이프라인을 생성합니다.
+        // (English Translation) Create the stencil mask, backdrop and surface pipelines of the mirror and the pipeline of the reflected scene.
+        let bind_group_layouts = &[&camera_bind_group_layout, &mirror_bind_group_layout];
+        let mirror_mask_pipeline = pipeline::create_mirror_mask_pipeline(&device, bind_group_layouts);
+        let mirror_backdrop_pipeline = pipeline::create_mirror_backdrop_pipeline(&device, bind_group_layouts);
+        let mirror_pipeline = pipeline::create_mirror_pipeline(&device, bind_group_layouts);
+        let bind_group_layouts = &[&camera_bind_group_layout, &object_bind_group_layout, &global_light_bind_group_layout, &shadow_map_bind_group_layout, &fog_bind_group_layout];
+        let reflected_pipeline = pipeline::create_reflected_pipeline(&mut pipeline_cache, &device, bind_group_layouts);
+
+        // (한국어) 수면에 반사된 장면을 그리는 파이프라인과 수면 파이프라인을 생성합니다.
+        // (English Translation) Create the pipeline drawing the scene reflected by the water surface and the water pipeline.
+        let water_reflection_pipeline = pipeline::create_water_reflection_pipeline(&mut pipeline_cache, &device, bind_group_layouts);
+        let water_pipeline = pipeline::create_water_pipeline(&device, &[&camera_bind_group_layout, &water_bind_group_layout]);
+        log::info!("Pipeline cache: {} pipelines, {} shader modules", pipeline_cache.num_pipelines(), pipeline_cache.num_shaders());
+
+        // (한국어) 텍스트 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a text bind group layout.
+        let text_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Text)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 통계 오버레이와 디버그 레이블을 그리는 텍스트 렌더러를 생성합니다.
+        // (English Translation) Creates a text renderer that draws the stats overlay and debug labels.
+        let text_renderer = TextRenderer::new(
+            text::DEFAULT_FONT, 
+            32.0, 
+            window.inner_size().width, 
+            window.inner_size().height, 
+            &text_bind_group_layout, 
+            &device, 
+            &queue
+        );
+        let show_labels = true;
+
+        // (한국어) 조명, 카메라, 물체의 값들을 실행 중에 조정하는 패널을 생성합니다.
+        // (English Translation) Creates the panel that tweaks the values of the lights, the camera, and the objects at runtime.
+        #[cfg(feature = "egui")]
+        let tweak_panel = TweakPanel::new(&window, surface_format, &device);
+        #[cfg(feature = "egui")]
+        let light_angles = glam::vec2(0.0, -90.0);
+
+        // (한국어) 텍스트 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a text graphics pipeline.
+        let bind_group_layouts = &[&text_bind_group_layout];
+        let text_pipeline = pipeline::create_text_pipeline(&device, bind_group_layouts, surface_format);
+
+        // (한국어) 스프라이트 텍스처 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a sprite texture bind group layout.
+        let sprite_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Sprite)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) HUD를 그리는 화면 공간 카메라와 스프라이트 배치를 생성합니다.
+        // (English Translation) Creates a screen space camera and sprite batch that draw the HUD.
+        let screen_camera = OrthographicCameraBuilder::new()
+            .set_width(window.inner_size().width as f32)
+            .set_height(window.inner_size().height as f32)
+            .build(&camera_bind_group_layout, &device, &queue);
+        let mut sprite_batch = SpriteBatch::new(&sprite_bind_group_layout, &device, &queue);
+
+        // (한국어) 조준점 가운데에 그릴 원형 점 텍스처를 생성합니다.
+        // (English Translation) Creates a round dot texture to draw at the center of the crosshair.
+        const DOT_SIZE: u32 = 16;
+        let mut dot_pixels = Vec::with_capacity((4 * DOT_SIZE * DOT_SIZE) as usize);
+        for y in 0..DOT_SIZE {
+            for x in 0..DOT_SIZE {
+                let offset = glam::vec2(x as f32 + 0.5, y as f32 + 0.5) - 0.5 * DOT_SIZE as f32;
+                let coverage = (0.5 * DOT_SIZE as f32 - offset.length()).clamp(0.0, 1.0);
+                dot_pixels.extend_from_slice(&[255, 255, 255, (coverage * 255.0) as u8]);
+            }
+        }
+        let dot_texture = sprite_batch.add_texture(SpriteTexture::from_rgba8(
+            DOT_SIZE, 
+            DOT_SIZE, 
+            &dot_pixels, 
+            &sprite_bind_group_layout, 
+            &device, 
+            &queue
+        ));
+
+        // (한국어) 스프라이트 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a sprite graphics pipeline.
+        let bind_group_layouts = &[&camera_bind_group_layout, &sprite_bind_group_layout];
+        let sprite_pipeline = pipeline::create_sprite_pipeline(&device, bind_group_layouts, surface_format);
+
+        // (한국어) 그림자 맵 디버그 화면의 바인드 그룹 레이아웃을 생성합니다. 그림자 맵은 필터링하지 않는 실수 텍스처로 읽습니다.
+        // (English Translation) Create the bind group layout of the shadow map debug view. The shadow map is read as a non-filterable float texture.
+        let shadow_view_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(ShadowView)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture { 
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::NonFiltering, 
+                        ), 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 전역 조명의 그림자 맵을 화면 오른쪽 아래에 보여주는 디버그 화면을 생성합니다.
+        // (English Translation) Creates the debug view showing the shadow map of the global light at the bottom-right of the screen.
+        let shadow_view = ShadowViewBuilder::new()
+            .build(&global_light, window.inner_size().width, window.inner_size().height, &shadow_view_bind_group_layout, &device, &queue);
+        let show_shadow_view = false;
+
+        // (한국어) 그림자 맵 디버그 화면의 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create the graphics pipeline of the shadow map debug view.
+        let shadow_view_pipeline = pipeline::create_shadow_view_pipeline(&mut pipeline_cache, &device, &[&shadow_view_bind_group_layout], surface_format);
+
+        // (한국어) 스카이박스를 생성하고 큐브맵을 불러옵니다.
+        // (English Translation) Creates a skybox and loads the cubemap.
+        let mut skybox = Skybox::new(&device, &queue);
+        if let Err(err) = skybox.set_skybox(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/skybox"), &device, &queue) {
+            log::warn!("Failed to load the skybox. ({})", err);
+            // (한국어) 면 이미지들이 없으면 등장방형 HDR 파노라마를 대신 불러옵니다.
+            // (English Translation) If there are no face images, loads an equirectangular HDR panorama instead.
+            if let Err(err) = skybox.set_equirect(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/skybox.hdr"), 512, &device, &queue) {
+                log::warn!("Failed to load the equirectangular skybox. ({})", err);
+            }
+        }
+
+        // (한국어) 스카이박스 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a skybox graphics pipeline.
+        let bind_group_layouts = &[&camera_bind_group_layout, skybox.bind_group_layout_ref()];
+        let skybox_pipeline = pipeline::create_skybox_pipeline(&device, bind_group_layouts);
+
+        // (한국어) 절차적 하늘 바인드 그룹 레이아웃을 생성합니다.
+        // (English Translation) Create a procedural sky bind group layout.
+        let procedural_sky_bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(ProceduralSky)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 전역 조명의 방향을 태양으로 사용하는 절차적 하늘을 생성합니다.
+        // (English Translation) Creates a procedural sky that uses the direction of the global light as the sun.
+        let procedural_sky = ProceduralSkyBuilder::new()
+            .set_sun_direction(global_light.get_look())
+            .build(&procedural_sky_bind_group_layout, &device, &queue);
+        let use_procedural_sky = !skybox.is_loaded();
+
+        // (한국어) 절차적 하늘 그래픽스 파이프라인을 생성합니다.
+        // (English Translation) Create a procedural sky graphics pipeline.
+        let bind_group_layouts = &[&camera_bind_group_layout, &procedural_sky_bind_group_layout];
+        let procedural_sky_pipeline = pipeline::create_procedural_sky_pipeline(&device, bind_group_layouts);
+
+        // (한국어) 스왑체인의 표시 모드를 정합니다. 벤치마크는 수직 동기화를 기다리지 않는 것이 기본입니다.
+        // (English Translation) Decides the present mode of the swapchain. The benchmark does not wait for the vertical sync by default.
+        let default_present_mode = match benchmark.is_some() {
+            true => wgpu::PresentMode::AutoNoVsync, 
+            false => utils::select_present_mode(settings.present_mode, &surface, &adapter), 
+        };
+        let present_mode = match options.present_mode {
+            Some(present_mode) => utils::select_present_mode(present_mode, &surface, &adapter), 
+            None => default_present_mode, 
+        };
+        log::info!("Present mode: {}", utils::present_mode_name(present_mode));
+
+        // (한국어) 스왑체인 및 프레임 버퍼를 설정합니다. 오버레이 모드에서는 미리 곱해진 알파로 창을 바탕 화면과 합성합니다.
+        // (English Translation) Sets the swapchain and frame buffer. In the overlay mode, composites the window with the desktop using premultiplied alpha.
+        let swapchain = Swapchain::new(
+            instance.clone(), 
+            surface.clone(), 
+            adapter, 
+            device.clone(), 
+            queue.clone(), 
+            surface_format, 
+            window.inner_size().width, 
+            window.inner_size().height, 
+            present_mode, 
+            overlay
+        );
+
+
+        // (한국어) 프레임 사이에 유지되는 타이머 입니다.
+        // (English Translation) The timer kept between frames.
+        let timer = timer::GameTimer::<50>::new();
+
+        // (한국어) 최근 프레임들의 CPU 시간 기록 입니다. 이동 평균이 가리는 튀는 프레임을 확인하는 데 사용합니다.
+        // (English Translation) The history of the CPU time of the recent frames. Used to find the spikes hidden by the moving average.
+        let frame_time_stats = timer::FrameTimeStats::new(1024);
+
+        // (한국어) 게임 로직을 진행하는 고정 갱신의 간격 입니다.
+        // (English Translation) The interval of the fixed updates advancing the game logic.
+        let tick_rate = options.tick_rate.unwrap_or(timer::DEFAULT_TICK_RATE);
+        log::info!("Tick rate: {} Hz", tick_rate);
+        let fixed_timestep = timer::FixedTimestep::new(tick_rate);
+
+        // (한국어) 매 프레임 다시 할당하지 않도록 프레임 사이에 재사용하는 작업 공간들 입니다.
+        // (English Translation) Scratch space reused between frames so it is not allocated again every frame.
+        let input_frame = ReplayFrame { elapsed_time_sec: 0.0, inputs: Vec::new() };
+        let input_state = InputState::new();
+        let in_frustum = Vec::new();
+        let transparent_objects: Vec<(f32, usize)> = Vec::new();
+        let security_transparent_objects: Vec<(f32, usize)> = Vec::new();
+        let nav_path = Vec::new();
+        let stats = String::new();
+        let render_target_view_descriptor = wgpu::TextureViewDescriptor { 
+            ..Default::default()
+        };
+        let num_allocations = allocation::num_allocations();
+
+        // (한국어) 이전 프레임의 그리기 호출, 인스턴스, 바인드 그룹 전환 수 입니다.
+        // (English Translation) The number of draw calls, instances and bind group switches of the previous frame.
+        let frame_stats = stats::FrameStats::default();
+
+        // (한국어) 첫 `update` 전까지 사용하는 카메라의 절두체 입니다.
+        // (English Translation) The frustum of the camera used until the first `update`.
+        let frustum = Frustum::from_matrix(&(camera.projection_transform() * camera.view_transform()));
+
+        return Self {
+            window, 
+            device, 
+            queue, 
+            overlay, 
+            depth_format, 
+            use_deferred, 
+            sample_count, 
+            surface_format, 
+            camera_bind_group_layout, 
+            scene_path, 
+            scene_watcher, 
+            camera, 
+            camera_controller, 
+            cursor_grabbed, 
+            orbit_controller, 
+            use_orbit_camera, 
+            object_bind_group_layout, 
+            object_uniforms, 
+            upload_ring, 
+            plane_mesh, 
+            cube_mesh_0, 
+            sphere_mesh, 
+            plane, 
+            cubes, 
+            cube_labels, 
+            textured_cube, 
+            terrain, 
+            show_terrain, 
+            wave_plane, 
+            displacement_pipeline, 
+            show_wave_plane, 
+            cube_bounds, 
+            scene_bvh, 
+            nav_grid, 
+            nav_waypoints, 
+            nav_agents, 
+            debug_lines, 
+            show_navigation, 
+            show_gizmos, 
+            collision_world, 
+            cube_colliders, 
+            animated_objects, 
+            camera_collider, 
+            steering_scale, 
+            steering_agents, 
+            show_steering_agents, 
+            net_session, 
+            is_net_client, 
+            input_replay, 
+            shader_watcher, 
+            benchmark, 
+            #[cfg(feature = "physics")]
+            physics_world, 
+            #[cfg(feature = "physics")]
+            pile_cubes, 
+            #[cfg(feature = "physics")]
+            camera_body, 
+            cube_field, 
+            cube_field_object, 
+            show_cube_field, 
+            cube_field_indirect, 
+            use_indirect_draw, 
+            cube_field_culler, 
+            gpu_cull_pipeline, 
+            use_gpu_culling, 
+            global_light_bind_group_layout, 
+            shadow_map_bind_group_layout, 
+            shadow_filter, 
+            global_light, 
+            spot_light, 
+            use_spot_light, 
+            fog_bind_group_layout, 
+            fog, 
+            grid_bind_group_layout, 
+            grid, 
+            show_grid, 
+            security_target, 
+            security_camera, 
+            monitor_bind_group_layout, 
+            monitor, 
+            show_monitor, 
+            mirror_bind_group_layout, 
+            mirror, 
+            show_mirror, 
+            water_bind_group_layout, 
+            water, 
+            show_water, 
+            pipeline_cache, 
+            color_pipeline, 
+            matcap_bind_group_layout, 
+            matcap, 
+            use_matcap, 
+            matcap_pipeline, 
+            parallax_bind_group_layout, 
+            parallax_material, 
+            use_parallax_floor, 
+            parallax_pipeline, 
+            material_bind_group_layout, 
+            cube_materials, 
+            textured_pipeline, 
+            lighting_bind_group_layout, 
+            lighting, 
+            use_phong, 
+            phong_pipeline, 
+            pbr_bind_group_layout, 
+            pbr_spheres, 
+            show_pbr_spheres, 
+            pbr_pipeline, 
+            instanced_pipeline, 
+            occlusion_proxy_pipeline, 
+            num_opaque_cubes, 
+            occlusion, 
+            use_occlusion_culling, 
+            gpu_profiler, 
+            transparent_pipeline, 
+            oit_pipeline, 
+            oit_bind_group_layout, 
+            target_pool, 
+            depth_stencil_target, 
+            oit_targets, 
+            use_oit, 
+            oit_composite_pipeline, 
+            deferred_bind_group_layout, 
+            deferred_renderer, 
+            gbuffer_pipeline, 
+            deferred_lighting_pipeline, 
+            id_picker, 
+            id_pipeline, 
+            post_process, 
+            post_process_pipeline, 
+            exposure_histogram_pipeline, 
+            exposure_average_pipeline, 
+            bloom_threshold_pipeline, 
+            bloom_downsample_pipeline, 
+            bloom_blur_pipeline, 
+            shadow_pipeline, 
+            instanced_shadow_pipeline, 
+            grid_pipeline, 
+            debug_line_pipeline, 
+            monitor_pipeline, 
+            mirror_mask_pipeline, 
+            mirror_backdrop_pipeline, 
+            mirror_pipeline, 
+            reflected_pipeline, 
+            water_reflection_pipeline, 
+            water_pipeline, 
+            text_bind_group_layout, 
+            text_renderer, 
+            show_labels, 
+            #[cfg(feature = "egui")]
+            tweak_panel, 
+            #[cfg(feature = "egui")]
+            light_angles, 
+            text_pipeline, 
+            sprite_bind_group_layout, 
+            screen_camera, 
+            sprite_batch, 
+            dot_texture, 
+            sprite_pipeline, 
+            shadow_view_bind_group_layout, 
+            shadow_view, 
+            show_shadow_view, 
+            shadow_view_pipeline, 
+            skybox, 
+            skybox_pipeline, 
+            procedural_sky_bind_group_layout, 
+            procedural_sky, 
+            use_procedural_sky, 
+            procedural_sky_pipeline, 
+            swapchain, 
+            timer, 
+            frame_time_stats, 
+            fixed_timestep, 
+            input_frame, 
+            input_state, 
+            in_frustum, 
+            transparent_objects, 
+            security_transparent_objects, 
+            nav_path, 
+            stats, 
+            render_target_view_descriptor, 
+            num_allocations, 
+            frame_stats, 
+            frustum, 
+            draw_mirror: false, 
+            draw_water: false, 
+            num_cubes: 0, 
+            running: true, 
+        };
+    }
+
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 작업이 끝날 때 까지 기다린 후 스왑체인을 새 크기로 재설정하고, 화면 크기를 따르는 렌더 타겟과 단계들을 갱신합니다. </br>
+    /// 크기가 0이거나 바뀌지 않았으면 아무것도 하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Waits until all operations are completed, reconfigures the swapchain with the new size, and updates the render targets and the stages following the screen size. </br>
+    /// Does nothing if the size is zero or did not change. </br>
+    /// 
+    pub fn resize(&mut self, width: u32, height: u32) {
+        if !self.swapchain.resize(width, height) {
+            return;
+        }
+
+        // (한국어) 렌더 타겟 풀의 텍스처들을 한 번에 재생성하고, 이를 사용하는 단계들이 새 텍스처를 묶도록 합니다.
+        // (English Translation) Recreates the textures of the render target pool at once, and lets the stages using them bind the new textures.
+        if self.target_pool.resize(width, height, &self.device) {
+            self.oit_targets.resize(&self.target_pool, &self.oit_bind_group_layout, &self.device);
+            if let Some(deferred_renderer) = self.deferred_renderer.as_mut() {
+                deferred_renderer.resize(&self.target_pool, &self.deferred_bind_group_layout, &self.device);
+            }
+            self.id_picker.resize(&self.target_pool);
+            self.post_process.resize(&self.target_pool, &self.device);
+        }
+
+        // (한국어) 텍스트 렌더러의 화면 크기를 갱신합니다.
+        // (English Translation) Updates the screen size of the text renderer.
+        self.text_renderer.resize(width, height, &self.queue);
+
+        // (한국어) 그림자 맵 디버그 화면을 화면 구석으로 옮깁니다.
+        // (English Translation) Moves the shadow map debug view to the corner of the screen.
+        self.shadow_view.resize(width, height, &self.queue);
+
+        // (한국어) 수면의 반사 렌더 타겟을 화면 크기에 맞춰 다시 생성합니다.
+        // (English Translation) Recreates the reflection render target of the water to fit the screen size.
+        self.water.resize(width, height, &self.water_bind_group_layout, &self.device);
+
+        // (한국어) 화면 공간 카메라의 크기를 갱신합니다.
+        // (English Translation) Updates the size of the screen space camera.
+        self.screen_camera.set_size(width as f32, height as f32);
+        self.screen_camera.update_resource(&self.queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임의 창 이벤트들을 처리하고, 입력과 게임 로직, 고정 갱신을 진행한 후 이번 프레임에 그릴 자원들을 준비합니다. </br>
+    /// 벤치마크나 재생이 끝나면 `is_running`이 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Handles the window events of one frame, advances the input, the game logic and the fixed updates, and then prepares the resources drawn in this frame. </br>
+    /// `is_running` returns `false` when the benchmark or the playback has ended. </br>
+    /// 
+    pub fn update<I: IntoIterator<Item = Event<()>>>(&mut self, events: I) {
+        // (한국어) 타이머를 갱신합니다.
+        // (English Translation) Updates the timer. 
+        self.timer.tick();
+        self.frame_time_stats.begin_frame();
+
+        // (한국어) 새 프레임을 시작하여 유니폼 버퍼들이 처리 중인 프레임과 겹치지 않는 조각에 쓰도록 합니다.
+        // (English Translation) Begins a new frame so the uniform buffers write to slices that do not overlap the frames in flight.
+        resource::begin_frame();
+
+        // (한국어) 이전 프레임 동안 일어난 힙 할당 횟수를 셉니다.
+        // (English Translation) Counts the heap allocations that happened during the previous frame.
+        let frame_allocations = allocation::num_allocations() - self.num_allocations;
+        self.num_allocations = allocation::num_allocations();
+
+        // (한국어) 창 이벤트를 처리합니다. 창 크기 변경 외의 이벤트는 입력 이벤트로 모읍니다.
+        // (English Translation) Handles window events. Events other than resizing are collected as input events.
+        self.input_frame.elapsed_time_sec = self.timer.elapsed_time_sec();
+        self.input_frame.inputs.clear();
+        for event in events {
+            match event {
+                Event::WindowEvent { event, .. } => match event {
+                    WindowEvent::Resized(size) => self.resize(size.width, size.height), 
+                    event => {
+                        // (한국어) 조정 패널이 사용한 이벤트는 입력 이벤트로 모으지 않습니다.
+                        // (English Translation) Events used by the tweak panel are not collected as input events.
+                        #[cfg(feature = "egui")]
+                        if self.tweak_panel.handle_event(&self.window, &event) {
+                            continue;
+                        }
+                        self.input_frame.inputs.extend(InputEvent::from_window_event(&event));
+                    }, 
+                }, 
+                Event::DeviceEvent { event, .. } => self.input_frame.inputs.extend(InputEvent::from_device_event(&event)), 
+                _ => { /*--- empty ---*/ }
+            }
+        }
+
+        // (한국어) 각 단계가 서로 다른 필드를 동시에 빌릴 수 있도록 필드들을 따로 빌립니다.
+        // (English Translation) Borrows the fields separately so each stage can borrow different fields at the same time.
+        let Self {
+            depth_format, 
+            use_deferred, 
+            sample_count, 
+            scene_path, 
+            scene_watcher, 
+            camera, 
+            camera_controller, 
+            cursor_grabbed, 
+            orbit_controller, 
+            use_orbit_camera, 
+            object_uniforms, 
+            cube_mesh_0, 
+            cubes, 
+            cube_labels, 
+            terrain, 
+            show_terrain, 
+            wave_plane, 
+            show_wave_plane, 
+            cube_bounds, 
+            scene_bvh, 
+            nav_grid, 
+            nav_waypoints, 
+            nav_agents, 
+            debug_lines, 
+            show_navigation, 
+            show_gizmos, 
+            collision_world, 
+            cube_colliders, 
+            animated_objects, 
+            camera_collider, 
+            steering_scale, 
+            steering_agents, 
+            show_steering_agents, 
+            net_session, 
+            is_net_client, 
+            input_replay, 
+            benchmark, 
+            #[cfg(feature = "physics")]
+            physics_world, 
+            #[cfg(feature = "physics")]
+            pile_cubes, 
+            #[cfg(feature = "physics")]
+            camera_body, 
+            cube_field, 
+            show_cube_field, 
+            cube_field_indirect, 
+            use_indirect_draw, 
+            cube_field_culler, 
+            gpu_cull_pipeline, 
+            use_gpu_culling, 
+            shadow_filter, 
+            global_light, 
+            spot_light, 
+            use_spot_light, 
+            fog, 
+            show_grid, 
+            security_camera, 
+            show_monitor, 
+            mirror, 
+            show_mirror, 
+            water, 
+            show_water, 
+            use_matcap, 
+            use_parallax_floor, 
+            use_phong, 
+            show_pbr_spheres, 
+            num_opaque_cubes, 
+            occlusion, 
+            use_occlusion_culling, 
+            gpu_profiler, 
+            use_oit, 
+            deferred_renderer, 
+            id_picker, 
+            post_process, 
+            text_renderer, 
+            show_labels, 
+            #[cfg(feature = "egui")]
+            tweak_panel, 
+            sprite_batch, 
+            dot_texture, 
+            show_shadow_view, 
+            skybox, 
+            procedural_sky, 
+            use_procedural_sky, 
+            swapchain, 
+            timer, 
+            frame_time_stats, 
+            fixed_timestep, 
+            input_frame, 
+            input_state, 
+            in_frustum, 
+            transparent_objects, 
+            nav_path, 
+            stats, 
+            frame_stats, 
+            window, 
+            device, 
+            queue, 
+            frustum, 
+            draw_mirror, 
+            draw_water, 
+            num_cubes, 
+            running, 
+            ..
+        } = self;
+
+        // (한국어) 이번 프레임을 기록하거나, 재생 중이면 기록된 프레임으로 바꿉니다. 재생이 끝나면 애플리케이션을 종료합니다.
+        // (English Translation) Records this frame, or replaces it with the recorded frame when playing back. Quits the application when the playback ends.
+        if let Some(replay) = input_replay.as_mut() {
+            match replay.advance(input_frame) {
+                Ok(true) => { /* empty */ }, 
+                Ok(false) => {
+                    log::info!("Finished replaying the input recording.");
+                    *running = false;
+                    window.request_redraw();
+                    return;
+                }, 
+                Err(e) => {
+                    match replay {
+                        InputReplay::Record(_) => log::error!("Failed to record the input. ({})", e), 
+                        InputReplay::Playback(_) => log::error!("Failed to replay the input. ({})", e), 
+                    }
+                    *input_replay = None;
+                }, 
+            }
+        }
+
+        // (한국어) 이번 프레임의 입력 이벤트들로 입력 상태를 갱신합니다. 자유 카메라 컨트롤러는 이 상태를 읽습니다.
+        // (English Translation) Updates the input state with the input events of this frame. The free camera controller reads this state.
+        input_state.update(&input_frame.inputs);
+
+        // (한국어) 이번 프레임의 입력을 처리합니다.
+        // (English Translation) Handles the input of this frame.
+        for input in input_frame.inputs.iter().copied() {
+            if *use_orbit_camera && orbit_controller.handle_input(&input) {
+                continue;
+            }
+
+            match input {
+                InputEvent::LeftMousePressed => {
+                    // (한국어) 커서 아래의 물체를 고르도록 요청합니다.
+                    // (English Translation) Requests picking the object under the cursor.
+                    let (x, y) = input_state.cursor_position();
+                    id_picker.request_pick(x, y);
+                }, 
+                InputEvent::Key { code, pressed, repeat } => {
+                    // (한국어) 전역 조명의 그림자 맵 디버그 화면을 켜거나 끕니다.
+                    // (English Translation) Toggles the shadow map debug view of the global light.
+                    if KeyCode::F1 == code && pressed && !repeat {
+                        *show_shadow_view = !*show_shadow_view;
+                        log::info!("Shadow map view: {}", if *show_shadow_view { "on" } else { "off" });
+                    }
+
+                    // (한국어) 그림자 맵의 필터링 커널을 바꿉니다.
+                    // (English Translation) Switches the filtering kernel of the shadow maps.
+                    if KeyCode::F2 == code && pressed && !repeat {
+                        shadow_filter.set_kernel(shadow_filter.kernel().next());
+                        shadow_filter.update_resource(queue);
+                        log::info!("Shadow filter: {}", shadow_filter.kernel().name());
+                    }
+
+                    // (한국어) 자유 카메라와 궤도 카메라를 전환합니다. 궤도 카메라는 현재 시점에서 시작합니다.
+                    // (English Translation) Switches between the free camera and the orbit camera. The orbit camera starts from the current view.
+                    if KeyCode::Tab == code && pressed && !repeat {
+                        *use_orbit_camera = !*use_orbit_camera;
+                        if *use_orbit_camera {
+                            orbit_controller.fit_to(camera);
+                            // (한국어) 궤도 카메라는 커서 위치를 사용하므로 가둔 커서를 풉니다.
+                            // (English Translation) The orbit camera uses the cursor position, so the grabbed cursor is released.
+                            if *cursor_grabbed {
+                                *cursor_grabbed = input::set_cursor_grab(window, false);
+                                camera_controller.set_mouse_look(*cursor_grabbed);
+                            }
+                        }
+                        log::info!("Camera mode: {}", if *use_orbit_camera { "orbit" } else { "free" });
+                    }
+
+                    // (한국어) 커서를 창에 가두고 마우스의 상대 이동만으로 자유 카메라가 주위를 둘러보게 하거나, 커서를 다시 풉니다. Esc 키로도 풀 수 있습니다.
+                    // (English Translation) Grabs the cursor so the free camera looks around with only the relative mouse motion, or releases the cursor again. Esc also releases it.
+                    if (KeyCode::F7 == code || (KeyCode::Escape == code && *cursor_grabbed)) && pressed && !repeat {
+                        if *use_orbit_camera && !*cursor_grabbed {
+                            log::warn!("The cursor can only be grabbed with the free camera.");
+                        } else {
+                            *cursor_grabbed = input::set_cursor_grab(window, !*cursor_grabbed);
+                            camera_controller.set_mouse_look(*cursor_grabbed);
+                            log::info!("Cursor grab: {}", if *cursor_grabbed { "on" } else { "off" });
+                        }
+                    }
+
+                    // (한국어) 평면 바닥과 무한 그리드를 전환합니다.
+                    // (English Translation) Toggles between the plane floor and the infinite grid.
+                    if KeyCode::KeyG == code && pressed && !repeat {
+                        *show_grid = !*show_grid;
+                    }
+
+                    // (한국어) 안개를 켜거나 끕니다.
+                    // (English Translation) Turns the fog on or off.
+                    if KeyCode::KeyF == code && pressed && !repeat {
+                        fog.set_enabled(!fog.is_enabled());
+                        fog.update_resource(queue);
+                    }
+
+                    // (한국어) 인스턴스 큐브 필드 스트레스 테스트를 켜거나 끕니다.
+                    // (English Translation) Turns the instanced cube field stress test on or off.
+                    if KeyCode::KeyI == code && pressed && !repeat {
+                        *show_cube_field = !*show_cube_field;
+                    }
+
+                    // (한국어) 큐브 필드의 직접 그리기와 간접 그리기를 전환합니다.
+                    // (English Translation) Toggles between direct and indirect drawing of the cube field.
+                    if KeyCode::KeyJ == code && pressed && !repeat {
+                        *use_indirect_draw = !*use_indirect_draw;
+                    }
+
+                    // (한국어) 큐브 필드의 GPU 절두체 컬링을 켜거나 끕니다.
+                    // (English Translation) Turns the GPU frustum culling of the cube field on or off.
+                    if KeyCode::F3 == code && pressed && !repeat {
+                        *use_gpu_culling = !*use_gpu_culling;
+                        if *use_gpu_culling && gpu_cull_pipeline.is_none() {
+                            *gpu_cull_pipeline = Some(pipeline::create_gpu_cull_pipeline(device, &[cube_field_culler.bind_group_layout_ref()]));
+                        }
+                        log::info!("GPU culling: {}", if *use_gpu_culling { "on" } else { "off" });
+                    }
+
+                    // (한국어) 평면 반사를 사용하는 수면을 켜거나 끕니다.
+                    // (English Translation) Turns the water surface using a planar reflection on or off.
+                    if KeyCode::F4 == code && pressed && !repeat {
+                        *show_water = !*show_water;
+                    }
+
+                    // (한국어) 현재 카메라와 전역 조명, 안개, 그리고 오브젝트들을 불러온 장면 파일에 저장합니다. 불러온 파일이 없으면 기본 경로에 저장합니다.
+                    // (English Translation) Saves the current camera, global light, fog and objects to the loaded scene file. If no file was loaded, it is saved to the default path.
+                    if KeyCode::F6 == code && pressed && !repeat {
+                        let scene = Scene {
+                            camera: SceneCamera { translation: camera.get_translation(), rotation: camera.get_rotation() }, 
+                            light: SceneLight {
+                                translation: global_light.get_translation(), 
+                                rotation: global_light.get_rotation(), 
+                                color: global_light.light_color()
+                            }, 
+                            fog: SceneFog::from_fog(fog), 
+                            objects: cubes.iter().zip(cube_labels.iter())
+                                .map(|(object, label)| SceneObject::from_object(label, SceneMesh::Cube, object))
+                                .chain(animated_objects.iter().enumerate().map(|(index, (object, _))| {
+                                    SceneObject::from_object(&format!("Sphere {}", index), SceneMesh::Sphere, object.object_ref())
+                                }))
+                                .collect(), 
+                        };
+                        let path = scene_path.as_deref().unwrap_or(Path::new(scene::DEFAULT_SCENE_PATH));
+                        match scene::save_to_file(path, &scene) {
+                            Ok(()) => {
+                                log::info!("Saved the scene to {}. ({} objects)", path.display(), scene.objects.len());
+                                if let Some(scene_watcher) = scene_watcher.as_mut() {
+                                    scene_watcher.refresh();
+                                }
+                            }, 
+                            Err(e) => log::error!("Failed to save the scene {}: {}", path.display(), e), 
+                        }
+                    }
+
+                    // (한국어) 안개 모드를 순서대로 바꿉니다.
+                    // (English Translation) Cycles through the fog modes.
+                    if KeyCode::F5 == code && pressed && !repeat {
+                        fog.set_mode(fog.mode().next());
+                        fog.update_resource(queue);
+                        log::info!("Fog mode: {}", fog.mode().name());
+                    }
+
+                    // (한국어) 오클루전 컬링을 켜거나 끕니다.
+                    // (English Translation) Turns the occlusion culling on or off.
+                    if KeyCode::KeyC == code && pressed && !repeat && *use_deferred {
+                        log::warn!("Occlusion culling is only supported by the forward swapchain.");
+                    } else if KeyCode::KeyC == code && pressed && !repeat {
+                        *use_occlusion_culling = !*use_occlusion_culling;
+                        occlusion.reset();
+                    }
+
+                    // (한국어) 정렬된 알파 블렌딩과 순서 독립 투명도를 전환합니다.
+                    // (English Translation) Toggles between sorted alpha blending and order-independent transparency.
+                    if KeyCode::KeyO == code && pressed && !repeat {
+                        *use_oit = !*use_oit;
+                    }
+
+                    // (한국어) 평면 바닥과 지형을 전환합니다.
+                    // (English Translation) Toggles between the plane floor and the terrain.
+                    if KeyCode::KeyT == code && pressed && !repeat {
+                        *show_terrain = !*show_terrain;
+                    }
+
+                    // (한국어) 전역 조명과 스포트라이트를 전환합니다.
+                    // (English Translation) Switches between the global light and the spot light.
+                    if KeyCode::KeyZ == code && pressed && !repeat {
+                        *use_spot_light = !*use_spot_light;
+                    }
+
+                    // (한국어) 후처리 효과를 켜거나 끕니다.
+                    // (English Translation) Turns the post-processing effects on or off.
+                    if KeyCode::KeyV == code && pressed && !repeat {
+                        post_process.set_enabled(!post_process.is_enabled(), queue);
+                    }
+
+                    // (한국어) 자동 노출을 켜거나 끕니다.
+                    // (English Translation) Turns the automatic exposure on or off.
+                    if KeyCode::KeyX == code && pressed && !repeat {
+                        let enabled = post_process.auto_exposure_ref().is_enabled();
+                        post_process.auto_exposure_mut().set_enabled(!enabled, queue);
+                    }
+
+                    // (한국어) 감시 카메라 모니터를 켜거나 끕니다.
+                    // (English Translation) Turns the security camera monitor on or off.
+                    if KeyCode::KeyM == code && pressed && !repeat {
+                        *show_monitor = !*show_monitor;
+                    }
+
+                    // (한국어) 거울을 켜거나 끕니다.
+                    // (English Translation) Turns the mirror on or off.
+                    if KeyCode::KeyR == code && pressed && !repeat {
+                        if target::has_stencil() {
+                            *show_mirror = !*show_mirror;
+                        } else {
+                            log::warn!("The mirror needs a depth buffer format with a stencil.");
+                        }
+                    }
+
+                    // (한국어) 조명 음영과 매트캡 미리보기를 전환합니다.
+                    // (English Translation) Toggles between the lit shading and the matcap preview.
+                    if KeyCode::KeyP == code && pressed && !repeat {
+                        *use_matcap = !*use_matcap;
+                    }
+
+                    // (한국어) 평면 음영과 여러 조명의 블린-퐁 음영을 전환합니다.
+                    // (English Translation) Toggles between the flat shading and the Blinn-Phong shading of multiple lights.
+                    if KeyCode::Digit1 == code && pressed && !repeat {
+                        *use_phong = !*use_phong;
+                        log::info!("Shading: {}", if *use_phong { "Blinn-Phong" } else { "flat" });
+                    }
+
+                    // (한국어) 물리 기반 구들을 보이거나 숨깁니다.
+                    // (English Translation) Shows or hides the physically-based spheres.
+                    if KeyCode::Digit2 == code && pressed && !repeat {
+                        *show_pbr_spheres = !*show_pbr_spheres;
+                    }
+
+                    // (한국어) 블룸의 세기를 단계별로 바꿉니다. 세기가 0이면 블룸이 꺼집니다.
+                    // (English Translation) Steps through the intensities of the bloom. The bloom is off when the intensity is 0.
+                    if KeyCode::Digit3 == code && pressed && !repeat {
+                        let current = post_process.bloom_ref().intensity();
+                        let intensity = [0.05, 0.1, 0.2, 0.4].into_iter().find(|&step| step > current + 1e-4).unwrap_or(0.0);
+                        post_process.set_bloom_intensity(intensity, queue);
+                        log::info!("Bloom intensity: {}", intensity);
+                    }
+
+                    // (한국어) 블룸이 시작되는 밝기의 임계값을 단계별로 바꿉니다.
+                    // (English Translation) Steps through the brightness thresholds at which the bloom starts.
+                    if KeyCode::Digit4 == code && pressed && !repeat {
+                        let current = post_process.bloom_ref().threshold();
+                        let threshold = [1.0, 1.5, 2.0, 4.0].into_iter().find(|&step| step > current + 1e-4).unwrap_or(0.5);
+                        post_process.bloom_mut().set_threshold(threshold, queue);
+                        log::info!("Bloom threshold: {}", threshold);
+                    }
+
+                    // (한국어) 경계 상자, 조명의 축, 감시 카메라의 절두체를 나타내는 디버그 선을 보이거나 숨깁니다.
+                    // (English Translation) Shows or hides the debug lines showing the bounding boxes, the axes of the lights, and the frustum of the security camera.
+                    if KeyCode::Digit5 == code && pressed && !repeat {
+                        *show_gizmos = !*show_gizmos;
+                    }
+
+                    // (한국어) 조명, 카메라, 물체의 값들을 조정하는 패널을 보이거나 숨깁니다.
+                    // (English Translation) Shows or hides the panel tweaking the values of the lights, the camera, and the objects.
+                    #[cfg(feature = "egui")]
+                    if KeyCode::Digit6 == code && pressed && !repeat {
+                        tweak_panel.set_visible(!tweak_panel.is_visible());
+                    }
+
+                    // (한국어) 0 키로 최근 프레임 시간들과 GPU 시간들의 요약을 출력합니다.
+                    // (English Translation) The 0 key prints the summary of the recent frame times and GPU times.
+                    if KeyCode::Digit0 == code && pressed && !repeat {
+                        log::info!("Frame times: {}", frame_time_stats.summary());
+                        if let Some(gpu_profiler) = gpu_profiler.as_ref() {
+                            for (label, time_ms) in gpu_profiler.results() {
+                                log::info!("GPU {}: {:.3} ms", label, time_ms);
+                            }
+                        }
+                    }
+
+                    // (한국어) F8 키로 최근 프레임 시간들을 CSV 파일로 내보냅니다.
+                    // (English Translation) The F8 key exports the recent frame times to a CSV file.
+                    if KeyCode::F8 == code && pressed && !repeat {
+                        match frame_time_stats.write_csv(timer::FRAME_TIMES_CSV_PATH) {
+                            Ok(()) => log::info!("Exported {} frame times to {}.", frame_time_stats.len(), timer::FRAME_TIMES_CSV_PATH), 
+                            Err(e) => log::error!("Failed to export the frame times: {}", e), 
+                        }
+                    }
+
+                    // (한국어) F11 키로 테두리 없는 전체 화면을, Alt+Enter 키로 독점 전체 화면을 켜거나 끕니다.
+                    // (English Translation) F11 toggles the borderless fullscreen, and Alt+Enter toggles the exclusive fullscreen.
+                    let alt = input_state.is_key_pressed(KeyCode::AltLeft) || input_state.is_key_pressed(KeyCode::AltRight);
+                    if (KeyCode::F11 == code || (KeyCode::Enter == code && alt)) && pressed && !repeat {
+                        toggle_fullscreen(window, KeyCode::Enter == code);
+                    }
+
+                    // (한국어) 장면의 시간을 멈추거나 다시 흐르게 합니다. 카메라는 계속 움직일 수 있습니다.
+                    // (English Translation) Pauses or resumes the time of the scene. The camera can still move.
+                    if KeyCode::Space == code && pressed && !repeat {
+                        match timer.is_paused() {
+                            true => timer.resume(), 
+                            false => timer.pause(), 
+                        }
+                        log::info!("Paused: {}", timer.is_paused());
+                    }
+
+                    // (한국어) 장면의 시간이 흐르는 배율을 절반 또는 두 배로 바꿉니다.
+                    // (English Translation) Halves or doubles the rate at which the time of the scene flows.
+                    if (KeyCode::Digit8 == code || KeyCode::Digit9 == code) && pressed && !repeat {
+                        let factor = if KeyCode::Digit8 == code { 0.5 } else { 2.0 };
+                        timer.set_time_scale((timer.time_scale() * factor).clamp(0.125, 4.0));
+                        log::info!("Time scale: {}x", timer.time_scale());
+                    }
+
+                    // (한국어) 표면이 지원하는 다음 표시 모드로 바꾸고 스왑체인을 다시 설정합니다.
+                    // (English Translation) Switches to the next present mode supported by the surface and reconfigures the swapchain.
+                    if KeyCode::Digit7 == code && pressed && !repeat {
+                        let present_mode = swapchain.cycle_present_mode();
+                        log::info!("Present mode: {}", utils::present_mode_name(present_mode));
+                    }
+
+                    // (한국어) 평면 바닥의 시차 차폐 매핑 재질을 켜거나 끕니다.
+                    // (English Translation) Turns the parallax occlusion mapping material of the plane floor on or off.
+                    if KeyCode::KeyH == code && pressed && !repeat {
+                        *use_parallax_floor = !*use_parallax_floor;
+                    }
+
+                    // (한국어) 컴퓨트 쉐이더로 움직이는 물결 평면을 켜거나 끕니다.
+                    // (English Translation) Turns the wave plane moved by the compute shader on or off.
+                    if KeyCode::KeyN == code && pressed && !repeat {
+                        *show_wave_plane = !*show_wave_plane;
+                    }
+
+                    // (한국어) 조향 에이전트들을 켜거나 끕니다. 켜져 있는 동안에만 충돌체가 등록됩니다.
+                    // (English Translation) Turns the steering agents on or off. Their colliders are only registered while they are on.
+                    if KeyCode::KeyU == code && pressed && !repeat {
+                        *show_steering_agents = !*show_steering_agents;
+                        for (agent, _, collider) in steering_agents.iter_mut() {
+                            *collider = match collider.take() {
+                                Some(collider) => {
+                                    collision_world.remove(collider);
+                                    None
+                                }, 
+                                None => Some(collision_world.insert(
+                                    "Agent".into(), 
+                                    CollisionShape::Obb { half_extents: glam::Vec3::splat(0.5) }, 
+                                    &agent.world_transform(*steering_scale)
+                                )), 
+                            };
+                        }
+                    }
+
+                    // (한국어) 내비게이션 에이전트들의 이동과 경로 표시를 켜거나 끕니다.
+                    // (English Translation) Turns the movement and the path display of the navigation agents on or off.
+                    if KeyCode::KeyY == code && pressed && !repeat {
+                        *show_navigation = !*show_navigation;
+                    }
+
+                    // (한국어) 큐브 더미를 처음 위치로 되돌려 다시 떨어뜨립니다.
+                    // (English Translation) Returns the pile of cubes to their initial positions and drops them again.
+                    #[cfg(feature = "physics")]
+                    if KeyCode::KeyB == code && pressed && !repeat {
+                        for (_, body, start) in pile_cubes.iter() {
+                            physics_world.push_transform(*body, start);
+                            physics_world.set_velocity(*body, glam::Vec3::ZERO, glam::Vec3::ZERO);
+                        }
+                    }
+
+                    // (한국어) 큐브맵 스카이박스와 절차적 하늘을 전환합니다.
+                    // (English Translation) Toggles between the cubemap skybox and the procedural sky.
+                    if KeyCode::KeyK == code && pressed && !repeat {
+                        *use_procedural_sky = !*use_procedural_sky || !skybox.is_loaded();
+                    }
+
+                    // (한국어) 디버그 레이블을 켜거나 끕니다.
+                    // (English Translation) Turns the debug labels on or off.
+                    if KeyCode::KeyL == code && pressed && !repeat {
+                        *show_labels = !*show_labels;
+                    }
+                }, 
+                _ => { /* empty */ }, 
+            }
+        }
+
+        // (한국어) 모인 입력으로 카메라를 자유롭게 움직이거나 초점 주위로 돌립니다.
+        // (English Translation) Moves the camera freely or orbits it around the focus with the collected input.
+        let mut camera_changed = match use_orbit_camera {
+            true => orbit_controller.update(camera), 
+            false => camera_controller.update(camera, input_state, input_frame.elapsed_time_sec), 
+        };
+
+        // (한국어) 왼쪽과 오른쪽 화살표 키를 누르고 있는 동안 카메라를 월드의 Y 축으로 돌립니다.
+        // (English Translation) Rotates the camera around the world Y axis while the left or right arrow key is held down.
+        let turn = input_state.is_key_pressed(KeyCode::ArrowRight) as i32 as f32 - input_state.is_key_pressed(KeyCode::ArrowLeft) as i32 as f32;
+        if turn != 0.0 {
+            let rot = glam::Mat4::from_quat(glam::Quat::from_rotation_y(turn * 180.0f32.to_radians() * input_frame.elapsed_time_sec));
+            *camera.world_transform_mut() = rot.mul_mat4(camera.world_transform_ref());
+            camera_changed = true;
+        }
+        if camera_changed {
+            camera.update_resource(queue);
+        }
+
+        // (한국어) 감시하는 장면 파일이 바뀌면 오브젝트들과 그 충돌체들, 공간 색인, 전역 조명, 그리고 안개를 다시 만듭니다. 카메라의 위치와 방향은 그대로 유지합니다.
+        // (English Translation) If the watched scene file has changed, rebuilds the objects and their colliders, the spatial index, the global light and the fog. The camera pose is kept as it is.
+        // (한국어) 내비게이션 격자와 물리 세계의 정적 물체들은 시작할 때의 배치를 계속 사용합니다.
+        // (English Translation) The navigation grid and the static bodies of the physics world keep using the layout at startup.
+        match scene_watcher.as_mut().and_then(SceneWatcher::poll) {
+            Some(Ok(scene)) => {
+                for collider in cube_colliders.drain(..) {
+                    collision_world.remove(collider);
+                }
+                for (_, collider) in animated_objects.drain(..) {
+                    collision_world.remove(collider);
+                }
+                cubes.clear();
+
+                let (new_cubes, new_cube_labels, spheres) = scene.build_objects(object_uniforms, queue);
+                *cubes = new_cubes;
+                *cube_labels = new_cube_labels.into_iter().map(Rc::from).collect();
+                *cube_colliders = cubes.iter().zip(cube_labels.iter())
+                    .map(|(object, label)| collision_world.insert(label.clone(), CollisionShape::Obb { half_extents: glam::Vec3::splat(0.5) }, object.world_transform_ref()))
+                    .collect();
+                *animated_objects = spheres.into_iter()
+                    .map(|object| {
+                        let collider = collision_world.insert("Sphere".into(), CollisionShape::Sphere { radius: 0.5 }, object.object_ref().world_transform_ref());
+                        (object, collider)
+                    })
+                    .collect();
+
+                *scene_bvh = Bvh::default();
+                for (index, object) in cubes.iter().enumerate() {
+                    scene_bvh.insert(cube_bounds.transform(object.world_transform_ref()), index);
+                }
+                *num_opaque_cubes = cubes.iter().filter(|object| !object.is_transparent()).count() as u32;
+                *occlusion = OcclusionCulling::new((*num_opaque_cubes).max(1), device);
+
+                global_light.set_translation(scene.light.translation);
+                global_light.set_rotation(scene.light.rotation);
+                global_light.set_light_color(scene.light.color);
+                global_light.update_resource(queue);
+                scene.fog.apply(fog);
+                fog.update_resource(queue);
+                log::info!("Reloaded the scene. ({} objects)", scene.objects.len());
+            }, 
+            Some(Err(e)) => log::error!("Failed to reload the scene: {}", e), 
+            None => { /*--- empty ---*/ }, 
+        }
+
+        // (한국어) 벤치마크 중에는 카메라를 정해진 경로로 움직입니다.
+        // (English Translation) Moves the camera along the fixed path during the benchmark.
+        if let Some(benchmark) = benchmark.as_mut() {
+            benchmark.begin_frame();
+            *camera.world_transform_mut() = benchmark.camera_transform();
+            camera.update_resource(queue);
+        }
+
+        // (한국어) 조명 단계가 깊이로부터 월드 위치를 복원하도록 지연 렌더러에 카메라를 전달합니다.
+        // (English Translation) Passes the camera to the deferred renderer so the lighting pass reconstructs the world position from the depth.
+        if let Some(deferred_renderer) = deferred_renderer.as_mut() {
+            deferred_renderer.update_camera(camera, queue);
+        }
+
+        // (한국어) 절차적 하늘의 태양을 전역 조명의 방향에 맞춥니다.
+        // (English Translation) Aligns the sun of the procedural sky with the direction of the global light.
+        if !procedural_sky.sun_direction().abs_diff_eq(global_light.get_look(), 1e-6) {
+            procedural_sky.set_sun_direction(global_light.get_look());
+            procedural_sky.update_resource(queue);
+        }
+
+        // (한국어) 시간 배율을 적용한 장면의 시간 입니다. 멈춘 동안에는 움직이는 물체들이 멈추고, 카메라는 계속 움직입니다.
+        // (English Translation) The time of the scene with the time scale applied. While paused, the moving objects stop, and the camera keeps moving.
+        let scene_time_sec = timer.scale(input_frame.elapsed_time_sec);
+
+        // (한국어) 애니메이션 오브젝트들을 진행하고 충돌체를 따라 옮깁니다.
+        // (English Translation) Advances the animated objects and moves their colliders along.
+        // (한국어) 애니메이션은 렌더링 속도와 상관없이 고정된 간격으로 진행되고, 그리기 전에 직전 두 갱신 사이를 보간합니다.
+        // (English Translation) The animations advance at a fixed interval regardless of the render rate, and are interpolated between the last two updates before drawing.
+        let num_fixed_steps = fixed_timestep.advance(scene_time_sec);
+        for (object, collider) in animated_objects.iter_mut() {
+            for _ in 0..num_fixed_steps {
+                object.fixed_update(fixed_timestep.step_sec());
+            }
+            collision_world.set_transform(*collider, object.current_transform_ref());
+            object.interpolate(fixed_timestep.alpha(), queue);
+        }
+
+        // (한국어) 카메라 주변의 지형 청크들을 불러오고 세부 수준을 갱신합니다.
+        // (English Translation) Streams the terrain chunks around the camera and updates their level of detail.
+        if *show_terrain {
+            terrain.update(camera.get_translation(), device, queue);
+        }
+
+        // (한국어) 조향 에이전트들을 배회, 회피, 경계 유지 힘과 가까운 카메라를 쫓는 힘으로 움직입니다.
+        // (English Translation) Moves the steering agents with the wander, avoid and contain forces, and the force chasing the camera when it is close.
+        // (한국어) 클라이언트에서는 서버에서 받은 변환을 사용합니다.
+        // (English Translation) On a client, the transforms received from the server are used.
+        if *show_steering_agents && !*is_net_client {
+            let target = camera.get_translation();
+            for (agent, object, collider) in steering_agents.iter_mut() {
+                let mut force = agent.wander(scene_time_sec)
+                    + 2.0 * agent.avoid(collision_world)
+                    + agent.contain((-5.0, -5.0).into(), (5.0, 5.0).into());
+                if agent.position().distance(target) < 4.0 {
+                    force += agent.seek(target);
+                }
+                agent.update(force, scene_time_sec);
+
+                *object.world_transform_mut() = agent.world_transform(*steering_scale);
+                object.update_resource(queue);
+                if let Some(collider) = collider {
+                    collision_world.set_transform(*collider, object.world_transform_ref());
+                }
+            }
+        }
+
+        // (한국어) 고정 갱신마다 충돌을 검사하고 접촉이 시작되거나 끝난 쌍을 기록합니다.
+        // (English Translation) Tests the collisions per fixed update and logs the pairs whose contact began or ended.
+        collision_world.set_transform(*camera_collider, camera.world_transform_ref());
+        for _ in 0..num_fixed_steps {
+            collision_world.fixed_update();
+        }
+        for event in collision_world.drain_events() {
+            match event.phase {
+                CollisionPhase::Begin => log::info!("Collision began. ({} - {})", event.owners.0, event.owners.1), 
+                CollisionPhase::End => log::info!("Collision ended. ({} - {})", event.owners.0, event.owners.1), 
+                CollisionPhase::Stay => { /*--- empty ---*/ }, 
+            }
+        }
+        for event in collision_world.drain_trigger_events() {
+            match event.phase {
+                TriggerPhase::Enter => log::info!("Entered trigger. ({} -> {})", event.owners.1, event.owners.0), 
+                TriggerPhase::Exit => log::info!("Exited trigger. ({} <- {})", event.owners.1, event.owners.0), 
+            }
+        }
+
+        // (한국어) 카메라의 변환을 물리 세계에 옮기고, 시뮬레이션된 큐브 더미의 변환을 물체들에 옮깁니다.
+        // (English Translation) Moves the transform of the camera into the physics world, and the simulated transforms of the pile of cubes into the objects.
+        #[cfg(feature = "physics")]
+        {
+            physics_world.push_transform(*camera_body, camera.world_transform_ref());
+            if !*is_net_client && physics_world.advance(scene_time_sec) > 0 {
+                for (object, body, _) in pile_cubes.iter_mut() {
+                    physics_world.pull_transform(*body, object.world_transform_mut());
+                    object.update_resource(queue);
+                }
+            }
+        }
+
+        // (한국어) 서버는 틱마다 움직이는 물체들의 변환을 보내고, 클라이언트는 받은 변환을 보간하여 물체들에 적용합니다.
+        // (English Translation) The server sends the transforms of the moving objects every tick, and the client interpolates the received transforms and applies them to the objects.
+        match net_session.as_mut() {
+            Some(NetSession::Server(server)) => {
+                if server.update(input_frame.elapsed_time_sec) {
+                    let entities = steering_agents.iter()
+                        .enumerate()
+                        .filter(|_| *show_steering_agents)
+                        .map(|(index, (_, object, _))| NetEntity::from_transform(index as u16, object.world_transform_ref()));
+                    #[cfg(feature = "physics")]
+                    let entities = entities.chain(pile_cubes.iter()
+                        .enumerate()
+                        .map(|(index, (object, _, _))| NetEntity::from_transform(PILE_NET_ID + index as u16, object.world_transform_ref())));
+                    server.broadcast(entities);
+                }
+            }, 
+            Some(NetSession::Client(client)) => {
+                client.update(input_frame.elapsed_time_sec);
+                for entity in client.sample() {
+                    if let Some((_, object, collider)) = steering_agents.get_mut(entity.id as usize) {
+                        *object.world_transform_mut() = entity.world_transform();
+                        object.update_resource(queue);
+                        if let Some(collider) = collider {
+                            collision_world.set_transform(*collider, object.world_transform_ref());
+                        }
+                    }
+
+                    #[cfg(feature = "physics")]
+                    if let Some((object, _, _)) = entity.id.checked_sub(PILE_NET_ID).and_then(|index| pile_cubes.get_mut(index as usize)) {
+                        *object.world_transform_mut() = entity.world_transform();
+                        object.update_resource(queue);
+                    }
+                }
+            }, 
+            None => { /*--- empty ---*/ }, 
+        }
+
+        // (한국어) 에이전트들을 경로를 따라 움직이고, 목적지에 도착하면 다음 지점으로 가는 경로를 찾습니다.
+        // (English Translation) Moves the agents along their paths, and finds a path to the next point when they arrive at the destination.
+        if *show_navigation {
+            for (agent, waypoint) in nav_agents.iter_mut() {
+                agent.update(scene_time_sec);
+                if agent.has_arrived() {
+                    *waypoint = (*waypoint + 1) % nav_waypoints.len();
+                    agent.set_destination(nav_grid, nav_waypoints[*waypoint]);
+                }
+
+                let lift = glam::vec3(0.0, 0.02, 0.0);
+                nav_path.clear();
+                nav_path.extend(
+                    std::iter::once(agent.position())
+                        .chain(agent.remaining_path().iter().copied())
+                        .map(|point| point + lift)
+                );
+                debug_lines.polyline(nav_path, (1.0, 0.85, 0.1, 1.0).into());
+                debug_lines.cross(agent.position() + glam::vec3(0.0, 0.25, 0.0), 0.4, (1.0, 0.3, 0.1, 1.0).into());
+            }
+        }
+
+        // (한국어) 큐브들의 월드 공간 경계 상자를 절두체 안이면 초록색, 밖이면 회색으로 그리고, 조명과 감시 카메라를 표시합니다.
+        // (English Translation) Draws the world-space bounding boxes of the cubes in green inside the frustum and gray outside, and marks the lights and the security camera.
+        if *show_gizmos {
+            for (object, &visible) in cubes.iter().zip(in_frustum.iter()) {
+                let color = if visible { (0.2, 1.0, 0.3, 1.0) } else { (0.5, 0.5, 0.5, 1.0) };
+                debug_lines.aabb(&cube_mesh_0.aabb().transform(object.world_transform_ref()), color.into());
+            }
+            debug_lines.axes(&glam::Mat4::IDENTITY, 1.0);
+            debug_lines.axes(global_light.world_transform_ref(), 0.75);
+            debug_lines.axes(spot_light.world_transform_ref(), 0.75);
+            if *show_monitor {
+                debug_lines.frustum(&(security_camera.projection_transform() * security_camera.view_transform()), (1.0, 0.6, 0.1, 1.0).into());
+            }
+        }
+        debug_lines.prepare(device, queue);
+
+        // (한국어) 필름 그레인이 움직이도록 후처리 단계의 시간을 진행시킵니다.
+        // (English Translation) Advances the time of the post-processing stage so the film grain animates.
+        post_process.advance(input_frame.elapsed_time_sec, queue);
+        if *show_wave_plane {
+            wave_plane.advance(scene_time_sec, queue);
+        }
+
+        // (한국어) 거울에 반사된 카메라를 갱신합니다.
+        // (English Translation) Updates the camera reflected by the mirror.
+        *draw_mirror = *show_mirror && mirror.is_facing(camera.get_translation());
+        if *draw_mirror {
+            mirror.update_reflected_camera(camera, queue);
+        }
+
+        // (한국어) 수면의 물결을 움직이고 수면에 반사된 카메라를 갱신합니다.
+        // (English Translation) Moves the waves of the water and updates the camera reflected by the water surface.
+        *draw_water = *show_water && water.is_above(camera.get_translation());
+        if *draw_water {
+            water.advance(scene_time_sec, queue);
+            water.update_reflected_camera(camera, queue);
+        }
+
+        // (한국어) 공간 색인으로 카메라의 절두체 안에 있는 큐브들을 찾습니다.
+        // (English Translation) Finds the cubes inside the frustum of the camera with the spatial index.
+        let view = camera.view_transform();
+        *frustum = Frustum::from_matrix(&(camera.projection_transform() * view));
+        in_frustum.clear();
+        in_frustum.resize(cubes.len(), false);
+        scene_bvh.query_frustum(frustum, |_, &index| in_frustum[index] = true);
+
+        // (한국어) 반투명한 물체들을 모아 뷰 공간의 깊이에 따라 정렬합니다.
+        // (English Translation) Collects the translucent objects and sorts them by view-space depth.
+        transparent_objects.clear();
+        transparent_objects.extend(
+            cubes.iter()
+                .enumerate()
+                .filter(|(index, object)| object.is_transparent() && in_frustum[*index])
+                .map(|(index, object)| (view.transform_point3(object.get_translation()).z, index))
+        );
+        transparent_objects.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        // (한국어) 조준점 아래의 물체를 광선 검사로 찾습니다.
+        // (English Translation) Finds the object under the crosshair with a raycast.
+        let aim = collision_world.raycast(camera.get_translation(), -camera.get_look(), 100.0);
+
+        // (한국어) 통계 오버레이와 디버그 레이블의 글자들을 준비합니다.
+        // (English Translation) Prepares the characters of the stats overlay and debug labels.
+        let num_draw_calls;
+        (*num_cubes, num_draw_calls) = match show_cube_field {
+            true if *use_gpu_culling => (cubes.len() + cube_field.num_instances() as usize, cubes.len() + 1), 
+            true if *use_indirect_draw => (
+                cubes.len() + cube_field.num_instances() as usize, 
+                cubes.len() + cube_field.num_indirect_draw_calls(cube_field_indirect, device.features())
+            ), 
+            true => (cubes.len() + cube_field.num_instances() as usize, cubes.len() + 1), 
+            false => (cubes.len(), cubes.len()), 
+        };
+        stats.clear();
+        write!(
+            stats, 
+            "FPS: {}\nFrame: {:.2} ms\nCubes: {} ({} draw calls)\nCulled: {}\nOccluded: {}\nPicked: {}\nAim: ", 
+            timer.frame_rate(), 
+            timer.elapsed_time_sec() * 1000.0, 
+            num_cubes, 
+            num_draw_calls, 
+            in_frustum.iter().filter(|in_frustum| !**in_frustum).count(), 
+            occlusion.num_occluded(), 
+            id_picker.picked().and_then(|index| cube_labels.get(index).map(|label| &**label)).unwrap_or("None")
+        ).unwrap();
+        match aim {
+            Some(hit) => write!(stats, "{} ({:.1} m)", hit.object, hit.distance).unwrap(), 
+            None => stats.push_str("None"), 
+        }
+        write!(stats, "\nContacts: {}\nAllocations: {}/frame\nDepth: {:?} (MSAA {}x)", collision_world.num_contacts(), frame_allocations, depth_format, sample_count).unwrap();
+        write!(stats, "\nPresent: {}", utils::present_mode_name(swapchain.present_mode())).unwrap();
+        if let Some(gpu_profiler) = gpu_profiler.as_ref() {
+            write!(stats, "\nGPU: {:.2} ms ({} passes)", gpu_profiler.total_ms(), gpu_profiler.results().len()).unwrap();
+        }
+        write!(
+            stats, 
+            "\nTime: {:.1} s ({}x{})", 
+            timer.total_time_sec(), 
+            timer.time_scale(), 
+            if timer.is_paused() { ", paused" } else { "" }
+        ).unwrap();
+        write!(
+            stats, 
+            "\nDraws: {} ({} instances)\nBind groups: {}\nVRAM: {:.1} MiB (est.)", 
+            frame_stats.num_draw_calls, 
+            frame_stats.num_instances, 
+            frame_stats.num_bind_group_switches, 
+            stats::estimated_vram_bytes() as f64 / (1024.0 * 1024.0)
+        ).unwrap();
+        let stats_size = text_renderer.measure_text(stats, 18.0);
+        sprite_batch.draw_quad((4.0, 4.0).into(), stats_size + 8.0, (1.0, 1.0, 1.0, 0.75).into());
+        text_renderer.draw_text((8.0, 8.0).into(), stats, 18.0, (0.0, 0.0, 0.0, 1.0).into());
+        if *show_labels {
+            let screen_size = text_renderer.screen_size();
+            for (object, label) in cubes.iter().zip(cube_labels.iter()) {
+                if let Some(position) = camera.world_to_screen(object.get_translation(), screen_size.x, screen_size.y) {
+                    let half_size = 0.5 * text_renderer.measure_text(label, 14.0);
+                    text_renderer.draw_text(position - half_size, label, 14.0, (0.1, 0.1, 0.1, 1.0).into());
+                }
+            }
+        }
+        text_renderer.prepare(device, queue);
+
+        // (한국어) 화면 가운데에 조준점을 그립니다.
+        // (English Translation) Draws a crosshair at the center of the screen.
+        let center = 0.5 * text_renderer.screen_size();
+        let crosshair_color = (0.1, 0.1, 0.1, 0.8).into();
+        sprite_batch.draw_quad(center - glam::vec2(10.0, 1.0), (20.0, 2.0).into(), crosshair_color);
+        sprite_batch.draw_quad(center - glam::vec2(1.0, 10.0), (2.0, 20.0).into(), crosshair_color);
+        sprite_batch.draw_sprite(
+            *dot_texture, 
+            center - 3.0, 
+            (6.0, 6.0).into(), 
+            (0.0, 0.0, 1.0, 1.0).into(), 
+            (1.0, 0.3, 0.1, 1.0).into()
+        );
+        sprite_batch.prepare(device, queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 프레임을 가져와 렌더 그래프로 장면을 그리고 출력합니다. `update` 다음에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the next frame, draws the scene with the render graph and presents it. It must be called after `update`. </br>
+    /// 
+    pub fn render(&mut self) {
+        let Self {
+            overlay, 
+            use_deferred, 
+            surface_format, 
+            camera_bind_group_layout, 
+            camera, 
+            object_bind_group_layout, 
+            object_uniforms, 
+            upload_ring, 
+            plane_mesh, 
+            cube_mesh_0, 
+            sphere_mesh, 
+            plane, 
+            cubes, 
+            #[cfg(feature = "egui")]
+            cube_labels, 
+            textured_cube, 
+            terrain, 
+            show_terrain, 
+            wave_plane, 
+            displacement_pipeline, 
+            show_wave_plane, 
+            debug_lines, 
+            show_navigation, 
+            show_gizmos, 
+            collision_world, 
+            animated_objects, 
+            steering_agents, 
+            show_steering_agents, 
+            shader_watcher, 
+            benchmark, 
+            #[cfg(feature = "physics")]
+            pile_cubes, 
+            cube_field, 
+            cube_field_object, 
+            show_cube_field, 
+            cube_field_indirect, 
+            use_indirect_draw, 
+            cube_field_culler, 
+            gpu_cull_pipeline, 
+            use_gpu_culling, 
+            global_light_bind_group_layout, 
+            shadow_map_bind_group_layout, 
+            shadow_filter, 
+            global_light, 
+            spot_light, 
+            use_spot_light, 
+            fog_bind_group_layout, 
+            fog, 
+            grid_bind_group_layout, 
+            grid, 
+            show_grid, 
+            security_target, 
+            security_camera, 
+            monitor_bind_group_layout, 
+            monitor, 
+            show_monitor, 
+            mirror_bind_group_layout, 
+            mirror, 
+            water_bind_group_layout, 
+            water, 
+            pipeline_cache, 
+            color_pipeline, 
+            matcap_bind_group_layout, 
+            matcap, 
+            use_matcap, 
+            matcap_pipeline, 
+            parallax_bind_group_layout, 
+            parallax_material, 
+            use_parallax_floor, 
+            parallax_pipeline, 
+            material_bind_group_layout, 
+            cube_materials, 
+            textured_pipeline, 
+            lighting_bind_group_layout, 
+            lighting, 
+            use_phong, 
+            phong_pipeline, 
+            pbr_bind_group_layout, 
+            pbr_spheres, 
+            show_pbr_spheres, 
+            pbr_pipeline, 
+            instanced_pipeline, 
+            occlusion_proxy_pipeline, 
+            num_opaque_cubes, 
+            occlusion, 
+            use_occlusion_culling, 
+            gpu_profiler, 
+            transparent_pipeline, 
+            oit_pipeline, 
+            oit_bind_group_layout, 
+            target_pool, 
+            depth_stencil_target, 
+            oit_targets, 
+            use_oit, 
+            oit_composite_pipeline, 
+            deferred_bind_group_layout, 
+            deferred_renderer, 
+            gbuffer_pipeline, 
+            deferred_lighting_pipeline, 
+            id_picker, 
+            id_pipeline, 
+            post_process, 
+            post_process_pipeline, 
+            exposure_histogram_pipeline, 
+            exposure_average_pipeline, 
+            bloom_threshold_pipeline, 
+            bloom_downsample_pipeline, 
+            bloom_blur_pipeline, 
+            shadow_pipeline, 
+            instanced_shadow_pipeline, 
+            grid_pipeline, 
+            debug_line_pipeline, 
+            monitor_pipeline, 
+            mirror_mask_pipeline, 
+            mirror_backdrop_pipeline, 
+            mirror_pipeline, 
+            reflected_pipeline, 
+            water_reflection_pipeline, 
+            water_pipeline, 
+            text_bind_group_layout, 
+            text_renderer, 
+            #[cfg(feature = "egui")]
+            tweak_panel, 
+            #[cfg(feature = "egui")]
+            light_angles, 
+            text_pipeline, 
+            sprite_bind_group_layout, 
+            screen_camera, 
+            sprite_batch, 
+            sprite_pipeline, 
+            shadow_view_bind_group_layout, 
+            shadow_view, 
+            show_shadow_view, 
+            shadow_view_pipeline, 
+            skybox, 
+            skybox_pipeline, 
+            procedural_sky_bind_group_layout, 
+            procedural_sky, 
+            use_procedural_sky, 
+            procedural_sky_pipeline, 
+            swapchain, 
+            frame_time_stats, 
+            in_frustum, 
+            transparent_objects, 
+            security_transparent_objects, 
+            render_target_view_descriptor, 
+            frame_stats, 
+            window, 
+            device, 
+            queue, 
+            frustum, 
+            draw_mirror, 
+            draw_water, 
+            num_cubes, 
+            running, 
+            ..
+        } = self;
+
+        // (한국어) 오브젝트들을 그립니다.
+        // (English Translation) Draws the objects.
+        window.pre_present_notify();
+        
+        // (한국어) 끝난 작업들의 콜백을 처리합니다. 유니폼 버퍼가 프레임마다 다른 조각에 쓰므로 이전 작업을 기다리지 않습니다.
+        // (English Translation) Processes the callbacks of the finished operations. The uniform buffers write to a different slice per frame, so the previous operation is not waited for.
+        device.poll(wgpu::Maintain::Poll);
+
+        // (한국어) 이전 프레임의 오클루전 쿼리 결과를 가져옵니다.
+        // (English Translation) Fetches the occlusion query results of the previous frame.
+        if *use_occlusion_culling {
+            occlusion.fetch_results();
+        }
+
+        // (한국어) 이전 프레임의 렌더 패스마다 걸린 GPU 시간을 가져옵니다.
+        // (English Translation) Fetches the GPU time taken by each render pass of the previous frame.
+        if let Some(gpu_profiler) = gpu_profiler.as_mut() {
+            gpu_profiler.fetch_results();
+        }
+
+        // (한국어) 이전 프레임에서 읽은 커서 아래의 물체 번호를 가져옵니다.
+        // (English Translation) Fetches the object id under the cursor read in the previous frame.
+        id_picker.fetch_result();
+
+        // (한국어) 다음 프레임을 가져옵니다.
+        // (English Translation) Get the next frame.
+        // (한국어) 표면이 오래되었거나 잃어버렸으면 다시 설정하고, 시간이 초과되었으면 이번 프레임을 건너뜁니다.
+        // (English Translation) Reconfigures the surface if it is outdated or lost, and skips this frame if it timed out.
+        let frame = match swapchain.acquire_frame() {
+            Ok(Some(frame)) => frame, 
+            Ok(None) => return, 
+            Err(e) => {
+                log::error!("Failed to acquire the next frame: {}", e);
+                *running = false;
+                window.request_redraw();
+                return;
+            }, 
+        };
+
+        // (한국어) 렌더 타겟의 텍스처 뷰를 생성합니다.
+        // (English Translation) Creates a texture view of render target.
+        let render_target_view = frame.texture.create_view(render_target_view_descriptor);
+
+        // (한국어) 바뀐 쉐이더를 다시 불러오고, 그 쉐이더를 사용하는 파이프라인들을 프레임 사이에 다시 만듭니다.
+        // (English Translation) Reloads the changed shaders and recreates the pipelines using them between frames.
+        // (한국어) 새 파이프라인에 검증 오류가 있으면 이전 파이프라인들을 그대로 사용합니다.
+        // (English Translation) If the new pipelines have validation errors, the previous pipelines are kept.
+        // (한국어) 이때 바뀐 쉐이더들도 이전 소스로 되돌려, 캐시가 나중에 다시 만드는 쉐이더 모듈이 잘못된 소스를 사용하지 않게 합니다.
+        // (English Translation) The changed shaders are also reverted to their previous sources then, so the shader modules the cache recreates later do not use the broken sources.
+        let changed_shaders = shader_watcher.as_mut().map(ShaderWatcher::poll).unwrap_or_default();
+        if !changed_shaders.is_empty() {
+            let uses = |names: &[&str]| names.iter().any(|name| changed_shaders.contains(*name));
+            device.push_error_scope(wgpu::ErrorFilter::Validation);
+            pipeline_cache.invalidate(&changed_shaders);
+            let new_displacement_pipeline = uses(&["displacement"]).then(|| pipeline::create_displacement_pipeline(device, &[wave_plane.bind_group_layout_ref()]));
+            let new_color_pipeline = uses(&["vertex", "fragment"]).then(|| pipeline::create_colored_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_matcap_pipeline = uses(&["matcap_vertex", "matcap_fragment"]).then(|| pipeline::create_matcap_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, matcap_bind_group_layout]));
+            let new_parallax_pipeline = uses(&["parallax_vertex", "parallax_fragment"]).then(|| pipeline::create_parallax_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout, parallax_bind_group_layout]));
+            let new_phong_pipeline = uses(&["vertex", "phong_fragment"]).then(|| pipeline::create_phong_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout, lighting_bind_group_layout]));
+            let new_pbr_pipeline = uses(&["vertex", "pbr_fragment"]).then(|| pipeline::create_pbr_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout, pbr_bind_group_layout, lighting_bind_group_layout]));
+            let new_textured_pipeline = uses(&["textured_vertex", "textured_fragment"]).then(|| pipeline::create_textured_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout, material_bind_group_layout]));
+            let new_instanced_pipeline = uses(&["instanced_vertex", "fragment"]).then(|| pipeline::create_instanced_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_occlusion_proxy_pipeline = uses(&["vertex", "fragment"]).then(|| pipeline::create_occlusion_proxy_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_transparent_pipeline = uses(&["vertex", "fragment"]).then(|| pipeline::create_transparent_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_oit_pipeline = uses(&["vertex", "oit_fragment"]).then(|| pipeline::create_oit_pipeline(device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_gbuffer_pipeline = uses(&["vertex", "gbuffer_fragment"]).then(|| pipeline::create_gbuffer_pipeline(device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, pbr_bind_group_layout]));
+            let new_deferred_lighting_pipeline = uses(&["fullscreen_vertex", "deferred_lighting_fragment"]).then(|| pipeline::create_deferred_lighting_pipeline(pipeline_cache, device, &[deferred_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_oit_composite_pipeline = uses(&["fullscreen_vertex", "oit_composite_fragment"]).then(|| pipeline::create_oit_composite_pipeline(device, &[oit_bind_group_layout]));
+            let new_id_pipeline = uses(&["id_vertex", "id_fragment"]).then(|| pipeline::create_id_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout]));
+            let new_post_process_pipeline = uses(&["fullscreen_vertex", "post_fragment"]).then(|| pipeline::create_post_process_pipeline(pipeline_cache, device, &[post_process.bind_group_layout_ref()], swapchain.surface_format()));
+            let new_gpu_cull_pipeline = (uses(&["gpu_cull"]) && gpu_cull_pipeline.is_some()).then(|| pipeline::create_gpu_cull_pipeline(device, &[cube_field_culler.bind_group_layout_ref()]));
+            let new_exposure_histogram_pipeline = uses(&["exposure_histogram"]).then(|| pipeline::create_exposure_histogram_pipeline(device, &[post_process.auto_exposure_ref().bind_group_layout_ref()]));
+            let new_exposure_average_pipeline = uses(&["exposure_average"]).then(|| pipeline::create_exposure_average_pipeline(device, &[post_process.auto_exposure_ref().bind_group_layout_ref()]));
+            let new_bloom_threshold_pipeline = uses(&["fullscreen_vertex", "bloom_threshold_fragment"]).then(|| pipeline::create_bloom_threshold_pipeline(pipeline_cache, device, &[post_process.bloom_ref().bind_group_layout_ref()]));
+            let new_bloom_downsample_pipeline = uses(&["fullscreen_vertex", "bloom_downsample_fragment"]).then(|| pipeline::create_bloom_downsample_pipeline(pipeline_cache, device, &[post_process.bloom_ref().bind_group_layout_ref()]));
+            let new_bloom_blur_pipeline = uses(&["fullscreen_vertex", "bloom_blur_fragment"]).then(|| pipeline::create_bloom_blur_pipeline(pipeline_cache, device, &[post_process.bloom_ref().bind_group_layout_ref()]));
+            let new_shadow_pipeline = uses(&["shadow"]).then(|| pipeline::create_shadow_pipeline(pipeline_cache, device, &[global_light_bind_group_layout, object_bind_group_layout], shadow_filter.depth_bias()));
+            let new_instanced_shadow_pipeline = uses(&["shadow_instanced"]).then(|| pipeline::create_instanced_shadow_pipeline(pipeline_cache, device, &[global_light_bind_group_layout, object_bind_group_layout], shadow_filter.depth_bias()));
+            let new_grid_pipeline = uses(&["grid_vertex", "grid_fragment"]).then(|| pipeline::create_grid_pipeline(device, &[camera_bind_group_layout, grid_bind_group_layout]));
+            let new_debug_line_pipeline = uses(&["debug_line_vertex", "debug_line_fragment"]).then(|| pipeline::create_debug_line_pipeline(device, &[camera_bind_group_layout]));
+            let new_monitor_pipeline = uses(&["monitor_vertex", "monitor_fragment"]).then(|| pipeline::create_monitor_pipeline(device, &[camera_bind_group_layout, monitor_bind_group_layout]));
+            let new_mirror_mask_pipeline = uses(&["mirror_vertex", "mirror_fragment"]).then(|| pipeline::create_mirror_mask_pipeline(device, &[camera_bind_group_layout, mirror_bind_group_layout]));
+            let new_mirror_backdrop_pipeline = uses(&["mirror_vertex", "mirror_backdrop_fragment"]).then(|| pipeline::create_mirror_backdrop_pipeline(device, &[camera_bind_group_layout, mirror_bind_group_layout]));
+            let new_mirror_pipeline = uses(&["mirror_vertex", "mirror_fragment"]).then(|| pipeline::create_mirror_pipeline(device, &[camera_bind_group_layout, mirror_bind_group_layout]));
+            let new_reflected_pipeline = uses(&["vertex", "fragment"]).then(|| pipeline::create_reflected_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_water_reflection_pipeline = uses(&["vertex", "fragment"]).then(|| pipeline::create_water_reflection_pipeline(pipeline_cache, device, &[camera_bind_group_layout, object_bind_group_layout, global_light_bind_group_layout, shadow_map_bind_group_layout, fog_bind_group_layout]));
+            let new_water_pipeline = uses(&["water_vertex", "water_fragment"]).then(|| pipeline::create_water_pipeline(device, &[camera_bind_group_layout, water_bind_group_layout]));
+            let new_text_pipeline = uses(&["text_vertex", "text_fragment"]).then(|| pipeline::create_text_pipeline(device, &[text_bind_group_layout], *surface_format));
+            let new_shadow_view_pipeline = uses(&["fullscreen_vertex", "shadow_view_fragment"]).then(|| pipeline::create_shadow_view_pipeline(pipeline_cache, device, &[shadow_view_bind_group_layout], *surface_format));
+            let new_sprite_pipeline = uses(&["sprite_vertex", "sprite_fragment"]).then(|| pipeline::create_sprite_pipeline(device, &[camera_bind_group_layout, sprite_bind_group_layout], *surface_format));
+            let new_skybox_pipeline = uses(&["skybox_vertex", "skybox_fragment"]).then(|| pipeline::create_skybox_pipeline(device, &[camera_bind_group_layout, skybox.bind_group_layout_ref()]));
+            let new_procedural_sky_pipeline = uses(&["skybox_vertex", "sky_fragment"]).then(|| pipeline::create_procedural_sky_pipeline(device, &[camera_bind_group_layout, procedural_sky_bind_group_layout]));
+            match pollster::block_on(device.pop_error_scope()) {
+                Some(e) => {
+                    log::error!("Failed to recreate the pipelines. The previous pipelines are kept. ({})", e);
+                    if let Some(shader_watcher) = shader_watcher.as_mut() {
+                        shader_watcher.rollback();
+                    }
+                    pipeline_cache.invalidate(&changed_shaders);
+                }, 
+                None => {
+                    if let Some(recreated) = new_displacement_pipeline { *displacement_pipeline = recreated; }
+                    if let Some(recreated) = new_color_pipeline { *color_pipeline = recreated; }
+                    if let Some(recreated) = new_matcap_pipeline { *matcap_pipeline = recreated; }
+                    if let Some(recreated) = new_parallax_pipeline { *parallax_pipeline = recreated; }
+                    if let Some(recreated) = new_phong_pipeline { *phong_pipeline = recreated; }
+                    if let Some(recreated) = new_pbr_pipeline { *pbr_pipeline = recreated; }
+                    if let Some(recreated) = new_textured_pipeline { *textured_pipeline = recreated; }
+                    if let Some(recreated) = new_instanced_pipeline { *instanced_pipeline = recreated; }
+                    if let Some(recreated) = new_occlusion_proxy_pipeline { *occlusion_proxy_pipeline = recreated; }
+                    if let Some(recreated) = new_transparent_pipeline { *transparent_pipeline = recreated; }
+                    if let Some(recreated) = new_oit_pipeline { *oit_pipeline = recreated; }
+                    if let Some(recreated) = new_oit_composite_pipeline { *oit_composite_pipeline = recreated; }
+                    if let Some(recreated) = new_gbuffer_pipeline { *gbuffer_pipeline = recreated; }
+                    if let Some(recreated) = new_deferred_lighting_pipeline { *deferred_lighting_pipeline = recreated; }
+                    if let Some(recreated) = new_id_pipeline { *id_pipeline = recreated; }
+                    if let Some(recreated) = new_post_process_pipeline { *post_process_pipeline = recreated; }
+                    if let Some(recreated) = new_gpu_cull_pipeline { *gpu_cull_pipeline = Some(recreated); }
+                    if let Some(recreated) = new_exposure_histogram_pipeline { *exposure_histogram_pipeline = recreated; }
+                    if let Some(recreated) = new_exposure_average_pipeline { *exposure_average_pipeline = recreated; }
+                    if let Some(recreated) = new_bloom_threshold_pipeline { *bloom_threshold_pipeline = recreated; }
+                    if let Some(recreated) = new_bloom_downsample_pipeline { *bloom_downsample_pipeline = recreated; }
+                    if let Some(recreated) = new_bloom_blur_pipeline { *bloom_blur_pipeline = recreated; }
+                    if let Some(recreated) = new_shadow_pipeline { *shadow_pipeline = recreated; }
+                    if let Some(recreated) = new_instanced_shadow_pipeline { *instanced_shadow_pipeline = recreated; }
+                    if let Some(recreated) = new_grid_pipeline { *grid_pipeline = recreated; }
+                    if let Some(recreated) = new_debug_line_pipeline { *debug_line_pipeline = recreated; }
+                    if let Some(recreated) = new_monitor_pipeline { *monitor_pipeline = recreated; }
+                    if let Some(recreated) = new_mirror_mask_pipeline { *mirror_mask_pipeline = recreated; }
+                    if let Some(recreated) = new_mirror_backdrop_pipeline { *mirror_backdrop_pipeline = recreated; }
+                    if let Some(recreated) = new_mirror_pipeline { *mirror_pipeline = recreated; }
+                    if let Some(recreated) = new_reflected_pipeline { *reflected_pipeline = recreated; }
+                    if let Some(recreated) = new_water_reflection_pipeline { *water_reflection_pipeline = recreated; }
+                    if let Some(recreated) = new_water_pipeline { *water_pipeline = recreated; }
+                    if let Some(recreated) = new_text_pipeline { *text_pipeline = recreated; }
+                    if let Some(recreated) = new_shadow_view_pipeline { *shadow_view_pipeline = recreated; }
+                    if let Some(recreated) = new_sprite_pipeline { *sprite_pipeline = recreated; }
+                    if let Some(recreated) = new_skybox_pipeline { *skybox_pipeline = recreated; }
+                    if let Some(recreated) = new_procedural_sky_pipeline { *procedural_sky_pipeline = recreated; }
+                }, 
+            }
+        }
+
+        // (한국어) 커맨드 버퍼를 생성합니다.
+        // (English Translation) Creates a command buffer. 
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+
+        // (한국어) 조정 패널을 구성하고, 바뀐 값들은 `update_resource`로 유니폼 데이터에 반영합니다.
+        // (English Translation) Builds the tweak panel, and reflects the changed values into the uniform data with `update_resource`.
+        #[cfg(feature = "egui")]
+        let mut shadow_bias_changed = false;
+        #[cfg(feature = "egui")]
+        tweak_panel.prepare(window, device, queue, &mut encoder, |context| {
+            egui::Window::new("Tweaks").default_width(240.0).show(context, |ui| {
+                ui.heading("Global Light");
+                let yaw = ui.add(egui::Slider::new(&mut light_angles.x, -180.0..=180.0).text("Yaw"));
+                let pitch = ui.add(egui::Slider::new(&mut light_angles.y, -90.0..=90.0).text("Pitch"));
+                let mut light_color = global_light.light_color().to_array();
+                let color = ui.horizontal(|ui| {
+                    ui.label("Color");
+                    ui.color_edit_button_rgb(&mut light_color)
+                }).inner;
+                if yaw.changed() || pitch.changed() || color.changed() {
+                    global_light.set_rotation(
+                        glam::Quat::from_rotation_y(light_angles.x.to_radians()) 
+                            * glam::Quat::from_rotation_x(light_angles.y.to_radians())
+                    );
+                    global_light.set_light_color(light_color.into());
+                    global_light.update_resource(queue);
+                }
+
+                ui.separator();
+                ui.heading("Shadows");
+                let mut kernel = shadow_filter.kernel();
+                let kernel_changed = egui::ComboBox::from_label("Filter")
+                    .selected_text(kernel.name())
+                    .show_ui(ui, |ui| {
+                        crate::shadow::PCF_KERNELS.iter()
+                            .fold(false, |changed, &(name, value)| ui.selectable_value(&mut kernel, value, name).changed() || changed)
+                    }).inner.unwrap_or(false);
+                let mut filter_radius = shadow_filter.filter_radius();
+                let radius = ui.add(egui::Slider::new(&mut filter_radius, 0.0..=4.0).text("Radius (texels)"));
+                if kernel_changed || radius.changed() {
+                    shadow_filter.set_kernel(kernel);
+                    shadow_filter.set_filter_radius(filter_radius);
+                    shadow_filter.update_resource(queue);
+                }
+                let mut depth_bias = shadow_filter.depth_bias();
+                let constant = ui.add(egui::Slider::new(&mut depth_bias.constant, -16..=16).text("Constant bias"));
+                let slope_scale = ui.add(egui::Slider::new(&mut depth_bias.slope_scale, -8.0..=8.0).text("Slope-scaled bias"));
+                if constant.changed() || slope_scale.changed() {
+                    shadow_filter.set_depth_bias(depth_bias);
+                    shadow_bias_changed = true;
+                }
+
+                ui.separator();
+                ui.heading("Fog");
+                let mut enabled = fog.is_enabled();
+                let enabled_changed = ui.checkbox(&mut enabled, "Enabled").changed();
+                let mut mode = fog.mode();
+                let mode_changed = egui::ComboBox::from_label("Mode")
+                    .selected_text(mode.name())
+                    .show_ui(ui, |ui| {
+                        crate::fog::FOG_MODES.iter()
+                            .fold(false, |changed, &(name, value)| ui.selectable_value(&mut mode, value, name).changed() || changed)
+                    }).inner.unwrap_or(false);
+                let mut density = fog.density();
+                let density_changed = ui.add(egui::Slider::new(&mut density, 0.0..=0.2).text("Density")).changed();
+                let (mut start, mut end) = (fog.start(), fog.end());
+                let start_changed = ui.add(egui::Slider::new(&mut start, 0.0..=100.0).text("Start")).changed();
+                let end_changed = ui.add(egui::Slider::new(&mut end, 0.0..=200.0).text("End")).changed();
+                if enabled_changed || mode_changed || density_changed || start_changed || end_changed {
+                    fog.set_enabled(enabled);
+                    fog.set_mode(mode);
+                    fog.set_density(density);
+                    fog.set_range(start, end);
+                    fog.update_resource(queue);
+                }
+
+                ui.separator();
+                ui.heading("Camera");
+                let mut fov_y = camera.fov_y_radian().to_degrees();
+                if ui.add(egui::Slider::new(&mut fov_y, 20.0..=120.0).text("FOV")).changed() {
+                    camera.set_fov_y_radian(fov_y.to_radians());
+                    camera.update_resource(queue);
+                }
+
+                ui.separator();
+                ui.heading("Objects");
+                for (object, label) in cubes.iter_mut().zip(cube_labels.iter()) {
+                    let mut color = object.color().to_array();
+                    ui.horizontal(|ui| {
+                        ui.label(&**label);
+                        if ui.color_edit_button_rgb(&mut color).changed() {
+                            object.set_color(color.into());
+                            object.update_resource(queue);
+                        }
+                    });
+                }
+            });
+        });
+
+        // (한국어) 깊이 편향은 파이프라인 상태이므로, 바뀌었으면 그림자 맵 파이프라인들을 다시 생성합니다.
+        // (English Translation) The depth bias is a pipeline state, so recreates the shadow map pipelines if it changed.
+        #[cfg(feature = "egui")]
+        if shadow_bias_changed {
+            let bind_group_layouts: &[&wgpu::BindGroupLayout] = &[global_light_bind_group_layout, object_bind_group_layout];
+            *shadow_pipeline = pipeline::create_shadow_pipeline(pipeline_cache, device, bind_group_layouts, shadow_filter.depth_bias());
+            *instanced_shadow_pipeline = pipeline::create_instanced_shadow_pipeline(pipeline_cache, device, bind_group_layouts, shadow_filter.depth_bias());
+        }
+
+        // (한국어) 이번 프레임에 장면을 비추는 조명의 자원을 선택합니다.
+        // (English Translation) Selects the resources of the light illuminating the scene this frame.
+        let (light_shadow_view, light_uniform_bind_group, light_texture_bind_group) = if *use_spot_light {
+            (spot_light.texture_view_ref(), spot_light.uniform_bind_group(), &spot_light.texture_bind_group)
+        } else {
+            (global_light.texture_view_ref(), global_light.uniform_bind_group(), &global_light.texture_bind_group)
+        };
+
+        // (한국어) 이번 프레임에 바뀐 오브젝트들의 유니폼 데이터를 모든 패스보다 먼저 복사합니다.
+        // (English Translation) Copies the uniform data of the objects changed this frame before all the passes.
+        object_uniforms.flush(upload_ring, &mut encoder, device);
+        upload_ring.finish();
+
+        // (한국어) 물결 평면을 그리는 패스들보다 먼저 정점 버퍼를 갱신합니다.
+        // (English Translation) Updates the vertex buffer before the passes that draw the wave plane.
+        if *show_wave_plane {
+            wave_plane.dispatch(&mut encoder, displacement_pipeline);
+        }
+
+        // (한국어) 큐브 필드를 그리는 패스보다 먼저 카메라의 절두체로 인스턴스들을 컬링합니다.
+        // (English Translation) Culls the instances with the frustum of the camera before the pass that draws the cube field.
+        if let Some(gpu_cull_pipeline) = gpu_cull_pipeline.as_ref().filter(|_| *show_cube_field && *use_gpu_culling) {
+            cube_field_culler.update(frustum, cube_field_object.world_transform_ref(), queue);
+            cube_field_culler.dispatch(&mut encoder, gpu_cull_pipeline);
+        }
+
+        // (한국어) 이번 프레임의 렌더 패스들을 렌더 그래프에 모은 후 한 번에 실행합니다.
+        // (English Translation) Collects the render passes of this frame into the render graph and executes them at once.
+        let mut graph = RenderGraph::new();
+        if let Some(gpu_profiler) = gpu_profiler.as_mut() {
+            gpu_profiler.begin_frame();
+        }
+        graph.set_profiler(gpu_profiler.as_mut());
+        let scene_view = GraphTexture::View(post_process.scene_view_ref());
+
+        // (한국어) 멀티샘플링을 사용하면 장면을 임시 멀티샘플 타겟에 그린 후 HDR 장면 타겟으로 리졸브합니다.
+        // (English Translation) With multisampling, the scene is drawn into a transient multisampled target and then resolved into the HDR scene target.
+        let scene_multisampled_target = (target::sample_count() > 1).then(|| graph.create_texture(
+            "Texture(SceneColorMultisampled)", 
+            postprocess::SCENE_COLOR_FORMAT, 
+            wgpu::TextureUsages::RENDER_ATTACHMENT, 
+            target::sample_count()
+        ));
+
+        let shadow_pass = RenderPassNodeBuilder::new("RenderPass(Shadow)")
+            .set_depth_stencil_attachment(wgpu::RenderPassDepthStencilAttachment {
+                view: light_shadow_view, 
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0), 
+                    store: wgpu::StoreOp::Store, 
+                }), 
+                stencil_ops: None, 
+            })
+            .build(|mut rpass| {
+                rpass.set_pipeline(shadow_pipeline);
+                stats::set_bind_group(&mut rpass, 0, light_uniform_bind_group, &[]);
+
+                if *show_terrain {
+                    terrain.draw(&mut rpass, None);
+                } else if !*show_grid {
+                    plane_mesh.bind(&mut rpass);
+                    plane.bind(&mut rpass, 1);
+                    plane_mesh.draw(&mut rpass);
+                }
+
+                if *show_wave_plane {
+                    wave_plane.draw(&mut rpass);
+                }
+
+                sphere_mesh.bind(&mut rpass);
+                for (object, _) in animated_objects.iter() {
+                    object.object_ref().bind(&mut rpass, 1);
+                    sphere_mesh.draw(&mut rpass);
+                }
+                if *show_pbr_spheres {
+                    for object in pbr_spheres.iter() {
+                        object.bind(&mut rpass, 1);
+                        sphere_mesh.draw(&mut rpass);
+                    }
+                }
+
+                cube_mesh_0.bind(&mut rpass);
+                for object in cubes.iter() {
+                    object.bind(&mut rpass, 1);
+                    cube_mesh_0.draw(&mut rpass);
+                }
+                textured_cube.bind(&mut rpass, 1);
+                cube_mesh_0.draw(&mut rpass);
+                if *show_steering_agents {
+                    for (_, object, _) in steering_agents.iter() {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                }
+
+                #[cfg(feature = "physics")]
+                for (object, _, _) in pile_cubes.iter() {
+                    object.bind(&mut rpass, 1);
+                    cube_mesh_0.draw(&mut rpass);
+                }
+
+                // (한국어) 큐브 필드의 인스턴스들도 그림자를 드리웁니다.
+                // (English Translation) The instances of the cube field cast shadows too.
+                if *show_cube_field {
+                    rpass.set_pipeline(instanced_shadow_pipeline);
+                    cube_field_object.bind(&mut rpass, 1);
+                    cube_field.bind(&mut rpass);
+                    cube_mesh_0.draw_instanced(&mut rpass, 0..cube_field.num_instances());
+                }
+            });
+        graph.add_node(shadow_pass);
+
+        // (한국어) 감시 카메라의 시점에서 장면을 렌더 타겟에 그립니다. 모니터 자신은 그리지 않습니다.
+        // (English Translation) Draws the scene into the render target from the view of the security camera. The monitor itself is not drawn.
+        if *show_monitor {
+            // (한국어) 감시 카메라의 시점에서도 반투명한 물체들이 올바르게 겹치도록 뷰 공간의 깊이에 따라 따로 정렬합니다.
+            // (English Translation) Sorts the translucent objects separately by the view-space depth of the security camera so they overlap correctly from its view too.
+            let security_view = security_camera.view_transform();
+            security_transparent_objects.clear();
+            security_transparent_objects.extend(
+                cubes.iter()
+                    .enumerate()
+                    .filter(|(_, object)| object.is_transparent())
+                    .map(|(index, object)| (security_view.transform_point3(object.get_translation()).z, index))
+            );
+            security_transparent_objects.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            let security_camera_pass = RenderPassNodeBuilder::new("RenderPass(SecurityCamera)")
+                .set_color_attachments([
+                    Some(security_target.color_attachment(wgpu::Color::WHITE)), 
+                ])
+                .set_depth_stencil_attachment(security_target.depth_stencil_attachment())
+                .build(|mut rpass| {
+                    security_target.apply_viewport(&mut rpass);
+
+                    rpass.set_pipeline(color_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, security_camera.uniform_bind_group(), &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, light_texture_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 4, &fog.uniform_bind_group, &[]);
+
+                    if *show_terrain {
+                        let frustum = Frustum::from_matrix(&(security_camera.projection_transform() * security_camera.view_transform()));
+                        terrain.draw(&mut rpass, Some(&frustum));
+                    } else if !*show_grid {
+                        plane_mesh.bind(&mut rpass);
+                        plane.bind(&mut rpass, 1);
+                        plane_mesh.draw(&mut rpass);
+                    }
+
+                    sphere_mesh.bind(&mut rpass);
+                    for (object, _) in animated_objects.iter() {
+                        object.object_ref().bind(&mut rpass, 1);
+                        sphere_mesh.draw(&mut rpass);
+                    }
+
+                    cube_mesh_0.bind(&mut rpass);
+                    for object in cubes.iter().filter(|object| !object.is_transparent()) {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                    if *show_steering_agents {
+                        for (_, object, _) in steering_agents.iter() {
+                            object.bind(&mut rpass, 1);
+                            cube_mesh_0.draw(&mut rpass);
+                        }
+                    }
+
+                    #[cfg(feature = "physics")]
+                    for (object, _, _) in pile_cubes.iter() {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+
+                    if *use_procedural_sky {
+                        rpass.set_pipeline(procedural_sky_pipeline);
+                        procedural_sky.draw(&mut rpass);
+                    } else {
+                        rpass.set_pipeline(skybox_pipeline);
+                        skybox.draw(&mut rpass);
+                    }
+
+                    rpass.set_pipeline(transparent_pipeline);
+                    cube_mesh_0.bind(&mut rpass);
+                    for &(_, index) in security_transparent_objects.iter() {
+                        cubes[index].bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                });
+            graph.add_node(security_camera_pass);
+        }
+
+        // (한국어) 수면에 반사된 카메라의 시점에서 불투명한 물체들과 하늘을 반사 렌더 타겟에 그립니다. 
+        // 바닥과 지형은 수면 아래에 있으므로 그리지 않습니다.
+        // (English Translation) Draws the opaque objects and the sky into the reflection render target from the view of the camera reflected by the water surface. 
+        // The floor and the terrain are under the surface, so they are not drawn.
+        if *draw_water {
+            let water_target = water.target_ref();
+            let water_reflection_pass = RenderPassNodeBuilder::new("RenderPass(WaterReflection)")
+                .set_color_attachments([
+                    Some(water_target.color_attachment(wgpu::Color::BLACK)), 
+                ])
+                .set_depth_stencil_attachment(water_target.depth_stencil_attachment())
+                .build(|mut rpass| {
+                    water_target.apply_viewport(&mut rpass);
+
+                    rpass.set_pipeline(water_reflection_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, &water.camera_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, light_texture_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 4, &fog.uniform_bind_group, &[]);
+
+                    sphere_mesh.bind(&mut rpass);
+                    for (object, _) in animated_objects.iter() {
+                        object.object_ref().bind(&mut rpass, 1);
+                        sphere_mesh.draw(&mut rpass);
+                    }
+
+                    cube_mesh_0.bind(&mut rpass);
+                    for object in cubes.iter().filter(|object| !object.is_transparent()) {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                    if *show_steering_agents {
+                        for (_, object, _) in steering_agents.iter() {
+                            object.bind(&mut rpass, 1);
+                            cube_mesh_0.draw(&mut rpass);
+                        }
+                    }
+
+                    #[cfg(feature = "physics")]
+                    for (object, _, _) in pile_cubes.iter() {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+
+                    if *use_procedural_sky {
+                        rpass.set_pipeline(procedural_sky_pipeline);
+                        procedural_sky.draw(&mut rpass);
+                    } else {
+                        rpass.set_pipeline(skybox_pipeline);
+                        skybox.draw(&mut rpass);
+                    }
+                });
+            graph.add_node(water_reflection_pass);
+        }
+
+        // (한국어) 지연 렌더러는 불투명한 물체들을 G-버퍼에 그린 후, 조명 단계에서 장면의 색을 계산합니다.
+        // (English Translation) The deferred renderer draws the opaque objects into the G-buffer, then computes the color of the scene in the lighting pass.
+        if let Some(deferred_renderer) = deferred_renderer.as_ref() {
+            let gbuffer_pass = RenderPassNodeBuilder::new("RenderPass(GBuffer)")
+                .set_color_attachments(deferred_renderer.color_attachments())
+                .set_depth_stencil_attachment(GraphDepthStencilAttachment {
+                    target: GraphTexture::Pooled(*depth_stencil_target), 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0), 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: target::has_stencil().then_some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(0), 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                })
+                .build(|mut rpass| {
+                    rpass.set_pipeline(gbuffer_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, camera.uniform_bind_group(), &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, &deferred_renderer.default_material_bind_group, &[]);
+
+                    if *show_terrain {
+                        terrain.draw(&mut rpass, Some(frustum));
+                    } else if !*show_grid {
+                        plane_mesh.bind(&mut rpass);
+                        plane.bind(&mut rpass, 1);
+                        plane_mesh.draw(&mut rpass);
+                    }
+
+                    if *show_wave_plane {
+                        wave_plane.draw(&mut rpass);
+                    }
+
+                    sphere_mesh.bind(&mut rpass);
+                    for (object, _) in animated_objects.iter().filter(|(object, _)| object.object_ref().is_in_frustum(frustum, sphere_mesh)) {
+                        object.object_ref().bind(&mut rpass, 1);
+                        sphere_mesh.draw(&mut rpass);
+                    }
+
+                    // (한국어) 물리 기반 구들은 자신의 재질을 3번 집합에 바인딩하여 G-버퍼에 기록합니다.
+                    // (English Translation) The physically-based spheres write their own materials into the G-buffer, binding them to set 3.
+                    if *show_pbr_spheres {
+                        for object in pbr_spheres.iter().filter(|object| object.object_ref().is_in_frustum(frustum, sphere_mesh)) {
+                            object.bind(&mut rpass, 1);
+                            stats::set_bind_group(&mut rpass, 3, &object.bind_group, &[]);
+                            sphere_mesh.draw(&mut rpass);
+                        }
+                        stats::set_bind_group(&mut rpass, 3, &deferred_renderer.default_material_bind_group, &[]);
+                    }
+
+                    cube_mesh_0.bind(&mut rpass);
+                    let opaque_cubes = cubes.iter()
+                        .zip(in_frustum.iter())
+                        .filter(|(object, &in_frustum)| in_frustum && !object.is_transparent());
+                    for (object, _) in opaque_cubes {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+
+                    if textured_cube.is_in_frustum(frustum, cube_mesh_0) {
+                        textured_cube.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+
+                    if *show_steering_agents {
+                        for (_, object, _) in steering_agents.iter().filter(|(_, object, _)| object.is_in_frustum(frustum, cube_mesh_0)) {
+                            object.bind(&mut rpass, 1);
+                            cube_mesh_0.draw(&mut rpass);
+                        }
+                    }
+
+                    #[cfg(feature = "physics")]
+                    for (object, _, _) in pile_cubes.iter().filter(|(object, _, _)| object.is_in_frustum(frustum, cube_mesh_0)) {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                });
+            graph.add_node(gbuffer_pass);
+        }
+
+        if let Some(deferred_renderer) = deferred_renderer.as_ref() {
+            let deferred_lighting_pass = RenderPassNodeBuilder::new("RenderPass(DeferredLighting)")
+                .set_color_attachments([
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: post_process.scene_view_ref(), 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(match overlay {
+                                true => wgpu::Color::TRANSPARENT, 
+                                false => wgpu::Color::WHITE, 
+                            }), 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ])
+                .build(|mut rpass| {
+                    rpass.set_pipeline(deferred_lighting_pipeline);
+                    stats::set_bind_group(&mut rpass, 1, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_texture_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, &fog.uniform_bind_group, &[]);
+                    deferred_renderer.draw(&mut rpass);
+                });
+            graph.add_node(deferred_lighting_pass);
+        }
+
+        let draw_pass = RenderPassNodeBuilder::new("RenderPass(Draw)")
+            .set_color_attachments([
+                Some(GraphColorAttachment {
+                    target: scene_multisampled_target.unwrap_or(scene_view), 
+                    resolve_target: scene_multisampled_target.map(|_| scene_view), 
+                    ops: wgpu::Operations {
+                        load: match (*use_deferred, *overlay) {
+                            (true, _) => wgpu::LoadOp::Load, 
+                            (false, true) => wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), 
+                            (false, false) => wgpu::LoadOp::Clear(wgpu::Color::WHITE), 
+                        }, 
+                        store: wgpu::StoreOp::Store, 
+                    }, 
+                }), 
+            ])
+            .set_depth_stencil_attachment(GraphDepthStencilAttachment {
+                target: GraphTexture::Pooled(*depth_stencil_target), 
+                depth_ops: Some(wgpu::Operations {
+                    load: match use_deferred {
+                        true => wgpu::LoadOp::Load, 
+                        false => wgpu::LoadOp::Clear(1.0), 
+                    }, 
+                    store: wgpu::StoreOp::Store, 
+                }), 
+                stencil_ops: target::has_stencil().then_some(wgpu::Operations {
+                    load: match use_deferred {
+                        true => wgpu::LoadOp::Load, 
+                        false => wgpu::LoadOp::Clear(0), 
+                    }, 
+                    store: wgpu::StoreOp::Store, 
+                }), 
+            })
+            .set_occlusion_query_set(use_occlusion_culling.then(|| occlusion.query_set_ref()))
+            .build(|mut rpass| {
+                rpass.set_pipeline(color_pipeline);
+                stats::set_bind_group(&mut rpass, 0, camera.uniform_bind_group(), &[]);
+                stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                stats::set_bind_group(&mut rpass, 3, light_texture_bind_group, &[]);
+                stats::set_bind_group(&mut rpass, 4, &fog.uniform_bind_group, &[]);
+
+                // (한국어) 지연 렌더러를 사용하면 불투명한 물체들은 이미 G-버퍼 단계와 조명 단계에서 그려졌습니다.
+                // (English Translation) With the deferred renderer, the opaque objects are already drawn in the G-buffer and lighting passes.
+                if !*use_deferred {
+                    // (한국어) 매트캡 미리보기에서는 불투명한 물체들을 매트캡 파이프라인으로, 블린-퐁 음영에서는 조명을 5번 집합에 바인딩하여 블린-퐁 파이프라인으로 그립니다.
+                    // (English Translation) In the matcap preview, the opaque objects are drawn with the matcap pipeline, and in the Blinn-Phong shading, with the Blinn-Phong pipeline binding the lights to set 5.
+                    let use_phong = *use_phong && !*use_matcap;
+                    let opaque_pipeline = if *use_matcap { &matcap_pipeline } else if use_phong { &phong_pipeline } else { &color_pipeline };
+                    if *use_matcap {
+                        rpass.set_pipeline(opaque_pipeline);
+                        stats::set_bind_group(&mut rpass, 2, &matcap.bind_group, &[]);
+                    } else if use_phong {
+                        rpass.set_pipeline(opaque_pipeline);
+                        stats::set_bind_group(&mut rpass, 5, &lighting.uniform_bind_group, &[]);
+                    }
+
+                    if *show_terrain {
+                        terrain.draw(&mut rpass, Some(frustum));
+                    } else if !*show_grid {
+                        plane_mesh.bind(&mut rpass);
+                        plane.bind(&mut rpass, 1);
+                        if *use_parallax_floor && !*use_matcap {
+                            rpass.set_pipeline(parallax_pipeline);
+                            stats::set_bind_group(&mut rpass, 5, &parallax_material.bind_group, &[]);
+                            plane_mesh.draw(&mut rpass);
+                            rpass.set_pipeline(opaque_pipeline);
+                            if use_phong {
+                                stats::set_bind_group(&mut rpass, 5, &lighting.uniform_bind_group, &[]);
+                            }
+                        } else {
+                            plane_mesh.draw(&mut rpass);
+                        }
+                    }
+
+                    if *show_wave_plane {
+                        wave_plane.draw(&mut rpass);
+                    }
+
+                    sphere_mesh.bind(&mut rpass);
+                    for (object, _) in animated_objects.iter().filter(|(object, _)| object.object_ref().is_in_frustum(frustum, sphere_mesh)) {
+                        object.object_ref().bind(&mut rpass, 1);
+                        sphere_mesh.draw(&mut rpass);
+                    }
+
+                    // (한국어) 물리 기반 구들은 재질을 5번 집합에, 조명을 6번 집합에 바인딩하여 물리 기반 파이프라인으로 그립니다.
+                    // (English Translation) The physically-based spheres are drawn with the physically-based pipeline, binding their materials to set 5 and the lights to set 6.
+                    if *show_pbr_spheres && *use_matcap {
+                        for object in pbr_spheres.iter().filter(|object| object.object_ref().is_in_frustum(frustum, sphere_mesh)) {
+                            object.bind(&mut rpass, 1);
+                            sphere_mesh.draw(&mut rpass);
+                        }
+                    } else if *show_pbr_spheres {
+                        rpass.set_pipeline(pbr_pipeline);
+                        stats::set_bind_group(&mut rpass, 6, &lighting.uniform_bind_group, &[]);
+                        for object in pbr_spheres.iter().filter(|object| object.object_ref().is_in_frustum(frustum, sphere_mesh)) {
+                            object.bind(&mut rpass, 1);
+                            stats::set_bind_group(&mut rpass, 5, &object.bind_group, &[]);
+                            sphere_mesh.draw(&mut rpass);
+                        }
+                        rpass.set_pipeline(opaque_pipeline);
+                        if use_phong {
+                            stats::set_bind_group(&mut rpass, 5, &lighting.uniform_bind_group, &[]);
+                        }
+                    }
+
+                    // (한국어) 이전 프레임에서 가려졌던 큐브는 대리 상자로만 그려 다시 보이는지 확인합니다.
+                    // (English Translation) Cubes that were occluded in the previous frame are only drawn as proxy boxes to check whether they are visible again.
+                    cube_mesh_0.bind(&mut rpass);
+                    // (한국어) 절두체 밖의 큐브는 그리지 않으며, 쿼리 결과는 가려진 것으로 기록됩니다.
+                    // (English Translation) Cubes outside the frustum are not drawn, and their query results are recorded as occluded.
+                    let opaque_cubes = cubes.iter()
+                        .zip(in_frustum.iter())
+                        .filter(|(object, _)| !object.is_transparent());
+                    for (query, (object, &in_frustum)) in opaque_cubes.enumerate() {
+                        object.bind(&mut rpass, 1);
+                        if !*use_occlusion_culling {
+                            if in_frustum {
+                                cube_mesh_0.draw(&mut rpass);
+                            }
+                            continue;
+                        }
+
+                        rpass.begin_occlusion_query(query as u32);
+                        if in_frustum && occlusion.is_visible(query) {
+                            cube_mesh_0.draw(&mut rpass);
+                        } else if in_frustum {
+                            rpass.set_pipeline(occlusion_proxy_pipeline);
+                            if *use_matcap {
+                                stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                            }
+                            cube_mesh_0.draw(&mut rpass);
+                            rpass.set_pipeline(opaque_pipeline);
+                            if *use_matcap {
+                                stats::set_bind_group(&mut rpass, 2, &matcap.bind_group, &[]);
+                            }
+                        }
+                        rpass.end_occlusion_query();
+                    }
+
+                    // (한국어) 텍스처 큐브는 서브 메쉬마다 재질을 5번 집합에 바인딩하여 텍스처 파이프라인으로 그립니다. 매트캡 미리보기에서는 다른 물체들과 같이 그립니다.
+                    // (English Translation) The textured cube is drawn with the textured pipeline, binding the material of each sub-mesh to set 5. In the matcap preview, it is drawn like the other objects.
+                    textured_cube.bind(&mut rpass, 1);
+                    if !textured_cube.is_in_frustum(frustum, cube_mesh_0) {
+                        // (한국어) 절두체 밖의 텍스처 큐브는 그리지 않습니다.
+                        // (English Translation) The textured cube outside the frustum is not drawn.
+                    } else if *use_matcap {
+                        cube_mesh_0.draw(&mut rpass);
+                    } else {
+                        rpass.set_pipeline(textured_pipeline);
+                        cube_mesh_0.draw_with_materials(&mut rpass, 5, cube_materials, 0..1);
+                        rpass.set_pipeline(opaque_pipeline);
+                        if use_phong {
+                            stats::set_bind_group(&mut rpass, 5, &lighting.uniform_bind_group, &[]);
+                        }
+                    }
+
+                    if *show_steering_agents {
+                        for (_, object, _) in steering_agents.iter().filter(|(_, object, _)| object.is_in_frustum(frustum, cube_mesh_0)) {
+                            object.bind(&mut rpass, 1);
+                            cube_mesh_0.draw(&mut rpass);
+                        }
+                    }
+
+                    #[cfg(feature = "physics")]
+                    for (object, _, _) in pile_cubes.iter().filter(|(object, _, _)| object.is_in_frustum(frustum, cube_mesh_0)) {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                }
+
+                // (한국어) 큐브 필드의 모든 인스턴스를 한 번의 그리기 호출로 그립니다.
+                // (English Translation) Draws all instances of the cube field with a single draw call.
+                if *show_cube_field {
+                    rpass.set_pipeline(instanced_pipeline);
+                    cube_field_object.bind(&mut rpass, 1);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    cube_mesh_0.bind(&mut rpass);
+                    if *use_gpu_culling {
+                        cube_field_culler.draw(&mut rpass);
+                    } else if *use_indirect_draw {
+                        cube_field.draw_indirect(&mut rpass, cube_field_indirect, device.features());
+                    } else {
+                        cube_field.bind(&mut rpass);
+                        cube_mesh_0.draw_instanced(&mut rpass, 0..cube_field.num_instances());
+                    }
+                }
+
+                // (한국어) 감시 카메라의 렌더 타겟을 입힌 모니터를 그립니다.
+                // (English Translation) Draws the monitor showing the render target of the security camera.
+                if *show_monitor {
+                    rpass.set_pipeline(monitor_pipeline);
+                    monitor.draw(&mut rpass);
+                }
+
+                // (한국어) 불투명한 물체들을 그린 후 가려지지 않은 곳에 스카이박스를 그립니다.
+                // (English Translation) After drawing the opaque objects, draws the skybox where it is not occluded.
+                // (한국어) 오버레이 모드에서는 바탕 화면이 보이도록 하늘을 그리지 않습니다.
+                // (English Translation) In the overlay mode, the sky is not drawn so the desktop shows through.
+                if !*overlay && *use_procedural_sky {
+                    rpass.set_pipeline(procedural_sky_pipeline);
+                    procedural_sky.draw(&mut rpass);
+                } else if !*overlay {
+                    rpass.set_pipeline(skybox_pipeline);
+                    skybox.draw(&mut rpass);
+                }
+
+                // (한국어) 하늘을 그린 후 반사된 장면과 물의 색을 혼합한 수면을 그립니다.
+                // (English Translation) After drawing the sky, draws the water surface blending the reflected scene and the water color.
+                if *draw_water {
+                    rpass.set_pipeline(water_pipeline);
+                    water.draw(&mut rpass);
+                }
+
+                // (한국어) 불투명한 물체들을 그린 후 반투명한 그리드를 그립니다.
+                // (English Translation) Draws the translucent grid after the opaque objects.
+                if *show_grid {
+                    rpass.set_pipeline(grid_pipeline);
+                    stats::set_bind_group(&mut rpass, 1, &grid.uniform_bind_group, &[]);
+                    grid.draw(&mut rpass);
+                }
+
+                // (한국어) 에이전트들의 경로와 디버그 표시들을 디버그 선으로 그립니다.
+                // (English Translation) Draws the paths of the agents and the debug markers with debug lines.
+                if *show_navigation || *show_gizmos {
+                    rpass.set_pipeline(debug_line_pipeline);
+                    debug_lines.draw(&mut rpass);
+                }
+
+                // (한국어) 거울이 보이는 영역을 스텐실에 표시하고 그 안에만 반사된 장면을 그린 후 거울 표면을 혼합합니다.
+                // (English Translation) Marks the visible area of the mirror in the stencil, draws the reflected scene only inside it, and blends the mirror surface.
+                if *draw_mirror {
+                    rpass.set_stencil_reference(mirror::STENCIL_REFERENCE);
+                    rpass.set_pipeline(mirror_mask_pipeline);
+                    mirror.draw(&mut rpass);
+                    rpass.set_pipeline(mirror_backdrop_pipeline);
+                    mirror.draw(&mut rpass);
+
+                    rpass.set_pipeline(reflected_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, &mirror.camera_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, light_texture_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 4, &fog.uniform_bind_group, &[]);
+
+                    if !*show_terrain && !*show_grid {
+                        plane_mesh.bind(&mut rpass);
+                        plane.bind(&mut rpass, 1);
+                        plane_mesh.draw(&mut rpass);
+                    }
+
+                    cube_mesh_0.bind(&mut rpass);
+                    for object in cubes.iter().filter(|object| !object.is_transparent()) {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                    if *show_steering_agents {
+                        for (_, object, _) in steering_agents.iter() {
+                            object.bind(&mut rpass, 1);
+                            cube_mesh_0.draw(&mut rpass);
+                        }
+                    }
+
+                    #[cfg(feature = "physics")]
+                    for (object, _, _) in pile_cubes.iter() {
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+
+                    rpass.set_pipeline(mirror_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, camera.uniform_bind_group(), &[]);
+                    mirror.draw(&mut rpass);
+                }
+
+                // (한국어) 반투명한 물체들을 뒤에서부터 앞으로 그립니다.
+                // (English Translation) Draws the translucent objects from back to front.
+                if !*use_oit && !transparent_objects.is_empty() {
+                    rpass.set_pipeline(transparent_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, camera.uniform_bind_group(), &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, light_texture_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 4, &fog.uniform_bind_group, &[]);
+
+                    cube_mesh_0.bind(&mut rpass);
+                    for &(_, index) in transparent_objects.iter() {
+                        cubes[index].bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                }
+            });
+        graph.add_node(draw_pass);
+
+        // (한국어) 반투명한 물체들을 정렬 없이 누적한 후 프레임 버퍼에 합성합니다.
+        // (English Translation) Accumulates the translucent objects without sorting and composites them into the frame buffer.
+        if *use_oit && !transparent_objects.is_empty() {
+            let oit_accumulation_pass = RenderPassNodeBuilder::new("RenderPass(OitAccumulation)")
+                .set_color_attachments(oit_targets.color_attachments())
+                .set_depth_stencil_attachment(GraphDepthStencilAttachment {
+                    target: GraphTexture::Pooled(*depth_stencil_target), 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                })
+                .build(|mut rpass| {
+                    rpass.set_pipeline(oit_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, camera.uniform_bind_group(), &[]);
+                    stats::set_bind_group(&mut rpass, 2, light_uniform_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 3, light_texture_bind_group, &[]);
+                    stats::set_bind_group(&mut rpass, 4, &fog.uniform_bind_group, &[]);
+
+                    cube_mesh_0.bind(&mut rpass);
+                    for &(_, index) in transparent_objects.iter() {
+                        cubes[index].bind(&mut rpass, 1);
+                        cube_mesh_0.draw(&mut rpass);
+                    }
+                });
+            graph.add_node(oit_accumulation_pass);
+
+            let oit_composite_pass = RenderPassNodeBuilder::new("RenderPass(OitComposite)")
+                .set_color_attachments([
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: post_process.scene_view_ref(), 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load, 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ])
+                .build(|mut rpass| {
+                    rpass.set_pipeline(oit_composite_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, &oit_targets.bind_group, &[]);
+                    stats::draw(&mut rpass, 0..3, 0..1);
+                });
+            graph.add_node(oit_composite_pass);
+        }
+
+        // (한국어) 피킹이 요청된 경우 물체 번호를 ID 타겟에 그립니다.
+        // (English Translation) If picking was requested, draws the object ids into the ID target.
+        if id_picker.needs_pass() {
+            let id_pass = RenderPassNodeBuilder::new("RenderPass(Id)")
+                .set_color_attachments([
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: id_picker.id_view_ref(), 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ])
+                .set_depth_stencil_attachment(wgpu::RenderPassDepthStencilAttachment {
+                    view: id_picker.depth_view_ref(), 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0), 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                })
+                .build(|mut rpass| {
+                    rpass.set_pipeline(id_pipeline);
+                    stats::set_bind_group(&mut rpass, 0, camera.uniform_bind_group(), &[]);
+
+                    // (한국어) 바닥은 물체를 가리기만 하고 고를 수 없도록 빈 번호로 그립니다.
+                    // (English Translation) The floor is drawn with the empty id so it only occludes objects and cannot be picked.
+                    if *show_terrain {
+                        terrain.draw(&mut rpass, Some(frustum));
+                    } else if !*show_grid {
+                        plane_mesh.bind(&mut rpass);
+                        plane.bind(&mut rpass, 1);
+                        plane_mesh.draw(&mut rpass);
+                    }
+
+                    // (한국어) 절두체 밖의 큐브는 커서 아래에 있을 수 없으므로 그리지 않습니다.
+                    // (English Translation) Cubes outside the frustum cannot be under the cursor, so they are not drawn.
+                    cube_mesh_0.bind(&mut rpass);
+                    let visible_cubes = cubes.iter()
+                        .enumerate()
+                        .zip(in_frustum.iter())
+                        .filter(|(_, &in_frustum)| in_frustum);
+                    for ((index, object), _) in visible_cubes {
+                        let id = picking::object_id(index);
+                        object.bind(&mut rpass, 1);
+                        cube_mesh_0.draw_instanced(&mut rpass, id..id + 1);
+                    }
+                });
+            graph.add_node(id_pass);
+        }
+
+        // (한국어) 장면의 휘도 히스토그램으로부터 노출을 적응시킵니다.
+        // (English Translation) Adapts the exposure from the luminance histogram of the scene.
+        let auto_exposure_node = RenderPassNodeBuilder::new("AutoExposure")
+            .add_read(scene_view)
+            .build_commands(|encoder, _| {
+                post_process.auto_exposure_ref().dispatch(encoder, exposure_histogram_pipeline, exposure_average_pipeline);
+            });
+        graph.add_node(auto_exposure_node);
+
+        // (한국어) 장면의 밝은 부분을 추출하고 밉 체인을 따라 흐리게 합니다.
+        // (English Translation) Extracts the bright areas of the scene and blurs them along the mip chain.
+        let bloom_node = RenderPassNodeBuilder::new("Bloom")
+            .add_read(scene_view)
+            .add_write(GraphTexture::View(post_process.bloom_ref().chain_view_ref()))
+            .build_commands(|encoder, _| {
+                post_process.bloom_ref().draw(encoder, bloom_threshold_pipeline, bloom_downsample_pipeline, bloom_blur_pipeline);
+            });
+        graph.add_node(bloom_node);
+
+        // (한국어) 후처리 효과를 적용하여 장면을 프레임 버퍼에 그립니다.
+        // (English Translation) Draws the scene into the frame buffer with the post-processing effects applied.
+        let post_process_pass = RenderPassNodeBuilder::new("RenderPass(PostProcess)")
+            .set_color_attachments([
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &render_target_view, 
+                    resolve_target: None, 
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(match overlay {
+                            true => wgpu::Color::TRANSPARENT, 
+                            false => wgpu::Color::BLACK, 
+                        }), 
+                        store: wgpu::StoreOp::Store, 
+                    }, 
+                }), 
+            ])
+            .add_read(scene_view)
+            .add_read(GraphTexture::View(post_process.bloom_ref().chain_view_ref()))
+            .add_dependency("AutoExposure")
+            .build(|mut rpass| {
+                rpass.set_pipeline(post_process_pipeline);
+                post_process.draw(&mut rpass);
+            });
+        graph.add_node(post_process_pass);
+
+        let overlay_pass = RenderPassNodeBuilder::new("RenderPass(Overlay)")
+            .set_color_attachments([
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &render_target_view, 
+                    resolve_target: None, 
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }, 
+                }), 
+            ])
+            .build(|mut rpass| {
+                rpass.set_pipeline(sprite_pipeline);
+                stats::set_bind_group(&mut rpass, 0, screen_camera.uniform_bind_group(), &[]);
+                sprite_batch.draw(&mut rpass);
+
+                if *show_shadow_view {
+                    rpass.set_pipeline(shadow_view_pipeline);
+                    shadow_view.draw(&mut rpass);
+                }
+
+                rpass.set_pipeline(text_pipeline);
+                text_renderer.draw(&mut rpass);
+
+                #[cfg(feature = "egui")]
+                tweak_panel.draw(&mut rpass);
+            });
+        graph.add_node(overlay_pass);
+        graph.execute(target_pool, &mut encoder, device);
+
+        // (한국어) 피킹이 요청된 경우 ID 패스가 그린 커서 아래의 픽셀을 복사합니다.
+        // (English Translation) If picking was requested, copies the pixel under the cursor drawn by the ID pass.
+        id_picker.copy_pixel(target_pool, &mut encoder);
+
+        // (한국어) 이번 프레임의 오클루전 쿼리 결과를 읽기 버퍼로 복사합니다.
+        // (English Translation) Copies the occlusion query results of this frame into the readback buffer.
+        if *use_occlusion_culling {
+            occlusion.resolve(&mut encoder, *num_opaque_cubes);
+        }
+
+        // (한국어) 이번 프레임의 렌더 패스 타임스탬프들을 읽기 버퍼로 복사합니다.
+        // (English Translation) Copies the render pass timestamps of this frame into the readback buffer.
+        if let Some(gpu_profiler) = gpu_profiler.as_mut() {
+            gpu_profiler.resolve(&mut encoder);
+        }
+
+        // (한국어) 명령 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
+        // (English Translation) Submit command buffer to the queue and output to the framebuffer. 
+        frame_time_stats.end_frame();
+        swapchain.present(encoder, frame);
+        upload_ring.recall();
+        target_pool.end_frame();
+        *frame_stats = stats::end_frame();
+
+        // (한국어) 벤치마크의 프레임을 기록하고, 모든 프레임을 렌더링했으면 보고서를 출력하고 종료합니다.
+        // (English Translation) Records the frame of the benchmark, and prints the report and quits when all frames are rendered.
+        if let Some(benchmark) = benchmark.as_mut() {
+            benchmark.end_frame(frame_stats.num_draw_calls);
+            if benchmark.is_finished() {
+                println!("{}", benchmark.report(&[
+                    ("width", swapchain.width() as usize), 
+                    ("height", swapchain.height() as usize), 
+                    ("objects", *num_cubes), 
+                    ("colliders", collision_world.num_colliders()), 
+                    ("contacts", collision_world.num_contacts()), 
+                    ("terrain_chunks", terrain.num_chunks()), 
+                    ("occluded", occlusion.num_occluded()), 
+                    ("instances", frame_stats.num_instances), 
+                    ("bind_group_switches", frame_stats.num_bind_group_switches), 
+                    ("estimated_vram_bytes", stats::estimated_vram_bytes() as usize), 
+                ]));
+                *running = false;
+                window.request_redraw();
+            }
+        }
+
+        // (한국어) 이번 프레임의 오클루전 쿼리 결과를 다음 프레임에서 읽을 수 있도록 요청합니다.
+        // (English Translation) Requests the occlusion query results of this frame so they can be read in the next frame.
+        if *use_occlusion_culling {
+            occlusion.request_results();
+        }
+
+        // (한국어) 이번 프레임의 타임스탬프들을 다음 프레임에서 읽을 수 있도록 요청합니다.
+        // (English Translation) Requests the timestamps of this frame so they can be read in the next frame.
+        if let Some(gpu_profiler) = gpu_profiler.as_mut() {
+            gpu_profiler.request_results();
+        }
+
+        // (한국어) 복사한 피킹 픽셀을 다음 프레임에서 읽을 수 있도록 요청합니다.
+        // (English Translation) Requests the copied picking pixel so it can be read in the next frame.
+    }
+}
+
+/// #### 한국어 </br>
+/// 표면과 스왑체인의 설정, 그리고 이를 사용하는 장치와 명령 대기열을 묶은 렌더러의 상태 입니다. </br>
+/// 스왑체인의 재설정, 다음 프레임의 획득과 오류 복구, 제출과 출력을 맡으며, 장면의 자원은 각 단계가 따로 소유합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The state of the renderer bundling the surface and the swapchain configuration, and the device and the queue using them. </br>
+/// It takes care of reconfiguring the swapchain, acquiring the next frame with error recovery, and submitting and presenting, while the resources of the scene are owned by each stage. </br>
+/// 
+#[derive(Debug)]
+pub struct Swapchain {
+    instance: Arc<wgpu::Instance>, 
+    surface: Arc<wgpu::Surface<'static>>, 
+    adapter: Arc<wgpu::Adapter>, 
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    config: wgpu::SurfaceConfiguration, 
+}
+
+#[allow(dead_code)]
+impl Swapchain {
+    /// #### 한국어 </br>
+    /// 주어진 형식과 표시 모드로 스왑체인을 설정합니다. </br>
+    /// `overlay`가 `true`이면 미리 곱해진 알파로 창을 바탕 화면과 합성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Configures the swapchain with the given format and present mode. </br>
+    /// If `overlay` is `true`, composites the window with the desktop using premultiplied alpha. </br>
+    /// 
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        instance: Arc<wgpu::Instance>, 
+        surface: Arc<wgpu::Surface<'static>>, 
+        adapter: Arc<wgpu::Adapter>, 
+        device: Arc<wgpu::Device>, 
+        queue: Arc<wgpu::Queue>, 
+        format: wgpu::TextureFormat, 
+        width: u32, 
+        height: u32, 
+        present_mode: wgpu::PresentMode, 
+        overlay: bool
+    ) -> Self {
+        let mut config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT, 
+            format, 
+            width: width.max(1), 
+            height: height.max(1), 
+            present_mode, 
+            desired_maximum_frame_latency: resource::FRAMES_IN_FLIGHT as u32, 
+            alpha_mode: wgpu::CompositeAlphaMode::Auto, 
+            view_formats: vec![], 
+        };
+        if overlay {
+            if surface.get_capabilities(&adapter).alpha_modes.contains(&wgpu::CompositeAlphaMode::PreMultiplied) {
+                config.alpha_mode = wgpu::CompositeAlphaMode::PreMultiplied;
+            } else {
+                log::warn!("The surface does not support premultiplied alpha. The overlay window will not be transparent.");
+            }
+        }
+        surface.configure(&device, &config);
+
+        return Self { instance, surface, adapter, device, queue, config };
+    }
+
+    #[inline]
+    pub fn config(&self) -> &wgpu::SurfaceConfiguration {
+        &self.config
+    }
+
+    #[inline]
+    pub fn surface_format(&self) -> wgpu::TextureFormat {
+        self.config.format
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.config.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.config.height
+    }
+
+    #[inline]
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.config.present_mode
+    }
+
+    #[inline]
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    #[inline]
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 작업이 끝날 때 까지 기다린 후 스왑체인을 새 크기로 재설정합니다. </br>
+    /// 크기가 0이거나 바뀌지 않았으면 아무것도 하지 않고 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Waits until all operations are completed and then reconfigures the swapchain with the new size. </br>
+    /// Does nothing and returns `false` if the size is zero or did not change. </br>
+    /// 
+    pub fn resize(&mut self, width: u32, height: u32) -> bool {
+        if width == 0 || height == 0 || (width == self.config.width && height == self.config.height) {
+            return false;
+        }
+
+        self.instance.poll_all(true);
+        self.config.width = width;
+        self.config.height = height;
+        self.surface.configure(&self.device, &self.config);
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// 표면이 지원하는 다음 표시 모드로 바꾸고 스왑체인을 재설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Switches to the next present mode supported by the surface and reconfigures the swapchain. </br>
+    /// 
+    pub fn cycle_present_mode(&mut self) -> wgpu::PresentMode {
+        self.config.present_mode = utils::next_present_mode(self.config.present_mode, &self.surface, &self.adapter);
+        self.surface.configure(&self.device, &self.config);
+        return self.config.present_mode;
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 프레임을 가져옵니다. </br>
+    /// 표면이 오래되었거나 잃어버렸으면 다시 설정하고, 시간이 초과되었으면 `Ok(None)`을 반환하여 이번 프레임을 건너뛰도록 합니다. </br>
+    /// 복구할 수 없는 메모리 부족 오류만 `Err`로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the next frame. </br>
+    /// Reconfigures the surface if it is outdated or lost, and returns `Ok(None)` to skip this frame if it timed out. </br>
+    /// Only the unrecoverable out of memory error is returned as `Err`. </br>
+    /// 
+    pub fn acquire_frame(&self) -> Result<Option<wgpu::SurfaceTexture>, wgpu::SurfaceError> {
+        match self.surface.get_current_texture() {
+            Ok(frame) => Ok(Some(frame)), 
+            Err(wgpu::SurfaceError::Outdated | wgpu::SurfaceError::Lost) => {
+                log::warn!("The surface is outdated or lost. Reconfiguring the surface.");
+                self.surface.configure(&self.device, &self.config);
+                Ok(None)
+            }, 
+            Err(wgpu::SurfaceError::Timeout) => {
+                log::warn!("Timed out while acquiring the next frame. The frame is skipped.");
+                Ok(None)
+            }, 
+            Err(e) => Err(e), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 명령 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Submits the command buffer to the queue and outputs to the framebuffer. </br>
+    /// 
+    pub fn present(&self, encoder: wgpu::CommandEncoder, frame: wgpu::SurfaceTexture) {
+        self.queue.submit(Some(encoder.finish()));
+        frame.present();
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 창을 전체 화면으로 바꾸거나 창 모드로 되돌립니다. 이미 같은 종류의 전체 화면이면 창 모드로 되돌립니다. </br>
+/// 독점 전체 화면은 현재 모니터의 가장 큰 해상도와 가장 높은 주사율의 비디오 모드를 사용하며, 비디오 모드가 없으면 테두리 없는 전체 화면을 사용합니다. </br>
+/// 바뀐 창의 크기는 `WindowEvent::Resized`로 전달되어 스왑체인과 깊이 버퍼를 다시 설정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Switches the window to fullscreen or back to windowed mode. If it is already the same kind of fullscreen, it returns to windowed mode. </br>
+/// The exclusive fullscreen uses the video mode with the largest resolution and the highest refresh rate of the current monitor, and uses the borderless fullscreen if there is no video mode. </br>
+/// The new size of the window is delivered as `WindowEvent::Resized`, which reconfigures the swapchain and the depth buffer. </br>
+/// 
+fn toggle_fullscreen(window: &Window, exclusive: bool) {
+    let fullscreen = match (window.fullscreen(), exclusive) {
+        (Some(Fullscreen::Exclusive(_)), true) | (Some(Fullscreen::Borderless(_)), false) => None, 
+        (_, true) => {
+            let video_mode = window.current_monitor().and_then(|monitor| monitor.video_modes()
+                .max_by_key(|mode| (mode.size().width * mode.size().height, mode.refresh_rate_millihertz())));
+            match video_mode {
+                Some(video_mode) => Some(Fullscreen::Exclusive(video_mode)), 
+                None => {
+                    log::warn!("No video mode of the current monitor is available. The borderless fullscreen is used instead.");
+                    Some(Fullscreen::Borderless(None))
+                }, 
+            }
+        }, 
+        (_, false) => Some(Fullscreen::Borderless(None)), 
+    };
+
+    log::info!("Window mode: {}", match &fullscreen {
+        Some(Fullscreen::Exclusive(_)) => "exclusive fullscreen", 
+        Some(Fullscreen::Borderless(_)) => "borderless fullscreen", 
+        None => "windowed", 
+    });
+    window.set_fullscreen(fullscreen);
+}
@@ -0,0 +1,315 @@
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::env;
+use std::fs::File;
+use std::path::Path;
+use std::collections::VecDeque;
+use winit::{
+    keyboard::{KeyCode, PhysicalKey}, 
+    event::{DeviceEvent, ElementState, MouseButton, MouseScrollDelta, WindowEvent}, 
+};
+
+
+
+/// #### 한국어 </br>
+/// 입력 기록 모드를 설정하는 환경 변수의 이름 입니다. </br>
+/// 값은 `record:<파일 경로>` 또는 `replay:<파일 경로>` 입니다. (예: `record:capture.lpr`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The name of the environment variable that sets the input recording mode. </br>
+/// The value is `record:<file path>` or `replay:<file path>`. (e.g. `record:capture.lpr`) </br>
+/// 
+pub const REPLAY_MODE_VAR: &str = "LAB_PROJECT_REPLAY";
+
+const FILE_HEADER: &str = "LPREPLAY 1";
+
+const PIXELS_PER_LINE: f64 = 40.0;
+
+/// #### 한국어 </br>
+/// 기록할 수 있는 키들 입니다. 파일에는 키의 이름이 저장됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The keys that can be recorded. The names of the keys are stored in the file. </br>
+/// 
+const RECORDED_KEYS: [KeyCode; 58] = [
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF, KeyCode::KeyG, 
+    KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN, 
+    KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU, 
+    KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY, KeyCode::KeyZ, 
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4, 
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9, 
+    KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::ArrowUp, KeyCode::ArrowDown, 
+    KeyCode::Space, KeyCode::Enter, KeyCode::Escape, KeyCode::Tab, KeyCode::AltLeft, KeyCode::AltRight, 
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6, 
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12, 
+];
+
+/// #### 한국어 </br>
+/// 렌더링 루프가 처리하는 입력 이벤트 입니다. 창 크기 변경은 실제 창을 따라야 하므로 포함하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An input event handled by the rendering loop. Resizing is not included since it must follow the actual window. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputEvent {
+    Key { code: KeyCode, pressed: bool, repeat: bool }, 
+    CursorMoved { x: f64, y: f64 }, 
+    LeftMousePressed, 
+    LeftMouseReleased, 
+    MiddleMouse { pressed: bool }, 
+    RightMouse { pressed: bool }, 
+    MouseMotion { dx: f64, dy: f64 }, 
+    MouseWheel { lines: f32 }, 
+}
+
+impl InputEvent {
+    /// #### 한국어 </br>
+    /// 창 이벤트를 입력 이벤트로 바꿉니다. 기록할 수 없는 이벤트는 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts a window event into an input event. Returns `None` for events that cannot be recorded. </br>
+    /// 
+    pub fn from_window_event(event: &WindowEvent) -> Option<Self> {
+        match event {
+            WindowEvent::KeyboardInput { event, .. } => match event.physical_key {
+                PhysicalKey::Code(code) if RECORDED_KEYS.contains(&code) => Some(Self::Key {
+                    code, 
+                    pressed: event.state.is_pressed(), 
+                    repeat: event.repeat
+                }), 
+                _ => None, 
+            }, 
+            WindowEvent::CursorMoved { position, .. } => Some(Self::CursorMoved { x: position.x, y: position.y }), 
+            WindowEvent::MouseInput { state: ElementState::Pressed, button: MouseButton::Left, .. } => Some(Self::LeftMousePressed), 
+            WindowEvent::MouseInput { state: ElementState::Released, button: MouseButton::Left, .. } => Some(Self::LeftMouseReleased), 
+            WindowEvent::MouseInput { state, button: MouseButton::Middle, .. } => Some(Self::MiddleMouse { pressed: state.is_pressed() }), 
+            WindowEvent::MouseInput { state, button: MouseButton::Right, .. } => Some(Self::RightMouse { pressed: state.is_pressed() }), 
+            // (한국어) 픽셀 단위의 스크롤은 한 줄을 약 40 픽셀로 보고 줄 단위로 바꿉니다.
+            // (English Translation) The scroll in pixels is converted into lines, taking one line as about 40 pixels.
+            WindowEvent::MouseWheel { delta, .. } => Some(Self::MouseWheel {
+                lines: match *delta {
+                    MouseScrollDelta::LineDelta(_, y) => y, 
+                    MouseScrollDelta::PixelDelta(position) => (position.y / PIXELS_PER_LINE) as f32, 
+                }
+            }), 
+            _ => None, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 장치 이벤트를 입력 이벤트로 바꿉니다. 창 밖에서도 전달되는 마우스의 상대 이동만 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts a device event into an input event. Only the relative mouse motion, which is delivered even outside the window, is used. </br>
+    /// 
+    pub fn from_device_event(event: &DeviceEvent) -> Option<Self> {
+        match event {
+            DeviceEvent::MouseMotion { delta: (dx, dy) } => Some(Self::MouseMotion { dx: *dx, dy: *dy }), 
+            _ => None, 
+        }
+    }
+
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Self::Key { code, pressed, repeat } => writeln!(writer, "K {:?} {} {}", code, *pressed as u8, *repeat as u8), 
+            Self::CursorMoved { x, y } => writeln!(writer, "C {} {}", x, y), 
+            Self::LeftMousePressed => writeln!(writer, "M"), 
+            Self::LeftMouseReleased => writeln!(writer, "L"), 
+            Self::MiddleMouse { pressed } => writeln!(writer, "B {}", *pressed as u8), 
+            Self::RightMouse { pressed } => writeln!(writer, "R {}", *pressed as u8), 
+            Self::MouseMotion { dx, dy } => writeln!(writer, "D {} {}", dx, dy), 
+            Self::MouseWheel { lines } => writeln!(writer, "W {}", lines), 
+        }
+    }
+
+    fn parse(tokens: &[&str]) -> Option<Self> {
+        match tokens {
+            ["K", name, pressed, repeat] => Some(Self::Key {
+                code: *RECORDED_KEYS.iter().find(|code| format!("{:?}", code) == *name)?, 
+                pressed: *pressed == "1", 
+                repeat: *repeat == "1"
+            }), 
+            ["C", x, y] => Some(Self::CursorMoved { x: x.parse().ok()?, y: y.parse().ok()? }), 
+            ["M"] => Some(Self::LeftMousePressed), 
+            ["L"] => Some(Self::LeftMouseReleased), 
+            ["B", pressed] => Some(Self::MiddleMouse { pressed: *pressed == "1" }), 
+            ["R", pressed] => Some(Self::RightMouse { pressed: *pressed == "1" }), 
+            ["D", dx, dy] => Some(Self::MouseMotion { dx: dx.parse().ok()?, dy: dy.parse().ok()? }), 
+            ["W", lines] => Some(Self::MouseWheel { lines: lines.parse().ok()? }), 
+            _ => None, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 프레임 동안의 경과 시간과 입력 이벤트들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The elapsed time and the input events during one frame. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayFrame {
+    pub elapsed_time_sec: f32, 
+    pub inputs: Vec<InputEvent>, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 매 프레임의 경과 시간과 입력을 텍스트 파일에 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the elapsed time and the input of every frame to a text file. </br>
+/// 
+#[derive(Debug)]
+pub struct InputRecorder {
+    writer: BufWriter<File>, 
+    num_frames: u64, 
+}
+
+#[allow(dead_code)]
+impl InputRecorder {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{}", FILE_HEADER)?;
+        Ok(Self { writer, num_frames: 0 })
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임을 기록합니다. 경과 시간은 다시 읽었을 때 같은 값이 되도록 저장됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records one frame. The elapsed time is stored so that it reads back as the same value. </br>
+    /// 
+    pub fn record(&mut self, frame: &ReplayFrame) -> io::Result<()> {
+        writeln!(self.writer, "F {}", frame.elapsed_time_sec)?;
+        for input in frame.inputs.iter() {
+            input.write_to(&mut self.writer)?;
+        }
+        self.num_frames += 1;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn num_frames(&self) -> u64 {
+        self.num_frames
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 기록된 파일을 읽어 프레임을 순서대로 돌려줍니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads a recorded file and returns its frames in order. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct InputPlayer {
+    frames: VecDeque<ReplayFrame>, 
+}
+
+#[allow(dead_code)]
+impl InputPlayer {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let invalid = |line: usize| io::Error::new(
+            io::ErrorKind::InvalidData, 
+            format!("invalid input recording at line {}", line)
+        );
+
+        let mut lines = BufReader::new(File::open(path)?).lines();
+        if lines.next().transpose()?.as_deref() != Some(FILE_HEADER) {
+            return Err(invalid(1));
+        }
+
+        let mut frames = VecDeque::new();
+        for (number, line) in lines.enumerate() {
+            let line = line?;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                [] => continue, 
+                ["F", elapsed_time_sec] => frames.push_back(ReplayFrame {
+                    elapsed_time_sec: elapsed_time_sec.parse().map_err(|_| invalid(number + 2))?, 
+                    inputs: Vec::new(), 
+                }), 
+                tokens => {
+                    let input = InputEvent::parse(tokens).ok_or_else(|| invalid(number + 2))?;
+                    frames.back_mut().ok_or_else(|| invalid(number + 2))?.inputs.push(input);
+                }
+            }
+        }
+        Ok(Self { frames })
+    }
+
+    #[inline]
+    pub fn next_frame(&mut self) -> Option<ReplayFrame> {
+        self.frames.pop_front()
+    }
+
+    #[inline]
+    pub fn num_remaining_frames(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 환경 변수로 선택된 입력 기록 또는 재생 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The input recording or playback selected by the environment variable. </br>
+/// 
+#[derive(Debug)]
+pub enum InputReplay {
+    Record(InputRecorder), 
+    Playback(InputPlayer), 
+}
+
+#[allow(dead_code)]
+impl InputReplay {
+    /// #### 한국어 </br>
+    /// `REPLAY_MODE_VAR` 환경 변수로 생성합니다. 변수가 없으면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates it from the `REPLAY_MODE_VAR` environment variable. Returns `None` if the variable is not set. </br>
+    /// 
+    pub fn from_env() -> Option<io::Result<Self>> {
+        let value = env::var(REPLAY_MODE_VAR).ok()?;
+        Some(match value.split_once(':') {
+            Some(("record", path)) => InputRecorder::create(path).map(InputReplay::Record), 
+            Some(("replay", path)) => InputPlayer::open(path).map(InputReplay::Playback), 
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput, 
+                format!("{} must be `record:<path>` or `replay:<path>`", REPLAY_MODE_VAR)
+            )), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 기록 중이면 주어진 프레임을 기록하고, 재생 중이면 프레임을 기록된 다음 프레임으로 바꿉니다. </br>
+    /// 재생할 프레임이 더 이상 없으면 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the given frame when recording, and replaces the frame with the next recorded one when playing back. </br>
+    /// Returns `false` if there are no more frames to play back. </br>
+    /// 
+    pub fn advance(&mut self, frame: &mut ReplayFrame) -> io::Result<bool> {
+        match self {
+            Self::Record(recorder) => recorder.record(frame).map(|_| true), 
+            Self::Playback(player) => match player.next_frame() {
+                Some(next) => {
+                    *frame = next;
+                    Ok(true)
+                }, 
+                None => Ok(false), 
+            }, 
+        }
+    }
+
+    #[inline]
+    pub fn is_playback(&self) -> bool {
+        matches!(self, InputReplay::Playback(_))
+    }
+}
@@ -1,3 +1,13 @@
+use std::rc::Rc;
+use std::ops::Range;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicU64, Ordering as MemOrdering};
+use wgpu::util::StagingBelt;
+
+use crate::stats;
+
+
+
 /// #### 한국어 </br>
 /// 쉐이더 리소스가 사용하는 trait 입니다. </br>
 /// 
@@ -6,4 +16,569 @@
 /// 
 pub trait ShaderResource {
     fn update_resource(&self, queue: &wgpu::Queue);
-}
\ No newline at end of file
+}
+
+
+
+/// #### 한국어 </br>
+/// GPU가 동시에 처리할 수 있는 최대 프레임 수 입니다. 스왑체인의 `desired_maximum_frame_latency`와 같습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of frames the GPU can process at once. Same as `desired_maximum_frame_latency` of the swapchain. </br>
+/// 
+pub const FRAMES_IN_FLIGHT: usize = 2;
+
+/// #### 한국어 </br>
+/// 프레임별 유니폼 버퍼가 가지는 조각의 수 입니다. 처리 중인 프레임들이 읽는 조각과 CPU가 쓰는 조각이 겹치지 않도록 하나를 더 둡니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of slices a per-frame uniform buffer has. One more is kept so the slice written by the CPU never overlaps the slices read by the frames in flight. </br>
+/// 
+pub const NUM_UNIFORM_SLICES: usize = FRAMES_IN_FLIGHT + 1;
+
+static FRAME_INDEX: AtomicU64 = AtomicU64::new(0);
+
+/// #### 한국어 </br>
+/// 새 프레임을 시작합니다. 렌더링 루프에서 매 프레임 처음에 한 번 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Begins a new frame. Must be called once at the start of every frame in the rendering loop. </br>
+/// 
+#[inline]
+pub fn begin_frame() {
+    FRAME_INDEX.fetch_add(1, MemOrdering::Relaxed);
+}
+
+#[inline]
+pub fn frame_index() -> u64 {
+    FRAME_INDEX.load(MemOrdering::Relaxed)
+}
+
+/// #### 한국어 </br>
+/// 처리 중인 프레임마다 조각 하나씩을 가지는 유니폼 버퍼 입니다. </br>
+/// 새 프레임에서 처음 쓸 때 다음 조각으로 넘어가므로, 아직 GPU가 읽고 있는 이전 프레임의 조각에는 쓰지 않습니다. </br>
+/// 쓰지 않은 프레임에서는 마지막으로 쓴 조각을 계속 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A uniform buffer that has one slice per frame in flight. </br>
+/// It moves on to the next slice when first written in a new frame, so it never writes to a slice of a previous frame still being read by the GPU. </br>
+/// Frames that do not write to it keep using the last written slice. </br>
+/// 
+#[derive(Debug)]
+pub struct FrameUniformBuffer {
+    buffer: wgpu::Buffer, 
+    bind_groups: Vec<wgpu::BindGroup>, 
+    slice_size: wgpu::BufferAddress, 
+    slice: Cell<usize>, 
+    written_frame: Cell<Option<u64>>, 
+}
+
+#[allow(dead_code)]
+impl FrameUniformBuffer {
+    /// #### 한국어 </br>
+    /// 주어진 크기의 데이터를 담는 조각들과, 각 조각을 `binding`에 묶는 바인드 그룹들을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the slices holding data of the given size, and the bind groups binding each slice to `binding`. </br>
+    /// 
+    pub fn new(
+        label: &str, 
+        size: wgpu::BufferAddress, 
+        binding: u32, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device
+    ) -> Self {
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+        let slice_size = size.div_ceil(alignment) * alignment;
+        let buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&format!("Uniform({})", label)), 
+                mapped_at_creation: false, 
+                size: slice_size * NUM_UNIFORM_SLICES as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_groups = (0..NUM_UNIFORM_SLICES).map(|slice| device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(&format!("BindGroup({}[{}])", label, slice)), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding, 
+                        resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                            buffer: &buffer, 
+                            offset: slice as wgpu::BufferAddress * slice_size, 
+                            size: wgpu::BufferSize::new(size), 
+                        }), 
+                    }, 
+                ], 
+            }, 
+        ))
+        .collect();
+
+        Self {
+            buffer, 
+            bind_groups, 
+            slice_size, 
+            slice: Cell::new(0), 
+            written_frame: Cell::new(None), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 조각에 데이터를 씁니다. 같은 프레임에서 다시 쓰면 같은 조각을 덮어씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the data to the slice of this frame. Writing again in the same frame overwrites the same slice. </br>
+    /// 
+    pub fn write(&self, data: &[u8], queue: &wgpu::Queue) {
+        advance_slice(&self.slice, &self.written_frame);
+        queue.write_buffer(&self.buffer, self.slice.get() as wgpu::BufferAddress * self.slice_size, data);
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 쓴 조각을 묶는 바인드 그룹을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bind group binding the last written slice. </br>
+    /// 
+    #[inline]
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_groups[self.slice.get()]
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 여러 오브젝트의 유니폼 데이터를 하나의 버퍼에 담고, 동적 오프셋으로 구분하는 유니폼 버퍼 입니다. </br>
+/// 모든 조각이 하나의 바인드 그룹을 공유하므로 오브젝트마다 버퍼와 바인드 그룹을 만들지 않습니다. </br>
+/// 바인드 그룹 레이아웃의 항목은 `has_dynamic_offset: true` 이어야 합니다. </br>
+/// 슬롯에 쓴 데이터는 CPU 쪽 사본에 모아 두었다가 `flush`에서 한 번에 복사하므로, 매 프레임 그리기 전에 `flush`를 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A uniform buffer that holds the uniform data of many objects in one buffer and tells them apart with dynamic offsets. </br>
+/// All slots share one bind group, so no buffer and bind group is created per object. </br>
+/// The entry of the bind group layout must be `has_dynamic_offset: true`. </br>
+/// The data written to the slots is gathered in a CPU side copy and copied at once in `flush`, so `flush` must be called every frame before drawing. </br>
+/// 
+#[derive(Debug)]
+pub struct UniformArena {
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+    staging: RefCell<Vec<u8>>, 
+    dirty_ranges: RefCell<Vec<Range<wgpu::BufferAddress>>>, 
+    stride: wgpu::BufferAddress, 
+    capacity: u32, 
+    next_slot: Cell<u32>, 
+    free_slots: RefCell<Vec<u32>>, 
+}
+
+#[allow(dead_code)]
+impl UniformArena {
+    /// #### 한국어 </br>
+    /// 주어진 크기의 데이터를 `capacity`개 담는 버퍼와, 그 중 한 조각을 `binding`에 묶는 바인드 그룹을 생성합니다. </br>
+    /// 각 슬롯은 처리 중인 프레임과 겹치지 않도록 `NUM_UNIFORM_SLICES`개의 조각을 가집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a buffer holding `capacity` pieces of data of the given size, and a bind group binding one slice of it to `binding`. </br>
+    /// Each slot has `NUM_UNIFORM_SLICES` slices so that it never overlaps the frames in flight. </br>
+    /// 
+    pub fn new(
+        label: &str, 
+        size: wgpu::BufferAddress, 
+        capacity: u32, 
+        binding: u32, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device
+    ) -> Rc<Self> {
+        assert!(capacity > 0);
+
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+        let stride = size.div_ceil(alignment) * alignment;
+        let buffer_size = stride * (capacity as usize * NUM_UNIFORM_SLICES) as wgpu::BufferAddress;
+        let buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&format!("Uniform({})", label)), 
+                mapped_at_creation: false, 
+                size: buffer_size, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(&format!("BindGroup({})", label)), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding, 
+                        resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                            buffer: &buffer, 
+                            offset: 0, 
+                            size: wgpu::BufferSize::new(size), 
+                        }), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Rc::new(Self {
+            buffer, 
+            bind_group, 
+            staging: RefCell::new(vec![0; buffer_size as usize]), 
+            dirty_ranges: RefCell::new(Vec::new()), 
+            stride, 
+            capacity, 
+            next_slot: Cell::new(0), 
+            free_slots: RefCell::new(Vec::new()), 
+        })
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 할당된 슬롯의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of slots currently allocated. </br>
+    /// 
+    #[inline]
+    pub fn num_allocated(&self) -> u32 {
+        self.next_slot.get() - self.free_slots.borrow().len() as u32
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 슬롯이 공유하는 바인드 그룹을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bind group shared by all slots. </br>
+    /// 
+    #[inline]
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// #### 한국어 </br>
+    /// 빈 슬롯을 할당합니다. 슬롯이 해제되면 다시 사용됩니다. </br>
+    /// 
+    /// #### Panics </br>
+    /// 모든 슬롯이 사용 중이면 패닉합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Allocates an empty slot. The slot is reused once it is released. </br>
+    /// 
+    /// #### Panics </br>
+    /// Panics if all slots are in use. </br>
+    /// 
+    pub fn allocate(self: &Rc<Self>) -> UniformSlot {
+        let index = self.free_slots.borrow_mut().pop().unwrap_or_else(|| {
+            let index = self.next_slot.get();
+            assert!(index < self.capacity, "UniformArena is full. (capacity: {})", self.capacity);
+            self.next_slot.set(index + 1);
+            index
+        });
+
+        UniformSlot {
+            arena: self.clone(), 
+            index, 
+            slice: Cell::new(0), 
+            written_frame: Cell::new(None), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 `flush` 이후 슬롯들에 쓴 데이터를 `upload_ring`으로 유니폼 버퍼에 복사합니다. </br>
+    /// 붙어 있는 슬롯들은 하나의 복사로 합쳐지며, 복사는 `encoder`에 기록되므로 이 유니폼 데이터를 읽는 패스들보다 먼저 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Copies the data written to the slots since the last `flush` into the uniform buffer with `upload_ring`. </br>
+    /// Adjacent slots are merged into one copy, and the copies are recorded into `encoder`, so it must be called before the passes reading this uniform data. </br>
+    /// 
+    pub fn flush(&self, upload_ring: &mut UploadRing, encoder: &mut wgpu::CommandEncoder, device: &wgpu::Device) {
+        let mut dirty_ranges = self.dirty_ranges.borrow_mut();
+        if dirty_ranges.is_empty() {
+            return;
+        }
+
+        dirty_ranges.sort_unstable_by_key(|range| range.start);
+        let staging = self.staging.borrow();
+        let mut merged = dirty_ranges[0].clone();
+        for range in dirty_ranges.iter().skip(1) {
+            if range.start <= merged.end {
+                merged.end = merged.end.max(range.end);
+            } else {
+                upload_ring.write(encoder, &self.buffer, merged.start, &staging[merged.start as usize..merged.end as usize], device);
+                merged = range.clone();
+            }
+        }
+        upload_ring.write(encoder, &self.buffer, merged.start, &staging[merged.start as usize..merged.end as usize], device);
+        dirty_ranges.clear();
+    }
+
+    /// #### 한국어 </br>
+    /// 같은 조각의 슬롯들이 붙어 있도록 조각 순서로 놓습니다. 한 프레임에 쓴 슬롯들은 대부분 같은 조각에 있으므로 복사가 합쳐집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Places the slots in slice order so that the slots of the same slice are adjacent. The slots written in a frame are mostly in the same slice, so their copies are merged. </br>
+    /// 
+    #[inline]
+    fn offset(&self, index: u32, slice: usize) -> wgpu::BufferAddress {
+        (slice * self.capacity as usize + index as usize) as wgpu::BufferAddress * self.stride
+    }
+}
+
+/// #### 한국어 </br>
+/// `UniformArena`에서 할당된 슬롯 입니다. `FrameUniformBuffer`처럼 새 프레임에서 처음 쓸 때 다음 조각으로 넘어갑니다. </br>
+/// 슬롯이 버려지면 아레나에 반환됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A slot allocated from a `UniformArena`. Like `FrameUniformBuffer`, it moves on to the next slice when first written in a new frame. </br>
+/// The slot is returned to the arena when dropped. </br>
+/// 
+#[derive(Debug)]
+pub struct UniformSlot {
+    arena: Rc<UniformArena>, 
+    index: u32, 
+    slice: Cell<usize>, 
+    written_frame: Cell<Option<u64>>, 
+}
+
+#[allow(dead_code)]
+impl UniformSlot {
+    /// #### 한국어 </br>
+    /// 이번 프레임의 조각에 데이터를 씁니다. 같은 프레임에서 다시 쓰면 같은 조각을 덮어씁니다. </br>
+    /// 데이터는 아레나의 CPU 쪽 사본에 쓰이고, 아레나의 `flush`에서 유니폼 버퍼로 복사됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the data to the slice of this frame. Writing again in the same frame overwrites the same slice. </br>
+    /// The data is written to the CPU side copy of the arena, and copied into the uniform buffer in `flush` of the arena. </br>
+    /// 
+    pub fn write(&self, data: &[u8]) {
+        assert!(data.len() as wgpu::BufferAddress <= self.arena.stride);
+        advance_slice(&self.slice, &self.written_frame);
+        let offset = self.arena.offset(self.index, self.slice.get());
+        let start = offset as usize;
+        self.arena.staging.borrow_mut()[start..start + data.len()].copy_from_slice(data);
+        self.arena.dirty_ranges.borrow_mut().push(offset..offset + self.arena.stride);
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 쓴 조각의 동적 오프셋을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the dynamic offset of the last written slice. </br>
+    /// 
+    #[inline]
+    pub fn dynamic_offset(&self) -> u32 {
+        self.arena.offset(self.index, self.slice.get()) as u32
+    }
+
+    /// #### 한국어 </br>
+    /// 아레나의 바인드 그룹을 마지막으로 쓴 조각의 동적 오프셋으로 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the bind group of the arena with the dynamic offset of the last written slice. </br>
+    /// 
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, index: u32) {
+        stats::set_bind_group(rpass, index, &self.arena.bind_group, &[self.dynamic_offset()]);
+    }
+}
+
+impl Drop for UniformSlot {
+    #[inline]
+    fn drop(&mut self) {
+        self.arena.free_slots.borrow_mut().push(self.index);
+    }
+}
+
+/// #### 한국어 </br>
+/// 업로드 링이 한 번에 할당하는 스테이징 버퍼의 크기 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of the staging buffers the upload ring allocates at a time. </br>
+/// 
+pub const UPLOAD_RING_CHUNK_SIZE: wgpu::BufferAddress = 64 * 1024;
+
+/// #### 한국어 </br>
+/// 매 프레임 바뀌는 데이터를 매핑된 스테이징 버퍼에 모았다가 커맨드 버퍼의 복사로 올리는 업로드 링 입니다. </br>
+/// `wgpu::util::StagingBelt`를 감싸며, 다 쓴 스테이징 버퍼는 GPU가 복사를 끝낸 후 다시 사용됩니다. </br>
+/// 한 프레임에서 `write`를 모두 호출한 뒤 커맨드 버퍼를 제출하기 전에 `finish`를, 제출한 후에 `recall`을 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An upload ring that gathers the data changing every frame in mapped staging buffers and uploads it with copies in the command buffer. </br>
+/// It wraps `wgpu::util::StagingBelt`, and used staging buffers are reused after the GPU has finished copying. </br>
+/// In a frame, `finish` must be called after all `write` calls and before submitting the command buffer, and `recall` after submitting it. </br>
+/// 
+#[derive(Debug)]
+pub struct UploadRing {
+    belt: StagingBelt, 
+    num_bytes: wgpu::BufferAddress, 
+    num_copies: u32, 
+}
+
+#[allow(dead_code)]
+impl UploadRing {
+    #[inline]
+    pub fn new(chunk_size: wgpu::BufferAddress) -> Self {
+        Self {
+            belt: StagingBelt::new(chunk_size), 
+            num_bytes: 0, 
+            num_copies: 0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 올린 바이트 수 입니다. `recall`에서 초기화됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of bytes uploaded this frame. It is reset in `recall`. </br>
+    /// 
+    #[inline]
+    pub fn num_bytes(&self) -> wgpu::BufferAddress {
+        self.num_bytes
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 기록한 복사의 수 입니다. `recall`에서 초기화됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of copies recorded this frame. It is reset in `recall`. </br>
+    /// 
+    #[inline]
+    pub fn num_copies(&self) -> u32 {
+        self.num_copies
+    }
+
+    /// #### 한국어 </br>
+    /// `data`를 스테이징 버퍼에 쓰고, `target`의 `offset`으로 복사하는 명령을 `encoder`에 기록합니다. </br>
+    /// `target`은 `COPY_DST` 용도를 가져야 하며, `offset`과 데이터의 길이는 `wgpu::COPY_BUFFER_ALIGNMENT`의 배수여야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes `data` to a staging buffer, and records the command copying it to `offset` of `target` into `encoder`. </br>
+    /// `target` must have the `COPY_DST` usage, and `offset` and the length of the data must be multiples of `wgpu::COPY_BUFFER_ALIGNMENT`. </br>
+    /// 
+    pub fn write(
+        &mut self, 
+        encoder: &mut wgpu::CommandEncoder, 
+        target: &wgpu::Buffer, 
+        offset: wgpu::BufferAddress, 
+        data: &[u8], 
+        device: &wgpu::Device
+    ) {
+        let Some(size) = wgpu::BufferSize::new(data.len() as wgpu::BufferAddress) else {
+            return;
+        };
+        assert!(offset % wgpu::COPY_BUFFER_ALIGNMENT == 0 && size.get() % wgpu::COPY_BUFFER_ALIGNMENT == 0);
+
+        self.belt.write_buffer(encoder, target, offset, size, device).copy_from_slice(data);
+        self.num_bytes += size.get();
+        self.num_copies += 1;
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 스테이징 버퍼들의 매핑을 해제합니다. 커맨드 버퍼를 제출하기 전에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Unmaps the staging buffers of this frame. Must be called before submitting the command buffer. </br>
+    /// 
+    #[inline]
+    pub fn finish(&mut self) {
+        self.belt.finish();
+    }
+
+    /// #### 한국어 </br>
+    /// GPU가 복사를 끝낸 스테이징 버퍼들을 다시 사용할 수 있도록 매핑을 요청합니다. 커맨드 버퍼를 제출한 후에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests mapping of the staging buffers the GPU has finished copying from so they can be reused. Must be called after submitting the command buffer. </br>
+    /// 
+    #[inline]
+    pub fn recall(&mut self) {
+        self.belt.recall();
+        self.num_bytes = 0;
+        self.num_copies = 0;
+    }
+}
+
+/// #### 한국어 </br>
+/// 새 프레임에서 처음 쓰는 경우 다음 조각으로 넘어갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Moves on to the next slice when first written in a new frame. </br>
+/// 
+#[inline]
+fn advance_slice(slice: &Cell<usize>, written_frame: &Cell<Option<u64>>) {
+    let frame = frame_index();
+    match written_frame.get() {
+        Some(written) if written == frame => { /* empty */ }, 
+        Some(_) => slice.set((slice.get() + 1) % NUM_UNIFORM_SLICES), 
+        None => { /* empty */ }, 
+    }
+    written_frame.set(Some(frame));
+}
+
+
+
+/// #### 한국어 </br>
+/// 유니폼 버퍼를 담는 바인드 그룹 레이아웃 항목을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a bind group layout entry holding a uniform buffer. </br>
+/// 
+#[inline]
+pub fn uniform_buffer_layout_entry(binding: u32, visibility: wgpu::ShaderStages) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding, 
+        visibility, 
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Uniform, 
+            has_dynamic_offset: false, 
+            min_binding_size: None
+        }, 
+        count: None, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 저장소 버퍼를 담는 바인드 그룹 레이아웃 항목을 생성합니다. </br>
+/// `read_only`가 `false`이면 쉐이더가 버퍼에 쓸 수 있으며, 이 경우 정점 쉐이더에서는 사용할 수 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a bind group layout entry holding a storage buffer. </br>
+/// If `read_only` is `false` the shader can write to the buffer, and in that case it cannot be used in the vertex shader. </br>
+/// 
+#[inline]
+pub fn storage_buffer_layout_entry(binding: u32, visibility: wgpu::ShaderStages, read_only: bool) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding, 
+        visibility, 
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only }, 
+            has_dynamic_offset: false, 
+            min_binding_size: None
+        }, 
+        count: None, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 버퍼 전체를 묶는 바인드 그룹 항목을 생성합니다. 유니폼 버퍼와 저장소 버퍼 모두에 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a bind group entry binding the entire buffer. Used for both uniform and storage buffers. </br>
+/// 
+#[inline]
+pub fn buffer_bind_group_entry(binding: u32, buffer: &wgpu::Buffer) -> wgpu::BindGroupEntry<'_> {
+    wgpu::BindGroupEntry {
+        binding, 
+        resource: wgpu::BindingResource::Buffer(
+            buffer.as_entire_buffer_binding()
+        ), 
+    }
+}
@@ -0,0 +1,890 @@
+use std::io;
+use std::fs;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use std::rc::Rc;
+
+use crate::fog::{FogMode, HeightFog, HeightFogBuilder, FOG_MODES};
+use crate::object::{AnimatedObject, GameObject, StdObject, StdObjectBuilder};
+use crate::resource::UniformArena;
+
+
+
+/// #### 한국어 </br>
+/// 장면 파일을 불러오는 명령줄 인수 입니다. 뒤에 JSON 장면 파일의 경로가 옵니다. (예: `--scene assets/scene.json`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that loads a scene file. It is followed by the path of a JSON scene file. (e.g. `--scene assets/scene.json`) </br>
+/// 
+pub const SCENE_ARG: &str = "--scene";
+
+/// #### 한국어 </br>
+/// 불러온 장면 파일이 없을 때 장면을 저장하는 경로 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The path the scene is saved to when no scene file was loaded. </br>
+/// 
+pub const DEFAULT_SCENE_PATH: &str = "scene.json";
+
+/// #### 한국어 </br>
+/// 장면 파일에 쓸 수 있는 메쉬 종류와 파일에서 쓰는 이름 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The mesh kinds that can be used in a scene file and the names used in the file. </br>
+/// 
+pub const SCENE_MESHES: [(&str, SceneMesh); 2] = [
+    ("cube", SceneMesh::Cube), 
+    ("sphere", SceneMesh::Sphere), 
+];
+
+/// #### 한국어 </br>
+/// 장면 파일에 담을 수 있는 오브젝트의 최대 수 입니다. 공유 유니폼 버퍼는 이만큼의 슬롯을 장면 파일의 오브젝트들을 위해 더 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of objects a scene file can hold. The shared uniform buffer has this many more slots for the objects of the scene file. </br>
+/// 
+pub const MAX_SCENE_OBJECTS: usize = 1024;
+
+/// #### 한국어 </br>
+/// 장면 파일의 수정 시각을 확인하는 간격 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The interval at which the modification time of the scene file is checked. </br>
+/// 
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// #### 한국어 </br>
+/// 장면 파일에서 객체와 배열이 중첩될 수 있는 최대 깊이 입니다. 깊이 중첩된 파일이 스택을 넘치게 하지 않도록 제한합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum depth objects and arrays can be nested in a scene file. It keeps deeply nested files from overflowing the stack. </br>
+/// 
+const MAX_JSON_DEPTH: usize = 64;
+
+
+
+/// #### 한국어 </br>
+/// JSON 장면 파일을 읽습니다. 빠진 항목은 기본값을 사용하며, 형식이 잘못되면 `InvalidData` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads a JSON scene file. Missing entries use the default values, and an `InvalidData` error is returned if the format is wrong. </br>
+/// 
+pub fn load_from_file<P: AsRef<Path>>(path: P) -> io::Result<Scene> {
+    let source = fs::read_to_string(path)?;
+    let root = JsonParser::new(&source).parse()?;
+    Scene::from_json(&root)
+}
+
+/// #### 한국어 </br>
+/// 장면을 `load_from_file`로 다시 읽을 수 있는 JSON 장면 파일로 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Writes the scene as a JSON scene file that can be read again with `load_from_file`. </br>
+/// 
+pub fn save_to_file<P: AsRef<Path>>(path: P, scene: &Scene) -> io::Result<()> {
+    fs::write(path, scene.to_json())
+}
+
+
+
+/// #### 한국어 </br>
+/// 장면의 오브젝트가 그려지는 메쉬의 종류 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The kind of mesh an object of the scene is drawn with. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SceneMesh {
+    #[default]
+    Cube, 
+    Sphere, 
+}
+
+impl SceneMesh {
+    #[inline]
+    pub fn name(self) -> &'static str {
+        SCENE_MESHES.iter().find(|&&(_, mesh)| mesh == self).map(|&(name, _)| name).unwrap_or("cube")
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일의 카메라 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The camera of a scene file. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SceneCamera {
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+}
+
+impl Default for SceneCamera {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            translation: (0.0, 3.5, 8.0).into(), 
+            rotation: glam::Quat::from_rotation_x(-15.0f32.to_radians())
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일의 전역 조명 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The global light of a scene file. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SceneLight {
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub color: glam::Vec3, 
+}
+
+impl Default for SceneLight {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            translation: (0.0, 5.0, 0.0).into(), 
+            rotation: glam::Quat::from_rotation_x(-90.0f32.to_radians()), 
+            color: glam::Vec3::ONE
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일의 안개 입니다. 빠진 항목은 `HeightFogBuilder`의 기본값을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The fog of a scene file. Missing entries use the default values of `HeightFogBuilder`. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SceneFog {
+    pub enabled: bool, 
+    pub mode: FogMode, 
+    pub color: glam::Vec3, 
+    pub density: f32, 
+    pub start: f32, 
+    pub end: f32, 
+}
+
+impl Default for SceneFog {
+    #[inline]
+    fn default() -> Self {
+        let builder = HeightFogBuilder::default();
+        Self {
+            enabled: true, 
+            mode: builder.mode, 
+            color: builder.color, 
+            density: builder.density, 
+            start: builder.start, 
+            end: builder.end
+        }
+    }
+}
+
+impl SceneFog {
+    /// #### 한국어 </br>
+    /// 실행 중에 바뀐 값들을 저장할 수 있도록 현재 안개의 값들을 담는 장면 안개를 만듭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Makes a scene fog holding the values of the current fog, so that the values changed at runtime can be saved. </br>
+    /// 
+    pub fn from_fog(fog: &HeightFog) -> Self {
+        Self {
+            enabled: fog.is_enabled(), 
+            mode: fog.mode(), 
+            color: fog.color(), 
+            density: fog.density(), 
+            start: fog.start(), 
+            end: fog.end()
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이 장면 안개의 값들을 안개에 설정합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the values of this scene fog to the fog. `update_resource` must be called after the change. </br>
+    /// 
+    pub fn apply(&self, fog: &mut HeightFog) {
+        fog.set_enabled(self.enabled);
+        fog.set_mode(self.mode);
+        fog.set_color(self.color);
+        fog.set_density(self.density);
+        fog.set_range(self.start, self.end);
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일의 오브젝트 입니다. `StdObjectBuilder`가 받는 값들을 담습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An object of a scene file. It holds the values taken by `StdObjectBuilder`. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneObject {
+    pub label: String, 
+    pub mesh: SceneMesh, 
+    pub color: glam::Vec3, 
+    pub opacity: f32, 
+    pub rim_color: glam::Vec3, 
+    pub rim_power: f32, 
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+}
+
+impl Default for SceneObject {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            label: String::new(), 
+            mesh: SceneMesh::default(), 
+            color: glam::Vec3::ONE, 
+            opacity: 1.0, 
+            rim_color: glam::Vec3::ZERO, 
+            rim_power: 3.0, 
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY
+        }
+    }
+}
+
+impl SceneObject {
+    /// #### 한국어 </br>
+    /// 실행 중에 바뀐 값들을 저장할 수 있도록 현재 오브젝트의 재질과 변환을 담는 장면 오브젝트를 만듭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Makes a scene object holding the material and the transform of the current object, so that the values changed at runtime can be saved. </br>
+    /// 
+    pub fn from_object(label: &str, mesh: SceneMesh, object: &StdObject) -> Self {
+        Self {
+            label: label.to_string(), 
+            mesh, 
+            color: object.color(), 
+            opacity: object.opacity(), 
+            rim_color: object.rim_color(), 
+            rim_power: object.rim_power(), 
+            translation: object.get_translation(), 
+            rotation: object.get_rotation()
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트 빌더로 이 오브젝트를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates this object with the object builder. </br>
+    /// 
+    pub fn build(&self, object_uniforms: &Rc<UniformArena>, queue: &wgpu::Queue) -> StdObject {
+        StdObjectBuilder::new()
+            .set_color(self.color)
+            .set_opacity(self.opacity)
+            .set_rim_color(self.rim_color)
+            .set_rim_power(self.rim_power)
+            .set_translation(self.translation)
+            .set_rotation(self.rotation)
+            .build(object_uniforms, queue)
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일에서 읽은 카메라와 전역 조명, 안개, 그리고 오브젝트들 입니다. </br>
+/// 장면 파일은 다음과 같은 JSON 이며, 회전은 (x, y, z, w) 사원수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The camera, the global light, the fog and the objects read from a scene file. </br>
+/// A scene file is JSON like the following, and rotations are (x, y, z, w) quaternions. </br>
+/// 
+/// ```json
+/// {
+///     "camera": { "translation": [0, 3.5, 8], "rotation": [-0.1305, 0, 0, 0.9914] }, 
+///     "light": { "translation": [0, 5, 0], "rotation": [-0.7071, 0, 0, 0.7071], "color": [1, 1, 1] }, 
+///     "fog": { "enabled": true, "mode": "height", "color": [0.6, 0.68, 0.78], "density": 0.02, "start": 5, "end": 40 }, 
+///     "objects": [
+///         { "label": "Red Cube", "mesh": "cube", "color": [1, 0.2, 0.2], "translation": [0, 0.5, 0] }, 
+///         { "label": "Glass", "mesh": "sphere", "color": [0.6, 0.85, 1], "opacity": 0.35, "translation": [1.6, 0.5, -1.2] }
+///     ]
+/// }
+/// ```
+/// 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Scene {
+    pub camera: SceneCamera, 
+    pub light: SceneLight, 
+    pub fog: SceneFog, 
+    pub objects: Vec<SceneObject>, 
+}
+
+impl Scene {
+    /// #### 한국어 </br>
+    /// 장면의 오브젝트들을 생성하여 큐브들과 그 이름들, 그리고 구들로 나누어 반환합니다. </br>
+    /// 구들은 움직이지 않는 애니메이션 오브젝트로 만들어지므로 데모 장면의 구처럼 충돌체와 함께 그려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the objects of the scene and returns them split into the cubes, their labels and the spheres. </br>
+    /// The spheres are made into animated objects that do not move, so they are drawn with colliders like the sphere of the demo scene. </br>
+    /// 
+    pub fn build_objects(
+        &self, 
+        object_uniforms: &Rc<UniformArena>, 
+        queue: &wgpu::Queue
+    ) -> (Vec<StdObject>, Vec<String>, Vec<AnimatedObject>) {
+        let mut cubes = Vec::new();
+        let mut cube_labels = Vec::new();
+        let mut spheres = Vec::new();
+        for object in self.objects.iter() {
+            match object.mesh {
+                SceneMesh::Cube => {
+                    cubes.push(object.build(object_uniforms, queue));
+                    cube_labels.push(object.label.clone());
+                }, 
+                SceneMesh::Sphere => spheres.push(AnimatedObject::new(object.build(object_uniforms, queue), |_, _, _| {})), 
+            }
+        }
+        return (cubes, cube_labels, spheres);
+    }
+
+    /// #### 한국어 </br>
+    /// 장면을 오브젝트마다 한 줄씩 쓰는 JSON 문자열로 변환합니다. </br>
+    /// JSON은 NaN과 무한대를 나타낼 수 없으므로, NaN은 0으로, 무한대는 같은 부호의 가장 큰 유한한 값으로 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the scene into a JSON string writing one line per object. </br>
+    /// JSON cannot represent NaN and infinity, so NaN is written as 0 and infinity as the largest finite value of the same sign. </br>
+    /// 
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        writeln!(&mut json, "{{").unwrap();
+        writeln!(
+            &mut json, 
+            "    \"camera\": {{ \"translation\": {}, \"rotation\": {} }},", 
+            json_numbers(&self.camera.translation.to_array()), 
+            json_numbers(&self.camera.rotation.to_array())
+        ).unwrap();
+        writeln!(
+            &mut json, 
+            "    \"light\": {{ \"translation\": {}, \"rotation\": {}, \"color\": {} }},", 
+            json_numbers(&self.light.translation.to_array()), 
+            json_numbers(&self.light.rotation.to_array()), 
+            json_numbers(&self.light.color.to_array())
+        ).unwrap();
+        writeln!(
+            &mut json, 
+            "    \"fog\": {{ \"enabled\": {}, \"mode\": \"{}\", \"color\": {}, \"density\": {}, \"start\": {}, \"end\": {} }},", 
+            self.fog.enabled, 
+            self.fog.mode.name(), 
+            json_numbers(&self.fog.color.to_array()), 
+            json_number(self.fog.density), 
+            json_number(self.fog.start), 
+            json_number(self.fog.end)
+        ).unwrap();
+        writeln!(&mut json, "    \"objects\": [").unwrap();
+        for (index, object) in self.objects.iter().enumerate() {
+            writeln!(
+                &mut json, 
+                "        {{ \"label\": {}, \"mesh\": \"{}\", \"color\": {}, \"opacity\": {}, \"rim_color\": {}, \"rim_power\": {}, \"translation\": {}, \"rotation\": {} }}{}", 
+                json_string(&object.label), 
+                object.mesh.name(), 
+                json_numbers(&object.color.to_array()), 
+                json_number(object.opacity), 
+                json_numbers(&object.rim_color.to_array()), 
+                json_number(object.rim_power),  
+                json_numbers(&object.translation.to_array()), 
+                json_numbers(&object.rotation.to_array()), 
+                if index + 1 < self.objects.len() { "," } else { "" }
+            ).unwrap();
+        }
+        writeln!(&mut json, "    ]").unwrap();
+        writeln!(&mut json, "}}").unwrap();
+        return json;
+    }
+
+    fn from_json(root: &JsonValue) -> io::Result<Self> {
+        let mut scene = Self::default();
+        if let Some(camera) = root.get("camera") {
+            scene.camera.translation = read_vec3(camera, "translation", scene.camera.translation)?;
+            scene.camera.rotation = read_quat(camera, "rotation", scene.camera.rotation)?;
+        }
+        if let Some(light) = root.get("light") {
+            scene.light.translation = read_vec3(light, "translation", scene.light.translation)?;
+            scene.light.rotation = read_quat(light, "rotation", scene.light.rotation)?;
+            scene.light.color = read_vec3(light, "color", scene.light.color)?;
+        }
+        if let Some(fog) = root.get("fog") {
+            scene.fog.enabled = read_bool(fog, "enabled", scene.fog.enabled)?;
+            scene.fog.mode = match fog.get("mode") {
+                Some(JsonValue::String(name)) => FOG_MODES.iter()
+                    .find(|(mode_name, _)| mode_name == name)
+                    .map(|&(_, mode)| mode)
+                    .ok_or_else(|| invalid_data(format!("unknown fog mode \"{}\"", name)))?, 
+                Some(_) => return Err(invalid_data("\"mode\" must be a string")), 
+                None => scene.fog.mode, 
+            };
+            scene.fog.color = read_vec3(fog, "color", scene.fog.color)?;
+            scene.fog.density = read_f32(fog, "density", scene.fog.density)?.max(0.0);
+            scene.fog.start = read_f32(fog, "start", scene.fog.start)?;
+            scene.fog.end = read_f32(fog, "end", scene.fog.end)?;
+        }
+
+        let objects = match root.get("objects") {
+            Some(JsonValue::Array(objects)) => objects.as_slice(), 
+            Some(_) => return Err(invalid_data("\"objects\" must be an array")), 
+            None => &[], 
+        };
+        if objects.len() > MAX_SCENE_OBJECTS {
+            return Err(invalid_data(format!("the scene has {} objects, but at most {} are supported", objects.len(), MAX_SCENE_OBJECTS)));
+        }
+        for (index, value) in objects.iter().enumerate() {
+            let mut object = SceneObject::default();
+            object.mesh = match value.get("mesh") {
+                Some(JsonValue::String(name)) => SCENE_MESHES.iter()
+                    .find(|(mesh_name, _)| mesh_name == name)
+                    .map(|&(_, mesh)| mesh)
+                    .ok_or_else(|| invalid_data(format!("unknown mesh \"{}\"", name)))?, 
+                Some(_) => return Err(invalid_data("\"mesh\" must be a string")), 
+                None => object.mesh, 
+            };
+            object.label = match value.get("label") {
+                Some(JsonValue::String(label)) => label.clone(), 
+                Some(_) => return Err(invalid_data("\"label\" must be a string")), 
+                None => format!("Object {} ({})", index, object.mesh.name()), 
+            };
+            object.color = read_vec3(value, "color", object.color)?;
+            object.opacity = read_f32(value, "opacity", object.opacity)?.clamp(0.0, 1.0);
+            object.rim_color = read_vec3(value, "rim_color", object.rim_color)?;
+            object.rim_power = read_f32(value, "rim_power", object.rim_power)?;
+            object.translation = read_vec3(value, "translation", object.translation)?;
+            object.rotation = read_quat(value, "rotation", object.rotation)?;
+            scene.objects.push(object);
+        }
+
+        return Ok(scene);
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일의 수정 시각을 주기적으로 확인하여, 파일이 바뀌면 다시 읽는 감시자 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A watcher that periodically checks the modification time of a scene file and reads it again when the file changes. </br>
+/// 
+#[derive(Debug)]
+pub struct SceneWatcher {
+    path: PathBuf, 
+    modified_time: Option<SystemTime>, 
+    last_poll: Instant, 
+}
+
+#[allow(dead_code)]
+impl SceneWatcher {
+    /// #### 한국어 </br>
+    /// 주어진 장면 파일을 감시하는 감시자를 생성합니다. 현재 파일은 바뀌지 않은 것으로 봅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a watcher watching the given scene file. The current file is regarded as unchanged. </br>
+    /// 
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let modified_time = modified_time(&path);
+        Self { 
+            path, 
+            modified_time, 
+            last_poll: Instant::now() 
+        }
+    }
+
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 파일을 바뀌지 않은 것으로 봅니다. 감시하는 파일에 장면을 저장한 후 호출하면 저장한 장면을 다시 읽지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Regards the current file as unchanged. Calling it after saving a scene to the watched file keeps the saved scene from being read again. </br>
+    /// 
+    #[inline]
+    pub fn refresh(&mut self) {
+        self.modified_time = modified_time(&self.path);
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 확인 후 `POLL_INTERVAL`이 지났고 파일이 바뀌었으면 장면 파일을 다시 읽어 반환합니다. 바뀌지 않았으면 `None`을 반환합니다. </br>
+    /// 파일을 저장하는 도중에 읽어 실패할 수 있으므로, 실패한 경우에도 다음 수정은 다시 읽습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If `POLL_INTERVAL` has passed since the last check and the file has changed, reads the scene file again and returns it. Returns `None` if it has not changed. </br>
+    /// It may fail when read while the file is being saved, so even after a failure the next modification is read again. </br>
+    /// 
+    pub fn poll(&mut self) -> Option<io::Result<Scene>> {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return None;
+        }
+        self.last_poll = Instant::now();
+
+        let modified_time = modified_time(&self.path);
+        if modified_time.is_none() || modified_time == self.modified_time {
+            return None;
+        }
+        self.modified_time = modified_time;
+
+        Some(load_from_file(&self.path))
+    }
+}
+
+
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null, 
+    Bool(bool), 
+    Number(f64), 
+    String(String), 
+    Array(Vec<JsonValue>), 
+    Object(Vec<(String, JsonValue)>), 
+}
+
+impl JsonValue {
+    #[inline]
+    fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            Self::Object(members) => members.iter().find(|(name, _)| name == key).map(|(_, value)| value), 
+            _ => None, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 파일을 읽는 데 필요한 만큼만 구현한 재귀 하강 JSON 파서 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A recursive descent JSON parser implementing only as much as needed to read scene files. </br>
+/// 
+struct JsonParser<'a> {
+    bytes: &'a [u8], 
+    position: usize, 
+    depth: usize, 
+}
+
+impl<'a> JsonParser<'a> {
+    #[inline]
+    fn new(source: &'a str) -> Self {
+        Self { bytes: source.as_bytes(), position: 0, depth: 0 }
+    }
+
+    fn parse(mut self) -> io::Result<JsonValue> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        if self.position < self.bytes.len() {
+            return Err(self.error("unexpected trailing characters"));
+        }
+        return Ok(value);
+    }
+
+    #[inline]
+    fn error(&self, message: &str) -> io::Error {
+        invalid_data(format!("{} at byte {}", message, self.position))
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> io::Result<()> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(found) if found == byte => {
+                self.position += 1;
+                Ok(())
+            }, 
+            _ => Err(self.error(&format!("expected '{}'", byte as char))), 
+        }
+    }
+
+    fn parse_keyword(&mut self, keyword: &str, value: JsonValue) -> io::Result<JsonValue> {
+        match self.bytes[self.position..].starts_with(keyword.as_bytes()) {
+            true => {
+                self.position += keyword.len();
+                Ok(value)
+            }, 
+            false => Err(self.error("unexpected character")), 
+        }
+    }
+
+    fn parse_value(&mut self) -> io::Result<JsonValue> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.parse_nested(Self::parse_object), 
+            Some(b'[') => self.parse_nested(Self::parse_array), 
+            Some(b'"') => self.parse_string().map(JsonValue::String),  
+            Some(b't') => self.parse_keyword("true", JsonValue::Bool(true)), 
+            Some(b'f') => self.parse_keyword("false", JsonValue::Bool(false)), 
+            Some(b'n') => self.parse_keyword("null", JsonValue::Null), 
+            Some(b'-' | b'0'..=b'9') => self.parse_number(), 
+            Some(_) => Err(self.error("unexpected character")), 
+            None => Err(self.error("unexpected end of file")), 
+        }
+    }
+
+    fn parse_nested(&mut self, parse: fn(&mut Self) -> io::Result<JsonValue>) -> io::Result<JsonValue> {
+        if self.depth >= MAX_JSON_DEPTH {
+            return Err(self.error(&format!("nested deeper than {} levels", MAX_JSON_DEPTH)));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        return value;
+    }
+
+    fn parse_object(&mut self) -> io::Result<JsonValue> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1, 
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(members));
+                }, 
+                _ => return Err(self.error("expected ',' or '}'")), 
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> io::Result<JsonValue> {
+        self.expect(b'[')?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(elements));
+        }
+
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1, 
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(elements));
+                }, 
+                _ => return Err(self.error("expected ',' or ']'")), 
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> io::Result<String> {
+        if self.peek() != Some(b'"') {
+            return Err(self.error("expected a string"));
+        }
+        self.position += 1;
+
+        let mut string = Vec::new();
+        loop {
+            let Some(byte) = self.peek() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.position += 1;
+            match byte {
+                b'"' => break, 
+                b'\\' => {
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"', 
+                        Some(b'\\') => '\\', 
+                        Some(b'/') => '/', 
+                        Some(b'b') => '\u{8}', 
+                        Some(b'f') => '\u{c}', 
+                        Some(b'n') => '\n', 
+                        Some(b'r') => '\r', 
+                        Some(b't') => '\t', 
+                        Some(b'u') => {
+                            let code_unit = self.parse_code_unit(self.position + 1);
+                            self.position += 4;
+                            match code_unit {
+                                // (한국어) 기본 다국어 평면 밖의 문자는 상위 서로게이트 뒤에 `\u`로 시작하는 하위 서로게이트가 와야 합니다.
+                                // (English Translation) A character outside the basic multilingual plane must be a high surrogate followed by a low surrogate starting with `\u`.
+                                Some(high @ 0xD800..=0xDBFF) => {
+                                    let low = match self.bytes.get(self.position + 1..self.position + 3) {
+                                        Some(b"\\u") => self.parse_code_unit(self.position + 3), 
+                                        _ => None, 
+                                    };
+                                    match low {
+                                        Some(low @ 0xDC00..=0xDFFF) => {
+                                            self.position += 6;
+                                            char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)).unwrap()
+                                        }, 
+                                        _ => return Err(self.error("unpaired surrogate in unicode escape")), 
+                                    }
+                                }, 
+                                Some(0xDC00..=0xDFFF) => return Err(self.error("unpaired surrogate in unicode escape")), 
+                                code_unit => code_unit.and_then(char::from_u32).ok_or_else(|| self.error("invalid unicode escape"))?, 
+                            }
+                        },  
+                        _ => return Err(self.error("invalid escape")), 
+                    };
+                    self.position += 1;
+                    string.extend_from_slice(escaped.encode_utf8(&mut [0; 4]).as_bytes());
+                }, 
+                _ => string.push(byte), 
+            }
+        }
+
+        String::from_utf8(string).map_err(|_| self.error("invalid UTF-8"))
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 위치의 16진수 네 자리를 UTF-16 코드 단위로 읽습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the four hexadecimal digits at the given position as a UTF-16 code unit. </br>
+    /// 
+    #[inline]
+    fn parse_code_unit(&self, position: usize) -> Option<u32> {
+        let digits = self.bytes.get(position..position + 4)?;
+        if !digits.iter().all(u8::is_ascii_hexdigit) {
+            return None;
+        }
+        std::str::from_utf8(digits).ok().and_then(|digits| u32::from_str_radix(digits, 16).ok())
+    }
+
+    fn parse_number(&mut self) -> io::Result<JsonValue> {
+        let start = self.position;
+        while matches!(self.peek(), Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.position]).ok()
+            .and_then(|number| number.parse().ok())
+            .map(JsonValue::Number)
+            .ok_or_else(|| self.error("invalid number"))
+    }
+}
+
+/// #### 한국어 </br>
+/// 문자열을 따옴표와 제어 문자를 이스케이프한 JSON 문자열로 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Writes a string as a JSON string with the quotes and the control characters escaped. </br>
+/// 
+fn json_string(string: &str) -> String {
+    let mut json = String::with_capacity(string.len() + 2);
+    json.push('"');
+    for ch in string.chars() {
+        match ch {
+            '"' => json.push_str("\\\""), 
+            '\\' => json.push_str("\\\\"), 
+            '\n' => json.push_str("\\n"), 
+            '\r' => json.push_str("\\r"), 
+            '\t' => json.push_str("\\t"), 
+            ch if (ch as u32) < 0x20 => write!(&mut json, "\\u{:04x}", ch as u32).unwrap(), 
+            ch => json.push(ch), 
+        }
+    }
+    json.push('"');
+    return json;
+}
+
+/// #### 한국어 </br>
+/// 숫자를 JSON 숫자로 씁니다. NaN은 0으로, 무한대는 같은 부호의 가장 큰 유한한 값으로 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Writes a number as a JSON number. NaN is written as 0, and infinity as the largest finite value of the same sign. </br>
+/// 
+#[inline]
+fn json_number(number: f32) -> String {
+    match number.is_nan() {
+        true => String::from("0"), 
+        false => number.clamp(f32::MIN, f32::MAX).to_string(), 
+    }
+}
+
+#[inline]
+fn json_numbers(numbers: &[f32]) -> String {
+    format!("[{}]", numbers.iter().map(|&number| json_number(number)).collect::<Vec<_>>().join(", "))
+}
+
+#[inline]
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+#[inline]
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn read_numbers<const N: usize>(value: &JsonValue, key: &str) -> io::Result<Option<[f32; N]>> {
+    let elements = match value.get(key) {
+        Some(JsonValue::Array(elements)) if elements.len() == N => elements, 
+        Some(_) => return Err(invalid_data(format!("\"{}\" must be an array of {} finite numbers", key, N))), 
+        None => return Ok(None), 
+    };
+
+    let mut numbers = [0.0; N];
+    for (number, element) in numbers.iter_mut().zip(elements.iter()) {
+        match element {
+            JsonValue::Number(value) if (*value as f32).is_finite() => *number = *value as f32, 
+            _ => return Err(invalid_data(format!("\"{}\" must be an array of {} finite numbers", key, N))),  
+        }
+    }
+    return Ok(Some(numbers));
+}
+
+#[inline]
+fn read_f32(value: &JsonValue, key: &str, default: f32) -> io::Result<f32> {
+    match value.get(key) {
+        Some(JsonValue::Number(number)) if (*number as f32).is_finite() => Ok(*number as f32), 
+        Some(_) => Err(invalid_data(format!("\"{}\" must be a finite number", key))), 
+        None => Ok(default), 
+    }
+}
+
+#[inline]
+fn read_bool(value: &JsonValue, key: &str, default: bool) -> io::Result<bool> {
+    match value.get(key) {
+        Some(JsonValue::Bool(boolean)) => Ok(*boolean), 
+        Some(_) => Err(invalid_data(format!("\"{}\" must be a boolean", key))), 
+        None => Ok(default), 
+    }
+}
+
+#[inline]
+fn read_vec3(value: &JsonValue, key: &str, default: glam::Vec3) -> io::Result<glam::Vec3> {
+    Ok(read_numbers::<3>(value, key)?.map_or(default, glam::Vec3::from_array))
+}
+
+fn read_quat(value: &JsonValue, key: &str, default: glam::Quat) -> io::Result<glam::Quat> {
+    match read_numbers::<4>(value, key)? {
+        Some(numbers) => {
+            let rotation = glam::Quat::from_array(numbers);
+            match rotation.length_squared() > f32::EPSILON {
+                true => Ok(rotation.normalize()), 
+                false => Err(invalid_data(format!("\"{}\" must not be a zero quaternion", key))), 
+            }
+        }, 
+        None => Ok(default), 
+    }
+}
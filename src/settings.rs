@@ -0,0 +1,218 @@
+use std::io;
+use std::fs;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::target::SAMPLE_COUNTS;
+use crate::utils::{present_mode_name, PRESENT_MODES};
+
+
+
+/// #### 한국어 </br>
+/// 시작할 때 읽는 설정 파일의 경로 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The path of the settings file read at startup. </br>
+/// 
+pub const SETTINGS_PATH: &str = "settings.toml";
+
+
+
+/// #### 한국어 </br>
+/// 설정 파일을 읽습니다. 파일이 없으면 기본 설정을 파일로 쓰고 기본 설정을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the settings file. If the file does not exist, writes the default settings to the file and returns the default settings. </br>
+/// 
+pub fn load_or_create<P: AsRef<Path>>(path: P) -> io::Result<Settings> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(source) => Settings::from_toml(&source), 
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let settings = Settings::default();
+            fs::write(path, settings.to_toml())?;
+            log::info!("Wrote the default settings to {}.", path.display());
+            Ok(settings)
+        }, 
+        Err(e) => Err(e), 
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 다시 빌드하지 않고 바꿀 수 있는 렌더러 설정 입니다. 명령줄 인수가 주어지면 명령줄 인수가 우선합니다. </br>
+/// 설정 파일은 다음과 같은 TOML 이며, 빠진 항목은 기본값을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The renderer settings that can be changed without rebuilding. If a command line argument is given, the command line argument takes precedence. </br>
+/// The settings file is TOML like the following, and missing entries use the default values. </br>
+/// 
+/// ```toml
+/// [window]
+/// width = 800
+/// height = 600
+/// fullscreen = false
+/// 
+/// [renderer]
+/// present_mode = "auto-vsync"
+/// msaa = 1
+/// shadow_map_size = 1024
+/// 
+/// [camera]
+/// fov_y = 60.0
+/// ```
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    pub window_width: u32, 
+    pub window_height: u32, 
+    pub fullscreen: bool, 
+    pub present_mode: wgpu::PresentMode, 
+    pub sample_count: u32, 
+    pub shadow_map_size: u32, 
+    pub fov_y_radian: f32, 
+}
+
+impl Default for Settings {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            window_width: 800, 
+            window_height: 600, 
+            fullscreen: false, 
+            present_mode: wgpu::PresentMode::AutoVsync, 
+            sample_count: 1, 
+            shadow_map_size: 1024, 
+            fov_y_radian: 60.0f32.to_radians()
+        }
+    }
+}
+
+impl Settings {
+    /// #### 한국어 </br>
+    /// 설정을 `load_or_create`로 다시 읽을 수 있는 TOML 문자열로 변환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the settings into a TOML string that can be read again with `load_or_create`. </br>
+    /// 
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        writeln!(&mut toml, "# Command line arguments take precedence over these settings.").unwrap();
+        writeln!(&mut toml, "[window]").unwrap();
+        writeln!(&mut toml, "width = {}", self.window_width).unwrap();
+        writeln!(&mut toml, "height = {}", self.window_height).unwrap();
+        writeln!(&mut toml, "fullscreen = {}", self.fullscreen).unwrap();
+        writeln!(&mut toml).unwrap();
+        writeln!(&mut toml, "[renderer]").unwrap();
+        writeln!(&mut toml, "# One of {}.", PRESENT_MODES.map(|(name, _)| name).join(", ")).unwrap();
+        writeln!(&mut toml, "present_mode = \"{}\"", present_mode_name(self.present_mode)).unwrap();
+        writeln!(&mut toml, "# One of {:?}.", SAMPLE_COUNTS).unwrap();
+        writeln!(&mut toml, "msaa = {}", self.sample_count).unwrap();
+        writeln!(&mut toml, "shadow_map_size = {}", self.shadow_map_size).unwrap();
+        writeln!(&mut toml).unwrap();
+        writeln!(&mut toml, "[camera]").unwrap();
+        writeln!(&mut toml, "# The vertical field of view in degrees.").unwrap();
+        writeln!(&mut toml, "fov_y = {:.1}", self.fov_y_radian.to_degrees()).unwrap();
+        return toml;
+    }
+
+    /// #### 한국어 </br>
+    /// 설정 파일에 필요한 만큼만 TOML을 읽습니다. 표 머리글과 `키 = 값` 줄, 주석, 그리고 정수, 실수, 불리언, 문자열 값만 지원합니다. </br>
+    /// 알 수 없는 키는 경고를 남기고 무시하며, 값의 형식이 잘못되면 `InvalidData` 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads only as much TOML as the settings file needs. Only table headers, `key = value` lines, comments, and integer, float, boolean and string values are supported. </br>
+    /// Unknown keys are ignored with a warning, and an `InvalidData` error is returned if a value is malformed. </br>
+    /// 
+    pub fn from_toml(source: &str) -> io::Result<Self> {
+        let mut settings = Self::default();
+        let mut table = String::new();
+        for (number, line) in source.lines().enumerate().map(|(index, line)| (index + 1, line.trim())) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                table = header.strip_suffix(']')
+                    .ok_or_else(|| invalid_data(format!("unterminated table header at line {}", number)))?
+                    .trim()
+                    .to_string();
+                continue;
+            }
+
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| invalid_data(format!("expected `key = value` at line {}", number)))?;
+            let value = strip_comment(value.trim());
+            let key = format!("{}.{}", table, key.trim());
+            match key.as_str() {
+                "window.width" => settings.window_width = parse_value(value, &key, number)?, 
+                "window.height" => settings.window_height = parse_value(value, &key, number)?, 
+                "window.fullscreen" => settings.fullscreen = parse_value(value, &key, number)?, 
+                "renderer.present_mode" => {
+                    let name = parse_string(value, &key, number)?;
+                    settings.present_mode = PRESENT_MODES.iter()
+                        .find(|(mode_name, _)| *mode_name == name)
+                        .map(|&(_, present_mode)| present_mode)
+                        .ok_or_else(|| invalid_data(format!("unknown present mode \"{}\" at line {}", name, number)))?;
+                }, 
+                "renderer.msaa" => {
+                    settings.sample_count = parse_value(value, &key, number)?;
+                    if !SAMPLE_COUNTS.contains(&settings.sample_count) {
+                        return Err(invalid_data(format!("\"{}\" must be one of {:?} at line {}", key, SAMPLE_COUNTS, number)));
+                    }
+                }, 
+                "renderer.shadow_map_size" => settings.shadow_map_size = parse_value(value, &key, number)?, 
+                "camera.fov_y" => {
+                    let fov_y: f32 = parse_value(value, &key, number)?;
+                    if !fov_y.is_finite() {
+                        return Err(invalid_data(format!("\"{}\" must be a finite number at line {}", key, number)));
+                    }
+                    settings.fov_y_radian = fov_y.clamp(1.0, 179.0).to_radians();
+                },  
+                _ => log::warn!("Unknown setting \"{}\" at line {} is ignored.", key, number), 
+            }
+        }
+
+        if settings.window_width == 0 || settings.window_height == 0 || settings.shadow_map_size == 0 {
+            return Err(invalid_data("the window size and the shadow map size must be greater than 0"));
+        }
+        return Ok(settings);
+    }
+}
+
+/// #### 한국어 </br>
+/// 값 뒤의 주석을 지웁니다. 문자열 값 안의 `#`은 주석이 아닙니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Removes the comment after a value. A `#` inside a string value is not a comment. </br>
+/// 
+fn strip_comment(value: &str) -> &str {
+    let mut in_string = false;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '"' => in_string = !in_string, 
+            '#' if !in_string => return value[..index].trim_end(), 
+            _ => { /*--- empty ---*/ }, 
+        }
+    }
+    return value;
+}
+
+#[inline]
+fn parse_value<T: std::str::FromStr>(value: &str, key: &str, number: usize) -> io::Result<T> {
+    value.replace('_', "").parse()
+        .map_err(|_| invalid_data(format!("invalid value of \"{}\" at line {}", key, number)))
+}
+
+#[inline]
+fn parse_string<'a>(value: &'a str, key: &str, number: usize) -> io::Result<&'a str> {
+    value.strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| invalid_data(format!("\"{}\" must be a string at line {}", key, number)))
+}
+
+#[inline]
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
@@ -0,0 +1,288 @@
+use std::io;
+use std::fs;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+use std::collections::{HashMap, HashSet};
+
+use wgpu::naga;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더 모듈을 만들 때 사용하는 쉐이더 소스의 형식 입니다. </br>
+/// `wgsl` 기능을 켜면 미리 컴파일된 SPIR-V 대신 `shaders/` 폴더의 WGSL 소스를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The format of the shader sources used to create shader modules. </br>
+/// With the `wgsl` feature, the WGSL sources of the `shaders/` directory are used instead of the precompiled SPIR-V. </br>
+/// 
+#[cfg(not(feature = "wgsl"))]
+pub const SHADER_SOURCE_FORMAT: &str = "SPIR-V";
+#[cfg(feature = "wgsl")]
+pub const SHADER_SOURCE_FORMAT: &str = "WGSL";
+
+/// #### 한국어 </br>
+/// `shaders/` 폴더에서 주어진 이름의 쉐이더를 빌드 시에 포함하는 `wgpu::ShaderModuleDescriptor`를 만듭니다. </br>
+/// 기본으로는 `<name>.spv`를 `include_spirv!`로, `wgsl` 기능이 켜져 있으면 `<name>.wgsl`을 `include_wgsl!`로 포함합니다. </br>
+/// `ShaderWatcher`가 쉐이더를 다시 불러왔다면 대신 다시 불러온 소스를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a `wgpu::ShaderModuleDescriptor` that includes the shader of the given name from the `shaders/` directory at build time. </br>
+/// By default `<name>.spv` is included with `include_spirv!`, and `<name>.wgsl` with `include_wgsl!` if the `wgsl` feature is on. </br>
+/// If the shader was reloaded by a `ShaderWatcher`, the reloaded source is used instead. </br>
+/// 
+#[cfg(not(feature = "wgsl"))]
+macro_rules! include_shader {
+    ($name:literal) => {
+        $crate::shader::reloadable(wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/", $name, ".spv")))
+    };
+}
+
+#[cfg(feature = "wgsl")]
+macro_rules! include_shader {
+    ($name:literal) => {
+        $crate::shader::reloadable(wgpu::include_wgsl!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/", $name, ".wgsl")))
+    };
+}
+
+pub(crate) use include_shader;
+
+/// #### 한국어 </br>
+/// `wgsl` 기능과 상관없이 `shaders/` 폴더에서 `<name>.wgsl`을 `include_wgsl!`로 포함합니다. </br>
+/// `naga`의 SPIR-V 프론트엔드가 지원하지 않는 명령어(예: `OpAtomicStore`, `OpAtomicIAdd`)를 사용하는 쉐이더에 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Includes `<name>.wgsl` from the `shaders/` directory with `include_wgsl!` regardless of the `wgsl` feature. </br>
+/// It is used for the shaders using instructions the SPIR-V frontend of `naga` does not support. (e.g. `OpAtomicStore`, `OpAtomicIAdd`) </br>
+/// 
+macro_rules! include_wgsl_shader {
+    ($name:literal) => {
+        $crate::shader::reloadable(wgpu::include_wgsl!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/", $name, ".wgsl")))
+    };
+}
+
+pub(crate) use include_wgsl_shader;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더 폴더를 감시하여 쉐이더가 바뀌면 다시 불러오는 명령줄 인수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that watches the shader directory and reloads shaders when they change. </br>
+/// 
+pub const HOT_RELOAD_ARG: &str = "--hot-reload";
+
+/// #### 한국어 </br>
+/// 쉐이더 파일의 수정 시각을 확인하는 간격 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The interval at which the modification times of the shader files are checked. </br>
+/// 
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+static RELOADED_SHADERS: Mutex<Vec<(String, ReloadedShader)>> = Mutex::new(Vec::new());
+
+/// #### 한국어 </br>
+/// 실행 중에 다시 불러온 쉐이더의 소스 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The source of a shader reloaded at runtime. </br>
+/// 
+#[derive(Debug, Clone)]
+enum ReloadedShader {
+    Glsl { source: String, stage: naga::ShaderStage }, 
+    Wgsl(String), 
+}
+
+/// #### 한국어 </br>
+/// 다시 불러온 쉐이더가 있으면 그 소스로, 없으면 빌드 시에 포함된 소스로 쉐이더 모듈 서술자를 반환합니다. </br>
+/// 쉐이더는 서술자의 이름표에 있는 파일 이름으로 찾습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the shader module descriptor with the reloaded source if there is one, otherwise with the source included at build time. </br>
+/// The shader is looked up by the file name in the label of the descriptor. </br>
+/// 
+pub fn reloadable(descriptor: wgpu::ShaderModuleDescriptor<'static>) -> wgpu::ShaderModuleDescriptor<'static> {
+    let Some(name) = descriptor.label.and_then(|label| Path::new(label).file_stem()).and_then(|stem| stem.to_str()) else {
+        return descriptor;
+    };
+
+    let reloaded = RELOADED_SHADERS.lock().unwrap();
+    match reloaded.iter().find(|(reloaded_name, _)| reloaded_name == name) {
+        Some((_, ReloadedShader::Glsl { source, stage })) => wgpu::ShaderModuleDescriptor {
+            label: descriptor.label, 
+            source: wgpu::ShaderSource::Glsl {
+                shader: Cow::Owned(source.clone()), 
+                stage: *stage, 
+                defines: Default::default(), 
+            }, 
+        }, 
+        Some((_, ReloadedShader::Wgsl(source))) => wgpu::ShaderModuleDescriptor {
+            label: descriptor.label, 
+            source: wgpu::ShaderSource::Wgsl(Cow::Owned(source.clone())), 
+        }, 
+        None => descriptor, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더 폴더의 GLSL과 WGSL 소스의 수정 시각을 주기적으로 확인하여, 바뀐 쉐이더를 다시 불러오는 감시자 입니다. </br>
+/// 다시 불러온 쉐이더는 이후 `include_shader!`로 만드는 쉐이더 모듈에 사용되므로, 
+/// 바뀐 쉐이더를 사용하는 파이프라인을 다시 만들면 반영됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A watcher that periodically checks the modification times of the GLSL and WGSL sources in the shader directory and reloads the changed shaders. </br>
+/// The reloaded shaders are used by the shader modules created with `include_shader!` afterwards, 
+/// so they take effect once the pipelines using the changed shaders are recreated. </br>
+/// 
+#[derive(Debug)]
+pub struct ShaderWatcher {
+    directory: PathBuf, 
+    modified_times: HashMap<PathBuf, SystemTime>, 
+    last_poll: Instant, 
+    replaced: Vec<(String, Option<ReloadedShader>)>, 
+}
+
+#[allow(dead_code)]
+impl ShaderWatcher {
+    /// #### 한국어 </br>
+    /// 주어진 폴더를 감시하는 감시자를 생성합니다. 현재 파일들은 바뀌지 않은 것으로 봅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a watcher watching the given directory. The current files are regarded as unchanged. </br>
+    /// 
+    pub fn new<P: AsRef<Path>>(directory: P) -> io::Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        let modified_times = scan_sources(&directory)?;
+        Ok(Self { 
+            directory, 
+            modified_times, 
+            last_poll: Instant::now(), 
+            replaced: Vec::new() 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 이 크레이트의 `shaders/` 폴더를 감시하는 감시자를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a watcher watching the `shaders/` directory of this crate. </br>
+    /// 
+    pub fn for_crate_shaders() -> io::Result<Self> {
+        Self::new(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders"))
+    }
+
+    #[inline]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 확인 후 `POLL_INTERVAL`이 지났으면 바뀐 쉐이더 소스들을 다시 불러오고, 그 쉐이더들의 이름을 반환합니다. </br>
+    /// GLSL 소스의 단계는 내용으로 추정합니다. (`local_size`가 있으면 컴퓨트, `gl_Position`이 있으면 정점, 그 외에는 프래그먼트) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If `POLL_INTERVAL` has passed since the last check, reloads the changed shader sources and returns the names of those shaders. </br>
+    /// The stage of a GLSL source is guessed from its contents. (compute if it has `local_size`, vertex if it has `gl_Position`, fragment otherwise) </br>
+    /// 
+    pub fn poll(&mut self) -> HashSet<String> {
+        let mut changed = HashSet::new();
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return changed;
+        }
+        self.last_poll = Instant::now();
+        self.replaced.clear();
+
+        let modified_times = match scan_sources(&self.directory) {
+            Ok(modified_times) => modified_times, 
+            Err(e) => {
+                log::warn!("Failed to scan the shader directory. ({})", e);
+                return changed;
+            }
+        };
+
+        for (path, modified_time) in modified_times.iter() {
+            if self.modified_times.get(path) == Some(modified_time) {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let source = match fs::read_to_string(path) {
+                Ok(source) => source, 
+                Err(e) => {
+                    log::warn!("Failed to read the shader. ({}: {})", path.display(), e);
+                    continue;
+                }
+            };
+
+            let reloaded = match path.extension().and_then(|extension| extension.to_str()) {
+                Some("wgsl") => ReloadedShader::Wgsl(source), 
+                _ => {
+                    let stage = if source.contains("local_size") {
+                        naga::ShaderStage::Compute
+                    } else if source.contains("gl_Position") {
+                        naga::ShaderStage::Vertex
+                    } else {
+                        naga::ShaderStage::Fragment
+                    };
+                    ReloadedShader::Glsl { source, stage }
+                }
+            };
+
+            let mut reloaded_shaders = RELOADED_SHADERS.lock().unwrap();
+            let previous = reloaded_shaders.iter()
+                .position(|(reloaded_name, _)| reloaded_name == name)
+                .map(|index| reloaded_shaders.remove(index).1);
+            self.replaced.push((name.to_string(), previous));
+            reloaded_shaders.push((name.to_string(), reloaded));
+            log::info!("Reloaded the shader. ({})", path.display());
+            changed.insert(name.to_string());
+        }
+
+        self.modified_times = modified_times;
+        return changed;
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 `poll`로 다시 불러온 쉐이더들을 그 전의 소스로 되돌립니다. 새 소스로 만든 파이프라인이 검증에 실패했을 때 호출합니다. </br>
+    /// 되돌리지 않으면 이후 오류 범위 밖에서 파이프라인을 다시 만들 때 잘못된 소스가 컴파일되어 기본 오류 처리기가 패닉을 일으킵니다. </br>
+    /// 파일의 수정 시각은 그대로 기록되므로, 파일을 다시 고쳐 저장하면 다시 불러옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reverts the shaders reloaded by the last `poll` to their previous sources. It is called when the pipelines created with the new sources fail validation. </br>
+    /// Without reverting, recreating a pipeline outside an error scope later would compile the broken source and the default error handler would panic. </br>
+    /// The modification times of the files stay recorded, so the file is reloaded again once it is fixed and saved. </br>
+    /// 
+    pub fn rollback(&mut self) {
+        let mut reloaded_shaders = RELOADED_SHADERS.lock().unwrap();
+        for (name, previous) in self.replaced.drain(..) {
+            reloaded_shaders.retain(|(reloaded_name, _)| *reloaded_name != name);
+            if let Some(previous) = previous {
+                reloaded_shaders.push((name, previous));
+            }
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 폴더 안의 GLSL과 WGSL 소스 파일들의 수정 시각을 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the modification times of the GLSL and WGSL source files in the directory. </br>
+/// 
+fn scan_sources(directory: &Path) -> io::Result<HashMap<PathBuf, SystemTime>> {
+    let mut modified_times = HashMap::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let is_source = matches!(path.extension().and_then(|extension| extension.to_str()), Some("glsl" | "wgsl"));
+        if is_source {
+            modified_times.insert(path.clone(), fs::metadata(&path)?.modified()?);
+        }
+    }
+    return Ok(modified_times);
+}
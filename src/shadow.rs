@@ -0,0 +1,316 @@
+use std::io;
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 그림자 맵의 필터링 커널을 선택하는 명령줄 인수 입니다. 뒤에 `PCF_KERNELS`의 이름이 옵니다. (예: `--shadow-filter poisson`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that selects the filtering kernel of the shadow maps. It is followed by a name of `PCF_KERNELS`. (e.g. `--shadow-filter poisson`) </br>
+/// 
+pub const SHADOW_FILTER_ARG: &str = "--shadow-filter";
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 필터링 커널과 명령줄에서 쓰는 이름 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable filtering kernels and the names used on the command line. </br>
+/// 
+pub const PCF_KERNELS: [(&str, PcfKernel); 4] = [
+    ("hardware", PcfKernel::Hardware), 
+    ("3x3", PcfKernel::Grid3x3), 
+    ("5x5", PcfKernel::Grid5x5), 
+    ("poisson", PcfKernel::Poisson), 
+];
+
+/// #### 한국어 </br>
+/// 그림자 맵 파이프라인의 기본 깊이 편향 입니다. </br>
+/// 그림자 맵에는 뒷면이 그려지므로 깊이를 조명 쪽으로 당겨 얇은 물체의 빛샘을 줄입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default depth bias of the shadow map pipelines. </br>
+/// Back faces are drawn into the shadow map, so the depth is pulled towards the light to reduce the light leaking of thin objects. </br>
+/// 
+pub const DEFAULT_DEPTH_BIAS: wgpu::DepthBiasState = wgpu::DepthBiasState {
+    constant: -2, 
+    slope_scale: -2.0, 
+    clamp: 0.0, 
+};
+
+
+
+/// #### 한국어 </br>
+/// 그림자 맵을 비교하는 퍼센티지 클로저 필터링(PCF) 커널 입니다. </br>
+/// 모든 탭은 비교 샘플러의 쌍선형 필터링을 사용하므로 한 탭도 2x2 텍셀을 비교합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The percentage-closer filtering (PCF) kernel comparing the shadow map. </br>
+/// Every tap uses the bilinear filtering of the comparison sampler, so even a single tap compares 2x2 texels. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PcfKernel {
+    /// #### 한국어 </br>
+    /// 비교 샘플러로 한 번만 비교합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Compares only once with the comparison sampler. </br>
+    /// 
+    Hardware, 
+    /// #### 한국어 </br>
+    /// 3x3 격자의 9개 탭을 평균합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Averages the 9 taps of a 3x3 grid. </br>
+    /// 
+    #[default]
+    Grid3x3, 
+    /// #### 한국어 </br>
+    /// 5x5 격자의 25개 탭을 평균합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Averages the 25 taps of a 5x5 grid. </br>
+    /// 
+    Grid5x5, 
+    /// #### 한국어 </br>
+    /// 원판 위에 고르게 흩어진 16개의 푸아송 탭을 평균합니다. 격자보다 적은 탭으로 더 부드러운 경계를 만듭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Averages the 16 Poisson taps evenly scattered over a disk. It makes softer edges with fewer taps than the grids. </br>
+    /// 
+    Poisson, 
+}
+
+impl PcfKernel {
+    #[inline]
+    pub fn name(self) -> &'static str {
+        PCF_KERNELS.iter().find(|&&(_, kernel)| kernel == self).map(|&(name, _)| name).unwrap_or("3x3")
+    }
+
+    /// #### 한국어 </br>
+    /// `PCF_KERNELS`의 순서대로 다음 커널을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the next kernel in the order of `PCF_KERNELS`. </br>
+    /// 
+    #[inline]
+    pub fn next(self) -> Self {
+        let index = PCF_KERNELS.iter().position(|&(_, kernel)| kernel == self).unwrap_or(0);
+        PCF_KERNELS[(index + 1) % PCF_KERNELS.len()].1
+    }
+
+    #[inline]
+    fn as_index(self) -> f32 {
+        match self {
+            Self::Hardware => 0.0, 
+            Self::Grid3x3 => 1.0, 
+            Self::Grid5x5 => 2.0, 
+            Self::Poisson => 3.0, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// `SHADOW_FILTER_ARG` 명령줄 인수의 값을 필터링 커널로 해석합니다. 알 수 없는 값이면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `SHADOW_FILTER_ARG` command line argument as a filtering kernel. Returns an `InvalidInput` error if the value is unknown. </br>
+/// 
+pub fn parse_pcf_kernel(value: &str) -> io::Result<PcfKernel> {
+    match PCF_KERNELS.iter().find(|(name, _)| *name == value) {
+        Some(&(_, kernel)) => Ok(kernel), 
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!(
+                "{} must be followed by one of {}", 
+                SHADOW_FILTER_ARG, 
+                PCF_KERNELS.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+            )
+        )), 
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 조명들이 공유하는 그림자 필터를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates the shadow filter shared by the lights. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowFilterBuilder {
+    pub kernel: PcfKernel, 
+    pub filter_radius: f32, 
+    pub depth_bias: wgpu::DepthBiasState, 
+}
+
+impl Default for ShadowFilterBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            kernel: PcfKernel::default(), 
+            filter_radius: 1.5, 
+            depth_bias: DEFAULT_DEPTH_BIAS
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ShadowFilterBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_kernel(mut self, kernel: PcfKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 커널이 퍼지는 반지름을 그림자 맵의 텍셀 단위로 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the radius the kernel spreads over in texels of the shadow map. </br>
+    /// 
+    #[inline]
+    pub fn set_filter_radius(mut self, filter_radius: f32) -> Self {
+        self.filter_radius = filter_radius;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자 맵을 그리는 파이프라인의 깊이 편향을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the depth bias of the pipelines drawing the shadow map. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_bias(mut self, depth_bias: wgpu::DepthBiasState) -> Self {
+        self.depth_bias = depth_bias;
+        self
+    }
+
+    pub fn build(self, device: &wgpu::Device, queue: &wgpu::Queue) -> ShadowFilter {
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(ShadowFilter)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ShadowFilterUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let shadow_filter = ShadowFilter {
+            kernel: self.kernel, 
+            filter_radius: self.filter_radius.max(0.0), 
+            depth_bias: self.depth_bias, 
+            uniform_buffer, 
+        };
+        shadow_filter.update_resource(queue);
+
+        return shadow_filter;
+    }
+}
+
+/// #### 한국어 </br>
+/// 그림자 맵을 비교하는 방법과 그림자 맵을 그릴 때의 깊이 편향을 담는 그림자 필터 입니다. </br>
+/// 커널과 반지름은 조명들의 그림자 맵 바인드 그룹에 묶인 유니폼 버퍼로 쉐이더에 전달되고, 
+/// 깊이 편향은 그림자 맵 파이프라인의 `DepthBiasState`로 쓰입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The shadow filter holding the way the shadow maps are compared and the depth bias used when drawing the shadow maps. </br>
+/// The kernel and the radius are passed to the shaders with a uniform buffer bound to the shadow map bind groups of the lights, 
+/// and the depth bias is used as the `DepthBiasState` of the shadow map pipelines. </br>
+/// 
+#[derive(Debug)]
+pub struct ShadowFilter {
+    kernel: PcfKernel, 
+    filter_radius: f32, 
+    depth_bias: wgpu::DepthBiasState, 
+    uniform_buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl ShadowFilter {
+    #[inline]
+    pub fn kernel(&self) -> PcfKernel {
+        self.kernel
+    }
+
+    #[inline]
+    pub fn set_kernel(&mut self, kernel: PcfKernel) {
+        self.kernel = kernel;
+    }
+
+    #[inline]
+    pub fn filter_radius(&self) -> f32 {
+        self.filter_radius
+    }
+
+    #[inline]
+    pub fn set_filter_radius(&mut self, filter_radius: f32) {
+        self.filter_radius = filter_radius.max(0.0);
+    }
+
+    #[inline]
+    pub fn depth_bias(&self) -> wgpu::DepthBiasState {
+        self.depth_bias
+    }
+
+    /// #### 한국어 </br>
+    /// 깊이 편향을 설정합니다. 파이프라인 상태이므로 그림자 맵 파이프라인을 다시 생성해야 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the depth bias. It is a pipeline state, so the shadow map pipelines must be recreated for it to take effect. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_bias(&mut self, depth_bias: wgpu::DepthBiasState) {
+        self.depth_bias = depth_bias;
+    }
+
+    #[inline]
+    pub fn buffer_ref(&self) -> &wgpu::Buffer {
+        &self.uniform_buffer
+    }
+}
+
+impl ShaderResource for ShadowFilter {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = ShadowFilterUniformLayout {
+            params: (self.kernel.as_index(), self.filter_radius, 0.0, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 그림자 필터 유니폼 데이터의 레이아웃 입니다. </br>
+/// `params`는 (커널 번호, 텍셀 단위 반지름, 0, 0) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the shadow filter uniform data used in the shader. </br>
+/// `params` is (kernel index, radius in texels, 0, 0). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowFilterUniformLayout {
+    pub params: glam::Vec4, 
+}
+
+impl Default for ShadowFilterUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            params: (1.0, 1.5, 0.0, 0.0).into()
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::light::LightObject;
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 조명의 그림자 맵을 화면 구석에 보여주는 디버그 화면을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a debug view showing the shadow map of a light in a corner of the screen. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowViewBuilder {
+    pub size: f32, 
+    pub margin: f32, 
+    pub display_range: f32, 
+}
+
+impl Default for ShadowViewBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            size: 256.0, 
+            margin: 16.0, 
+            display_range: 30.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ShadowViewBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 화면에 보여줄 정사각형의 한 변의 길이를 픽셀 단위로 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the side length of the square shown on the screen in pixels. </br>
+    /// 
+    #[inline]
+    pub fn set_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    #[inline]
+    pub fn set_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 흰색으로 보여줄 조명으로부터의 거리를 설정합니다. 더 가까운 깊이는 어둡게 보입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distance from the light shown in white. Closer depths look darker. </br>
+    /// 
+    #[inline]
+    pub fn set_display_range(mut self, display_range: f32) -> Self {
+        self.display_range = display_range;
+        self
+    }
+
+    pub fn build<L: LightObject>(
+        self, 
+        light: &L, 
+        screen_width: u32, 
+        screen_height: u32, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> ShadowView {
+        assert!(self.size > 0.0 && self.display_range > 0.0);
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(ShadowView)"), 
+                mag_filter: wgpu::FilterMode::Nearest, 
+                min_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(ShadowView)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ShadowViewUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(ShadowView)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::TextureView(light.texture_view_ref()), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 원근 투영 행렬의 깊이 성분으로 깊이 버퍼의 값을 조명으로부터의 거리로 되돌립니다.
+        // (English Translation) Converts the values of the depth buffer back to the distance from the light with the depth components of the perspective projection matrix.
+        let projection = light.get_projection_matrix();
+        let mut shadow_view = ShadowView {
+            size: self.size, 
+            margin: self.margin, 
+            display_range: self.display_range, 
+            depth_params: (projection.z_axis.z, projection.w_axis.z).into(), 
+            screen_size: glam::Vec2::ONE, 
+            rect: glam::Vec4::ZERO, 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+        };
+        shadow_view.resize(screen_width, screen_height, queue);
+
+        return shadow_view;
+    }
+}
+
+/// #### 한국어 </br>
+/// 조명의 `Depth32Float` 그림자 맵을 화면 구석의 정사각형에 그리는 디버그 화면 입니다. </br>
+/// 깊이는 조명으로부터의 거리로 선형화되어 회색조로 보이며, 아무것도 그려지지 않은 부분은 붉게 보이므로
+/// 그림자 맵이 장면을 얼마나 덮는지와 깊이 편향 문제를 확인할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A debug view that draws the `Depth32Float` shadow map of a light into a square in a corner of the screen. </br>
+/// The depth is linearized into the distance from the light and shown in grayscale, and the parts where nothing was drawn look red, 
+/// so the coverage of the scene by the shadow map and the depth bias issues can be checked. </br>
+/// 
+#[derive(Debug)]
+pub struct ShadowView {
+    size: f32, 
+    margin: f32, 
+    display_range: f32, 
+    depth_params: glam::Vec2, 
+    screen_size: glam::Vec2, 
+    rect: glam::Vec4, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl ShadowView {
+    /// #### 한국어 </br>
+    /// 화면 크기가 바뀌었을 때 정사각형을 화면의 오른쪽 아래 구석으로 옮깁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Moves the square to the bottom-right corner of the screen when the screen size changes. </br>
+    /// 
+    pub fn resize(&mut self, width: u32, height: u32, queue: &wgpu::Queue) {
+        self.screen_size = glam::vec2(width.max(1) as f32, height.max(1) as f32);
+        let size = self.size
+            .min(self.screen_size.x - 2.0 * self.margin)
+            .min(self.screen_size.y - 2.0 * self.margin)
+            .max(0.0);
+        self.rect = (self.screen_size.x - self.margin - size, self.screen_size.y - self.margin - size, size, size).into();
+        self.update_resource(queue);
+    }
+
+    #[inline]
+    pub fn display_range(&self) -> f32 {
+        self.display_range
+    }
+
+    /// #### 한국어 </br>
+    /// 흰색으로 보여줄 조명으로부터의 거리를 설정합니다. 변경 사항은 `update_resource`를 호출해야 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distance from the light shown in white. The change is reflected in the shader after calling `update_resource`. </br>
+    /// 
+    #[inline]
+    pub fn set_display_range(&mut self, display_range: f32) {
+        self.display_range = display_range.max(1e-3);
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자 맵을 그립니다. 그림자 화면 파이프라인이 설정되어 있어야 하며, 그린 후 뷰포트를 화면 전체로 되돌립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the shadow map. The shadow view pipeline must be set, and the viewport is restored to the whole screen after drawing. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if self.rect.z <= 0.0 || self.rect.w <= 0.0 {
+            return;
+        }
+
+        rpass.set_viewport(self.rect.x, self.rect.y, self.rect.z, self.rect.w, 0.0, 1.0);
+        stats::set_bind_group(rpass, 0, &self.uniform_bind_group, &[]);
+        stats::draw(rpass, 0..3, 0..1);
+        rpass.set_viewport(0.0, 0.0, self.screen_size.x, self.screen_size.y, 0.0, 1.0);
+    }
+}
+
+impl ShaderResource for ShadowView {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = ShadowViewUniformLayout {
+            rect: self.rect, 
+            params: (self.depth_params.x, self.depth_params.y, self.display_range, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 그림자 화면 유니폼 데이터의 레이아웃 입니다. </br>
+/// `rect`는 화면에서의 (x, y, 너비, 높이) 이고, `params`는 (투영 행렬의 z 배율, 투영 행렬의 z 이동, 보여줄 거리, 0) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the shadow view uniform data used in the shader. </br>
+/// `rect` is (x, y, width, height) on the screen, and `params` is (z scale of the projection matrix, z translation of the projection matrix, display range, 0). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowViewUniformLayout {
+    pub rect: glam::Vec4, 
+    pub params: glam::Vec4, 
+}
+
+impl Default for ShadowViewUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            rect: (0.0, 0.0, 1.0, 1.0).into(), 
+            params: (-1.0, -0.001, 30.0, 0.0).into()
+        }
+    }
+}
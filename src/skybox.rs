@@ -0,0 +1,651 @@
+use std::mem;
+use std::ops::Range;
+use std::path::Path;
+use bytemuck::{Pod, Zeroable};
+
+use crate::bounds::{Aabb, Sphere};
+use crate::mesh::{IndexBuffer, IndexData, ModelMesh, SubMesh};
+use crate::resource::ShaderResource;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 큐브맵 면 이미지들의 파일 이름 입니다. (+X, -X, +Y, -Y, +Z, -Z 순서) </br>
+/// 
+/// #### English (Translation) </br>
+/// File names of the cubemap face images. (+X, -X, +Y, -Y, +Z, -Z order) </br>
+/// 
+pub const CUBEMAP_FACE_NAMES: [&str; 6] = ["px.png", "nx.png", "py.png", "ny.png", "pz.png", "nz.png"];
+
+/// #### 한국어 </br>
+/// 디렉토리에서 여섯 개의 면 이미지를 읽어 RGBA8 픽셀 데이터로 반환합니다. </br>
+/// 모든 면은 같은 크기의 정사각형이어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads six face images from a directory and returns them as RGBA8 pixel data. </br>
+/// All faces must be squares of the same size. </br>
+/// 
+pub fn load_cubemap_faces<P: AsRef<Path>>(dir: P) -> image::ImageResult<(u32, Vec<u8>)> {
+    let mut size = None;
+    let mut pixels = Vec::new();
+    for name in CUBEMAP_FACE_NAMES.iter() {
+        let face = image::open(dir.as_ref().join(name))?.to_rgba8();
+        let (width, height) = face.dimensions();
+        if width != height || size.is_some_and(|size| size != width) {
+            return Err(image::ImageError::Parameter(image::error::ParameterError::from_kind(
+                image::error::ParameterErrorKind::DimensionMismatch
+            )));
+        }
+        size = Some(width);
+        pixels.extend_from_slice(face.as_raw());
+    }
+
+    Ok((size.unwrap_or(0), pixels))
+}
+
+/// #### 한국어 </br>
+/// 등장방형(equirectangular) 파노라마 이미지를 읽어 `face_size` 크기의 여섯 면 RGBA8 픽셀 데이터로 변환합니다. </br>
+/// HDR 이미지는 라인하르트(Reinhard) 톤 매핑과 감마 보정으로 면 이미지와 같은 8비트 색으로 바꿉니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads an equirectangular panorama image and converts it to the RGBA8 pixel data of six faces of size `face_size`. </br>
+/// HDR images are converted to the same 8-bit colors as the face images with Reinhard tone mapping and gamma correction. </br>
+/// 
+pub fn load_equirect_faces<P: AsRef<Path>>(path: P, face_size: u32) -> image::ImageResult<(u32, Vec<u8>)> {
+    assert!(face_size > 0);
+
+    let image = image::open(path)?;
+    let is_hdr = matches!(image.color(), image::ColorType::Rgb32F | image::ColorType::Rgba32F);
+    let image = image.to_rgba32f();
+    let (width, height) = image.dimensions();
+    let texel = |x: i64, y: i64| -> glam::Vec4 {
+        let x = x.rem_euclid(width as i64) as u32;
+        let y = y.clamp(0, height as i64 - 1) as u32;
+        glam::Vec4::from_array(image.get_pixel(x, y).0)
+    };
+
+    let mut pixels = Vec::with_capacity((4 * face_size * face_size * 6) as usize);
+    for face in 0..6 {
+        for y in 0..face_size {
+            for x in 0..face_size {
+                // (한국어) 면의 픽셀을 큐브맵 방향으로 바꾼 뒤, 경도와 위도로 파노라마의 텍스처 좌표를 구합니다.
+                // (English Translation) Converts the pixel of the face to a cubemap direction, then gets the texture coordinates of the panorama from the longitude and latitude.
+                let s = 2.0 * (x as f32 + 0.5) / face_size as f32 - 1.0;
+                let t = 2.0 * (y as f32 + 0.5) / face_size as f32 - 1.0;
+                let direction = match face {
+                    0 => glam::vec3(1.0, -t, -s), 
+                    1 => glam::vec3(-1.0, -t, s), 
+                    2 => glam::vec3(s, 1.0, t), 
+                    3 => glam::vec3(s, -1.0, -t), 
+                    4 => glam::vec3(s, -t, 1.0), 
+                    _ => glam::vec3(-s, -t, -1.0), 
+                }.normalize();
+                let u = 0.5 + direction.z.atan2(direction.x) / std::f32::consts::TAU;
+                let v = direction.y.clamp(-1.0, 1.0).acos() / std::f32::consts::PI;
+
+                let fx = u * width as f32 - 0.5;
+                let fy = v * height as f32 - 0.5;
+                let (x0, y0) = (fx.floor() as i64, fy.floor() as i64);
+                let (wx, wy) = (fx - fx.floor(), fy - fy.floor());
+                let top = texel(x0, y0).lerp(texel(x0 + 1, y0), wx);
+                let bottom = texel(x0, y0 + 1).lerp(texel(x0 + 1, y0 + 1), wx);
+                let color = top.lerp(bottom, wy);
+
+                let rgb = if is_hdr {
+                    let rgb = color.truncate().max(glam::Vec3::ZERO);
+                    (rgb / (rgb + 1.0)).powf(1.0 / 2.2)
+                } else {
+                    color.truncate()
+                };
+                let rgb = rgb.clamp(glam::Vec3::ZERO, glam::Vec3::ONE) * 255.0;
+                pixels.extend_from_slice(&[rgb.x as u8, rgb.y as u8, rgb.z as u8, 255]);
+            }
+        }
+    }
+
+    Ok((face_size, pixels))
+}
+
+/// #### 한국어 </br>
+/// 스카이박스에 사용하는 큐브맵 텍스처 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the cubemap texture used by the skybox. </br>
+/// 
+#[derive(Debug)]
+pub struct Cubemap {
+    pub bind_group: wgpu::BindGroup, 
+}
+
+impl Cubemap {
+    /// #### 한국어 </br>
+    /// 여섯 면의 RGBA8 픽셀 데이터로 큐브맵을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a cubemap from the RGBA8 pixel data of six faces. </br>
+    /// 
+    pub fn from_rgba8(
+        size: u32, 
+        pixels: &[u8], 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(size > 0);
+        assert_eq!(pixels.len(), (4 * size * size * 6) as usize);
+
+        let texture = stats::track_texture(device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(Cubemap)"), 
+                size: wgpu::Extent3d {
+                    width: size, 
+                    height: size, 
+                    depth_or_array_layers: 6, 
+                }, 
+                dimension: wgpu::TextureDimension::D2, 
+                format: wgpu::TextureFormat::Rgba8Unorm, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        ));
+        queue.write_texture(
+            texture.as_image_copy(), 
+            pixels, 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(4 * size), 
+                rows_per_image: Some(size), 
+            }, 
+            texture.size(), 
+        );
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::Cube), 
+            ..Default::default()
+        });
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Cubemap)"), 
+                address_mode_u: wgpu::AddressMode::ClampToEdge, 
+                address_mode_v: wgpu::AddressMode::ClampToEdge, 
+                address_mode_w: wgpu::AddressMode::ClampToEdge, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                mipmap_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Cubemap)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::TextureView(&texture_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            bind_group
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 스카이박스를 그릴 때 사용하는 단위 큐브 메쉬 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A unit cube mesh used to draw the skybox. </br>
+/// 
+#[derive(Debug)]
+pub struct SkyboxMesh {
+    aabb: Aabb, 
+    bounding_sphere: Sphere, 
+    submeshes: [SubMesh; 1], 
+    index_buffer: IndexBuffer, 
+    vertex_buffer: wgpu::Buffer, 
+}
+
+impl SkyboxMesh {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let vertices: [SkyboxVertexLayout; 8] = [
+            SkyboxVertexLayout { position: (-1.0, -1.0,  1.0).into() }, 
+            SkyboxVertexLayout { position: ( 1.0, -1.0,  1.0).into() }, 
+            SkyboxVertexLayout { position: ( 1.0,  1.0,  1.0).into() }, 
+            SkyboxVertexLayout { position: (-1.0,  1.0,  1.0).into() }, 
+            SkyboxVertexLayout { position: (-1.0, -1.0, -1.0).into() }, 
+            SkyboxVertexLayout { position: ( 1.0, -1.0, -1.0).into() }, 
+            SkyboxVertexLayout { position: ( 1.0,  1.0, -1.0).into() }, 
+            SkyboxVertexLayout { position: (-1.0,  1.0, -1.0).into() }, 
+        ];
+
+        let positions: Vec<_> = vertices.iter().map(|vertex| vertex.position).collect();
+        let vertex_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Vertex(Skybox)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of_val(&vertices) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+
+        let indices = IndexData::U16(vec![
+            0, 1, 2, 2, 3, 0, 
+            5, 4, 7, 7, 6, 5, 
+            1, 5, 6, 6, 2, 1, 
+            4, 0, 3, 3, 7, 4, 
+            3, 2, 6, 6, 7, 3, 
+            4, 5, 1, 1, 0, 4, 
+        ]);
+        let index_buffer = IndexBuffer::new("Skybox", &indices, device, queue);
+
+        Self {
+            aabb: Aabb::from_points(&positions), 
+            bounding_sphere: Sphere::from_points(&positions), 
+            submeshes: [SubMesh::new(0..indices.len() as u32)], 
+            index_buffer, 
+            vertex_buffer
+        }
+    }
+}
+
+impl ModelMesh for SkyboxMesh {
+    #[inline]
+    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        self.index_buffer.bind(rpass);
+    }
+
+    #[inline]
+    fn submeshes(&self) -> &[SubMesh] {
+        &self.submeshes
+    }
+
+    #[inline]
+    fn draw_submesh_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, submesh: &SubMesh, instances: Range<u32>) {
+        stats::draw_indexed(rpass, submesh.range.clone(), submesh.base_vertex, instances);
+    }
+
+    #[inline]
+    fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    #[inline]
+    fn bounding_sphere(&self) -> Sphere {
+        self.bounding_sphere
+    }
+}
+
+/// #### 한국어 </br>
+/// 큐브맵 로더, 스카이박스 메쉬, 큐브맵 바인드 그룹 레이아웃을 묶은 스카이박스 서브시스템 입니다. </br>
+/// 스카이박스가 설정되지 않은 경우 아무것도 그리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A skybox subsystem that ties together the cubemap loader, skybox mesh, and cubemap bind group layout. </br>
+/// Nothing is drawn if the skybox is not set. </br>
+/// 
+#[derive(Debug)]
+pub struct Skybox {
+    mesh: SkyboxMesh, 
+    cubemap: Option<Cubemap>, 
+    bind_group_layout: wgpu::BindGroupLayout, 
+}
+
+impl Skybox {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Skybox)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::Cube, 
+                            multisampled: false
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering, 
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            mesh: SkyboxMesh::new(device, queue), 
+            cubemap: None, 
+            bind_group_layout
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 스카이박스 파이프라인을 생성할 때 사용하는 큐브맵 바인드 그룹 레이아웃 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The cubemap bind group layout used to create the skybox pipeline. </br>
+    /// 
+    #[inline]
+    pub fn bind_group_layout_ref(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    /// #### 한국어 </br>
+    /// 디렉토리의 면 이미지들(`CUBEMAP_FACE_NAMES`)로 스카이박스를 설정합니다. </br>
+    /// 읽기에 실패하면 기존 스카이박스를 유지하고 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the skybox with the face images (`CUBEMAP_FACE_NAMES`) in the directory. </br>
+    /// If reading fails, the existing skybox is kept and an error is returned. </br>
+    /// 
+    pub fn set_skybox<P: AsRef<Path>>(
+        &mut self, 
+        path: P, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> image::ImageResult<()> {
+        let (size, pixels) = load_cubemap_faces(path.as_ref())?;
+        self.cubemap = Some(Cubemap::from_rgba8(size, &pixels, &self.bind_group_layout, device, queue));
+        log::info!("Skybox loaded. (path:{}, size:{})", path.as_ref().display(), size);
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 등장방형 파노라마 이미지(예: `.hdr`)를 `face_size` 크기의 큐브맵으로 바꾸어 스카이박스를 설정합니다. </br>
+    /// 읽기에 실패하면 기존 스카이박스를 유지하고 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the skybox by converting an equirectangular panorama image (e.g. `.hdr`) to a cubemap of size `face_size`. </br>
+    /// If reading fails, the existing skybox is kept and an error is returned. </br>
+    /// 
+    pub fn set_equirect<P: AsRef<Path>>(
+        &mut self, 
+        path: P, 
+        face_size: u32, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> image::ImageResult<()> {
+        let (size, pixels) = load_equirect_faces(path.as_ref(), face_size)?;
+        self.cubemap = Some(Cubemap::from_rgba8(size, &pixels, &self.bind_group_layout, device, queue));
+        log::info!("Equirectangular skybox loaded. (path:{}, size:{})", path.as_ref().display(), size);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_loaded(&self) -> bool {
+        self.cubemap.is_some()
+    }
+
+    /// #### 한국어 </br>
+    /// 스카이박스를 그립니다. 스카이박스 파이프라인과 카메라(0번 그룹)가 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the skybox. The skybox pipeline and camera (group 0) must be set. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if let Some(cubemap) = self.cubemap.as_ref() {
+            stats::set_bind_group(rpass, 1, &cubemap.bind_group, &[]);
+            self.mesh.bind(rpass);
+            self.mesh.draw(rpass);
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 절차적 하늘을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a procedural sky. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProceduralSkyBuilder {
+    pub zenith_color: glam::Vec3, 
+    pub horizon_color: glam::Vec3, 
+    pub ground_color: glam::Vec3, 
+    pub sun_direction: glam::Vec3, 
+    pub sun_color: glam::Vec3, 
+    pub sun_angular_radius: f32, 
+}
+
+impl Default for ProceduralSkyBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            zenith_color: (0.15, 0.35, 0.75).into(), 
+            horizon_color: (0.75, 0.85, 0.95).into(), 
+            ground_color: (0.3, 0.28, 0.26).into(), 
+            sun_direction: glam::Vec3::Y, 
+            sun_color: (1.0, 0.95, 0.85).into(), 
+            sun_angular_radius: 1.5f32.to_radians()
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ProceduralSkyBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_zenith_color(mut self, zenith_color: glam::Vec3) -> Self {
+        self.zenith_color = zenith_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_horizon_color(mut self, horizon_color: glam::Vec3) -> Self {
+        self.horizon_color = horizon_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_ground_color(mut self, ground_color: glam::Vec3) -> Self {
+        self.ground_color = ground_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_sun_direction(mut self, sun_direction: glam::Vec3) -> Self {
+        self.sun_direction = sun_direction.normalize();
+        self
+    }
+
+    #[inline]
+    pub fn set_sun_color(mut self, sun_color: glam::Vec3) -> Self {
+        self.sun_color = sun_color;
+        self
+    }
+
+    #[inline]
+    pub fn set_sun_angular_radius(mut self, sun_angular_radius: f32) -> Self {
+        self.sun_angular_radius = sun_angular_radius;
+        self
+    }
+
+    pub fn build(
+        self, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> ProceduralSky {
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(ProceduralSky)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<SkyUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(ProceduralSky)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let sky = ProceduralSky {
+            zenith_color: self.zenith_color, 
+            horizon_color: self.horizon_color, 
+            ground_color: self.ground_color, 
+            sun_direction: self.sun_direction.normalize(), 
+            sun_color: self.sun_color, 
+            sun_angular_radius: self.sun_angular_radius, 
+            mesh: SkyboxMesh::new(device, queue), 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+        };
+        sky.update_resource(queue);
+
+        return sky;
+    }
+}
+
+/// #### 한국어 </br>
+/// 큐브맵 대신 사용할 수 있는 절차적 하늘 입니다. </br>
+/// 태양의 고도에 따라 지평선의 색이 노을빛으로 변하며, 태양 주변에 빛 번짐을 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A procedural sky that can be used instead of a cubemap. </br>
+/// The horizon turns to sunset colors depending on the elevation of the sun, and a glow is drawn around the sun. </br>
+/// 
+#[derive(Debug)]
+pub struct ProceduralSky {
+    zenith_color: glam::Vec3, 
+    horizon_color: glam::Vec3, 
+    ground_color: glam::Vec3, 
+    sun_direction: glam::Vec3, 
+    sun_color: glam::Vec3, 
+    sun_angular_radius: f32, 
+    mesh: SkyboxMesh, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+}
+
+impl ProceduralSky {
+    /// #### 한국어 </br>
+    /// 태양을 향하는 방향을 설정합니다. 변경 후 `update_resource`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the direction towards the sun. `update_resource` must be called after the change. </br>
+    /// 
+    #[inline]
+    pub fn set_sun_direction(&mut self, sun_direction: glam::Vec3) {
+        self.sun_direction = sun_direction.normalize();
+    }
+
+    #[inline]
+    pub fn sun_direction(&self) -> glam::Vec3 {
+        self.sun_direction
+    }
+
+    /// #### 한국어 </br>
+    /// 절차적 하늘을 그립니다. 절차적 하늘 파이프라인과 카메라(0번 그룹)가 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the procedural sky. The procedural sky pipeline and camera (group 0) must be set. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::set_bind_group(rpass, 1, &self.uniform_bind_group, &[]);
+        self.mesh.bind(rpass);
+        self.mesh.draw(rpass);
+    }
+}
+
+impl ShaderResource for ProceduralSky {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = SkyUniformLayout {
+            zenith_color: (self.zenith_color, 1.0).into(), 
+            horizon_color: (self.horizon_color, 1.0).into(), 
+            ground_color: (self.ground_color, 1.0).into(), 
+            sun_direction: (self.sun_direction, self.sun_angular_radius.cos()).into(), 
+            sun_color: (self.sun_color, 1.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 절차적 하늘 유니폼 데이터의 레이아웃 입니다. </br>
+/// `sun_direction`의 `w`는 태양 원반의 각반지름의 코사인 값입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the procedural sky uniform data used in the shader. </br>
+/// `w` of `sun_direction` is the cosine of the angular radius of the sun disc. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkyUniformLayout {
+    pub zenith_color: glam::Vec4, 
+    pub horizon_color: glam::Vec4, 
+    pub ground_color: glam::Vec4, 
+    pub sun_direction: glam::Vec4, 
+    pub sun_color: glam::Vec4, 
+}
+
+impl Default for SkyUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            zenith_color: glam::Vec4::ONE, 
+            horizon_color: glam::Vec4::ONE, 
+            ground_color: glam::Vec4::ONE, 
+            sun_direction: (0.0, 1.0, 0.0, 1.0).into(), 
+            sun_color: glam::Vec4::ONE, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 스카이박스 버텍스 입력 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the skybox vertex input data used in the shader. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkyboxVertexLayout {
+    pub position: glam::Vec3, 
+}
+
+impl Default for SkyboxVertexLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            position: glam::Vec3::ZERO, 
+        }
+    }
+}
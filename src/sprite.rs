@@ -0,0 +1,300 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 스프라이트가 사용하는 텍스처 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the texture used by sprites. </br>
+/// 
+#[derive(Debug)]
+pub struct SpriteTexture {
+    pub bind_group: wgpu::BindGroup, 
+}
+
+impl SpriteTexture {
+    /// #### 한국어 </br>
+    /// RGBA8 픽셀 데이터로 스프라이트 텍스처를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a sprite texture from RGBA8 pixel data. </br>
+    /// 
+    pub fn from_rgba8(
+        width: u32, 
+        height: u32, 
+        pixels: &[u8], 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(width > 0 && height > 0);
+        assert_eq!(pixels.len(), (4 * width * height) as usize);
+
+        let texture = stats::track_texture(device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(Sprite)"), 
+                size: wgpu::Extent3d {
+                    width, 
+                    height, 
+                    depth_or_array_layers: 1, 
+                }, 
+                dimension: wgpu::TextureDimension::D2, 
+                format: wgpu::TextureFormat::Rgba8Unorm, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        ));
+        queue.write_texture(
+            texture.as_image_copy(), 
+            pixels, 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(4 * width), 
+                rows_per_image: Some(height), 
+            }, 
+            texture.size(), 
+        );
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        });
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Sprite)"), 
+                address_mode_u: wgpu::AddressMode::ClampToEdge, 
+                address_mode_v: wgpu::AddressMode::ClampToEdge, 
+                address_mode_w: wgpu::AddressMode::ClampToEdge, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                mipmap_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Sprite)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::TextureView(&texture_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            bind_group
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 단색 사각형을 그릴 때 사용하는 1x1 흰색 텍스처를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a 1x1 white texture used to draw solid color quads. </br>
+    /// 
+    #[inline]
+    pub fn white(
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        Self::from_rgba8(1, 1, &[255, 255, 255, 255], bind_group_layout, device, queue)
+    }
+}
+
+/// #### 한국어 </br>
+/// 같은 텍스처를 사용하는 연속된 정점들의 범위 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A range of consecutive vertices that use the same texture. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SpriteBatchRange {
+    texture: usize, 
+    start: u32, 
+    end: u32, 
+}
+
+/// #### 한국어 </br>
+/// 화면 공간의 사각형들을 모아서 그리는 스프라이트 배치 입니다. </br>
+/// 직교 투영 카메라와 함께 사용하며, 텍스처가 바뀔 때만 그리기 호출이 나뉩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A sprite batch that collects and draws quads in screen space. </br>
+/// It is used with an orthographic camera, and draw calls are split only when the texture changes. </br>
+/// 
+#[derive(Debug)]
+pub struct SpriteBatch {
+    textures: Vec<SpriteTexture>, 
+    vertices: Vec<SpriteVertexLayout>, 
+    ranges: Vec<SpriteBatchRange>, 
+    prepared_ranges: Vec<SpriteBatchRange>, 
+    vertex_buffer: wgpu::Buffer, 
+}
+
+impl SpriteBatch {
+    /// #### 한국어 </br>
+    /// 기본 흰색 텍스처를 0번 텍스처로 가지는 스프라이트 배치를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a sprite batch that has the default white texture as texture number 0. </br>
+    /// 
+    pub fn new(
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        Self {
+            textures: vec![SpriteTexture::white(bind_group_layout, device, queue)], 
+            vertices: Vec::new(), 
+            ranges: Vec::new(), 
+            prepared_ranges: Vec::new(), 
+            vertex_buffer: create_vertex_buffer(256, device), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처를 등록하고 그 텍스처의 번호를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers a texture and returns the number of the texture. </br>
+    /// 
+    #[inline]
+    pub fn add_texture(&mut self, texture: SpriteTexture) -> usize {
+        self.textures.push(texture);
+        self.textures.len() - 1
+    }
+
+    /// #### 한국어 </br>
+    /// 단색 사각형을 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a solid color quad. </br>
+    /// 
+    #[inline]
+    pub fn draw_quad(&mut self, position: glam::Vec2, size: glam::Vec2, color: glam::Vec4) {
+        self.draw_sprite(0, position, size, glam::Vec4::new(0.0, 0.0, 1.0, 1.0), color);
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처가 입혀진 사각형을 그립니다. `uv_rect`는 (최소 u, 최소 v, 최대 u, 최대 v) 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a textured quad. `uv_rect` is (min u, min v, max u, max v). </br>
+    /// 
+    pub fn draw_sprite(
+        &mut self, 
+        texture: usize, 
+        position: glam::Vec2, 
+        size: glam::Vec2, 
+        uv_rect: glam::Vec4, 
+        color: glam::Vec4
+    ) {
+        assert!(texture < self.textures.len());
+
+        let start = self.vertices.len() as u32;
+        let (min, max) = (position, position + size);
+        let quad = [
+            SpriteVertexLayout { position: glam::vec2(min.x, min.y), texcoord: glam::vec2(uv_rect.x, uv_rect.y), color }, 
+            SpriteVertexLayout { position: glam::vec2(min.x, max.y), texcoord: glam::vec2(uv_rect.x, uv_rect.w), color }, 
+            SpriteVertexLayout { position: glam::vec2(max.x, max.y), texcoord: glam::vec2(uv_rect.z, uv_rect.w), color }, 
+            SpriteVertexLayout { position: glam::vec2(max.x, min.y), texcoord: glam::vec2(uv_rect.z, uv_rect.y), color }, 
+        ];
+        self.vertices.extend_from_slice(&[quad[0], quad[1], quad[2], quad[2], quad[3], quad[0]]);
+        let end = self.vertices.len() as u32;
+
+        match self.ranges.last_mut() {
+            Some(range) if range.texture == texture && range.end == start => range.end = end, 
+            _ => self.ranges.push(SpriteBatchRange { texture, start, end }), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 모은 사각형들의 정점 데이터를 GPU에 올리고, 대기열을 비웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uploads the vertex data of the quads collected this frame to the GPU and clears the queue. </br>
+    /// 
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let required = (mem::size_of::<SpriteVertexLayout>() * self.vertices.len()) as wgpu::BufferAddress;
+        if required > self.vertex_buffer.size() {
+            self.vertex_buffer = create_vertex_buffer(self.vertices.len().next_power_of_two(), device);
+        }
+
+        if !self.vertices.is_empty() {
+            queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+        self.vertices.clear();
+        self.prepared_ranges.clear();
+        self.prepared_ranges.append(&mut self.ranges);
+    }
+
+    /// #### 한국어 </br>
+    /// 준비된 사각형들을 그립니다. 스프라이트 파이프라인과 카메라(0번 그룹)가 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the prepared quads. The sprite pipeline and camera (group 0) must be set. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if self.prepared_ranges.is_empty() {
+            return;
+        }
+
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        for range in self.prepared_ranges.iter() {
+            stats::set_bind_group(rpass, 1, &self.textures[range.texture].bind_group, &[]);
+            stats::draw(rpass, range.start..range.end, 0..1);
+        }
+    }
+}
+
+#[inline]
+fn create_vertex_buffer(capacity: usize, device: &wgpu::Device) -> wgpu::Buffer {
+    stats::track_buffer(device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some("Vertex(SpriteBatch)"), 
+            mapped_at_creation: false, 
+            size: (mem::size_of::<SpriteVertexLayout>() * capacity) as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    ))
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 스프라이트 버텍스 입력 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the sprite vertex input data used in the shader. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteVertexLayout {
+    pub position: glam::Vec2, 
+    pub texcoord: glam::Vec2, 
+    pub color: glam::Vec4, 
+}
+
+impl Default for SpriteVertexLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            position: glam::Vec2::ZERO, 
+            texcoord: glam::Vec2::ZERO, 
+            color: glam::Vec4::ONE, 
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering as MemOrdering};
+
+
+
+static NUM_DRAW_CALLS: AtomicUsize = AtomicUsize::new(0);
+static NUM_INSTANCES: AtomicUsize = AtomicUsize::new(0);
+static NUM_BIND_GROUP_SWITCHES: AtomicUsize = AtomicUsize::new(0);
+static TRACKED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// #### 한국어 </br>
+/// 한 프레임 동안 기록된 렌더링 통계 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The rendering stats recorded during one frame. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameStats {
+    pub num_draw_calls: usize, 
+    pub num_instances: usize, 
+    pub num_bind_group_switches: usize, 
+}
+
+/// #### 한국어 </br>
+/// 프레임이 제출된 후 호출합니다. 이번 프레임의 통계를 반환하고 다음 프레임을 위해 초기화합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Called after a frame is submitted. Returns the stats of this frame and resets them for the next frame. </br>
+/// 
+#[inline]
+pub fn end_frame() -> FrameStats {
+    FrameStats {
+        num_draw_calls: NUM_DRAW_CALLS.swap(0, MemOrdering::Relaxed), 
+        num_instances: NUM_INSTANCES.swap(0, MemOrdering::Relaxed), 
+        num_bind_group_switches: NUM_BIND_GROUP_SWITCHES.swap(0, MemOrdering::Relaxed), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 그리기 호출 수와 인스턴스 수를 기록합니다. 간접 그리기처럼 `wgpu`를 직접 호출하는 곳에서 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the number of draw calls and instances. Used where `wgpu` is called directly, such as the indirect draws. </br>
+/// 
+#[inline]
+pub fn record_draws(num_draw_calls: usize, num_instances: usize) {
+    NUM_DRAW_CALLS.fetch_add(num_draw_calls, MemOrdering::Relaxed);
+    NUM_INSTANCES.fetch_add(num_instances, MemOrdering::Relaxed);
+}
+
+/// #### 한국어 </br>
+/// 그리기 호출을 기록하고 `draw`를 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the draw call and calls `draw`. </br>
+/// 
+#[inline]
+pub fn draw(rpass: &mut wgpu::RenderPass, vertices: Range<u32>, instances: Range<u32>) {
+    record_draws(1, instances.len());
+    rpass.draw(vertices, instances);
+}
+
+/// #### 한국어 </br>
+/// 그리기 호출을 기록하고 `draw_indexed`를 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the draw call and calls `draw_indexed`. </br>
+/// 
+#[inline]
+pub fn draw_indexed(rpass: &mut wgpu::RenderPass, indices: Range<u32>, base_vertex: i32, instances: Range<u32>) {
+    record_draws(1, instances.len());
+    rpass.draw_indexed(indices, base_vertex, instances);
+}
+
+/// #### 한국어 </br>
+/// 바인드 그룹 전환을 기록하고 `set_bind_group`을 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the bind group switch and calls `set_bind_group`. </br>
+/// 
+#[inline]
+pub fn set_bind_group<'a>(rpass: &mut wgpu::RenderPass<'a>, index: u32, bind_group: &'a wgpu::BindGroup, offsets: &[u32]) {
+    NUM_BIND_GROUP_SWITCHES.fetch_add(1, MemOrdering::Relaxed);
+    rpass.set_bind_group(index, bind_group, offsets);
+}
+
+
+
+/// #### 한국어 </br>
+/// 버퍼의 크기를 추정 비디오 메모리 사용량에 더하고 버퍼를 그대로 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Adds the size of the buffer to the estimated video memory usage and returns the buffer as is. </br>
+/// 
+#[inline]
+pub fn track_buffer(buffer: wgpu::Buffer) -> wgpu::Buffer {
+    TRACKED_BYTES.fetch_add(buffer.size(), MemOrdering::Relaxed);
+    buffer
+}
+
+/// #### 한국어 </br>
+/// 텍스처의 추정 크기를 추정 비디오 메모리 사용량에 더하고 텍스처를 그대로 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Adds the estimated size of the texture to the estimated video memory usage and returns the texture as is. </br>
+/// 
+#[inline]
+pub fn track_texture(texture: wgpu::Texture) -> wgpu::Texture {
+    TRACKED_BYTES.fetch_add(texture_bytes(&texture), MemOrdering::Relaxed);
+    texture
+}
+
+/// #### 한국어 </br>
+/// 파괴되거나 교체된 텍스처의 추정 크기를 추정 비디오 메모리 사용량에서 뺍니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Subtracts the estimated size of a destroyed or replaced texture from the estimated video memory usage. </br>
+/// 
+#[inline]
+pub fn untrack_texture(texture: &wgpu::Texture) {
+    untrack_bytes(texture_bytes(texture));
+}
+
+/// #### 한국어 </br>
+/// 더 이상 사용하지 않는 자원의 크기를 추정 비디오 메모리 사용량에서 뺍니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Subtracts the size of a resource no longer used from the estimated video memory usage. </br>
+/// 
+#[inline]
+pub fn untrack_bytes(bytes: u64) {
+    TRACKED_BYTES.fetch_sub(bytes, MemOrdering::Relaxed);
+}
+
+/// #### 한국어 </br>
+/// 생성된 버퍼와 텍스처가 차지하는 비디오 메모리의 추정치를 바이트 단위로 반환합니다. </br>
+/// 드라이버의 정렬과 압축은 고려하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the estimate of the video memory taken by the created buffers and textures in bytes. </br>
+/// The alignment and compression of the driver are not considered. </br>
+/// 
+#[inline]
+pub fn estimated_vram_bytes() -> u64 {
+    TRACKED_BYTES.load(MemOrdering::Relaxed)
+}
+
+/// #### 한국어 </br>
+/// 모든 밉 레벨과 샘플을 포함한 텍스처의 크기를 추정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Estimates the size of the texture including all mip levels and samples. </br>
+/// 
+#[inline]
+pub fn texture_bytes(texture: &wgpu::Texture) -> u64 {
+    estimate_texture_bytes(texture.size(), texture.format(), texture.dimension(), texture.mip_level_count(), texture.sample_count())
+}
+
+/// #### 한국어 </br>
+/// 주어진 크기와 형식을 가진 텍스처의 크기를 모든 밉 레벨과 샘플을 포함하여 추정합니다. </br>
+/// 복사할 수 없는 깊이 형식은 텍셀당 4바이트로, 깊이-스텐실 형식은 두 측면의 합으로 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Estimates the size of a texture with the given size and format, including all mip levels and samples. </br>
+/// Depth formats that cannot be copied count as 4 bytes per texel, and depth-stencil formats as the sum of both aspects. </br>
+/// 
+pub fn estimate_texture_bytes(
+    size: wgpu::Extent3d, 
+    format: wgpu::TextureFormat, 
+    dimension: wgpu::TextureDimension, 
+    mip_level_count: u32, 
+    sample_count: u32
+) -> u64 {
+    let block_bytes = format.block_copy_size(None).unwrap_or_else(|| {
+        let depth = format.block_copy_size(Some(wgpu::TextureAspect::DepthOnly)).unwrap_or(4);
+        let stencil = format.block_copy_size(Some(wgpu::TextureAspect::StencilOnly)).unwrap_or(0);
+        depth + stencil
+    }) as u64;
+    let (block_width, block_height) = format.block_dimensions();
+
+    (0..mip_level_count)
+        .map(|level| {
+            let size = size.mip_level_size(level, dimension).physical_size(format);
+            let num_blocks = (size.width / block_width) as u64
+                * (size.height / block_height) as u64
+                * size.depth_or_array_layers as u64;
+            num_blocks * block_bytes
+        })
+        .sum::<u64>() * sample_count as u64
+}
@@ -0,0 +1,285 @@
+use std::fmt;
+
+use crate::collision::CollisionWorld;
+
+
+
+/// #### 한국어 </br>
+/// 조향 행동(steering behavior)으로 움직이는 에이전트를 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates an agent moved by steering behaviors. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteeringAgentBuilder {
+    pub position: glam::Vec3, 
+    pub max_speed: f32, 
+    pub max_force: f32, 
+    pub radius: f32, 
+    pub wander_distance: f32, 
+    pub wander_radius: f32, 
+    pub wander_jitter: f32, 
+    pub avoid_distance: f32, 
+    pub seed: u32, 
+}
+
+impl Default for SteeringAgentBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            position: glam::Vec3::ZERO, 
+            max_speed: 1.5, 
+            max_force: 4.0, 
+            radius: 0.3, 
+            wander_distance: 1.2, 
+            wander_radius: 0.6, 
+            wander_jitter: 2.5, 
+            avoid_distance: 1.5, 
+            seed: 1
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl SteeringAgentBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_position(mut self, position: glam::Vec3) -> Self {
+        self.position = position;
+        self
+    }
+
+    #[inline]
+    pub fn set_max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = max_speed.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn set_radius(mut self, radius: f32) -> Self {
+        self.radius = radius.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 배회 행동의 원을 설정합니다. 원은 에이전트 앞쪽 `distance`에 놓이며, 목표점은 매 초 `jitter`만큼 원 위를 움직입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the circle of the wander behavior. The circle lies `distance` ahead of the agent, and the target moves on the circle by `jitter` per second. </br>
+    /// 
+    #[inline]
+    pub fn set_wander(mut self, distance: f32, radius: f32, jitter: f32) -> Self {
+        self.wander_distance = distance;
+        self.wander_radius = radius;
+        self.wander_jitter = jitter;
+        self
+    }
+
+    #[inline]
+    pub fn set_avoid_distance(mut self, avoid_distance: f32) -> Self {
+        self.avoid_distance = avoid_distance.max(0.0);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 배회 행동이 사용하는 난수 생성기의 시드를 설정합니다. 0은 1로 바뀝니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the seed of the random number generator used by the wander behavior. 0 is changed to 1. </br>
+    /// 
+    #[inline]
+    pub fn set_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn build(self) -> SteeringAgent {
+        let mut agent = SteeringAgent {
+            position: self.position, 
+            velocity: glam::Vec3::ZERO, 
+            heading: glam::Vec3::Z, 
+            max_speed: self.max_speed, 
+            max_force: self.max_force, 
+            radius: self.radius, 
+            wander_distance: self.wander_distance, 
+            wander_radius: self.wander_radius, 
+            wander_jitter: self.wander_jitter, 
+            wander_angle: 0.0, 
+            avoid_distance: self.avoid_distance, 
+            random_state: self.seed.max(1), 
+        };
+        agent.wander_angle = std::f32::consts::TAU * agent.next_random();
+        agent.heading = glam::Quat::from_rotation_y(agent.wander_angle) * glam::Vec3::Z;
+        return agent;
+    }
+}
+
+/// #### 한국어 </br>
+/// XZ 평면 위에서 속도를 가지고 움직이는 조향 에이전트 입니다. </br>
+/// 각 행동(`seek`, `wander`, `avoid`, `contain`)은 조향 힘을 반환하며, 합친 힘을 `update`에 넘겨 속도와 위치를 적분합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A steering agent that moves with a velocity on the XZ plane. </br>
+/// Each behavior (`seek`, `wander`, `avoid`, `contain`) returns a steering force, and the combined force is passed to `update` to integrate the velocity and position. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteeringAgent {
+    position: glam::Vec3, 
+    velocity: glam::Vec3, 
+    heading: glam::Vec3, 
+    max_speed: f32, 
+    max_force: f32, 
+    radius: f32, 
+    wander_distance: f32, 
+    wander_radius: f32, 
+    wander_jitter: f32, 
+    wander_angle: f32, 
+    avoid_distance: f32, 
+    random_state: u32, 
+}
+
+#[allow(dead_code)]
+impl SteeringAgent {
+    #[inline]
+    pub fn position(&self) -> glam::Vec3 {
+        self.position
+    }
+
+    #[inline]
+    pub fn velocity(&self) -> glam::Vec3 {
+        self.velocity
+    }
+
+    /// #### 한국어 </br>
+    /// 에이전트가 바라보는 방향을 반환합니다. 멈춰 있으면 마지막으로 움직인 방향을 유지합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the direction the agent faces. If it is stopped, the last direction it moved in is kept. </br>
+    /// 
+    #[inline]
+    pub fn heading(&self) -> glam::Vec3 {
+        self.heading
+    }
+
+    /// #### 한국어 </br>
+    /// 목표 지점을 향해 최대 속력으로 가려는 힘을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the force to go toward the target at the maximum speed. </br>
+    /// 
+    pub fn seek(&self, target: glam::Vec3) -> glam::Vec3 {
+        let desired = flatten(target - self.position).normalize_or_zero() * self.max_speed;
+        desired - self.velocity
+    }
+
+    /// #### 한국어 </br>
+    /// 앞쪽의 원 위를 무작위로 움직이는 목표점을 따라가며 배회하는 힘을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the force to wander by following a target moving randomly on a circle ahead. </br>
+    /// 
+    pub fn wander(&mut self, elapsed_time_sec: f32) -> glam::Vec3 {
+        let jitter = (2.0 * self.next_random() - 1.0) * self.wander_jitter * elapsed_time_sec;
+        self.wander_angle = (self.wander_angle + jitter).rem_euclid(std::f32::consts::TAU);
+        let offset = glam::Quat::from_rotation_y(self.wander_angle) * glam::Vec3::Z * self.wander_radius;
+        self.seek(self.position + self.heading * self.wander_distance + offset)
+    }
+
+    /// #### 한국어 </br>
+    /// 앞쪽과 양옆으로 쏜 감지 광선에 맞은 장애물에서 멀어지려는 힘을 반환합니다. </br>
+    /// 장애물이 가까울수록 힘이 커지며, 에이전트 자신의 충돌체는 광선이 그 안에서 시작하므로 맞지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the force to move away from the obstacles hit by the feeler rays cast ahead and to the sides. </br>
+    /// The closer the obstacle, the larger the force, and the agent's own collider is not hit since the rays start inside it. </br>
+    /// 
+    pub fn avoid<T: fmt::Debug + Clone>(&self, world: &CollisionWorld<T>) -> glam::Vec3 {
+        let mut force = glam::Vec3::ZERO;
+        for (angle, length) in [(0.0f32, 1.0), (35.0, 0.6), (-35.0, 0.6)] {
+            let direction = glam::Quat::from_rotation_y(angle.to_radians()) * self.heading;
+            let distance = self.radius + self.avoid_distance * length;
+            let Some(hit) = world.raycast(self.position, direction, distance) else {
+                continue;
+            };
+
+            // (한국어) 맞은 면의 법선 방향으로 밀어내되, 정면으로 부딪힌 경우에는 옆으로 비켜가도록 합니다.
+            // (English Translation) Pushes along the normal of the surface hit, and steps aside when hitting it head on.
+            let normal = flatten(hit.normal).normalize_or_zero();
+            let side = glam::vec3(-direction.z, 0.0, direction.x);
+            let head_on = if angle == 0.0 { normal.dot(-direction).max(0.0) } else { 0.0 };
+            let away = (normal + side * head_on).normalize_or_zero();
+            let strength = 1.0 - (hit.distance / distance).clamp(0.0, 1.0);
+            force += away * self.max_force * strength;
+        }
+        return force;
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 XZ 범위 밖으로 나가면 안쪽으로 되돌아오는 힘을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the force to come back inside when going out of the given XZ range. </br>
+    /// 
+    pub fn contain(&self, min: glam::Vec2, max: glam::Vec2) -> glam::Vec3 {
+        let inner_min = min + self.radius;
+        let inner_max = max - self.radius;
+        let x = (inner_min.x - self.position.x).max(0.0) - (self.position.x - inner_max.x).max(0.0);
+        let z = (inner_min.y - self.position.z).max(0.0) - (self.position.z - inner_max.y).max(0.0);
+        glam::vec3(x, 0.0, z) * self.max_force * 4.0
+    }
+
+    /// #### 한국어 </br>
+    /// 조향 힘을 최대 힘으로 제한하여 속도와 위치를 적분합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Integrates the velocity and position with the steering force limited to the maximum force. </br>
+    /// 
+    pub fn update(&mut self, force: glam::Vec3, elapsed_time_sec: f32) {
+        let force = flatten(force).clamp_length_max(self.max_force);
+        self.velocity = (self.velocity + force * elapsed_time_sec).clamp_length_max(self.max_speed);
+        self.position += self.velocity * elapsed_time_sec;
+        if self.velocity.length_squared() > 1e-6 {
+            self.heading = self.velocity.normalize();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 에이전트의 위치에서 진행 방향을 바라보는 월드 변환 행렬을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the world transform matrix at the position of the agent facing the heading. </br>
+    /// 
+    #[inline]
+    pub fn world_transform(&self, scale: glam::Vec3) -> glam::Mat4 {
+        let rotation = glam::Quat::from_rotation_arc(glam::Vec3::Z, self.heading);
+        glam::Mat4::from_scale_rotation_translation(scale, rotation, self.position)
+    }
+
+    /// #### 한국어 </br>
+    /// 0 이상 1 미만의 난수를 반환합니다. (xorshift32) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns a random number in [0, 1). (xorshift32) </br>
+    /// 
+    fn next_random(&mut self) -> f32 {
+        self.random_state ^= self.random_state << 13;
+        self.random_state ^= self.random_state >> 17;
+        self.random_state ^= self.random_state << 5;
+        (self.random_state >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+#[inline]
+fn flatten(vector: glam::Vec3) -> glam::Vec3 {
+    glam::vec3(vector.x, 0.0, vector.z)
+}
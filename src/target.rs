@@ -0,0 +1,701 @@
+use std::io;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering as MemOrdering};
+
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 장면과 렌더 타겟의 기본 깊이-스텐실 텍스처 형식입니다. 거울의 마스크에 스텐실을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default depth-stencil texture format of the scene and the render targets. The stencil is used for the mirror mask. </br>
+/// 
+pub const DEFAULT_DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24PlusStencil8;
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 깊이 버퍼 형식과 명령줄에서 쓰는 이름 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable depth buffer formats and the names used on the command line. </br>
+/// 
+pub const DEPTH_FORMATS: [(&str, wgpu::TextureFormat); 3] = [
+    ("depth24plus-stencil8", wgpu::TextureFormat::Depth24PlusStencil8), 
+    ("depth32float", wgpu::TextureFormat::Depth32Float), 
+    ("depth32float-stencil8", wgpu::TextureFormat::Depth32FloatStencil8), 
+];
+
+/// #### 한국어 </br>
+/// 깊이 버퍼 형식을 선택하는 명령줄 인수 입니다. 뒤에 `DEPTH_FORMATS`의 이름이 옵니다. (예: `--depth-format depth32float`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that selects the depth buffer format. It is followed by a name of `DEPTH_FORMATS`. (e.g. `--depth-format depth32float`) </br>
+/// 
+pub const DEPTH_FORMAT_ARG: &str = "--depth-format";
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 깊이 버퍼 형식들을 위해 사용할 수 있으면 요청하는 장치 기능들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The device features requested for the selectable depth buffer formats when they are available. </br>
+/// 
+pub const DEPTH_FORMAT_FEATURES: wgpu::Features = wgpu::Features::DEPTH32FLOAT_STENCIL8;
+
+static DEPTH_FORMAT_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+/// #### 한국어 </br>
+/// 멀티샘플링의 샘플 수를 선택하는 명령줄 인수 입니다. 뒤에 1, 2, 4, 8 중 하나가 옵니다. (예: `--msaa 4`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that selects the sample count of the multisampling. It is followed by one of 1, 2, 4 and 8. (e.g. `--msaa 4`) </br>
+/// 
+pub const SAMPLE_COUNT_ARG: &str = "--msaa";
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 멀티샘플링의 샘플 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable sample counts of the multisampling. </br>
+/// 
+pub const SAMPLE_COUNTS: [u32; 4] = [1, 2, 4, 8];
+
+/// #### 한국어 </br>
+/// 4가 아닌 샘플 수를 어댑터가 지원하는지 알아보기 위해 사용할 수 있으면 요청하는 장치 기능들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The device features requested when available to find out whether the adapter supports sample counts other than 4. </br>
+/// 
+pub const SAMPLE_COUNT_FEATURES: wgpu::Features = wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES;
+
+static SAMPLE_COUNT: AtomicU32 = AtomicU32::new(1);
+
+/// #### 한국어 </br>
+/// `DEPTH_FORMAT_ARG` 명령줄 인수의 값을 깊이 버퍼 형식으로 해석합니다. 알 수 없는 값이면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `DEPTH_FORMAT_ARG` command line argument as a depth buffer format. Returns an `InvalidInput` error if the value is unknown. </br>
+/// 
+pub fn parse_depth_format(value: &str) -> io::Result<wgpu::TextureFormat> {
+    match DEPTH_FORMATS.iter().find(|(name, _)| *name == value) {
+        Some(&(_, format)) => Ok(format), 
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!(
+                "{} must be followed by one of {}", 
+                DEPTH_FORMAT_ARG, 
+                DEPTH_FORMATS.map(|(name, _)| name).join(", ")
+            )
+        )), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면과 렌더 타겟이 사용할 깊이 버퍼 형식을 설정하고 실제로 사용되는 형식을 반환합니다. </br>
+/// 장치가 형식에 필요한 기능을 지원하지 않으면 기본 형식을 사용합니다. 파이프라인과 타겟을 생성하기 전에 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sets the depth buffer format used by the scene and the render targets, and returns the format actually used. </br>
+/// If the device does not support the features required by the format, the default format is used. It must be called before creating the pipelines and targets. </br>
+/// 
+pub fn set_depth_format(format: wgpu::TextureFormat, features: wgpu::Features) -> wgpu::TextureFormat {
+    let format = match features.contains(format.required_features()) {
+        true => format, 
+        false => {
+            log::warn!("The device does not support {:?}. Falls back to {:?}.", format, DEFAULT_DEPTH_FORMAT);
+            DEFAULT_DEPTH_FORMAT
+        }
+    };
+    let index = DEPTH_FORMATS.iter().position(|&(_, candidate)| candidate == format).unwrap_or(0);
+    DEPTH_FORMAT_INDEX.store(index, MemOrdering::Release);
+    return DEPTH_FORMATS[index].1;
+}
+
+/// #### 한국어 </br>
+/// 장면과 렌더 타겟의 깊이(-스텐실) 텍스처 형식을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the depth(-stencil) texture format of the scene and the render targets. </br>
+/// 
+#[inline]
+pub fn depth_format() -> wgpu::TextureFormat {
+    DEPTH_FORMATS[DEPTH_FORMAT_INDEX.load(MemOrdering::Acquire)].1
+}
+
+/// #### 한국어 </br>
+/// 깊이 버퍼 형식에 스텐실이 있는지 여부를 반환합니다. 스텐실이 없으면 거울을 그릴 수 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns whether the depth buffer format has a stencil. The mirror cannot be drawn without the stencil. </br>
+/// 
+#[inline]
+pub fn has_stencil() -> bool {
+    depth_format().has_stencil_aspect()
+}
+
+/// #### 한국어 </br>
+/// 깊이 버퍼 형식에 스텐실이 있으면 주어진 스텐실 상태를, 없으면 스텐실을 쓰지 않는 상태를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the given stencil state if the depth buffer format has a stencil, otherwise a state that does not use the stencil. </br>
+/// 
+#[inline]
+pub fn stencil_state(stencil: wgpu::StencilState) -> wgpu::StencilState {
+    match has_stencil() {
+        true => stencil, 
+        false => wgpu::StencilState::default(), 
+    }
+}
+
+/// #### 한국어 </br>
+/// `SAMPLE_COUNT_ARG` 명령줄 인수의 값을 샘플 수로 해석합니다. `SAMPLE_COUNTS`에 없는 값이면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `SAMPLE_COUNT_ARG` command line argument as a sample count. Returns an `InvalidInput` error if the value is not in `SAMPLE_COUNTS`. </br>
+/// 
+pub fn parse_sample_count(value: &str) -> io::Result<u32> {
+    match value.parse::<u32>() {
+        Ok(count) if SAMPLE_COUNTS.contains(&count) => Ok(count), 
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!("{} must be followed by one of {:?}", SAMPLE_COUNT_ARG, SAMPLE_COUNTS)
+        )), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 텍스처 형식들이 모두 지원하는 샘플 수들을 반환합니다. </br>
+/// 장치에 `SAMPLE_COUNT_FEATURES`가 없으면 모든 장치가 지원하는 1과 4만 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the sample counts supported by all of the given texture formats. </br>
+/// If the device does not have `SAMPLE_COUNT_FEATURES`, only 1 and 4, which every device supports, are returned. </br>
+/// 
+pub fn supported_sample_counts(
+    formats: &[wgpu::TextureFormat], 
+    adapter: &wgpu::Adapter, 
+    features: wgpu::Features
+) -> Vec<u32> {
+    SAMPLE_COUNTS.into_iter()
+        .filter(|&count| match features.contains(SAMPLE_COUNT_FEATURES) {
+            true => formats.iter().all(|&format| {
+                adapter.get_texture_format_features(format).flags.sample_count_supported(count)
+            }), 
+            false => count == 1 || count == 4, 
+        })
+        .collect()
+}
+
+/// #### 한국어 </br>
+/// 장면과 감시 카메라의 타겟, 그리고 그 안에 그리는 파이프라인들이 사용할 샘플 수를 설정하고 실제로 사용되는 샘플 수를 반환합니다. </br>
+/// 요청된 샘플 수가 지원되지 않으면 그보다 작은 지원되는 샘플 수 중 가장 큰 것을 사용합니다. 파이프라인과 타겟을 생성하기 전에 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sets the sample count used by the targets of the scene and the security camera and the pipelines drawing into them, and returns the sample count actually used. </br>
+/// If the requested sample count is not supported, the largest supported sample count below it is used. It must be called before creating the pipelines and targets. </br>
+/// 
+pub fn set_sample_count(count: u32, supported: &[u32]) -> u32 {
+    let selected = supported.iter().copied().filter(|&supported| supported <= count).max().unwrap_or(1);
+    if selected != count {
+        log::warn!("{}x multisampling is not supported. Falls back to {}x.", count, selected);
+    }
+    SAMPLE_COUNT.store(selected, MemOrdering::Release);
+    return selected;
+}
+
+/// #### 한국어 </br>
+/// 장면과 감시 카메라의 타겟이 사용하는 샘플 수를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the sample count used by the targets of the scene and the security camera. </br>
+/// 
+#[inline]
+pub fn sample_count() -> u32 {
+    SAMPLE_COUNT.load(MemOrdering::Acquire)
+}
+
+/// #### 한국어 </br>
+/// 장면과 감시 카메라의 타겟에 그리는 파이프라인의 멀티샘플 상태를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the multisample state of the pipelines drawing into the targets of the scene and the security camera. </br>
+/// 
+#[inline]
+pub fn multisample_state() -> wgpu::MultisampleState {
+    wgpu::MultisampleState {
+        count: sample_count(), 
+        ..Default::default()
+    }
+}
+
+/// #### 한국어 </br>
+/// 크기가 바뀌어 교체된 텍스처를 파괴하기 전까지 기다리는 프레임의 수 입니다. </br>
+/// 스왑체인이 동시에 처리하는 최대 프레임 수(`desired_maximum_frame_latency`)보다 커야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of frames to wait before destroying a texture replaced by a resize. </br>
+/// It must be greater than the maximum number of frames the swapchain processes at once (`desired_maximum_frame_latency`). </br>
+/// 
+pub const RETIRE_FRAMES: u64 = 3;
+
+
+
+/// #### 한국어 </br>
+/// 렌더 타겟 안에서 그려지는 영역 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The area drawn inside the render target. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub x: f32, 
+    pub y: f32, 
+    pub width: f32, 
+    pub height: f32, 
+    pub min_depth: f32, 
+    pub max_depth: f32, 
+}
+
+impl Viewport {
+    /// #### 한국어 </br>
+    /// 주어진 크기의 타겟 전체를 덮는 영역을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an area covering the whole target of the given size. </br>
+    /// 
+    #[inline]
+    pub fn full(width: u32, height: u32) -> Self {
+        Self {
+            x: 0.0, 
+            y: 0.0, 
+            width: width as f32, 
+            height: height as f32, 
+            min_depth: 0.0, 
+            max_depth: 1.0
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 색상 텍스처와 깊이 텍스처, 그리고 그려지는 영역을 묶은 오프스크린 렌더 타겟 입니다. </br>
+/// 색상 텍스처는 쉐이더에서 읽을 수 있으므로 다른 카메라의 장면을 물체의 표면에 입힐 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An off-screen render target that bundles a color texture, a depth texture and the area drawn into. </br>
+/// The color texture can be read in shaders, so the scene of another camera can be applied to the surface of an object. </br>
+/// 
+#[derive(Debug)]
+pub struct RenderTarget {
+    label: String, 
+    width: u32, 
+    height: u32, 
+    color_format: wgpu::TextureFormat, 
+    color_view: wgpu::TextureView, 
+    multisampled_view: Option<wgpu::TextureView>, 
+    depth_view: wgpu::TextureView, 
+    viewport: Viewport, 
+}
+
+#[allow(dead_code)]
+impl RenderTarget {
+    pub fn new(
+        label: &str, 
+        width: u32, 
+        height: u32, 
+        color_format: wgpu::TextureFormat, 
+        device: &wgpu::Device
+    ) -> Self {
+        assert!(width > 0 && height > 0);
+
+        let color_view = create_target_view(&format!("Texture({}Color)", label), color_format, 1, width, height, device);
+        let multisampled_view = create_multisampled_view(label, color_format, width, height, device);
+        let depth_view = create_target_view(&format!("Texture({}Depth)", label), depth_format(), sample_count(), width, height, device);
+
+        Self {
+            label: label.to_string(), 
+            width, 
+            height, 
+            color_format, 
+            color_view, 
+            multisampled_view, 
+            depth_view, 
+            viewport: Viewport::full(width, height)
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 타겟의 크기를 바꾸고 텍스처들을 다시 생성합니다. 그려지는 영역은 타겟 전체로 재설정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the size of the target and recreates the textures. The drawn area is reset to the whole target. </br>
+    /// 
+    pub fn resize(&mut self, width: u32, height: u32, device: &wgpu::Device) {
+        assert!(width > 0 && height > 0);
+        stats::untrack_bytes(self.estimated_bytes());
+        self.width = width;
+        self.height = height;
+        self.color_view = create_target_view(&format!("Texture({}Color)", self.label), self.color_format, 1, width, height, device);
+        self.multisampled_view = create_multisampled_view(&self.label, self.color_format, width, height, device);
+        self.depth_view = create_target_view(&format!("Texture({}Depth)", self.label), depth_format(), sample_count(), width, height, device);
+        self.viewport = Viewport::full(width, height);
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    pub fn color_format(&self) -> wgpu::TextureFormat {
+        self.color_format
+    }
+
+    #[inline]
+    pub fn color_view_ref(&self) -> &wgpu::TextureView {
+        &self.color_view
+    }
+
+    #[inline]
+    pub fn depth_view_ref(&self) -> &wgpu::TextureView {
+        &self.depth_view
+    }
+
+    #[inline]
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    #[inline]
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+    }
+
+    /// #### 한국어 </br>
+    /// 색상 텍스처를 지우고 그리는 색상 첨부를 반환합니다. </br>
+    /// 멀티샘플링을 사용하면 멀티샘플 텍스처에 그린 후 색상 텍스처로 리졸브합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns a color attachment that clears and draws into the color texture. </br>
+    /// With multisampling, it draws into the multisampled texture and resolves it into the color texture. </br>
+    /// 
+    #[inline]
+    pub fn color_attachment(&self, clear_color: wgpu::Color) -> wgpu::RenderPassColorAttachment<'_> {
+        wgpu::RenderPassColorAttachment {
+            view: self.multisampled_view.as_ref().unwrap_or(&self.color_view), 
+            resolve_target: self.multisampled_view.as_ref().map(|_| &self.color_view), 
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(clear_color), 
+                store: wgpu::StoreOp::Store, 
+            }, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 깊이 텍스처를 지우고 그리는 깊이-스텐실 첨부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns a depth-stencil attachment that clears and draws into the depth texture. </br>
+    /// 
+    #[inline]
+    pub fn depth_stencil_attachment(&self) -> wgpu::RenderPassDepthStencilAttachment<'_> {
+        wgpu::RenderPassDepthStencilAttachment {
+            view: &self.depth_view, 
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(1.0), 
+                store: wgpu::StoreOp::Store, 
+            }), 
+            stencil_ops: None, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 타겟의 텍스처들이 차지하는 비디오 메모리의 추정치를 바이트 단위로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the estimate of the video memory taken by the textures of the target in bytes. </br>
+    /// 
+    pub fn estimated_bytes(&self) -> u64 {
+        let size = wgpu::Extent3d { width: self.width, height: self.height, depth_or_array_layers: 1 };
+        let dimension = wgpu::TextureDimension::D2;
+        let multisampled = match self.multisampled_view {
+            Some(_) => stats::estimate_texture_bytes(size, self.color_format, dimension, 1, sample_count()), 
+            None => 0, 
+        };
+        stats::estimate_texture_bytes(size, self.color_format, dimension, 1, 1)
+            + stats::estimate_texture_bytes(size, depth_format(), dimension, 1, sample_count())
+            + multisampled
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 패스에 그려지는 영역을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the drawn area on the render pass. </br>
+    /// 
+    #[inline]
+    pub fn apply_viewport(&self, rpass: &mut wgpu::RenderPass) {
+        let viewport = &self.viewport;
+        rpass.set_viewport(viewport.x, viewport.y, viewport.width, viewport.height, viewport.min_depth, viewport.max_depth);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 렌더 타겟 풀에 등록된 텍스처의 번호 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The id of a texture registered in the render target pool. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PooledTargetId(usize);
+
+#[derive(Debug)]
+struct PooledTarget {
+    label: String, 
+    format: wgpu::TextureFormat, 
+    usage: wgpu::TextureUsages, 
+    sample_count: u32, 
+    texture: wgpu::Texture, 
+    view: wgpu::TextureView, 
+}
+
+/// #### 한국어 </br>
+/// 화면 크기를 따르는 텍스처들(깊이 버퍼, HDR 장면, 후처리 타겟 등)을 한 곳에서 관리하는 풀 입니다. </br>
+/// 같은 레이블, 형식, 용도의 텍스처는 다시 요청해도 공유되며, 크기가 바뀌면 모든 텍스처가 한 번에 다시 생성됩니다. </br>
+/// 교체된 텍스처는 바로 파괴하지 않고 `RETIRE_FRAMES` 프레임이 지난 뒤 `end_frame`에서 파괴하므로 
+/// 아직 GPU에서 처리 중인 프레임이 사용하더라도 안전합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A pool that manages the textures following the screen size (depth buffer, HDR scene, post-processing targets, etc.) in one place. </br>
+/// A texture with the same label, format and usage is shared when requested again, and all textures are recreated at once when the size changes. </br>
+/// Replaced textures are not destroyed right away but in `end_frame` after `RETIRE_FRAMES` frames have passed, 
+/// so it is safe even if a frame still being processed by the GPU uses them. </br>
+/// 
+#[derive(Debug)]
+pub struct RenderTargetPool {
+    width: u32, 
+    height: u32, 
+    targets: Vec<PooledTarget>, 
+    retired: Vec<(u64, wgpu::Texture)>, 
+    frame_index: u64, 
+}
+
+#[allow(dead_code)]
+impl RenderTargetPool {
+    #[inline]
+    pub fn new(width: u32, height: u32) -> Self {
+        assert!(width > 0 && height > 0);
+        Self {
+            width, 
+            height, 
+            targets: Vec::new(), 
+            retired: Vec::new(), 
+            frame_index: 0
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    pub fn num_targets(&self) -> usize {
+        self.targets.len()
+    }
+
+    #[inline]
+    pub fn num_retired(&self) -> usize {
+        self.retired.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 크기의 텍스처를 요청합니다. 같은 레이블, 형식, 용도의 텍스처가 이미 있으면 그 텍스처를 재사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests a texture of the current size. If a texture with the same label, format and usage already exists, it is reused. </br>
+    /// 
+    #[inline]
+    pub fn acquire(
+        &mut self, 
+        label: &str, 
+        format: wgpu::TextureFormat, 
+        usage: wgpu::TextureUsages, 
+        device: &wgpu::Device
+    ) -> PooledTargetId {
+        self.acquire_multisampled(label, format, usage, 1, device)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 샘플 수를 가진 현재 크기의 텍스처를 요청합니다. 같은 레이블, 형식, 용도, 샘플 수의 텍스처가 이미 있으면 그 텍스처를 재사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests a texture of the current size with the given sample count. If a texture with the same label, format, usage and sample count already exists, it is reused. </br>
+    /// 
+    pub fn acquire_multisampled(
+        &mut self, 
+        label: &str, 
+        format: wgpu::TextureFormat, 
+        usage: wgpu::TextureUsages, 
+        sample_count: u32, 
+        device: &wgpu::Device
+    ) -> PooledTargetId {
+        if let Some(index) = self.targets.iter().position(|target| {
+            target.label == label && target.format == format && target.usage == usage && target.sample_count == sample_count
+        }) {
+            return PooledTargetId(index);
+        }
+
+        let texture = create_texture(label, format, usage, sample_count, self.width, self.height, device);
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        });
+        self.targets.push(PooledTarget {
+            label: label.to_string(), 
+            format, 
+            usage, 
+            sample_count, 
+            texture, 
+            view
+        });
+        return PooledTargetId(self.targets.len() - 1);
+    }
+
+    #[inline]
+    pub fn texture_ref(&self, id: PooledTargetId) -> &wgpu::Texture {
+        &self.targets[id.0].texture
+    }
+
+    #[inline]
+    pub fn view_ref(&self, id: PooledTargetId) -> &wgpu::TextureView {
+        &self.targets[id.0].view
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처의 새로운 뷰를 생성합니다. 바인드 그룹처럼 뷰를 따로 보관해야 하는 곳에서 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new view of the texture. Used where the view must be kept separately, such as in bind groups. </br>
+    /// 
+    #[inline]
+    pub fn create_view(&self, id: PooledTargetId) -> wgpu::TextureView {
+        self.targets[id.0].texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 텍스처를 주어진 크기로 다시 생성하고, 이전 텍스처들을 파괴 대기 목록으로 옮깁니다. </br>
+    /// 크기가 같거나 0이면 아무것도 하지 않고 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates all textures at the given size, and moves the previous textures to the retired list. </br>
+    /// Does nothing and returns `false` if the size is the same or zero. </br>
+    /// 
+    pub fn resize(&mut self, width: u32, height: u32, device: &wgpu::Device) -> bool {
+        if width == 0 || height == 0 || (width == self.width && height == self.height) {
+            return false;
+        }
+
+        self.width = width;
+        self.height = height;
+        for target in self.targets.iter_mut() {
+            let texture = create_texture(&target.label, target.format, target.usage, target.sample_count, width, height, device);
+            target.view = texture.create_view(&wgpu::TextureViewDescriptor {
+                ..Default::default()
+            });
+            let previous = std::mem::replace(&mut target.texture, texture);
+            self.retired.push((self.frame_index, previous));
+        }
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// 프레임이 제출된 후 호출합니다. `RETIRE_FRAMES` 프레임보다 오래된 교체된 텍스처들을 파괴합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called after a frame is submitted. Destroys the replaced textures older than `RETIRE_FRAMES` frames. </br>
+    /// 
+    pub fn end_frame(&mut self) {
+        self.frame_index += 1;
+        let frame_index = self.frame_index;
+        self.retired.retain(|(retired_at, texture)| {
+            let expired = frame_index >= retired_at + RETIRE_FRAMES;
+            if expired {
+                stats::untrack_texture(texture);
+                texture.destroy();
+            }
+            !expired
+        });
+    }
+}
+
+fn create_texture(
+    label: &str, 
+    format: wgpu::TextureFormat, 
+    usage: wgpu::TextureUsages, 
+    sample_count: u32, 
+    width: u32, 
+    height: u32, 
+    device: &wgpu::Device
+) -> wgpu::Texture {
+    stats::track_texture(device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some(label), 
+            size: wgpu::Extent3d {
+                width, 
+                height, 
+                depth_or_array_layers: 1, 
+            }, 
+            format, 
+            dimension: wgpu::TextureDimension::D2, 
+            mip_level_count: 1, 
+            sample_count, 
+            usage, 
+            view_formats: &[], 
+        }, 
+    ))
+}
+
+fn create_target_view(
+    label: &str, 
+    format: wgpu::TextureFormat, 
+    sample_count: u32, 
+    width: u32, 
+    height: u32, 
+    device: &wgpu::Device
+) -> wgpu::TextureView {
+    let usage = match sample_count {
+        1 => wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
+        _ => wgpu::TextureUsages::RENDER_ATTACHMENT, 
+    };
+    create_texture(label, format, usage, sample_count, width, height, device)
+        .create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        })
+}
+
+fn create_multisampled_view(
+    label: &str, 
+    format: wgpu::TextureFormat, 
+    width: u32, 
+    height: u32, 
+    device: &wgpu::Device
+) -> Option<wgpu::TextureView> {
+    match sample_count() {
+        1 => None, 
+        count => Some(create_target_view(&format!("Texture({}ColorMultisampled)", label), format, count, width, height, device)), 
+    }
+}
@@ -0,0 +1,721 @@
+use std::mem;
+use std::rc::Rc;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::collections::{HashMap, HashSet};
+
+use crate::bounds::{Aabb, Frustum, Sphere};
+use crate::mesh::{IndexBuffer, IndexData, ModelMesh, SubMesh};
+use crate::object::{ObjectVertexLayout, StdObject, StdObjectBuilder};
+use crate::resource::UniformArena;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 바닥 평면 대신 그릴 높이맵 이미지를 지정하는 명령줄 인수 입니다. 뒤에 이미지 파일의 경로가 옵니다. (예: `--heightmap assets/heightmap.png`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that specifies the heightmap image drawn instead of the floor plane. It is followed by the path of the image file. (e.g. `--heightmap assets/heightmap.png`) </br>
+/// 
+pub const HEIGHTMAP_ARG: &str = "--heightmap";
+
+
+
+
+/// #### 한국어 </br>
+/// 카메라 주변의 높이맵 청크들을 불러오는 지형을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a terrain streaming heightmap chunks around the camera. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrainBuilder {
+    pub color: glam::Vec3, 
+    pub chunk_size: f32, 
+    pub resolution: u32, 
+    pub view_distance: i32, 
+    pub lod_distance: f32, 
+    pub max_lod: u32, 
+    pub height_scale: f32, 
+    pub feature_scale: f32, 
+    pub flat_radius: f32, 
+    pub max_loads_per_frame: usize, 
+    pub num_workers: usize, 
+}
+
+impl Default for TerrainBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            color: (0.35, 0.55, 0.3).into(), 
+            chunk_size: 16.0, 
+            resolution: 32, 
+            view_distance: 8, 
+            lod_distance: 2.0, 
+            max_lod: 3, 
+            height_scale: 6.0, 
+            feature_scale: 0.03, 
+            flat_radius: 8.0, 
+            max_loads_per_frame: 4, 
+            num_workers: thread::available_parallelism().map_or(1, |n| n.get().saturating_sub(1).clamp(1, 4))
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl TerrainBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_color(mut self, color: glam::Vec3) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[inline]
+    pub fn set_chunk_size(mut self, chunk_size: f32) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    #[inline]
+    pub fn set_resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub fn set_view_distance(mut self, view_distance: i32) -> Self {
+        self.view_distance = view_distance;
+        self
+    }
+
+    #[inline]
+    pub fn set_lod_distance(mut self, lod_distance: f32) -> Self {
+        self.lod_distance = lod_distance;
+        self
+    }
+
+    #[inline]
+    pub fn set_max_lod(mut self, max_lod: u32) -> Self {
+        self.max_lod = max_lod;
+        self
+    }
+
+    #[inline]
+    pub fn set_height_scale(mut self, height_scale: f32) -> Self {
+        self.height_scale = height_scale;
+        self
+    }
+
+    #[inline]
+    pub fn set_feature_scale(mut self, feature_scale: f32) -> Self {
+        self.feature_scale = feature_scale;
+        self
+    }
+
+    #[inline]
+    pub fn set_flat_radius(mut self, flat_radius: f32) -> Self {
+        self.flat_radius = flat_radius;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임에 GPU로 올리는 최대 청크 수를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the maximum number of chunks uploaded to the GPU in a frame. </br>
+    /// 
+    #[inline]
+    pub fn set_max_loads_per_frame(mut self, max_loads_per_frame: usize) -> Self {
+        self.max_loads_per_frame = max_loads_per_frame;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 청크의 정점들을 생성하는 작업 스레드의 수를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the number of worker threads generating the vertices of the chunks. </br>
+    /// 
+    #[inline]
+    pub fn set_num_workers(mut self, num_workers: usize) -> Self {
+        self.num_workers = num_workers;
+        self
+    }
+
+    pub fn build(
+        self, 
+        object_uniforms: &Rc<UniformArena>, 
+        queue: &wgpu::Queue
+    ) -> Terrain {
+        assert!(self.resolution.is_power_of_two() && (self.resolution >> self.max_lod) > 0);
+        assert!(self.chunk_size > 0.0 && self.view_distance > 0 && self.lod_distance > 0.0);
+        assert!(self.max_loads_per_frame > 0 && self.num_workers > 0);
+
+        let object = StdObjectBuilder::new()
+            .set_color(self.color)
+            .build(object_uniforms, queue);
+
+        // (한국어) 작업 스레드들은 요청 채널을 공유하며, 지형이 버려져 요청 채널이 닫히면 종료됩니다.
+        // (English Translation) The worker threads share the request channel, and exit when the terrain is dropped and the request channel is closed.
+        let (request_sender, request_receiver) = mpsc::channel::<ChunkCoord>();
+        let (result_sender, result_receiver) = mpsc::channel();
+        let request_receiver = Arc::new(Mutex::new(request_receiver));
+        for index in 0..self.num_workers {
+            let params = self;
+            let request_receiver = request_receiver.clone();
+            let result_sender = result_sender.clone();
+            thread::Builder::new()
+                .name(format!("TerrainWorker({})", index))
+                .spawn(move || loop {
+                    let request = match request_receiver.lock() {
+                        Ok(receiver) => receiver.recv(), 
+                        Err(_) => break, 
+                    };
+                    let Ok(coord) = request else {
+                        break;
+                    };
+                    if result_sender.send(generate_chunk(&params, coord)).is_err() {
+                        break;
+                    }
+                })
+                .expect("Failed to spawn a terrain worker thread.");
+        }
+
+        return Terrain {
+            params: self, 
+            object, 
+            chunks: HashMap::new(), 
+            index_buffers: HashMap::new(), 
+            pending: HashSet::new(), 
+            request_sender, 
+            result_receiver, 
+        };
+    }
+}
+
+/// #### 한국어 </br>
+/// 지형 청크의 좌표 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The coordinates of a terrain chunk. </br>
+/// 
+pub type ChunkCoord = (i32, i32);
+
+/// #### 한국어 </br>
+/// 각 변의 이웃 청크 정점 간격을 포함한 인덱스 버퍼의 키 입니다. (본인 간격, [서, 동, 북, 남]) </br>
+/// 
+/// #### English (Translation) </br>
+/// The key of an index buffer including the vertex step of the neighbor chunk on each side. (own step, [west, east, north, south]) </br>
+/// 
+type IndexKey = (u32, [u32; 4]);
+
+#[derive(Debug)]
+struct TerrainChunk {
+    aabb: Aabb, 
+    lod: u32, 
+    index_key: IndexKey, 
+    vertex_buffer: wgpu::Buffer, 
+}
+
+/// #### 한국어 </br>
+/// 작업 스레드가 생성한, 아직 GPU로 올리지 않은 청크 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A chunk generated by a worker thread that has not been uploaded to the GPU yet. </br>
+/// 
+#[derive(Debug)]
+struct GeneratedChunk {
+    coord: ChunkCoord, 
+    aabb: Aabb, 
+    vertices: Vec<ObjectVertexLayout>, 
+}
+
+/// #### 한국어 </br>
+/// 카메라 주변의 청크들을 불러오고 청크마다 세부 수준(LOD)을 선택하는 지형 입니다. </br>
+/// 이웃보다 세밀한 청크는 맞닿은 변의 정점들을 이웃의 정점 간격에 맞추어 틈이 생기지 않도록 이어붙이며, 
+/// 그리기 전에 절두체 밖의 청크들을 걸러냅니다. </br>
+/// 청크의 정점들은 작업 스레드들에서 생성되고, 렌더링 스레드는 완성된 청크들을 GPU로 올리기만 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A terrain that streams the chunks around the camera and selects a level of detail (LOD) per chunk. </br>
+/// A chunk finer than its neighbor snaps the vertices of the shared side to the vertex step of the neighbor, 
+/// stitching them without cracks, and chunks outside the frustum are culled before drawing. </br>
+/// The vertices of the chunks are generated on worker threads, and the rendering thread only uploads the finished chunks to the GPU. </br>
+/// 
+#[derive(Debug)]
+pub struct Terrain {
+    params: TerrainBuilder, 
+    object: StdObject, 
+    chunks: HashMap<ChunkCoord, TerrainChunk>, 
+    index_buffers: HashMap<IndexKey, (wgpu::Buffer, u32)>, 
+    pending: HashSet<ChunkCoord>, 
+    request_sender: mpsc::Sender<ChunkCoord>, 
+    result_receiver: mpsc::Receiver<GeneratedChunk>, 
+}
+
+#[allow(dead_code)]
+impl Terrain {
+    #[inline]
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 좌표 (x, z)에서의 지형 높이를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the height of the terrain at world coordinates (x, z). </br>
+    /// 
+    #[inline]
+    pub fn height_at(&self, x: f32, z: f32) -> f32 {
+        terrain_height(&self.params, x, z)
+    }
+
+    /// #### 한국어 </br>
+    /// 작업 스레드에 요청되었지만 아직 GPU로 올라가지 않은 청크의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of chunks requested from the worker threads that have not been uploaded to the GPU yet. </br>
+    /// 
+    #[inline]
+    pub fn num_pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라 위치를 기준으로 청크들을 불러오거나 버리고, 청크마다 세부 수준을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Streams chunks in or out based on the camera position, and updates the level of detail per chunk. </br>
+    /// 
+    pub fn update(&mut self, camera_position: glam::Vec3, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let center = self.chunk_coord_at(camera_position);
+        let view_distance = self.params.view_distance;
+
+        // (한국어) 시야 거리보다 멀어진 청크들을 버립니다.
+        // (English Translation) Drops the chunks that are farther than the view distance.
+        self.chunks.retain(|&(x, z), _| {
+            (x - center.0).abs() <= view_distance + 1 && (z - center.1).abs() <= view_distance + 1
+        });
+
+        // (한국어) 작업 스레드들이 끝낸 청크들을 프레임마다 정해진 수만큼 올립니다. 그 사이 시야 밖으로 나간 청크는 버립니다.
+        // (English Translation) Uploads a fixed number of the chunks finished by the worker threads per frame. Chunks that left the view in the meantime are dropped.
+        for generated in self.result_receiver.try_iter().take(self.params.max_loads_per_frame) {
+            self.pending.remove(&generated.coord);
+            if chunk_distance(generated.coord, center) <= view_distance as f32 {
+                let chunk = upload_chunk(generated.aabb, &generated.vertices, device, queue);
+                self.chunks.insert(generated.coord, chunk);
+            }
+        }
+
+        // (한국어) 가까운 청크부터 작업 스레드들에 요청합니다. 카메라가 움직이면 순서가 바뀌므로 한 번에 요청하는 수를 제한합니다.
+        // (English Translation) Requests chunks from the worker threads, nearest first. The order changes as the camera moves, so the number of requests in flight is limited.
+        let mut missing: Vec<ChunkCoord> = (-view_distance..=view_distance)
+            .flat_map(|dz| (-view_distance..=view_distance).map(move |dx| (center.0 + dx, center.1 + dz)))
+            .filter(|coord| chunk_distance(*coord, center) <= view_distance as f32)
+            .filter(|coord| !self.chunks.contains_key(coord) && !self.pending.contains(coord))
+            .collect();
+        missing.sort_by(|a, b| chunk_distance(*a, center).total_cmp(&chunk_distance(*b, center)));
+        let max_pending = 2 * self.params.num_workers.max(self.params.max_loads_per_frame);
+        for coord in missing.into_iter().take(max_pending.saturating_sub(self.pending.len())) {
+            if self.request_sender.send(coord).is_ok() {
+                self.pending.insert(coord);
+            }
+        }
+
+        // (한국어) 카메라와의 거리로 세부 수준을 선택합니다.
+        // (English Translation) Selects the level of detail by the distance to the camera.
+        for (coord, chunk) in self.chunks.iter_mut() {
+            let lod = (chunk_distance(*coord, center) / self.params.lod_distance) as u32;
+            chunk.lod = lod.min(self.params.max_lod);
+        }
+
+        // (한국어) 이웃 청크들의 세부 수준에 맞추어 이어붙인 인덱스 버퍼를 선택합니다.
+        // (English Translation) Selects the stitched index buffer matching the level of detail of the neighbor chunks.
+        let lods: HashMap<ChunkCoord, u32> = self.chunks.iter().map(|(coord, chunk)| (*coord, chunk.lod)).collect();
+        let mut keys = Vec::with_capacity(self.chunks.len());
+        for (&(x, z), chunk) in self.chunks.iter() {
+            let step = 1 << chunk.lod;
+            let neighbor_step = |coord: ChunkCoord| lods.get(&coord).map_or(step, |lod| (1 << *lod).max(step));
+            let key = (step, [
+                neighbor_step((x - 1, z)), 
+                neighbor_step((x + 1, z)), 
+                neighbor_step((x, z - 1)), 
+                neighbor_step((x, z + 1)), 
+            ]);
+            keys.push(((x, z), key));
+        }
+        for (coord, key) in keys {
+            if !self.index_buffers.contains_key(&key) {
+                let buffer = create_index_buffer(self.params.resolution, key, device, queue);
+                self.index_buffers.insert(key, buffer);
+            }
+            self.chunks.get_mut(&coord).unwrap().index_key = key;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 절두체 안의 청크들을 그리고 그려진 청크의 수를 반환합니다. </br>
+    /// 0, 2, 3번 바인드 그룹은 미리 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the chunks inside the frustum and returns the number of chunks drawn. </br>
+    /// Bind groups 0, 2 and 3 must be set in advance. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, frustum: Option<&Frustum>) -> usize {
+        let mut num_drawn = 0;
+        self.object.bind(rpass, 1);
+        for chunk in self.chunks.values() {
+            if frustum.is_some_and(|frustum| !frustum.intersects_aabb(&chunk.aabb)) {
+                continue;
+            }
+
+            let Some((index_buffer, num_indices)) = self.index_buffers.get(&chunk.index_key) else {
+                continue;
+            };
+            rpass.set_vertex_buffer(0, chunk.vertex_buffer.slice(..));
+            rpass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            stats::draw_indexed(rpass, 0..*num_indices, 0, 0..1);
+            num_drawn += 1;
+        }
+        return num_drawn;
+    }
+
+    #[inline]
+    fn chunk_coord_at(&self, position: glam::Vec3) -> ChunkCoord {
+        (
+            (position.x / self.params.chunk_size).floor() as i32, 
+            (position.z / self.params.chunk_size).floor() as i32, 
+        )
+    }
+}
+
+/// #### 한국어 </br>
+/// 월드 좌표 (x, z)에서의 지형 높이를 계산합니다. 작업 스레드들도 사용하므로 지형의 설정 값만으로 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Computes the height of the terrain at world coordinates (x, z). It is also used by the worker threads, so it is computed from the terrain settings alone. </br>
+/// 
+fn terrain_height(params: &TerrainBuilder, x: f32, z: f32) -> f32 {
+    let mut amplitude = 1.0;
+    let mut frequency = params.feature_scale;
+    let mut height = 0.0;
+    for _ in 0..5 {
+        height += amplitude * value_noise(x * frequency, z * frequency);
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+
+    // (한국어) 원래 장면이 놓인 가운데 부분은 평평하게 유지합니다.
+    // (English Translation) Keeps the center where the original scene sits flat.
+    let distance = (x * x + z * z).sqrt();
+    let t = ((distance - params.flat_radius) / params.flat_radius).clamp(0.0, 1.0);
+    let flatten = t * t * (3.0 - 2.0 * t);
+    return height * params.height_scale * flatten;
+}
+
+/// #### 한국어 </br>
+/// 작업 스레드에서 청크의 정점들과 경계 상자를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Generates the vertices and the bounding box of a chunk on a worker thread. </br>
+/// 
+fn generate_chunk(params: &TerrainBuilder, coord: ChunkCoord) -> GeneratedChunk {
+    let resolution = params.resolution;
+    let cell_size = params.chunk_size / resolution as f32;
+    let origin = glam::vec2(coord.0 as f32, coord.1 as f32) * params.chunk_size;
+    let height_at = |x: f32, z: f32| terrain_height(params, x, z);
+
+    let mut min_height = f32::MAX;
+    let mut max_height = f32::MIN;
+    let mut vertices = Vec::with_capacity(((resolution + 1) * (resolution + 1)) as usize);
+    for j in 0..=resolution {
+        for i in 0..=resolution {
+            let x = origin.x + i as f32 * cell_size;
+            let z = origin.y + j as f32 * cell_size;
+            let y = height_at(x, z);
+            min_height = min_height.min(y);
+            max_height = max_height.max(y);
+
+            let dx = height_at(x + cell_size, z) - height_at(x - cell_size, z);
+            let dz = height_at(x, z + cell_size) - height_at(x, z - cell_size);
+            let normal = glam::vec3(-dx, 2.0 * cell_size, -dz).normalize();
+            let tangent = glam::vec3(2.0 * cell_size, dx, 0.0).normalize().extend(-1.0);
+            // (한국어) 청크 사이의 이음매가 보이지 않도록 텍스처 좌표는 월드 공간의 XZ 좌표를 사용합니다.
+            // (English Translation) The texture coordinates use the world space XZ coordinates so that the seams between chunks are not visible.
+            vertices.push(ObjectVertexLayout { position: (x, y, z).into(), normal, texcoord: (x, z).into(), tangent });
+        }
+    }
+
+    GeneratedChunk {
+        coord, 
+        aabb: Aabb::new(
+            (origin.x, min_height, origin.y).into(), 
+            (origin.x + params.chunk_size, max_height, origin.y + params.chunk_size).into()
+        ), 
+        vertices, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 생성된 청크의 정점들을 GPU로 올립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Uploads the vertices of a generated chunk to the GPU. </br>
+/// 
+fn upload_chunk(aabb: Aabb, vertices: &[ObjectVertexLayout], device: &wgpu::Device, queue: &wgpu::Queue) -> TerrainChunk {
+    let vertex_buffer = stats::track_buffer(device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some("Vertex(TerrainChunk)"), 
+            mapped_at_creation: false, 
+            size: mem::size_of_val(vertices) as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    ));
+    queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(vertices));
+
+    TerrainChunk {
+        aabb, 
+        lod: 0, 
+        index_key: (1, [1; 4]), 
+        vertex_buffer, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 높이 격자로부터 생성되는 하나의 지형 메쉬 입니다. </br>
+/// 격자의 각 값이 하나의 정점이 되며, 법선과 접선은 이웃한 높이들의 중앙 차분으로 계산되어 매끄럽게 이어집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A single terrain mesh created from a grid of heights. </br>
+/// Each value of the grid becomes a vertex, and the normals and tangents are computed from central differences of the neighboring heights so they blend smoothly. </br>
+/// 
+#[derive(Debug)]
+pub struct TerrainMesh {
+    aabb: Aabb, 
+    bounding_sphere: Sphere, 
+    columns: u32, 
+    rows: u32, 
+    submeshes: [SubMesh; 1], 
+    index_buffer: IndexBuffer, 
+    vertex_buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl TerrainMesh {
+    /// #### 한국어 </br>
+    /// 회색조 이미지의 밝기를 높이로 사용하는 지형 메쉬를 생성합니다. </br>
+    /// 메쉬는 원점을 중심으로 xz 평면에서 `size` 만큼 펼쳐지며, 가장 밝은 픽셀의 높이는 `height_scale` 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a terrain mesh that uses the brightness of a grayscale image as the height. </br>
+    /// The mesh spans `size` on the xz plane centered at the origin, and the height of the brightest pixel is `height_scale`. </br>
+    /// 
+    pub fn from_heightmap<P: AsRef<Path>>(
+        path: P, 
+        size: glam::Vec2, 
+        height_scale: f32, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> image::ImageResult<Self> {
+        let image = image::open(path)?.to_luma16();
+        let heights: Vec<f32> = image.pixels().map(|pixel| pixel.0[0] as f32 / u16::MAX as f32).collect();
+        Ok(Self::from_heights(image.width(), image.height(), &heights, size, height_scale, device, queue))
+    }
+
+    /// #### 한국어 </br>
+    /// 행 우선 순서로 놓인 `columns` x `rows` 높이 격자로 지형 메쉬를 생성합니다. 높이에는 `height_scale`이 곱해집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a terrain mesh from a `columns` x `rows` grid of heights in row-major order. The heights are multiplied by `height_scale`. </br>
+    /// 
+    pub fn from_heights(
+        columns: u32, 
+        rows: u32, 
+        heights: &[f32], 
+        size: glam::Vec2, 
+        height_scale: f32, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(columns >= 2 && rows >= 2 && size.x > 0.0 && size.y > 0.0);
+        assert_eq!(heights.len(), (columns * rows) as usize);
+
+        let spacing = glam::vec2(size.x / (columns - 1) as f32, size.y / (rows - 1) as f32);
+        let height = |i: u32, j: u32| heights[(j * columns + i) as usize] * height_scale;
+
+        let mut vertices = Vec::with_capacity((columns * rows) as usize);
+        for j in 0..rows {
+            for i in 0..columns {
+                // (한국어) 가장자리에서는 격자 밖의 이웃 대신 자기 자신을 사용하여 한쪽 차분이 됩니다.
+                // (English Translation) At the edges the vertex itself is used instead of the neighbor outside the grid, becoming a one-sided difference.
+                let (left, right) = (i.saturating_sub(1), (i + 1).min(columns - 1));
+                let (back, front) = (j.saturating_sub(1), (j + 1).min(rows - 1));
+                let dx = (height(right, j) - height(left, j)) / ((right - left) as f32 * spacing.x);
+                let dz = (height(i, front) - height(i, back)) / ((front - back) as f32 * spacing.y);
+                let position = glam::vec3(i as f32 * spacing.x - 0.5 * size.x, height(i, j), j as f32 * spacing.y - 0.5 * size.y);
+                let normal = glam::vec3(-dx, 1.0, -dz).normalize();
+                let tangent = glam::vec3(1.0, dx, 0.0).normalize().extend(-1.0);
+                let texcoord = glam::vec2(i as f32 / (columns - 1) as f32, j as f32 / (rows - 1) as f32);
+                vertices.push(ObjectVertexLayout { position, normal, texcoord, tangent });
+            }
+        }
+
+        let positions: Vec<_> = vertices.iter().map(|vertex| vertex.position).collect();
+        let vertex_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Vertex(TerrainMesh)"), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<ObjectVertexLayout>() * vertices.len()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+        queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+
+        let mut indices = Vec::with_capacity((6 * (columns - 1) * (rows - 1)) as usize);
+        for j in 0..rows - 1 {
+            for i in 0..columns - 1 {
+                let i0 = j * columns + i;
+                let i1 = i0 + 1;
+                let i2 = i0 + columns;
+                let i3 = i2 + 1;
+                indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
+            }
+        }
+        let indices = IndexData::from_u32(indices);
+        let index_buffer = IndexBuffer::new("TerrainMesh", &indices, device, queue);
+
+        Self {
+            aabb: Aabb::from_points(&positions), 
+            bounding_sphere: Sphere::from_points(&positions), 
+            columns, 
+            rows, 
+            submeshes: [SubMesh::new(0..indices.len() as u32)], 
+            index_buffer, 
+            vertex_buffer
+        }
+    }
+
+    #[inline]
+    pub fn columns(&self) -> u32 {
+        self.columns
+    }
+
+    #[inline]
+    pub fn rows(&self) -> u32 {
+        self.rows
+    }
+}
+
+impl ModelMesh for TerrainMesh {
+    #[inline]
+    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        self.index_buffer.bind(rpass);
+    }
+
+    #[inline]
+    fn submeshes(&self) -> &[SubMesh] {
+        &self.submeshes
+    }
+
+    #[inline]
+    fn draw_submesh_instanced<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, submesh: &SubMesh, instances: Range<u32>) {
+        stats::draw_indexed(rpass, submesh.range.clone(), submesh.base_vertex, instances);
+    }
+
+    #[inline]
+    fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    #[inline]
+    fn bounding_sphere(&self) -> Sphere {
+        self.bounding_sphere
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 정점 간격으로 청크의 인덱스 버퍼를 생성합니다. </br>
+/// 이웃의 간격이 더 큰 변에서는 정점을 이웃의 간격에 맞추어 내림하여, 
+/// 이웃 청크에 없는 정점이 퇴화된 삼각형으로 사라지도록 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the index buffer of a chunk with the given vertex step. </br>
+/// On sides where the neighbor's step is larger, vertices are rounded down to the neighbor's step, 
+/// so vertices that do not exist in the neighbor chunk vanish into degenerate triangles. </br>
+/// 
+fn create_index_buffer(
+    resolution: u32, 
+    (step, [west, east, north, south]): IndexKey, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue
+) -> (wgpu::Buffer, u32) {
+    let index_of = |i: u32, j: u32| {
+        let (mut i, mut j) = (i, j);
+        if i == 0 { j = j / west * west; }
+        if i == resolution { j = j / east * east; }
+        if j == 0 { i = i / north * north; }
+        if j == resolution { i = i / south * south; }
+        j * (resolution + 1) + i
+    };
+
+    let mut indices = Vec::new();
+    for j in (0..resolution).step_by(step as usize) {
+        for i in (0..resolution).step_by(step as usize) {
+            let i0 = index_of(i, j);
+            let i1 = index_of(i + step, j);
+            let i2 = index_of(i, j + step);
+            let i3 = index_of(i + step, j + step);
+            indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
+        }
+    }
+
+    let buffer = stats::track_buffer(device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some("Index(TerrainChunk)"), 
+            mapped_at_creation: false, 
+            size: mem::size_of_val(indices.as_slice()) as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    ));
+    queue.write_buffer(&buffer, 0, bytemuck::cast_slice(&indices));
+
+    (buffer, indices.len() as u32)
+}
+
+#[inline]
+fn chunk_distance(a: ChunkCoord, b: ChunkCoord) -> f32 {
+    let dx = (a.0 - b.0) as f32;
+    let dz = (a.1 - b.1) as f32;
+    (dx * dx + dz * dz).sqrt()
+}
+
+#[inline]
+fn hash(x: i32, z: i32) -> f32 {
+    let mut h = (x as u32).wrapping_mul(0x27D4_EB2D) ^ (z as u32).wrapping_mul(0x1656_67B1);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2C1B_3C6D);
+    h ^= h >> 12;
+    (h & 0xFFFF) as f32 / 65535.0 * 2.0 - 1.0
+}
+
+fn value_noise(x: f32, z: f32) -> f32 {
+    let (xi, zi) = (x.floor(), z.floor());
+    let (xf, zf) = (x - xi, z - zi);
+    let (u, v) = (xf * xf * (3.0 - 2.0 * xf), zf * zf * (3.0 - 2.0 * zf));
+    let (xi, zi) = (xi as i32, zi as i32);
+    let a = hash(xi, zi);
+    let b = hash(xi + 1, zi);
+    let c = hash(xi, zi + 1);
+    let d = hash(xi + 1, zi + 1);
+    a + (b - a) * u + (c - a) * v + (a - b - c + d) * u * v
+}
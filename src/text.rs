@@ -0,0 +1,409 @@
+use std::mem;
+use std::collections::HashMap;
+use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+use bytemuck::{Pod, Zeroable};
+
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 기본으로 사용하는 글꼴 데이터 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the default font data. </br>
+/// 
+pub const DEFAULT_FONT: &[u8] = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fonts/DejaVuSansMono.ttf"));
+
+/// #### 한국어 </br>
+/// 글리프 아틀라스의 너비와 높이 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Width and height of the glyph atlas. </br>
+/// 
+const ATLAS_SIZE: u32 = 512;
+
+/// #### 한국어 </br>
+/// 아틀라스에 저장된 글리프 하나의 정보 입니다. </br>
+/// 위치와 크기는 아틀라스를 만들 때 사용한 픽셀 크기를 기준으로 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Information about one glyph stored in the atlas. </br>
+/// Positions and sizes are relative to the pixel size used to build the atlas. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GlyphInfo {
+    uv_min: glam::Vec2, 
+    uv_max: glam::Vec2, 
+    offset: glam::Vec2, 
+    size: glam::Vec2, 
+    advance: f32, 
+}
+
+/// #### 한국어 </br>
+/// 화면 공간에 글자를 그리는 텍스트 렌더러 입니다. </br>
+/// `draw_text`로 글자들을 모은 뒤, `prepare`로 정점 데이터를 올리고 `draw`로 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A text renderer that draws characters in screen space. </br>
+/// Collect characters with `draw_text`, upload the vertex data with `prepare`, and draw with `draw`. </br>
+/// 
+#[derive(Debug)]
+pub struct TextRenderer {
+    glyphs: HashMap<char, GlyphInfo>, 
+    atlas_px: f32, 
+    line_height: f32, 
+    screen_size: glam::Vec2, 
+    vertices: Vec<TextVertexLayout>, 
+    num_vertices: u32, 
+    vertex_buffer: wgpu::Buffer, 
+    uniform_buffer: wgpu::Buffer, 
+    pub bind_group: wgpu::BindGroup, 
+}
+
+impl TextRenderer {
+    pub fn new(
+        font_data: &[u8], 
+        atlas_px: f32, 
+        width: u32, 
+        height: u32, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(atlas_px > 0.0);
+
+        let font = FontRef::try_from_slice(font_data).expect("Invalid font data.");
+        let scale = PxScale::from(atlas_px);
+        let scaled_font = font.as_scaled(scale);
+
+        // (한국어) 출력 가능한 ASCII 문자들을 아틀라스에 한 줄씩 채워 넣습니다.
+        // (English Translation) Packs the printable ASCII characters into the atlas row by row.
+        let mut pixels = vec![0u8; (ATLAS_SIZE * ATLAS_SIZE) as usize];
+        let mut glyphs = HashMap::new();
+        let (mut cursor_x, mut cursor_y, mut row_height) = (1u32, 1u32, 0u32);
+        for ch in (32u8..127).map(char::from) {
+            let glyph_id = font.glyph_id(ch);
+            let advance = scaled_font.h_advance(glyph_id);
+            let glyph = glyph_id.with_scale_and_position(scale, ab_glyph::point(0.0, scaled_font.ascent()));
+
+            let mut info = GlyphInfo {
+                uv_min: glam::Vec2::ZERO, 
+                uv_max: glam::Vec2::ZERO, 
+                offset: glam::Vec2::ZERO, 
+                size: glam::Vec2::ZERO, 
+                advance, 
+            };
+
+            if let Some(outlined) = font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                let w = bounds.width().ceil() as u32;
+                let h = bounds.height().ceil() as u32;
+                if cursor_x + w + 1 > ATLAS_SIZE {
+                    cursor_x = 1;
+                    cursor_y += row_height + 1;
+                    row_height = 0;
+                }
+                assert!(cursor_y + h < ATLAS_SIZE, "The glyph atlas is too small.");
+
+                let (origin_x, origin_y) = (cursor_x, cursor_y);
+                outlined.draw(|x, y, coverage| {
+                    if x < w && y < h {
+                        let index = ((origin_y + y) * ATLAS_SIZE + origin_x + x) as usize;
+                        pixels[index] = (coverage.clamp(0.0, 1.0) * 255.0) as u8;
+                    }
+                });
+
+                info.uv_min = glam::vec2(origin_x as f32, origin_y as f32) / ATLAS_SIZE as f32;
+                info.uv_max = glam::vec2((origin_x + w) as f32, (origin_y + h) as f32) / ATLAS_SIZE as f32;
+                info.offset = glam::vec2(bounds.min.x, bounds.min.y);
+                info.size = glam::vec2(w as f32, h as f32);
+
+                cursor_x += w + 1;
+                row_height = row_height.max(h);
+            }
+
+            glyphs.insert(ch, info);
+        }
+
+        let atlas_texture = stats::track_texture(device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(GlyphAtlas)"), 
+                size: wgpu::Extent3d {
+                    width: ATLAS_SIZE, 
+                    height: ATLAS_SIZE, 
+                    depth_or_array_layers: 1, 
+                }, 
+                dimension: wgpu::TextureDimension::D2, 
+                format: wgpu::TextureFormat::R8Unorm, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        ));
+        queue.write_texture(
+            atlas_texture.as_image_copy(), 
+            &pixels, 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(ATLAS_SIZE), 
+                rows_per_image: Some(ATLAS_SIZE), 
+            }, 
+            atlas_texture.size(), 
+        );
+        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        });
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(GlyphAtlas)"), 
+                address_mode_u: wgpu::AddressMode::ClampToEdge, 
+                address_mode_v: wgpu::AddressMode::ClampToEdge, 
+                address_mode_w: wgpu::AddressMode::ClampToEdge, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                mipmap_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(TextRenderer)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ScreenUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(TextRenderer)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::TextureView(&atlas_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let vertex_buffer = create_vertex_buffer(1024, device);
+
+        let mut text_renderer = Self {
+            glyphs, 
+            atlas_px, 
+            line_height: scaled_font.height() + scaled_font.line_gap(), 
+            screen_size: glam::Vec2::ONE, 
+            vertices: Vec::new(), 
+            num_vertices: 0, 
+            vertex_buffer, 
+            uniform_buffer, 
+            bind_group, 
+        };
+        text_renderer.resize(width, height, queue);
+
+        return text_renderer;
+    }
+
+    /// #### 한국어 </br>
+    /// 화면의 크기가 변경되었을 때 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Must be called when the size of the screen changes. </br>
+    /// 
+    pub fn resize(&mut self, width: u32, height: u32, queue: &wgpu::Queue) {
+        self.screen_size = glam::vec2(width.max(1) as f32, height.max(1) as f32);
+        let data = ScreenUniformLayout {
+            screen_size: glam::vec4(
+                self.screen_size.x, 
+                self.screen_size.y, 
+                self.screen_size.x.recip(), 
+                self.screen_size.y.recip()
+            ), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 화면의 크기를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the current size of the screen. </br>
+    /// 
+    #[inline]
+    pub fn screen_size(&self) -> glam::Vec2 {
+        self.screen_size
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 크기의 글자로 문자열을 그렸을 때의 너비와 높이를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the width and height of the string drawn with characters of the given size. </br>
+    /// 
+    pub fn measure_text(&self, text: &str, size: f32) -> glam::Vec2 {
+        let ratio = size / self.atlas_px;
+        let (mut width, mut line_width, mut lines) = (0.0f32, 0.0f32, 1u32);
+        for ch in text.chars() {
+            if ch == '\n' {
+                width = width.max(line_width);
+                line_width = 0.0;
+                lines += 1;
+                continue;
+            }
+            if let Some(info) = self.glyphs.get(&ch).or_else(|| self.glyphs.get(&'?')) {
+                line_width += info.advance * ratio;
+            }
+        }
+        glam::vec2(width.max(line_width), lines as f32 * self.line_height * ratio)
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 공간의 `position`(좌측 상단 기준, 픽셀 단위)에 문자열을 그립니다. </br>
+    /// `size`는 글자의 픽셀 높이 입니다. 아틀라스에 없는 문자는 `?`로 그려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a string at `position` in screen space (top-left origin, in pixels). </br>
+    /// `size` is the pixel height of the characters. Characters not in the atlas are drawn as `?`. </br>
+    /// 
+    pub fn draw_text(&mut self, position: glam::Vec2, text: &str, size: f32, color: glam::Vec4) {
+        let ratio = size / self.atlas_px;
+        let mut pen = position;
+        for ch in text.chars() {
+            if ch == '\n' {
+                pen.x = position.x;
+                pen.y += self.line_height * ratio;
+                continue;
+            }
+
+            let info = match self.glyphs.get(&ch).or_else(|| self.glyphs.get(&'?')) {
+                Some(info) => *info, 
+                None => continue, 
+            };
+
+            if info.size.x > 0.0 && info.size.y > 0.0 {
+                let min = pen + info.offset * ratio;
+                let max = min + info.size * ratio;
+                let quad = [
+                    TextVertexLayout { position: glam::vec2(min.x, min.y), texcoord: glam::vec2(info.uv_min.x, info.uv_min.y), color }, 
+                    TextVertexLayout { position: glam::vec2(min.x, max.y), texcoord: glam::vec2(info.uv_min.x, info.uv_max.y), color }, 
+                    TextVertexLayout { position: glam::vec2(max.x, max.y), texcoord: glam::vec2(info.uv_max.x, info.uv_max.y), color }, 
+                    TextVertexLayout { position: glam::vec2(max.x, min.y), texcoord: glam::vec2(info.uv_max.x, info.uv_min.y), color }, 
+                ];
+                self.vertices.extend_from_slice(&[quad[0], quad[1], quad[2], quad[2], quad[3], quad[0]]);
+            }
+
+            pen.x += info.advance * ratio;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 모은 글자들의 정점 데이터를 GPU에 올리고, 대기열을 비웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uploads the vertex data of the characters collected this frame to the GPU and clears the queue. </br>
+    /// 
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let required = (mem::size_of::<TextVertexLayout>() * self.vertices.len()) as wgpu::BufferAddress;
+        if required > self.vertex_buffer.size() {
+            let capacity = self.vertices.len().next_power_of_two();
+            self.vertex_buffer = create_vertex_buffer(capacity, device);
+        }
+
+        if !self.vertices.is_empty() {
+            queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+        self.num_vertices = self.vertices.len() as u32;
+        self.vertices.clear();
+    }
+
+    /// #### 한국어 </br>
+    /// 준비된 글자들을 그립니다. 텍스트 파이프라인이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the prepared characters. The text pipeline must be set. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if self.num_vertices > 0 {
+            stats::set_bind_group(rpass, 0, &self.bind_group, &[]);
+            rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            stats::draw(rpass, 0..self.num_vertices, 0..1);
+        }
+    }
+}
+
+#[inline]
+fn create_vertex_buffer(capacity: usize, device: &wgpu::Device) -> wgpu::Buffer {
+    stats::track_buffer(device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some("Vertex(TextRenderer)"), 
+            mapped_at_creation: false, 
+            size: (mem::size_of::<TextVertexLayout>() * capacity) as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    ))
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 화면 유니폼 데이터의 레이아웃 입니다. </br>
+/// `screen_size`의 `xy`는 화면의 크기, `zw`는 그 역수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the screen uniform data used in the shader. </br>
+/// `xy` of `screen_size` is the size of the screen and `zw` is its reciprocal. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenUniformLayout {
+    pub screen_size: glam::Vec4, 
+}
+
+impl Default for ScreenUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            screen_size: glam::Vec4::ONE, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 텍스트 버텍스 입력 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the text vertex input data used in the shader. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextVertexLayout {
+    pub position: glam::Vec2, 
+    pub texcoord: glam::Vec2, 
+    pub color: glam::Vec4, 
+}
+
+impl Default for TextVertexLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            position: glam::Vec2::ZERO, 
+            texcoord: glam::Vec2::ZERO, 
+            color: glam::Vec4::ONE, 
+        }
+    }
+}
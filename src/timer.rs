@@ -1,7 +1,141 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
 use std::time::Instant;
 
 
 
+/// #### 한국어 </br>
+/// 고정 갱신의 초당 횟수를 정하는 명령줄 인수 입니다. 뒤에 1에서 1000 사이의 정수가 옵니다. (예: `--tick-rate 30`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that decides the number of fixed updates per second. It is followed by an integer between 1 and 1000. (e.g. `--tick-rate 30`) </br>
+/// 
+pub const TICK_RATE_ARG: &str = "--tick-rate";
+
+/// #### 한국어 </br>
+/// 명령줄 인수가 없을 때 사용하는 고정 갱신의 초당 횟수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of fixed updates per second used when the command line argument is not given. </br>
+/// 
+pub const DEFAULT_TICK_RATE: u32 = 60;
+
+/// #### 한국어 </br>
+/// `TICK_RATE_ARG` 명령줄 인수의 값을 고정 갱신의 초당 횟수로 해석합니다. 1에서 1000 사이의 정수가 아니면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `TICK_RATE_ARG` command line argument as the number of fixed updates per second. Returns an `InvalidInput` error if it is not an integer between 1 and 1000. </br>
+/// 
+pub fn parse_tick_rate(value: &str) -> io::Result<u32> {
+    match value.parse::<u32>() {
+        Ok(tick_rate) if (1..=1000).contains(&tick_rate) => Ok(tick_rate), 
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!("{} must be followed by an integer between 1 and 1000", TICK_RATE_ARG)
+        )), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 프레임 시간 기록을 내보내는 CSV 파일의 기본 경로 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default path of the CSV file the frame time history is exported to. </br>
+/// 
+pub const FRAME_TIMES_CSV_PATH: &str = "frame_times.csv";
+
+/// #### 한국어 </br>
+/// 오름차순으로 정렬된 값들의 `p` 백분위수(0 ~ 100)를 최근접 순위 방법으로 반환합니다. 값이 없으면 0을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the `p` percentile (0 to 100) of the values sorted in ascending order with the nearest-rank method. Returns 0 if there are no values. </br>
+/// 
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0, 
+        len => sorted[((p / 100.0 * len as f64).ceil() as usize).clamp(1, len) - 1], 
+    }
+}
+
+/// #### 한국어 </br>
+/// 렌더링 속도와 상관없이 게임 로직을 일정한 간격으로 진행하기 위해 프레임 시간을 모으는 누산기 입니다. </br>
+/// 모인 시간이 한 간격을 넘을 때마다 고정 갱신을 한 번 실행하고, 남은 시간의 비율로 두 갱신 사이를 보간합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An accumulator collecting the frame time to advance the game logic at a constant interval regardless of the render rate. </br>
+/// Runs a fixed update each time the collected time exceeds one interval, and interpolates between two updates by the ratio of the remaining time. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedTimestep {
+    step_sec: f32, 
+    accumulated_sec: f32, 
+    max_steps_per_frame: u32, 
+}
+
+#[allow(dead_code)]
+impl FixedTimestep {
+    #[inline]
+    pub fn new(tick_rate: u32) -> Self {
+        assert!(tick_rate > 0);
+        Self {
+            step_sec: 1.0 / tick_rate as f32, 
+            accumulated_sec: 0.0, 
+            max_steps_per_frame: 8, 
+        }
+    }
+
+    #[inline]
+    pub fn step_sec(&self) -> f32 {
+        self.step_sec
+    }
+
+    #[inline]
+    pub fn tick_rate(&self) -> u32 {
+        (1.0 / self.step_sec).round() as u32
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임에 실행할 수 있는 최대 고정 갱신 수를 설정합니다. 느린 프레임 뒤에 갱신이 계속 밀리는 것을 막습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the maximum number of fixed updates that can run in a frame. It keeps the updates from piling up after a slow frame. </br>
+    /// 
+    #[inline]
+    pub fn set_max_steps_per_frame(&mut self, max_steps_per_frame: u32) {
+        self.max_steps_per_frame = max_steps_per_frame.max(1);
+    }
+
+    /// #### 한국어 </br>
+    /// 지난 프레임의 시간을 모으고, 이번 프레임에 실행할 고정 갱신 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Accumulates the time of the last frame and returns the number of fixed updates to run in this frame. </br>
+    /// 
+    pub fn advance(&mut self, elapsed_time_sec: f32) -> u32 {
+        self.accumulated_sec += elapsed_time_sec.max(0.0);
+        let mut steps = 0;
+        while self.accumulated_sec >= self.step_sec && steps < self.max_steps_per_frame {
+            self.accumulated_sec -= self.step_sec;
+            steps += 1;
+        }
+        self.accumulated_sec = self.accumulated_sec.min(self.step_sec);
+        return steps;
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 고정 갱신 이후 지난 시간의 한 간격에 대한 비율(0 ~ 1)을 반환합니다. 이전 상태와 현재 상태를 보간하는 데 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the ratio (0 to 1) of the time since the last fixed update to one interval. Used to interpolate between the previous and the current state. </br>
+    /// 
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        (self.accumulated_sec / self.step_sec).clamp(0.0, 1.0)
+    }
+}
+
 /// #### 한국어 </br>
 /// `tick`함수를 호출할 때 까지의 걸린 시간을 측정합니다. </br>
 /// 
@@ -20,6 +154,17 @@ pub struct GameTimer<const NUM_SAMPLE: usize = 50> {
     fps_elapsed_time_sec: f64, 
     frame_per_seconds: u64, 
     frame_rate: u64, 
+
+    paused: bool, 
+    time_scale: f32, 
+    total_time_sec: f64, 
+}
+
+impl<const NUM_SAMPLES: usize> Default for GameTimer<NUM_SAMPLES> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
@@ -35,6 +180,9 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
             fps_elapsed_time_sec: 0.0, 
             frame_per_seconds: 0, 
             frame_rate: 0,
+            paused: false, 
+            time_scale: 1.0, 
+            total_time_sec: 0.0, 
         }
     }
 
@@ -52,6 +200,10 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
 
         self.previous_timepoint = self.current_timepoint;
 
+        if !self.paused {
+            self.total_time_sec += elapsed_time_sec * self.time_scale as f64;
+        }
+
         if (self.elapsed_time_sec - elapsed_time_sec).abs() < 1.0 {
             self.frame_times.copy_within(0..(NUM_SAMPLES - 1), 1);
             self.frame_times[0] = elapsed_time_sec;
@@ -85,4 +237,222 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
     pub fn frame_rate(&self) -> u32 {
         self.frame_rate as u32
     }
+
+    /// #### 한국어 </br>
+    /// 장면의 시간을 멈춥니다. 프레임 시간과 프레임 속도는 계속 측정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pauses the time of the scene. The frame time and the frame rate keep being measured. </br>
+    /// 
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[inline]
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// #### 한국어 </br>
+    /// 장면의 시간이 흐르는 배율을 설정합니다. 1보다 작으면 느려지고, 크면 빨라집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the rate at which the time of the scene flows. It slows down below 1 and speeds up above 1. </br>
+    /// 
+    #[inline]
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 멈춘 동안을 빼고 배율을 적용하여 흐른 장면의 전체 시간 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The total time of the scene that flowed with the time scale applied, excluding while paused. </br>
+    /// 
+    #[inline]
+    pub fn total_time_sec(&self) -> f64 {
+        self.total_time_sec
+    }
+
+    /// #### 한국어 </br>
+    /// 프레임 시간에 배율을 적용한 장면의 시간을 반환합니다. 멈춘 동안에는 0을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the time of the scene with the time scale applied to the frame time. Returns 0 while paused. </br>
+    /// 
+    #[inline]
+    pub fn scale(&self, elapsed_time_sec: f32) -> f32 {
+        match self.paused {
+            true => 0.0, 
+            false => elapsed_time_sec * self.time_scale, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 최근 프레임들의 CPU 시간을 고리 버퍼에 기록하는 수집기 입니다. </br>
+/// `GameTimer`의 이동 평균은 튀는 프레임을 가리므로, 최소, 최대 값과 백분위수로 요약하거나 CSV 파일로 내보냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A collector recording the CPU time of the recent frames into a ring buffer. </br>
+/// The moving average of `GameTimer` hides the spikes, so it summarizes them with the minimum, maximum and percentiles, or exports them to a CSV file. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct FrameTimeStats {
+    frame_times_ms: Vec<f64>, 
+    capacity: usize, 
+    next: usize, 
+    frame_start: Option<Instant>, 
+}
+
+#[allow(dead_code)]
+impl FrameTimeStats {
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            frame_times_ms: Vec::with_capacity(capacity), 
+            capacity, 
+            next: 0, 
+            frame_start: None, 
+        }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.frame_times_ms.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.frame_times_ms.is_empty()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.frame_times_ms.clear();
+        self.next = 0;
+    }
+
+    #[inline]
+    pub fn begin_frame(&mut self) {
+        self.frame_start = Some(Instant::now());
+    }
+
+    /// #### 한국어 </br>
+    /// `begin_frame` 이후 걸린 시간을 기록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the time taken since `begin_frame`. </br>
+    /// 
+    #[inline]
+    pub fn end_frame(&mut self) {
+        if let Some(frame_start) = self.frame_start.take() {
+            self.record(frame_start.elapsed().as_secs_f64() * 1000.0);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임의 시간을 기록합니다. 버퍼가 가득 차면 가장 오래된 기록을 덮어씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the time of one frame. When the buffer is full, the oldest record is overwritten. </br>
+    /// 
+    pub fn record(&mut self, frame_time_ms: f64) {
+        match self.frame_times_ms.len() < self.capacity {
+            true => self.frame_times_ms.push(frame_time_ms), 
+            false => self.frame_times_ms[self.next] = frame_time_ms, 
+        }
+        self.next = (self.next + 1) % self.capacity;
+    }
+
+    /// #### 한국어 </br>
+    /// 기록된 프레임 시간들을 오래된 것부터 차례로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the recorded frame times in order from the oldest. </br>
+    /// 
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        let (newer, older) = match self.frame_times_ms.len() < self.capacity {
+            true => self.frame_times_ms.split_at(0), 
+            false => self.frame_times_ms.split_at(self.next), 
+        };
+        older.iter().chain(newer.iter()).copied()
+    }
+
+    pub fn summary(&self) -> FrameTimeSummary {
+        let mut sorted: Vec<f64> = self.frame_times_ms.clone();
+        sorted.sort_by(f64::total_cmp);
+        FrameTimeSummary {
+            num_frames: sorted.len(), 
+            min_ms: sorted.first().copied().unwrap_or(0.0), 
+            max_ms: sorted.last().copied().unwrap_or(0.0), 
+            p50_ms: percentile(&sorted, 50.0), 
+            p95_ms: percentile(&sorted, 95.0), 
+            p99_ms: percentile(&sorted, 99.0), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 기록된 프레임 시간들을 오래된 것부터 `frame,cpu_time_ms` 열의 CSV 파일로 내보냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Exports the recorded frame times from the oldest to a CSV file with the `frame,cpu_time_ms` columns. </br>
+    /// 
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "frame,cpu_time_ms")?;
+        for (index, frame_time_ms) in self.iter().enumerate() {
+            writeln!(writer, "{},{:.3}", index, frame_time_ms)?;
+        }
+        writer.flush()
+    }
+}
+
+/// #### 한국어 </br>
+/// 기록된 프레임 시간들의 요약 입니다. 모든 값은 밀리초 단위 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The summary of the recorded frame times. All values are in milliseconds. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FrameTimeSummary {
+    pub num_frames: usize, 
+    pub min_ms: f64, 
+    pub max_ms: f64, 
+    pub p50_ms: f64, 
+    pub p95_ms: f64, 
+    pub p99_ms: f64, 
+}
+
+impl fmt::Display for FrameTimeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f, 
+            "{} frames, min {:.2} ms, p50 {:.2} ms, p95 {:.2} ms, p99 {:.2} ms, max {:.2} ms", 
+            self.num_frames, 
+            self.min_ms, 
+            self.p50_ms, 
+            self.p95_ms, 
+            self.p99_ms, 
+            self.max_ms
+        )
+    }
 }
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+
+
+/// #### 한국어 </br>
+/// 실행 중에 장면의 값들을 조정하는 `egui` 패널 입니다. </br>
+/// 창 이벤트는 이벤트 대기열에서 꺼낸 후 입력 이벤트로 바뀌기 전에 전달되며, 패널이 사용한 이벤트는 카메라와 단축키에 전달되지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An `egui` panel that tweaks the values of the scene at runtime. </br>
+/// Window events are passed to it after being popped from the event queue and before being converted into input events, and the events used by the panel are not passed to the camera and the shortcuts. </br>
+/// 
+pub struct TweakPanel {
+    visible: bool, 
+    context: egui::Context, 
+    state: egui_winit::State, 
+    renderer: egui_wgpu::Renderer, 
+    paint_jobs: Vec<egui::ClippedPrimitive>, 
+    textures_to_free: Vec<egui::TextureId>, 
+    screen: egui_wgpu::ScreenDescriptor, 
+}
+
+#[allow(dead_code)]
+impl TweakPanel {
+    pub fn new(window: &Window, output_format: wgpu::TextureFormat, device: &wgpu::Device) -> Self {
+        let context = egui::Context::default();
+        let state = egui_winit::State::new(
+            context.clone(), 
+            egui::ViewportId::ROOT, 
+            window, 
+            Some(window.scale_factor() as f32), 
+            Some(device.limits().max_texture_dimension_2d as usize)
+        );
+        let renderer = egui_wgpu::Renderer::new(device, output_format, None, 1);
+
+        Self {
+            visible: false, 
+            context, 
+            state, 
+            renderer, 
+            paint_jobs: Vec::new(), 
+            textures_to_free: Vec::new(), 
+            screen: egui_wgpu::ScreenDescriptor { size_in_pixels: [1, 1], pixels_per_point: 1.0 }, 
+        }
+    }
+
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    #[inline]
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// #### 한국어 </br>
+    /// 창 이벤트를 패널에 전달합니다. 패널이 보이고 이벤트를 사용했으면 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Passes a window event to the panel. Returns `true` if the panel is visible and used the event. </br>
+    /// 
+    pub fn handle_event(&mut self, window: &Window, event: &WindowEvent) -> bool {
+        if !self.visible {
+            return false;
+        }
+
+        self.state.on_window_event(window, event).consumed
+    }
+
+    /// #### 한국어 </br>
+    /// `ui`로 이번 프레임의 패널을 구성하고, 그리는 데 필요한 텍스처와 정점 데이터를 GPU에 올립니다. </br>
+    /// 패널이 보이지 않으면 아무것도 하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds the panel of this frame with `ui`, and uploads the textures and vertex data needed to draw it to the GPU. </br>
+    /// Does nothing if the panel is not visible. </br>
+    /// 
+    pub fn prepare(
+        &mut self, 
+        window: &Window, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        encoder: &mut wgpu::CommandEncoder, 
+        ui: impl FnOnce(&egui::Context)
+    ) {
+        // (한국어) 이전 프레임이 그린 후 더 이상 쓰이지 않는 텍스처들을 해제합니다.
+        // (English Translation) Frees the textures that are no longer used after the previous frame was drawn.
+        for id in self.textures_to_free.drain(..) {
+            self.renderer.free_texture(&id);
+        }
+
+        self.paint_jobs.clear();
+        if !self.visible {
+            return;
+        }
+
+        let input = self.state.take_egui_input(window);
+        let output = self.context.run(input, ui);
+        self.state.handle_platform_output(window, output.platform_output);
+
+        for (id, delta) in output.textures_delta.set.iter() {
+            self.renderer.update_texture(device, queue, *id, delta);
+        }
+        self.textures_to_free = output.textures_delta.free;
+
+        let size = window.inner_size();
+        self.screen = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: [size.width.max(1), size.height.max(1)], 
+            pixels_per_point: output.pixels_per_point, 
+        };
+        self.paint_jobs = self.context.tessellate(output.shapes, output.pixels_per_point);
+
+        // (한국어) 패널은 그리기 콜백을 사용하지 않으므로 반환되는 커맨드 버퍼는 항상 비어 있습니다.
+        // (English Translation) The panel does not use paint callbacks, so the returned command buffers are always empty.
+        let callback_buffers = self.renderer.update_buffers(device, queue, encoder, &self.paint_jobs, &self.screen);
+        debug_assert!(callback_buffers.is_empty());
+    }
+
+    /// #### 한국어 </br>
+    /// 준비된 패널을 그립니다. 화면의 렌더 타겟에 다른 모든 것을 그린 후 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the prepared panel. It must be called after everything else is drawn into the render target of the screen. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if self.paint_jobs.is_empty() {
+            return;
+        }
+
+        self.renderer.render(rpass, &self.paint_jobs, &self.screen);
+    }
+}
+
+impl fmt::Debug for TweakPanel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TweakPanel")
+            .field("visible", &self.visible)
+            .field("num_paint_jobs", &self.paint_jobs.len())
+            .finish_non_exhaustive()
+    }
+}
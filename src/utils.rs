@@ -1,60 +1,343 @@
+use std::io;
+use std::io::Write;
+use std::fmt;
+use std::error;
 use std::sync::Arc;
 use winit::window::Window;
 
+use crate::indirect::INDIRECT_DRAW_FEATURES;
+use crate::profiler::GPU_PROFILER_FEATURES;
+use crate::target::{DEPTH_FORMAT_FEATURES, SAMPLE_COUNT_FEATURES};
 
 
+
+/// #### 한국어 </br>
+/// 스왑체인의 표시 모드를 선택하는 명령줄 인수 입니다. 뒤에 `PRESENT_MODES`의 이름이 옵니다. (예: `--present-mode mailbox`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that selects the present mode of the swapchain. It is followed by a name of `PRESENT_MODES`. (e.g. `--present-mode mailbox`) </br>
+/// 
+pub const PRESENT_MODE_ARG: &str = "--present-mode";
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 표시 모드와 명령줄에서 쓰는 이름 입니다. 단축키는 이 순서로 표면이 지원하는 모드들을 돌아갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable present modes and the names used on the command line. The shortcut cycles through the modes supported by the surface in this order. </br>
+/// 
+pub const PRESENT_MODES: [(&str, wgpu::PresentMode); 5] = [
+    ("auto-vsync", wgpu::PresentMode::AutoVsync), 
+    ("auto-no-vsync", wgpu::PresentMode::AutoNoVsync), 
+    ("fifo", wgpu::PresentMode::Fifo), 
+    ("mailbox", wgpu::PresentMode::Mailbox), 
+    ("immediate", wgpu::PresentMode::Immediate), 
+];
+
+/// #### 한국어 </br>
+/// 플랫폼의 기본 그래픽스 백엔드 대신 사용할 백엔드를 선택하는 명령줄 인수 입니다. 뒤에 `BACKENDS`의 이름이 옵니다. (예: `--backend gl`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that selects the graphics backend used instead of the default one of the platform. It is followed by a name of `BACKENDS`. (e.g. `--backend gl`) </br>
+/// 
+pub const BACKEND_ARG: &str = "--backend";
+
+/// #### 한국어 </br>
+/// 선택할 수 있는 그래픽스 백엔드와 명령줄에서 쓰는 이름 입니다. `auto`는 모든 백엔드에서 어뎁터를 찾습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selectable graphics backends and the names used on the command line. `auto` looks for an adapter on every backend. </br>
+/// 
+pub const BACKENDS: [(&str, wgpu::Backends); 5] = [
+    ("vulkan", wgpu::Backends::VULKAN), 
+    ("dx12", wgpu::Backends::DX12), 
+    ("metal", wgpu::Backends::METAL), 
+    ("gl", wgpu::Backends::GL), 
+    ("auto", wgpu::Backends::all()), 
+];
+
+/// #### 한국어 </br>
+/// 기본 어뎁터 대신 사용할 어뎁터를 고르는 명령줄 인수 입니다. </br>
+/// 뒤에 시작할 때 기록되는 어뎁터 목록의 번호나, 목록을 보여주고 번호를 입력받는 `prompt`가 옵니다. (예: `--adapter 1`) </br>
+/// 
+/// #### English (Translation) </br>
+/// The command line argument that picks the adapter used instead of the default one. </br>
+/// It is followed by an index of the adapter list logged at startup, or `prompt` to show the list and read an index. (e.g. `--adapter 1`) </br>
+/// 
+pub const ADAPTER_ARG: &str = "--adapter";
+
+
+
+/// #### 한국어 </br>
+/// `ADAPTER_ARG` 명령줄 인수로 어뎁터를 고르는 방법 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The way an adapter is picked with the `ADAPTER_ARG` command line argument. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterSelection {
+    /// #### 한국어 </br>
+    /// 어뎁터 목록의 번호로 고릅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Picks by the index in the adapter list. </br>
+    /// 
+    Index(usize), 
+    /// #### 한국어 </br>
+    /// 어뎁터 목록을 표준 출력에 보여주고 표준 입력으로 번호를 입력받습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Shows the adapter list on the standard output and reads an index from the standard input. </br>
+    /// 
+    Prompt, 
+}
+
+/// #### 한국어 </br>
+/// 렌더링 시스템을 초기화하는 동안 일어날 수 있는 오류 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The errors that can occur while initializing the rendering system. </br>
+/// 
+#[derive(Debug)]
+pub enum RenderError {
+    /// #### 한국어 </br>
+    /// 창으로부터 렌더링 표면을 생성하지 못했습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Failed to create the rendering surface from the window. </br>
+    /// 
+    CreateSurface(wgpu::CreateSurfaceError), 
+    /// #### 한국어 </br>
+    /// 표면과 호환되는 `backends`의 어뎁터가 없습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// There is no adapter of `backends` compatible with the surface. </br>
+    /// 
+    NoAdapter { backends: wgpu::Backends }, 
+    /// #### 한국어 </br>
+    /// 어뎁터로부터 렌더링 장치를 생성하지 못했습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Failed to create the rendering device from the adapter. </br>
+    /// 
+    RequestDevice(wgpu::RequestDeviceError), 
+    /// #### 한국어 </br>
+    /// 어뎁터가 `name` 경로에 반드시 필요한 기능 `features`를 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The adapter does not support the features `features` required by the `name` path. </br>
+    /// 
+    MissingFeatures { name: &'static str, features: wgpu::Features }, 
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateSurface(e) => write!(f, "failed to create a rendering surface for the window ({})", e), 
+            Self::NoAdapter { backends } => write!(f, "no compatible GPU adapter with {} backend found", backend_name(*backends)), 
+            Self::RequestDevice(e) => write!(f, "failed to create a rendering device ({})", e), 
+            Self::MissingFeatures { name, features } => write!(f, "the adapter does not support the features {:?} required by {}", features, name), 
+        }
+    }
+}
+
+impl error::Error for RenderError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::CreateSurface(e) => Some(e), 
+            Self::NoAdapter { .. } => None, 
+            Self::RequestDevice(e) => Some(e), 
+            Self::MissingFeatures { .. } => None, 
+        }
+    }
+}
+
+impl From<wgpu::CreateSurfaceError> for RenderError {
+    #[inline]
+    fn from(e: wgpu::CreateSurfaceError) -> Self {
+        Self::CreateSurface(e)
+    }
+}
+
+impl From<wgpu::RequestDeviceError> for RenderError {
+    #[inline]
+    fn from(e: wgpu::RequestDeviceError) -> Self {
+        Self::RequestDevice(e)
+    }
+}
+
 /// #### 한국어 </br>
-/// 렌더링 시스템을 초기화 합니다. </br>
+/// 렌더링 시스템을 초기화 합니다. `requested`가 `None`이면 플랫폼의 기본 백엔드를 사용하고, 
+/// 기본 백엔드에 호환되는 어뎁터가 없으면 모든 백엔드에서 다시 찾습니다. </br>
+/// `selection`이 주어지면 기본 어뎁터 대신 찾은 어뎁터들 중에서 고릅니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Initialize the rendering system. </br>
+/// Initialize the rendering system. If `requested` is `None`, the default backend of the platform is used, 
+/// and if there is no compatible adapter on the default backend, it is looked for again on every backend. </br>
+/// If `selection` is given, the adapter is picked among the found adapters instead of the default one. </br>
 /// 
-pub fn setup_rendering_system(window: Arc<Window>) -> (
+pub fn setup_rendering_system(
+    window: Arc<Window>, 
+    requested: Option<wgpu::Backends>, 
+    selection: Option<AdapterSelection>
+) -> Result<(
     Arc<wgpu::Instance>, 
     Arc<wgpu::Surface<'static>>, 
     Arc<wgpu::Adapter>, 
     Arc<wgpu::Device>, 
     Arc<wgpu::Queue>, 
-) {
-    let instance = create_render_instance();
-    let surface = create_render_surface(&instance, window.clone());
-    let adapter = create_render_adapter(&instance, &surface);
-    let (device, queue) = create_render_device_and_queue(&adapter);
-    (instance, surface, adapter, device, queue)
+), RenderError> {
+    let (instance, backends) = create_render_instance(requested.unwrap_or_else(default_backends));
+    let surface = create_render_surface(&instance, window.clone())?;
+    let (instance, surface, adapter) = match select_render_adapter(&instance, &surface, backends, selection) {
+        Some(adapter) => (instance, surface, adapter), 
+        None if requested.is_none() && backends != wgpu::Backends::all() => {
+            log::warn!("No compatible GPU adapter with {} backend found. Every backend is tried instead.", backend_name(backends));
+            drop(surface);
+            let (instance, backends) = create_render_instance(wgpu::Backends::all());
+            let surface = create_render_surface(&instance, window.clone())?;
+            let adapter = select_render_adapter(&instance, &surface, backends, selection).ok_or(RenderError::NoAdapter { backends })?;
+            (instance, surface, adapter)
+        }, 
+        None => return Err(RenderError::NoAdapter { backends }), 
+    };
+
+    log::info!("Adapter: {}", adapter_description(&adapter));
+    let (device, queue) = create_render_device_and_queue(&adapter)?;
+    Ok((instance, surface, adapter, device, queue))
+}
+
+/// #### 한국어 </br>
+/// 인스턴스가 주어진 백엔드에서 찾은 모든 어뎁터를 반환합니다. 각 어뎁터의 번호, 이름, 백엔드, 장치 종류도 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns every adapter the instance finds on the given backends. Also logs the index, name, backend and device type of each adapter. </br>
+/// 
+pub fn enumerate_adapters(instance: &wgpu::Instance, backends: wgpu::Backends) -> Vec<wgpu::Adapter> {
+    let adapters = instance.enumerate_adapters(backends);
+    log::info!("Found {} adapter(s) with {} backend.", adapters.len(), backend_name(backends));
+    for (index, adapter) in adapters.iter().enumerate() {
+        log::info!("  [{}] {}", index, adapter_description(adapter));
+    }
+    return adapters;
+}
+
+/// #### 한국어 </br>
+/// 어뎁터의 이름, 백엔드, 장치 종류를 담은 설명을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns a description holding the name, backend and device type of the adapter. </br>
+/// 
+pub fn adapter_description(adapter: &wgpu::Adapter) -> String {
+    let info = adapter.get_info();
+    format!("{} ({:?}, {:?})", info.name, info.backend, info.device_type)
 }
 
 /// #### 한국어 </br>
-/// `wgpu` 렌더링 인스턴스를 생성합니다. </br>
+/// 찾은 어뎁터들 중에서 `selection`으로 어뎁터를 고릅니다. </br>
+/// `selection`이 없거나, 고른 어뎁터가 없거나 표면과 호환되지 않으면 기본 어뎁터를 요청합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Creates a `wgpu` rendering instance. </br>
+/// Picks an adapter among the found adapters with `selection`. </br>
+/// If there is no `selection`, or the picked adapter does not exist or is not compatible with the surface, the default adapter is requested. </br>
+/// 
+fn select_render_adapter(
+    instance: &wgpu::Instance, 
+    surface: &wgpu::Surface, 
+    backends: wgpu::Backends, 
+    selection: Option<AdapterSelection>
+) -> Option<Arc<wgpu::Adapter>> {
+    let mut adapters = enumerate_adapters(instance, backends);
+    let index = match selection {
+        Some(AdapterSelection::Index(index)) => index, 
+        Some(AdapterSelection::Prompt) if !adapters.is_empty() => match prompt_adapter_index(&adapters) {
+            Some(index) => index, 
+            None => return create_render_adapter(instance, surface), 
+        }, 
+        _ => return create_render_adapter(instance, surface), 
+    };
+
+    match adapters.get(index) {
+        Some(adapter) if adapter.is_surface_supported(surface) => Some(Arc::new(adapters.swap_remove(index))), 
+        Some(adapter) => {
+            log::warn!("The adapter {} is not compatible with the window. The default adapter is used.", adapter_description(adapter));
+            create_render_adapter(instance, surface)
+        }, 
+        None => {
+            log::warn!("There is no adapter [{}]. The default adapter is used.", index);
+            create_render_adapter(instance, surface)
+        }, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 어뎁터 목록을 표준 출력에 보여주고 표준 입력에서 어뎁터의 번호를 읽습니다. 빈 줄이나 잘못된 입력은 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Shows the adapter list on the standard output and reads the index of an adapter from the standard input. An empty line or invalid input returns `None`. </br>
+/// 
+fn prompt_adapter_index(adapters: &[wgpu::Adapter]) -> Option<usize> {
+    println!("Select an adapter: (empty for the default adapter)");
+    for (index, adapter) in adapters.iter().enumerate() {
+        println!("  [{}] {}", index, adapter_description(adapter));
+    }
+    print!("> ");
+    io::stdout().flush().ok()?;
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).ok()?;
+    line.trim().parse().ok()
+}
+
+/// #### 한국어 </br>
+/// 플랫폼의 기본 그래픽스 백엔드를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the default graphics backend of the platform. </br>
 /// 
 #[inline]
-fn create_render_instance() -> Arc<wgpu::Instance> {
-    let instance_desc = if cfg!(target_os = "windows") {
-        wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::DX12,
-            dx12_shader_compiler: wgpu::util::dx12_shader_compiler_from_env().unwrap_or_default(), 
-            ..Default::default()
-        }
+fn default_backends() -> wgpu::Backends {
+    if cfg!(target_os = "windows") {
+        wgpu::Backends::DX12
     } else if cfg!(target_os = "linux") {
-        wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::VULKAN,
-            ..Default::default()
-        }
+        wgpu::Backends::VULKAN
     } else if cfg!(target_os = "macos") {
-        wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::METAL,
-            ..Default::default()
-        }
+        wgpu::Backends::METAL
     } else {
-        wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::PRIMARY, 
-            ..Default::default()
-        }
+        wgpu::Backends::PRIMARY
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 백엔드를 사용하는 `wgpu` 렌더링 인스턴스를 생성합니다. 인스턴스가 사용하는 백엔드도 함께 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a `wgpu` rendering instance using the given backends. Also returns the backends the instance uses. </br>
+/// 
+#[inline]
+fn create_render_instance(backends: wgpu::Backends) -> (Arc<wgpu::Instance>, wgpu::Backends) {
+    let instance_desc = wgpu::InstanceDescriptor {
+        backends, 
+        dx12_shader_compiler: wgpu::util::dx12_shader_compiler_from_env().unwrap_or_default(), 
+        ..Default::default()
     };
+    (Arc::new(wgpu::Instance::new(instance_desc)), backends)
+}
 
-    Arc::new(wgpu::Instance::new(instance_desc))
+/// #### 한국어 </br>
+/// 오류 메시지에 보여줄 백엔드의 이름을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the name of the backends shown in error messages. </br>
+/// 
+fn backend_name(backends: wgpu::Backends) -> &'static str {
+    match backends {
+        wgpu::Backends::VULKAN => "Vulkan", 
+        wgpu::Backends::DX12 => "DirectX 12", 
+        wgpu::Backends::METAL => "Metal", 
+        wgpu::Backends::GL => "OpenGL", 
+        backends if backends == wgpu::Backends::all() => "any", 
+        _ => "a primary",  
+    }
 }
 
 /// #### 한국어 </br>
@@ -64,46 +347,300 @@ fn create_render_instance() -> Arc<wgpu::Instance> {
 /// Creates a `wgpu` rendering surface. </br>
 /// 
 #[inline]
-fn create_render_surface(instance: &wgpu::Instance, window: Arc<Window>) -> Arc<wgpu::Surface<'static>> {
-    Arc::new(instance.create_surface(wgpu::SurfaceTarget::from(window)).unwrap())
+fn create_render_surface(instance: &wgpu::Instance, window: Arc<Window>) -> Result<Arc<wgpu::Surface<'static>>, wgpu::CreateSurfaceError> {
+    instance.create_surface(wgpu::SurfaceTarget::from(window)).map(Arc::new)
 }
 
 /// #### 한국어 </br>
-/// `wgpu` 렌더링 어뎁터를 생성합니다. </br>
+/// `wgpu` 렌더링 어뎁터를 생성합니다. 표면과 호환되는 어뎁터가 없으면 `None`을 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Creates a `wgpu` rendering adapter. </br>
+/// Creates a `wgpu` rendering adapter. Returns `None` if there is no adapter compatible with the surface. </br>
 /// 
 #[inline]
-fn create_render_adapter(instance: &wgpu::Instance, surface: &wgpu::Surface) -> Arc<wgpu::Adapter> {
-    Arc::new(pollster::block_on(
+fn create_render_adapter(instance: &wgpu::Instance, surface: &wgpu::Surface) -> Option<Arc<wgpu::Adapter>> {
+    pollster::block_on(
         instance.request_adapter(&wgpu::RequestAdapterOptions {
             compatible_surface: Some(surface), 
             force_fallback_adapter: false, 
             power_preference: wgpu::PowerPreference::default()
         }) 
-    ).unwrap())
+    ).map(Arc::new)
+}
+
+/// #### 한국어 </br>
+/// 렌더링 장치에 요청할 기능과 제한을 선언하는 빌더 입니다. </br>
+/// 기능은 반드시 필요한 것과 지원될 때만 사용하는 것으로 나뉘며, 각각 그 기능을 사용하는 경로의 이름과 함께 선언합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that declares the features and limits requested from the rendering device. </br>
+/// The features are split into the required ones and the optional ones used only when supported, and each is declared with the name of the path using it. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceRequirements {
+    pub required: Vec<(&'static str, wgpu::Features)>, 
+    pub optional: Vec<(&'static str, wgpu::Features)>, 
+    pub max_bind_groups: u32, 
+}
+
+impl Default for DeviceRequirements {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            required: Vec::new(), 
+            optional: Vec::new(), 
+            max_bind_groups: wgpu::Limits::default().max_bind_groups
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl DeviceRequirements {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// `name` 경로에 반드시 필요한 기능을 추가합니다. 어뎁터가 지원하지 않으면 장치를 만들지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds features required by the `name` path. The device is not created if the adapter does not support them. </br>
+    /// 
+    #[inline]
+    pub fn require(mut self, name: &'static str, features: wgpu::Features) -> Self {
+        self.required.push((name, features));
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// `name` 경로가 지원될 때만 사용하는 기능을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds features the `name` path uses only when they are supported. </br>
+    /// 
+    #[inline]
+    pub fn request(mut self, name: &'static str, features: wgpu::Features) -> Self {
+        self.optional.push((name, features));
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 원하는 바인드 그룹의 최대 수를 설정합니다. 어뎁터의 제한보다 크면 어뎁터의 제한을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the wanted maximum number of bind groups. The limit of the adapter is used if it is larger. </br>
+    /// 
+    #[inline]
+    pub fn set_max_bind_groups(mut self, max_bind_groups: u32) -> Self {
+        self.max_bind_groups = max_bind_groups;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 요구 사항을 어뎁터와 맞추어 요청할 기능과 제한을 반환하고, 선택 경로마다 켜졌는지 꺼졌는지 기록합니다. </br>
+    /// 반드시 필요한 기능 중 하나라도 지원되지 않으면 `MissingFeatures` 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Negotiates the requirements with the adapter, returns the features and limits to request, and logs whether each optional path is enabled or disabled. </br>
+    /// Returns a `MissingFeatures` error if any of the required features is not supported. </br>
+    /// 
+    pub fn negotiate(&self, adapter: &wgpu::Adapter) -> Result<(wgpu::Features, wgpu::Limits), RenderError> {
+        let supported = adapter.features();
+        let mut features = wgpu::Features::empty();
+        for &(name, required) in self.required.iter() {
+            if !supported.contains(required) {
+                return Err(RenderError::MissingFeatures { name, features: required - supported });
+            }
+            features |= required;
+        }
+
+        for &(name, optional) in self.optional.iter() {
+            match supported.contains(optional) {
+                true => {
+                    log::info!("Enabled: {}", name);
+                    features |= optional;
+                }, 
+                false => log::info!("Disabled: {} (missing {:?})", name, optional - supported), 
+            }
+        }
+
+        let limits = wgpu::Limits {
+            max_bind_groups: adapter.limits().max_bind_groups.min(self.max_bind_groups), 
+            ..wgpu::Limits::default().using_resolution(adapter.limits())
+        };
+        return Ok((features, limits));
+    }
 }
 
 /// #### 한국어 </br>
-/// `wgpu` 렌더링 장치와 명령어 대기열을 생성합니다. </br>
+/// 이 애플리케이션의 렌더링 장치 요구 사항을 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Creates a `wgpu` rendering device and command queue. </br>
+/// Returns the rendering device requirements of this application. </br>
+/// 
+pub fn device_requirements() -> DeviceRequirements {
+    // (한국어) 간접 그리기 경로, 선택할 수 있는 깊이 버퍼 형식과 멀티샘플링은 지원되는 기능이 있을 때 이를 사용합니다.
+    // (English Translation) The indirect draw path, the selectable depth buffer formats and the multisampling use the supported features when available.
+    // (한국어) GPU 프로파일러도 타임스탬프 쿼리가 지원될 때만 사용됩니다.
+    // (English Translation) The GPU profiler is also used only when the timestamp queries are supported.
+    // (한국어) 색상 파이프라인은 안개를 포함해 4개보다 많은 바인드 그룹을 사용합니다.
+    // (English Translation) The colored pipeline uses more than 4 bind groups, including the fog.
+    DeviceRequirements::new()
+        .request("multi-draw indirect", INDIRECT_DRAW_FEATURES)
+        .request("depth32float-stencil8 depth format", DEPTH_FORMAT_FEATURES)
+        .request("adapter specific sample counts", SAMPLE_COUNT_FEATURES)
+        .request("GPU profiler", GPU_PROFILER_FEATURES)
+        .set_max_bind_groups(8)
+}
+
+/// #### 한국어 </br>
+/// `device_requirements`를 어뎁터와 맞추어 `wgpu` 렌더링 장치와 명령어 대기열을 생성합니다. </br>
+/// 장치를 잃었을 때 장치를 다시 생성하는 데도 사용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a `wgpu` rendering device and command queue, negotiating `device_requirements` with the adapter. </br>
+/// It is also used to recreate the device when the device is lost. </br>
 /// 
 #[inline]
-fn create_render_device_and_queue(adapter: &wgpu::Adapter) -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
+pub fn create_render_device_and_queue(adapter: &wgpu::Adapter) -> Result<(Arc<wgpu::Device>, Arc<wgpu::Queue>), RenderError> {
+    let (required_features, required_limits) = device_requirements().negotiate(adapter)?;
     pollster::block_on(
         adapter.request_device(
             &wgpu::DeviceDescriptor {
                 label: Some("DeviceDescriptor"), 
-                required_features: wgpu::Features::empty(), 
-                required_limits: wgpu::Limits::default()
-                    .using_resolution(adapter.limits())
+                required_features, 
+                required_limits
             }, 
             None
         )
     )
     .map(|(device, queue)| (Arc::new(device), Arc::new(queue)))
-    .unwrap()
+    .map_err(RenderError::from)
+}
+
+/// #### 한국어 </br>
+/// 표면이 지원하는 형식 중에서 스왑체인의 텍스처 형식을 고릅니다. </br>
+/// `Bgra8UnormSrgb`, `Rgba8UnormSrgb`, 그 밖의 sRGB 형식 순서로 우선하며, 없으면 표면이 가장 선호하는 형식을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Picks the texture format of the swapchain among the ones supported by the surface. </br>
+/// Prefers `Bgra8UnormSrgb`, `Rgba8UnormSrgb` and then any other sRGB format, and uses the format most preferred by the surface if there is none. </br>
+/// 
+pub fn select_surface_format(surface: &wgpu::Surface, adapter: &wgpu::Adapter) -> wgpu::TextureFormat {
+    let formats = surface.get_capabilities(adapter).formats;
+    [wgpu::TextureFormat::Bgra8UnormSrgb, wgpu::TextureFormat::Rgba8UnormSrgb].into_iter()
+        .find(|format| formats.contains(format))
+        .or_else(|| formats.iter().copied().find(|format| format.is_srgb()))
+        .or_else(|| formats.first().copied())
+        .unwrap_or(wgpu::TextureFormat::Bgra8Unorm)
+}
+
+/// #### 한국어 </br>
+/// `PRESENT_MODE_ARG` 명령줄 인수의 값을 표시 모드로 해석합니다. 알 수 없는 값이면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `PRESENT_MODE_ARG` command line argument as a present mode. Returns an `InvalidInput` error if the value is unknown. </br>
+/// 
+pub fn parse_present_mode(value: &str) -> io::Result<wgpu::PresentMode> {
+    match PRESENT_MODES.iter().find(|(name, _)| *name == value) {
+        Some(&(_, present_mode)) => Ok(present_mode), 
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!(
+                "{} must be followed by one of {}", 
+                PRESENT_MODE_ARG, 
+                PRESENT_MODES.map(|(name, _)| name).join(", ")
+            )
+        )), 
+    }
+}
+
+/// #### 한국어 </br>
+/// `BACKEND_ARG` 명령줄 인수의 값을 그래픽스 백엔드로 해석합니다. 알 수 없는 값이면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `BACKEND_ARG` command line argument as a graphics backend. Returns an `InvalidInput` error if the value is unknown. </br>
+/// 
+pub fn parse_backend(value: &str) -> io::Result<wgpu::Backends> {
+    match BACKENDS.iter().find(|(name, _)| *name == value) {
+        Some(&(_, backends)) => Ok(backends), 
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput, 
+            format!(
+                "{} must be followed by one of {}", 
+                BACKEND_ARG, 
+                BACKENDS.map(|(name, _)| name).join(", ")
+            )
+        )), 
+    }
+}
+
+/// #### 한국어 </br>
+/// `ADAPTER_ARG` 명령줄 인수의 값을 어뎁터를 고르는 방법으로 해석합니다. 어뎁터 번호나 `prompt`가 아니면 `InvalidInput` 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the value of the `ADAPTER_ARG` command line argument as the way of picking the adapter. Returns an `InvalidInput` error if it is neither an adapter index nor `prompt`. </br>
+/// 
+pub fn parse_adapter_selection(value: &str) -> io::Result<AdapterSelection> {
+    let selection = match value {
+        "prompt" => Some(AdapterSelection::Prompt), 
+        value => value.parse().ok().map(AdapterSelection::Index), 
+    };
+    selection.ok_or_else(|| io::Error::new(
+        io::ErrorKind::InvalidInput, 
+        format!("{} must be followed by an adapter index or prompt", ADAPTER_ARG)
+    ))
+}
+
+/// #### 한국어 </br>
+/// 표시 모드의 명령줄 이름을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the command line name of the present mode. </br>
+/// 
+pub fn present_mode_name(present_mode: wgpu::PresentMode) -> &'static str {
+    PRESENT_MODES.iter()
+        .find(|(_, mode)| *mode == present_mode)
+        .map_or("unknown", |(name, _)| name)
+}
+
+/// #### 한국어 </br>
+/// 표면이 요청된 표시 모드를 지원하면 그대로 반환하고, 지원하지 않으면 모든 표면이 지원하는 `AutoVsync`를 반환합니다. </br>
+/// `AutoVsync`와 `AutoNoVsync`는 `wgpu`가 지원되는 모드로 바꾸어 주므로 항상 지원됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the requested present mode if the surface supports it, and otherwise `AutoVsync`, which every surface supports. </br>
+/// `AutoVsync` and `AutoNoVsync` are always supported since `wgpu` replaces them with a supported mode. </br>
+/// 
+pub fn select_present_mode(requested: wgpu::PresentMode, surface: &wgpu::Surface, adapter: &wgpu::Adapter) -> wgpu::PresentMode {
+    match is_present_mode_supported(requested, surface, adapter) {
+        true => requested, 
+        false => {
+            log::warn!("The surface does not support the {} present mode. The auto-vsync present mode is used.", present_mode_name(requested));
+            wgpu::PresentMode::AutoVsync
+        }, 
+    }
+}
+
+/// #### 한국어 </br>
+/// `PRESENT_MODES`에서 `current` 다음에 오는 표면이 지원하는 표시 모드를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the present mode supported by the surface that comes after `current` in `PRESENT_MODES`. </br>
+/// 
+pub fn next_present_mode(current: wgpu::PresentMode, surface: &wgpu::Surface, adapter: &wgpu::Adapter) -> wgpu::PresentMode {
+    let index = PRESENT_MODES.iter().position(|(_, mode)| *mode == current).unwrap_or(0);
+    (1..=PRESENT_MODES.len())
+        .map(|offset| PRESENT_MODES[(index + offset) % PRESENT_MODES.len()].1)
+        .find(|&mode| is_present_mode_supported(mode, surface, adapter))
+        .unwrap_or(current)
+}
+
+#[inline]
+fn is_present_mode_supported(present_mode: wgpu::PresentMode, surface: &wgpu::Surface, adapter: &wgpu::Adapter) -> bool {
+    match present_mode {
+        wgpu::PresentMode::AutoVsync | wgpu::PresentMode::AutoNoVsync => true, 
+        _ => surface.get_capabilities(adapter).present_modes.contains(&present_mode), 
+    }
 }
@@ -0,0 +1,461 @@
+use std::mem;
+use bytemuck::{Pod, Zeroable};
+
+use crate::camera::{CameraUniformLayout, GameCameraObject};
+use crate::object::GameObject;
+use crate::postprocess;
+use crate::resource::ShaderResource;
+use crate::target::RenderTarget;
+use crate::stats;
+
+
+
+/// #### 한국어 </br>
+/// 반사 렌더 타겟의 해상도를 화면 해상도로 나누는 값 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The value dividing the screen resolution into the resolution of the reflection render target. </br>
+/// 
+pub const REFLECTION_DOWNSCALE: u32 = 2;
+
+
+
+/// #### 한국어 </br>
+/// 평면 반사를 사용하는 수면을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a water surface using a planar reflection. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaterBuilder {
+    pub translation: glam::Vec3, 
+    pub size: glam::Vec2, 
+    pub color: glam::Vec3, 
+    pub opacity: f32, 
+    pub wave_scale: f32, 
+    pub wave_speed: f32, 
+    pub distortion: f32, 
+    pub fresnel_power: f32, 
+}
+
+impl Default for WaterBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            translation: glam::Vec3::ZERO, 
+            size: (4.0, 4.0).into(), 
+            color: (0.02, 0.15, 0.22).into(), 
+            opacity: 0.8, 
+            wave_scale: 2.0, 
+            wave_speed: 1.0, 
+            distortion: 0.02, 
+            fresnel_power: 5.0
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WaterBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 수면의 중심을 설정합니다. 수면은 이 위치의 높이에 수평으로 놓입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the center of the water surface. The surface lies horizontally at the height of this position. </br>
+    /// 
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 수면의 X축과 Z축 방향 크기를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the size of the water surface along the X and Z axes. </br>
+    /// 
+    #[inline]
+    pub fn set_size(mut self, size: glam::Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    #[inline]
+    pub fn set_color(mut self, color: glam::Vec3) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 수면을 정면에서 내려다볼 때의 불투명도를 설정합니다. 비스듬히 볼수록 반사가 강해지며 불투명해집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the opacity when looking straight down at the surface. The more grazing the view, the stronger and more opaque the reflection becomes. </br>
+    /// 
+    #[inline]
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 미터당 물결의 수를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the number of waves per meter. </br>
+    /// 
+    #[inline]
+    pub fn set_wave_scale(mut self, wave_scale: f32) -> Self {
+        self.wave_scale = wave_scale;
+        self
+    }
+
+    #[inline]
+    pub fn set_wave_speed(mut self, wave_speed: f32) -> Self {
+        self.wave_speed = wave_speed;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 물결의 법선이 반사 텍스처의 좌표를 흔드는 정도를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets how much the wave normals shake the coordinates of the reflection texture. </br>
+    /// 
+    #[inline]
+    pub fn set_distortion(mut self, distortion: f32) -> Self {
+        self.distortion = distortion;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 프레넬 항의 지수를 설정합니다. 클수록 비스듬히 볼 때에만 반사가 보입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the exponent of the fresnel term. The larger it is, the more the reflection only shows at grazing angles. </br>
+    /// 
+    #[inline]
+    pub fn set_fresnel_power(mut self, fresnel_power: f32) -> Self {
+        self.fresnel_power = fresnel_power;
+        self
+    }
+
+    pub fn build(
+        self, 
+        screen_width: u32, 
+        screen_height: u32, 
+        camera_bind_group_layout: &wgpu::BindGroupLayout, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Water {
+        assert!(self.size.x > 0.0 && self.size.y > 0.0);
+
+        let (width, height) = reflection_size(screen_width, screen_height);
+        let target = RenderTarget::new("WaterReflection", width, height, postprocess::SCENE_COLOR_FORMAT, device);
+
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(Water)"), 
+                address_mode_u: wgpu::AddressMode::ClampToEdge, 
+                address_mode_v: wgpu::AddressMode::ClampToEdge, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                ..Default::default()
+            }, 
+        );
+
+        let uniform_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(Water)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<WaterUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let bind_group = create_bind_group(&target, &sampler, &uniform_buffer, bind_group_layout, device);
+
+        let camera_buffer = stats::track_buffer(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Uniform(WaterReflectedCamera)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<CameraUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        ));
+
+        let camera_bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(WaterReflectedCamera)"), 
+                layout: camera_bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            camera_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let water = Water {
+            transform: glam::Mat4::from_translation(self.translation), 
+            size: self.size, 
+            color: self.color, 
+            opacity: self.opacity.clamp(0.0, 1.0), 
+            wave_scale: self.wave_scale, 
+            wave_speed: self.wave_speed, 
+            distortion: self.distortion, 
+            fresnel_power: self.fresnel_power.max(0.0), 
+            time: 0.0, 
+            target, 
+            sampler, 
+            uniform_buffer, 
+            uniform_bind_group: bind_group, 
+            camera_buffer, 
+            camera_bind_group, 
+        };
+        water.update_resource(queue);
+
+        return water;
+    }
+}
+
+/// #### 한국어 </br>
+/// 평면 반사와 움직이는 물결 법선으로 그려지는 수면 입니다. </br>
+/// 수면 사각형은 로컬 공간의 XZ 평면에 정점 버퍼 없이 그려지며 +Y 방향을 바라봅니다. </br>
+/// 수면을 그리는 순서는 다음과 같습니다: </br>
+/// 1. 수면에 대해 반사된 카메라(`camera_bind_group`)로 장면을 반사 렌더 타겟에 그립니다. </br>
+/// 2. 수면을 그릴 때 화면 공간 좌표로 반사 텍스처를 읽고, 시간에 따라 움직이는 물결의 법선으로 좌표를 흔듭니다. </br>
+/// 3. 프레넬 항으로 물의 색과 반사된 색을 혼합합니다. </br>
+/// 
+/// 반사된 장면을 자르는 평면은 사용하지 않으므로 물체들은 수면 위쪽에 있어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A water surface drawn with a planar reflection and animated wave normals. </br>
+/// The water quad is drawn on the XZ plane of local space without a vertex buffer and faces the +Y direction. </br>
+/// The water is drawn in the following order: </br>
+/// 1. Draws the scene into the reflection render target with the camera reflected about the surface (`camera_bind_group`). </br>
+/// 2. When drawing the surface, reads the reflection texture at the screen space coordinates and shakes them with the normals of the waves moving over time. </br>
+/// 3. Blends the water color and the reflected color with the fresnel term. </br>
+/// 
+/// No clip plane is used for the reflected scene, so objects must be above the surface. </br>
+/// 
+#[derive(Debug)]
+pub struct Water {
+    transform: glam::Mat4, 
+    size: glam::Vec2, 
+    color: glam::Vec3, 
+    opacity: f32, 
+    wave_scale: f32, 
+    wave_speed: f32, 
+    distortion: f32, 
+    fresnel_power: f32, 
+    time: f32, 
+    target: RenderTarget, 
+    sampler: wgpu::Sampler, 
+    uniform_buffer: wgpu::Buffer, 
+    pub uniform_bind_group: wgpu::BindGroup, 
+    camera_buffer: wgpu::Buffer, 
+    pub camera_bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl Water {
+    #[inline]
+    pub fn target_ref(&self) -> &RenderTarget {
+        &self.target
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 공간에서 수면의 높이를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the height of the water surface in world space. </br>
+    /// 
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.get_translation().y
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 위치가 수면의 위쪽에 있는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the given position is above the water surface. </br>
+    /// 
+    #[inline]
+    pub fn is_above(&self, position: glam::Vec3) -> bool {
+        position.y > self.height()
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 크기가 바뀌었을 때 반사 렌더 타겟을 다시 생성하고 수면이 읽는 텍스처를 바꿉니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the reflection render target and changes the texture read by the water when the screen size changes. </br>
+    /// 
+    pub fn resize(
+        &mut self, 
+        screen_width: u32, 
+        screen_height: u32, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        device: &wgpu::Device
+    ) {
+        let (width, height) = reflection_size(screen_width, screen_height);
+        self.target.resize(width, height, device);
+        self.uniform_bind_group = create_bind_group(&self.target, &self.sampler, &self.uniform_buffer, bind_group_layout, device);
+    }
+
+    /// #### 한국어 </br>
+    /// 물결 애니메이션의 시간을 진행시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time of the wave animation. </br>
+    /// 
+    pub fn advance(&mut self, elapsed_time_sec: f32, queue: &wgpu::Queue) {
+        self.time += elapsed_time_sec;
+        self.update_resource(queue);
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 공간의 점을 수면에 대해 반사하는 변환 행렬을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the transform matrix that reflects world space points about the water surface. </br>
+    /// 
+    #[inline]
+    pub fn reflection_transform(&self) -> glam::Mat4 {
+        glam::Mat4::from_translation(glam::vec3(0.0, 2.0 * self.height(), 0.0)) * glam::Mat4::from_scale(glam::vec3(1.0, -1.0, 1.0))
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 카메라를 수면에 반사한 카메라의 유니폼 데이터를 갱신합니다. </br>
+    /// 반사 렌더 타겟은 화면과 같은 종횡비를 가지므로 주어진 카메라의 투영 행렬을 그대로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the uniform data of the camera reflected from the given camera by the water surface. </br>
+    /// The reflection render target has the same aspect ratio as the screen, so the projection matrix of the given camera is used as is. </br>
+    /// 
+    pub fn update_reflected_camera<C: GameCameraObject>(&self, camera: &C, queue: &wgpu::Queue) {
+        let reflection = self.reflection_transform();
+        let data = CameraUniformLayout {
+            view: camera.view_transform() * reflection, 
+            projection: camera.projection_transform(), 
+            position: (reflection.transform_point3(camera.get_translation()), 0.0).into(), 
+        };
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    /// #### 한국어 </br>
+    /// 수면 사각형을 그립니다. 수면 파이프라인이 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the water quad. The water pipeline must be set. </br>
+    /// 
+    #[inline]
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        stats::set_bind_group(rpass, 1, &self.uniform_bind_group, &[]);
+        stats::draw(rpass, 0..6, 0..1);
+    }
+}
+
+impl GameObject for Water {
+    #[inline]
+    fn world_transform_ref(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    #[inline]
+    fn world_transform_mut(&mut self) -> &mut glam::Mat4 {
+        &mut self.transform
+    }
+}
+
+impl ShaderResource for Water {
+    #[inline]
+    fn update_resource(&self, queue: &wgpu::Queue) {
+        let data = WaterUniformLayout {
+            world: self.transform, 
+            color: (self.color, self.opacity).into(), 
+            params: (0.5 * self.size.x, 0.5 * self.size.y, self.time * self.wave_speed, self.wave_scale).into(), 
+            surface: (self.distortion, self.fresnel_power, 0.0, 0.0).into(), 
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&data));
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에서 사용하는 수면 유니폼 데이터의 레이아웃 입니다. </br>
+/// `color`는 (물의 색, 불투명도) 이고, `params`는 (가로 절반 크기, 세로 절반 크기, 물결의 위상, 미터당 물결 수), 
+/// `surface`는 (왜곡 세기, 프레넬 지수, 0, 0) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the layout of the water uniform data used in the shader. </br>
+/// `color` is (water color, opacity), `params` is (half width, half depth, wave phase, waves per meter), 
+/// and `surface` is (distortion strength, fresnel exponent, 0, 0). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaterUniformLayout {
+    pub world: glam::Mat4, 
+    pub color: glam::Vec4, 
+    pub params: glam::Vec4, 
+    pub surface: glam::Vec4, 
+}
+
+impl Default for WaterUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            world: glam::Mat4::IDENTITY, 
+            color: (0.02, 0.15, 0.22, 0.8).into(), 
+            params: (2.0, 2.0, 0.0, 2.0).into(), 
+            surface: (0.02, 5.0, 0.0, 0.0).into()
+        }
+    }
+}
+
+#[inline]
+fn reflection_size(screen_width: u32, screen_height: u32) -> (u32, u32) {
+    ((screen_width / REFLECTION_DOWNSCALE).max(1), (screen_height / REFLECTION_DOWNSCALE).max(1))
+}
+
+fn create_bind_group(
+    target: &RenderTarget, 
+    sampler: &wgpu::Sampler, 
+    uniform_buffer: &wgpu::Buffer, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    device: &wgpu::Device
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(Water)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::Buffer(
+                        uniform_buffer.as_entire_buffer_binding()
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::TextureView(target.color_view_ref()), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+            ], 
+        }, 
+    )
+}